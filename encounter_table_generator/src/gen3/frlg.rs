@@ -1,11 +1,10 @@
 use crate::gen3::pack::pack_encounter_gen3;
+use crate::gen3::source::Source;
 use crate::gen3::text::{clean_string, load_pokemon};
-use crate::gen3::WildEncounters;
 use bzip2::write::BzEncoder;
 use bzip2::Compression;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use reqwest::blocking::Response;
 use std::fs::OpenOptions;
 use std::io::{BufWriter, Write};
 use std::path::PathBuf;
@@ -13,11 +12,10 @@ use std::path::PathBuf;
 static ALTERING_CAVE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"AlteringCave_[2-9]").unwrap());
 
 pub fn encounters(text: bool, mut resources_path: PathBuf) {
-    const DATA: &str =
-        "https://raw.githubusercontent.com/pret/pokefirered/master/src/data/wild_encounters.json";
-
-    let data_raw = reqwest::blocking::get(DATA).unwrap();
-    let data = serde_json::from_reader::<Response, WildEncounters>(data_raw).unwrap();
+    let data = Source {
+        repo: "pokefirered",
+    }
+    .load();
 
     let pokemon = load_pokemon();
     let encounters = &data.wild_encounter_groups[0].encounters;
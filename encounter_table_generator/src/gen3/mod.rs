@@ -3,6 +3,7 @@ mod encounters3;
 pub mod frlg;
 mod pack;
 pub mod rs;
+pub mod source;
 mod text;
 pub mod xd;
 
@@ -0,0 +1,122 @@
+use crate::gen3::WildEncounters;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// Environment variable pointing to a directory with the pret `wild_encounters.json` files
+pub const DATA_DIR_ENV: &str = "POKEFINDER_PRET_DIR";
+
+const VENDORED_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen3/pret");
+const CHECKSUMS: &str = include_str!("pret/SHA256SUMS");
+
+/// Upstream pret repository that a `wild_encounters.json` is taken from
+pub struct Source {
+    /// Name of the pret repository, also used as the file name
+    pub repo: &'static str,
+}
+
+impl Source {
+    fn file_name(&self) -> String {
+        format!("{}.json", self.repo)
+    }
+
+    #[cfg(feature = "download")]
+    fn url(&self, commit: &str) -> String {
+        format!(
+            "https://raw.githubusercontent.com/pret/{}/{commit}/src/data/wild_encounters.json",
+            self.repo
+        )
+    }
+
+    /// Returns the pinned SHA-256 and pret commit of the source from `pret/SHA256SUMS`
+    fn pin(&self) -> Option<(&'static str, &'static str)> {
+        let file_name = self.file_name();
+        CHECKSUMS
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .find_map(|line| {
+                let mut fields = line.split_whitespace();
+                let hash = fields.next()?;
+                let name = fields.next()?;
+                let commit = fields.next()?;
+                (name == file_name).then_some((hash, commit))
+            })
+    }
+
+    /// Loads the encounters of the source
+    ///
+    /// The file is read from [`DATA_DIR_ENV`] first, then from the vendored `pret` directory.
+    /// Only with the `download` feature is the file fetched from GitHub, at the commit pinned in
+    /// `pret/SHA256SUMS`. Data that does not match its pinned SHA-256 is rejected.
+    ///
+    /// Panics, failing the build, when the file is neither available locally nor downloadable.
+    pub fn load(&self) -> WildEncounters {
+        let pin = self.pin();
+        let data = match (self.read_local(), pin) {
+            (Some(data), _) => data,
+            (None, Some((_, commit))) if cfg!(feature = "download") => self.download(commit),
+            (None, _) => panic!(
+                "{} was not found, copy it into {VENDORED_DIR} or point {DATA_DIR_ENV} at a \
+                 directory that holds it{}",
+                self.file_name(),
+                if pin.is_some() {
+                    ", or build with the `download` feature to fetch the pinned copy"
+                } else {
+                    ""
+                }
+            ),
+        };
+
+        let hash = Sha256::digest(&data)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+        match pin {
+            Some((expected, _)) if expected != hash => panic!(
+                "{} has SHA-256 {hash} but {expected} is pinned in SHA256SUMS",
+                self.file_name()
+            ),
+            Some(_) => {}
+            // Local copies are accepted before they are pinned, the hash is reported so the pin
+            // can be added to SHA256SUMS together with the pret commit the file was taken from
+            None => println!(
+                "cargo:warning={} is not pinned in SHA256SUMS, its SHA-256 is {hash}",
+                self.file_name()
+            ),
+        }
+
+        serde_json::from_slice(&data).unwrap()
+    }
+
+    fn read_local(&self) -> Option<Vec<u8>> {
+        let mut dirs = vec![];
+        if let Ok(dir) = std::env::var(DATA_DIR_ENV) {
+            dirs.push(PathBuf::from(dir));
+        }
+        dirs.push(PathBuf::from(VENDORED_DIR));
+
+        dirs.into_iter()
+            .map(|mut path| {
+                path.push(self.file_name());
+                path
+            })
+            .find_map(|path| {
+                let data = std::fs::read(&path).ok()?;
+                println!("cargo:rerun-if-changed={}", path.display());
+                Some(data)
+            })
+    }
+
+    #[cfg(feature = "download")]
+    fn download(&self, commit: &str) -> Vec<u8> {
+        reqwest::blocking::get(self.url(commit))
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .unwrap()
+            .to_vec()
+    }
+
+    #[cfg(not(feature = "download"))]
+    fn download(&self, _commit: &str) -> Vec<u8> {
+        unreachable!("downloads are only attempted with the `download` feature")
+    }
+}
@@ -4,8 +4,6 @@ use std::collections::HashMap;
 pub mod dp;
 mod encounters4;
 pub mod hgss;
-mod pack;
-pub mod pt;
 mod text;
 
 pub use encounters4::*;
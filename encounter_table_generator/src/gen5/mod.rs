@@ -4,7 +4,6 @@ use std::collections::HashMap;
 pub mod bw;
 pub mod bw2;
 mod encounters5;
-mod pack;
 mod text;
 
 pub use encounters5::*;
@@ -3,4 +3,4 @@ pub mod gen4;
 pub mod gen5;
 pub mod gen8;
 
-pub(crate) mod narc;
+pub mod rom;
@@ -0,0 +1,89 @@
+use bzip2::write::BzEncoder;
+use bzip2::Compression;
+use nds_encounters::nds::Nds;
+use nds_encounters::rom::{self, RESOURCES};
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Error, ErrorKind, Result, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing to a directory with `.nds` ROMs to extract encounters from
+pub const ROM_DIR_ENV: &str = "POKEFINDER_ROM_DIR";
+
+/// Writes `data` to the resource `file_name`
+fn write_resource(resources_path: &Path, file_name: &str, data: &[u8]) {
+    let file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(resources_path.join(file_name))
+        .unwrap();
+    let mut writer = BufWriter::new(file);
+    writer.write_all(data).unwrap();
+}
+
+/// Returns the text bank with the map names of a ROM, `map_names` is the NARC and index of the
+/// bank such as [`nds_encounters::gen4::dp::MAP_NAMES`]
+pub(crate) fn map_names(rom: &Nds, (path, index): (&str, usize)) -> Result<Vec<u8>> {
+    rom.get_narc(path)?
+        .elements
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("{path} has no map names")))
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut compressor = BzEncoder::new(vec![], Compression::best());
+    compressor.write_all(data).unwrap();
+    compressor.finish().unwrap()
+}
+
+/// Writes the Gen 4 and Gen 5 encounter resources packed from every ROM in [`ROM_DIR_ENV`].
+///
+/// The encounter data of these games can not be distributed, so every resource that no ROM
+/// provides is written without any encounters and listed in a warning. ROMs that can not be read
+/// are skipped with a warning. Cargo is told to rerun the build when the directory or one of its
+/// ROMs changes.
+pub fn write_encounters(resources_path: PathBuf) {
+    let mut written = HashSet::new();
+    if let Ok(dir) = std::env::var(ROM_DIR_ENV) {
+        println!("cargo:rerun-if-changed={dir}");
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension() != Some(OsStr::new("nds")) {
+                continue;
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+
+            let resources = match Nds::open(&path).and_then(|rom| rom::pack_resources(&rom)) {
+                Ok(resources) => resources,
+                Err(error) => {
+                    println!("cargo:warning={}: {error}", path.display());
+                    continue;
+                }
+            };
+
+            for (file_name, data) in resources {
+                write_resource(&resources_path, file_name, &compress(&data));
+                written.insert(file_name);
+            }
+        }
+    }
+
+    let missing = RESOURCES
+        .into_iter()
+        .filter(|file_name| !written.contains(file_name))
+        .collect::<Vec<_>>();
+    for file_name in &missing {
+        write_resource(&resources_path, file_name, &compress(&[]));
+    }
+    if !missing.is_empty() {
+        println!(
+            "cargo:warning={} have no encounters, set {ROM_DIR_ENV} to a directory with the \
+             matching .nds ROMs to extract them",
+            missing.join(", ")
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::gen4::pack::{
+    pack_encounter_hgss, pack_encounter_hgss_bug, pack_encounter_hgss_headbutt,
+};
+use crate::narc::Narc;
+use crate::rom::MapHeaders;
+use no_std_io::{StreamContainer, StreamReader};
+
+/// Map headers in the ARM9 binary
+pub(crate) const MAP_HEADERS: MapHeaders = MapHeaders {
+    count: 540,
+    size: 24,
+    music: 12,
+    encounter_id,
+};
+
+/// NARC holding the text banks and the index of the map names in it
+pub const MAP_NAMES: (&str, usize) = ("a/0/2/7", 279);
+
+/// Bug Catching Contest encounters
+pub(crate) const BUG_PATH: &str = "fielddata/mushi/mushi_encount.bin";
+/// NARC with the headbutt encounters
+pub(crate) const HEADBUTT_PATH: &str = "a/2/5/2";
+/// NARC with the Safari Zone encounters
+pub(crate) const SAFARI_PATH: &str = "a/2/3/0";
+
+fn encounter_id(map_header: &[u8]) -> Option<u16> {
+    (map_header[0] != u8::MAX).then_some(map_header[0] as u16)
+}
+
+/// Returns the encounter table and location number of every map with wild encounters
+pub fn map_encounters(map_headers: &[u8]) -> Vec<(u8, u8)> {
+    let mut encounters = vec![];
+    let mut reader = StreamContainer::new(map_headers);
+    for _ in 0..MAP_HEADERS.count {
+        let map_header = reader.read_byte_stream(MAP_HEADERS.size).unwrap();
+        let encounter_id = map_header[0];
+        if [7, 31, 32, 33, 34, 35, 36, 37, 84, 107, 11, 12, 13].contains(&encounter_id) {
+            continue;
+        }
+
+        if encounter_id != u8::MAX {
+            let location_number = map_header[18];
+            encounters.push((encounter_id, location_number));
+        }
+    }
+    encounters
+}
+
+/// Packs the wild encounters of every map from a HeartGold and SoulSilver encounter NARC
+pub(crate) fn pack(encounters: &Narc, map_headers: &[u8]) -> Vec<u8> {
+    let mut data = vec![];
+    for (encounter_id, _) in map_encounters(map_headers) {
+        data.push(encounter_id);
+        data.extend(pack_encounter_hgss(
+            &encounters.elements[encounter_id as usize],
+        ));
+    }
+    data
+}
+
+/// Packs the Bug Catching Contest encounters
+pub(crate) fn pack_bug(bug_encount: &[u8]) -> Vec<u8> {
+    pack_encounter_hgss_bug(bug_encount)
+}
+
+/// Packs the headbutt encounters from the headbutt NARC
+pub(crate) fn pack_headbutt(headbutt_encount: &Narc) -> Vec<u8> {
+    const LOCATIONS: [u8; 60] = [
+        111, 112, 113, 114, 115, 116, 117, 118, 121, 92, 122, 123, 124, 125, 127, 129, 131, 103,
+        104, 105, 1, 3, 4, 8, 17, 21, 22, 25, 26, 38, 39, 52, 57, 59, 67, 68, 95, 96, 147, 97, 98,
+        99, 100, 0, 2, 5, 146, 27, 58, 85, 128, 24, 20, 137, 71, 102, 148, 136, 125, 87,
+    ];
+
+    // Maps without trees only store their tree counts
+    let mut headbutt = vec![];
+    for (i, encounter) in headbutt_encount
+        .elements
+        .iter()
+        .filter(|x| x.len() != 4)
+        .enumerate()
+    {
+        if i == 58 {
+            continue;
+        }
+
+        headbutt.push(LOCATIONS[i]);
+        headbutt.extend(pack_encounter_hgss_headbutt(encounter));
+    }
+    headbutt
+}
+
+/// Packs the Safari Zone encounters from the Safari Zone NARC
+pub(crate) fn pack_safari(safari_encount: &Narc) -> Vec<u8> {
+    let mut safari = vec![];
+    const LOCATION_START: u8 = 149;
+
+    for (i, safari_encount) in safari_encount.elements.iter().enumerate() {
+        safari.push(LOCATION_START + (i as u8));
+
+        let waterflag = [1, 4, 5, 7, 8].contains(&(i as u8));
+        safari.push(u8::from(waterflag));
+
+        let mut stream = StreamContainer::new(safari_encount.as_slice());
+        let tall_grass_encounters = stream.read_stream_le::<u8>().unwrap();
+        let surfing_encounters = stream.read_stream_le::<u8>().unwrap();
+        let old_rod_encounters = stream.read_stream_le::<u8>().unwrap();
+        let good_rod_encounters = stream.read_stream_le::<u8>().unwrap();
+        let super_rod_encounters = stream.read_stream_le::<u8>().unwrap();
+        let _padding = stream.read_stream_le::<[u8; 3]>().unwrap();
+
+        let encounters = [
+            tall_grass_encounters,
+            surfing_encounters,
+            old_rod_encounters,
+            good_rod_encounters,
+            super_rod_encounters,
+        ];
+
+        'enc: for encounter in encounters {
+            for _ in 0..30 {
+                let specie = stream.read_stream_le::<u16>().unwrap();
+                let level = stream.read_stream_le::<u8>().unwrap();
+                let _padding = stream.read_stream_le::<u8>();
+
+                safari.extend(specie.to_le_bytes());
+                safari.push(level);
+                safari.push(0);
+            }
+
+            for _ in 0..(encounter * 3) {
+                let specie = stream.read_stream_le::<u16>().unwrap();
+                let level = stream.read_stream_le::<u8>().unwrap();
+                let _padding = stream.read_stream_le::<u8>();
+
+                safari.extend(specie.to_le_bytes());
+                safari.push(level);
+                safari.push(0);
+            }
+
+            let mut first_block_type = vec![];
+            let mut first_block_quantity = vec![];
+            let mut second_block_type = vec![];
+            let mut second_block_quantity = vec![];
+
+            for _ in 0..encounter {
+                first_block_type.push(stream.read_stream_le::<u8>().unwrap());
+                first_block_quantity.push(stream.read_stream_le::<u8>().unwrap());
+                second_block_type.push(stream.read_stream_le::<u8>().unwrap());
+                second_block_quantity.push(stream.read_stream_le::<u8>().unwrap());
+            }
+
+            for ty in first_block_type {
+                safari.push(ty);
+            }
+
+            for quantity in first_block_quantity {
+                safari.push(quantity);
+            }
+
+            for ty in second_block_type {
+                safari.push(ty);
+            }
+
+            for quantity in second_block_quantity {
+                safari.push(quantity);
+            }
+
+            if !waterflag {
+                break 'enc;
+            }
+        }
+
+        if !waterflag {
+            safari.extend([0; 624]);
+        }
+    }
+    safari
+}
@@ -0,0 +1,4 @@
+pub mod dp;
+pub mod hgss;
+mod pack;
+pub mod pt;
@@ -0,0 +1,52 @@
+use crate::gen4::pack::pack_encounter_dppt;
+use crate::narc::Narc;
+use crate::rom::MapHeaders;
+use no_std_io::{StreamContainer, StreamReader};
+
+/// Map headers in the ARM9 binary
+pub(crate) const MAP_HEADERS: MapHeaders = MapHeaders {
+    count: 593,
+    size: 24,
+    music: 10,
+    encounter_id,
+};
+
+fn encounter_id(map_header: &[u8]) -> Option<u16> {
+    let encounter_id = (map_header[14] as u16) | ((map_header[15] as u16) << 8);
+    (encounter_id != u16::MAX).then_some(encounter_id)
+}
+
+/// Returns the encounter table of every map with wild encounters
+fn map_encounters(map_headers: &[u8]) -> Vec<u16> {
+    let mut encounters = vec![];
+    let mut reader = StreamContainer::new(map_headers);
+    for _ in 0..MAP_HEADERS.count {
+        let map_header = reader.read_byte_stream(MAP_HEADERS.size).unwrap();
+        let Some(encounter_id) = encounter_id(&map_header) else {
+            continue;
+        };
+        if [
+            14, 126, 127, 128, 129, 130, 131, 133, 64, 65, 66, 67, 68, 70, 71, 72, 73, 74, 76, 77,
+            78, 79, 80, 31, 33, 35, 36, 37, 38, 39, 44, 45, 46,
+        ]
+        .contains(&encounter_id)
+        {
+            continue;
+        }
+
+        encounters.push(encounter_id);
+    }
+    encounters
+}
+
+/// Packs the wild encounters of every map from a Platinum encounter NARC
+pub(crate) fn pack(encounters: &Narc, map_headers: &[u8]) -> Vec<u8> {
+    let mut data = vec![];
+    for encounter_id in map_encounters(map_headers) {
+        data.push(encounter_id as u8);
+        data.extend(pack_encounter_dppt(
+            &encounters.elements[encounter_id as usize],
+        ));
+    }
+    data
+}
@@ -0,0 +1,56 @@
+use crate::gen5::pack::pack_encounter_gen5;
+use crate::narc::Narc;
+use crate::rom::MapHeaders;
+use no_std_io::{StreamContainer, StreamReader};
+
+/// Map headers in the zone data NARC
+pub(crate) const MAP_HEADERS: MapHeaders = MapHeaders {
+    count: 427,
+    size: 48,
+    music: 12,
+    encounter_id,
+};
+
+/// NARC holding the text banks and the index of the map names in it
+pub const MAP_NAMES: (&str, usize) = ("a/0/0/2", 89);
+
+fn encounter_id(map_header: &[u8]) -> Option<u16> {
+    let encounter_id = (map_header[20] as u16) | ((map_header[21] as u16) << 8);
+    (encounter_id != u16::MAX).then_some(encounter_id)
+}
+
+/// Returns the encounter table and location number of every map with wild encounters
+pub fn map_encounters(map_headers: &[u8]) -> Vec<(u16, u16)> {
+    let mut encounters = vec![];
+    let mut reader = StreamContainer::new(map_headers);
+    for _ in 0..MAP_HEADERS.count {
+        let map_header = reader.read_byte_stream(MAP_HEADERS.size).unwrap();
+        let encounter_id = (map_header[20] as u16) | ((map_header[21] as u16) << 8);
+        if [
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 32, 31, 12, 13, 14, 33, 34, 35,
+            36, 37, 10, 38, 39,
+        ]
+        .contains(&encounter_id)
+        {
+            continue;
+        }
+
+        if encounter_id != u16::MAX {
+            let location_number = map_header[26] as u16;
+            encounters.push((encounter_id, location_number));
+        }
+    }
+    encounters
+}
+
+/// Packs the wild encounters of every map from a Black and White encounter NARC
+pub(crate) fn pack(encounters: &Narc, map_headers: &[u8]) -> Vec<u8> {
+    let mut data = vec![];
+    for (encounter_id, _) in map_encounters(map_headers) {
+        data.push(encounter_id as u8);
+        data.extend(pack_encounter_gen5(
+            &encounters.elements[encounter_id as usize],
+        ));
+    }
+    data
+}
@@ -0,0 +1,132 @@
+use crate::gen5::pack::pack_encounter_gen5;
+use crate::narc::Narc;
+use crate::rom::MapHeaders;
+use no_std_io::{Cursor, StreamContainer, StreamReader};
+
+/// Map headers in the zone data NARC
+pub(crate) const MAP_HEADERS: MapHeaders = MapHeaders {
+    count: 615,
+    size: 48,
+    music: 12,
+    encounter_id,
+};
+
+/// NARC holding the text banks and the index of the map names in it
+pub const MAP_NAMES: (&str, usize) = ("a/0/0/2", 109);
+
+/// NARC with the Hidden Grotto encounters
+pub(crate) const HIDDEN_GROTTO_PATH: &str = "a/2/7/3";
+
+fn encounter_id(map_header: &[u8]) -> Option<u16> {
+    (map_header[20] != u8::MAX).then_some(map_header[20] as u16)
+}
+
+/// Returns the encounter table and location number of every map with wild encounters
+pub fn map_encounters(map_headers: &[u8]) -> Vec<(u8, u8)> {
+    let mut encounters = vec![];
+    let mut reader = StreamContainer::new(map_headers);
+    for _ in 0..MAP_HEADERS.count {
+        let map_header = reader.read_byte_stream(MAP_HEADERS.size).unwrap();
+        let encounter_id = map_header[20];
+        if [
+            84, 86, 44, 35, 105, 15, 16, 17, 19, 51, 53, 54, 55, 57, 59, 60, 56, 58, 78, 79, 88,
+            89, 62, 63, 64, 66, 67, 68, 69, 70, 38, 39, 40, 41,
+        ]
+        .contains(&encounter_id)
+        {
+            continue;
+        }
+
+        if encounter_id != u8::MAX {
+            let location_number = map_header[26];
+            encounters.push((encounter_id, location_number));
+        }
+    }
+    encounters
+}
+
+/// Packs the wild encounters of every map from a Black 2 and White 2 encounter NARC
+pub(crate) fn pack(encounters: &Narc, map_headers: &[u8]) -> Vec<u8> {
+    let mut data = vec![];
+    for (encounter_id, _) in map_encounters(map_headers) {
+        data.push(encounter_id);
+        data.extend(pack_encounter_gen5(
+            &encounters.elements[encounter_id as usize],
+        ));
+    }
+    data
+}
+
+/// Packs the Hidden Grotto encounters from the Hidden Grotto NARC
+pub(crate) fn pack_hidden_grotto(grotto_encount: &Narc) -> Vec<u8> {
+    let locations = [
+        45, 106, 126, 107, 135, 111, 121, 136, 118, 34, 130, 131, 123, 137, 9, 8, 101, 138, 100,
+        127,
+    ];
+
+    let mut bw = vec![];
+
+    for (encounter, location) in grotto_encount.elements.iter().zip(locations.into_iter()) {
+        let mut reader = StreamContainer::new(encounter.as_slice());
+        bw.push(location);
+        bw.push(0u8);
+
+        let mut species = [0; 12];
+        let mut max_level = [0; 12];
+        let mut min_level = [0; 12];
+        let mut gender = [0; 12];
+        let mut item = [0; 16];
+        let mut hidden_item = [0; 16];
+
+        for i in 0..3 {
+            for j in 0..4 {
+                species[i + j * 3] = reader.read_stream_le::<u16>().unwrap();
+            }
+
+            for j in 0..4 {
+                max_level[i + j * 3] = reader.read_stream_le::<u8>().unwrap();
+            }
+
+            for j in 0..4 {
+                min_level[i + j * 3] = reader.read_stream_le::<u8>().unwrap();
+            }
+
+            for j in 0..4 {
+                gender[i + j * 3] = reader.read_stream_le::<u8>().unwrap();
+            }
+
+            let _form = reader.read_stream_le::<u32>().unwrap();
+            let _padding = reader.read_stream_le::<u16>().unwrap();
+        }
+
+        reader.set_index(0x9c);
+        for i in 0..4 {
+            for j in 0..4 {
+                item[i + j * 4] = reader.read_stream_le::<u16>().unwrap();
+            }
+        }
+
+        for i in 0..4 {
+            for j in 0..4 {
+                hidden_item[i + j * 4] = reader.read_stream_le::<u16>().unwrap();
+            }
+        }
+
+        for i in 0..12 {
+            bw.extend(species[i].to_le_bytes());
+            bw.push(max_level[i]);
+            bw.push(min_level[i]);
+            bw.push(gender[i]);
+            bw.push(0);
+        }
+
+        for item in item {
+            bw.extend(item.to_le_bytes());
+        }
+
+        for item in hidden_item {
+            bw.extend(item.to_le_bytes());
+        }
+    }
+    bw
+}
@@ -0,0 +1,3 @@
+pub mod bw;
+pub mod bw2;
+mod pack;
@@ -0,0 +1,7 @@
+//! Reads Gen 4 and Gen 5 `.nds` ROM dumps and packs their encounter tables into the format used by
+//! the encounter resources of `pokefinder_rs_core`
+pub mod gen4;
+pub mod gen5;
+pub mod narc;
+pub mod nds;
+pub mod rom;
@@ -0,0 +1,55 @@
+use no_std_io::{Cursor, StreamContainer, StreamReader};
+use std::io::{Error, ErrorKind, Result};
+
+#[derive(Clone, Default)]
+pub struct Narc {
+    pub fat_b_offset: usize,
+    pub fnt_b_offset: usize,
+    pub fimg_offset: usize,
+    pub elements: Vec<Vec<u8>>,
+}
+
+impl Narc {
+    /// Parses a NARC archive, returns an error when it is truncated
+    pub fn read(mut reader: StreamContainer<&[u8]>) -> Result<Self> {
+        let truncated = |_| Error::new(ErrorKind::InvalidData, "NARC is truncated");
+        let fat_b_offset = 0x10;
+
+        reader.set_index(0x18);
+        let fnt_b_offset =
+            reader.read_stream_le::<u32>().map_err(truncated)? as usize * 8 + fat_b_offset + 12;
+
+        reader.set_index(fnt_b_offset + 4);
+        let fimg_offset =
+            reader.read_stream_le::<u32>().map_err(truncated)? as usize + fnt_b_offset;
+
+        reader.set_index(0x18);
+        let number_of_elements = reader.read_stream_le::<u32>().map_err(truncated)?;
+
+        let mut elements = vec![];
+        let mut start_offsets = vec![];
+        let mut end_offsets = vec![];
+
+        reader.set_index(fat_b_offset + 0xC);
+
+        for _ in 0..number_of_elements {
+            start_offsets.push(reader.read_stream_le::<u32>().map_err(truncated)?);
+            end_offsets.push(reader.read_stream_le::<u32>().map_err(truncated)?);
+        }
+
+        for i in 0..number_of_elements as usize {
+            let size = end_offsets[i]
+                .checked_sub(start_offsets[i])
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "NARC element is malformed"))?;
+            reader.set_index(fimg_offset + start_offsets[i] as usize + 8);
+            elements.push(reader.read_byte_stream(size as usize).map_err(truncated)?);
+        }
+
+        Ok(Self {
+            fat_b_offset,
+            fnt_b_offset,
+            fimg_offset,
+            elements,
+        })
+    }
+}
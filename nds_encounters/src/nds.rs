@@ -0,0 +1,203 @@
+use crate::narc::Narc;
+use no_std_io::{Cursor, StreamContainer, StreamReader};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Result};
+use std::ops::Range;
+use std::path::Path;
+
+/// Directory ids in the file name table start at this value, ids below it are files
+const DIRECTORY_ID: u16 = 0xF000;
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// Nintendo DS ROM with its filesystem
+pub struct Nds {
+    data: Vec<u8>,
+    /// Location of the ARM9 binary in the ROM
+    arm9: Range<usize>,
+    /// Start and end offset of each file in the ROM
+    fat: Vec<(u32, u32)>,
+    /// Full path of each file mapped to its file id
+    files: HashMap<String, u16>,
+}
+
+impl Nds {
+    /// Parses the header, file name table (FNT) and file allocation table (FAT) of a `.nds` dump.
+    ///
+    /// Returns an error when the tables do not fit inside the ROM or the FNT is malformed.
+    pub fn new(data: Vec<u8>) -> Result<Self> {
+        let mut reader = StreamContainer::new(data.as_slice());
+        let truncated = |_| invalid("ROM is truncated");
+
+        reader.set_index(0x20);
+        let arm9_offset = reader.read_stream_le::<u32>().map_err(truncated)? as usize;
+        reader.set_index(0x2C);
+        let arm9_size = reader.read_stream_le::<u32>().map_err(truncated)? as usize;
+        let arm9 = arm9_offset..arm9_offset + arm9_size;
+        if arm9.end > data.len() {
+            return Err(invalid("ARM9 binary is outside of the ROM"));
+        }
+
+        reader.set_index(0x40);
+        let fnt_offset = reader.read_stream_le::<u32>().map_err(truncated)? as usize;
+        let _fnt_size = reader.read_stream_le::<u32>().map_err(truncated)?;
+        let fat_offset = reader.read_stream_le::<u32>().map_err(truncated)?;
+        let fat_size = reader.read_stream_le::<u32>().map_err(truncated)?;
+
+        reader.set_index(fat_offset as usize);
+        let mut fat = vec![];
+        for _ in 0..fat_size / 8 {
+            let start = reader.read_stream_le::<u32>().map_err(truncated)?;
+            let end = reader.read_stream_le::<u32>().map_err(truncated)?;
+            if start > end || end as usize > data.len() {
+                return Err(invalid("file is outside of the ROM"));
+            }
+            fat.push((start, end));
+        }
+
+        // The root directory stores the total number of directories in place of its parent
+        reader.set_index(fnt_offset + 6);
+        let directory_count = reader.read_stream_le::<u16>().map_err(truncated)? as usize;
+
+        let mut files = HashMap::new();
+        let mut visited = vec![false; directory_count];
+        let mut directories = vec![(DIRECTORY_ID, String::new())];
+        while let Some((id, path)) = directories.pop() {
+            // Directories are resolved by their id, a second visit means the FNT has a cycle
+            let index = (id - DIRECTORY_ID) as usize;
+            match visited.get_mut(index) {
+                Some(visited) if !*visited => *visited = true,
+                Some(_) => return Err(invalid("directory is listed more than once")),
+                None => return Err(invalid("directory id is out of range")),
+            }
+
+            reader.set_index(fnt_offset + index * 8);
+            let entries_offset = reader.read_stream_le::<u32>().map_err(truncated)? as usize;
+            let mut file_id = Some(reader.read_stream_le::<u16>().map_err(truncated)?);
+
+            reader.set_index(fnt_offset + entries_offset);
+            loop {
+                let ty = reader.read_stream_le::<u8>().map_err(truncated)?;
+                if ty == 0 {
+                    break;
+                }
+
+                let name = reader
+                    .read_byte_stream((ty & 0x7F) as usize)
+                    .map_err(truncated)?;
+                let name = format!("{path}{}", String::from_utf8_lossy(&name));
+                if ty & 0x80 != 0 {
+                    let id = reader.read_stream_le::<u16>().map_err(truncated)?;
+                    if id < DIRECTORY_ID {
+                        return Err(invalid("directory id is out of range"));
+                    }
+                    directories.push((id, name + "/"));
+                } else {
+                    // Files of a directory have consecutive ids
+                    let id = file_id.ok_or_else(|| invalid("file id overflows"))?;
+                    files.insert(name, id);
+                    file_id = id.checked_add(1);
+                }
+            }
+        }
+
+        Ok(Self {
+            data,
+            arm9,
+            fat,
+            files,
+        })
+    }
+
+    /// Reads and parses the ROM at `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new(std::fs::read(path)?)
+    }
+
+    /// Returns the four character game code, e.g. `ADAE` for English Diamond
+    pub fn game_code(&self) -> String {
+        String::from_utf8_lossy(&self.data[0xC..0x10]).into_owned()
+    }
+
+    /// Returns the ARM9 binary as it is stored in the ROM, see [`blz_decompress`]
+    pub fn arm9(&self) -> &[u8] {
+        &self.data[self.arm9.clone()]
+    }
+
+    /// Returns the contents of the file at `path`, such as `fielddata/encountdata/d_enc_data.narc`
+    pub fn get_file(&self, path: &str) -> Option<&[u8]> {
+        let id = *self.files.get(path.trim_start_matches('/'))?;
+        let &(start, end) = self.fat.get(id as usize)?;
+        Some(&self.data[start as usize..end as usize])
+    }
+
+    /// Returns the contents of the file at `path`, or a `NotFound` error when it does not exist
+    pub(crate) fn read_file(&self, path: &str) -> Result<&[u8]> {
+        self.get_file(path)
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, format!("{path} does not exist")))
+    }
+
+    /// Returns the NARC archive at `path`
+    pub fn get_narc(&self, path: &str) -> Result<Narc> {
+        Narc::read(StreamContainer::new(self.read_file(path)?))
+    }
+}
+
+/// Decompresses a binary compressed with the backwards LZ scheme used for ARM9 binaries.
+///
+/// Returns `None` when `data` does not end with a valid footer, which is the case for binaries
+/// that are not compressed.
+pub fn blz_decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let footer = data.len().checked_sub(8)?;
+    let read_u32 = |index: usize| u32::from_le_bytes(data[index..index + 4].try_into().unwrap());
+
+    let inc_len = read_u32(footer + 4) as usize;
+    let header_len = data[footer + 3] as usize;
+    let enc_len = (read_u32(footer) & 0xFFFFFF) as usize;
+    if inc_len == 0 || header_len < 8 || enc_len < header_len || enc_len > data.len() {
+        return None;
+    }
+
+    // Everything before the compressed block is stored as is, the block is read from its end
+    let start = data.len() - enc_len;
+    let mut compressed = data[start..data.len() - header_len].iter().rev().copied();
+    let mut decompressed = data[..start].to_vec();
+    let block_len = enc_len + inc_len;
+
+    let mut block = Vec::with_capacity(block_len);
+    'outer: while block.len() < block_len {
+        let Some(flags) = compressed.next() else {
+            break;
+        };
+        for bit in (0..8).rev() {
+            if block.len() == block_len {
+                break 'outer;
+            }
+
+            if flags & (1 << bit) == 0 {
+                let Some(byte) = compressed.next() else {
+                    break 'outer;
+                };
+                block.push(byte);
+            } else {
+                let pos = ((compressed.next()? as usize) << 8) | compressed.next()? as usize;
+                let distance = (pos & 0xFFF) + 3;
+                let count = ((pos >> 12) + 3).min(block_len - block.len());
+                if distance > block.len() {
+                    return None;
+                }
+                for _ in 0..count {
+                    block.push(block[block.len() - distance]);
+                }
+            }
+        }
+    }
+
+    if block.len() != block_len {
+        return None;
+    }
+    decompressed.extend(block.into_iter().rev());
+    Some(decompressed)
+}
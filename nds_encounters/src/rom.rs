@@ -0,0 +1,226 @@
+use crate::narc::Narc;
+use crate::nds::{blz_decompress, Nds};
+use crate::{gen4, gen5};
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind, Result};
+
+/// Every encounter resource that is packed from a ROM, see [`pack_resources`]
+pub const RESOURCES: [&str; 14] = [
+    "diamond.bin",
+    "pearl.bin",
+    "platinum.bin",
+    "heartgold.bin",
+    "soulsilver.bin",
+    "hgss_bug.bin",
+    "hg_headbutt.bin",
+    "ss_headbutt.bin",
+    "hgss_safari.bin",
+    "black.bin",
+    "white.bin",
+    "black2.bin",
+    "white2.bin",
+    "bw2_grotto.bin",
+];
+
+/// Every map plays music, the sound sequences used for it start at this id
+const FIRST_MUSIC: u16 = 1000;
+
+/// Layout of the map header table of a game
+pub(crate) struct MapHeaders {
+    /// Number of map headers in the table
+    pub count: usize,
+    /// Size of a single map header
+    pub size: usize,
+    /// Offset of the music played during the day
+    pub music: usize,
+    /// Returns the encounter table of a map header, `None` for maps without wild encounters
+    pub encounter_id: fn(&[u8]) -> Option<u16>,
+}
+
+/// Where a game stores its map header table
+enum MapHeaderSource {
+    /// Inside the ARM9 binary
+    Arm9,
+    /// Inside the NARC at the given path
+    Narc(&'static str),
+}
+
+/// How a family of games stores its wild encounters
+struct Family {
+    /// Sizes a wild encounter table can have
+    encounter_sizes: &'static [usize],
+    map_header_source: MapHeaderSource,
+    map_headers: MapHeaders,
+    pack: fn(&Narc, &[u8]) -> Vec<u8>,
+}
+
+const DP: Family = Family {
+    encounter_sizes: &[424],
+    map_header_source: MapHeaderSource::Arm9,
+    map_headers: gen4::dp::MAP_HEADERS,
+    pack: gen4::dp::pack,
+};
+
+const PT: Family = Family {
+    encounter_sizes: &[424],
+    map_header_source: MapHeaderSource::Arm9,
+    map_headers: gen4::pt::MAP_HEADERS,
+    pack: gen4::pt::pack,
+};
+
+const HGSS: Family = Family {
+    encounter_sizes: &[196],
+    map_header_source: MapHeaderSource::Arm9,
+    map_headers: gen4::hgss::MAP_HEADERS,
+    pack: gen4::hgss::pack,
+};
+
+const BW: Family = Family {
+    encounter_sizes: &[232, 928],
+    map_header_source: MapHeaderSource::Narc("a/0/1/2"),
+    map_headers: gen5::bw::MAP_HEADERS,
+    pack: gen5::bw::pack,
+};
+
+const BW2: Family = Family {
+    encounter_sizes: &[232, 928],
+    map_header_source: MapHeaderSource::Narc("a/0/1/2"),
+    map_headers: gen5::bw2::MAP_HEADERS,
+    pack: gen5::bw2::pack,
+};
+
+/// Returns the resource file name, wild encounter NARC path and family for a game code
+fn layout(game_code: &str) -> Option<(&'static str, &'static str, Family)> {
+    let layout = match game_code.get(..3)? {
+        "ADA" => ("diamond.bin", "fielddata/encountdata/d_enc_data.narc", DP),
+        "APA" => ("pearl.bin", "fielddata/encountdata/p_enc_data.narc", DP),
+        "CPU" => ("platinum.bin", "fielddata/encountdata/pl_enc_data.narc", PT),
+        "IPK" => ("heartgold.bin", "a/0/3/7", HGSS),
+        "IPG" => ("soulsilver.bin", "a/1/3/6", HGSS),
+        "IRB" => ("black.bin", "a/1/2/6", BW),
+        "IRA" => ("white.bin", "a/1/2/6", BW),
+        "IRE" => ("black2.bin", "a/1/2/7", BW2),
+        "IRD" => ("white2.bin", "a/1/2/7", BW2),
+        _ => return None,
+    };
+    Some(layout)
+}
+
+fn unsupported(rom: &Nds) -> Error {
+    Error::new(
+        ErrorKind::Unsupported,
+        format!("{} is not a Gen 4 or Gen 5 game", rom.game_code()),
+    )
+}
+
+/// Searches `data` for the map header table described by `map_headers`.
+///
+/// The position of the table differs between languages and hacks, so every 4 byte aligned offset
+/// is tried. A table is only accepted when every map plays music and points to an encounter table
+/// of the expected size, and the maps use at least half of the encounter tables.
+pub(crate) fn find_map_headers<'a>(
+    data: &'a [u8],
+    map_headers: &MapHeaders,
+    encounters: &Narc,
+    encounter_sizes: &[usize],
+) -> Option<&'a [u8]> {
+    let table_size = map_headers.count * map_headers.size;
+    let last = data.len().checked_sub(table_size)?;
+
+    (0..=last).step_by(4).find_map(|offset| {
+        let table = &data[offset..offset + table_size];
+        let mut used = HashSet::new();
+        for map_header in table.chunks_exact(map_headers.size) {
+            let music = &map_header[map_headers.music..map_headers.music + 2];
+            if u16::from_le_bytes([music[0], music[1]]) < FIRST_MUSIC {
+                return None;
+            }
+            if let Some(encounter_id) = (map_headers.encounter_id)(map_header) {
+                let encounter = encounters.elements.get(encounter_id as usize)?;
+                if !encounter_sizes.contains(&encounter.len()) {
+                    return None;
+                }
+                used.insert(encounter_id);
+            }
+        }
+        (used.len() * 2 >= encounters.elements.len()).then_some(table)
+    })
+}
+
+/// Returns the map header table of a Gen 4 or Gen 5 ROM along with its wild encounter NARC
+fn read_tables(rom: &Nds, path: &str, family: &Family) -> Result<(Vec<u8>, Narc)> {
+    let encounters = rom.get_narc(path)?;
+    let find = |data: &[u8]| {
+        find_map_headers(
+            data,
+            &family.map_headers,
+            &encounters,
+            family.encounter_sizes,
+        )
+        .map(<[u8]>::to_vec)
+    };
+
+    let map_headers = match family.map_header_source {
+        MapHeaderSource::Arm9 => {
+            find(rom.arm9()).or_else(|| blz_decompress(rom.arm9()).and_then(|arm9| find(&arm9)))
+        }
+        MapHeaderSource::Narc(path) => find(&rom.get_narc(path)?.elements.concat()),
+    }
+    .ok_or_else(|| Error::new(ErrorKind::InvalidData, "map headers could not be found"))?;
+
+    Ok((map_headers, encounters))
+}
+
+/// Returns the map header table of a Gen 4 or Gen 5 ROM
+pub fn map_headers(rom: &Nds) -> Result<Vec<u8>> {
+    let (_, path, family) = layout(&rom.game_code()).ok_or_else(|| unsupported(rom))?;
+    read_tables(rom, path, &family).map(|(map_headers, _)| map_headers)
+}
+
+/// Packs the wild encounters of a Gen 4 or Gen 5 ROM.
+///
+/// Returns the name of the resource file the data replaces along with the uncompressed data. Both
+/// the encounter tables and the map headers they belong to are read from the ROM, so translated
+/// and hacked ROMs work as long as they keep the layout of the original game.
+pub fn pack_encounters(rom: &Nds) -> Result<(&'static str, Vec<u8>)> {
+    let (file_name, path, family) = layout(&rom.game_code()).ok_or_else(|| unsupported(rom))?;
+    let (map_headers, encounters) = read_tables(rom, path, &family)?;
+    Ok((file_name, (family.pack)(&encounters, &map_headers)))
+}
+
+/// Packs every encounter resource of a Gen 4 or Gen 5 ROM.
+///
+/// Besides the wild encounters of [`pack_encounters`], HeartGold and SoulSilver ROMs provide the
+/// Bug Catching Contest, headbutt and Safari Zone encounters, and Black 2 and White 2 ROMs the
+/// Hidden Grotto encounters. Returns the name of each resource file along with its uncompressed
+/// data.
+pub fn pack_resources(rom: &Nds) -> Result<Vec<(&'static str, Vec<u8>)>> {
+    let mut resources = vec![pack_encounters(rom)?];
+    match rom.game_code().get(..3) {
+        Some(code @ ("IPK" | "IPG")) => {
+            let headbutt = if code == "IPK" {
+                "hg_headbutt.bin"
+            } else {
+                "ss_headbutt.bin"
+            };
+            resources.push((
+                "hgss_bug.bin",
+                gen4::hgss::pack_bug(rom.read_file(gen4::hgss::BUG_PATH)?),
+            ));
+            resources.push((
+                headbutt,
+                gen4::hgss::pack_headbutt(&rom.get_narc(gen4::hgss::HEADBUTT_PATH)?),
+            ));
+            resources.push((
+                "hgss_safari.bin",
+                gen4::hgss::pack_safari(&rom.get_narc(gen4::hgss::SAFARI_PATH)?),
+            ));
+        }
+        Some("IRE" | "IRD") => resources.push((
+            "bw2_grotto.bin",
+            gen5::bw2::pack_hidden_grotto(&rom.get_narc(gen5::bw2::HIDDEN_GROTTO_PATH)?),
+        )),
+        _ => {}
+    }
+    Ok(resources)
+}
@@ -0,0 +1,347 @@
+use nds_encounters::nds::{blz_decompress, Nds};
+use nds_encounters::rom;
+use std::io::ErrorKind;
+
+const HEADER_SIZE: usize = 0x200;
+
+/// Entry of a directory in the file name table
+enum Entry {
+    File(&'static str),
+    Directory(&'static str, u16),
+}
+
+/// Builds the file name table from directories listed by id, each with the id of its first file
+fn fnt(directories: &[(u16, Vec<Entry>)]) -> Vec<u8> {
+    let mut entries = vec![];
+    let mut offsets = vec![];
+    for (_, directory) in directories {
+        offsets.push(directories.len() * 8 + entries.len());
+        for entry in directory {
+            match entry {
+                Entry::File(name) => {
+                    entries.push(name.len() as u8);
+                    entries.extend(name.as_bytes());
+                }
+                Entry::Directory(name, id) => {
+                    entries.push(name.len() as u8 | 0x80);
+                    entries.extend(name.as_bytes());
+                    entries.extend(id.to_le_bytes());
+                }
+            }
+        }
+        entries.push(0);
+    }
+
+    let mut fnt = vec![];
+    for (i, (first_file, _)) in directories.iter().enumerate() {
+        fnt.extend((offsets[i] as u32).to_le_bytes());
+        fnt.extend(first_file.to_le_bytes());
+        // The root stores the number of directories, the parent is not needed to resolve paths
+        let parent = if i == 0 {
+            directories.len() as u16
+        } else {
+            0xF000
+        };
+        fnt.extend(parent.to_le_bytes());
+    }
+    fnt.extend(entries);
+    fnt
+}
+
+/// Builds a ROM with the given game code, ARM9 binary, file name table and files
+fn nds(game_code: &str, arm9: &[u8], fnt: &[u8], files: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = vec![0; HEADER_SIZE];
+    data[0xC..0x10].copy_from_slice(game_code.as_bytes());
+
+    let arm9_offset = data.len();
+    data.extend(arm9);
+    let fnt_offset = data.len();
+    data.extend(fnt);
+    let fat_offset = data.len();
+    let mut start = fat_offset + files.len() * 8;
+    for file in files {
+        data.extend((start as u32).to_le_bytes());
+        data.extend(((start + file.len()) as u32).to_le_bytes());
+        start += file.len();
+    }
+    for file in files {
+        data.extend(file);
+    }
+
+    for (offset, value) in [
+        (0x20, arm9_offset),
+        (0x2C, arm9.len()),
+        (0x40, fnt_offset),
+        (0x44, fnt.len()),
+        (0x48, fat_offset),
+        (0x4C, files.len() * 8),
+    ] {
+        data[offset..offset + 4].copy_from_slice(&(value as u32).to_le_bytes());
+    }
+    data
+}
+
+/// Builds a NARC archive with the given elements
+fn narc(elements: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = b"NARC".to_vec();
+    data.extend([0xFE, 0xFF, 0x00, 0x01, 0, 0, 0, 0, 0x10, 0x00, 0x03, 0x00]);
+
+    data.extend(b"BTAF");
+    data.extend(((12 + elements.len() * 8) as u32).to_le_bytes());
+    data.extend((elements.len() as u32).to_le_bytes());
+    let mut start = 0;
+    for element in elements {
+        data.extend((start as u32).to_le_bytes());
+        data.extend(((start + element.len()) as u32).to_le_bytes());
+        start += element.len();
+    }
+
+    data.extend(b"BTNF");
+    data.extend(16u32.to_le_bytes());
+    data.extend(4u32.to_le_bytes());
+    data.extend(0xF000_0001u32.to_le_bytes());
+
+    data.extend(b"GMIF");
+    data.extend(((8 + start) as u32).to_le_bytes());
+    for element in elements {
+        data.extend(element);
+    }
+    data
+}
+
+#[test]
+fn file_name_table() {
+    // Directory 2 comes before directory 1 in the tree, so both have to be resolved by their id
+    let fnt = fnt(&[
+        (
+            0,
+            vec![Entry::File("root.bin"), Entry::Directory("b", 0xF002)],
+        ),
+        (2, vec![Entry::File("z.bin"), Entry::File("zz.bin")]),
+        (1, vec![Entry::File("y.bin"), Entry::Directory("c", 0xF001)]),
+    ]);
+    let files = (0..4).map(|i| vec![i; i as usize + 1]).collect::<Vec<_>>();
+    let rom = Nds::new(nds("ADAE", &[1, 2, 3, 4], &fnt, &files)).unwrap();
+
+    assert_eq!(rom.game_code(), "ADAE");
+    assert_eq!(rom.arm9(), [1, 2, 3, 4]);
+    assert_eq!(rom.get_file("root.bin"), Some(files[0].as_slice()));
+    assert_eq!(rom.get_file("/b/y.bin"), Some(files[1].as_slice()));
+    assert_eq!(rom.get_file("b/c/z.bin"), Some(files[2].as_slice()));
+    assert_eq!(rom.get_file("b/c/zz.bin"), Some(files[3].as_slice()));
+    assert_eq!(rom.get_file("c/z.bin"), None);
+}
+
+#[test]
+fn malformed_file_name_table() {
+    let error = |directories: &[(u16, Vec<Entry>)]| {
+        Nds::new(nds("ADAE", &[], &fnt(directories), &[]))
+            .err()
+            .unwrap()
+            .kind()
+    };
+
+    // Directory that contains its parent
+    assert_eq!(
+        error(&[
+            (0, vec![Entry::Directory("a", 0xF001)]),
+            (0, vec![Entry::Directory("b", 0xF000)]),
+        ]),
+        ErrorKind::InvalidData
+    );
+    // Directory id past the number of directories
+    assert_eq!(
+        error(&[(0, vec![Entry::Directory("a", 0xF001)])]),
+        ErrorKind::InvalidData
+    );
+    // File ids past the last possible id
+    assert_eq!(
+        error(&[(0xFFFF, vec![Entry::File("a"), Entry::File("b")])]),
+        ErrorKind::InvalidData
+    );
+    // The last possible file id can still be used
+    assert!(Nds::new(nds(
+        "ADAE",
+        &[],
+        &fnt(&[(0xFFFF, vec![Entry::File("a")])]),
+        &[]
+    ))
+    .is_ok());
+    // Header without any tables
+    assert_eq!(
+        Nds::new(vec![0; 0x10]).err().unwrap().kind(),
+        ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn blz() {
+    // 3 literals followed by 5 copies of 18 bytes, 3 bytes back
+    let stream = [
+        0x1F, 1, 2, 3, 0xF0, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0xF0, 0x00, 0xF0, 0x00,
+    ];
+    let decompressed_len = 3 + 5 * 18;
+    let enc_len = stream.len() + 8;
+
+    let mut data = vec![0xAA; 4];
+    data.extend(stream.iter().rev());
+    data.extend((enc_len as u32 | (8 << 24)).to_le_bytes());
+    data.extend(((decompressed_len - enc_len) as u32).to_le_bytes());
+
+    // The block is decompressed from its end
+    let mut expected = vec![0xAA; 4];
+    expected.extend((0..decompressed_len).map(|i| [1, 2, 3][i % 3]).rev());
+    assert_eq!(blz_decompress(&data), Some(expected));
+
+    // Binaries that are not compressed end without a footer
+    assert_eq!(blz_decompress(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0]), None);
+}
+
+/// Builds a Diamond ROM where map `i` uses encounter table `i` and the other maps have none
+fn diamond() -> Vec<u8> {
+    let encounters = (0..183u32)
+        .map(|i| {
+            let mut encounter = vec![0; 424];
+            encounter[..4].copy_from_slice(&(i + 1).to_le_bytes());
+            encounter
+        })
+        .collect::<Vec<_>>();
+
+    // The map header table sits after some code in the ARM9 binary
+    let mut arm9 = vec![0; 0x100];
+    for i in 0..559u16 {
+        let mut map_header = [0; 24];
+        map_header[10..12].copy_from_slice(&1000u16.to_le_bytes());
+        let encounter_id = if i < 183 { i } else { u16::MAX };
+        map_header[14..16].copy_from_slice(&encounter_id.to_le_bytes());
+        arm9.extend(map_header);
+    }
+
+    let fnt = fnt(&[
+        (0, vec![Entry::Directory("fielddata", 0xF001)]),
+        (0, vec![Entry::Directory("encountdata", 0xF002)]),
+        (0, vec![Entry::File("d_enc_data.narc")]),
+    ]);
+    nds("ADAE", &arm9, &fnt, &[narc(&encounters)])
+}
+
+#[test]
+fn pack_encounters() {
+    let rom = Nds::new(diamond()).unwrap();
+
+    let map_headers = rom::map_headers(&rom).unwrap();
+    assert_eq!(map_headers.len(), 559 * 24);
+    assert_eq!(map_headers, rom.arm9()[0x100..]);
+
+    let (file_name, data) = rom::pack_encounters(&rom).unwrap();
+    assert_eq!(file_name, "diamond.bin");
+    // 33 of the encounter tables are not packed as they belong to special maps
+    assert_eq!(data.len() % 150, 0);
+    let size = data.len() / 150;
+    for (i, encounter_id) in [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 15]
+        .into_iter()
+        .enumerate()
+    {
+        assert_eq!(data[i * size], encounter_id);
+        assert_eq!(data[i * size + 1], encounter_id + 1);
+    }
+}
+
+#[test]
+fn pack_encounters_errors() {
+    let mut data = diamond();
+    data[0xC..0x10].copy_from_slice(b"AAAE");
+    let rom = Nds::new(data).unwrap();
+    assert_eq!(
+        rom::pack_encounters(&rom).err().unwrap().kind(),
+        ErrorKind::Unsupported
+    );
+
+    // Without music the map headers can not be told apart from other data
+    let mut data = diamond();
+    for i in 0..559 {
+        let offset = HEADER_SIZE + 0x100 + i * 24 + 10;
+        data[offset..offset + 2].copy_from_slice(&[0, 0]);
+    }
+    let rom = Nds::new(data).unwrap();
+    assert_eq!(
+        rom::pack_encounters(&rom).err().unwrap().kind(),
+        ErrorKind::InvalidData
+    );
+}
+
+/// Builds a Black 2 ROM where map `i` uses encounter table `i`, along with its Hidden Grottos
+fn black2(grottos: bool) -> Vec<u8> {
+    let encounters = (0..100).map(|_| vec![0; 232]).collect::<Vec<_>>();
+
+    let mut zones = vec![];
+    for i in 0..615u16 {
+        let mut map_header = [0; 48];
+        map_header[12..14].copy_from_slice(&1000u16.to_le_bytes());
+        map_header[20] = if i < 100 { i as u8 } else { u8::MAX };
+        zones.push(map_header.to_vec());
+    }
+
+    let grotto = (0..20u16)
+        .map(|i| {
+            let mut grotto = vec![0; 0xdc];
+            grotto[..2].copy_from_slice(&(i + 1).to_le_bytes());
+            grotto
+        })
+        .collect::<Vec<_>>();
+
+    let fnt = fnt(&[
+        (0, vec![Entry::Directory("a", 0xF001)]),
+        (
+            0,
+            vec![
+                Entry::Directory("0", 0xF002),
+                Entry::Directory("1", 0xF003),
+                Entry::Directory("2", 0xF004),
+            ],
+        ),
+        (0, vec![Entry::Directory("1", 0xF005)]),
+        (0, vec![Entry::Directory("2", 0xF006)]),
+        (0, vec![Entry::Directory("7", 0xF007)]),
+        (0, vec![Entry::File("2")]),
+        (1, vec![Entry::File("7")]),
+        (2, vec![Entry::File(if grottos { "3" } else { "4" })]),
+    ]);
+    nds(
+        "IREO",
+        &[],
+        &fnt,
+        &[narc(&zones), narc(&encounters), narc(&grotto)],
+    )
+}
+
+#[test]
+fn pack_resources() {
+    let rom = Nds::new(black2(true)).unwrap();
+    let resources = rom::pack_resources(&rom).unwrap();
+    assert_eq!(
+        resources.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        ["black2.bin", "bw2_grotto.bin"]
+    );
+    assert_eq!(resources[0].1, rom::pack_encounters(&rom).unwrap().1);
+
+    // Every grotto stores its location, 12 slots and 32 items
+    let grotto = &resources[1].1;
+    let size = 2 + 12 * 6 + 32 * 2;
+    assert_eq!(grotto.len(), 20 * size);
+    assert_eq!(grotto[..4], [45, 0, 1, 0]);
+    assert_eq!(grotto[size..size + 4], [106, 0, 2, 0]);
+
+    let rom = Nds::new(black2(false)).unwrap();
+    assert_eq!(
+        rom::pack_resources(&rom).err().unwrap().kind(),
+        ErrorKind::NotFound
+    );
+
+    // Diamond and Pearl only have wild encounters
+    let rom = Nds::new(diamond()).unwrap();
+    assert_eq!(
+        rom::pack_resources(&rom).unwrap(),
+        [rom::pack_encounters(&rom).unwrap()]
+    );
+}
@@ -0,0 +1,765 @@
+use clap::{Args, ValueEnum};
+use pokefinder_rs_core::enums::{
+    Buttons, DSType, Encounter, Game, Language, Lead, Method, Shiny as ShinyLock,
+};
+use pokefinder_rs_core::parents::filters::IDFilter;
+use pokefinder_rs_core::parents::{
+    personal_loader, Daycare, EncounterArea, EncounterAreaT, Profile, Slot, StaticTemplate,
+    Template,
+};
+use pokefinder_rs_core::util::{translator, Date};
+use std::path::Path;
+
+const GAMES: [(&str, Game); 28] = [
+    ("ruby", Game::RUBY),
+    ("sapphire", Game::SAPPHIRE),
+    ("emerald", Game::EMERALD),
+    ("firered", Game::FIRE_RED),
+    ("leafgreen", Game::LEAF_GREEN),
+    ("gales", Game::GALES),
+    ("colosseum", Game::COLOSSEUM),
+    ("diamond", Game::DIAMOND),
+    ("pearl", Game::PEARL),
+    ("platinum", Game::PLATINUM),
+    ("heartgold", Game::HEART_GOLD),
+    ("soulsilver", Game::SOUL_SILVER),
+    ("black", Game::BLACK),
+    ("white", Game::WHITE),
+    ("black2", Game::BLACK2),
+    ("white2", Game::WHITE2),
+    ("x", Game::X),
+    ("y", Game::Y),
+    ("or", Game::OR),
+    ("as", Game::AS),
+    ("sun", Game::SUN),
+    ("moon", Game::MOON),
+    ("us", Game::US),
+    ("um", Game::UM),
+    ("sword", Game::SWORD),
+    ("shield", Game::SHIELD),
+    ("bd", Game::BD),
+    ("sp", Game::SP),
+];
+
+const LEADS: [(&str, Lead); 12] = [
+    ("none", Lead::NONE),
+    ("cute-charm-f", Lead::CUTE_CHARM_F),
+    ("cute-charm-m", Lead::CUTE_CHARM_M),
+    ("magnet-pull", Lead::MAGNET_PULL),
+    ("static", Lead::STATIC),
+    ("harvest", Lead::HARVEST),
+    ("flash-fire", Lead::FLASH_FIRE),
+    ("storm-drain", Lead::STORM_DRAIN),
+    ("pressure", Lead::PRESSURE),
+    ("suction-cups", Lead::SUCTION_CUPS),
+    ("compound-eyes", Lead::COMPOUND_EYES),
+    ("arena-trap", Lead::ARENA_TRAP),
+];
+
+const METHODS: [(&str, Method); 18] = [
+    ("method1", Method::Method1),
+    ("method2", Method::Method2),
+    ("method4", Method::Method4),
+    ("xd-colo", Method::XDColo),
+    ("channel", Method::Channel),
+    ("method-j", Method::MethodJ),
+    ("method-k", Method::MethodK),
+    ("poke-radar", Method::PokeRadar),
+    ("method5-ivs", Method::Method5IVs),
+    ("method5-cgear", Method::Method5CGear),
+    ("method5", Method::Method5),
+    ("e-bred", Method::EBred),
+    ("e-bred-split", Method::EBredSplit),
+    ("e-bred-alternate", Method::EBredAlternate),
+    ("rsfrlg-bred", Method::RSFRLGBred),
+    ("rsfrlg-bred-split", Method::RSFRLGBredSplit),
+    ("rsfrlg-bred-alternate", Method::RSFRLGBredAlternate),
+    ("rsfrlg-bred-mixed", Method::RSFRLGBredMixed),
+];
+
+const ENCOUNTERS: [(&str, Encounter); 17] = [
+    ("grass", Encounter::Grass),
+    ("double-grass", Encounter::DoubleGrass),
+    ("special-grass", Encounter::SpecialGrass),
+    ("rock-smash", Encounter::RockSmash),
+    ("surfing", Encounter::Surfing),
+    ("special-surf", Encounter::SpecialSurf),
+    ("old-rod", Encounter::OldRod),
+    ("good-rod", Encounter::GoodRod),
+    ("super-rod", Encounter::SuperRod),
+    ("special-super-rod", Encounter::SpecialSuperRod),
+    ("bug-catching-contest", Encounter::BugCatchingContest),
+    ("headbutt", Encounter::Headbutt),
+    ("headbutt-alt", Encounter::HeadbuttAlt),
+    ("headbutt-special", Encounter::HeadbuttSpecial),
+    ("static", Encounter::Static),
+    ("roamer", Encounter::Roamer),
+    ("gift", Encounter::Gift),
+];
+
+const LANGUAGES: [(&str, Language); 8] = [
+    ("en", Language::English),
+    ("fr", Language::French),
+    ("de", Language::German),
+    ("it", Language::Italian),
+    ("ja", Language::Japanese),
+    ("ko", Language::Korean),
+    ("es", Language::Spanish),
+    ("zh", Language::Chinese),
+];
+
+const DS_TYPES: [(&str, DSType); 3] = [
+    ("ds", DSType::DS),
+    ("dsi", DSType::DSi),
+    ("3ds", DSType::DS3),
+];
+
+const BUTTONS: [(&str, Buttons); 13] = [
+    ("none", Buttons::NONE),
+    ("r", Buttons::R),
+    ("l", Buttons::L),
+    ("x", Buttons::X),
+    ("y", Buttons::Y),
+    ("a", Buttons::A),
+    ("b", Buttons::B),
+    ("select", Buttons::SELECT),
+    ("start", Buttons::START),
+    ("right", Buttons::RIGHT),
+    ("left", Buttons::LEFT),
+    ("up", Buttons::UP),
+    ("down", Buttons::DOWN),
+];
+
+fn lookup<T: Copy>(table: &[(&str, T)], value: &str, kind: &str) -> Result<T, String> {
+    table
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+        .map(|(_, item)| *item)
+        .ok_or_else(|| {
+            let names = table.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            format!(
+                "unknown {kind} '{value}', expected one of: {}",
+                names.join(", ")
+            )
+        })
+}
+
+/// Looks `value` up by index or case insensitive name in a translated list
+pub fn lookup_translated(list: &[String], value: &str, kind: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|&i| i < list.len())
+        .or_else(|| {
+            list.iter()
+                .position(|name| name.eq_ignore_ascii_case(value))
+        })
+        .ok_or_else(|| format!("unknown {kind} '{value}'"))
+}
+
+pub fn parse_game(value: &str) -> Result<Game, String> {
+    lookup(&GAMES, value, "game")
+}
+
+pub fn parse_method(value: &str) -> Result<Method, String> {
+    lookup(&METHODS, value, "method")
+}
+
+pub fn parse_encounter(value: &str) -> Result<Encounter, String> {
+    lookup(&ENCOUNTERS, value, "encounter")
+}
+
+pub fn parse_language(value: &str) -> Result<Language, String> {
+    lookup(&LANGUAGES, value, "language")
+}
+
+pub fn parse_ds_type(value: &str) -> Result<DSType, String> {
+    lookup(&DS_TYPES, value, "DS type")
+}
+
+/// Parses held buttons joined with `+`, e.g. `a+start`
+pub fn parse_buttons(value: &str) -> Result<Buttons, String> {
+    value.split('+').try_fold(Buttons::NONE, |buttons, button| {
+        Ok(buttons | lookup(&BUTTONS, button.trim(), "button")?)
+    })
+}
+
+/// Parses a lead, where Synchronize is written as `synchronize:<nature>`
+pub fn parse_lead(value: &str) -> Result<Lead, String> {
+    match value.split_once(':') {
+        Some((lead, nature)) if lead.eq_ignore_ascii_case("synchronize") => {
+            let nature = lookup_translated(translator::get_natures(), nature, "nature")?;
+            Ok(Lead::from_bits_retain(nature as u8))
+        }
+        _ => lookup(&LEADS, value, "lead"),
+    }
+}
+
+/// Parses a seed written in hexadecimal with an optional `0x` prefix
+pub fn parse_hex_u32(value: &str) -> Result<u32, String> {
+    let digits = value.trim_start_matches("0x").trim_start_matches("0X");
+    u32::from_str_radix(digits, 16).map_err(|e| format!("invalid seed '{value}': {e}"))
+}
+
+/// Parses a 64-bit seed written in hexadecimal with an optional `0x` prefix
+pub fn parse_hex_u64(value: &str) -> Result<u64, String> {
+    let digits = value.trim_start_matches("0x").trim_start_matches("0X");
+    u64::from_str_radix(digits, 16).map_err(|e| format!("invalid seed '{value}': {e}"))
+}
+
+/// Parses a date written as `YYYY-MM-DD`
+pub fn parse_date(value: &str) -> Result<Date, String> {
+    let parts = value
+        .split('-')
+        .map(|part| part.parse::<u16>().ok())
+        .collect::<Vec<_>>();
+    match parts[..] {
+        [Some(year @ 2000..=2099), Some(month @ 1..=12), Some(day)]
+            if day >= 1 && day <= Date::days_in_month(year, month as u8) as u16 =>
+        {
+            Ok(Date::new_ymd(year, month as u8, day as u8))
+        }
+        _ => Err(format!("invalid date '{value}', expected YYYY-MM-DD")),
+    }
+}
+
+/// Reads a wondercard file, which has to be at least `size` bytes long
+pub fn read_wondercard(path: &Path, size: usize) -> Result<Vec<u8>, String> {
+    let data = std::fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    if data.len() < size {
+        return Err(format!(
+            "{} is {} bytes long, a wondercard needs at least {size}",
+            path.display(),
+            data.len()
+        ));
+    }
+    Ok(data)
+}
+
+/// Parses six comma separated IVs in HP/Atk/Def/SpA/SpD/Spe order
+pub fn parse_ivs(value: &str) -> Result<[u8; 6], String> {
+    let ivs = value
+        .split(',')
+        .map(|iv| match iv.trim().parse::<u8>() {
+            Ok(iv) if iv <= 31 => Ok(iv),
+            _ => Err(format!("invalid IV '{iv}'")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    ivs.try_into()
+        .map_err(|_| format!("expected 6 comma separated IVs, got '{value}'"))
+}
+
+/// Picks the encounter area at `location`, listing the valid locations when it does not exist
+pub fn select_area<A: EncounterAreaT>(
+    areas: Vec<A>,
+    location: u8,
+    version: Game,
+) -> Result<A, String> {
+    let locations = areas
+        .iter()
+        .map(|area| area.get_location() as u16)
+        .collect::<Vec<_>>();
+    areas
+        .into_iter()
+        .find(|area| area.get_location() == location)
+        .ok_or_else(|| {
+            let names = translator::get_locations(&locations, version);
+            let available = locations
+                .iter()
+                .zip(names)
+                .map(|(location, name)| format!("  {location}: {name}"))
+                .collect::<Vec<_>>();
+            format!(
+                "location {location} has no encounters, available locations:\n{}",
+                available.join("\n")
+            )
+        })
+}
+
+/// Picks the template of `species` out of `templates` that is available in `version`
+pub fn select_template<T: Template>(
+    templates: &'static [T],
+    species: u16,
+    version: Game,
+) -> Result<&'static T, String> {
+    let available = templates
+        .iter()
+        .filter(|template| (template.get_version() & version) != Game::NONE);
+    available
+        .clone()
+        .find(|template| template.get_species() == species)
+        .ok_or_else(|| {
+            let names = available
+                .map(|template| {
+                    format!(
+                        "  {}: {}",
+                        template.get_species(),
+                        translator::get_species(template.get_species())
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "species {species} is not in this category, available species:\n{}",
+                names.join("\n")
+            )
+        })
+}
+
+/// Profile selection shared by every subcommand.
+///
+/// A stored profile is picked by name and the remaining flags override its values, so a
+/// one-off run does not need a profile file at all.
+#[derive(Args)]
+pub struct ProfileArgs {
+    /// Name of a stored profile, defaults to the first stored profile
+    #[arg(long)]
+    pub profile: Option<String>,
+    /// Game version, e.g. emerald, heartgold, black2, bd
+    #[arg(long = "game", value_parser = parse_game)]
+    pub version: Option<Game>,
+    /// Trainer ID
+    #[arg(long)]
+    pub tid: Option<u16>,
+    /// Secret ID
+    #[arg(long)]
+    pub sid: Option<u16>,
+}
+
+impl ProfileArgs {
+    /// Picks the requested profile out of `profiles`, falling back to the default profile
+    pub fn select<P: Profile + Default>(&self, profiles: Vec<P>) -> Result<P, String> {
+        match &self.profile {
+            Some(name) => profiles
+                .into_iter()
+                .find(|profile| profile.get_name() == name)
+                .ok_or_else(|| format!("no stored profile named '{name}'")),
+            None => Ok(profiles.into_iter().next().unwrap_or_default()),
+        }
+    }
+
+    /// Applies the version and ID overrides to a profile's fields
+    pub fn apply(&self, version: &mut Game, tid: &mut u16, sid: &mut u16) {
+        if let Some(v) = self.version {
+            *version = v;
+        }
+        if let Some(t) = self.tid {
+            *tid = t;
+        }
+        if let Some(s) = self.sid {
+            *sid = s;
+        }
+    }
+}
+
+/// Advance range of a generator
+#[derive(Args)]
+pub struct GeneratorArgs {
+    /// First advance to report
+    #[arg(long, default_value_t = 0)]
+    pub initial_advances: u32,
+    /// Number of advances to generate
+    #[arg(long, default_value_t = 1000)]
+    pub max_advances: u32,
+    /// Advances consumed before the first reported advance
+    #[arg(long, default_value_t = 0)]
+    pub delay: u32,
+}
+
+/// Advance range of a generator whose results are checked against a second RNG, such as the
+/// pickup of a Gen 3 or Gen 4 egg
+#[derive(Args)]
+pub struct PickupArgs {
+    /// First pickup advance to report
+    #[arg(long, default_value_t = 0)]
+    pub pickup_initial_advances: u32,
+    /// Number of pickup advances to generate
+    #[arg(long, default_value_t = 1000)]
+    pub pickup_max_advances: u32,
+    /// Pickup advances consumed before the first reported advance
+    #[arg(long, default_value_t = 0)]
+    pub pickup_delay: u32,
+}
+
+/// Advance and delay ranges of the Gen 4 searchers
+#[derive(Args)]
+pub struct DelayArgs {
+    #[arg(long, default_value_t = 0)]
+    pub min_advance: u32,
+    #[arg(long, default_value_t = 100)]
+    pub max_advance: u32,
+    #[arg(long, default_value_t = 600)]
+    pub min_delay: u32,
+    #[arg(long, default_value_t = 800)]
+    pub max_delay: u32,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Shiny {
+    Any,
+    Star,
+    Square,
+    /// Either star or square
+    #[value(name = "shiny")]
+    Either,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum ParentGender {
+    Male,
+    Female,
+    Ditto,
+}
+
+/// How well the daycare parents get along
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Compatibility {
+    /// The two don't seem to like each other
+    Low,
+    /// The two seem to get along
+    Medium,
+    /// The two seem to get along very well
+    High,
+}
+
+impl Compatibility {
+    /// Returns the chance of an egg being ready, out of 100
+    pub fn chance(self, oval_charm: bool) -> u8 {
+        let chances = if oval_charm {
+            [40, 80, 88]
+        } else {
+            [20, 50, 70]
+        };
+        chances[self as usize]
+    }
+}
+
+/// Parents left at the daycare
+#[derive(Args)]
+pub struct DaycareArgs {
+    /// National dex number of the egg
+    #[arg(long = "egg-species", default_value_t = 1)]
+    pub species: u16,
+    /// IVs of parent A as HP,Atk,Def,SpA,SpD,Spe
+    #[arg(long, value_parser = parse_ivs, default_value = "31,31,31,31,31,31")]
+    pub parent_a_ivs: [u8; 6],
+    /// IVs of parent B as HP,Atk,Def,SpA,SpD,Spe
+    #[arg(long, value_parser = parse_ivs, default_value = "31,31,31,31,31,31")]
+    pub parent_b_ivs: [u8; 6],
+    /// Ability slot of parent A and B, 2 is the hidden ability
+    #[arg(long, value_delimiter = ',', default_value = "0,0", value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub parent_abilities: Vec<u8>,
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "male,female")]
+    pub parent_genders: Vec<ParentGender>,
+    /// Item of parent A and B: 0 none, 1 Everstone, 2 to 7 the HP to Spe power items, 8 Destiny
+    /// Knot
+    #[arg(long, value_delimiter = ',', default_value = "0,0", value_parser = clap::value_parser!(u8).range(0..=8))]
+    pub parent_items: Vec<u8>,
+    /// Nature of parent A and B by name or index
+    #[arg(long, value_delimiter = ',', default_value = "0,0")]
+    pub parent_natures: Vec<String>,
+    /// The parents come from different languages
+    #[arg(long)]
+    pub masuda: bool,
+}
+
+impl DaycareArgs {
+    pub fn daycare(&self) -> Result<Daycare, String> {
+        fn pair<T: Clone>(values: &[T], kind: &str) -> Result<[T; 2], String> {
+            <[T; 2]>::try_from(values.to_vec())
+                .map_err(|_| format!("expected a {kind} for parent A and B"))
+        }
+
+        let natures = pair(&self.parent_natures, "nature")?;
+        Ok(Daycare::new(
+            [self.parent_a_ivs, self.parent_b_ivs],
+            pair(&self.parent_abilities, "ability")?,
+            pair(&self.parent_genders, "gender")?.map(|gender| match gender {
+                ParentGender::Male => 0,
+                ParentGender::Female => 1,
+                ParentGender::Ditto => 3,
+            }),
+            pair(&self.parent_items, "item")?,
+            [
+                lookup_translated(translator::get_natures(), &natures[0], "nature")? as u8,
+                lookup_translated(translator::get_natures(), &natures[1], "nature")? as u8,
+            ],
+            self.species,
+            self.masuda,
+        ))
+    }
+}
+
+/// Trainer ID criteria of the ID generators and searchers, every value is kept when empty
+#[derive(Args)]
+pub struct IDFilterArgs {
+    /// Trainer IDs to keep
+    #[arg(long = "tid-filter", value_delimiter = ',')]
+    pub tids: Vec<u16>,
+    /// Secret IDs to keep
+    #[arg(long = "sid-filter", value_delimiter = ',')]
+    pub sids: Vec<u16>,
+    /// Trainer shiny values to keep
+    #[arg(long = "tsv-filter", value_delimiter = ',')]
+    pub tsvs: Vec<u16>,
+}
+
+impl IDFilterArgs {
+    pub fn filter(&self, display: Vec<u32>) -> IDFilter {
+        IDFilter::new(
+            self.tids.clone(),
+            self.sids.clone(),
+            self.tsvs.clone(),
+            display,
+        )
+    }
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Ability {
+    #[value(name = "1")]
+    First,
+    #[value(name = "2")]
+    Second,
+    #[value(name = "h")]
+    Hidden,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+/// Filter criteria shared by every generator and searcher
+#[derive(Args)]
+pub struct FilterArgs {
+    /// Minimum IVs as HP,Atk,Def,SpA,SpD,Spe
+    #[arg(long, value_parser = parse_ivs, default_value = "0,0,0,0,0,0")]
+    pub min_ivs: [u8; 6],
+    /// Maximum IVs as HP,Atk,Def,SpA,SpD,Spe
+    #[arg(long, value_parser = parse_ivs, default_value = "31,31,31,31,31,31")]
+    pub max_ivs: [u8; 6],
+    /// Natures to keep by name or index, defaults to all natures
+    #[arg(long, value_delimiter = ',')]
+    pub natures: Vec<String>,
+    /// Hidden power types to keep by name or index, defaults to all types
+    #[arg(long, value_delimiter = ',')]
+    pub hidden_powers: Vec<String>,
+    /// Encounter slots to keep, defaults to all slots
+    #[arg(long, value_delimiter = ',')]
+    pub slots: Vec<usize>,
+    #[arg(long, value_enum)]
+    pub ability: Option<Ability>,
+    #[arg(long, value_enum)]
+    pub gender: Option<Gender>,
+    #[arg(long, value_enum, default_value_t = Shiny::Any)]
+    pub shiny: Shiny,
+    /// Report every result without applying the filter
+    #[arg(long)]
+    pub skip: bool,
+}
+
+/// Filter values in the representation the core `StateFilter` constructors expect
+pub struct FilterValues {
+    pub gender: u8,
+    pub ability: u8,
+    pub shiny: u8,
+    pub skip: bool,
+    pub min: [u8; 6],
+    pub max: [u8; 6],
+    pub natures: [bool; 25],
+    pub powers: [bool; 16],
+    pub encounter_slots: [bool; 12],
+}
+
+impl FilterArgs {
+    pub fn values(&self) -> Result<FilterValues, String> {
+        let mut natures = [self.natures.is_empty(); 25];
+        for nature in &self.natures {
+            natures[lookup_translated(translator::get_natures(), nature, "nature")?] = true;
+        }
+
+        let mut powers = [self.hidden_powers.is_empty(); 16];
+        for power in &self.hidden_powers {
+            powers[lookup_translated(translator::get_hidden_powers(), power, "hidden power")?] =
+                true;
+        }
+
+        let mut encounter_slots = [self.slots.is_empty(); 12];
+        for &slot in &self.slots {
+            *encounter_slots
+                .get_mut(slot)
+                .ok_or_else(|| format!("invalid encounter slot {slot}"))? = true;
+        }
+
+        if self
+            .min_ivs
+            .iter()
+            .zip(self.max_ivs)
+            .any(|(&min, max)| min > max)
+        {
+            return Err("minimum IVs must not exceed the maximum IVs".to_string());
+        }
+
+        Ok(FilterValues {
+            gender: self.gender.map_or(255, |gender| gender as u8),
+            ability: self.ability.map_or(255, |ability| ability as u8),
+            shiny: match self.shiny {
+                Shiny::Any => 255,
+                Shiny::Star => 1,
+                Shiny::Square => 2,
+                Shiny::Either => 3,
+            },
+            skip: self.skip,
+            min: self.min_ivs,
+            max: self.max_ivs,
+            natures,
+            powers,
+            encounter_slots,
+        })
+    }
+}
+
+/// Trainer of a Gen 6 or Gen 7 game, which have no stored profiles
+#[derive(Args)]
+pub struct TrainerArgs {
+    /// Game version, e.g. x, or, sun, um
+    #[arg(long = "game", value_parser = parse_game)]
+    pub version: Game,
+    /// Trainer ID
+    #[arg(long, default_value_t = 0)]
+    pub tid: u16,
+    /// Secret ID
+    #[arg(long, default_value_t = 0)]
+    pub sid: u16,
+    /// The shiny charm is unlocked
+    #[arg(long)]
+    pub shiny_charm: bool,
+}
+
+impl TrainerArgs {
+    /// Returns an error unless the version is one of `versions`
+    pub fn check(&self, versions: Game) -> Result<(), String> {
+        if (self.version & versions) == Game::NONE {
+            return Err("the game version does not support this command".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `species` and `form` have personal info in `version`
+fn check_species(version: Game, species: u16, form: u8) -> Result<(), String> {
+    let table = personal_loader::resolve_personal_table(version);
+    let info = table
+        .get(species as usize)
+        .filter(|info| species != 0 && info.get_present())
+        .ok_or_else(|| format!("species {species} is not available in this game"))?;
+    if form != 0 && form >= info.get_form_count() {
+        return Err(format!("species {species} has no form {form}"));
+    }
+    Ok(())
+}
+
+/// Parses an encounter slot written as `species:min_level:max_level`
+fn parse_area_slot(value: &str) -> Result<(u16, u8, u8), String> {
+    let parts = value.split(':').collect::<Vec<_>>();
+    let invalid = || format!("invalid slot '{value}', expected species:min_level:max_level");
+    match parts.as_slice() {
+        [species, min, max] => Ok((
+            species.parse().map_err(|_| invalid())?,
+            min.parse().map_err(|_| invalid())?,
+            max.parse().map_err(|_| invalid())?,
+        )),
+        _ => Err(invalid()),
+    }
+}
+
+/// Wild encounter slots given by hand, for the games without encounter tables
+#[derive(Args)]
+pub struct AreaArgs {
+    /// One of grass, surfing, old-rod, good-rod or super-rod
+    #[arg(long, value_parser = parse_encounter, default_value = "grass")]
+    pub encounter: Encounter,
+    /// Every encounter slot in order as species:min_level:max_level, e.g. 10:3:4,13:3:3
+    #[arg(long, value_delimiter = ',', value_parser = parse_area_slot, required = true)]
+    pub area_slots: Vec<(u16, u8, u8)>,
+}
+
+impl AreaArgs {
+    /// Builds the encounter area, `slots` is the number of slots the generator picks from
+    pub fn area(&self, version: Game, slots: usize) -> Result<EncounterArea, String> {
+        if self.area_slots.len() != slots {
+            return Err(format!(
+                "expected {slots} encounter slots for this encounter type, got {}",
+                self.area_slots.len()
+            ));
+        }
+
+        let mut pokemon = vec![];
+        for &(species, min_level, max_level) in &self.area_slots {
+            check_species(version, species, 0)?;
+            if min_level > max_level {
+                return Err(format!(
+                    "the minimum level of species {species} exceeds its maximum level"
+                ));
+            }
+            let info = personal_loader::resolve_personal_info(version, species, 0);
+            pokemon.push(Slot::new(species, min_level, max_level, &info));
+        }
+        Ok(EncounterArea::new(0, 0, self.encounter, pokemon))
+    }
+}
+
+/// Static encounter given by hand, for the games without encounter tables
+#[derive(Args)]
+pub struct TemplateArgs {
+    /// National dex number of the Pokemon
+    #[arg(long)]
+    pub species: u16,
+    /// Form of the Pokemon
+    #[arg(long, default_value_t = 0)]
+    pub form: u8,
+    /// Level of the Pokemon
+    #[arg(long)]
+    pub level: u8,
+    /// Number of IVs that are guaranteed to be 31
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=6))]
+    pub iv_count: u8,
+    /// Fixed ability slot, 2 is the hidden ability. Random between the first two when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub fixed_ability: Option<u8>,
+    /// The Pokemon can not be shiny
+    #[arg(long)]
+    pub shiny_locked: bool,
+}
+
+impl TemplateArgs {
+    pub fn template(&self, version: Game) -> Result<StaticTemplate, String> {
+        check_species(version, self.species, self.form)?;
+        Ok(StaticTemplate::new(
+            version,
+            self.species,
+            self.form,
+            if self.shiny_locked {
+                ShinyLock::Never
+            } else {
+                ShinyLock::Random
+            },
+            self.fixed_ability.unwrap_or(255),
+            255,
+            self.iv_count,
+            self.level,
+        ))
+    }
+}
+
+/// Parses the four TinyMT egg seed values written in hexadecimal, separated by commas
+pub fn parse_egg_seed(value: &str) -> Result<[u32; 4], String> {
+    let seed = value
+        .split(',')
+        .map(|part| parse_hex_u32(part.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    seed.try_into()
+        .map_err(|_| format!("expected 4 comma separated seed values, got '{value}'"))
+}
@@ -0,0 +1,711 @@
+use crate::args::{
+    parse_encounter, parse_hex_u32, parse_lead, parse_method, select_area, select_template,
+    Compatibility, DaycareArgs, FilterArgs, GeneratorArgs, IDFilterArgs, PickupArgs, ProfileArgs,
+};
+use crate::search::{self, threads};
+use clap::{Args, Subcommand, ValueEnum};
+use pokefinder_rs_core::enums::{Encounter, Game, Lead, Method};
+use pokefinder_rs_core::gen3::filters::{StateFilter3, WildStateFilter3};
+use pokefinder_rs_core::gen3::generators::{
+    EggGenerator3, GameCubeGenerator, IDGenerator3, PokeSpotGenerator, StaticGenerator3,
+    WildGenerator3,
+};
+use pokefinder_rs_core::gen3::searchers::{
+    BattleNowCriteria, ChannelSeedSearcher, ColoSeedSearcher, GalesSeedSearcher, GameCubeSearcher,
+    StaticSearcher3, WildSearcher3,
+};
+use pokefinder_rs_core::gen3::{encounters3, Profile3, ShadowTemplate};
+use pokefinder_rs_core::parents::searchers::iv_combinations;
+use pokefinder_rs_core::parents::{profile_loader_3, StaticTemplate, Template};
+use pokefinder_rs_core::util::export::{Export, ExportState};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate wild encounters from a seed
+    WildGenerate {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search every seed for wild encounters matching the filter IVs
+    WildSearch {
+        #[command(flatten)]
+        wild: WildArgs,
+    },
+    /// Generate static encounters from a seed
+    StaticGenerate {
+        #[command(flatten)]
+        fixed: StaticArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search every seed for static encounters matching the filter IVs
+    StaticSearch {
+        #[command(flatten)]
+        fixed: StaticArgs,
+    },
+    /// Generate Gales/Colosseum encounters from a seed
+    GamecubeGenerate {
+        #[command(flatten)]
+        gamecube: GameCubeArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search every seed for Gales/Colosseum encounters matching the filter IVs
+    GamecubeSearch {
+        #[command(flatten)]
+        gamecube: GameCubeArgs,
+    },
+    /// Search the Gales seed from the Battle Now teams of each reroll
+    GalesSeedSearch {
+        #[command(flatten)]
+        battle_now: BattleNowArgs,
+    },
+    /// Search the Colosseum seed from the Battle Now teams of each reroll
+    ColoSeedSearch {
+        #[command(flatten)]
+        battle_now: BattleNowArgs,
+    },
+    /// Search the Channel seed from the order of the Jirachi menu options
+    ChannelSeedSearch {
+        /// Order the three menu options came up in for each check, as the digits 1 to 3. A
+        /// leading 0 marks a check that started with a pause, e.g. 123,0312
+        #[arg(long, value_delimiter = ',', value_parser = parse_channel_pattern, required = true)]
+        patterns: Vec<u8>,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Generate Poke Spot encounters from the food and encounter seeds (Gales)
+    PokespotGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Seed of the food check in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Seed of the encounter in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        encounter_seed: u32,
+        /// First advance of the encounter to report
+        #[arg(long, default_value_t = 0)]
+        encounter_initial_advances: u32,
+        /// Number of encounter advances to generate
+        #[arg(long, default_value_t = 1000)]
+        encounter_max_advances: u32,
+        /// Offset added to the encounter advances
+        #[arg(long, default_value_t = 0)]
+        encounter_delay: u32,
+        /// Location id of the Poke Spot, an invalid id lists the valid ones
+        #[arg(long)]
+        location: u8,
+    },
+    /// Generate eggs from the held and pickup seeds
+    EggGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        daycare: DaycareArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        pickup: PickupArgs,
+        /// Seed of the held egg in hexadecimal, not used by Emerald
+        #[arg(long, value_parser = parse_hex_u32, default_value = "0")]
+        seed: u32,
+        /// Seed of the picked up egg in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32, default_value = "0")]
+        pickup_seed: u32,
+        /// e-bred, e-bred-split or e-bred-alternate for Emerald, rsfrlg-bred, rsfrlg-bred-split,
+        /// rsfrlg-bred-alternate or rsfrlg-bred-mixed otherwise. Defaults to the first one
+        #[arg(long, value_parser = parse_method)]
+        method: Option<Method>,
+        #[arg(long, value_enum, default_value_t = Compatibility::Low)]
+        compatibility: Compatibility,
+        /// Advances between the egg being generated and accepted (Emerald)
+        #[arg(long, default_value_t = 18)]
+        calibration: u8,
+        /// Fewest times the held egg is redrawn (Emerald)
+        #[arg(long, default_value_t = 0)]
+        min_redraw: u8,
+        /// Most times the held egg is redrawn (Emerald)
+        #[arg(long, default_value_t = 0)]
+        max_redraw: u8,
+    },
+    /// Generate trainer IDs
+    IdGenerate {
+        #[command(flatten)]
+        filter: IDFilterArgs,
+        /// First advance to report
+        #[arg(long, default_value_t = 0)]
+        initial_advances: u32,
+        /// Number of advances to generate
+        #[arg(long, default_value_t = 1000)]
+        max_advances: u32,
+        #[arg(long, value_enum)]
+        mode: IDMode,
+        /// Initial seed in hexadecimal, for xd-colo and rs
+        #[arg(long, value_parser = parse_hex_u32, default_value = "0")]
+        seed: u32,
+        /// Trainer ID that seeds the SID, for frlge
+        #[arg(long, default_value_t = 0)]
+        tid: u16,
+    },
+}
+
+/// Encounter options shared by the wild generator and searcher
+#[derive(Args)]
+pub struct WildArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// One of method1, method2 or method4
+    #[arg(long, value_parser = parse_method, default_value = "method1")]
+    method: Method,
+    /// One of grass, rock-smash, surfing, old-rod, good-rod or super-rod
+    #[arg(long, value_parser = parse_encounter, default_value = "grass")]
+    encounter: Encounter,
+    /// Location id of the encounter area, an invalid id lists the valid ones
+    #[arg(long)]
+    location: u8,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+}
+
+/// Encounter options shared by the static generator and searcher
+#[derive(Args)]
+pub struct StaticArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// One of method1, method2 or method4
+    #[arg(long, value_parser = parse_method, default_value = "method1")]
+    method: Method,
+    /// 0 starters, 1 fossils, 2 gifts, 3 game corner, 4 stationary, 5 legends, 6 events
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=6))]
+    category: u8,
+    /// National dex number of the Pokemon
+    #[arg(long)]
+    species: u16,
+}
+
+/// Encounter options shared by the Gales/Colosseum generator and searcher
+#[derive(Args)]
+pub struct GameCubeArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[arg(long, value_enum, default_value_t = GameCubeCategory::Shadow)]
+    category: GameCubeCategory,
+    /// National dex number of the Pokemon
+    #[arg(long)]
+    species: u16,
+    /// Whether the shadow Pokemon was not seen before (Gales only)
+    #[arg(long)]
+    unset: bool,
+}
+
+/// Observations shared by the Gales and Colosseum seed searchers
+#[derive(Args)]
+pub struct BattleNowArgs {
+    /// Teams of one reroll as player:enemy, optionally followed by
+    /// :species:level:hp:species:level:hp with the species, level and HP stat of the player and
+    /// enemy lead. Repeat once per reroll in the order they were rerolled
+    #[arg(long, value_parser = parse_battle_now, required = true)]
+    reroll: Vec<BattleNowCriteria>,
+    /// Seeds of a previous search in hexadecimal to narrow down instead of searching every seed
+    #[arg(long, value_delimiter = ',', value_parser = parse_hex_u32)]
+    seeds: Vec<u32>,
+    /// Number of search threads, defaults to every available core
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+impl BattleNowArgs {
+    /// Progress the seed searchers report once they are done
+    fn total(&self) -> u32 {
+        if self.seeds.is_empty() {
+            0x10000
+        } else {
+            self.seeds.len() as u32
+        }
+    }
+}
+
+/// Seed found by one of the Gamecube seed searchers
+struct Seed(u32);
+
+impl ExportState for Seed {
+    fn headers() -> Vec<&'static str> {
+        vec!["Seed"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(format!("{:0>8X}", self.0));
+    }
+}
+
+fn export_seeds(seeds: Vec<u32>) -> Export {
+    Export::new(&seeds.into_iter().map(Seed).collect::<Vec<_>>())
+}
+
+/// Parses one Battle Now reroll written as `player:enemy[:species:level:hp:species:level:hp]`
+fn parse_battle_now(value: &str) -> Result<BattleNowCriteria, String> {
+    let parts = value
+        .split(':')
+        .map(|part| part.parse::<u16>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("invalid reroll '{value}': {e}"))?;
+    let (teams, leads) = match *parts.as_slice() {
+        [player, enemy] => ([player, enemy], None),
+        [player, enemy, ref leads @ ..] if leads.len() == 6 => ([player, enemy], Some(leads)),
+        _ => {
+            return Err(format!(
+                "invalid reroll '{value}', expected player:enemy with optionally \
+                 :species:level:hp:species:level:hp"
+            ))
+        }
+    };
+    if teams.iter().any(|&team| team > 4) {
+        return Err(format!("invalid reroll '{value}', teams range from 0 to 4"));
+    }
+    let Some(leads) = leads else {
+        return Ok(BattleNowCriteria::teams(teams[0] as u8, teams[1] as u8));
+    };
+    for lead in leads.chunks_exact(3) {
+        if !(1..=386).contains(&lead[0]) || !(1..=100).contains(&lead[1]) || lead[2] == 0 {
+            return Err(format!(
+                "invalid reroll '{value}', leads need a species from 1 to 386, a level from 1 to \
+                 100 and a non-zero HP"
+            ));
+        }
+    }
+    Ok(BattleNowCriteria::new(
+        teams[0] as u8,
+        teams[1] as u8,
+        leads[0],
+        leads[1] as u8,
+        leads[2],
+        leads[3],
+        leads[4] as u8,
+        leads[5],
+    ))
+}
+
+/// Parses the order of the Channel menu options into the pattern the searcher compares against.
+///
+/// Each option adds its number shifted by its position, a leading pause shifts every option by
+/// one more.
+fn parse_channel_pattern(value: &str) -> Result<u8, String> {
+    let (shift, order) = match value.strip_prefix('0') {
+        Some(order) => (1, order),
+        None => (0, value),
+    };
+    let mut digits = order.chars().map(|c| c.to_digit(10)).collect::<Vec<_>>();
+    digits.sort_unstable();
+    if digits != [Some(1), Some(2), Some(3)] {
+        return Err(format!(
+            "invalid pattern '{value}', expected the digits 1, 2 and 3 in any order"
+        ));
+    }
+    Ok(order
+        .bytes()
+        .enumerate()
+        .map(|(i, c)| (c - b'0') << (i + shift))
+        .sum())
+}
+
+#[derive(Copy, Clone, PartialEq, ValueEnum)]
+pub enum GameCubeCategory {
+    Shadow,
+    NonShadow,
+    Channel,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum IDMode {
+    /// Gales and Colosseum, from the initial seed
+    XdColo,
+    /// FireRed, LeafGreen and Emerald, from the TID
+    Frlge,
+    /// Ruby and Sapphire, from the initial seed
+    Rs,
+}
+
+/// Template of a Gales/Colosseum encounter
+enum GameCubeTemplate {
+    Shadow(&'static ShadowTemplate),
+    Static(&'static StaticTemplate),
+}
+
+impl GameCubeArgs {
+    fn method(&self) -> Method {
+        if self.category == GameCubeCategory::Channel {
+            Method::Channel
+        } else {
+            Method::XDColo
+        }
+    }
+
+    fn template(&self, version: Game) -> Result<GameCubeTemplate, String> {
+        Ok(match self.category {
+            GameCubeCategory::Shadow => GameCubeTemplate::Shadow(select_template(
+                encounters3::get_shadow_teams(),
+                self.species,
+                version,
+            )?),
+            GameCubeCategory::NonShadow => GameCubeTemplate::Static(select_template(
+                encounters3::get_static_encounters(7),
+                self.species,
+                version,
+            )?),
+            GameCubeCategory::Channel => GameCubeTemplate::Static(select_template(
+                encounters3::get_static_encounters(8),
+                self.species,
+                version,
+            )?),
+        })
+    }
+}
+
+fn load_profile(args: &ProfileArgs, versions: Game) -> Result<Profile3, String> {
+    let mut profile = args.select(profile_loader_3::get_profiles())?;
+    args.apply(&mut profile.version, &mut profile.tid, &mut profile.sid);
+    if (profile.version & versions) == Game::NONE {
+        return Err("the profile version does not support this command".to_string());
+    }
+    Ok(profile)
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter3, String> {
+    let values = filter.values()?;
+    Ok(StateFilter3::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+fn wild_state_filter(filter: &FilterArgs) -> Result<WildStateFilter3, String> {
+    let values = filter.values()?;
+    Ok(WildStateFilter3::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+        values.encounter_slots,
+    ))
+}
+
+impl Command {
+    pub fn run(self, quiet: bool) -> Result<Export, String> {
+        match self {
+            Command::WildGenerate {
+                wild,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&wild.profile, Game::GEN3)?;
+                let filter = wild_state_filter(&wild.filter)?;
+                let area = select_area(
+                    encounters3::get_encounters(wild.encounter, profile.version),
+                    wild.location,
+                    profile.version,
+                )?;
+                let generator = WildGenerator3::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    wild.method,
+                    wild.encounter,
+                    wild.lead,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, &area)))
+            }
+            Command::WildSearch { wild } => {
+                let profile = load_profile(&wild.profile, Game::GEN3)?;
+                let filter = wild_state_filter(&wild.filter)?;
+                let area = select_area(
+                    encounters3::get_encounters(wild.encounter, profile.version),
+                    wild.location,
+                    profile.version,
+                )?;
+                let searcher = WildSearcher3::new(
+                    wild.method,
+                    wild.encounter,
+                    wild.lead,
+                    &area,
+                    &profile,
+                    &filter,
+                );
+
+                let (min, max) = (wild.filter.min_ivs, wild.filter.max_ivs);
+                let states = search::run(&searcher, iv_combinations(min, max), quiet, |s| {
+                    s.start_search(min, max)
+                });
+                Ok(Export::new(&states))
+            }
+            Command::StaticGenerate {
+                fixed,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&fixed.profile, Game::GEN3)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = select_template(
+                    encounters3::get_static_encounters(fixed.category as usize),
+                    fixed.species,
+                    profile.version,
+                )?;
+                let generator = StaticGenerator3::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    fixed.method,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, template))
+                    .with_species(template.get_species(), template.get_form()))
+            }
+            Command::StaticSearch { fixed } => {
+                let profile = load_profile(&fixed.profile, Game::GEN3)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = select_template(
+                    encounters3::get_static_encounters(fixed.category as usize),
+                    fixed.species,
+                    profile.version,
+                )?;
+                let searcher = StaticSearcher3::new(fixed.method, &profile, &filter);
+
+                let (min, max) = (fixed.filter.min_ivs, fixed.filter.max_ivs);
+                let states = search::run(&searcher, iv_combinations(min, max), quiet, |s| {
+                    s.start_search(min, max, template)
+                });
+                Ok(Export::new(&states).with_species(template.get_species(), template.get_form()))
+            }
+            Command::GamecubeGenerate {
+                gamecube,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&gamecube.profile, Game::GC)?;
+                let filter = state_filter(&gamecube.filter)?;
+                let generator = GameCubeGenerator::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    gamecube.method(),
+                    gamecube.unset,
+                    &profile,
+                    &filter,
+                );
+                let export = match gamecube.template(profile.version)? {
+                    GameCubeTemplate::Shadow(template) => {
+                        Export::new(&generator.generate_shadow(seed, template))
+                            .with_species(template.get_species(), template.get_form())
+                    }
+                    GameCubeTemplate::Static(template) => {
+                        Export::new(&generator.generate(seed, template))
+                            .with_species(template.get_species(), template.get_form())
+                    }
+                };
+                Ok(export)
+            }
+            Command::GamecubeSearch { gamecube } => {
+                let profile = load_profile(&gamecube.profile, Game::GC)?;
+                let filter = state_filter(&gamecube.filter)?;
+                let searcher =
+                    GameCubeSearcher::new(gamecube.method(), gamecube.unset, &profile, &filter);
+
+                let (min, max) = (gamecube.filter.min_ivs, gamecube.filter.max_ivs);
+                // Channel searches walk every seed for each SpD IV instead of the IV combinations
+                let total = if gamecube.category == GameCubeCategory::Channel {
+                    ((max[4] - min[4]) as u32 + 1).saturating_mul(0x8000000)
+                } else {
+                    iv_combinations(min, max)
+                };
+                let export = match gamecube.template(profile.version)? {
+                    GameCubeTemplate::Shadow(template) => {
+                        Export::new(&search::run(&searcher, total, quiet, |s| {
+                            s.start_search_shadow(min, max, template)
+                        }))
+                        .with_species(template.get_species(), template.get_form())
+                    }
+                    GameCubeTemplate::Static(template) => {
+                        Export::new(&search::run(&searcher, total, quiet, |s| {
+                            s.start_search_static(min, max, template)
+                        }))
+                        .with_species(template.get_species(), template.get_form())
+                    }
+                };
+                Ok(export)
+            }
+            Command::GalesSeedSearch { battle_now } => {
+                let searcher = GalesSeedSearcher::new(battle_now.reroll.clone());
+                let seeds = search::run(&searcher, battle_now.total(), quiet, |s| {
+                    if battle_now.seeds.is_empty() {
+                        s.start_search(threads(battle_now.threads))
+                    } else {
+                        s.start_search_seeds(&battle_now.seeds)
+                    }
+                });
+                Ok(export_seeds(seeds))
+            }
+            Command::ColoSeedSearch { battle_now } => {
+                let searcher = ColoSeedSearcher::new(battle_now.reroll.clone());
+                let seeds = search::run(&searcher, battle_now.total(), quiet, |s| {
+                    if battle_now.seeds.is_empty() {
+                        s.start_search(threads(battle_now.threads))
+                    } else {
+                        s.start_search_seeds(&battle_now.seeds)
+                    }
+                });
+                Ok(export_seeds(seeds))
+            }
+            Command::ChannelSeedSearch {
+                patterns,
+                threads: count,
+            } => {
+                let searcher = ChannelSeedSearcher::new(patterns);
+                let seeds = search::run(&searcher, 0xBFFFFFFE, quiet, |s| {
+                    s.start_search(threads(count))
+                });
+                Ok(export_seeds(seeds))
+            }
+            Command::PokespotGenerate {
+                profile,
+                filter,
+                generator,
+                seed,
+                encounter_seed,
+                encounter_initial_advances,
+                encounter_max_advances,
+                encounter_delay,
+                location,
+            } => {
+                let profile = load_profile(&profile, Game::GALES)?;
+                let filter = wild_state_filter(&filter)?;
+                let area = select_area(
+                    encounters3::get_poke_spot_encounters(),
+                    location,
+                    profile.version,
+                )?;
+                let generator = PokeSpotGenerator::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    encounter_initial_advances,
+                    encounter_max_advances,
+                    encounter_delay,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(
+                    seed,
+                    encounter_seed,
+                    &area,
+                )))
+            }
+            Command::EggGenerate {
+                profile,
+                filter,
+                daycare,
+                generator,
+                pickup,
+                seed,
+                pickup_seed,
+                method,
+                compatibility,
+                calibration,
+                min_redraw,
+                max_redraw,
+            } => {
+                let profile = load_profile(&profile, Game::GEN3)?;
+                let methods = if profile.version == Game::EMERALD {
+                    [Method::EBred, Method::EBredSplit, Method::EBredAlternate].as_slice()
+                } else {
+                    [
+                        Method::RSFRLGBred,
+                        Method::RSFRLGBredSplit,
+                        Method::RSFRLGBredAlternate,
+                        Method::RSFRLGBredMixed,
+                    ]
+                    .as_slice()
+                };
+                let method = method.unwrap_or(methods[0]);
+                if !methods.contains(&method) {
+                    return Err("the method can not be used to breed in this game".to_string());
+                }
+
+                let filter = state_filter(&filter)?;
+                let daycare = daycare.daycare()?;
+                let generator = EggGenerator3::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    pickup.pickup_initial_advances,
+                    pickup.pickup_max_advances,
+                    pickup.pickup_delay,
+                    calibration,
+                    min_redraw,
+                    max_redraw,
+                    method,
+                    compatibility.chance(false),
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, pickup_seed))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+            Command::IdGenerate {
+                filter,
+                initial_advances,
+                max_advances,
+                mode,
+                seed,
+                tid,
+            } => {
+                let filter = filter.filter(vec![]);
+                let generator = IDGenerator3::new(initial_advances, max_advances, &filter);
+                let states = match mode {
+                    IDMode::XdColo => generator.generate_xd_colo(seed),
+                    IDMode::Frlge => generator.generate_frlge(tid),
+                    IDMode::Rs => generator.generate_rs(seed as u16),
+                };
+                Ok(Export::new(&states))
+            }
+        }
+    }
+}
@@ -0,0 +1,520 @@
+use crate::args::{
+    parse_encounter, parse_game, parse_hex_u32, parse_lead, parse_method, read_wondercard,
+    select_area, select_template, DaycareArgs, DelayArgs, FilterArgs, GeneratorArgs, IDFilterArgs,
+    PickupArgs, ProfileArgs,
+};
+use crate::search;
+use clap::{Args, Subcommand};
+use pokefinder_rs_core::enums::{Encounter, Game, Lead, Method};
+use pokefinder_rs_core::gen4::filters::{StateFilter4, WildStateFilter4};
+use pokefinder_rs_core::gen4::generators::{
+    EggGenerator4, EventGenerator4, IDGenerator4, StaticGenerator4, WildGenerator4,
+};
+use pokefinder_rs_core::gen4::searchers::{
+    EggSearcher4, EventSearcher4, IDSearcher4, StaticSearcher4, WildSearcher4,
+};
+use pokefinder_rs_core::gen4::{
+    encounters4, EncounterArea4, Profile4, PCD, PCD_SIZE, PGT, PGT_SIZE,
+};
+use pokefinder_rs_core::parents::searchers::iv_combinations;
+use pokefinder_rs_core::parents::{profile_loader_4, Template};
+use pokefinder_rs_core::util::export::Export;
+use std::path::{Path, PathBuf};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate wild encounters from a seed
+    WildGenerate {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search the seeds reachable in the delay range for wild encounters matching the filter IVs
+    WildSearch {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        delay: DelayArgs,
+    },
+    /// Generate static encounters from a seed
+    StaticGenerate {
+        #[command(flatten)]
+        fixed: StaticArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search the seeds reachable in the delay range for static encounters matching the filter IVs
+    StaticSearch {
+        #[command(flatten)]
+        fixed: StaticArgs,
+        #[command(flatten)]
+        delay: DelayArgs,
+    },
+    /// Generate eggs from the held and pickup seeds
+    EggGenerate {
+        #[command(flatten)]
+        egg: EggArgs,
+        /// Seed of the held egg in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Seed of the picked up egg in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        pickup_seed: u32,
+    },
+    /// Search the seeds reachable in the delay range for eggs matching the filter
+    EggSearch {
+        #[command(flatten)]
+        egg: EggArgs,
+        #[arg(long, default_value_t = 600)]
+        min_delay: u32,
+        #[arg(long, default_value_t = 800)]
+        max_delay: u32,
+    },
+    /// Generate a wondercard gift from a seed
+    EventGenerate {
+        #[command(flatten)]
+        event: EventArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+    /// Search the seeds reachable in the delay range for a wondercard gift matching the filter IVs
+    EventSearch {
+        #[command(flatten)]
+        event: EventArgs,
+        #[command(flatten)]
+        delay: DelayArgs,
+    },
+    /// Generate the trainer IDs of every delay at a date and time
+    IdGenerate {
+        #[command(flatten)]
+        filter: IDFilterArgs,
+        #[arg(long, default_value_t = 5000)]
+        min_delay: u32,
+        #[arg(long, default_value_t = 6000)]
+        max_delay: u32,
+        #[arg(long, default_value_t = 2000)]
+        year: u16,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=12))]
+        month: u8,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=31))]
+        day: u8,
+        #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=23))]
+        hour: u8,
+        #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=59))]
+        minute: u8,
+    },
+    /// Search the delay range for trainer IDs matching the filter
+    IdSearch {
+        #[command(flatten)]
+        filter: IDFilterArgs,
+        #[arg(long, default_value_t = 5000)]
+        min_delay: u32,
+        #[arg(long, default_value_t = 6000)]
+        max_delay: u32,
+        #[arg(long, default_value_t = 2000)]
+        year: u16,
+        /// Search every delay from the minimum delay on
+        #[arg(long)]
+        infinite: bool,
+    },
+}
+
+/// Encounter options shared by the wild generator and searcher
+#[derive(Args)]
+pub struct WildArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// method-j or poke-radar for DPPt, method-k for HGSS. Defaults to the game's method
+    #[arg(long, value_parser = parse_method)]
+    method: Option<Method>,
+    /// Encounter type, e.g. grass, surfing, super-rod, headbutt or bug-catching-contest
+    #[arg(long, value_parser = parse_encounter, default_value = "grass")]
+    encounter: Encounter,
+    /// Location id of the encounter area, an invalid id lists the valid ones
+    #[arg(long)]
+    location: u8,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+    /// 0 morning, 1 day, 2 night
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=2))]
+    time: u8,
+    /// Gen 3 game in the dual slot (DPPt)
+    #[arg(long, value_parser = parse_game)]
+    dual: Option<Game>,
+    /// Poke Radar slots are active (DPPt)
+    #[arg(long)]
+    radar: bool,
+    /// The Poke Radar chain is shiny
+    #[arg(long)]
+    radar_shiny: bool,
+    /// 0 none, 1 Hoenn Sound, 2 Sinnoh Sound (HGSS)
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=2))]
+    radio: u8,
+    /// Swarm slots are active
+    #[arg(long)]
+    swarm: bool,
+}
+
+/// Encounter options shared by the static generator and searcher
+#[derive(Args)]
+pub struct StaticArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+    /// 0 starters, 1 fossils, 2 gifts, 3 game corner, 4 stationary, 5 legends, 6 events, 7 roamers
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=7))]
+    category: u8,
+    /// National dex number of the Pokemon
+    #[arg(long)]
+    species: u16,
+}
+
+/// Daycare options shared by the egg generator and searcher
+#[derive(Args)]
+pub struct EggArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[command(flatten)]
+    daycare: DaycareArgs,
+    #[command(flatten)]
+    generator: GeneratorArgs,
+    #[command(flatten)]
+    pickup: PickupArgs,
+}
+
+/// Wondercard options shared by the event generator and searcher
+#[derive(Args)]
+pub struct EventArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// Path of a .pgt gift or .pcd wondercard
+    #[arg(long)]
+    file: PathBuf,
+}
+
+/// Reads the gift of a PGT or PCD file
+fn read_gift(path: &Path) -> Result<PGT, String> {
+    let data = read_wondercard(path, PGT_SIZE)?;
+    let gift = if data.len() >= PCD_SIZE {
+        PCD::try_from(data.as_slice()).map(|pcd| pcd.gift)
+    } else {
+        PGT::try_from(data.as_slice())
+    };
+    gift.map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn load_profile(args: &ProfileArgs) -> Result<Profile4, String> {
+    let mut profile = args.select(profile_loader_4::get_profiles())?;
+    args.apply(&mut profile.version, &mut profile.tid, &mut profile.sid);
+    if (profile.version & Game::GEN4) == Game::NONE {
+        return Err("the profile version is not a Gen 4 game".to_string());
+    }
+    Ok(profile)
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter4, String> {
+    let values = filter.values()?;
+    Ok(StateFilter4::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+impl WildArgs {
+    fn method(&self, profile: &Profile4) -> Method {
+        self.method
+            .unwrap_or(if (profile.version & Game::DPPT) != Game::NONE {
+                Method::MethodJ
+            } else {
+                Method::MethodK
+            })
+    }
+
+    /// The Poke Radar patch is the first encounter slot kept by the filter
+    fn radar_index(&self) -> u8 {
+        self.filter.slots.first().copied().unwrap_or_default() as u8
+    }
+
+    fn filter(&self) -> Result<WildStateFilter4, String> {
+        let values = self.filter.values()?;
+        Ok(WildStateFilter4::new(
+            values.gender,
+            values.ability,
+            values.shiny,
+            values.skip,
+            values.min,
+            values.max,
+            values.natures,
+            values.powers,
+            values.encounter_slots,
+        ))
+    }
+
+    fn area(&self, profile: &Profile4) -> Result<EncounterArea4, String> {
+        let areas = encounters4::get_encounters(
+            self.encounter,
+            self.time as usize,
+            self.dual.unwrap_or(Game::NONE),
+            self.radar || self.method(profile) == Method::PokeRadar,
+            self.radio as usize,
+            self.swarm,
+            [0, 0],
+            [0; 5],
+            profile,
+        );
+        select_area(areas, self.location, profile.version)
+    }
+}
+
+impl Command {
+    pub fn run(self, quiet: bool) -> Result<Export, String> {
+        match self {
+            Command::WildGenerate {
+                wild,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&wild.profile)?;
+                let filter = wild.filter()?;
+                let area = wild.area(&profile)?;
+                let generator = WildGenerator4::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    wild.method(&profile),
+                    wild.encounter,
+                    wild.lead,
+                    wild.radar_shiny,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(
+                    seed,
+                    &area,
+                    wild.radar_index(),
+                )))
+            }
+            Command::WildSearch { wild, delay } => {
+                let profile = load_profile(&wild.profile)?;
+                let filter = wild.filter()?;
+                let area = wild.area(&profile)?;
+                let searcher = WildSearcher4::new(
+                    delay.min_advance,
+                    delay.max_advance,
+                    delay.min_delay,
+                    delay.max_delay,
+                    wild.method(&profile),
+                    wild.encounter,
+                    wild.lead,
+                    wild.radar_shiny,
+                    &area,
+                    &profile,
+                    &filter,
+                );
+
+                let (min, max) = (wild.filter.min_ivs, wild.filter.max_ivs);
+                let index = wild.radar_index();
+                let states = search::run(&searcher, iv_combinations(min, max), quiet, |s| {
+                    s.start_search(min, max, index)
+                });
+                Ok(Export::new(&states))
+            }
+            Command::StaticGenerate {
+                fixed,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&fixed.profile)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = select_template(
+                    encounters4::get_static_encounters(fixed.category as usize),
+                    fixed.species,
+                    profile.version,
+                )?;
+                let generator = StaticGenerator4::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    template.method,
+                    fixed.lead,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed, template))
+                    .with_species(template.get_species(), template.get_form()))
+            }
+            Command::StaticSearch { fixed, delay } => {
+                let profile = load_profile(&fixed.profile)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = select_template(
+                    encounters4::get_static_encounters(fixed.category as usize),
+                    fixed.species,
+                    profile.version,
+                )?;
+                let searcher = StaticSearcher4::new(
+                    delay.min_advance,
+                    delay.max_advance,
+                    delay.min_delay,
+                    delay.max_delay,
+                    template.method,
+                    fixed.lead,
+                    &profile,
+                    &filter,
+                );
+
+                let (min, max) = (fixed.filter.min_ivs, fixed.filter.max_ivs);
+                let states = search::run(&searcher, iv_combinations(min, max), quiet, |s| {
+                    s.start_search(min, max, template)
+                });
+                Ok(Export::new(&states).with_species(template.get_species(), template.get_form()))
+            }
+            Command::EggGenerate {
+                egg,
+                seed,
+                pickup_seed,
+            } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator4::new(
+                    egg.generator.initial_advances,
+                    egg.generator.max_advances,
+                    egg.generator.delay,
+                    egg.pickup.pickup_initial_advances,
+                    egg.pickup.pickup_max_advances,
+                    egg.pickup.pickup_delay,
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, pickup_seed))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EggSearch {
+                egg,
+                min_delay,
+                max_delay,
+            } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator4::new(
+                    egg.generator.initial_advances,
+                    egg.generator.max_advances,
+                    egg.generator.delay,
+                    egg.pickup.pickup_initial_advances,
+                    egg.pickup.pickup_max_advances,
+                    egg.pickup.pickup_delay,
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                let searcher = EggSearcher4::new(min_delay, max_delay);
+
+                let total = 256 * 24 * (max_delay.saturating_sub(min_delay) + 1);
+                let states = search::run(&searcher, total, quiet, |s| s.start_search(&generator));
+                Ok(Export::new(&states).with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EventGenerate {
+                event,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&event.profile)?;
+                let filter = state_filter(&event.filter)?;
+                let pgt = read_gift(&event.file)?;
+                let generator = EventGenerator4::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    &pgt,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed)).with_species(pgt.species, pgt.form))
+            }
+            Command::EventSearch { event, delay } => {
+                let profile = load_profile(&event.profile)?;
+                let filter = state_filter(&event.filter)?;
+                let pgt = read_gift(&event.file)?;
+                let searcher = EventSearcher4::new(
+                    delay.min_advance,
+                    delay.max_advance,
+                    delay.min_delay,
+                    delay.max_delay,
+                    &pgt,
+                    &profile,
+                    &filter,
+                );
+
+                let (min, max) = (event.filter.min_ivs, event.filter.max_ivs);
+                let states = search::run(&searcher, iv_combinations(min, max), quiet, |s| {
+                    s.start_search(min, max)
+                });
+                Ok(Export::new(&states).with_species(pgt.species, pgt.form))
+            }
+            Command::IdGenerate {
+                filter,
+                min_delay,
+                max_delay,
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            } => {
+                let filter = filter.filter(vec![]);
+                let generator = IDGenerator4::new(
+                    min_delay, max_delay, year, month, day, hour, minute, &filter,
+                );
+                Ok(Export::new(&generator.generate()))
+            }
+            Command::IdSearch {
+                filter,
+                min_delay,
+                max_delay,
+                year,
+                infinite,
+            } => {
+                let filter = filter.filter(vec![]);
+                let searcher = IDSearcher4::new(&filter);
+                let max_delay = if infinite { 0xe8ffff } else { max_delay };
+
+                let total = (max_delay.saturating_sub(min_delay) + 1).saturating_mul(256 * 24);
+                let states = search::run(&searcher, total, quiet, |s| {
+                    s.start_search(infinite, year, min_delay, max_delay)
+                });
+                Ok(Export::new(&states))
+            }
+        }
+    }
+}
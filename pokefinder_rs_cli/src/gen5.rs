@@ -0,0 +1,791 @@
+use crate::args::{
+    parse_buttons, parse_date, parse_ds_type, parse_encounter, parse_hex_u32, parse_hex_u64,
+    parse_ivs, parse_language, parse_lead, parse_method, read_wondercard, select_area,
+    select_template, DaycareArgs, FilterArgs, GeneratorArgs, IDFilterArgs, ProfileArgs,
+};
+use crate::search;
+use clap::{Args, Subcommand};
+use pokefinder_rs_core::enums::{Buttons, DSType, Encounter, Game, Language, Lead, Method};
+use pokefinder_rs_core::gen5::filters::{HiddenGrottoFilter, StateFilter5, WildStateFilter5};
+use pokefinder_rs_core::gen5::generators::{
+    DreamRadarGenerator, EggGenerator5, EventGenerator5, HiddenGrottoGenerator, IDGenerator5,
+    StaticGenerator5, WildGenerator5,
+};
+use pokefinder_rs_core::gen5::searchers::{
+    IDSearcherGenerator5, MTSeedSearcher5, ProfileCriteria5, ProfileIVCriteria5,
+    ProfileNeedleCriteria5, ProfileSearcher5, ProfileSeedCriteria5, Searcher5, SeedGenerator5,
+};
+use pokefinder_rs_core::gen5::{
+    encounters5, keypresses, DreamRadarTemplate, EncounterArea5, Profile5, PGF,
+};
+use pokefinder_rs_core::parents::searchers::SearcherT;
+use pokefinder_rs_core::parents::{profile_loader_5, Template};
+use pokefinder_rs_core::util::export::Export;
+use pokefinder_rs_core::util::{translator, Date, Time};
+use std::path::PathBuf;
+
+/// Size of a PGF wondercard
+const PGF_SIZE: usize = 0xcc;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate static encounters from a seed
+    StaticGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+        /// One of method5-ivs, method5-cgear or method5
+        #[arg(long, value_parser = parse_method, default_value = "method5")]
+        method: Method,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+        /// 0 starters, 1 fossils, 2 gifts, 3 stationary, 4 legends, 5 events, 6 roamers
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=6))]
+        category: u8,
+        /// National dex number of the Pokemon
+        #[arg(long)]
+        species: u16,
+    },
+    /// Generate wild encounters from a seed
+    WildGenerate {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+    },
+    /// Generate eggs from a seed
+    EggGenerate {
+        #[command(flatten)]
+        egg: EggArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+    },
+    /// Search the initial seeds of a date range for eggs matching the filter
+    EggSearch {
+        #[command(flatten)]
+        egg: EggArgs,
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Search the initial seeds of a date range for a wondercard gift matching the filter
+    EventSearch {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Path of a .pgf wondercard
+        #[arg(long)]
+        file: PathBuf,
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Generate Dream Radar encounters from a seed
+    DreamRadarGenerate {
+        #[command(flatten)]
+        radar: DreamRadarArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+    },
+    /// Search the initial seeds of a date range for Dream Radar encounters matching the filter
+    DreamRadarSearch {
+        #[command(flatten)]
+        radar: DreamRadarArgs,
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Generate Hidden Grotto refills from a seed (BW2)
+    HiddenGrottoGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+        /// Location id of the grotto, an invalid id lists the valid ones
+        #[arg(long)]
+        location: u8,
+        /// Percent chance that the grotto is refilled
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(0..=100))]
+        power_level: u8,
+        /// Slots to keep: 0-2 Pokemon, 3-6 items and 7-10 hidden items, defaults to all slots
+        #[arg(long = "grotto-slots", value_delimiter = ',', value_parser = clap::value_parser!(u8).range(0..=10))]
+        slots: Vec<u8>,
+        /// Groups to keep, defaults to all groups
+        #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(0..=3))]
+        groups: Vec<u8>,
+        /// Genders of the Pokemon slots to keep: 0 male, 1 female, defaults to both
+        #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(0..=1))]
+        genders: Vec<u8>,
+    },
+    /// Generate trainer IDs from a seed
+    IdGenerate {
+        #[command(flatten)]
+        id: IdArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+    },
+    /// Search the initial seeds of a date range for trainer IDs matching the filter
+    IdSearch {
+        #[command(flatten)]
+        id: IdArgs,
+        #[command(flatten)]
+        range: DateRangeArgs,
+    },
+    /// Search every MT seed for the IVs of a caught Pokemon
+    MtSeedSearch {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        /// Minimum IVs as HP,Atk,Def,SpA,SpD,Spe
+        #[arg(long, value_parser = parse_ivs)]
+        min_ivs: [u8; 6],
+        /// Maximum IVs as HP,Atk,Def,SpA,SpD,Spe, defaults to the minimum IVs
+        #[arg(long, value_parser = parse_ivs)]
+        max_ivs: Option<[u8; 6]>,
+        /// First IV frame to check
+        #[arg(long, default_value_t = 0)]
+        initial_advances: u32,
+        /// Last IV frame to check
+        #[arg(long, default_value_t = 10)]
+        max_advances: u32,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Search the Timer0, VCount, VFrame and GxStat values of a console
+    ProfileSearch(ProfileSearchArgs),
+}
+
+/// Date range and thread count of the Gen 5 initial seed searchers
+#[derive(Args)]
+pub struct DateRangeArgs {
+    /// First day to search as YYYY-MM-DD
+    #[arg(long, value_parser = parse_date)]
+    start: Date,
+    /// Last day to search as YYYY-MM-DD
+    #[arg(long, value_parser = parse_date)]
+    end: Date,
+    /// Number of search threads, defaults to every available core
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+/// Encounter options of the wild generator
+#[derive(Args)]
+pub struct WildArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// One of method5-ivs, method5-cgear or method5
+    #[arg(long, value_parser = parse_method, default_value = "method5")]
+    method: Method,
+    /// Encounter type, e.g. grass, double-grass, surfing, special-surf or super-rod
+    #[arg(long, value_parser = parse_encounter, default_value = "grass")]
+    encounter: Encounter,
+    /// Location id of the encounter area, an invalid id lists the valid ones
+    #[arg(long)]
+    location: u8,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+    /// 0 spring, 1 summer, 2 autumn, 3 winter
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=3))]
+    season: u8,
+}
+
+/// Daycare options shared by the egg generator and searcher
+#[derive(Args)]
+pub struct EggArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[command(flatten)]
+    daycare: DaycareArgs,
+    #[command(flatten)]
+    generator: GeneratorArgs,
+}
+
+/// Dream Radar options shared by the generator and searcher
+#[derive(Args)]
+pub struct DreamRadarArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// First advance to report
+    #[arg(long, default_value_t = 0)]
+    initial_advances: u32,
+    /// Number of advances to generate
+    #[arg(long, default_value_t = 1000)]
+    max_advances: u32,
+    /// Number of badges, which sets the level of the encounter
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=8))]
+    badges: u8,
+    /// National dex numbers of the Dream Radar slots in order, the last one is generated
+    #[arg(long, value_delimiter = ',', required = true)]
+    radar_slots: Vec<u16>,
+}
+
+/// Trainer ID options shared by the ID generator and searcher
+#[derive(Args)]
+pub struct IdArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: IDFilterArgs,
+    /// First advance to report
+    #[arg(long, default_value_t = 0)]
+    initial_advances: u32,
+    /// Number of advances to generate
+    #[arg(long, default_value_t = 1000)]
+    max_advances: u32,
+    /// Only keep IDs that make this PID, in hexadecimal, shiny
+    #[arg(long, value_parser = parse_hex_u32)]
+    pid: Option<u32>,
+    /// Also require the ID to give the PID's shiny bit
+    #[arg(long, requires = "pid")]
+    check_xor: bool,
+}
+
+/// Hardware ranges and criteria of the profile searcher.
+///
+/// The version, MAC address, DS type, language and soft reset setting come from the selected
+/// profile unless overridden.
+#[derive(Args)]
+pub struct ProfileSearchArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    /// MAC address in hexadecimal
+    #[arg(long, value_parser = parse_hex_u64)]
+    mac: Option<u64>,
+    /// One of ds, dsi or 3ds
+    #[arg(long, value_parser = parse_ds_type)]
+    ds_type: Option<DSType>,
+    /// Language code, e.g. en, fr, de, it, ja, ko, es or zh
+    #[arg(long, value_parser = parse_language)]
+    language: Option<Language>,
+    /// The game was soft reset instead of booted
+    #[arg(long)]
+    soft_reset: bool,
+    /// Buttons held while loading the game joined with +, e.g. a+start
+    #[arg(long, value_parser = parse_buttons, default_value = "none")]
+    buttons: Buttons,
+    /// Date the game was loaded at as YYYY-MM-DD
+    #[arg(long, value_parser = parse_date)]
+    date: Date,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=23))]
+    hour: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=59))]
+    minute: u8,
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=59))]
+    min_second: u8,
+    #[arg(long, default_value_t = 59, value_parser = clap::value_parser!(u8).range(0..=59))]
+    max_second: u8,
+    /// Minimum VCount in hexadecimal
+    #[arg(long, value_parser = parse_hex_u8, default_value = "5")]
+    min_vcount: u8,
+    /// Maximum VCount in hexadecimal
+    #[arg(long, value_parser = parse_hex_u8, default_value = "8f")]
+    max_vcount: u8,
+    /// Minimum Timer0 in hexadecimal
+    #[arg(long, value_parser = parse_hex_u16, default_value = "c60")]
+    min_timer0: u16,
+    /// Maximum Timer0 in hexadecimal
+    #[arg(long, value_parser = parse_hex_u16, default_value = "c90")]
+    max_timer0: u16,
+    #[arg(long, default_value_t = 6)]
+    min_gx_stat: u8,
+    #[arg(long, default_value_t = 6)]
+    max_gx_stat: u8,
+    #[arg(long, default_value_t = 0)]
+    min_vframe: u8,
+    #[arg(long, default_value_t = 15)]
+    max_vframe: u8,
+    /// Minimum IVs of the first Pokemon as HP,Atk,Def,SpA,SpD,Spe
+    #[arg(long, value_parser = parse_ivs, conflicts_with_all = ["needles", "initial_seed"])]
+    min_ivs: Option<[u8; 6]>,
+    /// Maximum IVs of the first Pokemon, defaults to the minimum IVs
+    #[arg(long, value_parser = parse_ivs, requires = "min_ivs")]
+    max_ivs: Option<[u8; 6]>,
+    /// Needle directions from 0 to 7 shown after loading the game, comma separated
+    #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(0..=7), conflicts_with = "initial_seed")]
+    needles: Vec<u8>,
+    /// The needles are read after connecting to Unova Link (BW2)
+    #[arg(long, requires = "needles")]
+    unova_link: bool,
+    /// Known initial seed in hexadecimal
+    #[arg(long, value_parser = parse_hex_u64)]
+    initial_seed: Option<u64>,
+    /// Number of search threads, defaults to every available core
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+fn load_profile(args: &ProfileArgs) -> Result<Profile5, String> {
+    let mut profile = args.select(profile_loader_5::get_profiles())?;
+    args.apply(&mut profile.version, &mut profile.tid, &mut profile.sid);
+    if (profile.version & Game::GEN5) == Game::NONE {
+        return Err("the profile version is not a Gen 5 game".to_string());
+    }
+    Ok(profile)
+}
+
+fn load_bw2_profile(args: &ProfileArgs) -> Result<Profile5, String> {
+    let profile = load_profile(args)?;
+    if (profile.version & Game::BW2) == Game::NONE {
+        return Err("this encounter only exists in Black 2 and White 2".to_string());
+    }
+    Ok(profile)
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter5, String> {
+    let values = filter.values()?;
+    Ok(StateFilter5::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+fn parse_hex_u8(value: &str) -> Result<u8, String> {
+    u8::try_from(parse_hex_u32(value)?).map_err(|_| format!("'{value}' is larger than ff"))
+}
+
+fn parse_hex_u16(value: &str) -> Result<u16, String> {
+    u16::try_from(parse_hex_u32(value)?).map_err(|_| format!("'{value}' is larger than ffff"))
+}
+
+/// Builds a list of flags that is all set when `values` is empty
+fn flags<const N: usize>(values: &[u8]) -> [bool; N] {
+    let mut flags = [values.is_empty(); N];
+    for &value in values {
+        flags[value as usize] = true;
+    }
+    flags
+}
+
+impl DateRangeArgs {
+    /// Runs `searcher` over the date range, reporting progress once per day, Timer0 value and
+    /// key press combination
+    fn run<G: SeedGenerator5>(
+        &self,
+        searcher: &Searcher5<G>,
+        profile: &Profile5,
+        quiet: bool,
+    ) -> Result<Vec<<Searcher5<'_, G> as SearcherT>::State>, String> {
+        if self.start > self.end {
+            return Err("the start date must not be after the end date".to_string());
+        }
+
+        let timer0 = (profile.get_timer0_max() - profile.get_timer0_min()) as u32 + 1;
+        let total = (self.start.days_to(&self.end) + 1)
+            .saturating_mul(timer0)
+            .saturating_mul(keypresses::get_key_presses(profile).len() as u32);
+        Ok(search::run(searcher, total, quiet, |s| {
+            s.start_search(search::threads(self.threads), self.start, self.end)
+        }))
+    }
+}
+
+impl WildArgs {
+    fn filter(&self) -> Result<WildStateFilter5, String> {
+        let values = self.filter.values()?;
+        Ok(WildStateFilter5::new(
+            values.gender,
+            values.ability,
+            values.shiny,
+            values.skip,
+            values.min,
+            values.max,
+            values.natures,
+            values.powers,
+            values.encounter_slots,
+        ))
+    }
+
+    fn area(&self, profile: &Profile5) -> Result<EncounterArea5, String> {
+        let areas = encounters5::get_encounters(self.encounter, self.season, profile);
+        select_area(areas, self.location, profile.version)
+    }
+}
+
+impl DreamRadarArgs {
+    /// Picks the Dream Radar template of every slot
+    fn templates(&self) -> Result<Vec<DreamRadarTemplate>, String> {
+        if self.radar_slots.len() > 6 {
+            return Err("the Dream Radar has at most 6 slots".to_string());
+        }
+
+        let encounters = encounters5::get_dream_radar_encounters();
+        self.radar_slots
+            .iter()
+            .map(|&species| {
+                encounters
+                    .iter()
+                    .find(|template| template.base.get_species() == species)
+                    .copied()
+                    .ok_or_else(|| {
+                        let names = encounters
+                            .iter()
+                            .map(|template| {
+                                let species = template.base.get_species();
+                                format!("  {species}: {}", translator::get_species(species))
+                            })
+                            .collect::<Vec<_>>();
+                        format!(
+                            "species {species} is not in the Dream Radar, available species:\n{}",
+                            names.join("\n")
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+impl ProfileSearchArgs {
+    fn run<C: ProfileCriteria5>(
+        &self,
+        profile: &Profile5,
+        criteria: C,
+        quiet: bool,
+    ) -> Result<Export, String> {
+        if self.min_second > self.max_second
+            || self.min_vcount > self.max_vcount
+            || self.min_timer0 > self.max_timer0
+            || self.min_gx_stat > self.max_gx_stat
+            || self.min_vframe > self.max_vframe
+        {
+            return Err("every minimum must not exceed its maximum".to_string());
+        }
+
+        let searcher = ProfileSearcher5::new(
+            self.date,
+            Time::new_hms(self.hour, self.minute, 0),
+            self.min_second,
+            self.max_second,
+            self.min_vcount,
+            self.max_vcount,
+            self.min_timer0,
+            self.max_timer0,
+            self.min_gx_stat,
+            self.max_gx_stat,
+            self.min_vframe,
+            self.max_vframe,
+            self.soft_reset || profile.get_soft_reset(),
+            profile.version,
+            self.language.unwrap_or(profile.get_language()),
+            self.ds_type.unwrap_or(profile.get_ds_type()),
+            self.mac.unwrap_or(profile.get_mac()),
+            self.buttons,
+            criteria,
+        );
+
+        // Progress is reported once per VFrame, GxStat, VCount and Timer0 value
+        let total = ((self.max_vframe - self.min_vframe) as u32 + 1)
+            * ((self.max_gx_stat - self.min_gx_stat) as u32 + 1)
+            * ((self.max_vcount - self.min_vcount) as u32 + 1)
+            * ((self.max_timer0 - self.min_timer0) as u32 + 1);
+        let states = search::run(&searcher, total, quiet, |s| {
+            s.start_search(search::threads(self.threads))
+        });
+        Ok(Export::new(&states))
+    }
+}
+
+impl Command {
+    pub fn run(self, quiet: bool) -> Result<Export, String> {
+        match self {
+            Command::StaticGenerate {
+                profile,
+                filter,
+                generator,
+                seed,
+                method,
+                lead,
+                category,
+                species,
+            } => {
+                let profile = load_profile(&profile)?;
+                let filter = state_filter(&filter)?;
+                let template = select_template(
+                    encounters5::get_static_encounters(category as usize),
+                    species,
+                    profile.version,
+                )?;
+                let encounter = match category {
+                    0..=2 => Encounter::Gift,
+                    3..=5 => Encounter::Static,
+                    _ => Encounter::Roamer,
+                };
+                let generator = StaticGenerator5::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    method,
+                    encounter,
+                    lead,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed, template))
+                    .with_species(template.get_species(), template.get_form()))
+            }
+            Command::WildGenerate {
+                wild,
+                generator,
+                seed,
+            } => {
+                let profile = load_profile(&wild.profile)?;
+                let filter = wild.filter()?;
+                let area = wild.area(&profile)?;
+                let generator = WildGenerator5::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    wild.method,
+                    wild.encounter,
+                    wild.lead,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed, &area)))
+            }
+            Command::EggGenerate { egg, seed } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator5::new(
+                    egg.generator.initial_advances,
+                    egg.generator.max_advances,
+                    egg.generator.delay,
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EggSearch { egg, range } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator5::new(
+                    egg.generator.initial_advances,
+                    egg.generator.max_advances,
+                    egg.generator.delay,
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                let searcher = Searcher5::new(&generator, &profile);
+
+                let states = range.run(&searcher, &profile, quiet)?;
+                Ok(Export::new(&states).with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EventSearch {
+                profile,
+                filter,
+                generator,
+                file,
+                range,
+            } => {
+                let profile = load_profile(&profile)?;
+                let filter = state_filter(&filter)?;
+                let pgf = PGF::from(read_wondercard(&file, PGF_SIZE)?.as_slice());
+                let generator = EventGenerator5::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    &pgf,
+                    &profile,
+                    &filter,
+                );
+                let searcher = Searcher5::new(&generator, &profile);
+
+                let states = range.run(&searcher, &profile, quiet)?;
+                Ok(Export::new(&states).with_species(pgf.species, 0))
+            }
+            Command::DreamRadarGenerate { radar, seed } => {
+                let profile = load_bw2_profile(&radar.profile)?;
+                let filter = state_filter(&radar.filter)?;
+                let templates = radar.templates()?;
+                let generator = DreamRadarGenerator::new(
+                    radar.initial_advances,
+                    radar.max_advances,
+                    radar.badges,
+                    &templates,
+                    &profile,
+                    &filter,
+                );
+
+                let target = generator.radar_template.base;
+                Ok(Export::new(&generator.generate(seed))
+                    .with_species(target.get_species(), target.get_form()))
+            }
+            Command::DreamRadarSearch { radar, range } => {
+                let profile = load_bw2_profile(&radar.profile)?;
+                let filter = state_filter(&radar.filter)?;
+                let templates = radar.templates()?;
+                let generator = DreamRadarGenerator::new(
+                    radar.initial_advances,
+                    radar.max_advances,
+                    radar.badges,
+                    &templates,
+                    &profile,
+                    &filter,
+                );
+                let searcher = Searcher5::new(&generator, &profile);
+
+                let target = generator.radar_template.base;
+                let states = range.run(&searcher, &profile, quiet)?;
+                Ok(Export::new(&states).with_species(target.get_species(), target.get_form()))
+            }
+            Command::HiddenGrottoGenerate {
+                profile,
+                generator,
+                seed,
+                location,
+                power_level,
+                slots,
+                groups,
+                genders,
+            } => {
+                let profile = load_bw2_profile(&profile)?;
+                let areas = encounters5::get_hidden_grotto_encounters();
+                let area = areas
+                    .iter()
+                    .find(|area| area.location == location)
+                    .ok_or_else(|| {
+                        let locations = areas
+                            .iter()
+                            .map(|area| area.location as u16)
+                            .collect::<Vec<_>>();
+                        let names = translator::get_locations(&locations, Game::BW2);
+                        let available = locations
+                            .iter()
+                            .zip(names)
+                            .map(|(location, name)| format!("  {location}: {name}"))
+                            .collect::<Vec<_>>();
+                        format!(
+                            "location {location} has no hidden grotto, available locations:\n{}",
+                            available.join("\n")
+                        )
+                    })?;
+                let filter =
+                    HiddenGrottoFilter::new(flags(&slots), flags(&genders), flags(&groups));
+                let generator = HiddenGrottoGenerator::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    power_level,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed, area))
+                    .with_location(area.location as u16, Game::BW2))
+            }
+            Command::IdGenerate { id, seed } => {
+                let profile = load_profile(&id.profile)?;
+                let filter = id.filter.filter(vec![]);
+                let generator =
+                    IDGenerator5::new(id.initial_advances, id.max_advances, &profile, &filter);
+
+                Ok(Export::new(&generator.generate(
+                    seed,
+                    id.pid.unwrap_or_default(),
+                    id.pid.is_some(),
+                    id.check_xor,
+                )))
+            }
+            Command::IdSearch { id, range } => {
+                let profile = load_profile(&id.profile)?;
+                let filter = id.filter.filter(vec![]);
+                let generator = IDSearcherGenerator5::new(
+                    IDGenerator5::new(id.initial_advances, id.max_advances, &profile, &filter),
+                    id.pid.unwrap_or_default(),
+                    id.pid.is_some(),
+                    id.check_xor,
+                );
+                let searcher = Searcher5::new(&generator, &profile);
+
+                Ok(Export::new(&range.run(&searcher, &profile, quiet)?))
+            }
+            Command::MtSeedSearch {
+                profile,
+                min_ivs,
+                max_ivs,
+                initial_advances,
+                max_advances,
+                threads,
+            } => {
+                let profile = load_profile(&profile)?;
+                let max_ivs = max_ivs.unwrap_or(min_ivs);
+                if min_ivs.iter().zip(max_ivs).any(|(&min, max)| min > max) {
+                    return Err("minimum IVs must not exceed the maximum IVs".to_string());
+                }
+
+                let searcher = MTSeedSearcher5::new(
+                    min_ivs,
+                    max_ivs,
+                    initial_advances,
+                    max_advances,
+                    profile.version,
+                )
+                .map_err(|e| e.to_string())?;
+                let states = search::run(&searcher, 0x10000, quiet, |s| {
+                    s.start_search(search::threads(threads))
+                });
+                Ok(Export::new(&states))
+            }
+            Command::ProfileSearch(args) => {
+                let profile = load_profile(&args.profile)?;
+                if let Some(min_ivs) = args.min_ivs {
+                    let max_ivs = args.max_ivs.unwrap_or(min_ivs);
+                    if min_ivs.iter().zip(max_ivs).any(|(&min, max)| min > max) {
+                        return Err("minimum IVs must not exceed the maximum IVs".to_string());
+                    }
+                    let criteria = ProfileIVCriteria5::new(min_ivs, max_ivs, profile.version);
+                    args.run(&profile, criteria, quiet)
+                } else if !args.needles.is_empty() {
+                    let criteria = ProfileNeedleCriteria5::new(
+                        args.needles.clone(),
+                        profile.version,
+                        args.unova_link,
+                        profile.get_memory_link(),
+                    );
+                    args.run(&profile, criteria, quiet)
+                } else if let Some(seed) = args.initial_seed {
+                    args.run(&profile, ProfileSeedCriteria5::new(seed), quiet)
+                } else {
+                    Err("one of --min-ivs, --needles or --initial-seed is required".to_string())
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::args::{
+    parse_egg_seed, parse_hex_u32, parse_lead, AreaArgs, Compatibility, DaycareArgs, FilterArgs,
+    GeneratorArgs, TemplateArgs, TrainerArgs,
+};
+use clap::Subcommand;
+use pokefinder_rs_core::enums::{Encounter, Game, Lead};
+use pokefinder_rs_core::gen6::filters::{StateFilter6, WildStateFilter6};
+use pokefinder_rs_core::gen6::generators::{EggGenerator6, StaticGenerator6, WildGenerator6};
+use pokefinder_rs_core::gen6::Profile6;
+use pokefinder_rs_core::util::export::Export;
+
+// Subcommands are named like the other generations even though these only generate
+#[allow(clippy::enum_variant_names)]
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate static encounters from a Mersenne Twister seed
+    StaticGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        template: TemplateArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+    },
+    /// Generate wild encounters from a Mersenne Twister seed
+    WildGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        area: AreaArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+    },
+    /// Generate eggs from the TinyMT egg seed
+    EggGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        daycare: DaycareArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Egg seed as four comma separated values in hexadecimal, [0] first
+        #[arg(long, value_parser = parse_egg_seed)]
+        seed: [u32; 4],
+        #[arg(long, value_enum, default_value_t = Compatibility::Low)]
+        compatibility: Compatibility,
+        /// The oval charm is unlocked
+        #[arg(long)]
+        oval_charm: bool,
+    },
+}
+
+fn profile(trainer: &TrainerArgs) -> Result<Profile6, String> {
+    trainer.check(Game::GEN6)?;
+    Ok(Profile6::new(
+        "-".to_string(),
+        trainer.version,
+        trainer.tid,
+        trainer.sid,
+        trainer.shiny_charm,
+    ))
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter6, String> {
+    let values = filter.values()?;
+    Ok(StateFilter6::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+fn wild_state_filter(filter: &FilterArgs) -> Result<WildStateFilter6, String> {
+    let values = filter.values()?;
+    Ok(WildStateFilter6::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+        values.encounter_slots,
+    ))
+}
+
+impl Command {
+    pub fn run(self) -> Result<Export, String> {
+        match self {
+            Command::StaticGenerate {
+                trainer,
+                filter,
+                generator,
+                template,
+                seed,
+                lead,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = state_filter(&filter)?;
+                let template = template.template(trainer.version)?;
+                let generator = StaticGenerator6::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    lead,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, &template))
+                    .with_species(template.species, template.form))
+            }
+            Command::WildGenerate {
+                trainer,
+                filter,
+                generator,
+                area,
+                seed,
+                lead,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = wild_state_filter(&filter)?;
+                // Surfing and the Super Rod pick from 5 slots, everything else from 12
+                let slots = match area.encounter {
+                    Encounter::Surfing | Encounter::SuperRod => 5,
+                    _ => 12,
+                };
+                let encounter_area = area.area(trainer.version, slots)?;
+                let generator = WildGenerator6::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    area.encounter,
+                    lead,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, &encounter_area)))
+            }
+            Command::EggGenerate {
+                trainer,
+                filter,
+                daycare,
+                generator,
+                seed,
+                compatibility,
+                oval_charm,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = state_filter(&filter)?;
+                let daycare = daycare.daycare()?;
+                let generator = EggGenerator6::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    compatibility.chance(oval_charm),
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::args::{
+    parse_egg_seed, parse_hex_u32, parse_lead, AreaArgs, Compatibility, DaycareArgs, FilterArgs,
+    GeneratorArgs, IDFilterArgs, TemplateArgs, TrainerArgs,
+};
+use clap::Subcommand;
+use pokefinder_rs_core::enums::{Game, Lead};
+use pokefinder_rs_core::gen7::filters::{StateFilter7, WildStateFilter7};
+use pokefinder_rs_core::gen7::generators::{
+    EggGenerator7, IDGenerator7, StaticGenerator7, WildGenerator7,
+};
+use pokefinder_rs_core::gen7::Profile7;
+use pokefinder_rs_core::util::export::Export;
+
+// Subcommands are named like the other generations even though these only generate
+#[allow(clippy::enum_variant_names)]
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate static encounters from an SFMT seed
+    StaticGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        template: TemplateArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+    },
+    /// Generate wild encounters from an SFMT seed
+    WildGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        area: AreaArgs,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+        /// Length of the SOS chain, 0 for a regular encounter
+        #[arg(long, default_value_t = 0)]
+        chain: u8,
+    },
+    /// Generate eggs from the TinyMT egg seed
+    EggGenerate {
+        #[command(flatten)]
+        trainer: TrainerArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        daycare: DaycareArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        /// Egg seed as four comma separated values in hexadecimal, [0] first
+        #[arg(long, value_parser = parse_egg_seed)]
+        seed: [u32; 4],
+        #[arg(long, value_enum, default_value_t = Compatibility::Low)]
+        compatibility: Compatibility,
+        /// The oval charm is unlocked
+        #[arg(long)]
+        oval_charm: bool,
+    },
+    /// Generate trainer IDs from an SFMT seed
+    IdGenerate {
+        #[command(flatten)]
+        filter: IDFilterArgs,
+        /// Six digit trainer IDs shown in game to keep
+        #[arg(long, value_delimiter = ',')]
+        display_tids: Vec<u32>,
+        /// First advance to report
+        #[arg(long, default_value_t = 0)]
+        initial_advances: u32,
+        /// Number of advances to generate
+        #[arg(long, default_value_t = 1000)]
+        max_advances: u32,
+        /// Initial seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        seed: u32,
+    },
+}
+
+fn profile(trainer: &TrainerArgs) -> Result<Profile7, String> {
+    trainer.check(Game::GEN7)?;
+    Ok(Profile7::new(
+        "-".to_string(),
+        trainer.version,
+        trainer.tid,
+        trainer.sid,
+        trainer.shiny_charm,
+    ))
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter7, String> {
+    let values = filter.values()?;
+    Ok(StateFilter7::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+fn wild_state_filter(filter: &FilterArgs) -> Result<WildStateFilter7, String> {
+    let values = filter.values()?;
+    Ok(WildStateFilter7::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+        values.encounter_slots,
+    ))
+}
+
+impl Command {
+    pub fn run(self) -> Result<Export, String> {
+        match self {
+            Command::StaticGenerate {
+                trainer,
+                filter,
+                generator,
+                template,
+                seed,
+                lead,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = state_filter(&filter)?;
+                let template = template.template(trainer.version)?;
+                let generator = StaticGenerator7::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    lead,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, &template))
+                    .with_species(template.species, template.form))
+            }
+            Command::WildGenerate {
+                trainer,
+                filter,
+                generator,
+                area,
+                seed,
+                lead,
+                chain,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = wild_state_filter(&filter)?;
+                // Every encounter type picks from the same 10 slots
+                let encounter_area = area.area(trainer.version, 10)?;
+                let generator = WildGenerator7::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    area.encounter,
+                    lead,
+                    chain,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed, &encounter_area)))
+            }
+            Command::EggGenerate {
+                trainer,
+                filter,
+                daycare,
+                generator,
+                seed,
+                compatibility,
+                oval_charm,
+            } => {
+                let profile = profile(&trainer)?;
+                let filter = state_filter(&filter)?;
+                let daycare = daycare.daycare()?;
+                let generator = EggGenerator7::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    compatibility.chance(oval_charm),
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                Ok(Export::new(&generator.generate(seed))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+            Command::IdGenerate {
+                filter,
+                display_tids,
+                initial_advances,
+                max_advances,
+                seed,
+            } => {
+                let filter = filter.filter(display_tids);
+                let generator = IDGenerator7::new(initial_advances, max_advances, &filter);
+                Ok(Export::new(&generator.generate(seed)))
+            }
+        }
+    }
+}
@@ -0,0 +1,833 @@
+use crate::args::{
+    lookup_translated, parse_encounter, parse_hex_u32, parse_hex_u64, parse_ivs, parse_lead,
+    read_wondercard, select_area, select_template, Compatibility, DaycareArgs, FilterArgs,
+    GeneratorArgs, ProfileArgs,
+};
+use crate::search;
+use clap::{Args, Subcommand};
+use pokefinder_rs_core::enums::{Encounter, Game, Lead};
+use pokefinder_rs_core::gen8::filters::{StateFilter8, UndergroundStateFilter, WildStateFilter8};
+use pokefinder_rs_core::gen8::generators::{
+    EggGenerator8, EventGenerator8, RaidGenerator, StaticGenerator8, UndergroundGenerator,
+    WildGenerator8,
+};
+use pokefinder_rs_core::gen8::searchers::{
+    recover_xorshift, RaidObservation, RaidSeedSearcher, Searcher8, SeedGenerator8,
+    StaticSearcherGenerator8, WildSearcherGenerator8, XorshiftObservation,
+};
+use pokefinder_rs_core::gen8::{den_loader, encounters8, EncounterArea8, Profile8, Raid, WB8};
+use pokefinder_rs_core::parents::{profile_loader_8, StaticTemplate, Template};
+use pokefinder_rs_core::util::export::{Export, ExportState};
+use pokefinder_rs_core::util::translator;
+use std::path::PathBuf;
+
+const ROAMERS: u8 = 4;
+
+/// Size of a WB8 wondercard
+const WB8_SIZE: usize = 0x2dc;
+
+/// Number of Sword and Shield dens
+const DENS: u16 = 276;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate BDSP static encounters from a Xorshift state
+    StaticGenerate {
+        #[command(flatten)]
+        fixed: StaticArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+    },
+    /// Search the advance range of a Xorshift state for BDSP static encounters matching the
+    /// filter
+    StaticSearch {
+        #[command(flatten)]
+        fixed: StaticArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Generate BDSP wild encounters from a Xorshift state
+    WildGenerate {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+    },
+    /// Search the advance range of a Xorshift state for BDSP wild encounters matching the
+    /// filter
+    WildSearch {
+        #[command(flatten)]
+        wild: WildArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Generate BDSP eggs from a Xorshift state
+    EggGenerate {
+        #[command(flatten)]
+        egg: EggArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+    },
+    /// Search the advance range of a Xorshift state for BDSP eggs matching the filter
+    EggSearch {
+        #[command(flatten)]
+        egg: EggArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Generate a BDSP wondercard gift from a Xorshift state
+    EventGenerate {
+        #[command(flatten)]
+        event: EventArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+    },
+    /// Search the advance range of a Xorshift state for a BDSP wondercard gift matching the
+    /// filter
+    EventSearch {
+        #[command(flatten)]
+        event: EventArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Generate Grand Underground encounters from a Xorshift state
+    UndergroundGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        state: XorshiftArgs,
+        /// Lead ability, Synchronize is written as synchronize:<nature>
+        #[arg(long, value_parser = parse_lead, default_value = "none")]
+        lead: Lead,
+        /// Room id from 2 (Spacious Cave) to 19 (Typhlo Cavern)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(2..=19))]
+        room: u8,
+        /// Story progress from 1 (Underground unlocked) to 6 (National Dex)
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=6))]
+        story_flag: u8,
+        /// Level range from 0 (0-1 badges) to 8 (National Dex)
+        #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=8))]
+        level_flag: u8,
+        /// Diglett boost is active
+        #[arg(long)]
+        diglett: bool,
+        /// National dex numbers to keep, defaults to every species of the room
+        #[arg(long, value_delimiter = ',')]
+        species: Vec<u16>,
+    },
+    /// Recover the BDSP Xorshift state from player blinks or an encountered Pokemon
+    XorshiftRecover {
+        /// Observed blinks in order: 0 single, 1 double
+        #[arg(
+            long,
+            value_delimiter = ',',
+            requires = "intervals",
+            conflicts_with = "ec"
+        )]
+        blinks: Vec<u8>,
+        /// Advances between each blink and the previous one, the first value is ignored
+        #[arg(long, value_delimiter = ',')]
+        intervals: Vec<u32>,
+        /// Encryption constant of the encountered Pokemon in hexadecimal
+        #[arg(long, value_parser = parse_hex_u32)]
+        ec: Option<u32>,
+        /// IVs of the encountered Pokemon as HP,Atk,Def,SpA,SpD,Spe
+        #[arg(long, value_parser = parse_ivs, requires = "ec")]
+        ivs: Option<[u8; 6]>,
+        /// Advance of the output that generated the encryption constant
+        #[arg(long, default_value_t = 0)]
+        advance: u32,
+    },
+    /// Generate Sword and Shield raid Pokemon from a den seed
+    RaidGenerate {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        generator: GeneratorArgs,
+        #[command(flatten)]
+        raid: RaidArgs,
+        /// Den seed in hexadecimal
+        #[arg(long, value_parser = parse_hex_u64)]
+        seed: u64,
+        /// Level of the raid Pokemon
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=100))]
+        level: u8,
+    },
+    /// Search the den seed of a Sword and Shield raid from observed raid Pokemon
+    RaidSearch {
+        #[command(flatten)]
+        profile: ProfileArgs,
+        #[command(flatten)]
+        raid: RaidArgs,
+        /// IVs of the observed Pokemon as HP,Atk,Def,SpA,SpD,Spe
+        #[arg(long, value_parser = parse_ivs)]
+        ivs: [u8; 6],
+        /// Ability slot of the observed Pokemon, 2 is the hidden ability
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+        ability: u8,
+        /// Nature of the observed Pokemon by name or index
+        #[arg(long)]
+        nature: String,
+        /// Raid slot of a second Pokemon of the same den
+        #[arg(long, requires_all = ["second_ivs", "second_ability", "second_nature", "days"])]
+        second_slot: Option<usize>,
+        /// IVs of the second Pokemon as HP,Atk,Def,SpA,SpD,Spe
+        #[arg(long, value_parser = parse_ivs)]
+        second_ivs: Option<[u8; 6]>,
+        /// Ability slot of the second Pokemon, 2 is the hidden ability
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+        second_ability: Option<u8>,
+        /// Nature of the second Pokemon by name or index
+        #[arg(long)]
+        second_nature: Option<String>,
+        /// Days skipped between the first and the second Pokemon
+        #[arg(long)]
+        days: Option<u32>,
+        /// Maximum number of rejected rolls to consider
+        #[arg(long, default_value_t = 0)]
+        rerolls: u8,
+        /// Number of search threads, defaults to every available core
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+}
+
+/// Xorshift state shared by the BDSP generators and searchers
+#[derive(Args)]
+pub struct XorshiftArgs {
+    /// Upper half of the Xorshift state in hexadecimal
+    #[arg(long, value_parser = parse_hex_u64)]
+    seed0: u64,
+    /// Lower half of the Xorshift state in hexadecimal
+    #[arg(long, value_parser = parse_hex_u64)]
+    seed1: u64,
+}
+
+/// Encounter options shared by the static generator and searcher
+#[derive(Args)]
+pub struct StaticArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+    /// 0 starters, 1 gifts, 2 fossils, 3 stationary, 4 roamers, 5 legends,
+    /// 6 Ramanas Park (Pure Space), 7 Ramanas Park (Strange Space), 8 mythics
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=8))]
+    category: u8,
+    /// National dex number of the Pokemon
+    #[arg(long)]
+    species: u16,
+}
+
+/// Encounter options shared by the wild generator and searcher
+#[derive(Args)]
+pub struct WildArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// Lead ability, Synchronize is written as synchronize:<nature>
+    #[arg(long, value_parser = parse_lead, default_value = "none")]
+    lead: Lead,
+    /// One of grass, surfing, old-rod, good-rod or super-rod
+    #[arg(long, value_parser = parse_encounter, default_value = "grass")]
+    encounter: Encounter,
+    /// Location id of the encounter area, an invalid id lists the valid ones
+    #[arg(long)]
+    location: u8,
+    /// 0 morning, 1 day, 2 night
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=2))]
+    time: u8,
+    /// Poke Radar slots are active
+    #[arg(long)]
+    radar: bool,
+    /// Swarm slots are active
+    #[arg(long)]
+    swarm: bool,
+}
+
+/// Daycare options shared by the egg generator and searcher
+#[derive(Args)]
+pub struct EggArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[command(flatten)]
+    daycare: DaycareArgs,
+    #[arg(long, value_enum, default_value_t = Compatibility::Low)]
+    compatibility: Compatibility,
+}
+
+/// Wondercard options shared by the event generator and searcher
+#[derive(Args)]
+pub struct EventArgs {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// Path of a .wb8 wondercard
+    #[arg(long)]
+    file: PathBuf,
+}
+
+/// Raid slot shared by the raid generator and seed searcher
+#[derive(Args)]
+pub struct RaidArgs {
+    /// Den index from 0 to 275, the order of the Sword and Shield den list
+    #[arg(long, value_parser = clap::value_parser!(u16).range(0..DENS as i64), required_unless_present = "event")]
+    den: Option<u16>,
+    /// Use the rare table of the den
+    #[arg(long)]
+    rare: bool,
+    /// Directory holding the nests_event.json of the current event den
+    #[arg(long, conflicts_with_all = ["den", "rare"])]
+    event: Option<PathBuf>,
+    /// Raid slot of the den, an invalid slot lists the valid ones
+    #[arg(long)]
+    slot: usize,
+}
+
+/// Den seed found by the raid seed searcher
+struct DenSeed(u64);
+
+impl ExportState for DenSeed {
+    fn headers() -> Vec<&'static str> {
+        vec!["Seed"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(format!("{:0>16X}", self.0));
+    }
+}
+
+/// Xorshift state recovered from observations
+struct XorshiftState(u64, u64);
+
+impl ExportState for XorshiftState {
+    fn headers() -> Vec<&'static str> {
+        vec!["Seed 0", "Seed 1"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(format!("{:0>16X}", self.0));
+        row.push(format!("{:0>16X}", self.1));
+    }
+}
+
+fn load_profile(args: &ProfileArgs) -> Result<Profile8, String> {
+    let mut profile = args.select(profile_loader_8::get_profiles())?;
+    args.apply(&mut profile.version, &mut profile.tid, &mut profile.sid);
+    if (profile.version & Game::BDSP) == Game::NONE {
+        return Err("the profile version is not Brilliant Diamond or Shining Pearl".to_string());
+    }
+    Ok(profile)
+}
+
+fn load_swsh_profile(args: &ProfileArgs) -> Result<Profile8, String> {
+    let mut profile = args.select(profile_loader_8::get_profiles())?;
+    args.apply(&mut profile.version, &mut profile.tid, &mut profile.sid);
+    if (profile.version & Game::SWSH) == Game::NONE {
+        return Err("the profile version is not Sword or Shield".to_string());
+    }
+    Ok(profile)
+}
+
+fn state_filter(filter: &FilterArgs) -> Result<StateFilter8, String> {
+    let values = filter.values()?;
+    Ok(StateFilter8::new(
+        values.gender,
+        values.ability,
+        values.shiny,
+        values.skip,
+        values.min,
+        values.max,
+        values.natures,
+        values.powers,
+    ))
+}
+
+/// Runs `searcher` over the advance window of its generator
+fn run_search<G: SeedGenerator8>(
+    searcher: &Searcher8<G>,
+    generator: &G,
+    state: &XorshiftArgs,
+    threads: Option<usize>,
+    quiet: bool,
+) -> Vec<G::State> {
+    let total = generator.max_advances().saturating_add(1);
+    search::run(searcher, total, quiet, |s| {
+        s.start_search(search::threads(threads), state.seed0, state.seed1)
+    })
+}
+
+impl StaticArgs {
+    fn template(&self, profile: &Profile8) -> Result<&'static StaticTemplate, String> {
+        select_template(
+            encounters8::get_static_encounters(self.category as usize),
+            self.species,
+            profile.version,
+        )
+    }
+}
+
+impl WildArgs {
+    fn filter(&self) -> Result<WildStateFilter8, String> {
+        let values = self.filter.values()?;
+        Ok(WildStateFilter8::new(
+            values.gender,
+            values.ability,
+            values.shiny,
+            values.skip,
+            values.min,
+            values.max,
+            values.natures,
+            values.powers,
+            values.encounter_slots,
+        ))
+    }
+
+    fn area(&self, profile: &Profile8) -> Result<EncounterArea8, String> {
+        let areas = encounters8::get_encounters(
+            self.encounter,
+            self.time,
+            self.radar,
+            self.swarm,
+            [0, 0],
+            profile,
+        );
+        select_area(areas, self.location, profile.version)
+    }
+}
+
+impl EventArgs {
+    fn wb8(&self) -> Result<WB8, String> {
+        Ok(WB8::from(read_wondercard(&self.file, WB8_SIZE)?.as_slice()))
+    }
+}
+
+impl RaidArgs {
+    /// Picks the raid slot out of the den or the event den
+    fn raid(&self, version: Game) -> Result<Raid, String> {
+        let raids = match (&self.event, self.den) {
+            (Some(directory), _) => {
+                den_loader::init(directory.clone());
+                den_loader::get_event()
+                    .lock()
+                    .unwrap()
+                    .get_raids(version)
+                    .to_vec()
+            }
+            (None, Some(den)) => den_loader::get_den(den as usize, self.rare as usize)
+                .get_raids(version)
+                .to_vec(),
+            (None, None) => unreachable!("clap requires either a den or an event"),
+        };
+
+        raids.get(self.slot).copied().ok_or_else(|| {
+            let names = raids
+                .iter()
+                .enumerate()
+                .map(|(i, raid)| {
+                    format!(
+                        "  {i}: {} {}",
+                        raid.get_star_display(),
+                        translator::get_species(raid.base.get_species())
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "slot {} is not in this den, available slots:\n{}",
+                self.slot,
+                names.join("\n")
+            )
+        })
+    }
+}
+
+impl Command {
+    pub fn run(self, quiet: bool) -> Result<Export, String> {
+        match self {
+            Command::StaticGenerate {
+                fixed,
+                generator,
+                state,
+            } => {
+                let profile = load_profile(&fixed.profile)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = fixed.template(&profile)?;
+                let generator = StaticGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    fixed.lead,
+                    &profile,
+                    &filter,
+                );
+                let states = if fixed.category == ROAMERS {
+                    generator.generate_roamer(state.seed0, state.seed1, template)
+                } else {
+                    generator.generate(state.seed0, state.seed1, template)
+                };
+
+                Ok(Export::new(&states).with_species(template.get_species(), template.get_form()))
+            }
+            Command::StaticSearch {
+                fixed,
+                generator,
+                state,
+                threads,
+            } => {
+                let profile = load_profile(&fixed.profile)?;
+                let filter = state_filter(&fixed.filter)?;
+                let template = fixed.template(&profile)?;
+                let generator = StaticSearcherGenerator8::new(
+                    StaticGenerator8::new(
+                        generator.initial_advances,
+                        generator.max_advances,
+                        generator.delay,
+                        fixed.lead,
+                        &profile,
+                        &filter,
+                    ),
+                    template,
+                    fixed.category == ROAMERS,
+                );
+                let searcher = Searcher8::new(&generator);
+
+                let states = run_search(&searcher, &generator, &state, threads, quiet);
+                Ok(Export::new(&states).with_species(template.get_species(), template.get_form()))
+            }
+            Command::WildGenerate {
+                wild,
+                generator,
+                state,
+            } => {
+                let profile = load_profile(&wild.profile)?;
+                let filter = wild.filter()?;
+                let area = wild.area(&profile)?;
+                let generator = WildGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    wild.encounter,
+                    wild.lead,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(
+                    state.seed0,
+                    state.seed1,
+                    &area,
+                )))
+            }
+            Command::WildSearch {
+                wild,
+                generator,
+                state,
+                threads,
+            } => {
+                let profile = load_profile(&wild.profile)?;
+                let filter = wild.filter()?;
+                let area = wild.area(&profile)?;
+                let generator = WildSearcherGenerator8::new(
+                    WildGenerator8::new(
+                        generator.initial_advances,
+                        generator.max_advances,
+                        generator.delay,
+                        wild.encounter,
+                        wild.lead,
+                        &profile,
+                        &filter,
+                    ),
+                    &area,
+                );
+                let searcher = Searcher8::new(&generator);
+
+                Ok(Export::new(&run_search(
+                    &searcher, &generator, &state, threads, quiet,
+                )))
+            }
+            Command::EggGenerate {
+                egg,
+                generator,
+                state,
+            } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    egg.compatibility.chance(profile.oval_charm),
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(state.seed0, state.seed1))
+                    .with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EggSearch {
+                egg,
+                generator,
+                state,
+                threads,
+            } => {
+                let profile = load_profile(&egg.profile)?;
+                let filter = state_filter(&egg.filter)?;
+                let daycare = egg.daycare.daycare()?;
+                let generator = EggGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    egg.compatibility.chance(profile.oval_charm),
+                    &daycare,
+                    &profile,
+                    &filter,
+                );
+                let searcher = Searcher8::new(&generator);
+
+                let states = run_search(&searcher, &generator, &state, threads, quiet);
+                Ok(Export::new(&states).with_species(daycare.get_egg_species(), 0))
+            }
+            Command::EventGenerate {
+                event,
+                generator,
+                state,
+            } => {
+                let profile = load_profile(&event.profile)?;
+                let filter = state_filter(&event.filter)?;
+                let wb8 = event.wb8()?;
+                let generator = EventGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    &wb8,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(state.seed0, state.seed1))
+                    .with_species(wb8.species, wb8.form))
+            }
+            Command::EventSearch {
+                event,
+                generator,
+                state,
+                threads,
+            } => {
+                let profile = load_profile(&event.profile)?;
+                let filter = state_filter(&event.filter)?;
+                let wb8 = event.wb8()?;
+                let generator = EventGenerator8::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    &wb8,
+                    &profile,
+                    &filter,
+                );
+                let searcher = Searcher8::new(&generator);
+
+                let states = run_search(&searcher, &generator, &state, threads, quiet);
+                Ok(Export::new(&states).with_species(wb8.species, wb8.form))
+            }
+            Command::UndergroundGenerate {
+                profile,
+                filter,
+                generator,
+                state,
+                lead,
+                room,
+                story_flag,
+                level_flag,
+                diglett,
+                species,
+            } => {
+                let profile = load_profile(&profile)?;
+                let area = encounters8::get_underground_encounters(story_flag, diglett, &profile)
+                    .into_iter()
+                    .find(|area| area.location == room)
+                    .ok_or_else(|| format!("room {room} has no encounters at this story flag"))?;
+
+                // The filter binary searches the species, so they have to be sorted
+                let mut species = if species.is_empty() {
+                    area.get_species()
+                } else {
+                    species
+                };
+                species.sort_unstable();
+
+                let values = filter.values()?;
+                let filter = UndergroundStateFilter::new(
+                    values.gender,
+                    values.ability,
+                    values.shiny,
+                    values.skip,
+                    values.min,
+                    values.max,
+                    values.natures,
+                    values.powers,
+                    &species,
+                );
+                let generator = UndergroundGenerator::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    lead,
+                    diglett,
+                    level_flag,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(
+                    state.seed0,
+                    state.seed1,
+                    &area,
+                )))
+            }
+            Command::XorshiftRecover {
+                blinks,
+                intervals,
+                ec,
+                ivs,
+                advance,
+            } => {
+                let observations = match ec {
+                    Some(ec) => XorshiftObservation::from_pokemon(
+                        advance,
+                        ec,
+                        ivs.unwrap_or_default(),
+                        ivs.is_none(),
+                    ),
+                    None if blinks.is_empty() => {
+                        return Err("either --blinks or --ec is required".to_string())
+                    }
+                    None if blinks.len() != intervals.len() => {
+                        return Err("every blink needs an interval".to_string())
+                    }
+                    None => XorshiftObservation::from_blinks(&blinks, &intervals),
+                };
+
+                let states = recover_xorshift(&observations)
+                    .map_err(|e| e.to_string())?
+                    .into_iter()
+                    .map(|(seed0, seed1)| XorshiftState(seed0, seed1))
+                    .collect::<Vec<_>>();
+                Ok(Export::new(&states))
+            }
+            Command::RaidGenerate {
+                profile,
+                filter,
+                generator,
+                raid,
+                seed,
+                level,
+            } => {
+                let profile = load_swsh_profile(&profile)?;
+                let filter = state_filter(&filter)?;
+                let raid = raid.raid(profile.version)?;
+                let generator = RaidGenerator::new(
+                    generator.initial_advances,
+                    generator.max_advances,
+                    generator.delay,
+                    &profile,
+                    &filter,
+                );
+
+                Ok(Export::new(&generator.generate(seed, level, &raid))
+                    .with_species(raid.base.get_species(), raid.base.get_form()))
+            }
+            Command::RaidSearch {
+                profile,
+                raid,
+                ivs,
+                ability,
+                nature,
+                second_slot,
+                second_ivs,
+                second_ability,
+                second_nature,
+                days,
+                rerolls,
+                threads,
+            } => {
+                let profile = load_swsh_profile(&profile)?;
+                let first = RaidObservation::new(
+                    raid.raid(profile.version)?,
+                    ivs,
+                    ability,
+                    lookup_translated(translator::get_natures(), &nature, "nature")? as u8,
+                );
+                let second = match (second_slot, second_ivs, second_ability, second_nature, days) {
+                    (Some(slot), Some(ivs), Some(ability), Some(nature), Some(days)) => {
+                        let second = RaidArgs { slot, ..raid };
+                        Some((
+                            days,
+                            RaidObservation::new(
+                                second.raid(profile.version)?,
+                                ivs,
+                                ability,
+                                lookup_translated(translator::get_natures(), &nature, "nature")?
+                                    as u8,
+                            ),
+                        ))
+                    }
+                    _ => None,
+                };
+
+                let searcher =
+                    RaidSeedSearcher::new(first, second, rerolls).map_err(|e| e.to_string())?;
+                let mut result = Ok(());
+                let seeds = search::run(&searcher, searcher.get_max_progress(), quiet, |s| {
+                    result = s.start_search(search::threads(threads));
+                });
+                result.map_err(|e| e.to_string())?;
+
+                let states = seeds.into_iter().map(DenSeed).collect::<Vec<_>>();
+                Ok(Export::new(&states))
+            }
+        }
+    }
+}
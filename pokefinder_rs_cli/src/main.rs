@@ -0,0 +1,94 @@
+mod args;
+mod gen3;
+mod gen4;
+mod gen5;
+mod gen6;
+mod gen7;
+mod gen8;
+mod output;
+mod researcher;
+mod search;
+
+use clap::{Parser, Subcommand};
+use output::Format;
+use pokefinder_rs_core::parents::init_profile_loader;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Headless front end for the PokeFinder generators and searchers
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Directory holding the gen3/gen4/gen5/gen8 profile json files.
+    ///
+    /// Defaults to the current directory, the same place the UI stores them.
+    #[arg(long, global = true)]
+    profiles: Option<PathBuf>,
+    /// How the results are printed
+    #[arg(long, global = true, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Do not report search progress on stderr
+    #[arg(long, global = true)]
+    quiet: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Gen 3 generators and searchers
+    #[command(subcommand)]
+    Gen3(gen3::Command),
+    /// Gen 4 generators and searchers
+    #[command(subcommand)]
+    Gen4(gen4::Command),
+    /// Gen 5 generators and searchers
+    #[command(subcommand)]
+    Gen5(gen5::Command),
+    /// Gen 6 generators
+    #[command(subcommand)]
+    Gen6(gen6::Command),
+    /// Gen 7 generators
+    #[command(subcommand)]
+    Gen7(gen7::Command),
+    /// Gen 8 generators and searchers
+    #[command(subcommand)]
+    Gen8(gen8::Command),
+    /// Evaluate custom calculations over the values of a PRNG
+    Researcher(researcher::ResearcherArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let Some(profiles) = &cli.profiles {
+        // The loader creates empty profile files when the directory is missing, which a
+        // script pointing at the wrong path should not silently trigger
+        if !profiles.is_dir() {
+            eprintln!(
+                "error: profile directory {} does not exist",
+                profiles.display()
+            );
+            return ExitCode::FAILURE;
+        }
+        init_profile_loader(profiles.to_string_lossy().to_string());
+    }
+
+    let results = match cli.command {
+        Command::Gen3(command) => command.run(cli.quiet),
+        Command::Gen4(command) => command.run(cli.quiet),
+        Command::Gen5(command) => command.run(cli.quiet),
+        Command::Gen6(command) => command.run(),
+        Command::Gen7(command) => command.run(),
+        Command::Gen8(command) => command.run(cli.quiet),
+        Command::Researcher(args) => args.run(),
+    };
+
+    match results.and_then(|export| output::print(&export, cli.format).map_err(|e| e.to_string())) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use clap::ValueEnum;
+use pokefinder_rs_core::util::export::{Export, ExportFormat};
+use std::io::{self, BufWriter, ErrorKind, Write};
+
+/// Output formats for the result rows
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for reading in a terminal
+    Table,
+    /// Comma separated values with a header row
+    Csv,
+    /// Array of objects keyed by the column headers
+    Json,
+}
+
+/// Writes `export` to stdout in the requested `format`
+pub fn print(export: &Export, format: Format) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut writer = BufWriter::new(stdout.lock());
+    let result = match format {
+        Format::Table => write_table(export, &mut writer),
+        Format::Csv => export.write(&mut writer, ExportFormat::Csv),
+        Format::Json => export.write(&mut writer, ExportFormat::Json),
+    }
+    .and_then(|_| writer.flush());
+
+    // Piping into tools like `head` closes stdout early, which is not an error for us
+    match result {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+fn write_table(export: &Export, writer: &mut impl Write) -> io::Result<()> {
+    let mut widths = export
+        .headers()
+        .iter()
+        .map(|h| h.chars().count())
+        .collect::<Vec<_>>();
+    for row in export.rows() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(export.headers()).chain(export.rows().iter().map(Vec::as_slice)) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::args::parse_hex_u64;
+use clap::{Args, ValueEnum};
+use pokefinder_rs_core::parents::generators::{Calculation, ResearcherGenerator, ResearcherRng};
+use pokefinder_rs_core::util::export::Export;
+
+/// Number of custom calculations the researcher evaluates
+const CUSTOM: usize = 10;
+
+#[derive(Args)]
+pub struct ResearcherArgs {
+    #[arg(long, value_enum)]
+    rng: RngKind,
+    /// Seed in hexadecimal, the first half of the state for tiny-mt-state, xorshift and xoroshiro
+    #[arg(long, value_parser = parse_hex_u64)]
+    seed: u64,
+    /// Second half of the state in hexadecimal, for tiny-mt-state, xorshift and xoroshiro
+    #[arg(long, value_parser = parse_hex_u64)]
+    seed1: Option<u64>,
+    /// First advance to report
+    #[arg(long, default_value_t = 0)]
+    initial_advances: u32,
+    /// Number of advances to generate
+    #[arg(long, default_value_t = 1000)]
+    max_advances: u32,
+    /// Calculation filling the next custom column, e.g. "high32 % 25". Repeat for up to 10
+    /// columns, later calculations can refer to the earlier ones as custom1 to custom10
+    #[arg(long)]
+    custom: Vec<String>,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum RngKind {
+    /// Gen 3/4 LCRNG
+    Poke,
+    /// Gamecube LCRNG
+    Xd,
+    /// Gen 4 alternate LCRNG
+    Arng,
+    /// Gen 5 64bit LCRNG
+    Bw,
+    /// Mersenne Twister
+    Mt,
+    /// Tiny Mersenne Twister
+    TinyMt,
+    /// Tiny Mersenne Twister from its full 128bit state
+    TinyMtState,
+    /// BDSP Xorshift
+    Xorshift,
+    /// SwSh Xoroshiro
+    Xoroshiro,
+}
+
+impl ResearcherArgs {
+    fn rng(&self) -> Result<ResearcherRng, String> {
+        let seed32 = || {
+            u32::try_from(self.seed)
+                .map_err(|_| format!("seed {:X} does not fit in 32 bits", self.seed))
+        };
+        let seed1 = || {
+            self.seed1
+                .ok_or_else(|| "the PRNG needs both halves of its state, pass --seed1".to_string())
+        };
+
+        Ok(match self.rng {
+            RngKind::Poke => ResearcherRng::PokeRNG(seed32()?),
+            RngKind::Xd => ResearcherRng::XDRNG(seed32()?),
+            RngKind::Arng => ResearcherRng::ARNG(seed32()?),
+            RngKind::Bw => ResearcherRng::BWRNG(self.seed),
+            RngKind::Mt => ResearcherRng::MT(seed32()?),
+            RngKind::TinyMt => ResearcherRng::TinyMT(seed32()?),
+            RngKind::TinyMtState => {
+                let seed1 = seed1()?;
+                ResearcherRng::TinyMTState(
+                    (self.seed >> 32) as u32,
+                    self.seed as u32,
+                    (seed1 >> 32) as u32,
+                    seed1 as u32,
+                )
+            }
+            RngKind::Xorshift => ResearcherRng::Xorshift(self.seed, seed1()?),
+            RngKind::Xoroshiro => ResearcherRng::Xoroshiro(self.seed, seed1()?),
+        })
+    }
+
+    pub fn run(self) -> Result<Export, String> {
+        if self.custom.len() > CUSTOM {
+            return Err(format!(
+                "at most {CUSTOM} custom calculations are supported"
+            ));
+        }
+
+        let mut calculations: [Option<Calculation>; CUSTOM] = Default::default();
+        for (i, (calculation, expression)) in calculations.iter_mut().zip(&self.custom).enumerate()
+        {
+            *calculation = Some(
+                Calculation::new(expression, i)
+                    .ok_or_else(|| format!("invalid calculation '{expression}'"))?,
+            );
+        }
+
+        let generator =
+            ResearcherGenerator::new(self.initial_advances, self.max_advances, calculations);
+        Ok(Export::new(&generator.generate(self.rng()?)))
+    }
+}
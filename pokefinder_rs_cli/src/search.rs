@@ -0,0 +1,50 @@
+use pokefinder_rs_core::parents::searchers::SearcherT;
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Returns the requested number of search threads, defaulting to every available core
+pub fn threads(requested: Option<usize>) -> usize {
+    requested
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+}
+
+/// Runs `search` on `searcher` in a worker thread and collects every result.
+///
+/// `total` is the progress value the searcher reports once it is done. Progress is drawn on
+/// stderr while it is a terminal so the results on stdout can still be piped.
+pub fn run<S: SearcherT + Sync>(
+    searcher: &S,
+    total: u32,
+    quiet: bool,
+    search: impl FnOnce(&S) + Send,
+) -> Vec<S::State>
+where
+    S::State: Send,
+{
+    let report = !quiet && io::stderr().is_terminal();
+
+    let mut results = vec![];
+    thread::scope(|scope| {
+        let handle = scope.spawn(|| search(searcher));
+        while !handle.is_finished() {
+            thread::sleep(Duration::from_millis(250));
+            results.append(&mut searcher.results());
+            if report {
+                let percent = searcher.progress() as f64 / total.max(1) as f64 * 100.0;
+                eprint!("\rSearching... {percent:.1}% ({} results)", results.len());
+                let _ = io::stderr().flush();
+            }
+        }
+        // A panic in the worker is already reported by the default hook, so only the results
+        // matter
+        let _ = handle.join();
+    });
+    results.append(&mut searcher.results());
+    if report {
+        eprintln!("\rSearching... done ({} results)     ", results.len());
+    }
+
+    results
+}
@@ -1,6 +1,8 @@
 use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
 bitflags! {
-    #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+    #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Deserialize, Serialize)]
     pub struct Buttons: u16 {
         const NONE = 0;
         const R = 1;
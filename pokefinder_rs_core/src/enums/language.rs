@@ -23,4 +23,5 @@ pub enum Language {
     Japanese,
     Korean,
     Spanish,
+    Chinese,
 }
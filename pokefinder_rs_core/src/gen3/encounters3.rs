@@ -1,6 +1,7 @@
 use crate::enums::{Encounter, Game};
 use crate::gen3::{EncounterArea3, ShadowTemplate};
-use crate::parents::personal_loader::get_personal_table;
+use crate::parents::custom_loader;
+use crate::parents::personal_loader::resolve_personal_table;
 use crate::parents::{EncounterArea, Slot, StaticTemplate};
 use crate::resources::encounter_data_3::{
     CHANNEL, EMERALD, EVENTS, FIRERED, FOSSILS, GALES_COLO, GALES_COLO_SHADOW, GAME_CORNER, GIFTS,
@@ -119,7 +120,7 @@ pub fn get_encounters(encounter: Encounter, version: Game) -> Vec<EncounterArea3
 
     let data = util::decompress(compressed_data);
 
-    let info = get_personal_table(version);
+    let info = resolve_personal_table(version);
 
     let mut encounters = vec![];
 
@@ -234,12 +235,14 @@ pub fn get_encounters(encounter: Encounter, version: Game) -> Vec<EncounterArea3
         }
     }
 
+    custom_loader::apply_encounters(version, encounter, &mut encounters, EncounterArea3::new);
+
     encounters
 }
 
 pub fn get_poke_spot_encounters() -> Vec<EncounterArea> {
     let data = util::decompress(XD);
-    let info = get_personal_table(Game::GEN3);
+    let info = resolve_personal_table(Game::GEN3);
 
     let mut reader = StreamContainer::new(data);
 
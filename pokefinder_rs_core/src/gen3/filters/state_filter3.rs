@@ -42,7 +42,7 @@ impl StateFilter3 {
 
         if !self.base.powers[state.base.base.base.hidden_power as usize]
             || !self.base.natures[state.base.base.base.nature as usize]
-            || (self.base.shiny != 255 && self.base.shiny & state.base.base.base.nature == 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.base.shiny) == 0)
         {
             return false;
         }
@@ -66,7 +66,7 @@ impl StateFilter3 {
             || (self.base.gender != 255 && self.base.gender != state.base.gender)
             || (!self.base.powers[state.base.hidden_power as usize])
             || (!self.base.natures[state.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.shiny) == 0)
         {
             return false;
         }
@@ -84,7 +84,7 @@ impl StateFilter3 {
         !((self.base.ability != 255 && self.base.ability != state.base.ability)
             || (self.base.gender != 255 && self.base.gender != state.base.gender)
             || (!self.base.powers[state.base.hidden_power as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.shiny)) != 0))
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.shiny) == 0))
     }
 }
 
@@ -167,9 +167,9 @@ impl WildStateFilter3 {
         }
 
         if (self.ability != 255 && self.ability != state.base.base.ability)
-            || (self.gender != 255 && self.gender != state.base.base.ability)
-            || (!self.powers[state.base.base.shiny as usize])
-            || (self.shiny != 255 && (!(self.shiny & state.base.base.shiny)) != 0)
+            || (self.gender != 255 && self.gender != state.base.base.gender)
+            || (!self.powers[state.base.base.hidden_power as usize])
+            || (self.shiny != 255 && (self.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -187,7 +187,7 @@ impl WildStateFilter3 {
         !((self.ability != 255 && self.ability != state.base.base.ability)
             || (self.gender != 255 && self.gender != state.base.base.gender)
             || (!self.powers[state.base.base.hidden_power as usize])
-            || (self.shiny != 255 && (!(self.shiny & state.base.base.shiny)) != 0))
+            || (self.shiny != 255 && (self.shiny & state.base.base.shiny) == 0))
     }
 }
 
@@ -1,10 +1,10 @@
-use crate::enums::{Game, Method};
+use crate::enums::Method;
 use crate::gen3::filters::StateFilter3;
 use crate::gen3::states::EggState3;
 use crate::gen3::Profile3;
 use crate::parents::filters::Filter;
 use crate::parents::generators::EggGenerator;
-use crate::parents::personal_loader;
+use crate::parents::personal_loader::{self, PersonalTable};
 use crate::parents::{Daycare, PersonalInfo, Profile};
 use crate::rng::PokeRNG;
 use std::cmp::Ordering;
@@ -97,10 +97,10 @@ fn set_inheritance<const EMERALD: bool>(
 }
 
 #[inline]
-fn get_male(version: Game, species: u16) -> Option<&'static PersonalInfo> {
+fn get_male(personal: &PersonalTable, species: u16) -> Option<&PersonalInfo> {
     match species {
-        29 => Some(personal_loader::get_personal_info(version, 32, 0)),
-        314 => Some(personal_loader::get_personal_info(version, 313, 0)),
+        29 => Some(personal.get_personal_info(32, 0)),
+        314 => Some(personal.get_personal_info(313, 0)),
         _ => None,
     }
 }
@@ -117,6 +117,7 @@ pub struct EggGenerator3<'a, 'b, 'c> {
     iv2: u8,
     max_redraw: u8,
     min_redraw: u8,
+    personal: PersonalTable,
 }
 
 impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
@@ -168,6 +169,7 @@ impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
             iv2,
             max_redraw,
             min_redraw,
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
         }
     }
 
@@ -198,15 +200,10 @@ impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
     }
 
     pub fn generate_emerald_held(&self) -> Vec<EggState3> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut parent = 0;
         for i in 0..2 {
@@ -314,15 +311,10 @@ impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
     }
 
     pub fn generate_emerald_pickup(&self, held: Vec<EggState3>) -> Vec<EggState3> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut rng =
             PokeRNG::new_with_initial_advances(0, self.initial_advances_pickup + self.delay_pickup);
@@ -386,15 +378,10 @@ impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
     }
 
     pub fn generate_rsfrlg_held(&self, seed: u32) -> Vec<EggState3> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut rng = PokeRNG::new_with_initial_advances(seed, self.base.base.initial_advances);
 
@@ -443,15 +430,10 @@ impl<'a, 'b, 'c> EggGenerator3<'a, 'b, 'c> {
     }
 
     pub fn generate_rsfrlg_pickup(&self, seed: u32, held: Vec<EggState3>) -> Vec<EggState3> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut rng = PokeRNG::new_with_initial_advances(
             seed,
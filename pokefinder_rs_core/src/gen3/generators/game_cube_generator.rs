@@ -82,7 +82,7 @@ impl<'a, 'b> GameCubeGenerator<'a, 'b> {
     fn generate_channel(&self, seed: u32, static_template: &StaticTemplate) -> Vec<GeneratorState> {
         let mut states = vec![];
 
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
         const THRESHOLDS: [u16; 2] = [0x4000, 0x547a];
 
         let mut rng =
@@ -151,7 +151,7 @@ impl<'a, 'b> GameCubeGenerator<'a, 'b> {
         shadow_template: &ShadowTemplate,
     ) -> Vec<GeneratorState> {
         let mut states = vec![];
-        let info = shadow_template.get_info();
+        let info = &shadow_template.resolve_info();
 
         let mut rng =
             XDRNG::new_with_initial_advances(seed, self.base.initial_advances + self.base.delay);
@@ -240,7 +240,7 @@ impl<'a, 'b> GameCubeGenerator<'a, 'b> {
         shadow_template: &ShadowTemplate,
     ) -> Vec<GeneratorState> {
         let mut states = vec![];
-        let info = shadow_template.get_info();
+        let info = &shadow_template.resolve_info();
 
         let mut rng =
             XDRNG::new_with_initial_advances(seed, self.base.initial_advances + self.base.delay);
@@ -332,7 +332,7 @@ impl<'a, 'b> GameCubeGenerator<'a, 'b> {
         static_template: &StaticTemplate,
     ) -> Vec<GeneratorState> {
         let mut states = vec![];
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         let mut actual_tsv = self.base.tsv;
 
@@ -125,7 +125,7 @@ impl<'a, 'b> PokeSpotGenerator<'a, 'b> {
 
             for mut state in food.clone() {
                 let slot = &encounter_area.pokemon[state.encounter_slot as usize];
-                let info = slot.info;
+                let info = &slot.info;
 
                 let level = encounter_area
                     .calculate_level_with_prng_state(state.get_encounter_slot(), level_rand);
@@ -56,7 +56,7 @@ impl<'a, 'b> StaticGenerator3<'a, 'b> {
 
     pub fn generate(&self, seed: u32, static_template: &StaticTemplate) -> Vec<GeneratorState> {
         let mut states = vec![];
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         let mut rng = PokeRNG::new_with_initial_advances(
             seed,
@@ -1,4 +1,5 @@
 use crate::gen3::searchers::SeedSearcher;
+use crate::parents::searchers::SearcherT;
 use crate::rng::XDRNG;
 use std::sync::atomic::Ordering;
 use std::thread;
@@ -15,10 +16,20 @@ impl ChannelSeedSearcher {
         }
     }
 
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.base.searching.store(false, Ordering::SeqCst);
+    }
+
     pub fn get_progress(&self) -> u32 {
         self.base.progress.load(Ordering::SeqCst)
     }
 
+    /// Returns the seeds that matched every criteria
+    pub fn get_results(&self) -> Vec<u32> {
+        self.base.take_results()
+    }
+
     pub fn start_search(&self, threads: usize) {
         self.base.searching.store(true, Ordering::SeqCst);
 
@@ -38,6 +49,10 @@ impl ChannelSeedSearcher {
             }
             start = start.wrapping_add(split);
         }
+
+        for thread in thread_container {
+            thread.join().unwrap();
+        }
     }
 
     pub fn search(&self, start: u32, end: u32) {
@@ -103,3 +118,19 @@ impl ChannelSeedSearcher {
         true
     }
 }
+
+impl SearcherT for ChannelSeedSearcher {
+    type State = u32;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::enums::Game;
+use crate::gen3::searchers::{generate_team, search_battle_now, BattleNowCriteria, SeedSearcher};
+use crate::parents::searchers::SearcherT;
+use crate::rng::XDRNG;
+use std::sync::atomic::Ordering;
+
+/// Number of Battle Now teams the player is picked from
+const PLAYER_TEAMS: u16 = 5;
+/// Number of Battle Now teams the enemy is picked from
+const ENEMY_TEAMS: u16 = 5;
+
+/// Searches Colosseum seeds from the Battle Now teams shown on each reroll
+#[derive(Clone)]
+pub struct ColoSeedSearcher {
+    pub base: SeedSearcher<Vec<BattleNowCriteria>>,
+}
+
+impl ColoSeedSearcher {
+    /// Construct a new [`ColoSeedSearcher`] struct.
+    ///
+    /// Each criteria is one reroll, in the order they were rerolled.
+    pub fn new(criteria: Vec<BattleNowCriteria>) -> Self {
+        Self {
+            base: SeedSearcher::new(criteria),
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.base.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    pub fn get_progress(&self) -> u32 {
+        self.base.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the seeds after the last reroll that matched every criteria
+    pub fn get_results(&self) -> Vec<u32> {
+        self.base.take_results()
+    }
+
+    /// Searches every seed across `threads` threads, progress ends at 0x10000
+    pub fn start_search(&self, threads: usize) {
+        self.base
+            .search_all(threads, |criteria, rng| Self::search_seed(criteria, rng));
+    }
+
+    /// Continues the search from the results of a previous search after more rerolls
+    pub fn start_search_seeds(&self, seeds: &[u32]) {
+        self.base
+            .search_seeds(seeds, |criteria, rng| Self::search_seed(criteria, rng));
+    }
+
+    fn search_seed(criteria: &[BattleNowCriteria], rng: &mut XDRNG) -> bool {
+        search_battle_now(
+            Game::COLOSSEUM,
+            criteria,
+            rng,
+            |rng| {
+                // Colosseum picks the player team first and the teams may repeat
+                let player = (rng.next_u16() % PLAYER_TEAMS) as u8;
+                let enemy = (rng.next_u16() % ENEMY_TEAMS) as u8;
+                (player, enemy)
+            },
+            |rng| {
+                // Each side has its own trainer ID that its Pokemon are not allowed to be shiny for
+                let tsv = rng.next_u16() ^ rng.next_u16();
+                let player = generate_team(rng, tsv, 0);
+                let tsv = rng.next_u16() ^ rng.next_u16();
+                let enemy = generate_team(rng, tsv, 0);
+                (player, enemy)
+            },
+        )
+    }
+}
+
+impl SearcherT for ColoSeedSearcher {
+    type State = u32;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::enums::Game;
+use crate::gen3::searchers::{generate_team, search_battle_now, BattleNowCriteria, SeedSearcher};
+use crate::parents::searchers::SearcherT;
+use crate::rng::XDRNG;
+use std::sync::atomic::Ordering;
+
+/// Number of Battle Now teams each side is picked from
+const TEAMS: u16 = 5;
+
+/// Searches XD seeds from the Battle Now teams shown on each reroll
+#[derive(Clone)]
+pub struct GalesSeedSearcher {
+    pub base: SeedSearcher<Vec<BattleNowCriteria>>,
+}
+
+impl GalesSeedSearcher {
+    /// Construct a new [`GalesSeedSearcher`] struct.
+    ///
+    /// Each criteria is one reroll, in the order they were rerolled.
+    pub fn new(criteria: Vec<BattleNowCriteria>) -> Self {
+        Self {
+            base: SeedSearcher::new(criteria),
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.base.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    pub fn get_progress(&self) -> u32 {
+        self.base.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the seeds after the last reroll that matched every criteria
+    pub fn get_results(&self) -> Vec<u32> {
+        self.base.take_results()
+    }
+
+    /// Searches every seed across `threads` threads, progress ends at 0x10000
+    pub fn start_search(&self, threads: usize) {
+        self.base
+            .search_all(threads, |criteria, rng| Self::search_seed(criteria, rng));
+    }
+
+    /// Continues the search from the results of a previous search after more rerolls
+    pub fn start_search_seeds(&self, seeds: &[u32]) {
+        self.base
+            .search_seeds(seeds, |criteria, rng| Self::search_seed(criteria, rng));
+    }
+
+    fn search_seed(criteria: &[BattleNowCriteria], rng: &mut XDRNG) -> bool {
+        search_battle_now(
+            Game::GALES,
+            criteria,
+            rng,
+            |rng| {
+                let enemy = (rng.next_u16() % TEAMS) as u8;
+                let player = loop {
+                    let index = (rng.next_u16() % TEAMS) as u8;
+                    if index != enemy {
+                        break index;
+                    }
+                };
+                (player, enemy)
+            },
+            |rng| {
+                // Enemy trainer ID, the player Pokemon are compared against it as well
+                let tsv = rng.next_u16() ^ rng.next_u16();
+                // Each Pokemon starts with an unused PID draw
+                let enemy = generate_team(rng, tsv, 2);
+                let player = generate_team(rng, tsv, 2);
+                (player, enemy)
+            },
+        )
+    }
+}
+
+impl SearcherT for GalesSeedSearcher {
+    type State = u32;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -2,7 +2,7 @@ use crate::enums::{Game, Method, ShadowType, Shiny};
 use crate::gen3::filters::StateFilter3;
 use crate::gen3::{shadow_lock, Profile3, ShadowTemplate};
 use crate::parents::filters::Filter;
-use crate::parents::searchers::Searcher;
+use crate::parents::searchers::{Searcher, SearcherT};
 use crate::parents::states::SearcherState;
 use crate::parents::{PersonalInfo, Profile, StaticTemplate, Template};
 use crate::rng::{lcrng_reverse, XDRNG, XDRNGR};
@@ -117,6 +117,7 @@ impl GameCubeSearcher {
         shadow_template: &'static ShadowTemplate,
     ) {
         self.searching.store(true, Ordering::SeqCst);
+        let info = shadow_template.resolve_info();
 
         for hp in min[0]..=max[0] {
             for atk in min[1]..=max[1] {
@@ -139,6 +140,7 @@ impl GameCubeSearcher {
                                         spd,
                                         spe,
                                         shadow_template,
+                                        &info,
                                     );
                                 } else {
                                     states = self.search_gales_shadow(
@@ -149,6 +151,7 @@ impl GameCubeSearcher {
                                         spd,
                                         spe,
                                         shadow_template,
+                                        &info,
                                     );
                                 }
 
@@ -175,6 +178,7 @@ impl GameCubeSearcher {
             return;
         }
 
+        let info = static_template.resolve_info();
         let tsv = match static_template.species {
             25 | 251 => 31121,
             250 => 10048,
@@ -200,6 +204,7 @@ impl GameCubeSearcher {
                                     spe,
                                     tsv,
                                     static_template,
+                                    &info,
                                 );
 
                                 self.results.lock().unwrap().append(&mut states);
@@ -218,7 +223,7 @@ impl GameCubeSearcher {
         max_spd: u8,
         static_template: &'static StaticTemplate,
     ) {
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         for spd in min_spd..=max_spd {
             let lower = (spd as u32) << 27;
@@ -296,10 +301,10 @@ impl GameCubeSearcher {
         spd: u8,
         spe: u8,
         shadow_template: &'static ShadowTemplate,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState> {
         let mut states = vec![];
 
-        let info = shadow_template.get_info();
         let ivs = [hp, atk, def, spa, spd, spe];
 
         let mut seeds = [0; 6];
@@ -365,10 +370,10 @@ impl GameCubeSearcher {
         spd: u8,
         spe: u8,
         shadow_template: &'static ShadowTemplate,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState> {
         let mut states = vec![];
 
-        let info = shadow_template.get_info();
         let ivs = [hp, atk, def, spa, spd, spe];
 
         let mut seeds = [0; 6];
@@ -459,9 +464,9 @@ impl GameCubeSearcher {
         spe: u8,
         mut tsv: u16,
         static_template: &'static StaticTemplate,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState> {
         let mut states = vec![];
-        let info = static_template.get_info();
         let ivs = [hp, atk, def, spa, spd, spe];
 
         let mut seeds = [0; 6];
@@ -590,3 +595,19 @@ impl GameCubeSearcher {
         states
     }
 }
+
+impl SearcherT for GameCubeSearcher {
+    type State = SearcherState;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
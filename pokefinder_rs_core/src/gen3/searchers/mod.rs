@@ -1,10 +1,14 @@
 mod channel_seed_searcher;
+mod colo_seed_searcher;
+mod gales_seed_searcher;
 mod game_cube_searcher;
 mod seed_searcher;
 mod static_searcher3;
 mod wild_searcher3;
 
 pub use channel_seed_searcher::*;
+pub use colo_seed_searcher::*;
+pub use gales_seed_searcher::*;
 pub use game_cube_searcher::*;
 pub use seed_searcher::*;
 pub use static_searcher3::*;
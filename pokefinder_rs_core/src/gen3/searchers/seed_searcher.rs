@@ -1,5 +1,140 @@
-use std::sync::atomic::{AtomicBool, AtomicU32};
+use crate::enums::Game;
+use crate::parents::personal_loader;
+use crate::rng::XDRNG;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Number of Pokemon generated for each side of a Battle Now team
+const TEAM_SIZE: usize = 6;
+
+/// Teams and leads observed on one Battle Now reroll
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BattleNowCriteria {
+    /// Index of the player team
+    pub player_team: u8,
+    /// Index of the enemy team
+    pub enemy_team: u8,
+    /// Species of the player lead
+    pub player_species: u16,
+    /// Level of the player lead
+    pub player_level: u8,
+    /// HP stat of the player lead, 0 to allow any
+    pub player_hp: u16,
+    /// Species of the enemy lead
+    pub enemy_species: u16,
+    /// Level of the enemy lead
+    pub enemy_level: u8,
+    /// HP stat of the enemy lead, 0 to allow any
+    pub enemy_hp: u16,
+}
+
+impl BattleNowCriteria {
+    /// Construct a new [`BattleNowCriteria`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        player_team: u8,
+        enemy_team: u8,
+        player_species: u16,
+        player_level: u8,
+        player_hp: u16,
+        enemy_species: u16,
+        enemy_level: u8,
+        enemy_hp: u16,
+    ) -> Self {
+        Self {
+            player_team,
+            enemy_team,
+            player_species,
+            player_level,
+            player_hp,
+            enemy_species,
+            enemy_level,
+            enemy_hp,
+        }
+    }
+
+    /// Construct a new [`BattleNowCriteria`] struct that only compares the teams
+    pub fn teams(player_team: u8, enemy_team: u8) -> Self {
+        Self::new(player_team, enemy_team, 0, 0, 0, 0, 0, 0)
+    }
+
+    fn compare_hp(&self, version: Game, player_iv: u8, enemy_iv: u8) -> bool {
+        (self.player_hp == 0
+            || self.player_hp
+                == hp_stat(version, self.player_species, self.player_level, player_iv))
+            && (self.enemy_hp == 0
+                || self.enemy_hp
+                    == hp_stat(version, self.enemy_species, self.enemy_level, enemy_iv))
+    }
+}
+
+/// Computes the HP stat of a Battle Now Pokemon, they have no EVs
+fn hp_stat(version: Game, species: u16, level: u8, iv: u8) -> u16 {
+    let base = personal_loader::get_personal_info(version, species, 0).get_stat(0) as u32;
+    ((2 * base + iv as u32) * level as u32 / 100 + level as u32 + 10) as u16
+}
+
+fn is_shiny(pid: u32, tsv: u16) -> bool {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    (psv ^ tsv) < 8
+}
+
+/// Advances the RNG past one Battle Now team and returns the HP IV of its lead.
+///
+/// `skip` is the number of draws before the IVs, each PID is rerolled until it is not shiny for
+/// `tsv`.
+pub(crate) fn generate_team(rng: &mut XDRNG, tsv: u16, skip: u32) -> u8 {
+    let mut hp = 0;
+    for i in 0..TEAM_SIZE {
+        rng.advance(skip);
+        let iv1 = rng.next_u16();
+        // Second IV call and ability
+        rng.advance(2);
+        loop {
+            let pid = ((rng.next_u16() as u32) << 16) | rng.next_u16() as u32;
+            if !is_shiny(pid, tsv) {
+                break;
+            }
+        }
+
+        if i == 0 {
+            hp = (iv1 & 0x1F) as u8;
+        }
+    }
+    hp
+}
+
+/// Advances the RNG through each Battle Now reroll of `criteria`.
+///
+/// `teams` draws the player and enemy team index and `leads` generates both teams, returning the
+/// HP IV of the player and enemy lead. The lead species are looked up in the personal table of
+/// `version`.
+pub(crate) fn search_battle_now<T, L>(
+    version: Game,
+    criteria: &[BattleNowCriteria],
+    rng: &mut XDRNG,
+    teams: T,
+    leads: L,
+) -> bool
+where
+    T: Fn(&mut XDRNG) -> (u8, u8),
+    L: Fn(&mut XDRNG) -> (u8, u8),
+{
+    for criteria in criteria {
+        let (player_team, enemy_team) = teams(rng);
+        if player_team != criteria.player_team || enemy_team != criteria.enemy_team {
+            return false;
+        }
+
+        let (player_hp, enemy_hp) = leads(rng);
+        if !criteria.compare_hp(version, player_hp, enemy_hp) {
+            return false;
+        }
+    }
+
+    true
+}
 
 #[derive(Clone)]
 pub struct SeedSearcher<P: Send + Sync> {
@@ -19,3 +154,83 @@ impl<P: Send + Sync> SeedSearcher<P> {
         }
     }
 }
+
+impl<P: Send + Sync + Clone + 'static> SeedSearcher<P> {
+    /// Takes the results of the last search, sorted and without duplicates
+    pub(crate) fn take_results(&self) -> Vec<u32> {
+        let mut results: Vec<u32> = std::mem::take(self.results.lock().unwrap().as_mut());
+        results.sort_unstable();
+        results.dedup();
+        results
+    }
+
+    /// Checks every 32 bit seed split across `threads` threads and blocks until they finish.
+    ///
+    /// `search_seed` advances the RNG through the criteria and the seed it leaves behind is
+    /// stored as the result. Progress is counted in blocks of 0x10000 seeds.
+    pub(crate) fn search_all<F>(&self, threads: usize, search_seed: F)
+    where
+        F: Fn(&P, &mut XDRNG) -> bool + Copy + Send + 'static,
+    {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let threads = threads.clamp(1, 0x10000);
+        let split = 0x10000 / threads as u32;
+        let mut thread_container = vec![];
+        let mut start = 0u32;
+        for i in 0..threads {
+            let end = if i == threads - 1 {
+                0x10000
+            } else {
+                start + split
+            };
+
+            let clone = self.clone();
+            thread_container.push(thread::spawn(move || {
+                let mut seeds = vec![];
+                for high in start..end {
+                    if !clone.searching.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    for low in 0..0x10000 {
+                        let mut rng = XDRNG::new((high << 16) | low);
+                        if search_seed(&clone.criteria, &mut rng) {
+                            seeds.push(rng.seed);
+                        }
+                    }
+                    clone.progress.fetch_add(1, Ordering::SeqCst);
+                }
+                clone.results.lock().unwrap().append(&mut seeds);
+            }));
+            start = end;
+        }
+
+        for thread in thread_container {
+            thread.join().unwrap();
+        }
+    }
+
+    /// Checks only the given `seeds`, used to narrow down the results of a previous search
+    pub(crate) fn search_seeds<F: Fn(&P, &mut XDRNG) -> bool>(
+        &self,
+        seeds: &[u32],
+        search_seed: F,
+    ) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let mut results = vec![];
+        for &seed in seeds {
+            if !self.searching.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let mut rng = XDRNG::new(seed);
+            if search_seed(&self.criteria, &mut rng) {
+                results.push(rng.seed);
+            }
+            self.progress.fetch_add(1, Ordering::SeqCst);
+        }
+        self.results.lock().unwrap().append(&mut results);
+    }
+}
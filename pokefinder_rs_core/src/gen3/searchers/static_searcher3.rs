@@ -2,7 +2,7 @@ use crate::enums::{Lead, Method};
 use crate::gen3::filters::StateFilter3;
 use crate::gen3::Profile3;
 use crate::parents::filters::Filter;
-use crate::parents::searchers::StaticSearcher;
+use crate::parents::searchers::{SearcherT, StaticSearcher};
 use crate::parents::states::SearcherState;
 use crate::parents::{PersonalInfo, StaticTemplate, Template};
 use crate::rng::{lcrng_reverse, PokeRNGR};
@@ -56,6 +56,7 @@ impl StaticSearcher3 {
         static_template: &'static StaticTemplate,
     ) {
         self.searching.store(true, Ordering::SeqCst);
+        let info = static_template.resolve_info();
 
         for hp in min[0]..=max[0] {
             for atk in min[1]..=max[1] {
@@ -67,8 +68,16 @@ impl StaticSearcher3 {
                                     return;
                                 }
 
-                                let mut states =
-                                    self.search(hp, atk, def, spa, spd, spe, static_template);
+                                let mut states = self.search(
+                                    hp,
+                                    atk,
+                                    def,
+                                    spa,
+                                    spd,
+                                    spe,
+                                    static_template,
+                                    &info,
+                                );
                                 self.results.lock().unwrap().append(&mut states);
                                 self.progress.fetch_add(1, Ordering::SeqCst);
                             }
@@ -102,10 +111,10 @@ impl StaticSearcher3 {
         spd: u8,
         spe: u8,
         static_template: &'static StaticTemplate,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState> {
         let mut states = vec![];
         let ivs = [hp, atk, def, spa, spd, spe];
-        let info = static_template.get_info();
 
         let mut seeds = [0; 6];
         let size = lcrng_reverse::recover_poke_rng_iv(
@@ -153,3 +162,19 @@ impl StaticSearcher3 {
         states
     }
 }
+
+impl SearcherT for StaticSearcher3 {
+    type State = SearcherState;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        StaticSearcher3::progress(self)
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -3,7 +3,7 @@ use crate::gen3::filters::WildStateFilter3;
 use crate::gen3::states::WildSearcherState3;
 use crate::gen3::{EncounterArea3, Profile3};
 use crate::parents::filters::{Filter, WildFilter};
-use crate::parents::searchers::WildSearcher;
+use crate::parents::searchers::{SearcherT, WildSearcher};
 use crate::parents::{EncounterAreaT, PersonalInfo, Profile};
 use crate::rng::{lcrng_reverse, PokeRNGR};
 use crate::util::encounter_slot;
@@ -354,3 +354,19 @@ impl WildSearcher3 {
         states
     }
 }
+
+impl SearcherT for WildSearcher3 {
+    type State = WildSearcherState3;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
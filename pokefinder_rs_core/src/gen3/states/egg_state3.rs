@@ -1,7 +1,8 @@
 use crate::parents::states::{EggGeneratorState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Serialize, Deserialize)]
 pub struct EggState3 {
     pub base: EggGeneratorState,
     pub pickup_advances: u32,
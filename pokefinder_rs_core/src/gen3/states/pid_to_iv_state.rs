@@ -1,6 +1,7 @@
 use crate::enums::Method;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct PIDToIVState {
     pub seed: u32,
     pub method: Method,
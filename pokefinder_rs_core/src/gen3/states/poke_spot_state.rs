@@ -1,7 +1,8 @@
 use crate::parents::states::{GeneratorState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct PokeSpotState {
     pub base: GeneratorState,
     pub species: u16,
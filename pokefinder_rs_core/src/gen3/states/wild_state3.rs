@@ -1,7 +1,8 @@
 use crate::parents::states::WildSearcherState;
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WildSearcherState3 {
     pub base: WildSearcherState,
 }
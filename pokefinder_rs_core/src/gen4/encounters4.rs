@@ -1,6 +1,6 @@
 use crate::enums::{Encounter, Game};
 use crate::gen4::{EncounterArea4, Profile4, StaticTemplate4};
-use crate::parents::{personal_loader, PersonalInfo, Profile, Slot};
+use crate::parents::{custom_loader, personal_loader, PersonalInfo, Profile, Slot};
 use crate::resources::encounter_data_4::{
     DIAMOND, EVENTS, FOSSILS, GAME_CORNER, GIFTS, HEART_GOLD, HGSS_BUG, HGSS_SAFARI, HG_HEADBUTT,
     LEGENDS, PEARL, PLATINUM, ROAMERS, SOUL_SILVER, SS_HEADBUTT, STARTERS, STATIONARY,
@@ -153,7 +153,7 @@ struct WildEncounterHGSSSafari {
 fn modify_radio(
     pokemon: &mut [Slot],
     entry: &WildEncounterHGSS,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     radio: usize,
 ) {
     let (species1, species2) = match radio {
@@ -171,7 +171,7 @@ fn modify_radio(
 fn modify_swarm_hgss(
     pokemon: &mut [Slot],
     entry: &WildEncounterHGSS,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     encounter: Encounter,
     swarm: bool,
 ) {
@@ -211,7 +211,7 @@ fn modify_swarm_hgss(
 fn modify_time_hgss(
     pokemon: &mut [Slot],
     entry: &WildEncounterHGSS,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     encounter: Encounter,
     time: usize,
 ) {
@@ -233,7 +233,7 @@ fn get_hgss_safari(
     encounter: Encounter,
     time: usize,
     blocks: [u8; 5],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
 ) -> Vec<EncounterArea4> {
     let data = util::decompress(HGSS_SAFARI);
 
@@ -388,7 +388,7 @@ fn get_hgss(
     dex: bool,
     time: usize,
     blocks: [u8; 5],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
 ) -> Vec<EncounterArea4> {
     let mut encounters = vec![];
 
@@ -401,9 +401,8 @@ fn get_hgss(
         reader = StreamContainer::new(data);
 
         if dex {
-            let _unused = reader
-                .read_stream_le::<WildEncounterHGSSBug>()
-                .expect("Failed to read non-dex HGSS Bug entry");
+            // Skips the non-dex contest, the table is empty when it was not extracted from a ROM
+            let _unused = reader.read_stream_le::<WildEncounterHGSSBug>();
         }
         while let Ok(entry) = reader.read_stream_le::<WildEncounterHGSSBug>() {
             let mut slots = Vec::with_capacity(10);
@@ -458,7 +457,7 @@ fn get_hgss(
             SOUL_SILVER
         };
 
-        data = util::decompress(compressed_data);
+        data = custom_loader::get_wild_encounters(version, compressed_data);
         reader = StreamContainer::new(data);
         while let Ok(entry) = reader.read_stream_le::<WildEncounterHGSS>() {
             match encounter {
@@ -592,12 +591,7 @@ fn get_hgss(
     encounters
 }
 
-fn modify_dual(
-    pokemon: &mut [Slot],
-    entry: &WildEncounterDPPt,
-    info: &'static [PersonalInfo],
-    dual: Game,
-) {
+fn modify_dual(pokemon: &mut [Slot], entry: &WildEncounterDPPt, info: &[PersonalInfo], dual: Game) {
     let (specie1, specie2) = if dual == Game::RUBY {
         (entry.ruby[0], entry.ruby[1])
     } else if dual == Game::SAPPHIRE {
@@ -619,7 +613,7 @@ fn modify_dual(
 fn modify_great_marsh(
     pokemon: &mut [Slot],
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     location: u8,
 ) {
     if (23..=28).contains(&location) && replacement[0] != 0 {
@@ -632,7 +626,7 @@ fn modify_great_marsh(
 fn modify_radar(
     pokemon: &mut [Slot],
     entry: &WildEncounterDPPt,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     radar: bool,
 ) {
     if radar {
@@ -646,7 +640,7 @@ fn modify_radar(
 fn modify_swarm_dppt(
     pokemon: &mut [Slot],
     entry: &WildEncounterDPPt,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     swarm: bool,
 ) {
     if swarm {
@@ -658,7 +652,7 @@ fn modify_swarm_dppt(
 fn modify_time_dppt(
     pokemon: &mut [Slot],
     entry: &WildEncounterDPPt,
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     time: usize,
 ) {
     let (specie1, specie2) = if time == 1 {
@@ -676,7 +670,7 @@ fn modify_time_dppt(
 fn modify_trophy_garden(
     pokemon: &mut [Slot],
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     location: u8,
 ) {
     if location == 117 && replacement[0] != 0 && replacement[1] != 0 {
@@ -697,7 +691,7 @@ fn get_dppt(
     swarm: bool,
     time: usize,
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
 ) -> Vec<EncounterArea4> {
     let compressed_data = if version == Game::DIAMOND {
         DIAMOND
@@ -707,7 +701,7 @@ fn get_dppt(
         PLATINUM
     };
 
-    let data = util::decompress(compressed_data);
+    let data = custom_loader::get_wild_encounters(version, compressed_data);
     let mut reader = StreamContainer::new(data);
 
     let mut encounters = vec![];
@@ -826,8 +820,8 @@ pub fn get_encounters(
     profile: &Profile4,
 ) -> Vec<EncounterArea4> {
     let version = profile.get_version();
-    let info = personal_loader::get_personal_table(version);
-    if (version & Game::DPPT) != Game::NONE {
+    let info = personal_loader::resolve_personal_table(version);
+    let mut encounters = if (version & Game::DPPT) != Game::NONE {
         get_dppt(
             version,
             encounter,
@@ -836,7 +830,7 @@ pub fn get_encounters(
             swarm,
             time,
             replacement,
-            info,
+            &info,
         )
     } else {
         get_hgss(
@@ -847,9 +841,12 @@ pub fn get_encounters(
             profile.get_national_dex(),
             time,
             blocks,
-            info,
+            &info,
         )
-    }
+    };
+
+    custom_loader::apply_encounters(version, encounter, &mut encounters, EncounterArea4::new);
+    encounters
 }
 
 /// Returns the changing pokemon of the Great Marsh
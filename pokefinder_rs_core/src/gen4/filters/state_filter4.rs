@@ -57,7 +57,7 @@ impl StateFilter4 {
             || (self.base.gender != 255 && self.base.gender != state.base.base.gender)
             || (!self.base.powers[state.base.base.hidden_power as usize])
             || (!self.base.natures[state.base.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -76,7 +76,7 @@ impl StateFilter4 {
         !((self.base.ability != 255 && self.base.ability != state.base.base.ability)
             || (self.base.gender != 255 && self.base.gender != state.base.base.gender)
             || (!self.base.powers[state.base.base.hidden_power as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.shiny)) != 0))
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.shiny) == 0))
     }
 }
 
@@ -158,10 +158,10 @@ impl WildStateFilter4 {
 
         if (self.base.base.ability != 255 && self.base.base.ability != state.base.base.base.ability)
             || (self.base.base.gender != 255
-                && self.base.base.gender != state.base.base.base.ability)
+                && self.base.base.gender != state.base.base.base.gender)
             || (!self.base.base.powers[state.base.base.base.hidden_power as usize])
             || (self.base.base.shiny != 255
-                && (!(self.base.base.shiny & state.base.base.base.shiny)) != 0)
+                && (self.base.base.shiny & state.base.base.base.shiny) == 0)
         {
             return false;
         }
@@ -182,7 +182,7 @@ impl WildStateFilter4 {
                 && self.base.base.gender != state.base.base.base.gender)
             || (!self.base.base.powers[state.base.base.base.hidden_power as usize])
             || (self.base.base.shiny != 255
-                && (!(self.base.base.shiny & state.base.base.base.shiny)) != 0))
+                && (self.base.base.shiny & state.base.base.base.shiny) == 0))
     }
 }
 
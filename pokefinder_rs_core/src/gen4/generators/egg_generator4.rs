@@ -4,7 +4,7 @@ use crate::gen4::states::EggGeneratorState4;
 use crate::gen4::Profile4;
 use crate::parents::filters::Filter;
 use crate::parents::generators::EggGenerator;
-use crate::parents::personal_loader::get_personal_info;
+use crate::parents::personal_loader::{self, PersonalTable};
 use crate::parents::{Daycare, PersonalInfo, Profile};
 use crate::rng::{PokeRNG, Rng, ARNG, MT};
 use std::cmp::Ordering;
@@ -97,10 +97,10 @@ fn set_inheritance<const DPPT: bool>(
 }
 
 #[inline]
-fn get_male(version: Game, species: u16) -> Option<&'static PersonalInfo> {
+fn get_male(personal: &PersonalTable, species: u16) -> Option<&PersonalInfo> {
     match species {
-        29 => Some(get_personal_info(version, 32, 0)),
-        314 => Some(get_personal_info(version, 313, 0)),
+        29 => Some(personal.get_personal_info(32, 0)),
+        314 => Some(personal.get_personal_info(313, 0)),
         _ => None,
     }
 }
@@ -116,6 +116,8 @@ pub struct EggGenerator4<'a, 'b, 'c> {
     pub initial_advances_pickup: u32,
     /// Maximum number of pickup advances
     pub max_advances_pickup: u32,
+    /// Personal table of the profile's game
+    pub personal: PersonalTable,
 }
 
 impl<'a, 'b, 'c> EggGenerator4<'a, 'b, 'c> {
@@ -146,6 +148,7 @@ impl<'a, 'b, 'c> EggGenerator4<'a, 'b, 'c> {
             delay_pickup,
             initial_advances_pickup,
             max_advances_pickup,
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
         }
     }
 
@@ -160,15 +163,10 @@ impl<'a, 'b, 'c> EggGenerator4<'a, 'b, 'c> {
     }
 
     fn generate_held(&self, seed: u32) -> Vec<EggGeneratorState4> {
-        let base = get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut mt = MT::new_with_initial_advances(
             seed,
@@ -238,15 +236,10 @@ impl<'a, 'b, 'c> EggGenerator4<'a, 'b, 'c> {
     }
 
     fn generate_pickup(&self, seed: u32, held: Vec<EggGeneratorState4>) -> Vec<EggGeneratorState4> {
-        let base = get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
-        let male = get_male(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let male = get_male(&self.personal, self.base.daycare.get_egg_species());
 
         let mut rng = PokeRNG::new_with_initial_advances(
             seed,
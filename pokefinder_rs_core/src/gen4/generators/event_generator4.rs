@@ -0,0 +1,134 @@
+use crate::enums::Method;
+use crate::gen4::filters::StateFilter4;
+use crate::gen4::states::GeneratorState4;
+use crate::gen4::{Profile4, PGT};
+use crate::parents::generators::Generator;
+use crate::parents::{personal_loader, PersonalInfo, Profile};
+use crate::rng::{PokeRNG, ARNG};
+
+fn get_gender(pid: u32, info: &PersonalInfo) -> u8 {
+    match info.get_gender() {
+        255 => 2,
+        254 => 1,
+        0 => 0,
+        i => (((pid & 255) as u8) < i) as u8,
+    }
+}
+
+fn get_shiny(pid: u32, tsv: u16) -> u8 {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    if tsv == psv {
+        2
+    } else if (tsv ^ psv) < 8 {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_shiny(pid: u32, tsv: u16) -> bool {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    (psv ^ tsv) < 8
+}
+
+/// Wondercard generator for Gen4
+#[derive(Clone)]
+pub struct EventGenerator4<'a, 'b, 'c> {
+    /// Base generator data
+    pub base: Generator<'a, 'b, Profile4, StateFilter4>,
+    /// Wondercard gift to generate
+    pub pgt: &'c PGT,
+    /// Personal info of the wondercard pokemon
+    pub info: PersonalInfo,
+}
+
+impl<'a, 'b, 'c> EventGenerator4<'a, 'b, 'c> {
+    /// Construct a new [`EventGenerator4`] struct
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        pgt: &'c PGT,
+        profile: &'a Profile4,
+        filter: &'b StateFilter4,
+    ) -> Self {
+        let mut new = Self {
+            base: Generator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                pgt.get_method(),
+                profile,
+                filter,
+            ),
+            pgt,
+            info: personal_loader::resolve_personal_info(
+                profile.get_version(),
+                pgt.species,
+                pgt.form,
+            ),
+        };
+        new.base.tsv = pgt.get_tsv(new.base.tsv);
+        new
+    }
+
+    /// Generates states for the wondercard
+    pub fn generate(&self, seed: u32) -> Vec<GeneratorState4> {
+        let info = &self.info;
+
+        let mut rng = PokeRNG::new_with_initial_advances(
+            seed,
+            self.base.initial_advances.wrapping_add(self.base.delay),
+        );
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.max_advances {
+            let mut go = rng;
+
+            let mut pid = self.pgt.pid;
+            if self.base.method == Method::Method1 {
+                pid = go.next_u16() as u32;
+                pid |= (go.next_u16() as u32) << 16;
+
+                if self.pgt.is_manaphy_egg() {
+                    while is_shiny(pid, self.base.tsv) {
+                        pid = ARNG::new(pid).next();
+                    }
+                }
+            }
+
+            let ivs = if self.pgt.random_ivs && self.base.method != Method::None {
+                let iv1 = go.next_u16();
+                let iv2 = go.next_u16();
+                [
+                    (iv1 & 31) as u8,
+                    ((iv1 >> 5) & 31) as u8,
+                    ((iv1 >> 10) & 31) as u8,
+                    ((iv2 >> 5) & 31) as u8,
+                    ((iv2 >> 10) & 31) as u8,
+                    (iv2 & 31) as u8,
+                ]
+            } else {
+                self.pgt.ivs
+            };
+
+            let state = GeneratorState4::new(
+                rng.next_u16(),
+                self.base.initial_advances.wrapping_add(cnt),
+                pid,
+                ivs,
+                (pid & 1) as u8,
+                get_gender(pid, info),
+                self.pgt.level,
+                (pid % 25) as u8,
+                get_shiny(pid, self.base.tsv),
+                info,
+            );
+            if self.base.filter.compare_generator_state(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+}
@@ -1,9 +1,11 @@
 mod egg_generator4;
+mod event_generator4;
 mod id_generator4;
 mod static_generator4;
 mod wild_generator4;
 
 pub use egg_generator4::*;
+pub use event_generator4::*;
 pub use id_generator4::*;
 pub use static_generator4::*;
 pub use wild_generator4::*;
@@ -78,7 +78,7 @@ impl<'a, 'b> StaticGenerator4<'a, 'b> {
         static_template: &StaticTemplate4,
     ) -> Vec<GeneratorState4> {
         let mut states = vec![];
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         let mut rng = PokeRNG::new_with_initial_advances(
             seed,
@@ -150,7 +150,7 @@ impl<'a, 'b> StaticGenerator4<'a, 'b> {
         static_template: &StaticTemplate4,
     ) -> Vec<GeneratorState4> {
         let mut states = vec![];
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         let mut cute_charm_flag = false;
         let mut buffer = 0;
@@ -237,7 +237,7 @@ impl<'a, 'b> StaticGenerator4<'a, 'b> {
         static_template: &StaticTemplate4,
     ) -> Vec<GeneratorState4> {
         let mut states = vec![];
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
 
         let mut cute_charm_flag = false;
         let mut buffer = 0;
@@ -7,6 +7,8 @@ pub mod filters;
 /// Contains generators that are used in Gen4 games
 pub mod generators;
 mod hgss_roamer;
+mod pcd;
+mod pgt;
 mod profile4;
 /// Contains searchers that are used in Gen4 games
 pub mod searchers;
@@ -20,6 +22,8 @@ pub mod tools;
 pub use chained_sid_calc::*;
 pub use encounter_area4::*;
 pub use hgss_roamer::*;
+pub use pcd::*;
+pub use pgt::*;
 pub use profile4::*;
 pub use seed_time4::*;
 pub use static_template4::*;
@@ -0,0 +1,30 @@
+use crate::gen4::pgt::{check_size, decode_string};
+use crate::gen4::{WondercardSizeError, PGT, PGT_SIZE};
+
+/// Size of a PCD wondercard in bytes
+pub const PCD_SIZE: usize = 0x358;
+
+/// Gen 4 wondercard (PCD), a [`PGT`] gift with the card shown in the game
+#[derive(Clone)]
+pub struct PCD {
+    /// Gift stored in the card
+    pub gift: PGT,
+    /// Title of the card
+    pub title: String,
+    /// Distribution ID of the card
+    pub card_id: u16,
+}
+
+impl TryFrom<&[u8]> for PCD {
+    type Error = WondercardSizeError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        check_size(value, PCD_SIZE)?;
+
+        Ok(Self {
+            gift: PGT::try_from(&value[..PGT_SIZE])?,
+            title: decode_string(&value[0x104..0x14c]),
+            card_id: u16::from_le_bytes(value[0x150..0x152].try_into().unwrap()),
+        })
+    }
+}
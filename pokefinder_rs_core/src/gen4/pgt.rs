@@ -0,0 +1,250 @@
+use crate::enums::{Method, Shiny};
+use std::fmt::{Display, Formatter};
+
+/// Size of a PGT gift in bytes
+pub const PGT_SIZE: usize = 0x104;
+
+const MANAPHY_EGG: u8 = 7;
+const RANDOM_PID: u32 = 1;
+
+const BLOCK_POSITION: [usize; 96] = [
+    0, 1, 2, 3, 0, 1, 3, 2, 0, 2, 1, 3, 0, 3, 1, 2, 0, 2, 3, 1, 0, 3, 2, 1, 1, 0, 2, 3, 1, 0, 3, 2,
+    2, 0, 1, 3, 3, 0, 1, 2, 2, 0, 3, 1, 3, 0, 2, 1, 1, 2, 0, 3, 1, 3, 0, 2, 2, 1, 0, 3, 3, 1, 0, 2,
+    2, 3, 0, 1, 3, 2, 0, 1, 1, 2, 3, 0, 1, 3, 2, 0, 2, 1, 3, 0, 3, 1, 2, 0, 2, 3, 1, 0, 3, 2, 1, 0,
+];
+
+fn crypt(data: &mut [u8], seed: u32) {
+    let mut seed = seed;
+    for word in data.chunks_exact_mut(2) {
+        seed = seed.wrapping_mul(0x41C64E6D).wrapping_add(0x6073);
+        let value = u16::from_le_bytes([word[0], word[1]]) ^ ((seed >> 16) as u16);
+        word.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Decrypts and unshuffles the 236 byte party PK4 stored in a wondercard
+fn decrypt(data: &[u8]) -> [u8; 0xEC] {
+    let mut pk4 = [0; 0xEC];
+    pk4.copy_from_slice(&data[..0xEC]);
+
+    // Decrypted data always has the unused bytes after the ribbons cleared
+    if u32::from_le_bytes(pk4[0x64..0x68].try_into().unwrap()) == 0 {
+        return pk4;
+    }
+
+    let pid = u32::from_le_bytes(pk4[0x0..0x4].try_into().unwrap());
+    let checksum = u16::from_le_bytes(pk4[0x6..0x8].try_into().unwrap());
+    crypt(&mut pk4[0x8..0x88], checksum as u32);
+    crypt(&mut pk4[0x88..0xEC], pid);
+
+    let shuffled = pk4;
+    let sv = ((pid >> 13) & 31) as usize % 24;
+    for block in 0..4 {
+        let from = 8 + BLOCK_POSITION[sv * 4 + block] * 32;
+        let to = 8 + block * 32;
+        pk4[to..to + 32].copy_from_slice(&shuffled[from..from + 32]);
+    }
+    pk4
+}
+
+/// Decodes a Gen 4 string, only the alphanumeric characters and common symbols are supported
+pub(crate) fn decode_string(data: &[u8]) -> String {
+    data.chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&c| c != 0xFFFF && c != 0)
+        .map(|c| match c {
+            0x0121..=0x012A => (b'0' + (c - 0x0121) as u8) as char,
+            0x012B..=0x0144 => (b'A' + (c - 0x012B) as u8) as char,
+            0x0145..=0x015E => (b'a' + (c - 0x0145) as u8) as char,
+            0x0188 => 'é',
+            0x01AB => '!',
+            0x01AC => '?',
+            0x01AD => ',',
+            0x01AE => '.',
+            0x01BE => '-',
+            0x01DE => ' ',
+            _ => '?',
+        })
+        .collect()
+}
+
+/// Error returned when wondercard data is shorter than its format
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WondercardSizeError {
+    /// Number of bytes the format needs
+    pub expected: usize,
+    /// Number of bytes that were passed in
+    pub actual: usize,
+}
+
+impl Display for WondercardSizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wondercard data is {} bytes long, at least {} are needed",
+            self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for WondercardSizeError {}
+
+/// Checks that `value` holds at least `expected` bytes
+pub(crate) fn check_size(value: &[u8], expected: usize) -> Result<(), WondercardSizeError> {
+    if value.len() < expected {
+        Err(WondercardSizeError {
+            expected,
+            actual: value.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Gen 4 mystery gift (PGT) holding a Pokemon
+#[derive(Clone)]
+pub struct PGT {
+    /// PID of the gift, `1` when the PID is generated on receipt
+    pub pid: u32,
+    /// Original trainer TID
+    pub tid: u16,
+    /// Original trainer SID
+    pub sid: u16,
+    /// Pokemon species
+    pub species: u16,
+    /// Held item
+    pub item: u16,
+    /// Original trainer name
+    pub ot: String,
+    /// Wondercard type, `7` for the Manaphy egg
+    pub card_type: u8,
+    /// Pokemon ability
+    pub ability: u8,
+    /// Pokemon form
+    pub form: u8,
+    /// Pokemon IVs, only used if `random_ivs` is not set
+    pub ivs: [u8; 6],
+    /// Pokemon level
+    pub level: u8,
+    /// Whether the gift is an egg
+    pub egg: bool,
+    /// Whether the IVs are generated on receipt
+    pub random_ivs: bool,
+}
+
+impl TryFrom<&[u8]> for PGT {
+    type Error = WondercardSizeError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        check_size(value, PGT_SIZE)?;
+
+        let pk4 = decrypt(&value[0x8..]);
+        let iv32 = u32::from_le_bytes(pk4[0x38..0x3c].try_into().unwrap());
+        let level = if pk4[0x8c] != 0 {
+            pk4[0x8c]
+        } else {
+            pk4[0x84] & 0x7f
+        };
+
+        Ok(Self {
+            pid: u32::from_le_bytes(pk4[0x0..0x4].try_into().unwrap()),
+            tid: u16::from_le_bytes(pk4[0xc..0xe].try_into().unwrap()),
+            sid: u16::from_le_bytes(pk4[0xe..0x10].try_into().unwrap()),
+            species: u16::from_le_bytes(pk4[0x8..0xa].try_into().unwrap()),
+            item: u16::from_le_bytes(pk4[0xa..0xc].try_into().unwrap()),
+            ot: decode_string(&pk4[0x68..0x78]),
+            card_type: value[0x0],
+            ability: pk4[0x15],
+            form: pk4[0x40] >> 3,
+            ivs: [
+                (iv32 & 31) as u8,
+                ((iv32 >> 5) & 31) as u8,
+                ((iv32 >> 10) & 31) as u8,
+                ((iv32 >> 20) & 31) as u8,
+                ((iv32 >> 25) & 31) as u8,
+                ((iv32 >> 15) & 31) as u8,
+            ],
+            level: level.max(1),
+            egg: (iv32 >> 30) & 1 == 1 || value[0x0] == MANAPHY_EGG,
+            // Egg and nickname flags share the IV field
+            random_ivs: iv32 & 0x3FFFFFFF == 0,
+        })
+    }
+}
+
+impl PGT {
+    /// Construct a new [`PGT`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pid: u32,
+        tid: u16,
+        sid: u16,
+        species: u16,
+        level: u8,
+        ivs: Option<[u8; 6]>,
+        egg: bool,
+        manaphy_egg: bool,
+    ) -> Self {
+        Self {
+            pid,
+            tid,
+            sid,
+            species,
+            item: 0,
+            ot: String::new(),
+            card_type: if manaphy_egg { MANAPHY_EGG } else { 1 },
+            ability: 0,
+            form: 0,
+            ivs: ivs.unwrap_or_default(),
+            level,
+            egg: egg || manaphy_egg,
+            random_ivs: ivs.is_none(),
+        }
+    }
+
+    /// Returns whether the gift is the Manaphy egg, which rerolls shiny PIDs on receipt
+    pub fn is_manaphy_egg(&self) -> bool {
+        self.card_type == MANAPHY_EGG
+    }
+
+    /// Returns whether the PID is generated on receipt
+    pub fn is_random_pid(&self) -> bool {
+        self.pid == RANDOM_PID || self.is_manaphy_egg()
+    }
+
+    /// Returns the method used to generate the gift from a seed
+    ///
+    /// Random PIDs are followed by the IVs like [`Method::Method1`]. Fixed PIDs with random IVs
+    /// only use two calls for the IVs, and fully fixed gifts do not use the RNG at all.
+    pub fn get_method(&self) -> Method {
+        if self.is_random_pid() {
+            Method::Method1
+        } else if self.random_ivs {
+            Method::WondercardIVs
+        } else {
+            Method::None
+        }
+    }
+
+    /// Returns the shininess rule of the gift
+    pub fn get_shiny(&self) -> Shiny {
+        if self.is_manaphy_egg() {
+            Shiny::Never
+        } else if self.is_random_pid() {
+            Shiny::Random
+        } else {
+            Shiny::Static
+        }
+    }
+
+    /// Returns the TSV shininess is compared against.
+    ///
+    /// Eggs are owned by the player receiving them, other gifts keep the wondercard OT.
+    pub fn get_tsv(&self, tsv: u16) -> u16 {
+        if self.egg {
+            tsv
+        } else {
+            self.tid ^ self.sid
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::gen4::generators::EggGenerator4;
 use crate::gen4::states::EggSearcherState4;
+use crate::parents::searchers::SearcherT;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -79,3 +80,19 @@ impl EggSearcher4 {
         }
     }
 }
+
+impl SearcherT for EggSearcher4 {
+    type State = EggSearcherState4;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
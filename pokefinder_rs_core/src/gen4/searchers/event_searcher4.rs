@@ -0,0 +1,239 @@
+use crate::enums::Method;
+use crate::gen4::filters::StateFilter4;
+use crate::gen4::states::SearcherState4;
+use crate::gen4::{Profile4, PGT};
+use crate::parents::filters::Filter;
+use crate::parents::searchers::{Searcher, SearcherT};
+use crate::parents::{personal_loader, PersonalInfo, Profile};
+use crate::rng::{lcrng_reverse, PokeRNGR, ARNG};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+fn get_gender(pid: u32, info: &PersonalInfo) -> u8 {
+    match info.get_gender() {
+        255 => 2,
+        254 => 1,
+        0 => 0,
+        i => (((pid & 255) as u8) < i) as u8,
+    }
+}
+
+fn get_shiny(pid: u32, tsv: u16) -> u8 {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    if tsv == psv {
+        2
+    } else if (tsv ^ psv) < 8 {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_shiny(pid: u32, tsv: u16) -> bool {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    (psv ^ tsv) < 8
+}
+
+/// Wondercard searcher for Gen4
+#[derive(Clone)]
+pub struct EventSearcher4 {
+    base: Searcher<Profile4, StateFilter4>,
+    pgt: PGT,
+    info: PersonalInfo,
+    results: Arc<Mutex<Vec<SearcherState4>>>,
+    progress: Arc<AtomicU32>,
+    max_advance: u32,
+    min_advance: u32,
+    max_delay: u32,
+    min_delay: u32,
+    searching: Arc<AtomicBool>,
+}
+
+impl EventSearcher4 {
+    /// Construct a new [`EventSearcher4`] struct
+    pub fn new(
+        min_advance: u32,
+        max_advance: u32,
+        min_delay: u32,
+        max_delay: u32,
+        pgt: &PGT,
+        profile: &Profile4,
+        filter: &StateFilter4,
+    ) -> Self {
+        let mut base = Searcher::new(pgt.get_method(), profile, filter);
+        base.tsv = pgt.get_tsv(base.tsv);
+
+        Self {
+            base,
+            pgt: pgt.clone(),
+            info: personal_loader::resolve_personal_info(
+                profile.get_version(),
+                pgt.species,
+                pgt.form,
+            ),
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            max_advance,
+            min_advance,
+            max_delay,
+            min_delay,
+            searching: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the states of the running search
+    pub fn get_results(&self) -> Vec<SearcherState4> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    pub fn start_search(&self, min: [u8; 6], max: [u8; 6]) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        for hp in min[0]..=max[0] {
+            for atk in min[1]..=max[1] {
+                for def in min[2]..=max[2] {
+                    for spa in min[3]..=max[3] {
+                        for spd in min[4]..=max[4] {
+                            for spe in min[5]..=max[5] {
+                                if !self.searching.load(Ordering::SeqCst) {
+                                    return;
+                                }
+
+                                let mut states = self.search(hp, atk, def, spa, spd, spe);
+                                self.results.lock().unwrap().append(&mut states);
+                                self.progress.fetch_add(1, Ordering::SeqCst);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn search(&self, hp: u8, atk: u8, def: u8, spa: u8, spd: u8, spe: u8) -> Vec<SearcherState4> {
+        // Gifts with fixed IVs do not use the RNG for them, so there is nothing to search for
+        if !self.pgt.random_ivs {
+            return vec![];
+        }
+
+        let states = match self.base.method {
+            Method::Method1 | Method::WondercardIVs => self.search_ivs(hp, atk, def, spa, spd, spe),
+            _ => vec![],
+        };
+
+        self.search_initial_seeds(states)
+    }
+
+    fn search_initial_seeds(&self, results: Vec<SearcherState4>) -> Vec<SearcherState4> {
+        let mut states = vec![];
+
+        for mut result in results.into_iter() {
+            let mut rng = PokeRNGR::new_with_initial_advances(result.base.seed, self.min_advance);
+            let mut seed = rng.seed;
+            for cnt in self.min_advance..=self.max_advance {
+                let hour = (seed >> 16) & 0xFF;
+                let delay = seed & 0xFFFF;
+
+                if hour < 24 && (self.min_delay..=self.max_delay).contains(&delay) {
+                    result.base.seed = seed;
+                    result.advances = cnt;
+                    states.push(result);
+                }
+                seed = rng.next();
+            }
+        }
+
+        states
+    }
+
+    fn search_ivs(
+        &self,
+        hp: u8,
+        atk: u8,
+        def: u8,
+        spa: u8,
+        spd: u8,
+        spe: u8,
+    ) -> Vec<SearcherState4> {
+        let mut states = vec![];
+
+        let ivs = [hp, atk, def, spa, spd, spe];
+        let info = &self.info;
+
+        let mut seeds = [0; 6];
+        let size = lcrng_reverse::recover_poke_rng_iv(
+            hp,
+            atk,
+            def,
+            spa,
+            spd,
+            spe,
+            &mut seeds,
+            Method::Method1,
+        );
+        for rev_seed in seeds.into_iter().take(size) {
+            let mut rng = PokeRNGR::new(rev_seed);
+
+            let mut pid = self.pgt.pid;
+            if self.base.method == Method::Method1 {
+                pid = (rng.next_u16() as u32) << 16;
+                pid |= rng.next_u16() as u32;
+
+                if self.pgt.is_manaphy_egg() {
+                    while is_shiny(pid, self.base.tsv) {
+                        pid = ARNG::new(pid).next();
+                    }
+                }
+            }
+
+            let nature = (pid % 25) as u8;
+            if !self.base.filter.compare_nature(nature) {
+                continue;
+            }
+
+            let state = SearcherState4::new(
+                rng.next(),
+                pid,
+                ivs,
+                (pid & 1) as u8,
+                get_gender(pid, info),
+                self.pgt.level,
+                nature,
+                get_shiny(pid, self.base.tsv),
+                info,
+            );
+            if self.base.filter.compare_searcher_state(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+}
+
+impl SearcherT for EventSearcher4 {
+    type State = SearcherState4;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
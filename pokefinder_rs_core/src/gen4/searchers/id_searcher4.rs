@@ -1,5 +1,6 @@
 use crate::gen4::states::IDState4;
 use crate::parents::filters::IDFilter;
+use crate::parents::searchers::SearcherT;
 use crate::rng::MTFast;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
@@ -74,3 +75,19 @@ impl IDSearcher4 {
         }
     }
 }
+
+impl SearcherT for IDSearcher4 {
+    type State = IDState4;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -1,9 +1,11 @@
 mod egg_searcher4;
+mod event_searcher4;
 mod id_searcher4;
 mod static_searcher4;
 mod wild_searcher4;
 
 pub use egg_searcher4::*;
+pub use event_searcher4::*;
 pub use id_searcher4::*;
 pub use static_searcher4::*;
 pub use wild_searcher4::*;
@@ -3,7 +3,7 @@ use crate::gen4::filters::StateFilter4;
 use crate::gen4::states::SearcherState4;
 use crate::gen4::{Profile4, StaticTemplate4};
 use crate::parents::filters::Filter;
-use crate::parents::searchers::StaticSearcher;
+use crate::parents::searchers::{SearcherT, StaticSearcher};
 use crate::parents::{PersonalInfo, Template};
 use crate::rng::{lcrng_reverse, PokeRNGR, ARNG};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
@@ -95,10 +95,9 @@ impl StaticSearcher4 {
         static_template: &'static StaticTemplate4,
     ) {
         self.searching.store(true, Ordering::SeqCst);
+        let info = static_template.resolve_info();
         let buffer = if self.base.lead == Lead::CUTE_CHARM_F {
-            25u16
-                .wrapping_mul((static_template.get_info().get_gender() as u16 / 25).wrapping_add(1))
-                as u8
+            25u16.wrapping_mul((info.get_gender() as u16 / 25).wrapping_add(1)) as u8
         } else {
             0
         };
@@ -122,6 +121,7 @@ impl StaticSearcher4 {
                                     spe,
                                     buffer,
                                     static_template,
+                                    &info,
                                 );
                                 self.results.lock().unwrap().append(&mut states);
                                 self.progress.fetch_add(1, Ordering::SeqCst);
@@ -144,14 +144,17 @@ impl StaticSearcher4 {
         spe: u8,
         buffer: u8,
         static_template: &'static StaticTemplate4,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState4> {
         let states = match self.base.base.method {
-            Method::Method1 => self.search_method_1(hp, atk, def, spa, spd, spe, static_template),
+            Method::Method1 => {
+                self.search_method_1(hp, atk, def, spa, spd, spe, static_template, info)
+            }
             Method::MethodJ => {
-                self.search_method_j(hp, atk, def, spa, spd, spe, buffer, static_template)
+                self.search_method_j(hp, atk, def, spa, spd, spe, buffer, static_template, info)
             }
             Method::MethodK => {
-                self.search_method_k(hp, atk, def, spa, spd, spe, buffer, static_template)
+                self.search_method_k(hp, atk, def, spa, spd, spe, buffer, static_template, info)
             }
             _ => vec![],
         };
@@ -191,11 +194,11 @@ impl StaticSearcher4 {
         spd: u8,
         spe: u8,
         static_template: &'static StaticTemplate4,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState4> {
         let mut states = vec![];
 
         let ivs = [hp, atk, def, spa, spd, spe];
-        let info = static_template.get_info();
 
         let mut seeds = [0; 6];
         let size = lcrng_reverse::recover_poke_rng_iv(
@@ -268,11 +271,11 @@ impl StaticSearcher4 {
         spe: u8,
         buffer: u8,
         static_template: &'static StaticTemplate4,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState4> {
         let mut states = vec![];
 
         let ivs = [hp, atk, def, spa, spd, spe];
-        let info = static_template.get_info();
 
         let mut seeds = [0; 6];
         let size = lcrng_reverse::recover_poke_rng_iv(
@@ -303,7 +306,7 @@ impl StaticSearcher4 {
                         static_template.get_level(),
                         nature,
                         get_shiny(pid, self.base.base.tsv),
-                        static_template.get_info(),
+                        info,
                     );
                     if self.base.base.filter.compare_searcher_state(&state) {
                         states.push(state);
@@ -378,11 +381,11 @@ impl StaticSearcher4 {
         spe: u8,
         buffer: u8,
         static_template: &'static StaticTemplate4,
+        info: &PersonalInfo,
     ) -> Vec<SearcherState4> {
         let mut states = vec![];
 
         let ivs = [hp, atk, def, spa, spd, spe];
-        let info = static_template.get_info();
 
         let mut seeds = [0; 6];
         let size = lcrng_reverse::recover_poke_rng_iv(
@@ -413,7 +416,7 @@ impl StaticSearcher4 {
                         static_template.get_level(),
                         nature,
                         get_shiny(pid, self.base.base.tsv),
-                        static_template.get_info(),
+                        info,
                     );
                     if self.base.base.filter.compare_searcher_state(&state) {
                         states.push(state);
@@ -477,3 +480,19 @@ impl StaticSearcher4 {
         states
     }
 }
+
+impl SearcherT for StaticSearcher4 {
+    type State = SearcherState4;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
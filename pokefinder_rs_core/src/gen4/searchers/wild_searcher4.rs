@@ -3,7 +3,7 @@ use crate::gen4::filters::WildStateFilter4;
 use crate::gen4::states::WildSearcherState4;
 use crate::gen4::{EncounterArea4, Profile4};
 use crate::parents::filters::{Filter, WildFilter};
-use crate::parents::searchers::WildSearcher;
+use crate::parents::searchers::{SearcherT, WildSearcher};
 use crate::parents::{EncounterAreaT, PersonalInfo, Profile};
 use crate::rng::{lcrng_reverse, PokeRNG, PokeRNGR};
 use crate::util::encounter_slot;
@@ -429,7 +429,7 @@ impl WildSearcher4 {
                             .base
                             .encounter_area
                             .get_specific_pokemon(encounter_slot as usize);
-                        let info = slot.info;
+                        let info = &slot.info;
                         let item = get_item(item_rand as u8, self.base.lead, info);
 
                         let form = if slot.get_species() == 201 {
@@ -719,7 +719,7 @@ impl WildSearcher4 {
                             .base
                             .encounter_area
                             .get_specific_pokemon(encounter_slot as usize);
-                        let info = slot.info;
+                        let info = &slot.info;
                         let item = get_item(item_rand, self.base.lead, info);
 
                         let state = WildSearcherState4::new(
@@ -1042,3 +1042,19 @@ impl WildSearcher4 {
         states
     }
 }
+
+impl SearcherT for WildSearcher4 {
+    type State = WildSearcherState4;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -1,8 +1,9 @@
 use crate::parents::states::{EggGeneratorState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// State struct for Gen4 egg encounters
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Serialize, Deserialize)]
 pub struct EggGeneratorState4 {
     /// Base egg generator state data
     pub base: EggGeneratorState,
@@ -59,7 +60,7 @@ impl StateT for EggGeneratorState4 {
 }
 
 /// Searcher struct for Gen4 egg encounters
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct EggSearcherState4 {
     /// State seed
     pub seed: u32,
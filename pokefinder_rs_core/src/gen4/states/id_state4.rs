@@ -1,7 +1,8 @@
 use crate::parents::states::{IDState, IDStateT};
+use serde::{Deserialize, Serialize};
 
 /// State struct for Gen4 TID/SID
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct IDState4 {
     /// Base ID state data
     pub base: IDState,
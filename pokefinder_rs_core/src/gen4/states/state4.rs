@@ -1,8 +1,9 @@
 use crate::parents::states::{GeneratorState, SearcherState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// Generator Struct for Gen4 static encounters
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct GeneratorState4 {
     /// Base generator state data
     pub base: GeneratorState,
@@ -44,7 +45,7 @@ impl StateT for GeneratorState4 {
 }
 
 /// Searcher struct for Gen4 static encounters
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SearcherState4 {
     /// Base searcher state data
     pub base: SearcherState,
@@ -1,8 +1,9 @@
 use crate::parents::states::{StateT, WildGeneratorState, WildSearcherState};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// Generator state for Gen4 wild encounters
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WildGeneratorState4 {
     /// Base wild generator state data
     pub base: WildGeneratorState,
@@ -64,7 +65,7 @@ impl StateT for WildGeneratorState4 {
 }
 
 /// Searcher state for Gen4 wild encounters
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WildSearcherState4 {
     /// Base wild searcher state data
     pub base: WildSearcherState,
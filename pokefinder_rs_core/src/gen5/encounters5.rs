@@ -2,7 +2,7 @@ use crate::enums::{Encounter, Game};
 use crate::gen5::{
     DreamRadarTemplate, EncounterArea5, HiddenGrottoArea, HiddenGrottoSlot, Profile5,
 };
-use crate::parents::{personal_loader, Profile, Slot, StaticTemplate};
+use crate::parents::{custom_loader, personal_loader, Profile, Slot, StaticTemplate};
 use crate::resources::encounter_data_5::{
     BLACK, BLACK2, BW2_GROTTO, DREAM_RADAR, EVENTS, FOSSILS, GIFTS, LEGENDS, ROAMERS, STARTERS,
     STATIONARY, WHITE, WHITE2,
@@ -17,6 +17,7 @@ struct GrottoSlot {
     max_level: u8,
     min_level: u8,
     gender: u8,
+    _padding: u8,
 }
 
 #[derive(EndianRead, Copy, Clone, Default)]
@@ -72,6 +73,7 @@ impl EndianRead for WildEncounter5 {
 #[derive(EndianRead)]
 struct WildEncounterGrotto {
     location: u8,
+    _padding: u8,
     pokemon: [GrottoSlot; 12],
     items: [u16; 16],
     hidden_items: [u16; 16],
@@ -87,7 +89,7 @@ pub fn get_dream_radar_encounter(index: usize) -> &'static DreamRadarTemplate {
 
 pub fn get_hidden_grotto_encounters() -> Vec<HiddenGrottoArea> {
     let data = util::decompress(BW2_GROTTO);
-    let info = personal_loader::get_personal_table(Game::BW2);
+    let info = personal_loader::resolve_personal_table(Game::BW2);
 
     let mut reader = StreamContainer::new(data);
 
@@ -127,7 +129,8 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
         _ => WHITE2,
     };
 
-    let data = util::decompress(compressed_data);
+    let data = custom_loader::get_wild_encounters(version, compressed_data);
+    let info = personal_loader::resolve_personal_table(version);
     let mut reader = StreamContainer::new(data);
 
     let mut encounters = vec![];
@@ -148,11 +151,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.level,
                         slot.level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -170,11 +169,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.level,
                         slot.level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -192,11 +187,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.level,
                         slot.level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -214,11 +205,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.min_level,
                         slot.max_level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -236,11 +223,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.min_level,
                         slot.max_level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -258,11 +241,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.min_level,
                         slot.max_level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -280,11 +259,7 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
                         (slot.species >> 11) as u8,
                         slot.min_level,
                         slot.max_level,
-                        personal_loader::get_personal_info(
-                            version,
-                            slot.species & 0x7ff,
-                            (slot.species >> 11) as u8,
-                        ),
+                        info.get_personal_info(slot.species & 0x7ff, (slot.species >> 11) as u8),
                     ));
                 }
                 encounters.push(EncounterArea5::new(
@@ -298,6 +273,8 @@ pub fn get_encounters(encounter: Encounter, season: u8, profile: &Profile5) -> V
         }
     }
 
+    custom_loader::apply_encounters(version, encounter, &mut encounters, EncounterArea5::new);
+
     encounters
 }
 
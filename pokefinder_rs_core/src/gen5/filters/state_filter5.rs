@@ -1,5 +1,5 @@
 use crate::gen5::states::{DreamRadarState, EggState5};
-use crate::parents::filters::{Filter, StateFilter};
+use crate::parents::filters::{Filter, StateFilter, WildFilter, WildStateFilter};
 use crate::parents::states::GeneratorState;
 
 /// Checks if encounters match the filter criteria
@@ -37,7 +37,7 @@ impl StateFilter5 {
             || (self.base.gender != 255 && self.base.gender != state.base.base.base.gender)
             || (!self.base.powers[state.base.base.base.hidden_power as usize])
             || (!self.base.natures[state.base.base.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.base.shiny) == 0)
         {
             return false;
         }
@@ -61,7 +61,7 @@ impl StateFilter5 {
             || (self.base.gender != 255 && self.base.gender != state.base.base.gender)
             || (!self.base.powers[state.base.base.hidden_power as usize])
             || (!self.base.natures[state.base.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -85,7 +85,7 @@ impl StateFilter5 {
             || (self.base.gender != 255 && self.base.gender != state.base.gender)
             || (!self.base.powers[state.base.hidden_power as usize])
             || (!self.base.natures[state.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.shiny) == 0)
         {
             return false;
         }
@@ -133,3 +133,80 @@ impl Filter for StateFilter5 {
         self.base.shiny
     }
 }
+
+/// Checks if wild encounters match the filter criteria
+#[derive(Copy, Clone, Default)]
+pub struct WildStateFilter5 {
+    /// Base wild state filter data
+    pub base: WildStateFilter,
+}
+
+impl WildStateFilter5 {
+    /// Construct a new [`WildStateFilter5`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        gender: u8,
+        ability: u8,
+        shiny: u8,
+        skip: bool,
+        min: [u8; 6],
+        max: [u8; 6],
+        natures: [bool; 25],
+        powers: [bool; 16],
+        encounter_slots: [bool; 12],
+    ) -> Self {
+        Self {
+            base: WildStateFilter::new(
+                gender,
+                ability,
+                shiny,
+                skip,
+                min,
+                max,
+                natures,
+                powers,
+                encounter_slots,
+            ),
+        }
+    }
+}
+
+impl Filter for WildStateFilter5 {
+    fn get_natures(&self) -> [bool; 25] {
+        self.base.get_natures()
+    }
+
+    fn get_powers(&self) -> [bool; 16] {
+        self.base.get_powers()
+    }
+
+    fn get_max(&self) -> [u8; 6] {
+        self.base.get_max()
+    }
+
+    fn get_min(&self) -> [u8; 6] {
+        self.base.get_min()
+    }
+
+    fn skip(&self) -> bool {
+        self.base.skip()
+    }
+
+    fn get_ability(&self) -> u8 {
+        self.base.get_ability()
+    }
+
+    fn get_gender(&self) -> u8 {
+        self.base.get_gender()
+    }
+
+    fn get_shiny(&self) -> u8 {
+        self.base.get_shiny()
+    }
+}
+
+impl WildFilter for WildStateFilter5 {
+    fn get_encounter_slots(&self) -> [bool; 12] {
+        self.base.encounter_slots
+    }
+}
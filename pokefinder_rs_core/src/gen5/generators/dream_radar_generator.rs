@@ -50,7 +50,7 @@ impl<'a, 'b, 'c> DreamRadarGenerator<'a, 'b, 'c> {
                 iv_advances = iv_advances.wrapping_add(13);
             }
 
-            let info = slot.base.get_info();
+            let info = slot.base.resolve_info();
             if i != radar_templates.len() - 1 {
                 pid_advances =
                     pid_advances.wrapping_add(if slot.legend || info.get_gender() != 255 {
@@ -72,7 +72,7 @@ impl<'a, 'b, 'c> DreamRadarGenerator<'a, 'b, 'c> {
     }
 
     pub fn generate(&self, seed: u64) -> Vec<DreamRadarState> {
-        let info = self.radar_template.base.get_info();
+        let info = &self.radar_template.base.resolve_info();
 
         let mut rng = BWRNG::new_with_initial_advances(
             seed,
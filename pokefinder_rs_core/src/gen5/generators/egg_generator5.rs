@@ -4,7 +4,8 @@ use crate::gen5::states::EggState5;
 use crate::gen5::Profile5;
 use crate::parents::filters::Filter;
 use crate::parents::generators::EggGenerator;
-use crate::parents::{personal_loader, Daycare, PersonalInfo, Profile};
+use crate::parents::personal_loader::{self, PersonalTable};
+use crate::parents::{Daycare, PersonalInfo, Profile};
 use crate::rng::{MTFast, BWRNG};
 use crate::util;
 
@@ -16,6 +17,7 @@ pub struct EggGenerator5<'a, 'b, 'c> {
     pub parent_ability: u8,
     pub power_item: u8,
     pub rolls: u8,
+    pub personal: PersonalTable,
 }
 
 fn get_gender(pid: u32, info: &PersonalInfo) -> u8 {
@@ -69,6 +71,7 @@ impl<'a, 'b, 'c> EggGenerator5<'a, 'b, 'c> {
             power_item: daycare.get_power_item_count(),
             rolls: if profile.get_shiny_charm() { 2 } else { 0 }
                 + if daycare.get_masuda() { 5 } else { 0 },
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
         }
     }
 
@@ -81,20 +84,18 @@ impl<'a, 'b, 'c> EggGenerator5<'a, 'b, 'c> {
     }
 
     fn generate_bw(&self, seed: u64) -> Vec<EggState5> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
         let (male, female) = if matches!(self.base.daycare.get_egg_species(), 29 | 32) {
             (
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 32, 0),
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 29, 0),
+                self.personal.get_personal_info(32, 0),
+                self.personal.get_personal_info(29, 0),
             )
         } else if matches!(self.base.daycare.get_egg_species(), 313 | 314) {
             (
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 313, 0),
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 314, 0),
+                self.personal.get_personal_info(313, 0),
+                self.personal.get_personal_info(314, 0),
             )
         } else {
             (base, base)
@@ -305,27 +306,24 @@ impl<'a, 'b, 'c> EggGenerator5<'a, 'b, 'c> {
         states
     }
 
-    fn generate_bw2_egg(&self, seed: u64) -> (EggState5, &'static PersonalInfo) {
+    fn generate_bw2_egg(&self, seed: u64) -> (EggState5, &PersonalInfo) {
         let mut rng = BWRNG::new(seed);
 
         let info = if matches!(self.base.daycare.get_egg_species(), 29 | 32) {
             if rng.next_u32_max(2) != 0 {
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 32, 0)
+                self.personal.get_personal_info(32, 0)
             } else {
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 29, 0)
+                self.personal.get_personal_info(29, 0)
             }
         } else if matches!(self.base.daycare.get_egg_species(), 313 | 314) {
             if rng.next_u32_max(2) != 0 {
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 314, 0)
+                self.personal.get_personal_info(314, 0)
             } else {
-                personal_loader::get_personal_info(self.base.base.profile.get_version(), 313, 0)
+                self.personal.get_personal_info(313, 0)
             }
         } else {
-            personal_loader::get_personal_info(
-                self.base.base.profile.get_version(),
-                self.base.daycare.get_egg_species(),
-                0,
-            )
+            self.personal
+                .get_personal_info(self.base.daycare.get_egg_species(), 0)
         };
 
         let mut nature = rng.next_u32_max(25) as u8;
@@ -32,6 +32,7 @@ pub struct EventGenerator5<'a, 'b, 'c> {
     pub base: Generator<'a, 'b, Profile5, StateFilter5>,
     pub pgf: &'c PGF,
     pub wondercard_advances: u8,
+    pub info: PersonalInfo,
 }
 
 impl<'a, 'c, 'b> EventGenerator5<'a, 'b, 'c> {
@@ -54,6 +55,7 @@ impl<'a, 'c, 'b> EventGenerator5<'a, 'b, 'c> {
             ),
             pgf,
             wondercard_advances: pgf.get_advances(),
+            info: personal_loader::resolve_personal_info(profile.get_version(), pgf.species, 0),
         };
 
         if !pgf.egg {
@@ -64,11 +66,7 @@ impl<'a, 'c, 'b> EventGenerator5<'a, 'b, 'c> {
     }
 
     pub fn generate(&self, seed: u64) -> Vec<State5> {
-        let info = personal_loader::get_personal_info(
-            self.base.profile.get_version(),
-            self.pgf.species,
-            0,
-        );
+        let info = &self.info;
 
         let advances = util::utilities5::initial_advances(seed, self.base.profile);
         let mut rng = BWRNG::new_with_initial_advances(
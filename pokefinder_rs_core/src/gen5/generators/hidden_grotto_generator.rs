@@ -0,0 +1,115 @@
+use crate::gen5::filters::HiddenGrottoFilter;
+use crate::gen5::states::HiddenGrottoState;
+use crate::gen5::{HiddenGrottoArea, Profile5};
+use crate::rng::BWRNG;
+use crate::util;
+
+const SLOT_TABLE: [u8; 11] = [1, 5, 20, 21, 25, 35, 60, 61, 65, 75, 100];
+
+fn get_slot(rand: u8) -> u8 {
+    SLOT_TABLE.iter().position(|&slot| rand < slot).unwrap() as u8
+}
+
+/// Hidden Grotto generator for BW2
+///
+/// Slots 0-2 are pokemon, 3-6 are items and 7-10 are hidden items
+#[derive(Copy, Clone)]
+pub struct HiddenGrottoGenerator<'a, 'b> {
+    /// Profile information
+    pub profile: &'a Profile5,
+    /// State filter
+    pub filter: &'b HiddenGrottoFilter,
+    /// Initial number of advances
+    pub initial_advances: u32,
+    /// Maximum number of advances
+    pub max_advances: u32,
+    /// Number of advances to offset
+    pub delay: u32,
+    /// Percent chance that the grotto is refilled
+    pub power_level: u8,
+}
+
+impl<'a, 'b> HiddenGrottoGenerator<'a, 'b> {
+    /// Construct a new [`HiddenGrottoGenerator`] struct
+    ///
+    /// `power_level` is the percent chance that the grotto is refilled
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        power_level: u8,
+        profile: &'a Profile5,
+        filter: &'b HiddenGrottoFilter,
+    ) -> Self {
+        Self {
+            profile,
+            filter,
+            initial_advances,
+            max_advances,
+            delay,
+            power_level,
+        }
+    }
+
+    /// Generates states for the `area`
+    pub fn generate(&self, seed: u64, area: &HiddenGrottoArea) -> Vec<HiddenGrottoState> {
+        let advances = util::utilities5::initial_advances(seed, self.profile);
+        let mut rng = BWRNG::new_with_initial_advances(
+            seed,
+            advances
+                .wrapping_add(self.initial_advances)
+                .wrapping_add(self.delay),
+        );
+
+        let mut states = vec![];
+        for cnt in 0..=self.max_advances {
+            let mut go = rng;
+            let prng = rng.next_u32_max(0x1FFF) as u16;
+
+            if go.next_u32_max(100) as u8 >= self.power_level {
+                continue;
+            }
+
+            let group = go.next_u32_max(4) as u8;
+            let slot = get_slot(go.next_u32_max(100) as u8);
+            let advances = advances
+                .wrapping_add(self.initial_advances)
+                .wrapping_add(cnt);
+
+            let state = match slot {
+                0..=2 => {
+                    let pokemon = area.get_pokemon(group, slot);
+                    let gender = (go.next_u32_max(100) < pokemon.gender as u32) as u8;
+                    HiddenGrottoState::new_species(
+                        prng,
+                        advances,
+                        group,
+                        slot,
+                        pokemon.species,
+                        gender,
+                    )
+                }
+                3..=6 => HiddenGrottoState::new_item(
+                    prng,
+                    advances,
+                    group,
+                    slot,
+                    area.get_item(group, slot - 3),
+                ),
+                _ => HiddenGrottoState::new_item(
+                    prng,
+                    advances,
+                    group,
+                    slot,
+                    area.get_hidden_item(group, slot - 7),
+                ),
+            };
+
+            if self.filter.compare_state(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+}
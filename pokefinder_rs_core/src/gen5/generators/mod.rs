@@ -1,9 +1,15 @@
 mod dream_radar_generator;
 mod egg_generator5;
 mod event_generator5;
+mod hidden_grotto_generator;
 mod id_generator5;
+mod static_generator5;
+mod wild_generator5;
 
 pub use dream_radar_generator::*;
 pub use egg_generator5::*;
 pub use event_generator5::*;
+pub use hidden_grotto_generator::*;
 pub use id_generator5::*;
+pub use static_generator5::*;
+pub use wild_generator5::*;
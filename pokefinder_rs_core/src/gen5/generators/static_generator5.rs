@@ -0,0 +1,310 @@
+use crate::enums::{Encounter, Game, Lead, Method, Shiny};
+use crate::gen5::filters::StateFilter5;
+use crate::gen5::states::State5;
+use crate::gen5::Profile5;
+use crate::parents::filters::Filter;
+use crate::parents::generators::StaticGenerator;
+use crate::parents::{PersonalInfo, Profile, StaticTemplate, Template};
+use crate::rng::{RNGList, Rng, BWRNG, MT};
+use crate::util;
+
+fn gen(rng: &mut MT) -> u32 {
+    rng.next() >> 27
+}
+
+fn get_ability(pid: u32, static_template: &StaticTemplate) -> u8 {
+    match static_template.get_ability() {
+        255 => ((pid >> 16) & 1) as u8,
+        ability => ability,
+    }
+}
+
+fn get_gender(pid: u32, info: &PersonalInfo) -> u8 {
+    match info.get_gender() {
+        255 => 2,
+        254 => 1,
+        0 => 0,
+        i => (((pid & 255) as u8) < i) as u8,
+    }
+}
+
+fn get_shiny(pid: u32, tsv: u16) -> u8 {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    if tsv == psv {
+        2
+    } else if (tsv ^ psv) < 8 {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_shiny(pid: u32, tsv: u16) -> bool {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    (psv ^ tsv) < 8
+}
+
+/// Static encounter generator for Gen5
+#[derive(Clone)]
+pub struct StaticGenerator5<'a, 'b> {
+    /// Base static generator data
+    pub base: StaticGenerator<'a, 'b, Profile5, StateFilter5>,
+    /// Encounter type
+    pub encounter: Encounter,
+}
+
+impl<'a, 'b> StaticGenerator5<'a, 'b> {
+    /// Construct a new [`StaticGenerator5`] struct
+    ///
+    /// `encounter` should be one of [`Encounter::Static`], [`Encounter::Gift`] or
+    /// [`Encounter::Roamer`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        method: Method,
+        encounter: Encounter,
+        lead: Lead,
+        profile: &'a Profile5,
+        filter: &'b StateFilter5,
+    ) -> Self {
+        Self {
+            base: StaticGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                method,
+                lead,
+                profile,
+                filter,
+            ),
+            encounter,
+        }
+    }
+
+    /// Generates states for the `static_template`
+    ///
+    /// [`Method::Method5IVs`] and [`Method::Method5CGear`] only generate IVs while
+    /// [`Method::Method5`] only generates the PID information
+    pub fn generate(&self, seed: u64, static_template: &StaticTemplate) -> Vec<State5> {
+        match self.base.base.method {
+            Method::Method5IVs | Method::Method5CGear => self.generate_ivs(seed, static_template),
+            Method::Method5 => match self.encounter {
+                Encounter::Gift | Encounter::Roamer => self.generate_gift(seed, static_template),
+                _ => self.generate_stationary(seed, static_template),
+            },
+            _ => vec![],
+        }
+    }
+
+    fn generate_ivs(&self, seed: u64, static_template: &StaticTemplate) -> Vec<State5> {
+        let info = &static_template.resolve_info();
+        let roamer = self.encounter == Encounter::Roamer;
+
+        let mut offset = 0;
+        if (self.base.base.profile.get_version() & Game::BW2) != Game::NONE {
+            offset += 2;
+        }
+        if self.base.base.method == Method::Method5CGear {
+            offset += 2;
+        }
+        if roamer {
+            offset += 1;
+        }
+
+        let rng = MT::new_with_initial_advances(
+            (seed >> 32) as u32,
+            self.base.base.initial_advances.wrapping_add(offset),
+        );
+        let mut rng_list = RNGList::<u32, MT, 8>::new(rng, Some(gen));
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut ivs = [0; 6];
+            ivs.iter_mut().for_each(|iv| *iv = rng_list.next() as u8);
+            rng_list.advance_state();
+
+            // Roamers generate their IVs as HP, Atk, Def, SpD, Spe, SpA
+            if roamer {
+                ivs = [ivs[0], ivs[1], ivs[2], ivs[5], ivs[3], ivs[4]];
+            }
+
+            let state = State5::new(
+                0,
+                self.base.base.initial_advances.wrapping_add(cnt),
+                0,
+                ivs,
+                0,
+                0,
+                static_template.get_level(),
+                0,
+                0,
+                info,
+            );
+
+            if self.base.base.filter.compare_iv(state.base.base.ivs)
+                && self
+                    .base
+                    .base
+                    .filter
+                    .compare_hidden_power(state.base.base.hidden_power)
+            {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    fn generate_gift(&self, seed: u64, static_template: &StaticTemplate) -> Vec<State5> {
+        let info = &static_template.resolve_info();
+
+        let advances = util::utilities5::initial_advances(seed, self.base.base.profile);
+        let mut rng = BWRNG::new_with_initial_advances(
+            seed,
+            advances
+                .wrapping_add(self.base.base.initial_advances)
+                .wrapping_add(self.base.base.delay),
+        );
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut go = rng;
+            let prng = rng.next_u32_max(0x1FFF) as u16;
+
+            let mut pid = go.next_u32();
+            if static_template.get_shiny() == Shiny::Never && is_shiny(pid, self.base.base.tsv) {
+                pid ^= 0x10000000;
+            }
+
+            let nature = go.next_u32_max(25) as u8;
+
+            let state = State5::new(
+                prng,
+                advances
+                    .wrapping_add(self.base.base.initial_advances)
+                    .wrapping_add(cnt),
+                pid,
+                [0; 6],
+                get_ability(pid, static_template),
+                get_gender(pid, info),
+                static_template.get_level(),
+                nature,
+                get_shiny(pid, self.base.base.tsv),
+                info,
+            );
+
+            if self.compare_pid(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    fn generate_stationary(&self, seed: u64, static_template: &StaticTemplate) -> Vec<State5> {
+        let info = &static_template.resolve_info();
+        let lead = self.base.lead;
+        let tsv = self.base.base.tsv;
+
+        let cute_charm = matches!(lead, Lead::CUTE_CHARM_F | Lead::CUTE_CHARM_M)
+            && !matches!(info.get_gender(), 0 | 254 | 255);
+        let rolls = if self.base.base.profile.get_shiny_charm()
+            && static_template.get_shiny() != Shiny::Never
+        {
+            3
+        } else {
+            1
+        };
+
+        let advances = util::utilities5::initial_advances(seed, self.base.base.profile);
+        let mut rng = BWRNG::new_with_initial_advances(
+            seed,
+            advances
+                .wrapping_add(self.base.base.initial_advances)
+                .wrapping_add(self.base.base.delay),
+        );
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut go = rng;
+            let prng = rng.next_u32_max(0x1FFF) as u16;
+
+            let mut sync = false;
+            let mut cute_charm_flag = false;
+            if lead <= Lead::SYNCHRONIZE_END {
+                sync = (go.next_u32() >> 31) == 1;
+            } else if cute_charm {
+                cute_charm_flag = (go.next_u32_max(0xFFFF) / 656) < 67;
+            } else {
+                go.advance(1);
+            }
+
+            let gender = if cute_charm_flag {
+                if lead == Lead::CUTE_CHARM_F {
+                    0
+                } else {
+                    1
+                }
+            } else {
+                static_template.get_gender()
+            };
+
+            let mut pid = 0;
+            for _ in 0..rolls {
+                pid = go.next_u32() ^ 0x10000;
+                if matches!(gender, 0 | 1) {
+                    pid = util::utilities5::force_gender(pid, &mut go, gender, info.get_gender());
+                }
+
+                if ((pid >> 31) ^ (pid & 1) ^ (tsv & 1) as u32) == 1 {
+                    pid ^= 0x80000000;
+                }
+
+                if is_shiny(pid, tsv) {
+                    break;
+                }
+            }
+
+            if static_template.get_shiny() == Shiny::Never && is_shiny(pid, tsv) {
+                pid ^= 0x10000000;
+            }
+
+            let nature = if sync {
+                lead.bits()
+            } else {
+                go.next_u32_max(25) as u8
+            };
+
+            let state = State5::new(
+                prng,
+                advances
+                    .wrapping_add(self.base.base.initial_advances)
+                    .wrapping_add(cnt),
+                pid,
+                [0; 6],
+                get_ability(pid, static_template),
+                get_gender(pid, info),
+                static_template.get_level(),
+                nature,
+                get_shiny(pid, tsv),
+                info,
+            );
+
+            if self.compare_pid(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    fn compare_pid(&self, state: &State5) -> bool {
+        let filter = self.base.base.filter;
+        filter.compare_ability(state.base.base.ability)
+            && filter.compare_gender(state.base.base.gender)
+            && filter.compare_nature(state.base.base.nature)
+            && filter.compare_shiny(state.base.base.shiny)
+    }
+}
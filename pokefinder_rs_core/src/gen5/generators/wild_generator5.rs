@@ -0,0 +1,275 @@
+use crate::enums::{Encounter, Game, Lead, Method};
+use crate::gen5::filters::WildStateFilter5;
+use crate::gen5::states::WildState5;
+use crate::gen5::{EncounterArea5, Profile5};
+use crate::parents::filters::{Filter, WildFilter};
+use crate::parents::generators::WildGenerator;
+use crate::parents::{EncounterAreaT, PersonalInfo, Profile};
+use crate::rng::{RNGList, Rng, BWRNG, MT};
+use crate::util;
+use crate::util::encounter_slot;
+
+fn gen(rng: &mut MT) -> u32 {
+    rng.next() >> 27
+}
+
+fn get_gender(pid: u32, info: &PersonalInfo) -> u8 {
+    match info.get_gender() {
+        255 => 2,
+        254 => 1,
+        0 => 0,
+        i => (((pid & 255) as u8) < i) as u8,
+    }
+}
+
+fn get_item(rand: u8, lead: Lead, info: &PersonalInfo) -> u16 {
+    const ITEM_TABLE_RANGE: [[u8; 2]; 2] = [[50, 55], [60, 80]];
+
+    let range = ITEM_TABLE_RANGE[if lead == Lead::COMPOUND_EYES { 1 } else { 0 }];
+    if rand < range[0] || (info.get_item(0) == info.get_item(1) && info.get_item(0) != 0) {
+        info.get_item(0)
+    } else if rand < range[1] {
+        info.get_item(1)
+    } else {
+        0
+    }
+}
+
+fn get_shiny(pid: u32, tsv: u16) -> u8 {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    if tsv == psv {
+        2
+    } else if (tsv ^ psv) < 8 {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_shiny(pid: u32, tsv: u16) -> bool {
+    let psv = ((pid >> 16) ^ (pid & 0xFFFF)) as u16;
+    (psv ^ tsv) < 8
+}
+
+/// Wild encounter generator for Gen5
+#[derive(Clone)]
+pub struct WildGenerator5<'a, 'b> {
+    /// Base wild generator data
+    pub base: WildGenerator<'a, 'b, Profile5, WildStateFilter5>,
+}
+
+impl<'a, 'b> WildGenerator5<'a, 'b> {
+    /// Construct a new [`WildGenerator5`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        method: Method,
+        encounter: Encounter,
+        lead: Lead,
+        profile: &'a Profile5,
+        filter: &'b WildStateFilter5,
+    ) -> Self {
+        Self {
+            base: WildGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                method,
+                encounter,
+                lead,
+                profile,
+                filter,
+            ),
+        }
+    }
+
+    /// Generates states for the `encounter_area`
+    ///
+    /// [`Method::Method5IVs`] and [`Method::Method5CGear`] only generate IVs and report the
+    /// first slot of the `encounter_area` while [`Method::Method5`] only generates the PID
+    /// information
+    pub fn generate(&self, seed: u64, encounter_area: &EncounterArea5) -> Vec<WildState5> {
+        match self.base.base.method {
+            Method::Method5IVs | Method::Method5CGear => self.generate_ivs(seed, encounter_area),
+            Method::Method5 => self.generate_method_5(seed, encounter_area),
+            _ => vec![],
+        }
+    }
+
+    fn generate_ivs(&self, seed: u64, encounter_area: &EncounterArea5) -> Vec<WildState5> {
+        let slot = encounter_area.get_specific_pokemon(0);
+        let info = slot.get_info();
+
+        let mut offset = 0;
+        if (self.base.base.profile.get_version() & Game::BW2) != Game::NONE {
+            offset += 2;
+        }
+        if self.base.base.method == Method::Method5CGear {
+            offset += 2;
+        }
+
+        let rng = MT::new_with_initial_advances(
+            (seed >> 32) as u32,
+            self.base.base.initial_advances.wrapping_add(offset),
+        );
+        let mut rng_list = RNGList::<u32, MT, 8>::new(rng, Some(gen));
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut ivs = [0; 6];
+            ivs.iter_mut().for_each(|iv| *iv = rng_list.next() as u8);
+            rng_list.advance_state();
+
+            let state = WildState5::new(
+                0,
+                self.base.base.initial_advances.wrapping_add(cnt),
+                0,
+                ivs,
+                0,
+                0,
+                encounter_area.calculate_level(0),
+                0,
+                0,
+                0,
+                0,
+                slot.get_species(),
+                slot.get_form(),
+                info,
+            );
+
+            if self.base.base.filter.compare_iv(state.base.base.base.ivs)
+                && self
+                    .base
+                    .base
+                    .filter
+                    .compare_hidden_power(state.base.base.base.hidden_power)
+            {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+
+    fn generate_method_5(&self, seed: u64, encounter_area: &EncounterArea5) -> Vec<WildState5> {
+        let lead = self.base.lead;
+        let tsv = self.base.base.tsv;
+        let rolls = if self.base.base.profile.get_shiny_charm() {
+            3
+        } else {
+            1
+        };
+
+        let advances = util::utilities5::initial_advances(seed, self.base.base.profile);
+        let mut rng = BWRNG::new_with_initial_advances(
+            seed,
+            advances
+                .wrapping_add(self.base.base.initial_advances)
+                .wrapping_add(self.base.base.delay),
+        );
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut go = rng;
+            let prng = rng.next_u32_max(0x1FFF) as u16;
+
+            let mut sync = false;
+            let mut cute_charm_flag = false;
+            if lead <= Lead::SYNCHRONIZE_END {
+                sync = (go.next_u32() >> 31) == 1;
+            } else if matches!(lead, Lead::CUTE_CHARM_F | Lead::CUTE_CHARM_M) {
+                cute_charm_flag = (go.next_u32_max(0xFFFF) / 656) < 67;
+            } else {
+                go.advance(1);
+            }
+
+            let encounter_slot =
+                encounter_slot::bw_slot(go.next_u32_max(100) as u8, self.base.encounter);
+            if !self.base.base.filter.compare_encounter_slot(encounter_slot) {
+                continue;
+            }
+
+            let level = if matches!(
+                self.base.encounter,
+                Encounter::Surfing
+                    | Encounter::SpecialSurf
+                    | Encounter::SuperRod
+                    | Encounter::SpecialSuperRod
+            ) {
+                encounter_area
+                    .calculate_level_with_prng_state(encounter_slot, go.next_u32_max(100) as u16)
+            } else {
+                encounter_area.calculate_level(encounter_slot)
+            };
+
+            let slot = encounter_area.get_specific_pokemon(encounter_slot as usize);
+            let info = slot.get_info();
+
+            let gender = if cute_charm_flag && !matches!(info.get_gender(), 0 | 254 | 255) {
+                if lead == Lead::CUTE_CHARM_F {
+                    0
+                } else {
+                    1
+                }
+            } else {
+                2
+            };
+
+            let mut pid = 0;
+            for _ in 0..rolls {
+                pid = go.next_u32() ^ 0x10000;
+                if gender != 2 {
+                    pid = util::utilities5::force_gender(pid, &mut go, gender, info.get_gender());
+                }
+
+                if ((pid >> 31) ^ (pid & 1) ^ (tsv & 1) as u32) == 1 {
+                    pid ^= 0x80000000;
+                }
+
+                if is_shiny(pid, tsv) {
+                    break;
+                }
+            }
+
+            let nature = if sync {
+                lead.bits()
+            } else {
+                go.next_u32_max(25) as u8
+            };
+
+            let item = get_item(go.next_u32_max(100) as u8, lead, info);
+
+            let state = WildState5::new(
+                prng,
+                advances
+                    .wrapping_add(self.base.base.initial_advances)
+                    .wrapping_add(cnt),
+                pid,
+                [0; 6],
+                ((pid >> 16) & 1) as u8,
+                get_gender(pid, info),
+                level,
+                nature,
+                get_shiny(pid, tsv),
+                encounter_slot,
+                item,
+                slot.get_species(),
+                slot.get_form(),
+                info,
+            );
+
+            let filter = self.base.base.filter;
+            if filter.compare_ability(state.base.base.base.ability)
+                && filter.compare_gender(state.base.base.base.gender)
+                && filter.compare_nature(state.base.base.base.nature)
+                && filter.compare_shiny(state.base.base.base.shiny)
+            {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+}
@@ -3,7 +3,7 @@ use crate::util::translator;
 
 #[derive(Copy, Clone)]
 pub struct HiddenGrottoSlot {
-    pub info: &'static PersonalInfo,
+    pub info: PersonalInfo,
     pub species: u16,
     pub gender: u8,
     pub max_level: u8,
@@ -16,10 +16,10 @@ impl HiddenGrottoSlot {
         gender: u8,
         min_level: u8,
         max_level: u8,
-        info: &'static PersonalInfo,
+        info: &PersonalInfo,
     ) -> Self {
         Self {
-            info,
+            info: *info,
             species,
             gender,
             max_level,
@@ -64,7 +64,7 @@ impl HiddenGrottoArea {
     }
 
     pub fn get_pokemon(&self, group: u8, index: u8) -> HiddenGrottoSlot {
-        self.pokemon[(group as usize) * 4 + (index as usize)]
+        self.pokemon[(group as usize) * 3 + (index as usize)]
     }
 
     pub fn get_species_names(&self) -> Vec<String> {
@@ -8,6 +8,7 @@ pub mod keypresses;
 pub mod nazos;
 mod pgf;
 mod profile5;
+pub mod searchers;
 pub mod states;
 
 pub use dream_radar_template::*;
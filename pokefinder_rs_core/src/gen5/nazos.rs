@@ -316,5 +316,7 @@ pub fn get_nazos(version: Game, language: Language, ds_type: DSType) -> [u32; 5]
             }
             _ => [0; 5],
         },
+        // Black and White were never released in Chinese
+        Language::Chinese => [0; 5],
     }
 }
@@ -5,23 +5,64 @@ use serde::{Deserialize, Serialize};
 /// Provides additional storage specific to Gen5
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
 pub struct Profile5 {
-    name: String,
-    version: Game,
-    sid: u16,
-    tid: u16,
-    mac: u64,
-    key_presses: [bool; 4],
-    timer0_max: u16,
-    timer0_min: u16,
-    memory_link: bool,
-    shiny_charm: bool,
-    skip_lr: bool,
-    soft_reset: bool,
-    ds_type: DSType,
-    language: Language,
-    gx_stat: u8,
-    vcount: u8,
-    vframe: u8,
+    /// Profile name
+    pub name: String,
+    /// Profile version
+    pub version: Game,
+    /// Profile SID
+    pub sid: u16,
+    /// Profile TID
+    pub tid: u16,
+    /// MAC address of the DS
+    pub mac: u64,
+    /// Which key press counts (none, one, two, three) are allowed
+    pub key_presses: [bool; 4],
+    /// Maximum Timer0 value
+    pub timer0_max: u16,
+    /// Minimum Timer0 value
+    pub timer0_min: u16,
+    /// Whether memory link is activated
+    pub memory_link: bool,
+    /// Whether the shiny charm is obtained
+    pub shiny_charm: bool,
+    /// Whether key combinations with both L and R are skipped
+    pub skip_lr: bool,
+    /// Whether the game is soft reset instead of started from power on
+    pub soft_reset: bool,
+    /// DS model the game is played on
+    pub ds_type: DSType,
+    /// Language of the game
+    pub language: Language,
+    /// GxStat value
+    pub gx_stat: u8,
+    /// VCount value
+    pub vcount: u8,
+    /// VFrame value
+    pub vframe: u8,
+}
+
+impl Default for Profile5 {
+    fn default() -> Self {
+        Self {
+            name: "-".to_string(),
+            version: Game::BLACK,
+            sid: 54321,
+            tid: 12345,
+            mac: 0x9BF123456,
+            key_presses: [true; 4],
+            timer0_max: 0xC7A,
+            timer0_min: 0xC79,
+            memory_link: false,
+            shiny_charm: false,
+            skip_lr: false,
+            soft_reset: false,
+            ds_type: DSType::DS,
+            language: Language::English,
+            gx_stat: 6,
+            vcount: 0x60,
+            vframe: 5,
+        }
+    }
 }
 
 impl Profile for Profile5 {
@@ -140,6 +181,7 @@ impl Profile5 {
             Language::Japanese => "JPN",
             Language::Korean => "KOR",
             Language::Spanish => "SPA",
+            Language::Chinese => "CHN",
         }
     }
 
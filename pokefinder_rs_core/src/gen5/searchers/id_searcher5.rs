@@ -0,0 +1,44 @@
+use crate::gen5::generators::IDGenerator5;
+use crate::gen5::searchers::{Searcher5, SeedGenerator5};
+use crate::parents::states::IDState;
+
+/// Binds an [`IDGenerator5`] to the PID options used by an [`IDSearcher5`]
+#[derive(Copy, Clone)]
+pub struct IDSearcherGenerator5<'a, 'b> {
+    generator: IDGenerator5<'a, 'b>,
+    pid: u32,
+    check_pid: bool,
+    check_xor: bool,
+}
+
+impl<'a, 'b> IDSearcherGenerator5<'a, 'b> {
+    /// Construct a new [`IDSearcherGenerator5`] struct
+    ///
+    /// When `check_pid` is set only IDs that make `pid` shiny are kept. `check_xor`
+    /// additionally requires the TID/SID bit to match the PID bit.
+    pub fn new(
+        generator: IDGenerator5<'a, 'b>,
+        pid: u32,
+        check_pid: bool,
+        check_xor: bool,
+    ) -> Self {
+        Self {
+            generator,
+            pid,
+            check_pid,
+            check_xor,
+        }
+    }
+}
+
+impl SeedGenerator5 for IDSearcherGenerator5<'_, '_> {
+    type State = IDState;
+
+    fn generate(&self, seed: u64) -> Vec<Self::State> {
+        self.generator
+            .generate(seed, self.pid, self.check_pid, self.check_xor)
+    }
+}
+
+/// TID/SID searcher for Gen5
+pub type IDSearcher5<'a, 'b, 'c> = Searcher5<'a, IDSearcherGenerator5<'b, 'c>>;
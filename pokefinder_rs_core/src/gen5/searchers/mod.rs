@@ -0,0 +1,9 @@
+mod id_searcher5;
+mod mt_seed_searcher5;
+mod profile_searcher5;
+mod searcher5;
+
+pub use id_searcher5::*;
+pub use mt_seed_searcher5::*;
+pub use profile_searcher5::*;
+pub use searcher5::*;
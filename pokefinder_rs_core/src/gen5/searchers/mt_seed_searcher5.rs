@@ -0,0 +1,178 @@
+use crate::enums::Game;
+use crate::gen5::states::MTSeedState5;
+use crate::parents::searchers::SearcherT;
+use crate::rng::MTFast;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Number of MT outputs computed for each seed
+const MT_SIZE: usize = 32;
+/// Last IV frame that still fits six IVs into the computed MT outputs
+const MAX_FRAME: u32 = MT_SIZE as u32 - 6;
+
+/// Error returned when the advances of a [`MTSeedSearcher5`] are past the last searchable frame
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MaxFrameError {
+    /// Last IV frame that can be searched for the version
+    pub max_frame: u32,
+}
+
+impl Display for MaxFrameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IV frames past {} can not be searched", self.max_frame)
+    }
+}
+
+impl std::error::Error for MaxFrameError {}
+
+/// Searcher that recovers the MT seed of a Gen5 Pokemon from its IVs
+///
+/// The MT seed is the upper 32 bits of the BWRNG initial seed, so every 32-bit seed is checked
+/// for the IVs on the frames between `initial_advances` and `max_advances`.
+#[derive(Clone)]
+pub struct MTSeedSearcher5 {
+    results: Arc<Mutex<Vec<MTSeedState5>>>,
+    progress: Arc<AtomicU32>,
+    searching: Arc<AtomicBool>,
+    min_ivs: [u8; 6],
+    max_ivs: [u8; 6],
+    initial_advances: u32,
+    max_advances: u32,
+    offset: u32,
+}
+
+impl MTSeedSearcher5 {
+    /// Construct a new [`MTSeedSearcher5`] struct
+    ///
+    /// BW2 skips the first two MT advances before generating IVs, so the last frame that can be
+    /// searched is 26 for BW and 24 for BW2. Returns a [`MaxFrameError`] if `max_advances` is
+    /// past that frame.
+    pub fn new(
+        min_ivs: [u8; 6],
+        max_ivs: [u8; 6],
+        initial_advances: u32,
+        max_advances: u32,
+        version: Game,
+    ) -> Result<Self, MaxFrameError> {
+        let offset = if (version & Game::BW2) != Game::NONE {
+            2
+        } else {
+            0
+        };
+        if max_advances > MAX_FRAME - offset {
+            return Err(MaxFrameError {
+                max_frame: MAX_FRAME - offset,
+            });
+        }
+
+        Ok(Self {
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            searching: Arc::new(AtomicBool::new(false)),
+            min_ivs,
+            max_ivs,
+            initial_advances,
+            max_advances,
+            offset,
+        })
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search, which ends at 0x10000
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the states of the running search
+    pub fn get_results(&self) -> Vec<MTSeedState5> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    ///
+    /// The upper 16 bits of the seed are split between `threads` and the function returns once
+    /// every thread has finished.
+    pub fn start_search(&self, threads: usize) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let threads = threads.clamp(1, 0x10000);
+        let split = 0x10000 / threads as u32;
+
+        thread::scope(|scope| {
+            let mut start = 0;
+            for i in 0..threads {
+                let end = if i == threads - 1 {
+                    0x10000
+                } else {
+                    start + split
+                };
+
+                scope.spawn(move || self.search(start, end));
+
+                start = end;
+            }
+        });
+    }
+
+    fn search(&self, start: u32, end: u32) {
+        let mut states = vec![];
+        let count = (self.max_advances.saturating_sub(self.initial_advances) + 6) as usize;
+
+        for high in start..end {
+            if !self.searching.load(Ordering::SeqCst) {
+                break;
+            }
+
+            for low in 0..0x10000 {
+                let seed = (high << 16) | low;
+                let mut mt =
+                    MTFast::<MT_SIZE, 36, true>::new(seed, self.initial_advances + self.offset);
+
+                let mut ivs = [0; MT_SIZE];
+                ivs.iter_mut()
+                    .take(count)
+                    .for_each(|iv| *iv = mt.next() as u8);
+
+                for (cnt, window) in ivs[..count].windows(6).enumerate() {
+                    if window
+                        .iter()
+                        .zip(self.min_ivs.iter().zip(self.max_ivs.iter()))
+                        .all(|(iv, (min, max))| iv >= min && iv <= max)
+                    {
+                        states.push(MTSeedState5::new(
+                            seed,
+                            self.initial_advances + cnt as u32,
+                            window.try_into().unwrap(),
+                        ));
+                    }
+                }
+            }
+
+            self.progress.fetch_add(1, Ordering::SeqCst);
+        }
+
+        self.results.lock().unwrap().append(&mut states);
+    }
+}
+
+impl SearcherT for MTSeedSearcher5 {
+    type State = MTSeedState5;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -0,0 +1,324 @@
+use crate::enums::{Buttons, DSType, Game, Language};
+use crate::gen5::keypresses;
+use crate::gen5::states::ProfileSearcherState5;
+use crate::parents::searchers::SearcherT;
+use crate::rng::{MTFast, BWRNG, SHA1};
+use crate::util::utilities5;
+use crate::util::{Date, Time};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Trait for the checks a [`ProfileSearcher5`] runs against each computed initial seed
+pub trait ProfileCriteria5: Send + Sync {
+    /// Returns whether the initial `seed` matches the criteria
+    fn valid(&self, seed: u64) -> bool;
+}
+
+/// Profile criteria that matches the IVs generated from the initial seed
+#[derive(Clone)]
+pub struct ProfileIVCriteria5 {
+    min_ivs: [u8; 6],
+    max_ivs: [u8; 6],
+    offset: u32,
+}
+
+impl ProfileIVCriteria5 {
+    /// Construct a new [`ProfileIVCriteria5`] struct
+    ///
+    /// BW2 skips the first two MT advances before generating IVs
+    pub fn new(min_ivs: [u8; 6], max_ivs: [u8; 6], version: Game) -> Self {
+        Self {
+            min_ivs,
+            max_ivs,
+            offset: if (version & Game::BW2) != Game::NONE {
+                2
+            } else {
+                0
+            },
+        }
+    }
+}
+
+impl ProfileCriteria5 for ProfileIVCriteria5 {
+    fn valid(&self, seed: u64) -> bool {
+        let mut mt = MTFast::<8, 12, true>::new((seed >> 32) as u32, self.offset);
+        for (&min, &max) in self.min_ivs.iter().zip(self.max_ivs.iter()) {
+            let iv = mt.next() as u8;
+            if iv < min || iv > max {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Profile criteria that matches the needle directions shown after loading the game
+#[derive(Clone)]
+pub struct ProfileNeedleCriteria5 {
+    needles: Vec<u8>,
+    version: Game,
+    memory_link: bool,
+    unova_link: bool,
+}
+
+impl ProfileNeedleCriteria5 {
+    /// Construct a new [`ProfileNeedleCriteria5`] struct
+    ///
+    /// `unova_link` and `memory_link` are only used by BW2
+    pub fn new(needles: Vec<u8>, version: Game, unova_link: bool, memory_link: bool) -> Self {
+        Self {
+            needles,
+            version,
+            memory_link,
+            unova_link,
+        }
+    }
+}
+
+impl ProfileCriteria5 for ProfileNeedleCriteria5 {
+    fn valid(&self, seed: u64) -> bool {
+        let mut rng = BWRNG::new(seed);
+        if (self.version & Game::BW) != Game::NONE {
+            rng.advance(utilities5::initial_advances_bw(seed));
+            self.needles
+                .iter()
+                .all(|&needle| utilities5::get_needle(rng.next()) == needle)
+        } else {
+            rng.advance(utilities5::initial_advances_bw2(seed, self.memory_link));
+            if self.unova_link {
+                if !self.memory_link {
+                    rng.advance(1);
+                }
+
+                self.needles.iter().all(|&needle| {
+                    let valid = utilities5::get_needle(rng.next()) == needle;
+                    rng.advance(1);
+                    valid
+                })
+            } else {
+                self.needles
+                    .iter()
+                    .all(|&needle| utilities5::get_needle(rng.next()) == needle)
+            }
+        }
+    }
+}
+
+/// Profile criteria that matches a known initial seed
+#[derive(Clone)]
+pub struct ProfileSeedCriteria5 {
+    seed: u64,
+}
+
+impl ProfileSeedCriteria5 {
+    /// Construct a new [`ProfileSeedCriteria5`] struct
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl ProfileCriteria5 for ProfileSeedCriteria5 {
+    fn valid(&self, seed: u64) -> bool {
+        self.seed == seed
+    }
+}
+
+/// Searcher that finds the Timer0, VCount, VFrame and GxStat values of a Gen5 console
+///
+/// Every combination of the hardware parameter ranges is hashed for each second of the
+/// specified minute and checked against the criteria.
+#[derive(Clone)]
+pub struct ProfileSearcher5<C: ProfileCriteria5> {
+    criteria: Arc<C>,
+    results: Arc<Mutex<Vec<ProfileSearcherState5>>>,
+    progress: Arc<AtomicU32>,
+    searching: Arc<AtomicBool>,
+    date: Date,
+    time: Time,
+    min_seconds: u8,
+    max_seconds: u8,
+    min_vcount: u8,
+    max_vcount: u8,
+    min_timer0: u16,
+    max_timer0: u16,
+    min_gx_stat: u8,
+    max_gx_stat: u8,
+    min_vframe: u8,
+    max_vframe: u8,
+    soft_reset: bool,
+    version: Game,
+    language: Language,
+    ds_type: DSType,
+    mac: u64,
+    button: u32,
+}
+
+/// Profile searcher that matches IVs
+pub type ProfileIVSearcher5 = ProfileSearcher5<ProfileIVCriteria5>;
+/// Profile searcher that matches needle directions
+pub type ProfileNeedleSearcher5 = ProfileSearcher5<ProfileNeedleCriteria5>;
+/// Profile searcher that matches an initial seed
+pub type ProfileSeedSearcher5 = ProfileSearcher5<ProfileSeedCriteria5>;
+
+impl<C: ProfileCriteria5> ProfileSearcher5<C> {
+    /// Construct a new [`ProfileSearcher5`] struct
+    ///
+    /// `time` only provides the hour and minute, the seconds are searched from `min_seconds`
+    /// to `max_seconds`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        date: Date,
+        time: Time,
+        min_seconds: u8,
+        max_seconds: u8,
+        min_vcount: u8,
+        max_vcount: u8,
+        min_timer0: u16,
+        max_timer0: u16,
+        min_gx_stat: u8,
+        max_gx_stat: u8,
+        min_vframe: u8,
+        max_vframe: u8,
+        soft_reset: bool,
+        version: Game,
+        language: Language,
+        ds_type: DSType,
+        mac: u64,
+        keypress: Buttons,
+        criteria: C,
+    ) -> Self {
+        Self {
+            criteria: Arc::new(criteria),
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            searching: Arc::new(AtomicBool::new(false)),
+            date,
+            time,
+            min_seconds,
+            max_seconds,
+            min_vcount,
+            max_vcount,
+            min_timer0,
+            max_timer0,
+            min_gx_stat,
+            max_gx_stat,
+            min_vframe,
+            max_vframe,
+            soft_reset,
+            version,
+            language,
+            ds_type,
+            mac,
+            button: keypresses::get_values(&[keypress])[0],
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the states of the running search
+    pub fn get_results(&self) -> Vec<ProfileSearcherState5> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    ///
+    /// The Timer0 range is split between `threads` and the function returns once every thread
+    /// has finished.
+    pub fn start_search(&self, threads: usize) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let total = (self.max_timer0 - self.min_timer0) as usize + 1;
+        let threads = threads.clamp(1, total);
+        let split = (total / threads) as u16;
+
+        thread::scope(|scope| {
+            let mut start = self.min_timer0;
+            for i in 0..threads {
+                let end = if i == threads - 1 {
+                    self.max_timer0
+                } else {
+                    start + split - 1
+                };
+
+                scope.spawn(move || self.search(start, end));
+
+                start = end.wrapping_add(1);
+            }
+        });
+    }
+
+    fn search(&self, timer0_start: u16, timer0_end: u16) {
+        let mut states = vec![];
+
+        for vframe in self.min_vframe..=self.max_vframe {
+            for gx_stat in self.min_gx_stat..=self.max_gx_stat {
+                let mut sha = SHA1::new_with_parameters(
+                    self.version,
+                    self.language,
+                    self.ds_type,
+                    self.mac,
+                    self.soft_reset,
+                    vframe,
+                    gx_stat,
+                );
+                sha.set_date(self.date);
+                sha.set_button(self.button);
+
+                for vcount in self.min_vcount..=self.max_vcount {
+                    for timer0 in timer0_start..=timer0_end {
+                        if !self.searching.load(Ordering::SeqCst) {
+                            self.results.lock().unwrap().append(&mut states);
+                            return;
+                        }
+
+                        sha.set_timer0(timer0 as u32, vcount);
+                        sha.precompute();
+
+                        for second in self.min_seconds..=self.max_seconds {
+                            sha.set_time(
+                                Time::new_hms(self.time.hour(), self.time.minute(), second),
+                                self.ds_type,
+                            );
+
+                            let seed = sha.hash_seed();
+                            if self.criteria.valid(seed) {
+                                states.push(ProfileSearcherState5::new(
+                                    seed, timer0, vcount, vframe, gx_stat, second,
+                                ));
+                            }
+                        }
+
+                        self.progress.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            }
+        }
+
+        self.results.lock().unwrap().append(&mut states);
+    }
+}
+
+impl<C: ProfileCriteria5> SearcherT for ProfileSearcher5<C> {
+    type State = ProfileSearcherState5;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
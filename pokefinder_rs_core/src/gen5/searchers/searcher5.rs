@@ -0,0 +1,196 @@
+use crate::enums::Buttons;
+use crate::gen5::generators::{DreamRadarGenerator, EggGenerator5, EventGenerator5};
+use crate::gen5::states::{DreamRadarState, EggState5, SearcherState5, State5};
+use crate::gen5::{keypresses, Profile5};
+use crate::parents::searchers::SearcherT;
+use crate::rng::SHA1;
+use crate::util::{Date, DateTime, Time};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Trait for Gen5 generators that can be driven by a [`Searcher5`]
+pub trait SeedGenerator5: Sync {
+    /// State type returned by the generator
+    type State: Send;
+
+    /// Generates the states for the initial `seed`
+    fn generate(&self, seed: u64) -> Vec<Self::State>;
+}
+
+impl SeedGenerator5 for EventGenerator5<'_, '_, '_> {
+    type State = State5;
+
+    fn generate(&self, seed: u64) -> Vec<Self::State> {
+        EventGenerator5::generate(self, seed)
+    }
+}
+
+impl SeedGenerator5 for EggGenerator5<'_, '_, '_> {
+    type State = EggState5;
+
+    fn generate(&self, seed: u64) -> Vec<Self::State> {
+        EggGenerator5::generate(self, seed)
+    }
+}
+
+impl SeedGenerator5 for DreamRadarGenerator<'_, '_, '_> {
+    type State = DreamRadarState;
+
+    fn generate(&self, seed: u64) -> Vec<Self::State> {
+        DreamRadarGenerator::generate(self, seed)
+    }
+}
+
+/// Searcher that runs a Gen5 generator over every initial seed of a date range
+///
+/// Initial seeds are computed for every second of each day, every key press combination of
+/// the profile and every Timer0 value of the profile.
+#[derive(Clone)]
+pub struct Searcher5<'a, G: SeedGenerator5> {
+    generator: &'a G,
+    profile: &'a Profile5,
+    results: Arc<Mutex<Vec<SearcherState5<G::State>>>>,
+    progress: Arc<AtomicU32>,
+    searching: Arc<AtomicBool>,
+}
+
+/// Searcher for Gen5 wondercard events
+pub type EventSearcher5<'a, 'b, 'c, 'd> = Searcher5<'a, EventGenerator5<'b, 'c, 'd>>;
+/// Searcher for Gen5 eggs
+pub type EggSearcher5<'a, 'b, 'c, 'd> = Searcher5<'a, EggGenerator5<'b, 'c, 'd>>;
+/// Searcher for Gen5 Dream Radar encounters
+pub type DreamRadarSearcher5<'a, 'b, 'c, 'd> = Searcher5<'a, DreamRadarGenerator<'b, 'c, 'd>>;
+
+impl<'a, G: SeedGenerator5> Searcher5<'a, G> {
+    /// Construct a new [`Searcher5`] struct
+    pub fn new(generator: &'a G, profile: &'a Profile5) -> Self {
+        Self {
+            generator,
+            profile,
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            searching: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the states of the running search
+    pub fn get_results(&self) -> Vec<SearcherState5<G::State>> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    ///
+    /// The days from `start` to `end` (inclusive) are split between `threads` and the function
+    /// returns once every thread has finished.
+    pub fn start_search(&self, threads: usize, start: Date, end: Date) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let total = start.days_to(&end) as usize + 1;
+        let threads = threads.clamp(1, total);
+        let split = (total / threads) as u32;
+
+        thread::scope(|scope| {
+            let mut start = start;
+            for i in 0..threads {
+                let thread_end = if i == threads - 1 {
+                    end
+                } else {
+                    start.add_days(split - 1)
+                };
+
+                scope.spawn(move || self.search(start, thread_end));
+
+                start = thread_end.add_days(1);
+            }
+        });
+    }
+
+    fn search(&self, start: Date, end: Date) {
+        let buttons = keypresses::get_key_presses(self.profile);
+        let values = keypresses::get_values(&buttons);
+
+        let mut sha = SHA1::new(self.profile);
+        let mut states = vec![];
+
+        let mut date = start;
+        while date <= end {
+            sha.set_date(date);
+
+            for timer0 in self.profile.get_timer0_min()..=self.profile.get_timer0_max() {
+                sha.set_timer0(timer0 as u32, self.profile.get_vcount());
+                sha.precompute();
+
+                for (&button, &value) in buttons.iter().zip(values.iter()) {
+                    if !self.searching.load(Ordering::SeqCst) {
+                        self.results.lock().unwrap().append(&mut states);
+                        return;
+                    }
+
+                    sha.set_button(value);
+                    self.search_day(&mut sha, &mut states, date, button, timer0);
+
+                    self.progress.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+
+            self.results.lock().unwrap().append(&mut states);
+            date += 1;
+        }
+    }
+
+    fn search_day(
+        &self,
+        sha: &mut SHA1,
+        states: &mut Vec<SearcherState5<G::State>>,
+        date: Date,
+        buttons: Buttons,
+        timer0: u16,
+    ) {
+        let ds_type = self.profile.get_ds_type();
+
+        for hour in 0..24 {
+            for minute in 0..60 {
+                for second in 0..60 {
+                    let time = Time::new_hms(hour, minute, second);
+                    sha.set_time(time, ds_type);
+
+                    let seed = sha.hash_seed();
+                    let dt = DateTime::new_from_parts(&date, &time);
+                    states.extend(
+                        self.generator
+                            .generate(seed)
+                            .into_iter()
+                            .map(|state| SearcherState5::new(dt, seed, buttons, timer0, state)),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<G: SeedGenerator5> SearcherT for Searcher5<'_, G> {
+    type State = SearcherState5<G::State>;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
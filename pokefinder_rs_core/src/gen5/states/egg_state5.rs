@@ -1,7 +1,8 @@
 use crate::parents::states::{EggGeneratorState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct EggState5 {
     pub base: EggGeneratorState,
     pub chatot: u8,
@@ -1,13 +1,17 @@
 mod dream_radar_state;
 mod egg_state5;
 mod hidden_grotto_state;
+mod mt_seed_state5;
 mod profile_searcher_state5;
 mod searcher_state5;
 mod state5;
+mod wild_state5;
 
 pub use dream_radar_state::*;
 pub use egg_state5::*;
 pub use hidden_grotto_state::*;
+pub use mt_seed_state5::*;
 pub use profile_searcher_state5::*;
 pub use searcher_state5::*;
 pub use state5::*;
+pub use wild_state5::*;
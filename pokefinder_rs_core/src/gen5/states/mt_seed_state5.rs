@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// State of a MT seed whose IVs matched the ones searched for
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct MTSeedState5 {
+    /// MT seed, which is also the upper 32 bits of the BWRNG initial seed
+    pub seed: u32,
+    /// IV frame the IVs were found on
+    pub advances: u32,
+    /// IVs generated on the frame
+    pub ivs: [u8; 6],
+}
+
+impl MTSeedState5 {
+    /// Construct a new [`MTSeedState5`] struct
+    pub fn new(seed: u32, advances: u32, ivs: [u8; 6]) -> Self {
+        Self {
+            seed,
+            advances,
+            ivs,
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::enums::Buttons;
 use crate::util::DateTime;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct SearcherState5<T> {
     pub initial_seed: u64,
     pub dt: DateTime,
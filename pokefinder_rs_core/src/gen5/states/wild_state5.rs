@@ -0,0 +1,54 @@
+use crate::parents::states::{StateT, WildGeneratorState};
+use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct WildState5 {
+    pub base: WildGeneratorState,
+    pub chatot: u8,
+}
+
+impl WildState5 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        prng: u16,
+        advances: u32,
+        pid: u32,
+        ivs: [u8; 6],
+        ability: u8,
+        gender: u8,
+        level: u8,
+        nature: u8,
+        shiny: u8,
+        encounter_slot: u8,
+        item: u16,
+        species: u16,
+        form: u8,
+        info: &PersonalInfo,
+    ) -> Self {
+        Self {
+            base: WildGeneratorState::new(
+                advances,
+                pid,
+                ivs,
+                ability,
+                gender,
+                level,
+                nature,
+                shiny,
+                encounter_slot,
+                item,
+                species,
+                form,
+                info,
+            ),
+            chatot: (prng / 82) as u8,
+        }
+    }
+}
+
+impl StateT for WildState5 {
+    fn update_stats(&mut self, info: &PersonalInfo) {
+        self.base.update_stats(info);
+    }
+}
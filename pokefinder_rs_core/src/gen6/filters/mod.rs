@@ -0,0 +1,3 @@
+mod state_filter6;
+
+pub use state_filter6::*;
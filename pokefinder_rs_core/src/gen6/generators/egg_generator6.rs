@@ -0,0 +1,184 @@
+use crate::enums::Method;
+use crate::gen6::filters::StateFilter6;
+use crate::gen6::Profile6;
+use crate::parents::generators::EggGenerator;
+use crate::parents::personal_loader::{self, PersonalTable};
+use crate::parents::states::EggGeneratorState;
+use crate::parents::{Daycare, Profile};
+use crate::rng::TinyMT;
+
+fn rand(rng: &mut TinyMT, max: u32) -> u32 {
+    ((rng.next() as u64 * max as u64) >> 32) as u32
+}
+
+/// Egg generator for Gen6
+///
+/// The traits of an egg come from the TinyMT egg seed. The PID is decided by the main MT
+/// once the egg is received, so the generated states leave it and the shiny value at 0.
+#[derive(Clone)]
+pub struct EggGenerator6<'a, 'b, 'c> {
+    /// Base egg generator data
+    pub base: EggGenerator<'a, 'b, 'c, Profile6, StateFilter6>,
+    /// Personal table of the profile's game
+    pub personal: PersonalTable,
+}
+
+impl<'a, 'b, 'c> EggGenerator6<'a, 'b, 'c> {
+    /// Construct a new [`EggGenerator6`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        compatability: u8,
+        daycare: &'c Daycare,
+        profile: &'a Profile6,
+        filter: &'b StateFilter6,
+    ) -> Self {
+        let mut new = Self {
+            base: EggGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                Method::None,
+                compatability,
+                daycare,
+                profile,
+                filter,
+            ),
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
+        };
+        new.base.base.tsv = profile.get_tsv();
+        new
+    }
+
+    /// Generate [`EggGeneratorState`] states from the TinyMT egg `seed`
+    pub fn generate(&self, seed: [u32; 4]) -> Vec<EggGeneratorState> {
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let (male, female) = match self.base.daycare.get_egg_species() {
+            29 | 32 => (
+                Some(self.personal.get_personal_info(32, 0)),
+                Some(self.personal.get_personal_info(29, 0)),
+            ),
+            313 | 314 => (
+                Some(self.personal.get_personal_info(313, 0)),
+                Some(self.personal.get_personal_info(314, 0)),
+            ),
+            _ => (None, None),
+        };
+
+        let mut tiny = TinyMT::new_from_full(seed[0], seed[1], seed[2], seed[3]);
+        tiny.advance(
+            self.base
+                .base
+                .initial_advances
+                .wrapping_add(self.base.base.delay),
+        );
+
+        let inheritance_count = if self.base.daycare.get_parent_item(0) == 8
+            || self.base.daycare.get_parent_item(1) == 8
+        {
+            5
+        } else {
+            3
+        };
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut rng = tiny;
+
+            let mut info = base;
+            let gender = if matches!(self.base.daycare.get_egg_species(), 29 | 32 | 313 | 314) {
+                let gender = rand(&mut rng, 2) as u8;
+                if gender != 0 {
+                    info = female.unwrap();
+                } else {
+                    info = male.unwrap();
+                }
+                gender
+            } else {
+                match base.get_gender() {
+                    255 => 2,
+                    254 => 1,
+                    0 => 0,
+                    i => u8::from((rand(&mut rng, 252) as u8 + 1) < i),
+                }
+            };
+
+            let mut nature = rand(&mut rng, 25) as u8;
+            if self.base.daycare.get_everstone_count() == 2 {
+                nature = self.base.daycare.get_parent_nature(rand(&mut rng, 2) as u8);
+            } else if self.base.daycare.get_parent_item(0) == 1 {
+                nature = self.base.daycare.get_parent_nature(0);
+            } else if self.base.daycare.get_parent_item(1) == 1 {
+                nature = self.base.daycare.get_parent_nature(1);
+            }
+
+            let parent_ability = self.base.daycare.get_parent_ability(
+                if self.base.daycare.get_parent_gender(1) == 3 {
+                    0
+                } else {
+                    1
+                },
+            );
+            let mut ability = rand(&mut rng, 100) as u8;
+            if parent_ability == 2 {
+                ability = if ability < 20 {
+                    0
+                } else if ability < 40 {
+                    1
+                } else {
+                    2
+                };
+            } else if parent_ability == 1 {
+                ability = if ability < 20 { 0 } else { 1 };
+            } else {
+                ability = if ability < 80 { 0 } else { 1 };
+            }
+
+            let mut inheritance = [0; 6];
+            let mut i = 0;
+            while i < inheritance_count {
+                let index = rand(&mut rng, 6) as usize;
+                if inheritance[index] == 0 {
+                    inheritance[index] = rand(&mut rng, 2) as u8 + 1;
+                    i += 1;
+                }
+            }
+
+            let mut ivs = [0; 6];
+            for (i, iv) in ivs.iter_mut().enumerate() {
+                *iv = match inheritance[i] {
+                    1 => self.base.daycare.get_parent_iv(0, i),
+                    2 => self.base.daycare.get_parent_iv(1, i),
+                    _ => (rng.next() >> 27) as u8,
+                };
+            }
+
+            let ec = rng.next();
+
+            let state = EggGeneratorState::new_with_ec(
+                self.base.base.initial_advances.wrapping_add(cnt),
+                ec,
+                0,
+                ivs,
+                ability,
+                gender,
+                1,
+                nature,
+                0,
+                inheritance,
+                info,
+            );
+            if self.base.base.filter.compare_egg_state(&state) {
+                states.push(state);
+            }
+
+            tiny.next_state();
+        }
+
+        states
+    }
+}
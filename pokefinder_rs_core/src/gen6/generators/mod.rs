@@ -0,0 +1,7 @@
+mod egg_generator6;
+mod static_generator6;
+mod wild_generator6;
+
+pub use egg_generator6::*;
+pub use static_generator6::*;
+pub use wild_generator6::*;
@@ -0,0 +1,148 @@
+use crate::enums::{Lead, Method, Shiny};
+use crate::gen6::filters::StateFilter6;
+use crate::gen6::Profile6;
+use crate::parents::generators::StaticGenerator;
+use crate::parents::states::GeneratorState;
+use crate::parents::{StaticTemplate, Template};
+use crate::rng::{RNGList, MT};
+
+fn rand(prng: u32, max: u32) -> u32 {
+    ((prng as u64 * max as u64) >> 32) as u32
+}
+
+/// Static encounter generator for Gen6
+#[derive(Clone)]
+pub struct StaticGenerator6<'a, 'b> {
+    /// Base static generator data
+    pub base: StaticGenerator<'a, 'b, Profile6, StateFilter6>,
+    /// If the profile has the shiny charm unlocked
+    pub shiny_charm: bool,
+}
+
+impl<'a, 'b> StaticGenerator6<'a, 'b> {
+    /// Construct a new [`StaticGenerator6`] struct
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        lead: Lead,
+        profile: &'a Profile6,
+        filter: &'b StateFilter6,
+    ) -> Self {
+        let mut new = Self {
+            base: StaticGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                Method::None,
+                lead,
+                profile,
+                filter,
+            ),
+            shiny_charm: profile.get_shiny_charm(),
+        };
+        new.base.base.tsv = profile.get_tsv();
+        new
+    }
+
+    /// Generates states for the `static_template`
+    pub fn generate(&self, seed: u32, static_template: &StaticTemplate) -> Vec<GeneratorState> {
+        let info = &static_template.resolve_info();
+        let mt = MT::new_with_initial_advances(
+            seed,
+            self.base
+                .base
+                .initial_advances
+                .wrapping_add(self.base.base.delay),
+        );
+        let mut rng_list = RNGList::<u32, MT, 64>::new(mt, None);
+
+        let pid_rolls = if self.shiny_charm { 3 } else { 1 };
+        let synchronize = self.base.lead.bits() <= Lead::SYNCHRONIZE_END.bits();
+
+        let mut states = vec![];
+        let mut cnt = 0;
+        while cnt <= self.base.base.max_advances {
+            let sync = synchronize && rand(rng_list.next(), 100) >= 50;
+
+            let ec = rng_list.next();
+
+            let mut pid = 0;
+            let mut xor = 0;
+            for _ in 0..pid_rolls {
+                pid = rng_list.next();
+                xor = ((pid >> 16) ^ (pid & 0xFFFF)) as u16 ^ self.base.base.tsv;
+                if xor < 16 {
+                    break;
+                }
+            }
+
+            let shiny = if xor >= 16 {
+                0
+            } else if static_template.get_shiny() == Shiny::Never {
+                pid ^= 0x10000000;
+                0
+            } else if xor == 0 {
+                2
+            } else {
+                1
+            };
+
+            let mut ivs = [255; 6];
+            let mut i = 0;
+            while i < static_template.get_iv_count() {
+                let index = rand(rng_list.next(), 6) as usize;
+                if ivs[index] == 255 {
+                    ivs[index] = 31;
+                    i += 1;
+                }
+            }
+
+            ivs.iter_mut().for_each(|iv| {
+                if *iv == 255 {
+                    *iv = (rng_list.next() >> 27) as u8;
+                }
+            });
+
+            let ability = if static_template.get_ability() != 255 {
+                static_template.get_ability()
+            } else {
+                (rng_list.next() >> 31) as u8
+            };
+
+            let nature = if sync {
+                self.base.lead.bits()
+            } else {
+                rand(rng_list.next(), 25) as u8
+            };
+
+            let gender = match info.get_gender() {
+                255 => 2,
+                254 => 1,
+                0 => 0,
+                i => u8::from((rand(rng_list.next(), 252) as u8 + 1) < i),
+            };
+
+            let state = GeneratorState::new_with_ec(
+                self.base.base.initial_advances.wrapping_add(cnt),
+                ec,
+                pid,
+                ivs,
+                ability,
+                gender,
+                static_template.get_level(),
+                nature,
+                shiny,
+                info,
+            );
+            if self.base.base.filter.compare_generator_state(&state) {
+                states.push(state);
+            }
+
+            cnt += 1;
+            rng_list.advance_state();
+        }
+
+        states
+    }
+}
@@ -0,0 +1,177 @@
+use crate::enums::{Encounter, Lead, Method};
+use crate::gen6::filters::WildStateFilter6;
+use crate::gen6::Profile6;
+use crate::parents::filters::WildFilter;
+use crate::parents::generators::WildGenerator;
+use crate::parents::states::WildGeneratorState;
+use crate::parents::{EncounterArea, EncounterAreaT, PersonalInfo};
+use crate::rng::{RNGList, MT};
+use crate::util::encounter_slot;
+
+fn get_item(rand: u8, lead: Lead, info: &PersonalInfo) -> u16 {
+    const ITEM_TABLE_RANGE: [[u8; 2]; 2] = [[50, 55], [60, 80]];
+
+    let range = ITEM_TABLE_RANGE[if lead == Lead::COMPOUND_EYES { 1 } else { 0 }];
+    if rand < range[0] || (info.get_item(0) == info.get_item(1) && info.get_item(0) != 0) {
+        info.get_item(0)
+    } else if rand < range[1] {
+        info.get_item(1)
+    } else {
+        0
+    }
+}
+
+fn rand(prng: u32, max: u32) -> u32 {
+    ((prng as u64 * max as u64) >> 32) as u32
+}
+
+/// Wild encounter generator for Gen6
+#[derive(Clone)]
+pub struct WildGenerator6<'a, 'b> {
+    /// Base wild generator data
+    pub base: WildGenerator<'a, 'b, Profile6, WildStateFilter6>,
+    /// If the profile has the shiny charm unlocked
+    pub shiny_charm: bool,
+}
+
+impl<'a, 'b> WildGenerator6<'a, 'b> {
+    /// Construct a new [`WildGenerator6`] struct
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        encounter: Encounter,
+        lead: Lead,
+        profile: &'a Profile6,
+        filter: &'b WildStateFilter6,
+    ) -> Self {
+        let mut new = Self {
+            base: WildGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                Method::None,
+                encounter,
+                lead,
+                profile,
+                filter,
+            ),
+            shiny_charm: profile.get_shiny_charm(),
+        };
+        new.base.base.tsv = profile.get_tsv();
+        new
+    }
+
+    /// Generates states for the `encounter_area`
+    pub fn generate(&self, seed: u32, encounter_area: &EncounterArea) -> Vec<WildGeneratorState> {
+        let mt = MT::new_with_initial_advances(
+            seed,
+            self.base
+                .base
+                .initial_advances
+                .wrapping_add(self.base.base.delay),
+        );
+        let mut rng_list = RNGList::<u32, MT, 64>::new(mt, None);
+
+        let pid_rolls = if self.shiny_charm { 3 } else { 1 };
+        let synchronize = self.base.lead.bits() <= Lead::SYNCHRONIZE_END.bits();
+        let cute_charm =
+            self.base.lead == Lead::CUTE_CHARM_F || self.base.lead == Lead::CUTE_CHARM_M;
+
+        let mut states = vec![];
+        let mut cnt = 0;
+        while cnt <= self.base.base.max_advances {
+            let sync = synchronize && rand(rng_list.next(), 100) >= 50;
+
+            let encounter_slot =
+                encounter_slot::bw_slot(rand(rng_list.next(), 100) as u8, self.base.encounter);
+            if !self.base.base.filter.compare_encounter_slot(encounter_slot) {
+                cnt += 1;
+                rng_list.advance_state();
+                continue;
+            }
+
+            let slot = encounter_area.get_specific_pokemon(encounter_slot as usize);
+            let level = encounter_area
+                .calculate_level_with_prng_state(encounter_slot, (rng_list.next() >> 16) as u16);
+
+            let ec = rng_list.next();
+
+            let mut pid = 0;
+            let mut xor = 0;
+            for _ in 0..pid_rolls {
+                pid = rng_list.next();
+                xor = ((pid >> 16) ^ (pid & 0xFFFF)) as u16 ^ self.base.base.tsv;
+                if xor < 16 {
+                    break;
+                }
+            }
+            let shiny = if xor >= 16 {
+                0
+            } else if xor == 0 {
+                2
+            } else {
+                1
+            };
+
+            let mut ivs = [0; 6];
+            ivs.iter_mut().for_each(|iv| {
+                *iv = (rng_list.next() >> 27) as u8;
+            });
+
+            let ability = (rng_list.next() >> 31) as u8;
+
+            let nature = if sync {
+                self.base.lead.bits()
+            } else {
+                rand(rng_list.next(), 25) as u8
+            };
+
+            let info = slot.get_info();
+
+            let gender = match info.get_gender() {
+                255 => 2,
+                254 => 1,
+                0 => 0,
+                i => {
+                    if cute_charm && rand(rng_list.next(), 3) != 0 {
+                        if self.base.lead == Lead::CUTE_CHARM_F {
+                            0
+                        } else {
+                            1
+                        }
+                    } else {
+                        u8::from((rand(rng_list.next(), 252) as u8 + 1) < i)
+                    }
+                }
+            };
+
+            let item = get_item(rand(rng_list.next(), 100) as u8, self.base.lead, info);
+
+            let state = WildGeneratorState::new_with_ec(
+                self.base.base.initial_advances.wrapping_add(cnt),
+                ec,
+                pid,
+                ivs,
+                ability,
+                gender,
+                level,
+                nature,
+                shiny,
+                encounter_slot,
+                item,
+                slot.get_species(),
+                slot.get_form(),
+                info,
+            );
+            if self.base.base.filter.compare_generator_state(&state) {
+                states.push(state);
+            }
+
+            cnt += 1;
+            rng_list.advance_state();
+        }
+
+        states
+    }
+}
@@ -0,0 +1,7 @@
+/// Contains filters that are used by Gen6 generators
+pub mod filters;
+/// Contains generators that are used in Gen6 games
+pub mod generators;
+mod profile6;
+
+pub use profile6::*;
@@ -0,0 +1,57 @@
+use crate::enums::Game;
+use crate::parents::Profile;
+use serde::{Deserialize, Serialize};
+
+/// Provides additional storage specific to Gen6
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub struct Profile6 {
+    name: String,
+    version: Game,
+    sid: u16,
+    tid: u16,
+    shiny_charm: bool,
+}
+
+impl Profile for Profile6 {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_version(&self) -> Game {
+        self.version
+    }
+
+    fn get_sid(&self) -> u16 {
+        self.sid
+    }
+
+    fn get_tid(&self) -> u16 {
+        self.tid
+    }
+}
+
+impl Profile6 {
+    /// Construct a new [`Profile6`] struct
+    pub fn new(name: String, version: Game, tid: u16, sid: u16, shiny_charm: bool) -> Self {
+        Self {
+            name,
+            version,
+            sid,
+            tid,
+            shiny_charm,
+        }
+    }
+
+    /// Get if the profile has the shiny charm unlocked
+    pub fn get_shiny_charm(&self) -> bool {
+        self.shiny_charm
+    }
+
+    /// Get the trainer shiny value of the profile
+    ///
+    /// This is the full TID/SID xor used by the generators, the value shown by in game tools
+    /// is the top 12 bits of it.
+    pub fn get_tsv(&self) -> u16 {
+        self.tid ^ self.sid
+    }
+}
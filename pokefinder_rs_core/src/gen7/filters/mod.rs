@@ -0,0 +1,3 @@
+mod state_filter7;
+
+pub use state_filter7::*;
@@ -0,0 +1,203 @@
+use crate::enums::Method;
+use crate::gen7::filters::StateFilter7;
+use crate::gen7::Profile7;
+use crate::parents::generators::EggGenerator;
+use crate::parents::personal_loader::{self, PersonalTable};
+use crate::parents::states::EggGeneratorState;
+use crate::parents::{Daycare, Profile};
+use crate::rng::TinyMT;
+
+/// Egg generator for Gen7
+#[derive(Clone)]
+pub struct EggGenerator7<'a, 'b, 'c> {
+    /// Base egg generator data
+    pub base: EggGenerator<'a, 'b, 'c, Profile7, StateFilter7>,
+    /// If the profile has the shiny charm unlocked
+    pub shiny_charm: bool,
+    /// Personal table of the profile's game
+    pub personal: PersonalTable,
+}
+
+fn rand(rng: &mut TinyMT, max: u32) -> u32 {
+    rng.next() % max
+}
+
+impl<'a, 'b, 'c> EggGenerator7<'a, 'b, 'c> {
+    /// Construct a new [`EggGenerator7`] struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        delay: u32,
+        compatability: u8,
+        daycare: &'c Daycare,
+        profile: &'a Profile7,
+        filter: &'b StateFilter7,
+    ) -> Self {
+        let mut new = Self {
+            base: EggGenerator::new(
+                initial_advances,
+                max_advances,
+                delay,
+                Method::None,
+                compatability,
+                daycare,
+                profile,
+                filter,
+            ),
+            shiny_charm: profile.get_shiny_charm(),
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
+        };
+        new.base.base.tsv = (profile.get_tid() & 0xFFF0) ^ profile.get_sid();
+        new
+    }
+
+    /// Generate [`EggGeneratorState`] states from the TinyMT egg `seed`
+    pub fn generate(&self, seed: [u32; 4]) -> Vec<EggGeneratorState> {
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
+        let (male, female) = match self.base.daycare.get_egg_species() {
+            29 | 32 => (
+                Some(self.personal.get_personal_info(32, 0)),
+                Some(self.personal.get_personal_info(29, 0)),
+            ),
+            313 | 314 => (
+                Some(self.personal.get_personal_info(313, 0)),
+                Some(self.personal.get_personal_info(314, 0)),
+            ),
+            _ => (None, None),
+        };
+
+        let mut tiny = TinyMT::new_from_full(seed[0], seed[1], seed[2], seed[3]);
+        tiny.advance(
+            self.base
+                .base
+                .initial_advances
+                .wrapping_add(self.base.base.delay),
+        );
+
+        let mut pid_rolls = 0;
+        if self.base.daycare.get_masuda() {
+            pid_rolls += 6;
+        }
+        if self.shiny_charm {
+            pid_rolls += 2;
+        }
+
+        let inheritance_count = if self.base.daycare.get_parent_item(0) == 8
+            || self.base.daycare.get_parent_item(1) == 8
+        {
+            5
+        } else {
+            3
+        };
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.base.max_advances {
+            let mut rng = tiny;
+            let mut info = base;
+            let gender = if matches!(self.base.daycare.get_egg_species(), 29 | 32 | 313 | 314) {
+                let gender = rand(&mut rng, 2) as u8;
+                if gender != 0 {
+                    info = female.unwrap();
+                } else {
+                    info = male.unwrap();
+                }
+                gender
+            } else {
+                match base.get_gender() {
+                    255 => 2,
+                    254 => 1,
+                    0 => 0,
+                    _ => u8::from((rand(&mut rng, 252) as u8 + 1) < base.get_gender()),
+                }
+            };
+
+            let mut nature = rand(&mut rng, 25) as u8;
+            if self.base.daycare.get_everstone_count() == 2 {
+                nature = self.base.daycare.get_parent_nature(rand(&mut rng, 2) as u8);
+            } else if self.base.daycare.get_parent_item(0) == 1 {
+                nature = self.base.daycare.get_parent_nature(0);
+            } else if self.base.daycare.get_parent_item(1) == 1 {
+                nature = self.base.daycare.get_parent_nature(1);
+            }
+
+            let parent_ability = self.base.daycare.get_parent_ability(
+                if self.base.daycare.get_parent_gender(1) == 3 {
+                    0
+                } else {
+                    1
+                },
+            );
+            let mut ability = rand(&mut rng, 100) as u8;
+            if parent_ability == 2 {
+                ability = if ability < 20 {
+                    0
+                } else if ability < 40 {
+                    1
+                } else {
+                    2
+                };
+            } else if parent_ability == 1 {
+                ability = if ability < 20 { 0 } else { 1 };
+            } else {
+                ability = if ability < 80 { 0 } else { 1 };
+            }
+
+            let mut inheritance = [0; 6];
+            let mut i = 0;
+            while i < inheritance_count {
+                let index = rand(&mut rng, 6) as usize;
+                if inheritance[index] == 0 {
+                    inheritance[index] = rand(&mut rng, 2) as u8 + 1;
+                    i += 1;
+                }
+            }
+
+            let mut ivs = [0; 6];
+            for (i, iv) in ivs.iter_mut().enumerate() {
+                let mut rand = rand(&mut rng, 32) as u8;
+                if inheritance[i] == 1 {
+                    rand = self.base.daycare.get_parent_iv(0, i);
+                } else if inheritance[i] == 2 {
+                    rand = self.base.daycare.get_parent_iv(1, i);
+                }
+                *iv = rand;
+            }
+
+            let ec = rng.next();
+
+            let mut pid = 0;
+            let mut psv = 0;
+            for _ in 0..pid_rolls {
+                pid = rng.next();
+                psv = ((pid >> 16) ^ (pid & 0xfff0)) as u16;
+                if (psv ^ self.base.base.tsv) < 16 {
+                    break;
+                }
+            }
+            let shiny = pid_rolls != 0 && (psv ^ self.base.base.tsv) < 16;
+
+            let state = EggGeneratorState::new_with_ec(
+                self.base.base.initial_advances.wrapping_add(cnt),
+                ec,
+                pid,
+                ivs,
+                ability,
+                gender,
+                1,
+                nature,
+                u8::from(shiny),
+                inheritance,
+                info,
+            );
+            if self.base.base.filter.compare_egg_state(&state) {
+                states.push(state);
+            }
+            tiny.next_state();
+        }
+
+        states
+    }
+}
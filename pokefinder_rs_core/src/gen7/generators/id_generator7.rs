@@ -0,0 +1,47 @@
+use crate::gen8::states::IDState8;
+use crate::parents::filters::IDFilter;
+use crate::parents::generators::IDGenerator;
+use crate::rng::{Rng, SFMT};
+
+/// TID/SID generator for Gen7
+///
+/// Gen7 displays the same 6 digit TID as Gen8 so the states are shared with [`IDState8`]
+pub struct IDGenerator7<'a> {
+    /// Base ID generator data
+    pub base: IDGenerator<'a>,
+}
+
+impl<'a> IDGenerator7<'a> {
+    /// Construct a new [`IDGenerator7`] struct
+    pub fn new(initial_advances: u32, max_advances: u32, filter: &'a IDFilter) -> Self {
+        Self {
+            base: IDGenerator::new(initial_advances, max_advances, filter),
+        }
+    }
+
+    /// Generates [`IDState8`] states
+    pub fn generate(&self, seed: u32) -> Vec<IDState8> {
+        let mut rng = SFMT::new_with_initial_advances(seed, self.base.initial_advances);
+
+        let mut states = vec![];
+        for cnt in 0..=self.base.max_advances {
+            let g7tid = rng.next() as u32;
+
+            let tid = (g7tid & 0xFFFF) as u16;
+            let sid = (g7tid >> 16) as u16;
+            let display_tid = g7tid % 1000000;
+
+            let state = IDState8::new(
+                self.base.initial_advances.wrapping_add(cnt),
+                tid,
+                sid,
+                display_tid,
+            );
+            if self.base.filter.compare_8(&state) {
+                states.push(state);
+            }
+        }
+
+        states
+    }
+}
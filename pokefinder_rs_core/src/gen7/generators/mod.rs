@@ -0,0 +1,9 @@
+mod egg_generator7;
+mod id_generator7;
+mod static_generator7;
+mod wild_generator7;
+
+pub use egg_generator7::*;
+pub use id_generator7::*;
+pub use static_generator7::*;
+pub use wild_generator7::*;
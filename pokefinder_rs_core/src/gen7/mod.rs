@@ -0,0 +1,7 @@
+/// Contains filters that are used by Gen7 generators
+pub mod filters;
+/// Contains generators that are used in Gen7 games
+pub mod generators;
+mod profile7;
+
+pub use profile7::*;
@@ -0,0 +1,62 @@
+use crate::enums::Game;
+use crate::parents::Profile;
+use serde::{Deserialize, Serialize};
+
+/// Provides additional storage specific to Gen7
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub struct Profile7 {
+    name: String,
+    version: Game,
+    sid: u16,
+    tid: u16,
+    shiny_charm: bool,
+}
+
+impl Profile for Profile7 {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_version(&self) -> Game {
+        self.version
+    }
+
+    fn get_sid(&self) -> u16 {
+        self.sid
+    }
+
+    fn get_tid(&self) -> u16 {
+        self.tid
+    }
+}
+
+impl Profile7 {
+    /// Construct a new [`Profile7`] struct
+    pub fn new(name: String, version: Game, tid: u16, sid: u16, shiny_charm: bool) -> Self {
+        Self {
+            name,
+            version,
+            sid,
+            tid,
+            shiny_charm,
+        }
+    }
+
+    /// Get if the profile has the shiny charm unlocked
+    pub fn get_shiny_charm(&self) -> bool {
+        self.shiny_charm
+    }
+
+    /// Get the trainer shiny value of the profile
+    ///
+    /// This is the full TID/SID xor used by the generators, the value shown by in game tools
+    /// is the top 12 bits of it.
+    pub fn get_tsv(&self) -> u16 {
+        self.tid ^ self.sid
+    }
+
+    /// Get the 6 digit trainer ID that is displayed in game
+    pub fn get_display_tid(&self) -> u32 {
+        (((self.sid as u32) << 16) | self.tid as u32) % 1000000
+    }
+}
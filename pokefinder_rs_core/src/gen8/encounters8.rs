@@ -1,6 +1,6 @@
 use crate::enums::{Encounter, Game};
 use crate::gen8::{EncounterArea8, Pokemon, Profile8, SpecialPokemon, TypeSize, UndergroundArea};
-use crate::parents::{personal_loader, PersonalInfo, Profile, Slot, StaticTemplate};
+use crate::parents::{custom_loader, personal_loader, PersonalInfo, Profile, Slot, StaticTemplate};
 use crate::resources::encounter_data_8::{
     BD, BD_UNDERGROUND, FOSSILS, GIFTS, LEGENDS, MYTHICS, RAMANASPARKPURESPACE,
     RAMANASPARKSTRANGESPACE, ROAMERS, SP, SP_UNDERGROUND, STARTERS, STATIONARY,
@@ -169,7 +169,7 @@ impl EndianRead for WildEncounterUnderground {
 fn modify_great_marsh(
     pokemon: &mut [Slot],
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     location: u8,
 ) {
     if (23..=28).contains(&location) && replacement[0] != 0 {
@@ -179,12 +179,7 @@ fn modify_great_marsh(
     }
 }
 
-fn modify_radar(
-    mons: &mut [Slot],
-    entry: &WildEncounter8,
-    info: &'static [PersonalInfo],
-    radar: bool,
-) {
+fn modify_radar(mons: &mut [Slot], entry: &WildEncounter8, info: &[PersonalInfo], radar: bool) {
     if radar {
         mons[4].set_species(entry.radar[0], &info[entry.radar[0] as usize]);
         mons[5].set_species(entry.radar[1], &info[entry.radar[1] as usize]);
@@ -193,19 +188,14 @@ fn modify_radar(
     }
 }
 
-fn modify_swarm(
-    mons: &mut [Slot],
-    entry: &WildEncounter8,
-    info: &'static [PersonalInfo],
-    swarm: bool,
-) {
+fn modify_swarm(mons: &mut [Slot], entry: &WildEncounter8, info: &[PersonalInfo], swarm: bool) {
     if swarm {
         mons[0].set_species(entry.swarm[0], &info[entry.swarm[0] as usize]);
         mons[1].set_species(entry.swarm[1], &info[entry.swarm[1] as usize]);
     }
 }
 
-fn modify_time(mons: &mut [Slot], entry: &WildEncounter8, info: &'static [PersonalInfo], time: u8) {
+fn modify_time(mons: &mut [Slot], entry: &WildEncounter8, info: &[PersonalInfo], time: u8) {
     let (specie1, specie2) = match time {
         1 => (entry.day[0], entry.day[1]),
         2 => (entry.night[0], entry.night[1]),
@@ -219,7 +209,7 @@ fn modify_time(mons: &mut [Slot], entry: &WildEncounter8, info: &'static [Person
 fn modify_trophy_garden(
     mons: &mut [Slot],
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
     location: u8,
 ) {
     if location == 117 && !replacement.contains(&0) {
@@ -237,7 +227,7 @@ fn get_bdsp(
     swarm: bool,
     version: Game,
     replacement: [u16; 2],
-    info: &'static [PersonalInfo],
+    info: &[PersonalInfo],
 ) -> Vec<EncounterArea8> {
     let compressed_data = if version == Game::BD { BD } else { SP };
 
@@ -352,16 +342,12 @@ pub fn get_encounters(
     replacement: [u16; 2],
     profile: &Profile8,
 ) -> Vec<EncounterArea8> {
-    let info = personal_loader::get_personal_table(profile.get_version());
-    get_bdsp(
-        encounter,
-        time,
-        radar,
-        swarm,
-        profile.get_version(),
-        replacement,
-        info,
-    )
+    let version = profile.get_version();
+    let info = personal_loader::resolve_personal_table(version);
+    let mut encounters = get_bdsp(encounter, time, radar, swarm, version, replacement, &info);
+
+    custom_loader::apply_encounters(version, encounter, &mut encounters, EncounterArea8::new);
+    encounters
 }
 
 /// Returns the changing pokemon of the Great Marsh
@@ -414,7 +400,7 @@ pub fn get_underground_encounters(
     let data = util::decompress(compressed_data);
     let mut reader = StreamContainer::new(data);
     let mut encounters = vec![];
-    let base = personal_loader::get_personal_table(version);
+    let base = personal_loader::resolve_personal_table(version);
     while let Ok(entry) = reader.read_stream_le::<WildEncounterUnderground>() {
         let mut pokemon = vec![];
         let mut types = vec![];
@@ -36,7 +36,7 @@ impl StateFilter8 {
         if (self.base.ability != 255 && self.base.ability != state.base.base.ability)
             || (self.base.gender != 255 && self.base.gender != state.base.base.gender)
             || (!self.base.natures[state.base.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -59,7 +59,7 @@ impl StateFilter8 {
         if (self.base.ability != 255 && self.base.ability != state.base.ability)
             || (self.base.gender != 255 && self.base.gender != state.base.gender)
             || (!self.base.natures[state.base.nature as usize])
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.shiny) == 0)
         {
             return false;
         }
@@ -151,11 +151,10 @@ impl WildStateFilter8 {
         }
 
         if (self.base.base.ability != 255 && self.base.base.ability != state.base.base.ability)
-            || (self.base.base.gender != 255 && self.base.base.gender != state.base.base.ability)
+            || (self.base.base.gender != 255 && self.base.base.gender != state.base.base.gender)
             || (!self.base.base.powers[state.base.base.hidden_power as usize])
             || (!self.base.base.natures[state.base.base.nature as usize])
-            || (self.base.base.shiny != 255
-                && (!(self.base.base.shiny & state.base.base.shiny)) != 0)
+            || (self.base.base.shiny != 255 && (self.base.base.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -249,7 +248,7 @@ impl<'a> UndergroundStateFilter<'a> {
             || (self.base.gender != 255 && self.base.gender != state.base.base.gender)
             || (!self.base.natures[state.base.base.nature as usize])
             || self.species.binary_search(&state.species).is_err()
-            || (self.base.shiny != 255 && (!(self.base.shiny & state.base.base.shiny)) != 0)
+            || (self.base.shiny != 255 && (self.base.shiny & state.base.base.shiny) == 0)
         {
             return false;
         }
@@ -2,8 +2,9 @@ use crate::enums::Method;
 use crate::gen8::filters::StateFilter8;
 use crate::gen8::Profile8;
 use crate::parents::generators::EggGenerator;
+use crate::parents::personal_loader::{self, PersonalTable};
 use crate::parents::states::EggGeneratorState;
-use crate::parents::{personal_loader, Daycare, Profile};
+use crate::parents::{Daycare, Profile};
 use crate::rng::{RNGList, XoroshiroBDSP, Xorshift};
 
 /// Egg generator for Gen8
@@ -13,6 +14,8 @@ pub struct EggGenerator8<'a, 'b, 'c> {
     pub base: EggGenerator<'a, 'b, 'c, Profile8, StateFilter8>,
     /// If the profile has the shiny charm unlocked
     pub shiny_charm: bool,
+    /// Personal table of the profile's game
+    pub personal: PersonalTable,
 }
 
 fn gen(rng: &mut Xorshift) -> u32 {
@@ -43,6 +46,7 @@ impl<'a, 'b, 'c> EggGenerator8<'a, 'b, 'c> {
                 filter,
             ),
             shiny_charm: profile.get_shiny_charm(),
+            personal: personal_loader::resolve_personal_table(profile.get_version()),
         };
         new.base.base.tsv = (profile.get_tid() & 0xFFF0) ^ profile.get_sid();
         new
@@ -50,35 +54,17 @@ impl<'a, 'b, 'c> EggGenerator8<'a, 'b, 'c> {
 
     /// Generate [`EggGeneratorState`] states
     pub fn generate(&self, seed0: u64, seed1: u64) -> Vec<EggGeneratorState> {
-        let base = personal_loader::get_personal_info(
-            self.base.base.profile.get_version(),
-            self.base.daycare.get_egg_species(),
-            0,
-        );
+        let base = self
+            .personal
+            .get_personal_info(self.base.daycare.get_egg_species(), 0);
         let (male, female) = match self.base.daycare.get_egg_species() {
             29 | 32 => (
-                Some(personal_loader::get_personal_info(
-                    self.base.base.profile.get_version(),
-                    32,
-                    0,
-                )),
-                Some(personal_loader::get_personal_info(
-                    self.base.base.profile.get_version(),
-                    29,
-                    0,
-                )),
+                Some(self.personal.get_personal_info(32, 0)),
+                Some(self.personal.get_personal_info(29, 0)),
             ),
             313 | 314 => (
-                Some(personal_loader::get_personal_info(
-                    self.base.base.profile.get_version(),
-                    313,
-                    0,
-                )),
-                Some(personal_loader::get_personal_info(
-                    self.base.base.profile.get_version(),
-                    314,
-                    0,
-                )),
+                Some(self.personal.get_personal_info(313, 0)),
+                Some(self.personal.get_personal_info(314, 0)),
             ),
             _ => (None, None),
         };
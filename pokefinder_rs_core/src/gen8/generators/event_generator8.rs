@@ -50,7 +50,7 @@ impl<'a, 'b, 'c> EventGenerator8<'a, 'b, 'c> {
 
     /// Generates [`GeneratorState`] states for events
     pub fn generate(&self, seed0: u64, seed1: u64) -> Vec<GeneratorState> {
-        let info = self.wb8.get_info(self.base.profile.get_version());
+        let info = &self.wb8.resolve_info(self.base.profile.get_version());
         let rng = Xorshift::new_with_initial_advances(
             seed0,
             seed1,
@@ -40,7 +40,7 @@ impl<'a, 'b> RaidGenerator<'a, 'b> {
 
     /// Generates states for the `raid`
     pub fn generate(&self, mut seed: u64, level: u8, raid: &Raid) -> Vec<GeneratorState> {
-        let info = raid.base.get_info();
+        let info = &raid.base.resolve_info();
         seed = seed.wrapping_add(
             0x82A2B175229D6A5Bu64
                 .wrapping_mul(self.base.initial_advances.wrapping_add(self.base.delay) as u64),
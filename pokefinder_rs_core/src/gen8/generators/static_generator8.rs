@@ -49,7 +49,7 @@ impl<'a, 'b> StaticGenerator8<'a, 'b> {
         seed1: u64,
         static_template: &StaticTemplate,
     ) -> Vec<GeneratorState> {
-        let info = static_template.get_info();
+        let info = &static_template.resolve_info();
         let rng = Xorshift::new_with_initial_advances(
             seed0,
             seed1,
@@ -177,6 +177,7 @@ impl<'a, 'b> StaticGenerator8<'a, 'b> {
         seed1: u64,
         static_template: &StaticTemplate,
     ) -> Vec<GeneratorState> {
+        let info = static_template.resolve_info();
         let gender = if static_template.get_species() == 488 {
             1
         } else {
@@ -259,7 +260,7 @@ impl<'a, 'b> StaticGenerator8<'a, 'b> {
                 static_template.get_level(),
                 nature,
                 shiny,
-                static_template.get_info(),
+                &info,
             );
             if self.base.base.filter.compare_generator_state(&state) {
                 states.push(state);
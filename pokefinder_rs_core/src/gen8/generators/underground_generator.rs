@@ -1006,7 +1006,7 @@ impl<'a, 'b, 'c> UndergroundGenerator<'a, 'b, 'c> {
                 .wrapping_add(self.base.base.delay),
         );
         let mut rng_list = RNGList::<u32, Xorshift, 256>::new(rng, None);
-        let base = personal_loader::get_personal_table(self.base.base.profile.get_version());
+        let base = personal_loader::resolve_personal_table(self.base.base.profile.get_version());
         let level_info = LEVEL_INFO_LIST[self.level_flag as usize];
         let pid_rolls = if self.diglett { 2 } else { 1 };
 
@@ -10,6 +10,8 @@ pub mod filters;
 pub mod generators;
 mod profile8;
 mod raid;
+/// Contains searchers that are used in Gen8 games
+pub mod searchers;
 /// Contains states that are used in Gen8 games
 pub mod states;
 mod underground_area;
@@ -5,13 +5,34 @@ use serde::{Deserialize, Serialize};
 /// Provides additional storage specific to Gen8
 #[derive(Clone, PartialEq, Deserialize, Serialize)]
 pub struct Profile8 {
-    name: String,
-    version: Game,
-    sid: u16,
-    tid: u16,
-    dex: bool,
-    oval_charm: bool,
-    shiny_charm: bool,
+    /// Profile name
+    pub name: String,
+    /// Profile version
+    pub version: Game,
+    /// Profile SID
+    pub sid: u16,
+    /// Profile TID
+    pub tid: u16,
+    /// Whether the national pokedex is obtained
+    pub dex: bool,
+    /// Whether the oval charm is obtained
+    pub oval_charm: bool,
+    /// Whether the shiny charm is obtained
+    pub shiny_charm: bool,
+}
+
+impl Default for Profile8 {
+    fn default() -> Self {
+        Self {
+            name: "-".to_string(),
+            version: Game::BD,
+            sid: 54321,
+            tid: 12345,
+            dex: false,
+            oval_charm: false,
+            shiny_charm: false,
+        }
+    }
 }
 
 impl Profile for Profile8 {
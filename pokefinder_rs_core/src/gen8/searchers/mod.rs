@@ -0,0 +1,7 @@
+mod raid_seed_searcher;
+mod searcher8;
+mod xorshift_recovery;
+
+pub use raid_seed_searcher::*;
+pub use searcher8::*;
+pub use xorshift_recovery::*;
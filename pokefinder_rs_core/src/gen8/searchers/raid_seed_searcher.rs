@@ -0,0 +1,705 @@
+use crate::gen8::Raid;
+use crate::parents::searchers::SearcherT;
+use crate::parents::Template;
+use crate::rng::{Rng, Xoroshiro};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const TOXTRICITY_AMPED_NATURES: [u8; 13] = [3, 4, 2, 8, 9, 19, 22, 11, 13, 14, 0, 6, 24];
+const TOXTRICITY_LOWKEY_NATURES: [u8; 12] = [1, 5, 7, 10, 12, 15, 16, 17, 18, 20, 21, 23];
+
+/// Seed difference between consecutive raid advances
+const RAID_CONSTANT: u64 = 0x82A2B175229D6A5B;
+
+/// Number of carry guesses that are used to split the search into tasks
+const SPLIT_DEPTH: u32 = 8;
+
+/// Maximum number of seed bits the first observation may leave unknown
+///
+/// Every seed that fits the first observation is checked against the second one, so a pattern
+/// takes `2^free bits` checks.
+const MAX_FREE_BITS: u32 = 32;
+
+/// Maximum number of seed bits that may be left unknown without a second observation
+///
+/// Every seed that fits the first observation becomes a result in that case.
+const MAX_UNCONFIRMED_BITS: u32 = 16;
+
+/// Errors returned by the [`RaidSeedSearcher`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RaidSeedSearcherError {
+    /// The observations leave this many seed bits unknown, which is too many to search
+    Underconstrained(u32),
+}
+
+impl Display for RaidSeedSearcherError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaidSeedSearcherError::Underconstrained(bits) => write!(
+                f,
+                "the observations leave {bits} seed bits unknown, observe a pokemon with fewer flawless IVs"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RaidSeedSearcherError {}
+
+/// Observed raid pokemon used by the [`RaidSeedSearcher`]
+#[derive(Copy, Clone)]
+pub struct RaidObservation {
+    /// Raid slot of the den the pokemon came from
+    pub raid: Raid,
+    /// Observed IVs
+    pub ivs: [u8; 6],
+    /// Ability index as reported by [`RaidGenerator`](crate::gen8::generators::RaidGenerator)
+    pub ability: u8,
+    /// Observed nature
+    pub nature: u8,
+    /// If a gender roll sits between the ability and the nature
+    random_gender: bool,
+}
+
+impl RaidObservation {
+    /// Construct a new [`RaidObservation`] struct
+    pub fn new(raid: Raid, ivs: [u8; 6], ability: u8, nature: u8) -> Self {
+        Self {
+            random_gender: random_gender(&raid),
+            raid,
+            ivs,
+            ability,
+            nature,
+        }
+    }
+
+    /// Checks if the raid pokemon generated from `seed` matches the observation
+    fn matches(&self, seed: u64) -> bool {
+        let raid = &self.raid.base;
+        let mut rng: Xoroshiro = seed.into();
+        rng.advance(3);
+
+        let mut ivs = [255; 6];
+        let mut i = 0;
+        while i < raid.get_iv_count() {
+            let index = rng.next_u32::<6>() as usize;
+            if ivs[index] == 255 {
+                ivs[index] = 31;
+                i += 1;
+            }
+        }
+
+        for (iv, &observed) in ivs.iter_mut().zip(self.ivs.iter()) {
+            if *iv == 255 {
+                *iv = rng.next_u32::<32>() as u8;
+            }
+            if *iv != observed {
+                return false;
+            }
+        }
+
+        let ability = match raid.get_ability() {
+            4 => rng.next_u32::<3>() as u8,
+            3 => rng.next_u32::<2>() as u8,
+            ability => ability,
+        };
+        if ability != self.ability {
+            return false;
+        }
+
+        if self.random_gender {
+            rng.next_u32::<253>();
+        }
+
+        let nature = if raid.get_species() != 849 {
+            rng.next_u32::<25>() as u8
+        } else if raid.get_form() == 0 {
+            TOXTRICITY_AMPED_NATURES[rng.next_u32::<13>() as usize]
+        } else {
+            TOXTRICITY_LOWKEY_NATURES[rng.next_u32::<12>() as usize]
+        };
+        nature == self.nature
+    }
+
+    /// Creates the output constraints for every order of the flawless IVs
+    ///
+    /// Up to `rerolls` rejected rolls are considered across the IV index, ability, gender and
+    /// nature rolls. The value of a rejected roll is known so every possible value becomes
+    /// its own pattern.
+    fn patterns(&self, rerolls: u8) -> Vec<Vec<Constraint>> {
+        let raid = &self.raid.base;
+        let count = raid.get_iv_count() as usize;
+        let flawless: Vec<usize> = (0..6).filter(|&i| self.ivs[i] == 31).collect();
+
+        let mut orders = vec![];
+        permutations(&flawless, count, &mut vec![], &mut orders);
+
+        let mut patterns = vec![];
+        for order in orders {
+            let mut indexes = vec![];
+            index_rolls(&order, rerolls, &mut vec![], &mut indexes);
+
+            for index in indexes {
+                let remaining = rerolls - (index.len() - order.len()) as u8;
+                let mut rolls: Vec<Roll> = index.iter().map(|&i| Some((3, i as u64))).collect();
+                rolls.extend(
+                    self.ivs
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| !order.contains(i))
+                        .map(|(_, &iv)| Some((5, iv as u64))),
+                );
+                patterns.push((rolls, remaining));
+            }
+        }
+
+        patterns = match raid.get_ability() {
+            4 => with_rerolls(patterns, 2, &[3], Some((2, self.ability as u64))),
+            3 => with_rerolls(patterns, 1, &[], Some((1, self.ability as u64))),
+            _ => patterns,
+        };
+
+        if self.random_gender {
+            patterns = with_rerolls(patterns, 8, &[253, 254, 255], None);
+        }
+
+        patterns = if raid.get_species() != 849 {
+            let rejected: Vec<u64> = (25..32).collect();
+            with_rerolls(patterns, 5, &rejected, Some((5, self.nature as u64)))
+        } else {
+            let natures: &[u8] = if raid.get_form() == 0 {
+                &TOXTRICITY_AMPED_NATURES
+            } else {
+                &TOXTRICITY_LOWKEY_NATURES
+            };
+            match natures.iter().position(|&n| n == self.nature) {
+                Some(index) => {
+                    let rejected: Vec<u64> = (natures.len() as u64..16).collect();
+                    with_rerolls(patterns, 4, &rejected, Some((4, index as u64)))
+                }
+                None => vec![],
+            }
+        };
+
+        patterns
+            .into_iter()
+            .map(|(rolls, _)| {
+                rolls
+                    .into_iter()
+                    .zip(3..)
+                    .filter_map(|(roll, advance)| {
+                        roll.map(|(bits, value)| Constraint::new(advance, bits, value))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the fewest output bits any of the patterns constrain
+    fn known_bits(&self, rerolls: u8) -> u32 {
+        self.patterns(rerolls)
+            .iter()
+            .map(|constraints| constraints.iter().map(|c| c.bits).sum())
+            .min()
+            .unwrap_or(64)
+    }
+}
+
+/// Known low bits and value of a roll, [`None`] if the value is unknown
+type Roll = Option<(u32, u64)>;
+
+/// Appends the `accepted` roll to every pattern after each way of rejecting up to the
+/// remaining reroll budget of the pattern
+///
+/// `rejected` are the masked `bits` wide values that [`Xoroshiro::next_u32()`] rerolls.
+fn with_rerolls(
+    patterns: Vec<(Vec<Roll>, u8)>,
+    bits: u32,
+    rejected: &[u64],
+    accepted: Roll,
+) -> Vec<(Vec<Roll>, u8)> {
+    let mut out = vec![];
+    for (rolls, remaining) in patterns {
+        let mut current = vec![(rolls, remaining)];
+        loop {
+            let mut next = vec![];
+            for (rolls, remaining) in current {
+                let mut done = rolls.clone();
+                done.push(accepted);
+                out.push((done, remaining));
+
+                if remaining > 0 {
+                    for &value in rejected {
+                        let mut rolls = rolls.clone();
+                        rolls.push(Some((bits, value)));
+                        next.push((rolls, remaining - 1));
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            current = next;
+        }
+    }
+    out
+}
+
+fn random_gender(raid: &Raid) -> bool {
+    raid.base.get_gender() == 0 && !matches!(raid.base.resolve_info().get_gender(), 0 | 254 | 255)
+}
+
+fn permutations(
+    items: &[usize],
+    count: usize,
+    current: &mut Vec<usize>,
+    out: &mut Vec<Vec<usize>>,
+) {
+    if current.len() == count {
+        out.push(current.clone());
+        return;
+    }
+    for &item in items {
+        if !current.contains(&item) {
+            current.push(item);
+            permutations(items, count, current, out);
+            current.pop();
+        }
+    }
+}
+
+fn index_rolls(order: &[usize], rerolls: u8, current: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+    let accepted = order.iter().filter(|index| current.contains(index)).count();
+    if accepted == order.len() {
+        out.push(current.clone());
+        return;
+    }
+
+    current.push(order[accepted]);
+    index_rolls(order, rerolls, current, out);
+    current.pop();
+
+    if rerolls > 0 {
+        for rejected in [6, 7].into_iter().chain(order[..accepted].iter().copied()) {
+            current.push(rejected);
+            index_rolls(order, rerolls - 1, current, out);
+            current.pop();
+        }
+    }
+}
+
+/// Known low `bits` of the Xoroshiro output at `advance`
+#[derive(Copy, Clone)]
+struct Constraint {
+    advance: u32,
+    bits: u32,
+    value: u64,
+}
+
+impl Constraint {
+    fn new(advance: u32, bits: u32, value: u64) -> Self {
+        Self {
+            advance,
+            bits,
+            value,
+        }
+    }
+}
+
+/// 64bit word where every bit is an affine function of the seed bits
+#[derive(Copy, Clone)]
+struct Word {
+    masks: [u64; 64],
+    constant: u64,
+}
+
+impl Word {
+    fn xor(mut self, other: &Word) -> Self {
+        self.masks
+            .iter_mut()
+            .zip(other.masks.iter())
+            .for_each(|(x, y)| *x ^= y);
+        self.constant ^= other.constant;
+        self
+    }
+
+    fn rotate_left(&self, shift: usize) -> Self {
+        let mut masks = [0; 64];
+        for (i, &mask) in self.masks.iter().enumerate() {
+            masks[(i + shift) % 64] = mask;
+        }
+        Self {
+            masks,
+            constant: self.constant.rotate_left(shift as u32),
+        }
+    }
+
+    fn shift_left(&self, shift: usize) -> Self {
+        let mut masks = [0; 64];
+        masks[shift..].copy_from_slice(&self.masks[..64 - shift]);
+        Self {
+            masks,
+            constant: self.constant << shift,
+        }
+    }
+
+    fn bit(&self, bit: usize) -> (u64, bool) {
+        (self.masks[bit], (self.constant >> bit) & 1 == 1)
+    }
+}
+
+/// Linear system over the 64 seed bits kept in row echelon form
+#[derive(Copy, Clone)]
+struct System {
+    rows: [u64; 64],
+    values: u64,
+    pivots: u64,
+}
+
+impl System {
+    fn new() -> Self {
+        Self {
+            rows: [0; 64],
+            values: 0,
+            pivots: 0,
+        }
+    }
+
+    /// Reduces `mask` against the pivots, returning the leftover mask and value
+    fn reduce(&self, mut mask: u64, mut value: bool) -> (u64, bool) {
+        while mask != 0 {
+            let lead = 63 - mask.leading_zeros();
+            if (self.pivots >> lead) & 1 == 0 {
+                break;
+            }
+            mask ^= self.rows[lead as usize];
+            value ^= (self.values >> lead) & 1 == 1;
+        }
+        (mask, value)
+    }
+
+    /// Adds the equation, returning false if it contradicts the system
+    fn insert(&mut self, mask: u64, value: bool) -> bool {
+        let (mask, value) = self.reduce(mask, value);
+        if mask == 0 {
+            return !value;
+        }
+
+        let lead = 63 - mask.leading_zeros();
+        self.rows[lead as usize] = mask;
+        self.values |= (value as u64) << lead;
+        self.pivots |= 1 << lead;
+        true
+    }
+
+    /// Returns the value of the affine bit if it is fixed by the system
+    fn evaluate(&self, (mask, constant): (u64, bool)) -> Option<bool> {
+        match self.reduce(mask, constant) {
+            (0, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Solves the system with the free bits set from `free`
+    fn solve(&self, free: u64) -> u64 {
+        let mut seed = free & !self.pivots;
+        for bit in 0..64 {
+            if (self.pivots >> bit) & 1 == 1 {
+                let row = self.rows[bit] & !(1 << bit);
+                let value = ((row & seed).count_ones() & 1 == 1) ^ ((self.values >> bit) & 1 == 1);
+                seed |= (value as u64) << bit;
+            }
+        }
+        seed
+    }
+}
+
+/// Single bit of a constraint with the affine forms of both Xoroshiro state words
+#[derive(Copy, Clone)]
+struct Step {
+    s0: (u64, bool),
+    s1: (u64, bool),
+    value: bool,
+    last: bool,
+}
+
+fn build_steps(constraints: &[Constraint]) -> Vec<Step> {
+    let mut s0 = Word {
+        masks: std::array::from_fn(|i| 1 << i),
+        constant: 0,
+    };
+    let mut s1 = Word {
+        masks: [0; 64],
+        constant: RAID_CONSTANT,
+    };
+
+    let mut steps = vec![];
+    let mut advance = 0;
+    for constraint in constraints {
+        while advance < constraint.advance {
+            let x = s1.xor(&s0);
+            s0 = s0.rotate_left(24).xor(&x).xor(&x.shift_left(16));
+            s1 = x.rotate_left(37);
+            advance += 1;
+        }
+
+        for bit in 0..constraint.bits as usize {
+            steps.push(Step {
+                s0: s0.bit(bit),
+                s1: s1.bit(bit),
+                value: (constraint.value >> bit) & 1 == 1,
+                last: bit == constraint.bits as usize - 1,
+            });
+        }
+    }
+    steps
+}
+
+/// Searcher that recovers the den seed of a Sword/Shield raid from observed pokemon
+///
+/// The lower bits of every IV, nature and ability roll are a sum of the two Xoroshiro state
+/// words which are linear functions of the den seed. Carries are guessed bit by bit and the
+/// remaining seed bits are brute forced and checked against the observations. The first
+/// observation should have as few flawless IVs as possible since every random IV narrows the
+/// search, while the optional second observation is only used to confirm the seed.
+#[derive(Clone)]
+pub struct RaidSeedSearcher {
+    first: RaidObservation,
+    second: Option<(u32, RaidObservation)>,
+    rerolls: u8,
+    limit: u32,
+    results: Arc<Mutex<Vec<u64>>>,
+    progress: Arc<AtomicU32>,
+    searching: Arc<AtomicBool>,
+    underconstrained: Arc<AtomicU32>,
+}
+
+impl RaidSeedSearcher {
+    /// Construct a new [`RaidSeedSearcher`] struct
+    ///
+    /// `second` is a pokemon observed `advances` days after the first. `rerolls` is the
+    /// maximum number of rejected rolls that are considered per pattern.
+    ///
+    /// Returns [`RaidSeedSearcherError::Underconstrained`] if the first observation leaves more
+    /// than 32 seed bits unknown, or more than 16 without a second observation.
+    pub fn new(
+        first: RaidObservation,
+        second: Option<(u32, RaidObservation)>,
+        rerolls: u8,
+    ) -> Result<Self, RaidSeedSearcherError> {
+        let limit = if second.is_some() {
+            MAX_FREE_BITS
+        } else {
+            MAX_UNCONFIRMED_BITS
+        };
+        let free = 64u32.saturating_sub(first.known_bits(rerolls));
+        if free > limit {
+            return Err(RaidSeedSearcherError::Underconstrained(free));
+        }
+
+        Ok(Self {
+            first,
+            second,
+            rerolls,
+            limit,
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            searching: Arc::new(AtomicBool::new(false)),
+            underconstrained: Arc::new(AtomicU32::new(0)),
+        })
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    ///
+    /// Progress is the number of finished tasks out of [`RaidSeedSearcher::get_max_progress()`]
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of tasks the search is split into
+    pub fn get_max_progress(&self) -> u32 {
+        self.first.patterns(self.rerolls).len() as u32 * (1 << SPLIT_DEPTH)
+    }
+
+    /// Returns the seeds found by the running search
+    pub fn get_results(&self) -> Vec<u64> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    ///
+    /// Tasks are handed out to `threads` and the function returns once every thread has
+    /// finished. Branches whose carry guesses leave too many seed bits unknown are skipped and
+    /// reported as [`RaidSeedSearcherError::Underconstrained`] once the search is done.
+    pub fn start_search(&self, threads: usize) -> Result<(), RaidSeedSearcherError> {
+        self.searching.store(true, Ordering::SeqCst);
+        self.underconstrained.store(0, Ordering::SeqCst);
+
+        let patterns: Vec<Vec<Step>> = self
+            .first
+            .patterns(self.rerolls)
+            .iter()
+            .map(|constraints| build_steps(constraints))
+            .collect();
+        let total = patterns.len() as u32 * (1 << SPLIT_DEPTH);
+        let next = AtomicU32::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..threads.max(1) {
+                scope.spawn(|| {
+                    let mut seeds = vec![];
+                    loop {
+                        let task = next.fetch_add(1, Ordering::SeqCst);
+                        if task >= total || !self.searching.load(Ordering::SeqCst) {
+                            break;
+                        }
+
+                        let steps = &patterns[(task >> SPLIT_DEPTH) as usize];
+                        let branch = task & ((1 << SPLIT_DEPTH) - 1);
+                        self.search(steps, System::new(), 0, false, branch, 0, &mut seeds);
+
+                        self.progress.fetch_add(1, Ordering::SeqCst);
+                    }
+
+                    let mut results = self.results.lock().unwrap();
+                    for seed in seeds {
+                        if !results.contains(&seed) {
+                            results.push(seed);
+                        }
+                    }
+                });
+            }
+        });
+
+        match self.underconstrained.load(Ordering::SeqCst) {
+            0 => Ok(()),
+            bits => Err(RaidSeedSearcherError::Underconstrained(bits)),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn search(
+        &self,
+        steps: &[Step],
+        mut system: System,
+        index: usize,
+        carry: bool,
+        branch: u32,
+        depth: u32,
+        seeds: &mut Vec<u64>,
+    ) {
+        let Some(step) = steps.get(index) else {
+            // Tasks that never reach the split depth only run once
+            if depth < SPLIT_DEPTH && (branch >> depth) != 0 {
+                return;
+            }
+            self.brute_force(&system, seeds);
+            return;
+        };
+
+        // Bit of the sum is s0 ^ s1 ^ carry
+        let target = step.value ^ carry;
+        if !system.insert(step.s0.0 ^ step.s1.0, target ^ step.s0.1 ^ step.s1.1) {
+            return;
+        }
+
+        if step.last {
+            self.search(steps, system, index + 1, false, branch, depth, seeds);
+            return;
+        }
+
+        let next_carry = |a: bool| {
+            let b = a ^ target;
+            (a & b) | (a & carry) | (b & carry)
+        };
+
+        match system.evaluate(step.s0) {
+            Some(a) => self.search(
+                steps,
+                system,
+                index + 1,
+                next_carry(a),
+                branch,
+                depth,
+                seeds,
+            ),
+            None => {
+                for a in [false, true] {
+                    if depth < SPLIT_DEPTH && ((branch >> depth) & 1 == 1) != a {
+                        continue;
+                    }
+
+                    let mut system = system;
+                    system.insert(step.s0.0, a ^ step.s0.1);
+                    self.search(
+                        steps,
+                        system,
+                        index + 1,
+                        next_carry(a),
+                        branch,
+                        depth + 1,
+                        seeds,
+                    );
+                }
+            }
+        }
+    }
+
+    fn brute_force(&self, system: &System, seeds: &mut Vec<u64>) {
+        // Every solution is the base solution XOR a combination of the free bit vectors
+        let base = system.solve(0);
+        let vectors: Vec<u64> = (0..64)
+            .filter(|&bit| (system.pivots >> bit) & 1 == 0)
+            .map(|bit| system.solve(1 << bit) ^ base)
+            .collect();
+        if vectors.len() as u32 > self.limit {
+            self.underconstrained
+                .fetch_max(vectors.len() as u32, Ordering::SeqCst);
+            return;
+        }
+
+        // Walk the combinations in gray code order so each step is a single XOR
+        let mut seed = base;
+        for combination in 0..(1u64 << vectors.len()) {
+            if combination != 0 {
+                seed ^= vectors[combination.trailing_zeros() as usize];
+            }
+
+            if combination & 0xFFFF == 0 && !self.searching.load(Ordering::SeqCst) {
+                return;
+            }
+
+            // The second observation is checked first since it rejects most seeds
+            let second = match self.second {
+                Some((advances, observation)) => observation
+                    .matches(seed.wrapping_add(RAID_CONSTANT.wrapping_mul(advances as u64))),
+                None => true,
+            };
+            if second && self.first.matches(seed) {
+                seeds.push(seed);
+            }
+        }
+    }
+}
+
+impl SearcherT for RaidSeedSearcher {
+    type State = u64;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -0,0 +1,287 @@
+use crate::gen8::generators::{EggGenerator8, EventGenerator8, StaticGenerator8, WildGenerator8};
+use crate::gen8::EncounterArea8;
+use crate::parents::searchers::SearcherT;
+use crate::parents::states::{EggGeneratorState, GeneratorState, WildGeneratorState};
+use crate::parents::StaticTemplate;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Number of advances each thread generates before checking for cancellation
+const CHUNK: u32 = 0x1000;
+
+/// Trait for Gen8 generators that can be driven by a [`Searcher8`]
+pub trait SeedGenerator8: Clone + Sync {
+    /// State type returned by the generator
+    type State: Send;
+
+    /// Returns the first advance that is generated
+    fn initial_advances(&self) -> u32;
+
+    /// Returns the number of advances generated after the initial advance
+    fn max_advances(&self) -> u32;
+
+    /// Returns a copy of the generator that covers a different advance window
+    fn with_window(&self, initial_advances: u32, max_advances: u32) -> Self;
+
+    /// Generates the states for the Xorshift state `seed0`/`seed1`
+    fn generate(&self, seed0: u64, seed1: u64) -> Vec<Self::State>;
+}
+
+/// Binds a [`StaticGenerator8`] to the template used by a [`StaticSearcher8`]
+#[derive(Clone)]
+pub struct StaticSearcherGenerator8<'a, 'b, 'c> {
+    generator: StaticGenerator8<'a, 'b>,
+    static_template: &'c StaticTemplate,
+    roamer: bool,
+}
+
+impl<'a, 'b, 'c> StaticSearcherGenerator8<'a, 'b, 'c> {
+    /// Construct a new [`StaticSearcherGenerator8`] struct
+    ///
+    /// When `roamer` is set [`StaticGenerator8::generate_roamer()`] is used instead of
+    /// [`StaticGenerator8::generate()`].
+    pub fn new(
+        generator: StaticGenerator8<'a, 'b>,
+        static_template: &'c StaticTemplate,
+        roamer: bool,
+    ) -> Self {
+        Self {
+            generator,
+            static_template,
+            roamer,
+        }
+    }
+}
+
+impl SeedGenerator8 for StaticSearcherGenerator8<'_, '_, '_> {
+    type State = GeneratorState;
+
+    fn initial_advances(&self) -> u32 {
+        self.generator.base.base.initial_advances
+    }
+
+    fn max_advances(&self) -> u32 {
+        self.generator.base.base.max_advances
+    }
+
+    fn with_window(&self, initial_advances: u32, max_advances: u32) -> Self {
+        let mut new = self.clone();
+        new.generator.base.base.initial_advances = initial_advances;
+        new.generator.base.base.max_advances = max_advances;
+        new
+    }
+
+    fn generate(&self, seed0: u64, seed1: u64) -> Vec<Self::State> {
+        if self.roamer {
+            self.generator
+                .generate_roamer(seed0, seed1, self.static_template)
+        } else {
+            self.generator.generate(seed0, seed1, self.static_template)
+        }
+    }
+}
+
+/// Binds a [`WildGenerator8`] to the encounter area used by a [`WildSearcher8`]
+#[derive(Clone)]
+pub struct WildSearcherGenerator8<'a, 'b, 'c> {
+    generator: WildGenerator8<'a, 'b>,
+    encounter_area: &'c EncounterArea8,
+}
+
+impl<'a, 'b, 'c> WildSearcherGenerator8<'a, 'b, 'c> {
+    /// Construct a new [`WildSearcherGenerator8`] struct
+    pub fn new(generator: WildGenerator8<'a, 'b>, encounter_area: &'c EncounterArea8) -> Self {
+        Self {
+            generator,
+            encounter_area,
+        }
+    }
+}
+
+impl SeedGenerator8 for WildSearcherGenerator8<'_, '_, '_> {
+    type State = WildGeneratorState;
+
+    fn initial_advances(&self) -> u32 {
+        self.generator.base.base.initial_advances
+    }
+
+    fn max_advances(&self) -> u32 {
+        self.generator.base.base.max_advances
+    }
+
+    fn with_window(&self, initial_advances: u32, max_advances: u32) -> Self {
+        let mut new = self.clone();
+        new.generator.base.base.initial_advances = initial_advances;
+        new.generator.base.base.max_advances = max_advances;
+        new
+    }
+
+    fn generate(&self, seed0: u64, seed1: u64) -> Vec<Self::State> {
+        self.generator.generate(seed0, seed1, self.encounter_area)
+    }
+}
+
+impl SeedGenerator8 for EggGenerator8<'_, '_, '_> {
+    type State = EggGeneratorState;
+
+    fn initial_advances(&self) -> u32 {
+        self.base.base.initial_advances
+    }
+
+    fn max_advances(&self) -> u32 {
+        self.base.base.max_advances
+    }
+
+    fn with_window(&self, initial_advances: u32, max_advances: u32) -> Self {
+        let mut new = self.clone();
+        new.base.base.initial_advances = initial_advances;
+        new.base.base.max_advances = max_advances;
+        new
+    }
+
+    fn generate(&self, seed0: u64, seed1: u64) -> Vec<Self::State> {
+        EggGenerator8::generate(self, seed0, seed1)
+    }
+}
+
+impl SeedGenerator8 for EventGenerator8<'_, '_, '_> {
+    type State = GeneratorState;
+
+    fn initial_advances(&self) -> u32 {
+        self.base.initial_advances
+    }
+
+    fn max_advances(&self) -> u32 {
+        self.base.max_advances
+    }
+
+    fn with_window(&self, initial_advances: u32, max_advances: u32) -> Self {
+        let mut new = self.clone();
+        new.base.initial_advances = initial_advances;
+        new.base.max_advances = max_advances;
+        new
+    }
+
+    fn generate(&self, seed0: u64, seed1: u64) -> Vec<Self::State> {
+        EventGenerator8::generate(self, seed0, seed1)
+    }
+}
+
+/// Searcher that sweeps the advance window of a Gen8 generator from a known Xorshift state
+///
+/// The window of the generator is split between threads and generated in chunks so that the
+/// search can be cancelled. Known states can be recovered from observations with
+/// [`recover_xorshift`](super::recover_xorshift).
+#[derive(Clone)]
+pub struct Searcher8<'a, G: SeedGenerator8> {
+    generator: &'a G,
+    results: Arc<Mutex<Vec<G::State>>>,
+    progress: Arc<AtomicU32>,
+    searching: Arc<AtomicBool>,
+}
+
+/// Searcher for BDSP static encounters
+pub type StaticSearcher8<'a, 'b, 'c, 'd> = Searcher8<'a, StaticSearcherGenerator8<'b, 'c, 'd>>;
+/// Searcher for BDSP wild encounters
+pub type WildSearcher8<'a, 'b, 'c, 'd> = Searcher8<'a, WildSearcherGenerator8<'b, 'c, 'd>>;
+/// Searcher for BDSP eggs
+pub type EggSearcher8<'a, 'b, 'c, 'd> = Searcher8<'a, EggGenerator8<'b, 'c, 'd>>;
+/// Searcher for BDSP wondercard events
+pub type EventSearcher8<'a, 'b, 'c, 'd> = Searcher8<'a, EventGenerator8<'b, 'c, 'd>>;
+
+impl<'a, G: SeedGenerator8> Searcher8<'a, G> {
+    /// Construct a new [`Searcher8`] struct
+    pub fn new(generator: &'a G) -> Self {
+        Self {
+            generator,
+            results: Arc::new(Mutex::new(vec![])),
+            progress: Arc::new(AtomicU32::new(0)),
+            searching: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Cancels the running search
+    pub fn cancel_search(&self) {
+        self.searching.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns the progress of the running search
+    ///
+    /// Progress is measured in advances
+    pub fn get_progress(&self) -> u32 {
+        self.progress.load(Ordering::SeqCst)
+    }
+
+    /// Returns the states of the running search
+    pub fn get_results(&self) -> Vec<G::State> {
+        std::mem::take(self.results.lock().unwrap().as_mut())
+    }
+
+    /// Starts the search
+    ///
+    /// The advance window of the generator is split between `threads` and the function
+    /// returns once every thread has finished.
+    pub fn start_search(&self, threads: usize, seed0: u64, seed1: u64) {
+        self.searching.store(true, Ordering::SeqCst);
+
+        let initial_advances = self.generator.initial_advances();
+        let total = self.generator.max_advances() as u64 + 1;
+        let threads = (threads as u64).clamp(1, total);
+        let split = total / threads;
+
+        thread::scope(|scope| {
+            let mut start = 0;
+            for i in 0..threads {
+                let count = if i == threads - 1 {
+                    total - start
+                } else {
+                    split
+                };
+
+                let initial = initial_advances.wrapping_add(start as u32);
+                scope.spawn(move || self.search(seed0, seed1, initial, count));
+
+                start += count;
+            }
+        });
+    }
+
+    fn search(&self, seed0: u64, seed1: u64, initial_advances: u32, count: u64) {
+        let mut states = vec![];
+
+        let mut offset = 0;
+        while offset < count {
+            if !self.searching.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let length = (count - offset).min(CHUNK as u64) as u32;
+            let generator = self
+                .generator
+                .with_window(initial_advances.wrapping_add(offset as u32), length - 1);
+            states.append(&mut generator.generate(seed0, seed1));
+
+            offset += length as u64;
+            self.progress.fetch_add(length, Ordering::SeqCst);
+        }
+
+        self.results.lock().unwrap().append(&mut states);
+    }
+}
+
+impl<G: SeedGenerator8> SearcherT for Searcher8<'_, G> {
+    type State = G::State;
+
+    fn cancel(&self) {
+        self.cancel_search();
+    }
+
+    fn progress(&self) -> u32 {
+        self.get_progress()
+    }
+
+    fn results(&self) -> Vec<Self::State> {
+        self.get_results()
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::{Display, Formatter};
+
+/// Maximum number of unknown state bits that are enumerated by [`recover_xorshift()`]
+const MAX_FREE_BITS: u32 = 16;
+
+/// Errors returned by [`recover_xorshift()`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum XorshiftRecoveryError {
+    /// No state produces every observation
+    Contradictory,
+    /// The observations leave this many state bits unknown, which is too many to enumerate
+    Underconstrained(u32),
+}
+
+impl Display for XorshiftRecoveryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XorshiftRecoveryError::Contradictory => write!(
+                f,
+                "the observations contradict each other, check that they were entered correctly"
+            ),
+            XorshiftRecoveryError::Underconstrained(bits) => write!(
+                f,
+                "the observations leave {bits} state bits unknown, observe more blinks"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for XorshiftRecoveryError {}
+
+type Word = [u128; 32];
+
+fn shift_left(word: &Word, shift: usize) -> Word {
+    let mut out = [0; 32];
+    out[shift..].copy_from_slice(&word[..32 - shift]);
+    out
+}
+
+fn shift_right(word: &Word, shift: usize) -> Word {
+    let mut out = [0; 32];
+    out[..32 - shift].copy_from_slice(&word[shift..]);
+    out
+}
+
+fn xor(mut x: Word, y: &Word) -> Word {
+    x.iter_mut().zip(y).for_each(|(x, y)| *x ^= y);
+    x
+}
+
+/// Xorshift where every output bit is tracked as the XOR of initial state bits
+struct SymbolicXorshift {
+    state: [Word; 4],
+}
+
+impl SymbolicXorshift {
+    fn new() -> Self {
+        let mut state = [[0; 32]; 4];
+        for (i, word) in state.iter_mut().enumerate() {
+            for (j, bit) in word.iter_mut().enumerate() {
+                *bit = 1 << (i * 32 + j);
+            }
+        }
+        Self { state }
+    }
+
+    fn next(&mut self) -> Word {
+        let mut t = self.state[0];
+        let s = self.state[3];
+
+        t = xor(t, &shift_left(&t, 11));
+        t = xor(t, &shift_right(&t, 8));
+        t = xor(xor(t, &s), &shift_right(&s, 19));
+
+        self.state.rotate_left(1);
+        self.state[3] = t;
+
+        t
+    }
+}
+
+/// Partially known Xorshift output
+///
+/// `advance` is the number of advances from the state being recovered to the output and only
+/// the bits set in `mask` are compared against `value`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct XorshiftObservation {
+    /// Advance of the observed output
+    pub advance: u32,
+    /// Known bits of the output
+    pub mask: u32,
+    /// Value of the known bits
+    pub value: u32,
+}
+
+impl XorshiftObservation {
+    /// Construct a new [`XorshiftObservation`] struct
+    pub fn new(advance: u32, mask: u32, value: u32) -> Self {
+        Self {
+            advance,
+            mask,
+            value: value & mask,
+        }
+    }
+
+    /// Creates the observations of a sequence of player blinks
+    ///
+    /// The player blinks when bits 1-3 of an output are all 0 and bit 0 decides between a
+    /// single (0) or double (1) blink. `intervals` holds the number of advances since the
+    /// previous blink; the first blink happens on advance 0 so `intervals[0]` is ignored.
+    pub fn from_blinks(blinks: &[u8], intervals: &[u32]) -> Vec<Self> {
+        let mut advance = 0u32;
+        blinks
+            .iter()
+            .enumerate()
+            .map(|(i, &blink)| {
+                if i != 0 {
+                    advance = advance.wrapping_add(intervals[i]);
+                }
+                Self::new(advance, 0xF, blink as u32 & 1)
+            })
+            .collect()
+    }
+
+    /// Creates the observations of an encountered static or wild pokemon
+    ///
+    /// `advance` is the advance of the output that generated the EC. IVs are only used when
+    /// the pokemon has no guaranteed flawless IVs since their order is otherwise random.
+    pub fn from_pokemon(advance: u32, ec: u32, ivs: [u8; 6], flawless: bool) -> Vec<Self> {
+        let mut observations = vec![Self::new(advance, 0xFFFFFFFF, ec ^ 0x80000000)];
+        if !flawless {
+            observations.extend(
+                ivs.iter()
+                    .enumerate()
+                    .map(|(i, &iv)| Self::new(advance.wrapping_add(3 + i as u32), 0x1F, iv as u32)),
+            );
+        }
+        observations
+    }
+}
+
+/// Recovers the Xorshift state from partially known outputs
+///
+/// Xorshift is linear over GF(2) so every known output bit is an equation of the 128 state
+/// bits. Returns every `seed0`/`seed1` pair that is consistent with the `observations`.
+/// Returns [`XorshiftRecoveryError::Contradictory`] if no state fits the observations and
+/// [`XorshiftRecoveryError::Underconstrained`] if more than 2^16 states are possible.
+pub fn recover_xorshift(
+    observations: &[XorshiftObservation],
+) -> Result<Vec<(u64, u64)>, XorshiftRecoveryError> {
+    let mut observations = observations.to_vec();
+    observations.sort_by_key(|observation| observation.advance);
+
+    let mut pivots: [Option<(u128, bool)>; 128] = [None; 128];
+    let mut rng = SymbolicXorshift::new();
+    let mut output = [0; 32];
+    let mut advance = 0;
+
+    for observation in observations {
+        while advance <= observation.advance {
+            output = rng.next();
+            advance += 1;
+        }
+
+        for (bit, &equation) in output.iter().enumerate() {
+            if (observation.mask >> bit) & 1 == 0 {
+                continue;
+            }
+
+            let mut equation = equation;
+            let mut result = (observation.value >> bit) & 1 == 1;
+            while equation != 0 {
+                let lead = 127 - equation.leading_zeros() as usize;
+                match pivots[lead] {
+                    Some((pivot, pivot_result)) => {
+                        equation ^= pivot;
+                        result ^= pivot_result;
+                    }
+                    None => {
+                        pivots[lead] = Some((equation, result));
+                        break;
+                    }
+                }
+            }
+
+            if equation == 0 && result {
+                return Err(XorshiftRecoveryError::Contradictory);
+            }
+        }
+    }
+
+    let free: Vec<usize> = (0..128).filter(|&bit| pivots[bit].is_none()).collect();
+    if free.len() as u32 > MAX_FREE_BITS {
+        return Err(XorshiftRecoveryError::Underconstrained(free.len() as u32));
+    }
+
+    let mut results = vec![];
+    for combination in 0..(1u32 << free.len()) {
+        let mut state = free.iter().enumerate().fold(0u128, |state, (i, &bit)| {
+            state | (((combination >> i) & 1) as u128) << bit
+        });
+
+        // Pivot equations only reference lower bits so they can be solved in order
+        for (bit, pivot) in pivots.iter().enumerate() {
+            if let Some((equation, result)) = pivot {
+                let parity = (equation & state & !(1 << bit)).count_ones() & 1 == 1;
+                if parity != *result {
+                    state |= 1 << bit;
+                }
+            }
+        }
+
+        let word = |i: u32| ((state >> (i * 32)) & 0xFFFFFFFF) as u64;
+        results.push(((word(0) << 32) | word(1), (word(2) << 32) | word(3)));
+    }
+
+    Ok(results)
+}
@@ -1,7 +1,8 @@
 use crate::parents::states::IDStateT;
+use serde::{Deserialize, Serialize};
 
 /// Contains additional information for Gen 8 TID/SID
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct IDState8 {
     /// Advances of the state
     pub advances: u32,
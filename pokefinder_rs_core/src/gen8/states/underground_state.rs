@@ -1,8 +1,9 @@
 use crate::parents::states::{GeneratorState, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// Generator state for Gen8 underground encounters
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub struct UndergroundState {
     /// Base generator state data
     pub base: GeneratorState,
@@ -98,8 +98,17 @@ impl WB8 {
         }
     }
 
-    /// Returns the pokemon information
+    /// Returns the built-in pokemon information
+    ///
+    /// Custom tables are ignored, use [`WB8::resolve_info`] to take them into account.
     pub fn get_info(&self, version: Game) -> &'static PersonalInfo {
         personal_loader::get_personal_info(version, self.species, self.form)
     }
+
+    /// Resolves the pokemon information
+    ///
+    /// Unlike [`WB8::get_info`] this takes custom tables into account.
+    pub fn resolve_info(&self, version: Game) -> PersonalInfo {
+        personal_loader::resolve_personal_info(version, self.species, self.form)
+    }
 }
@@ -14,6 +14,10 @@ pub mod gen3;
 pub mod gen4;
 /// Contains tools and structures used for predicting Gen 5 games.
 pub mod gen5;
+/// Contains tools and structures used for predicting Gen 6 games.
+pub mod gen6;
+/// Contains tools and structures used for predicting Gen 7 games.
+pub mod gen7;
 /// Contains tools and structures used for predicting Gen 8 games.
 pub mod gen8;
 /// Contains parent structures that tools within the other modules share.
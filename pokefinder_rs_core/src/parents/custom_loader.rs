@@ -0,0 +1,328 @@
+use crate::enums::{Encounter, Game};
+use crate::parents::{personal_loader, EncounterAreaT, PersonalInfo, Slot};
+use crate::util::{self, encounter_slot};
+#[cfg(not(target_arch = "wasm32"))]
+use nds_encounters::{nds::Nds, rom};
+use serde::Deserialize;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{Error, ErrorKind};
+use std::sync::{Arc, RwLock};
+
+static CUSTOM: RwLock<Vec<CustomTables>> = RwLock::new(Vec::new());
+/// Uncompressed wild encounters read from ROMs
+static ROMS: RwLock<Vec<(Game, Arc<[u8]>)>> = RwLock::new(Vec::new());
+
+/// Custom data registered for the games in `version`
+struct CustomTables {
+    version: Game,
+    personal: Option<Arc<[PersonalInfo]>>,
+    encounters: Vec<CustomArea>,
+}
+
+#[derive(Deserialize)]
+struct CustomData {
+    #[serde(default)]
+    personal: Vec<CustomPersonal>,
+    #[serde(default)]
+    encounters: Vec<CustomArea>,
+}
+
+#[derive(Deserialize)]
+struct CustomPersonal {
+    species: u16,
+    #[serde(default)]
+    form: u8,
+    stats: [u8; 6],
+    types: [u8; 2],
+    #[serde(default)]
+    items: [u16; 3],
+    gender: u8,
+    abilities: [u16; 3],
+}
+
+#[derive(Clone, Deserialize)]
+struct CustomArea {
+    location: u8,
+    encounter: Encounter,
+    rate: u8,
+    slots: Vec<CustomSlot>,
+}
+
+#[derive(Clone, Deserialize)]
+struct CustomSlot {
+    species: u16,
+    #[serde(default)]
+    form: u8,
+    min_level: u8,
+    max_level: u8,
+}
+
+fn error(message: String) -> serde_json::Error {
+    <serde_json::Error as serde::de::Error>::custom(message)
+}
+
+/// Returns the number of slots the games in `version` use for `encounter`, or `None` when the
+/// games disagree or have no wild encounter data
+fn slot_count(version: Game, encounter: Encounter) -> Option<usize> {
+    let slots = [
+        (
+            Game::GEN3,
+            encounter_slot::h_slot as fn(u8, Encounter) -> u8,
+        ),
+        (Game::DPPT, encounter_slot::j_slot),
+        (Game::HGSS, encounter_slot::k_slot),
+        (Game::GEN5, encounter_slot::bw_slot),
+        (Game::GEN7, |rand, _| encounter_slot::sm_slot(rand)),
+        (Game::BDSP, encounter_slot::bdsp_slot),
+    ];
+
+    let mut count = None;
+    for (games, slot) in slots {
+        if (games & version) == Game::NONE {
+            continue;
+        }
+        let games_count = (0..100)
+            .map(|rand| slot(rand, encounter))
+            .max()
+            .unwrap_or(0) as usize
+            + 1;
+        if count.is_some_and(|count| count != games_count) {
+            return None;
+        }
+        count = Some(games_count);
+    }
+    count
+}
+
+/// Loads custom personal and encounter data for the games in `version` from `json`, replacing any
+/// custom data previously loaded for them.
+///
+/// Every generator, searcher and encounter lookup of those games uses the custom data until
+/// [`clear_custom_data`] is called. Both lists are optional, personal entries overlay the
+/// built-in table and encounter areas replace the built-in area with the same location and
+/// encounter type, or are added when there is none.
+///
+/// ```json
+/// {
+///     "personal": [
+///         {
+///             "species": 25,
+///             "form": 0,
+///             "stats": [35, 55, 40, 50, 50, 90],
+///             "types": [12, 12],
+///             "items": [0, 155, 0],
+///             "gender": 127,
+///             "abilities": [9, 9, 31]
+///         }
+///     ],
+///     "encounters": [
+///         {
+///             "location": 16,
+///             "encounter": "Grass",
+///             "rate": 20,
+///             "slots": [
+///                 { "species": 25, "form": 0, "min_level": 3, "max_level": 5 }
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+///
+/// The data only applies to the games in `version`, use e.g. [`Game::GEN3`] for a hack that
+/// changes every game sharing a table. Forms can only be changed when the built-in table already
+/// has an entry for them. The `encounter` names match the variants of [`Encounter`] and each area
+/// needs as many slots as the games use for that encounter type, otherwise an error is returned.
+///
+/// # Example
+/// ```
+/// # use pokefinder_rs_core::enums::Game;
+/// # use pokefinder_rs_core::parents::{custom_loader, personal_loader};
+/// let json = r#"{ "personal": [{ "species": 1, "stats": [99, 49, 49, 65, 65, 45],
+///     "types": [11, 3], "gender": 31, "abilities": [65, 65, 65] }] }"#;
+/// custom_loader::load_custom_data(Game::EMERALD, json).unwrap();
+/// assert_eq!(personal_loader::resolve_personal_info(Game::EMERALD, 1, 0).get_stat(0), 99);
+/// custom_loader::clear_custom_data(Game::EMERALD);
+/// assert_eq!(personal_loader::resolve_personal_info(Game::EMERALD, 1, 0).get_stat(0), 45);
+/// ```
+pub fn load_custom_data(version: Game, json: &str) -> Result<(), serde_json::Error> {
+    let data: CustomData = serde_json::from_str(json)?;
+
+    let personal = if data.personal.is_empty() {
+        None
+    } else {
+        let mut table = personal_loader::get_personal_table(version).to_vec();
+        for entry in &data.personal {
+            let Some(base) = table.get(entry.species as usize) else {
+                return Err(error(format!("species {} does not exist", entry.species)));
+            };
+
+            let index = if entry.form == 0 {
+                entry.species as usize
+            } else if base.get_form_stats_index() != 0 && entry.form < base.get_form_count() {
+                base.get_form_stats_index() as usize + entry.form as usize - 1
+            } else {
+                return Err(error(format!(
+                    "species {} has no form {}",
+                    entry.species, entry.form
+                )));
+            };
+
+            let info = table[index];
+            table[index] = PersonalInfo::new(
+                entry.stats,
+                entry.types,
+                entry.items,
+                entry.gender,
+                entry.abilities,
+                info.get_form_count(),
+                info.get_form_stats_index(),
+                info.get_hatch_species(),
+                1,
+            );
+        }
+        Some(Arc::from(table))
+    };
+
+    for area in &data.encounters {
+        let Some(count) = slot_count(version, area.encounter) else {
+            return Err(error(format!(
+                "location {} uses an encounter type without a fixed slot count",
+                area.location
+            )));
+        };
+        if area.slots.len() != count {
+            return Err(error(format!(
+                "location {} has {} slots but the encounter type uses {}",
+                area.location,
+                area.slots.len(),
+                count
+            )));
+        }
+    }
+
+    let mut custom = CUSTOM.write().unwrap();
+    custom.retain(|tables| (tables.version & version) == Game::NONE);
+    custom.push(CustomTables {
+        version,
+        personal,
+        encounters: data.encounters,
+    });
+
+    Ok(())
+}
+
+/// Loads the wild encounters of a Gen 4 or Gen 5 `.nds` ROM dump, replacing the built-in wild
+/// encounters of its game until [`clear_custom_data`] is called.
+///
+/// Both the encounter tables and the maps they belong to are read from the ROM, so translated and
+/// hacked ROMs work as long as they keep the layout of the original game. Returns the game of the
+/// ROM, or an error when it can not be parsed or is not a Diamond, Pearl, Platinum, HeartGold,
+/// SoulSilver, Black, White, Black 2 or White 2 ROM.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_rom(rom: Vec<u8>) -> std::io::Result<Game> {
+    let rom = Nds::new(rom)?;
+    let version = match rom.game_code().get(..3) {
+        Some("ADA") => Game::DIAMOND,
+        Some("APA") => Game::PEARL,
+        Some("CPU") => Game::PLATINUM,
+        Some("IPK") => Game::HEART_GOLD,
+        Some("IPG") => Game::SOUL_SILVER,
+        Some("IRB") => Game::BLACK,
+        Some("IRA") => Game::WHITE,
+        Some("IRE") => Game::BLACK2,
+        Some("IRD") => Game::WHITE2,
+        _ => {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("{} is not a Gen 4 or Gen 5 game", rom.game_code()),
+            ))
+        }
+    };
+    let (_, data) = rom::pack_encounters(&rom)?;
+
+    let mut roms = ROMS.write().unwrap();
+    roms.retain(|&(game, _)| game != version);
+    roms.push((version, Arc::from(data)));
+
+    Ok(version)
+}
+
+/// Removes the custom data and ROM encounters of the games in `version`, restoring the built-in
+/// data
+pub fn clear_custom_data(version: Game) {
+    CUSTOM
+        .write()
+        .unwrap()
+        .retain(|tables| (tables.version & version) == Game::NONE);
+    ROMS.write()
+        .unwrap()
+        .retain(|&(game, _)| (game & version) == Game::NONE);
+}
+
+/// Returns the wild encounters loaded from a ROM of `version`, otherwise decompresses the
+/// `built_in` encounters
+pub(crate) fn get_wild_encounters(version: Game, built_in: &[u8]) -> Vec<u8> {
+    ROMS.read()
+        .unwrap()
+        .iter()
+        .find(|&&(game, _)| game == version)
+        .map_or_else(|| util::decompress(built_in), |(_, data)| data.to_vec())
+}
+
+/// Returns the custom personal table for `version` if one is loaded
+pub(crate) fn get_personal_table(version: Game) -> Option<Arc<[PersonalInfo]>> {
+    CUSTOM
+        .read()
+        .unwrap()
+        .iter()
+        .find(|tables| (tables.version & version) != Game::NONE)
+        .and_then(|tables| tables.personal.clone())
+}
+
+/// Overlays the custom `encounter` areas of `version` onto the built-in `areas`
+pub(crate) fn apply_encounters<A: EncounterAreaT>(
+    version: Game,
+    encounter: Encounter,
+    areas: &mut Vec<A>,
+    new: fn(u8, u8, Encounter, Vec<Slot>) -> A,
+) {
+    // The personal table is resolved after the lock is released, it takes the lock again
+    let custom_areas: Vec<CustomArea> = CUSTOM
+        .read()
+        .unwrap()
+        .iter()
+        .find(|tables| (tables.version & version) != Game::NONE)
+        .map(|tables| {
+            tables
+                .encounters
+                .iter()
+                .filter(|area| area.encounter == encounter)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if custom_areas.is_empty() {
+        return;
+    }
+
+    let info = personal_loader::resolve_personal_table(version);
+    for area in custom_areas {
+        let slots = area
+            .slots
+            .iter()
+            .map(|slot| {
+                Slot::new_with_form(
+                    slot.species,
+                    slot.form,
+                    slot.min_level,
+                    slot.max_level,
+                    info.get_personal_info(slot.species, slot.form),
+                )
+            })
+            .collect();
+
+        areas.retain(|built_in| built_in.get_location() != area.location);
+        areas.push(new(area.location, area.rate, encounter, slots));
+    }
+}
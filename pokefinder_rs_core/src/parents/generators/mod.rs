@@ -1,11 +1,13 @@
 mod egg_generator;
 mod generator;
 mod id_generator;
+mod researcher_generator;
 mod static_generator;
 mod wild_generator;
 
 pub use egg_generator::*;
 pub use generator::*;
 pub use id_generator::*;
+pub use researcher_generator::*;
 pub use static_generator::*;
 pub use wild_generator::*;
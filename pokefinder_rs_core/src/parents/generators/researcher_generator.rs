@@ -0,0 +1,359 @@
+use crate::parents::states::ResearcherState;
+use crate::rng::{PokeRNG, Rng, TinyMT, Xoroshiro, Xorshift, ARNG, BWRNG, MT, XDRNG};
+
+/// PRNG and seed that the [`ResearcherGenerator`] reads values from
+#[derive(Copy, Clone)]
+pub enum ResearcherRng {
+    /// Gen 3/4 [`PokeRNG`] with a 32bit seed
+    PokeRNG(u32),
+    /// Gamecube [`XDRNG`] with a 32bit seed
+    XDRNG(u32),
+    /// Gen 4 [`ARNG`] with a 32bit seed
+    ARNG(u32),
+    /// Gen 5 [`BWRNG`] with a 64bit seed
+    BWRNG(u64),
+    /// [`MT`] with a 32bit seed
+    MT(u32),
+    /// [`TinyMT`] with a 32bit seed
+    TinyMT(u32),
+    /// [`TinyMT`] with its full 128bit state
+    TinyMTState(u32, u32, u32, u32),
+    /// BDSP [`Xorshift`] with two 64bit seeds
+    Xorshift(u64, u64),
+    /// SwSh [`Xoroshiro`] with two 64bit seeds
+    Xoroshiro(u64, u64),
+}
+
+impl ResearcherRng {
+    /// Returns if the PRNG values are 64bit
+    pub fn is_64bit(&self) -> bool {
+        matches!(self, Self::BWRNG(_) | Self::Xoroshiro(_, _))
+    }
+
+    fn values(&self, initial_advances: u32) -> Box<dyn FnMut() -> u64> {
+        match *self {
+            Self::PokeRNG(seed) => {
+                let mut rng = PokeRNG::new_with_initial_advances(seed, initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::XDRNG(seed) => {
+                let mut rng = XDRNG::new_with_initial_advances(seed, initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::ARNG(seed) => {
+                let mut rng = ARNG::new_with_initial_advances(seed, initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::BWRNG(seed) => {
+                let mut rng = BWRNG::new_with_initial_advances(seed, initial_advances);
+                Box::new(move || rng.next())
+            }
+            Self::MT(seed) => {
+                let mut rng = MT::new_with_initial_advances(seed, initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::TinyMT(seed) => {
+                let mut rng = TinyMT::new(seed);
+                rng.advance(initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::TinyMTState(seed0, seed1, seed2, seed3) => {
+                let mut rng = TinyMT::new_from_full(seed0, seed1, seed2, seed3);
+                rng.advance(initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::Xorshift(seed0, seed1) => {
+                let mut rng = Xorshift::new_with_initial_advances(seed0, seed1, initial_advances);
+                Box::new(move || rng.next() as u64)
+            }
+            Self::Xoroshiro(seed0, seed1) => {
+                let mut rng = Xoroshiro::new(seed0, seed1);
+                rng.advance(initial_advances);
+                Box::new(move || rng.next())
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Operand {
+    Prng,
+    High32,
+    Low32,
+    High16,
+    Low16,
+    Previous,
+    Custom(usize),
+    Value(u64),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    ShiftLeft,
+    ShiftRight,
+    And,
+    Or,
+    Xor,
+}
+
+impl Operator {
+    fn apply(self, left: u64, right: u64) -> u64 {
+        match self {
+            Self::Add => left.wrapping_add(right),
+            Self::Subtract => left.wrapping_sub(right),
+            Self::Multiply => left.wrapping_mul(right),
+            Self::Divide => left.checked_div(right).unwrap_or(0),
+            Self::Modulo => left.checked_rem(right).unwrap_or(0),
+            Self::ShiftLeft => left.checked_shl(right as u32).unwrap_or(0),
+            Self::ShiftRight => left.checked_shr(right as u32).unwrap_or(0),
+            Self::And => left & right,
+            Self::Or => left | right,
+            Self::Xor => left ^ right,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Operand(Operand),
+    Operator(Operator),
+    Open,
+    Close,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Term {
+    Operand(Operand),
+    Group(Expression),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Expression {
+    first: Box<Term>,
+    rest: Vec<(Operator, Term)>,
+}
+
+impl Term {
+    fn evaluate(&self, operand: &dyn Fn(Operand) -> u64) -> u64 {
+        match self {
+            Term::Operand(o) => operand(*o),
+            Term::Group(expression) => expression.evaluate(operand),
+        }
+    }
+}
+
+impl Expression {
+    fn evaluate(&self, operand: &dyn Fn(Operand) -> u64) -> u64 {
+        self.rest
+            .iter()
+            .fold(self.first.evaluate(operand), |value, (operator, term)| {
+                operator.apply(value, term.evaluate(operand))
+            })
+    }
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c.is_ascii_alphanumeric() {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_ascii_alphanumeric() {
+                    break;
+                }
+                word.push(c.to_ascii_lowercase());
+                chars.next();
+            }
+
+            let operand = match word.as_str() {
+                "prng" => Operand::Prng,
+                "high32" => Operand::High32,
+                "low32" => Operand::Low32,
+                "high16" => Operand::High16,
+                "low16" => Operand::Low16,
+                "previous" => Operand::Previous,
+                _ => {
+                    if let Some(index) = word.strip_prefix("custom") {
+                        match index.parse::<usize>() {
+                            Ok(index @ 1..=10) => Operand::Custom(index - 1),
+                            _ => return None,
+                        }
+                    } else if let Some(hex) = word.strip_prefix("0x") {
+                        Operand::Value(u64::from_str_radix(hex, 16).ok()?)
+                    } else {
+                        Operand::Value(word.parse().ok()?)
+                    }
+                }
+            };
+            tokens.push(Token::Operand(operand));
+            continue;
+        }
+
+        chars.next();
+        let token = match c {
+            '+' => Token::Operator(Operator::Add),
+            '-' => Token::Operator(Operator::Subtract),
+            '*' => Token::Operator(Operator::Multiply),
+            '/' => Token::Operator(Operator::Divide),
+            '%' => Token::Operator(Operator::Modulo),
+            '&' => Token::Operator(Operator::And),
+            '|' => Token::Operator(Operator::Or),
+            '^' => Token::Operator(Operator::Xor),
+            '<' if chars.next_if_eq(&'<').is_some() => Token::Operator(Operator::ShiftLeft),
+            '>' if chars.next_if_eq(&'>').is_some() => Token::Operator(Operator::ShiftRight),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => return None,
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
+fn parse_term(tokens: &[Token], index: &mut usize) -> Option<Term> {
+    match tokens.get(*index)? {
+        Token::Operand(operand) => {
+            *index += 1;
+            Some(Term::Operand(*operand))
+        }
+        Token::Open => {
+            *index += 1;
+            let expression = parse_expression(tokens, index)?;
+            if tokens.get(*index)? != &Token::Close {
+                return None;
+            }
+            *index += 1;
+            Some(Term::Group(expression))
+        }
+        _ => None,
+    }
+}
+
+fn parse_expression(tokens: &[Token], index: &mut usize) -> Option<Expression> {
+    let first = Box::new(parse_term(tokens, index)?);
+    let mut rest = vec![];
+    while let Some(Token::Operator(operator)) = tokens.get(*index) {
+        *index += 1;
+        rest.push((*operator, parse_term(tokens, index)?));
+    }
+    Some(Expression { first, rest })
+}
+
+/// User defined calculation that fills a custom value of a [`ResearcherState`]
+///
+/// The expression is evaluated from left to right without operator precedence, so
+/// `prng >> 16 % 25` is `(prng >> 16) % 25`. Parentheses can be used to group terms.
+///
+/// Operands are `prng`, `high32`, `low32`, `high16`, `low16`, `previous` (the value of this
+/// calculation on the previous advance, 0 for the first one), `custom1` to `custom10` and decimal
+/// or `0x` prefixed hex numbers. A calculation can only refer to the custom values before its own.
+/// Operators are `+ - * / % << >> & | ^`, dividing by 0 gives 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Calculation {
+    expression: Expression,
+}
+
+impl Calculation {
+    /// Parses a new [`Calculation`] from `expression` that fills the custom value at `custom`,
+    /// 0 for `custom1`
+    ///
+    /// Returns [`None`] if the expression is not valid or refers to `custom` or a later custom value
+    pub fn new(expression: &str, custom: usize) -> Option<Self> {
+        let tokens = tokenize(expression)?;
+        if tokens
+            .iter()
+            .any(|token| matches!(token, Token::Operand(Operand::Custom(i)) if *i >= custom))
+        {
+            return None;
+        }
+
+        let mut index = 0;
+        let expression = parse_expression(&tokens, &mut index)?;
+        (index == tokens.len()).then_some(Self { expression })
+    }
+
+    fn evaluate(
+        &self,
+        state: &ResearcherState,
+        previous: Option<&ResearcherState>,
+        index: usize,
+    ) -> u64 {
+        let operand = |operand: Operand| match operand {
+            Operand::Prng => state.get_prng(),
+            Operand::High32 => state.get_high32() as u64,
+            Operand::Low32 => state.get_low32() as u64,
+            Operand::High16 => state.get_high16() as u64,
+            Operand::Low16 => state.get_low16() as u64,
+            Operand::Previous => previous.map_or(0, |previous| previous.get_custom(index)),
+            Operand::Custom(i) => state.get_custom(i),
+            Operand::Value(value) => value,
+        };
+
+        self.expression.evaluate(&operand)
+    }
+}
+
+/// Generator that evaluates custom calculations over the values of any supported PRNG
+#[derive(Clone)]
+pub struct ResearcherGenerator {
+    /// Initial number of advances
+    pub initial_advances: u32,
+    /// Maximum number of advances
+    pub max_advances: u32,
+    /// Calculations for each of the custom values
+    pub calculations: [Option<Calculation>; 10],
+}
+
+impl ResearcherGenerator {
+    /// Construct a new [`ResearcherGenerator`] struct
+    pub fn new(
+        initial_advances: u32,
+        max_advances: u32,
+        calculations: [Option<Calculation>; 10],
+    ) -> Self {
+        Self {
+            initial_advances,
+            max_advances,
+            calculations,
+        }
+    }
+
+    /// Generates [`ResearcherState`] states from the `rng`
+    ///
+    /// Custom values are calculated in order so a calculation can only refer to the ones
+    /// before it.
+    pub fn generate(&self, rng: ResearcherRng) -> Vec<ResearcherState> {
+        let mut next = rng.values(self.initial_advances);
+
+        let mut states: Vec<ResearcherState> = vec![];
+        for cnt in 0..=self.max_advances {
+            let mut state = ResearcherState::new(
+                self.initial_advances.wrapping_add(cnt),
+                next(),
+                rng.is_64bit(),
+            );
+
+            for (i, calculation) in self.calculations.iter().enumerate() {
+                if let Some(calculation) = calculation {
+                    let value = calculation.evaluate(&state, states.last(), i);
+                    state.set_custom(i, value);
+                }
+            }
+
+            states.push(state);
+        }
+
+        states
+    }
+}
@@ -1,3 +1,5 @@
+/// Contains functions for loading custom personal and encounter data at runtime
+pub mod custom_loader;
 mod daycare;
 mod encounter_area;
 /// Contains common filter structs that are shared across all games
@@ -41654,6 +41654,18918 @@ pub(crate) const PERSONAL_BW: [PersonalInfo; 668] = [
     ),
 ];
 
+/// Species data for X/Y and Omega Ruby/Alpha Sapphire
+///
+/// This is not a dump of the ORAS personal archive. It is derived from [`PERSONAL_USUM`]: species
+/// 1-721 keep their USUM entries with the Gen 7 base stat changes reverted (Arbok, Dugtrio,
+/// Farfetch'd, Dodrio, Noctowl, Ariados, Qwilfish, Magcargo, Corsola, Mantine, Swellow, Pelipper,
+/// Masquerain, Delcatty, Volbeat, Illumise, Lunatone, Solrock, Chimecho, Woobat, Crustle, Beartic
+/// and Cryogonal), the Alolan forms are dropped and the remaining alternate forms are appended
+/// from index 722 with their form indexes remapped.
+pub(crate) const PERSONAL_ORAS: [PersonalInfo; 780] = [
+    PersonalInfo::new(
+        [0, 0, 0, 0, 0, 0],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [0, 0, 0],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 49, 49, 65, 65, 45],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 63, 80, 80, 60],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 83, 100, 100, 80],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [39, 52, 43, 60, 50, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 64, 58, 80, 65, 80],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 84, 78, 109, 85, 100],
+        [9, 2],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 48, 65, 50, 64, 43],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 63, 80, 65, 80, 58],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 83, 100, 85, 105, 78],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 35, 20, 20, 45],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 20, 55, 25, 25, 30],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 50, 90, 80, 70],
+        [6, 2],
+        [0, 222, 0],
+        127,
+        [14, 14, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 30, 20, 20, 50],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 25, 50, 25, 25, 35],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 40, 45, 80, 75],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [68, 68, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 35, 35, 56],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 60, 55, 50, 50, 71],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [83, 80, 75, 70, 70, 101],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 56, 35, 25, 35, 72],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 62, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 81, 60, 50, 70, 97],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 62, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 60, 30, 31, 31, 70],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [51, 51, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 65, 61, 61, 100],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [51, 51, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 60, 44, 40, 54, 55],
+        [3, 3],
+        [0, 0, 0],
+        127,
+        [22, 61, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 69, 65, 79, 80],
+        [3, 3],
+        [0, 0, 0],
+        127,
+        [22, 61, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        127,
+        [9, 9, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 55, 90, 80, 110],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 85, 20, 30, 40],
+        [4, 4],
+        [0, 286, 0],
+        127,
+        [8, 8, 146],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 110, 45, 55, 65],
+        [4, 4],
+        [0, 286, 0],
+        127,
+        [8, 8, 146],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 47, 52, 40, 40, 41],
+        [3, 3],
+        [0, 0, 0],
+        254,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 62, 67, 55, 55, 56],
+        [3, 3],
+        [0, 0, 0],
+        254,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 92, 87, 75, 85, 76],
+        [3, 4],
+        [0, 0, 0],
+        254,
+        [38, 79, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [46, 57, 40, 40, 40, 50],
+        [3, 3],
+        [0, 0, 0],
+        0,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 72, 57, 55, 55, 65],
+        [3, 3],
+        [0, 0, 0],
+        0,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [81, 102, 77, 85, 75, 85],
+        [3, 4],
+        [0, 0, 0],
+        0,
+        [38, 79, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 45, 48, 60, 65, 35],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 70, 73, 95, 90, 60],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 41, 40, 50, 65, 65],
+        [9, 9],
+        [0, 249, 0],
+        191,
+        [18, 18, 70],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 76, 75, 81, 100, 100],
+        [9, 9],
+        [0, 249, 0],
+        191,
+        [18, 18, 70],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 45, 20, 45, 25, 20],
+        [0, 17],
+        [0, 81, 0],
+        191,
+        [56, 172, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [140, 70, 45, 85, 50, 45],
+        [0, 17],
+        [0, 81, 0],
+        191,
+        [56, 172, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 30, 40, 55],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 70, 65, 75, 90],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 55, 75, 65, 30],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 65, 70, 85, 75, 40],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 85, 110, 90, 50],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 27],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 70, 55, 45, 55, 25],
+        [6, 11],
+        [86, 87, 0],
+        127,
+        [27, 87, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 95, 80, 60, 80, 30],
+        [6, 11],
+        [86, 87, 0],
+        127,
+        [27, 87, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 50, 40, 55, 45],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [14, 110, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 65, 60, 90, 75, 90],
+        [6, 3],
+        [0, 295, 0],
+        127,
+        [19, 110, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [10, 55, 25, 35, 45, 95],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [8, 71, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 80, 50, 50, 70, 120],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [8, 71, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 40, 40, 90],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [53, 101, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 70, 60, 65, 65, 115],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [7, 101, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 52, 48, 65, 50, 55],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [6, 13, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 78, 95, 80, 85],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [6, 13, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 80, 35, 35, 45, 70],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [72, 83, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 105, 60, 60, 70, 95],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [72, 83, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 45, 70, 50, 60],
+        [9, 9],
+        [0, 0, 0],
+        63,
+        [22, 18, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 110, 80, 100, 80, 95],
+        [9, 9],
+        [0, 0, 0],
+        63,
+        [22, 18, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 40, 40, 40, 90],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 65, 50, 50, 90],
+        [10, 10],
+        [0, 221, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 95, 95, 70, 90, 70],
+        [10, 1],
+        [0, 221, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [25, 20, 15, 105, 55, 90],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 30, 120, 70, 105],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 50, 45, 135, 95, 120],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 50, 35, 35, 35],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 70, 50, 60, 45],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 130, 80, 65, 85, 55],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 35, 70, 30, 40],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 50, 85, 45, 55],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 65, 100, 70, 70],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 35, 50, 100, 70],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [29, 64, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 65, 80, 120, 100],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [29, 64, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 80, 100, 30, 30, 20],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 95, 115, 45, 45, 35],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 130, 55, 65, 45],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 55, 65, 65, 90],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [50, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 100, 70, 80, 80, 105],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [50, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 65, 65, 40, 40, 15],
+        [10, 13],
+        [0, 279, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 75, 110, 100, 80, 30],
+        [10, 13],
+        [0, 221, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [25, 35, 70, 95, 55, 45],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 95, 120, 70, 70],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [52, 65, 55, 58, 62, 60],
+        [0, 2],
+        [0, 259, 0],
+        127,
+        [51, 39, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 85, 45, 35, 35, 75],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [50, 48, 77],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 110, 70, 60, 60, 100],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [50, 48, 77],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 45, 55, 45, 70, 45],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [47, 93, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 70, 80, 70, 95, 70],
+        [10, 14],
+        [0, 0, 0],
+        127,
+        [47, 93, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 50, 40, 50, 25],
+        [3, 3],
+        [0, 281, 0],
+        127,
+        [1, 60, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 105, 75, 65, 100, 50],
+        [3, 3],
+        [281, 0, 0],
+        127,
+        [1, 60, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 65, 100, 45, 25, 40],
+        [10, 10],
+        [88, 89, 0],
+        127,
+        [75, 92, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 180, 85, 45, 70],
+        [10, 14],
+        [88, 89, 0],
+        127,
+        [75, 92, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 35, 30, 100, 35, 80],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 45, 115, 55, 95],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 65, 60, 130, 75, 110],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [130, 130, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 45, 160, 30, 45, 70],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [69, 5, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 48, 45, 43, 90, 42],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [15, 108, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 73, 70, 73, 115, 67],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [15, 108, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 105, 90, 25, 25, 50],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 130, 115, 50, 50, 75],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 50, 55, 55, 100],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [43, 9, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 70, 80, 80, 140],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [43, 9, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 80, 60, 45, 40],
+        [11, 13],
+        [0, 882, 0],
+        127,
+        [34, 34, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 85, 125, 65, 55],
+        [11, 13],
+        [0, 0, 0],
+        127,
+        [34, 34, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 95, 40, 50, 35],
+        [4, 4],
+        [0, 258, 0],
+        127,
+        [69, 31, 4],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 110, 50, 80, 45],
+        [4, 4],
+        [0, 258, 0],
+        127,
+        [69, 31, 4],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 120, 53, 35, 110, 87],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [7, 120, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 105, 79, 35, 110, 76],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [51, 89, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 55, 75, 60, 75, 30],
+        [0, 0],
+        [0, 279, 0],
+        127,
+        [20, 12, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 65, 95, 60, 45, 35],
+        [3, 3],
+        [0, 228, 0],
+        127,
+        [26, 256, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 120, 85, 70, 60],
+        [3, 3],
+        [0, 228, 0],
+        127,
+        [26, 256, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 85, 95, 30, 30, 25],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 69, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 130, 120, 45, 45, 40],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 69, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [250, 5, 5, 35, 105, 50],
+        [0, 0],
+        [0, 0, 0],
+        254,
+        [30, 32, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 55, 115, 100, 40, 60],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 102, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 95, 80, 40, 80, 90],
+        [0, 0],
+        [0, 0, 0],
+        254,
+        [48, 113, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 40, 70, 70, 25, 60],
+        [10, 10],
+        [0, 235, 0],
+        127,
+        [33, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 65, 95, 95, 45, 85],
+        [10, 10],
+        [0, 235, 0],
+        127,
+        [38, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 67, 60, 35, 50, 63],
+        [10, 10],
+        [0, 243, 0],
+        127,
+        [33, 41, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 92, 65, 65, 80, 68],
+        [10, 10],
+        [0, 243, 0],
+        127,
+        [33, 41, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 55, 70, 55, 85],
+        [10, 10],
+        [90, 91, 0],
+        255,
+        [35, 30, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 75, 85, 100, 85, 115],
+        [10, 13],
+        [90, 91, 0],
+        255,
+        [35, 30, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 65, 100, 120, 90],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [43, 111, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 80, 55, 80, 105],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 101, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 50, 35, 115, 95, 95],
+        [14, 13],
+        [0, 0, 0],
+        254,
+        [12, 108, 87],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 83, 57, 95, 85, 105],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [9, 9, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 57, 100, 85, 93],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 100, 55, 70, 85],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [52, 104, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 95, 40, 70, 110],
+        [0, 0],
+        [0, 0, 0],
+        0,
+        [22, 83, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 10, 55, 15, 20, 80],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 125, 79, 60, 100, 81],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 85, 80, 85, 95, 60],
+        [10, 14],
+        [243, 243, 0],
+        127,
+        [11, 75, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 48, 48, 48, 48, 48],
+        [0, 0],
+        [274, 257, 0],
+        255,
+        [7, 7, 150],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 50, 45, 65, 55],
+        [0, 0],
+        [0, 0, 0],
+        31,
+        [50, 91, 107],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 65, 60, 110, 95, 65],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [11, 11, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 60, 110, 95, 130],
+        [12, 12],
+        [0, 0, 0],
+        31,
+        [10, 10, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 130, 60, 95, 110, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [18, 18, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 60, 70, 85, 75, 40],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [36, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 40, 100, 90, 55, 35],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 125, 115, 70, 55],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 80, 90, 55, 45, 55],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 4, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 115, 105, 65, 70, 80],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 4, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 65, 60, 75, 130],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [69, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [160, 110, 65, 65, 110, 30],
+        [0, 0],
+        [234, 234, 0],
+        31,
+        [17, 47, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 85, 100, 95, 125, 85],
+        [14, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 81],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 90, 85, 125, 90, 100],
+        [12, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 9],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 90, 125, 85, 90],
+        [9, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 64, 45, 50, 50, 50],
+        [15, 15],
+        [0, 235, 0],
+        127,
+        [61, 61, 63],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 84, 65, 70, 70, 70],
+        [15, 15],
+        [0, 235, 0],
+        127,
+        [61, 61, 63],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 134, 95, 100, 100, 80],
+        [15, 2],
+        [0, 235, 0],
+        127,
+        [39, 39, 136],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 110, 90, 154, 90, 130],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 13],
+        [157, 157, 0],
+        255,
+        [28, 28, 28],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 49, 65, 49, 65, 45],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 80, 63, 80, 60],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 100, 83, 100, 80],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [39, 52, 43, 60, 50, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 64, 58, 80, 65, 80],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 84, 78, 109, 85, 100],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 64, 44, 48, 43],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 80, 59, 63, 58],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 100, 79, 83, 78],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 46, 34, 35, 45, 20],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 76, 64, 45, 55, 90],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 30, 30, 36, 56, 50],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [15, 51, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 50, 50, 76, 96, 70],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [15, 51, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 20, 30, 40, 80, 55],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 48, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 35, 50, 55, 110, 85],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 48, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 60, 40, 40, 40, 30],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [68, 15, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 70, 60, 60, 40],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [68, 15, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 90, 80, 70, 80, 130],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 38, 38, 56, 56, 67],
+        [10, 12],
+        [0, 0, 0],
+        127,
+        [10, 35, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 58, 58, 76, 76, 67],
+        [10, 12],
+        [0, 0, 0],
+        127,
+        [10, 35, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 40, 15, 35, 35, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 25, 28, 45, 55, 15],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 30, 15, 40, 20, 15],
+        [0, 17],
+        [0, 0, 0],
+        191,
+        [56, 172, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 20, 65, 40, 65, 20],
+        [17, 17],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 85, 80, 105, 40],
+        [17, 2],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 45, 70, 45, 70],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [28, 48, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 70, 95, 70, 95],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [28, 48, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 40, 65, 45, 35],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 55, 80, 60, 45],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 75, 85, 115, 90, 55],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 95, 90, 100, 50],
+        [11, 11],
+        [0, 545, 0],
+        127,
+        [34, 34, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 20, 50, 20, 50, 40],
+        [10, 17],
+        [0, 0, 0],
+        127,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 50, 80, 60, 80, 50],
+        [10, 17],
+        [0, 0, 0],
+        127,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 100, 115, 30, 65, 30],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [5, 69, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 75, 75, 90, 100, 70],
+        [10, 10],
+        [0, 221, 0],
+        127,
+        [11, 6, 2],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 35, 40, 35, 55, 50],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 45, 50, 45, 65, 80],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 55, 70, 55, 95, 110],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 55, 40, 55, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 53, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 30, 30, 30, 30, 30],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 94, 48],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 55, 105, 85, 30],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 94, 48],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 45, 75, 45, 95],
+        [6, 2],
+        [0, 265, 0],
+        127,
+        [3, 14, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 45, 45, 25, 25, 15],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [6, 11, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 85, 85, 65, 65, 35],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [6, 11, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 60, 130, 95, 110],
+        [13, 13],
+        [0, 0, 0],
+        31,
+        [28, 28, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 65, 110, 60, 130, 65],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [28, 28, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 42, 85, 42, 91],
+        [16, 2],
+        [0, 0, 0],
+        127,
+        [15, 105, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 75, 80, 100, 110, 30],
+        [10, 13],
+        [0, 221, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 85, 85, 85],
+        [7, 7],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 72, 48, 72, 48, 48],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [190, 33, 58, 33, 58, 33],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [23, 23, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 65, 90, 65, 85],
+        [0, 13],
+        [0, 0, 0],
+        127,
+        [39, 48, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 90, 35, 35, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [5, 5, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 90, 140, 60, 60, 40],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [5, 5, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 70, 70, 65, 65, 45],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [32, 50, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 105, 35, 65, 85],
+        [4, 2],
+        [0, 0, 0],
+        127,
+        [52, 8, 17],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 85, 200, 55, 65, 30],
+        [8, 4],
+        [0, 233, 0],
+        127,
+        [69, 5, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 50, 40, 40, 30],
+        [17, 17],
+        [0, 0, 0],
+        191,
+        [22, 50, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 75, 60, 60, 45],
+        [17, 17],
+        [0, 0, 0],
+        191,
+        [22, 95, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 75, 55, 55, 85],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [38, 33, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 130, 100, 55, 80, 65],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 101, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 10, 230, 10, 230, 5],
+        [6, 5],
+        [43, 43, 0],
+        127,
+        [5, 82, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 125, 75, 40, 95, 85],
+        [6, 1],
+        [0, 0, 0],
+        127,
+        [68, 62, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 95, 55, 35, 75, 115],
+        [16, 14],
+        [0, 217, 0],
+        127,
+        [39, 51, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 50, 50, 50, 40],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [53, 95, 118],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 130, 75, 75, 75, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [62, 95, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 40, 70, 40, 20],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [40, 49, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 120, 80, 80, 30],
+        [9, 5],
+        [0, 0, 0],
+        127,
+        [40, 49, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 40, 30, 30, 50],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 80, 60, 60, 50],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 85, 65, 85, 35],
+        [10, 5],
+        [0, 648, 0],
+        191,
+        [55, 30, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 65, 35, 65, 35, 65],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [55, 97, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 105, 75, 105, 75, 45],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [21, 97, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 55, 45, 65, 45, 75],
+        [14, 2],
+        [0, 0, 0],
+        127,
+        [72, 55, 15],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 40, 70, 80, 140, 70],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [33, 11, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 140, 40, 70, 70],
+        [8, 2],
+        [0, 233, 0],
+        127,
+        [51, 5, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 30, 80, 50, 65],
+        [16, 9],
+        [0, 0, 0],
+        127,
+        [48, 18, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 90, 50, 110, 80, 95],
+        [16, 9],
+        [0, 0, 0],
+        127,
+        [48, 18, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 95, 95, 95, 85],
+        [10, 15],
+        [0, 235, 0],
+        127,
+        [33, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 60, 60, 40, 40, 40],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [53, 53, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 120, 60, 60, 50],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [5, 5, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 80, 90, 105, 95, 60],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [36, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 95, 62, 85, 65, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 119, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 20, 35, 20, 45, 75],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [20, 101, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 35, 35, 35, 35, 35],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [62, 80, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 95, 35, 110, 70],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [22, 101, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 15, 85, 65, 65],
+        [14, 13],
+        [0, 0, 0],
+        254,
+        [12, 108, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 63, 37, 65, 55, 95],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [9, 9, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 75, 37, 70, 55, 83],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 80, 105, 40, 70, 100],
+        [0, 0],
+        [33, 33, 0],
+        254,
+        [47, 113, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [255, 10, 10, 75, 135, 55],
+        [0, 0],
+        [0, 231, 0],
+        254,
+        [30, 32, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 85, 75, 115, 100, 115],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 115, 85, 90, 75, 100],
+        [9, 9],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 75, 115, 90, 115, 85],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 64, 50, 45, 50, 41],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [62, 62, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 84, 70, 65, 70, 51],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 134, 110, 95, 100, 61],
+        [5, 16],
+        [0, 0, 0],
+        127,
+        [45, 45, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 90, 130, 90, 154, 110],
+        [13, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 136],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 130, 90, 110, 154, 90],
+        [9, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 11],
+        [157, 157, 0],
+        255,
+        [30, 30, 30],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 65, 55, 70],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 45, 85, 65, 95],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 65, 105, 85, 120],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 40, 70, 50, 45],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 60, 85, 60, 55],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 70, 110, 70, 80],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 50, 50, 50, 40],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 70, 60, 70, 50],
+        [10, 4],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 110, 90, 85, 90, 60],
+        [10, 4],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 35, 30, 30, 35],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [50, 95, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 70, 60, 60, 70],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [22, 95, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 30, 41, 30, 41, 60],
+        [0, 0],
+        [17, 28, 0],
+        127,
+        [53, 82, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 70, 61, 50, 61, 100],
+        [0, 0],
+        [17, 29, 0],
+        127,
+        [53, 82, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 45, 35, 20, 30, 20],
+        [6, 6],
+        [151, 213, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 35, 55, 25, 25, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 50, 100, 50, 65],
+        [6, 2],
+        [0, 295, 0],
+        127,
+        [68, 68, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 35, 55, 25, 25, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 70, 50, 90, 65],
+        [6, 3],
+        [0, 295, 0],
+        127,
+        [19, 19, 14],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 30, 40, 50, 30],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 50, 60, 70, 50],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 70, 90, 100, 70],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 50, 30, 30, 30],
+        [11, 11],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 40, 60, 40, 60],
+        [11, 16],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 60, 90, 60, 80],
+        [11, 16],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 30, 30, 30, 85],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [62, 62, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 60, 50, 50, 125],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [62, 62, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 30, 55, 30, 85],
+        [10, 2],
+        [571, 0, 0],
+        127,
+        [51, 93, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 100, 85, 70, 65],
+        [10, 2],
+        [571, 0, 0],
+        127,
+        [51, 2, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [28, 25, 25, 45, 35, 40],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 35, 35, 65, 55, 50],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 65, 65, 125, 115, 80],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 32, 50, 52, 65],
+        [6, 10],
+        [94, 0, 0],
+        127,
+        [33, 33, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 62, 80, 82, 60],
+        [6, 2],
+        [0, 222, 0],
+        127,
+        [22, 22, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 60, 40, 60, 35],
+        [11, 11],
+        [86, 87, 0],
+        127,
+        [27, 90, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 130, 80, 60, 60, 70],
+        [11, 1],
+        [86, 87, 0],
+        127,
+        [27, 90, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 35, 35, 30],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [54, 54, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 55, 55, 90],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [72, 72, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 160, 100, 95, 65, 100],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [54, 54, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [31, 45, 90, 30, 30, 40],
+        [6, 4],
+        [0, 237, 0],
+        127,
+        [14, 14, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 90, 45, 50, 50, 160],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [3, 3, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [1, 90, 45, 30, 30, 40],
+        [6, 7],
+        [0, 0, 0],
+        255,
+        [25, 25, 25],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 51, 23, 51, 23, 28],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [84, 71, 43, 71, 43, 48],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [104, 91, 63, 91, 73, 68],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 60, 30, 20, 30, 25],
+        [1, 1],
+        [0, 241, 0],
+        63,
+        [47, 62, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [144, 120, 60, 40, 60, 50],
+        [1, 1],
+        [0, 221, 0],
+        63,
+        [47, 62, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 20, 40, 20, 40, 20],
+        [0, 17],
+        [0, 0, 0],
+        191,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 135, 45, 90, 30],
+        [5, 5],
+        [0, 242, 0],
+        127,
+        [5, 42, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 45, 45, 35, 35, 50],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 96, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 65, 65, 55, 55, 70],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 96, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 75, 65, 65, 50],
+        [16, 7],
+        [0, 265, 0],
+        127,
+        [51, 100, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 85, 55, 55, 50],
+        [8, 17],
+        [0, 278, 0],
+        127,
+        [52, 22, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 100, 40, 40, 30],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 140, 50, 50, 40],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 180, 60, 60, 50],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 40, 55, 40, 55, 60],
+        [1, 13],
+        [0, 0, 0],
+        127,
+        [74, 74, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 75, 60, 75, 80],
+        [1, 13],
+        [0, 0, 0],
+        127,
+        [74, 74, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 65, 40, 65],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 31, 58],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 75, 60, 105, 60, 105],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 31, 58],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 40, 85, 75, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [57, 57, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 50, 75, 85, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [58, 58, 10],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 73, 55, 47, 75, 85],
+        [6, 6],
+        [0, 213, 0],
+        0,
+        [35, 68, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 47, 55, 73, 75, 85],
+        [6, 6],
+        [0, 213, 0],
+        254,
+        [12, 110, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 45, 100, 80, 65],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 43, 53, 43, 53, 40],
+        [3, 3],
+        [155, 158, 0],
+        127,
+        [64, 60, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 73, 83, 73, 83, 55],
+        [3, 3],
+        [155, 158, 0],
+        127,
+        [64, 60, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 90, 20, 65, 20, 65],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [24, 24, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 120, 40, 95, 40, 95],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [24, 24, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 70, 35, 70, 35, 60],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [41, 12, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [170, 90, 45, 90, 45, 60],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [41, 12, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 40, 65, 45, 35],
+        [9, 4],
+        [0, 0, 0],
+        127,
+        [12, 86, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 100, 70, 105, 75, 40],
+        [9, 4],
+        [0, 0, 0],
+        127,
+        [40, 116, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 140, 85, 70, 20],
+        [9, 9],
+        [0, 249, 0],
+        127,
+        [73, 70, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 25, 35, 70, 80, 60],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [47, 20, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 45, 65, 90, 110, 80],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [47, 20, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 60, 60, 60],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [20, 77, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 100, 45, 45, 45, 10],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [52, 71, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 50, 50, 50, 70],
+        [4, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 80, 80, 80, 100],
+        [4, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 40, 85, 40, 35],
+        [11, 11],
+        [0, 288, 0],
+        127,
+        [8, 8, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 115, 60, 115, 60, 55],
+        [11, 16],
+        [0, 288, 0],
+        127,
+        [8, 8, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 40, 60, 40, 75, 50],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [30, 30, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 90, 70, 105, 80],
+        [15, 2],
+        [0, 0, 0],
+        127,
+        [30, 30, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 115, 60, 60, 60, 90],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [17, 17, 137],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 100, 60, 100, 60, 65],
+        [3, 3],
+        [0, 295, 0],
+        127,
+        [61, 61, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 65, 95, 85, 70],
+        [5, 13],
+        [90, 81, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 95, 85, 55, 65, 70],
+        [5, 13],
+        [90, 80, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 48, 43, 46, 41, 60],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [12, 107, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 78, 73, 76, 71, 60],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [12, 107, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 80, 65, 50, 35, 35],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 120, 85, 90, 55, 55],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [52, 75, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 55, 40, 70, 55],
+        [4, 13],
+        [0, 269, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 105, 70, 120, 75],
+        [4, 13],
+        [0, 269, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 41, 77, 61, 87, 23],
+        [5, 11],
+        [0, 296, 0],
+        31,
+        [21, 21, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 81, 97, 81, 107, 43],
+        [5, 11],
+        [0, 296, 0],
+        31,
+        [21, 21, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 95, 50, 40, 50, 75],
+        [5, 6],
+        [0, 0, 0],
+        31,
+        [4, 4, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 100, 70, 80, 45],
+        [5, 6],
+        [0, 0, 0],
+        31,
+        [4, 4, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 15, 20, 10, 55, 80],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 12, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 60, 79, 100, 125, 81],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [63, 172, 56],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [0, 0],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        722,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 70, 60, 120, 40],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [16, 16, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 75, 35, 63, 33, 45],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [15, 119, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 115, 65, 83, 63, 65],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [15, 119, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 40, 90, 30, 90, 25],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [26, 26, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 70, 130, 60, 130, 25],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [46, 46, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [99, 68, 83, 72, 87, 51],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 94, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 50, 70, 95, 80, 65],
+        [13, 13],
+        [0, 224, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 130, 60, 75, 60, 75],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [46, 105, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 23, 48, 23, 48, 23],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [23, 23, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 50, 50, 50, 50],
+        [14, 14],
+        [0, 649, 0],
+        127,
+        [39, 115, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 80, 80, 80],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [39, 115, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 40, 50, 55, 50, 25],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 60, 70, 75, 70, 45],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 80, 90, 95, 90, 65],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 64, 85, 74, 55, 32],
+        [10, 10],
+        [88, 89, 0],
+        127,
+        [75, 75, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 104, 105, 94, 75, 52],
+        [10, 10],
+        [0, 226, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 84, 105, 114, 75, 52],
+        [10, 10],
+        [0, 227, 0],
+        127,
+        [33, 33, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 90, 130, 45, 65, 55],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [33, 69, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 30, 55, 40, 65, 97],
+        [10, 10],
+        [93, 0, 0],
+        191,
+        [33, 33, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 75, 60, 40, 30, 50],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [69, 69, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 100, 60, 50, 50],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [69, 69, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 135, 80, 110, 80, 100],
+        [15, 2],
+        [0, 250, 0],
+        127,
+        [22, 22, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 80, 35, 60, 30],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 75, 100, 55, 80, 50],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 135, 130, 95, 90, 70],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 200, 50, 100, 50],
+        [5, 5],
+        [0, 0, 0],
+        255,
+        [29, 29, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 50, 100, 100, 200, 50],
+        [14, 14],
+        [0, 0, 0],
+        255,
+        [29, 29, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 75, 150, 75, 150, 50],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 90, 110, 130, 110],
+        [15, 13],
+        [0, 0, 0],
+        254,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 90, 80, 130, 110, 110],
+        [15, 13],
+        [0, 0, 0],
+        0,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 90, 150, 140, 90],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [2, 2, 2],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 150, 140, 100, 90, 90],
+        [4, 4],
+        [0, 0, 0],
+        255,
+        [70, 70, 70],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 150, 90, 150, 90, 95],
+        [15, 2],
+        [0, 0, 0],
+        255,
+        [76, 76, 76],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [8, 13],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 150, 50, 150, 50, 150],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        725,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 68, 64, 45, 55, 31],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 89, 85, 55, 65, 36],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 109, 105, 75, 85, 56],
+        [11, 4],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 58, 44, 58, 44, 61],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 78, 52, 78, 52, 81],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 104, 71, 104, 71, 108],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [53, 51, 53, 61, 56, 40],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 66, 68, 81, 76, 50],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [84, 86, 88, 111, 101, 60],
+        [10, 8],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 30, 30, 30, 60],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 51, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 50, 40, 40, 80],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 120, 70, 50, 60, 100],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 45, 40, 35, 40, 31],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [86, 109, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 85, 60, 55, 60, 71],
+        [0, 10],
+        [0, 0, 0],
+        127,
+        [86, 109, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [37, 25, 41, 25, 41, 25],
+        [6, 6],
+        [0, 277, 0],
+        127,
+        [61, 61, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 85, 51, 55, 51, 65],
+        [6, 6],
+        [0, 277, 0],
+        127,
+        [68, 68, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 65, 34, 40, 34, 45],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 49, 60, 49, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 79, 95, 79, 70],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 35, 50, 70, 55],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 65, 125, 105, 90],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 125, 40, 30, 30, 58],
+        [5, 5],
+        [0, 0, 0],
+        31,
+        [104, 104, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 165, 60, 65, 50, 58],
+        [5, 5],
+        [0, 0, 0],
+        31,
+        [104, 104, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 42, 118, 42, 88, 30],
+        [5, 8],
+        [0, 0, 0],
+        31,
+        [5, 5, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 52, 168, 47, 138, 30],
+        [5, 8],
+        [0, 0, 0],
+        31,
+        [5, 5, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 29, 45, 29, 45, 36],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 59, 85, 79, 105, 36],
+        [6, 11],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        728,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 94, 50, 94, 50, 66],
+        [6, 2],
+        [0, 222, 0],
+        0,
+        [68, 68, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 30, 42, 30, 42, 70],
+        [6, 2],
+        [0, 94, 0],
+        31,
+        [118, 118, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 102, 80, 102, 40],
+        [6, 2],
+        [0, 245, 0],
+        254,
+        [46, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 70, 45, 90, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [50, 53, 10],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 65, 35, 60, 30, 85],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 55, 85, 50, 115],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 35, 45, 62, 53, 35],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [34, 34, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 70, 87, 78, 85],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [122, 122, 122],
+        2,
+        730,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 48, 48, 57, 62, 34],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        731,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [111, 83, 68, 92, 82, 39],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        732,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 66, 60, 66, 115],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [101, 53, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 50, 34, 60, 44, 70],
+        [7, 2],
+        [0, 0, 0],
+        127,
+        [106, 84, 138],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 80, 44, 90, 54, 80],
+        [7, 2],
+        [0, 0, 0],
+        127,
+        [106, 84, 138],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 66, 44, 44, 56, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 103, 7],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 76, 84, 54, 96, 105],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [56, 103, 7],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 105, 105, 105],
+        [7, 7],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 125, 52, 105, 52, 71],
+        [16, 2],
+        [0, 0, 0],
+        127,
+        [15, 105, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 55, 42, 42, 37, 85],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [7, 20, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 82, 64, 64, 59, 112],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [47, 20, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 50, 65, 50, 45],
+        [13, 13],
+        [0, 224, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 63, 47, 41, 41, 74],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [1, 106, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [103, 93, 67, 71, 61, 84],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [1, 106, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [57, 24, 86, 24, 86, 23],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [26, 85, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 89, 116, 79, 116, 33],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [26, 85, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 80, 95, 10, 45, 10],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [5, 69, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 25, 45, 70, 90, 60],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [43, 111, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 5, 5, 15, 65, 30],
+        [0, 0],
+        [110, 0, 0],
+        254,
+        [30, 32, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 65, 45, 92, 42, 91],
+        [0, 2],
+        [0, 277, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 92, 108, 92, 108, 35],
+        [7, 16],
+        [0, 0, 0],
+        127,
+        [46, 46, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 70, 45, 40, 45, 42],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 90, 65, 50, 55, 82],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 130, 95, 80, 85, 102],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [135, 85, 40, 40, 85, 5],
+        [0, 0],
+        [234, 234, 0],
+        31,
+        [53, 47, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 70, 40, 35, 40, 60],
+        [1, 1],
+        [0, 0, 0],
+        31,
+        [80, 39, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 70, 115, 70, 90],
+        [1, 8],
+        [0, 0, 0],
+        31,
+        [80, 39, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 72, 78, 38, 42, 32],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [45, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 112, 118, 68, 72, 47],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [45, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 90, 30, 55, 65],
+        [3, 6],
+        [0, 245, 0],
+        127,
+        [4, 97, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 110, 60, 75, 95],
+        [3, 16],
+        [0, 245, 0],
+        127,
+        [4, 97, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 61, 40, 61, 40, 50],
+        [3, 1],
+        [0, 281, 0],
+        127,
+        [107, 87, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [83, 106, 65, 86, 65, 85],
+        [3, 1],
+        [0, 281, 0],
+        127,
+        [107, 87, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 100, 72, 90, 72, 46],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 49, 56, 49, 61, 66],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 114, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [69, 69, 76, 69, 86, 91],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 114, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 20, 50, 60, 120, 50],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [33, 11, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 50, 62, 60, 40],
+        [11, 14],
+        [0, 246, 0],
+        127,
+        [117, 117, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 92, 75, 92, 85, 60],
+        [11, 14],
+        [0, 246, 0],
+        127,
+        [117, 117, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 120, 65, 45, 85, 125],
+        [16, 14],
+        [0, 217, 0],
+        127,
+        [46, 46, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 115, 130, 90, 60],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 85, 95, 80, 95, 50],
+        [0, 0],
+        [0, 279, 0],
+        127,
+        [20, 12, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 140, 130, 55, 55, 40],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 116, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 125, 110, 50, 50],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 102, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 123, 67, 95, 85, 95],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [78, 78, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 67, 125, 95, 83],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 50, 95, 120, 115, 80],
+        [17, 2],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 76, 86, 116, 56, 95],
+        [6, 2],
+        [0, 265, 0],
+        127,
+        [3, 110, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 110, 130, 60, 65, 95],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [102, 102, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 60, 110, 130, 95, 65],
+        [14, 14],
+        [0, 0, 0],
+        31,
+        [81, 81, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 125, 45, 75, 95],
+        [4, 2],
+        [0, 0, 0],
+        127,
+        [52, 8, 90],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 130, 80, 70, 60, 80],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 80, 70, 135, 75, 90],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [91, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 125, 65, 65, 115, 80],
+        [13, 1],
+        [0, 0, 0],
+        0,
+        [80, 80, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 145, 75, 150, 40],
+        [5, 8],
+        [0, 242, 0],
+        127,
+        [5, 42, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 100, 135, 65, 135, 45],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [46, 46, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 70, 80, 70, 110],
+        [14, 7],
+        [0, 0, 0],
+        254,
+        [81, 81, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 77, 95, 77, 91],
+        [12, 7],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 130, 75, 130, 95],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 105, 105, 105, 80],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 70, 125, 70, 115],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 120, 120, 150, 100, 90],
+        [8, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 100, 150, 120, 100],
+        [10, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 106, 130, 106, 77],
+        [9, 8],
+        [0, 0, 0],
+        127,
+        [18, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 160, 110, 80, 110, 100],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [112, 112, 112],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 100, 120, 100, 120, 90],
+        [7, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        2,
+        738,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 70, 120, 75, 130, 85],
+        [13, 13],
+        [0, 0, 0],
+        254,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 80, 80, 80],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [93, 93, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [93, 93, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 90, 135, 90, 125],
+        [16, 16],
+        [0, 0, 0],
+        255,
+        [123, 123, 123],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [11, 11],
+        [157, 157, 0],
+        255,
+        [30, 30, 30],
+        2,
+        739,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 9],
+        [0, 0, 0],
+        255,
+        [162, 162, 162],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 45, 55, 45, 55, 63],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 75, 60, 75, 83],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 95, 75, 95, 113],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 63, 45, 45, 45, 45],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 93, 55, 70, 55, 55],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 123, 65, 100, 65, 65],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 45, 63, 45, 45],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 60, 83, 60, 60],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 100, 85, 108, 70, 70],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 55, 39, 35, 39, 42],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 69, 60, 69, 77],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [35, 51, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 45, 25, 45, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [72, 53, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 65, 35, 65, 60],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 146, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 110, 90, 45, 90, 80],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 146, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 50, 37, 50, 37, 66],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [7, 84, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 88, 50, 88, 50, 106],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [7, 84, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [11, 11],
+        [155, 184, 0],
+        31,
+        [82, 82, 65],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [11, 11],
+        [155, 184, 0],
+        31,
+        [82, 82, 65],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [9, 9],
+        [155, 185, 0],
+        31,
+        [82, 82, 66],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [9, 9],
+        [155, 185, 0],
+        31,
+        [82, 82, 66],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [10, 10],
+        [155, 187, 0],
+        31,
+        [82, 82, 67],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [10, 10],
+        [155, 187, 0],
+        31,
+        [82, 82, 67],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 25, 45, 67, 55, 24],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [108, 28, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [116, 55, 85, 107, 95, 29],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [108, 28, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 55, 50, 36, 30, 43],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 77, 62, 50, 42, 65],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 115, 80, 65, 55, 93],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 32, 50, 32, 76],
+        [12, 12],
+        [149, 0, 0],
+        127,
+        [31, 78, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 63, 80, 63, 116],
+        [12, 12],
+        [149, 0, 0],
+        127,
+        [31, 78, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 85, 25, 25, 15],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 133, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 105, 105, 50, 40, 20],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 133, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 135, 130, 60, 80, 25],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 45, 43, 55, 43, 72],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [109, 103, 86],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 57, 55, 77, 55, 114],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [109, 103, 86],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 40, 30, 45, 68],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [146, 159, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 135, 60, 50, 65, 88],
+        [4, 8],
+        [0, 0, 0],
+        127,
+        [146, 159, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [103, 60, 86, 60, 86, 50],
+        [0, 0],
+        [155, 158, 0],
+        127,
+        [131, 144, 103],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 55, 25, 35, 35],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 85, 40, 50, 40],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 140, 95, 55, 65, 45],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 40, 50, 40, 64],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 93, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 65, 55, 65, 55, 69],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [33, 93, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 95, 75, 85, 75, 74],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [33, 143, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 100, 85, 30, 85, 45],
+        [1, 1],
+        [0, 241, 0],
+        0,
+        [62, 39, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 75, 30, 75, 85],
+        [1, 1],
+        [0, 241, 0],
+        0,
+        [5, 39, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 53, 70, 40, 60, 42],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [68, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 63, 90, 50, 80, 42],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [102, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 103, 80, 70, 80, 92],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [68, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 59, 30, 39, 57],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 99, 40, 79, 47],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 89, 55, 69, 112],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 27, 60, 37, 50, 66],
+        [11, 17],
+        [0, 545, 0],
+        127,
+        [158, 151, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 67, 85, 77, 75, 116],
+        [11, 17],
+        [0, 545, 0],
+        127,
+        [158, 151, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 35, 50, 70, 50, 30],
+        [11, 11],
+        [0, 545, 0],
+        254,
+        [34, 20, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 75, 110, 75, 90],
+        [11, 11],
+        [0, 545, 0],
+        254,
+        [34, 20, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 92, 65, 80, 55, 98],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [120, 91, 104],
+        2,
+        757,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 72, 35, 35, 35, 65],
+        [4, 16],
+        [0, 240, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 82, 45, 45, 45, 74],
+        [4, 16],
+        [0, 240, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 117, 80, 65, 70, 92],
+        [4, 16],
+        [240, 0, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 45, 15, 45, 50],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [55, 55, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 140, 55, 30, 55, 95],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [125, 125, 161],
+        2,
+        758,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 86, 67, 106, 67, 60],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [11, 34, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 85, 35, 35, 55],
+        [6, 5],
+        [0, 238, 0],
+        127,
+        [5, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 95, 125, 65, 75, 45],
+        [6, 5],
+        [0, 238, 0],
+        127,
+        [5, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 70, 35, 70, 48],
+        [16, 1],
+        [0, 295, 0],
+        127,
+        [61, 153, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 115, 45, 115, 58],
+        [16, 1],
+        [0, 295, 0],
+        127,
+        [61, 153, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 58, 80, 103, 80, 97],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [147, 98, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 30, 85, 55, 65, 30],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [152, 152, 152],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 50, 145, 95, 105, 30],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [152, 152, 152],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 78, 103, 53, 45, 22],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [116, 5, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 108, 133, 83, 65, 32],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [116, 5, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 112, 45, 74, 45, 70],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [129, 129, 129],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 140, 65, 112, 65, 110],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [129, 129, 129],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 62, 40, 62, 65],
+        [3, 3],
+        [0, 251, 0],
+        127,
+        [1, 60, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 95, 82, 60, 82, 75],
+        [3, 3],
+        [251, 281, 0],
+        127,
+        [1, 133, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 65, 40, 80, 40, 65],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [149, 149, 149],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 105, 60, 120, 60, 105],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [149, 149, 149],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 50, 40, 40, 40, 75],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 101, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 60, 65, 60, 115],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 101, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 50, 55, 65, 45],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 70, 75, 85, 55],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 95, 95, 110, 65],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 40, 105, 50, 20],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 40, 50, 125, 60, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 65, 75, 125, 85, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 44, 50, 44, 50, 55],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [51, 145, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 87, 63, 87, 63, 98],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [51, 145, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [36, 50, 50, 65, 60, 44],
+        [14, 14],
+        [0, 246, 0],
+        127,
+        [115, 81, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [51, 65, 65, 80, 75, 59],
+        [14, 14],
+        [0, 246, 0],
+        127,
+        [115, 81, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 95, 85, 110, 95, 79],
+        [14, 14],
+        [246, 0, 0],
+        127,
+        [115, 117, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 50, 40, 50, 75],
+        [0, 11],
+        [0, 0, 0],
+        127,
+        [34, 157, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 70, 60, 70, 95],
+        [0, 11],
+        [0, 0, 0],
+        127,
+        [34, 157, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 60, 75, 60, 103],
+        [12, 2],
+        [0, 0, 0],
+        127,
+        [9, 9, 78],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 45, 40, 45, 60],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [68, 61, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 135, 105, 60, 105, 20],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 75, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [69, 55, 45, 55, 55, 15],
+        [11, 3],
+        [86, 87, 0],
+        127,
+        [27, 27, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [114, 85, 70, 85, 80, 30],
+        [11, 3],
+        [86, 87, 0],
+        127,
+        [27, 27, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 50, 65, 85, 40],
+        [10, 7],
+        [0, 0, 0],
+        127,
+        [11, 130, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 60, 70, 85, 105, 60],
+        [10, 7],
+        [0, 0, 0],
+        127,
+        [11, 130, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [165, 75, 80, 40, 45, 65],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [131, 93, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 47, 50, 57, 50, 65],
+        [6, 12],
+        [0, 0, 0],
+        127,
+        [14, 127, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 77, 60, 97, 60, 108],
+        [6, 12],
+        [0, 0, 0],
+        127,
+        [14, 127, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 50, 91, 24, 86, 10],
+        [11, 8],
+        [0, 288, 0],
+        127,
+        [160, 160, 160],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 94, 131, 54, 116, 20],
+        [11, 8],
+        [0, 288, 0],
+        127,
+        [160, 160, 107],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 70, 45, 60, 30],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 95, 70, 85, 50],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 115, 70, 85, 90],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 45, 40, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 85, 70, 75, 70, 40],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 115, 80, 105, 80, 50],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 55, 85, 55, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [140, 28, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 75, 125, 95, 40],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [140, 28, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 30, 55, 65, 55, 20],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 60, 95, 60, 55],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 90, 145, 90, 80],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [46, 87, 60, 30, 40, 57],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 117, 70, 40, 50, 67],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 147, 90, 60, 70, 97],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 40, 60, 40, 40],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [81, 202, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 110, 80, 70, 80, 50],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [81, 202, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 50, 30, 95, 135, 105],
+        [14, 14],
+        [0, 246, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 40, 85, 40, 65, 25],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [93, 75, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 40, 100, 60, 145],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [93, 60, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [109, 66, 84, 81, 99, 32],
+        [4, 12],
+        [0, 237, 0],
+        127,
+        [9, 7, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 85, 50, 55, 50, 65],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [39, 144, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 60, 95, 60, 105],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [39, 144, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 120, 90, 60, 90, 48],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [24, 125, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 74, 50, 35, 50, 35],
+        [4, 7],
+        [0, 269, 0],
+        255,
+        [89, 103, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 124, 80, 55, 80, 55],
+        [4, 7],
+        [0, 269, 0],
+        255,
+        [89, 103, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 85, 70, 40, 40, 60],
+        [16, 8],
+        [0, 0, 0],
+        127,
+        [128, 39, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 100, 60, 70, 70],
+        [16, 8],
+        [0, 0, 0],
+        127,
+        [128, 39, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 110, 95, 40, 95, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [120, 157, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 83, 50, 37, 50, 60],
+        [0, 2],
+        [0, 0, 0],
+        0,
+        [51, 125, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 123, 75, 57, 75, 80],
+        [0, 2],
+        [0, 0, 0],
+        0,
+        [51, 125, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 75, 45, 65, 60],
+        [16, 2],
+        [0, 0, 0],
+        254,
+        [145, 142, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 65, 105, 55, 95, 80],
+        [16, 2],
+        [0, 0, 0],
+        254,
+        [145, 142, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 97, 66, 105, 66, 65],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [82, 18, 73],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 109, 112, 48, 48, 109],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 55, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [52, 65, 50, 45, 50, 38],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [55, 55, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 85, 70, 65, 70, 58],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [55, 55, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [92, 105, 90, 125, 90, 98],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 85, 55, 50, 55, 60],
+        [6, 9],
+        [0, 0, 0],
+        127,
+        [49, 49, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 60, 65, 135, 105, 100],
+        [6, 9],
+        [222, 222, 0],
+        127,
+        [49, 49, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 129, 90, 72, 108],
+        [8, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 129, 90, 72, 90, 108],
+        [5, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 72, 90, 129, 108],
+        [11, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 115, 70, 125, 80, 111],
+        [2, 2],
+        [0, 0, 0],
+        0,
+        [158, 158, 128],
+        2,
+        759,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 115, 70, 125, 80, 111],
+        [12, 2],
+        [0, 0, 0],
+        0,
+        [158, 158, 128],
+        2,
+        760,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 120, 100, 150, 120, 90],
+        [15, 9],
+        [0, 0, 0],
+        255,
+        [163, 163, 163],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 150, 120, 120, 100, 90],
+        [15, 12],
+        [0, 0, 0],
+        255,
+        [164, 164, 164],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 125, 90, 115, 80, 101],
+        [4, 2],
+        [0, 0, 0],
+        0,
+        [159, 159, 125],
+        2,
+        761,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 130, 90, 130, 90, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        3,
+        762,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 72, 90, 129, 90, 108],
+        [10, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        2,
+        764,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 77, 77, 128, 128, 90],
+        [0, 13],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        2,
+        765,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        766,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [56, 61, 65, 48, 45, 38],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 78, 95, 56, 58, 57],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [88, 107, 122, 74, 75, 64],
+        [11, 1],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 62, 60, 60],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 59, 58, 90, 70, 73],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 69, 72, 114, 100, 104],
+        [9, 13],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 56, 40, 62, 44, 71],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 63, 52, 83, 56, 97],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 95, 67, 103, 71, 122],
+        [10, 16],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 36, 38, 32, 36, 57],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [53, 167, 37],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 56, 77, 50, 77, 78],
+        [0, 4],
+        [0, 0, 0],
+        127,
+        [53, 167, 37],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 43, 40, 38, 62],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 145, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 73, 55, 56, 52, 84],
+        [9, 2],
+        [0, 0, 0],
+        127,
+        [49, 49, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 81, 71, 74, 69, 126],
+        [9, 2],
+        [0, 0, 0],
+        127,
+        [49, 49, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 35, 40, 27, 25, 35],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [19, 14, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 22, 60, 27, 30, 29],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 52, 50, 90, 50, 89],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [19, 14, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 50, 58, 73, 54, 72],
+        [9, 0],
+        [0, 0, 0],
+        225,
+        [79, 127, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 68, 72, 109, 66, 106],
+        [9, 0],
+        [0, 0, 0],
+        225,
+        [79, 127, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 38, 39, 61, 79, 42],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 45, 47, 75, 98, 52],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 65, 68, 112, 154, 75],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 65, 48, 62, 57, 52],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [157, 157, 179],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [123, 100, 62, 97, 81, 68],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [157, 157, 179],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 82, 62, 46, 48, 43],
+        [1, 1],
+        [0, 219, 0],
+        127,
+        [89, 104, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 124, 78, 69, 71, 58],
+        [1, 16],
+        [0, 219, 0],
+        127,
+        [89, 104, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 60, 65, 90, 102],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [169, 169, 169],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 48, 54, 63, 60, 68],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [51, 151, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 48, 76, 83, 81, 104],
+        [13, 13],
+        [0, 0, 0],
+        0,
+        [51, 151, 158],
+        2,
+        770,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 80, 100, 35, 37, 28],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [99, 99, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 110, 150, 45, 49, 35],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [99, 99, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 150, 50, 150, 60],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [176, 176, 176],
+        2,
+        771,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 52, 60, 63, 65, 23],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [131, 131, 165],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [101, 72, 72, 99, 89, 29],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [131, 131, 165],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 48, 66, 59, 57, 49],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [175, 175, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [82, 80, 86, 85, 75, 72],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [175, 175, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [53, 54, 53, 37, 46, 45],
+        [16, 13],
+        [0, 0, 0],
+        127,
+        [126, 21, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 92, 88, 68, 75, 73],
+        [16, 13],
+        [0, 0, 0],
+        127,
+        [126, 21, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [42, 52, 67, 39, 56, 50],
+        [5, 10],
+        [0, 0, 0],
+        127,
+        [181, 97, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 105, 115, 54, 86, 68],
+        [5, 10],
+        [0, 0, 0],
+        127,
+        [181, 97, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 60, 60, 60, 30],
+        [3, 10],
+        [0, 0, 0],
+        127,
+        [38, 143, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 90, 97, 123, 44],
+        [3, 15],
+        [0, 0, 0],
+        127,
+        [38, 143, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 62, 58, 63, 44],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [178, 178, 178],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 73, 88, 120, 89, 59],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [178, 178, 178],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 38, 33, 61, 43, 70],
+        [12, 0],
+        [0, 0, 0],
+        127,
+        [87, 8, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 55, 52, 109, 94, 109],
+        [12, 0],
+        [0, 0, 0],
+        127,
+        [87, 8, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 89, 77, 45, 45, 48],
+        [5, 15],
+        [0, 0, 0],
+        31,
+        [173, 173, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [82, 121, 119, 69, 59, 71],
+        [5, 15],
+        [0, 0, 0],
+        31,
+        [173, 173, 69],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 59, 50, 67, 63, 46],
+        [5, 14],
+        [0, 0, 0],
+        31,
+        [174, 174, 117],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [123, 77, 72, 99, 92, 58],
+        [5, 14],
+        [0, 0, 0],
+        31,
+        [174, 174, 117],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 65, 65, 110, 130, 60],
+        [17, 17],
+        [0, 0, 0],
+        31,
+        [56, 56, 182],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 92, 75, 74, 63, 118],
+        [1, 2],
+        [0, 221, 0],
+        127,
+        [7, 84, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 58, 57, 81, 67, 101],
+        [12, 17],
+        [0, 0, 0],
+        127,
+        [167, 53, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 150, 50, 150, 50],
+        [5, 17],
+        [0, 0, 0],
+        255,
+        [29, 29, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 35, 55, 75, 40],
+        [15, 15],
+        [0, 295, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 75, 53, 83, 113, 60],
+        [15, 15],
+        [0, 295, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 70, 110, 150, 80],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [57, 80, 91, 80, 87, 75],
+        [8, 17],
+        [0, 0, 0],
+        127,
+        [158, 158, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 70, 48, 50, 60, 38],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [30, 119, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 110, 76, 65, 82, 56],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [30, 119, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 66, 70, 44, 55, 51],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        772,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 122, 58, 75, 84],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        775,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 69, 85, 32, 35, 28],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [20, 115, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 117, 184, 44, 46, 28],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [20, 115, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 35, 45, 40, 55],
+        [2, 15],
+        [0, 0, 0],
+        127,
+        [119, 151, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 70, 80, 97, 80, 123],
+        [2, 15],
+        [0, 0, 0],
+        127,
+        [119, 151, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [187, 187, 187],
+        2,
+        778,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [16, 2],
+        [0, 0, 0],
+        255,
+        [186, 186, 186],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 100, 121, 81, 95, 95],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [188, 188, 188],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 100, 150, 100, 150, 50],
+        [5, 17],
+        [0, 0, 0],
+        255,
+        [29, 29, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 110, 60, 150, 130, 70],
+        [13, 7],
+        [0, 0, 0],
+        255,
+        [170, 170, 170],
+        2,
+        779,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 110, 120, 130, 90, 70],
+        [9, 10],
+        [0, 0, 0],
+        255,
+        [11, 11, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [9, 9],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        722,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [10, 10],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        722,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [14, 14],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        722,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 180, 20, 180, 20, 150],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        725,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 160, 70, 160, 90],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        725,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 90, 95, 90, 180],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        725,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 79, 105, 59, 85, 36],
+        [6, 4],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        728,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 69, 95, 69, 95, 36],
+        [6, 8],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        728,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 70, 87, 78, 85],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [122, 122, 122],
+        2,
+        730,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 48, 48, 57, 62, 34],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        731,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [111, 83, 68, 92, 82, 39],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        732,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 9],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 10],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 14],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 2],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 11],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        733,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 120, 100, 120, 100, 90],
+        [7, 15],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        2,
+        738,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 103, 75, 120, 75, 127],
+        [11, 2],
+        [157, 157, 0],
+        255,
+        [32, 32, 32],
+        2,
+        739,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [1, 1],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [2, 2],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [3, 3],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [4, 4],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [5, 5],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [6, 6],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [7, 7],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [9, 9],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [11, 11],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [14, 14],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [15, 15],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [16, 16],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        740,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 92, 65, 80, 55, 98],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [69, 91, 104],
+        2,
+        757,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 30, 105, 140, 105, 55],
+        [9, 13],
+        [0, 0, 0],
+        127,
+        [125, 125, 161],
+        2,
+        758,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 100, 80, 110, 90, 121],
+        [2, 2],
+        [0, 0, 0],
+        0,
+        [144, 144, 144],
+        2,
+        759,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 105, 70, 145, 80, 101],
+        [12, 2],
+        [0, 0, 0],
+        0,
+        [10, 10, 10],
+        2,
+        760,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 145, 90, 105, 80, 91],
+        [4, 2],
+        [0, 0, 0],
+        0,
+        [22, 22, 22],
+        2,
+        761,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 120, 90, 170, 100, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [163, 163, 163],
+        3,
+        762,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 170, 100, 120, 90, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [164, 164, 164],
+        3,
+        762,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 72, 90, 129, 90, 108],
+        [10, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        2,
+        764,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 128, 90, 77, 77, 128],
+        [0, 1],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        2,
+        765,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        766,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        766,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        766,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        766,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 48, 76, 83, 81, 104],
+        [13, 13],
+        [0, 0, 0],
+        254,
+        [51, 151, 172],
+        2,
+        770,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 150, 50, 150, 50, 60],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [176, 176, 176],
+        2,
+        771,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 66, 70, 44, 55, 56],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        772,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 66, 70, 44, 55, 46],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        772,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 66, 70, 44, 55, 41],
+        [7, 11],
+        [239, 239, 0],
+        127,
+        [53, 119, 15],
+        4,
+        772,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 85, 122, 58, 75, 99],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        775,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 122, 58, 75, 69],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        775,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 100, 122, 58, 75, 54],
+        [7, 11],
+        [239, 239, 0],
+        127,
+        [53, 119, 15],
+        4,
+        775,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [187, 187, 187],
+        2,
+        778,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 160, 60, 170, 130, 80],
+        [13, 16],
+        [0, 0, 0],
+        255,
+        [170, 170, 170],
+        2,
+        779,
+        0,
+        1,
+    ),
+];
+
+pub(crate) const PERSONAL_USUM: [PersonalInfo; 938] = [
+    PersonalInfo::new(
+        [0, 0, 0, 0, 0, 0],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [0, 0, 0],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 49, 49, 65, 65, 45],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 63, 80, 80, 60],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 83, 100, 100, 80],
+        [11, 3],
+        [0, 0, 0],
+        31,
+        [65, 65, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [39, 52, 43, 60, 50, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 64, 58, 80, 65, 80],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 84, 78, 109, 85, 100],
+        [9, 2],
+        [0, 0, 0],
+        31,
+        [66, 66, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 48, 65, 50, 64, 43],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 63, 80, 65, 80, 58],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 83, 100, 85, 105, 78],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 35, 20, 20, 45],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 20, 55, 25, 25, 30],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 50, 90, 80, 70],
+        [6, 2],
+        [0, 222, 0],
+        127,
+        [14, 14, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 30, 20, 20, 50],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 25, 50, 25, 25, 35],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 40, 45, 80, 75],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [68, 68, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 35, 35, 56],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 60, 55, 50, 50, 71],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [83, 80, 75, 70, 70, 101],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 56, 35, 25, 35, 72],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 62, 55],
+        2,
+        808,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 81, 60, 50, 70, 97],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 62, 55],
+        2,
+        809,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 60, 30, 31, 31, 70],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [51, 51, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 65, 61, 61, 100],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [51, 51, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 60, 44, 40, 54, 55],
+        [3, 3],
+        [0, 0, 0],
+        127,
+        [22, 61, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 95, 69, 65, 79, 80],
+        [3, 3],
+        [0, 0, 0],
+        127,
+        [22, 61, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        127,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 55, 90, 80, 110],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 31],
+        2,
+        817,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 85, 20, 30, 40],
+        [4, 4],
+        [0, 286, 0],
+        127,
+        [8, 8, 146],
+        2,
+        818,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 110, 45, 55, 65],
+        [4, 4],
+        [0, 286, 0],
+        127,
+        [8, 8, 146],
+        2,
+        819,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 47, 52, 40, 40, 41],
+        [3, 3],
+        [0, 0, 0],
+        254,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 62, 67, 55, 55, 56],
+        [3, 3],
+        [0, 0, 0],
+        254,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 92, 87, 75, 85, 76],
+        [3, 4],
+        [0, 0, 0],
+        254,
+        [38, 79, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [46, 57, 40, 40, 40, 50],
+        [3, 3],
+        [0, 0, 0],
+        0,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 72, 57, 55, 55, 65],
+        [3, 3],
+        [0, 0, 0],
+        0,
+        [38, 79, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [81, 102, 77, 85, 75, 85],
+        [3, 4],
+        [0, 0, 0],
+        0,
+        [38, 79, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 45, 48, 60, 65, 35],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 70, 73, 95, 90, 60],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 41, 40, 50, 65, 65],
+        [9, 9],
+        [0, 249, 0],
+        191,
+        [18, 18, 70],
+        2,
+        820,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 76, 75, 81, 100, 100],
+        [9, 9],
+        [0, 249, 0],
+        191,
+        [18, 18, 70],
+        2,
+        821,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 45, 20, 45, 25, 20],
+        [0, 17],
+        [0, 81, 0],
+        191,
+        [56, 172, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [140, 70, 45, 85, 50, 45],
+        [0, 17],
+        [0, 81, 0],
+        191,
+        [56, 172, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 30, 40, 55],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 70, 65, 75, 90],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 55, 75, 65, 30],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 65, 70, 85, 75, 40],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 85, 110, 90, 50],
+        [11, 3],
+        [0, 545, 0],
+        127,
+        [34, 34, 27],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 70, 55, 45, 55, 25],
+        [6, 11],
+        [86, 87, 0],
+        127,
+        [27, 87, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 95, 80, 60, 80, 30],
+        [6, 11],
+        [86, 87, 0],
+        127,
+        [27, 87, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 50, 40, 55, 45],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [14, 110, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 65, 60, 90, 75, 90],
+        [6, 3],
+        [0, 295, 0],
+        127,
+        [19, 110, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [10, 55, 25, 35, 45, 95],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [8, 71, 159],
+        2,
+        822,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 100, 50, 50, 70, 120],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [8, 71, 159],
+        2,
+        823,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 40, 40, 90],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [53, 101, 127],
+        2,
+        824,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 70, 60, 65, 65, 115],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [7, 101, 127],
+        2,
+        825,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 52, 48, 65, 50, 55],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [6, 13, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 78, 95, 80, 85],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [6, 13, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 80, 35, 35, 45, 70],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [72, 83, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 105, 60, 60, 70, 95],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [72, 83, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 45, 70, 50, 60],
+        [9, 9],
+        [0, 0, 0],
+        63,
+        [22, 18, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 110, 80, 100, 80, 95],
+        [9, 9],
+        [0, 0, 0],
+        63,
+        [22, 18, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 40, 40, 40, 90],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 65, 50, 50, 90],
+        [10, 10],
+        [0, 221, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 95, 95, 70, 90, 70],
+        [10, 1],
+        [0, 221, 0],
+        127,
+        [11, 6, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [25, 20, 15, 105, 55, 90],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 30, 120, 70, 105],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 50, 45, 135, 95, 120],
+        [13, 13],
+        [0, 248, 0],
+        63,
+        [28, 39, 98],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 50, 35, 35, 35],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 70, 50, 60, 45],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 130, 80, 65, 85, 55],
+        [1, 1],
+        [0, 230, 0],
+        63,
+        [62, 99, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 35, 70, 30, 40],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 50, 85, 45, 55],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 65, 100, 70, 70],
+        [11, 3],
+        [0, 0, 0],
+        127,
+        [34, 34, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 35, 50, 100, 70],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [29, 64, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 65, 80, 120, 100],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [29, 64, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 80, 100, 30, 30, 20],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        2,
+        826,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 95, 115, 45, 45, 35],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        2,
+        827,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 130, 55, 65, 45],
+        [5, 4],
+        [0, 229, 0],
+        127,
+        [69, 5, 8],
+        2,
+        828,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 55, 65, 65, 90],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [50, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 100, 70, 80, 80, 105],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [50, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 65, 65, 40, 40, 15],
+        [10, 13],
+        [0, 279, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 75, 110, 100, 80, 30],
+        [10, 13],
+        [0, 221, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [25, 35, 70, 95, 55, 45],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 95, 120, 70, 70],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [52, 90, 55, 58, 62, 60],
+        [0, 2],
+        [0, 259, 0],
+        127,
+        [51, 39, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 85, 45, 35, 35, 75],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [50, 48, 77],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 110, 70, 60, 60, 110],
+        [0, 2],
+        [0, 244, 0],
+        127,
+        [50, 48, 77],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 45, 55, 45, 70, 45],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [47, 93, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 70, 80, 70, 95, 70],
+        [10, 14],
+        [0, 0, 0],
+        127,
+        [47, 93, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 50, 40, 50, 25],
+        [3, 3],
+        [0, 281, 0],
+        127,
+        [1, 60, 143],
+        2,
+        829,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 105, 75, 65, 100, 50],
+        [3, 3],
+        [281, 0, 0],
+        127,
+        [1, 60, 143],
+        2,
+        830,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 65, 100, 45, 25, 40],
+        [10, 10],
+        [88, 89, 0],
+        127,
+        [75, 92, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 180, 85, 45, 70],
+        [10, 14],
+        [88, 89, 0],
+        127,
+        [75, 92, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 35, 30, 100, 35, 80],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 45, 115, 55, 95],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 65, 60, 130, 75, 110],
+        [7, 3],
+        [0, 0, 0],
+        127,
+        [130, 130, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 45, 160, 30, 45, 70],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [69, 5, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 48, 45, 43, 90, 42],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [15, 108, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 73, 70, 73, 115, 67],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [15, 108, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 105, 90, 25, 25, 50],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 130, 115, 50, 50, 75],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 50, 55, 55, 100],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [43, 9, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 70, 80, 80, 150],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [43, 9, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 80, 60, 45, 40],
+        [11, 13],
+        [0, 882, 0],
+        127,
+        [34, 34, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 85, 125, 75, 55],
+        [11, 13],
+        [0, 0, 0],
+        127,
+        [34, 34, 139],
+        2,
+        831,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 95, 40, 50, 35],
+        [4, 4],
+        [0, 258, 0],
+        127,
+        [69, 31, 4],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 110, 50, 80, 45],
+        [4, 4],
+        [0, 258, 0],
+        127,
+        [69, 31, 4],
+        2,
+        832,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 120, 53, 35, 110, 87],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [7, 120, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 105, 79, 35, 110, 76],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [51, 89, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 55, 75, 60, 75, 30],
+        [0, 0],
+        [0, 279, 0],
+        127,
+        [20, 12, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 65, 95, 60, 45, 35],
+        [3, 3],
+        [0, 228, 0],
+        127,
+        [26, 256, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 120, 85, 70, 60],
+        [3, 3],
+        [0, 228, 0],
+        127,
+        [26, 256, 1],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 85, 95, 30, 30, 25],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 69, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 130, 120, 45, 45, 40],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 69, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [250, 5, 5, 35, 105, 50],
+        [0, 0],
+        [0, 0, 0],
+        254,
+        [30, 32, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 55, 115, 100, 40, 60],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 102, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 95, 80, 40, 80, 90],
+        [0, 0],
+        [0, 0, 0],
+        254,
+        [48, 113, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 40, 70, 70, 25, 60],
+        [10, 10],
+        [0, 235, 0],
+        127,
+        [33, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 65, 95, 95, 45, 85],
+        [10, 10],
+        [0, 235, 0],
+        127,
+        [38, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 67, 60, 35, 50, 63],
+        [10, 10],
+        [0, 243, 0],
+        127,
+        [33, 41, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 92, 65, 65, 80, 68],
+        [10, 10],
+        [0, 243, 0],
+        127,
+        [33, 41, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 55, 70, 55, 85],
+        [10, 10],
+        [90, 91, 0],
+        255,
+        [35, 30, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 75, 85, 100, 85, 115],
+        [10, 13],
+        [90, 91, 0],
+        255,
+        [35, 30, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 65, 100, 120, 90],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [43, 111, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 80, 55, 80, 105],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 101, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 50, 35, 115, 95, 95],
+        [14, 13],
+        [0, 0, 0],
+        254,
+        [12, 108, 87],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 83, 57, 95, 85, 105],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [9, 9, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 57, 100, 85, 93],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 100, 55, 70, 85],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [52, 104, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 95, 40, 70, 110],
+        [0, 0],
+        [0, 0, 0],
+        0,
+        [22, 83, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 10, 55, 15, 20, 80],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 125, 79, 60, 100, 81],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 85, 80, 85, 95, 60],
+        [10, 14],
+        [243, 243, 0],
+        127,
+        [11, 75, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 48, 48, 48, 48, 48],
+        [0, 0],
+        [274, 257, 0],
+        255,
+        [7, 7, 150],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 50, 45, 65, 55],
+        [0, 0],
+        [0, 0, 0],
+        31,
+        [50, 91, 107],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 65, 60, 110, 95, 65],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [11, 11, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 60, 110, 95, 130],
+        [12, 12],
+        [0, 0, 0],
+        31,
+        [10, 10, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 130, 60, 95, 110, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [18, 18, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 60, 70, 85, 75, 40],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [36, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 40, 100, 90, 55, 35],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 125, 115, 70, 55],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 80, 90, 55, 45, 55],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 4, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 115, 105, 65, 70, 80],
+        [5, 10],
+        [0, 0, 0],
+        31,
+        [33, 4, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 65, 60, 75, 130],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [69, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [160, 110, 65, 65, 110, 30],
+        [0, 0],
+        [234, 234, 0],
+        31,
+        [17, 47, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 85, 100, 95, 125, 85],
+        [14, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 81],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 90, 85, 125, 90, 100],
+        [12, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 9],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 90, 125, 85, 90],
+        [9, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 64, 45, 50, 50, 50],
+        [15, 15],
+        [0, 235, 0],
+        127,
+        [61, 61, 63],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 84, 65, 70, 70, 70],
+        [15, 15],
+        [0, 235, 0],
+        127,
+        [61, 61, 63],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 134, 95, 100, 100, 80],
+        [15, 2],
+        [0, 235, 0],
+        127,
+        [39, 39, 136],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 110, 90, 154, 90, 130],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 13],
+        [157, 157, 0],
+        255,
+        [28, 28, 28],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 49, 65, 49, 65, 45],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 80, 63, 80, 60],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 82, 100, 83, 100, 80],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [39, 52, 43, 60, 50, 65],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 64, 58, 80, 65, 80],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 84, 78, 109, 85, 100],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 18],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 64, 44, 48, 43],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 80, 59, 63, 58],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 100, 79, 83, 78],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 46, 34, 35, 45, 20],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 76, 64, 45, 55, 90],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 30, 30, 36, 56, 50],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [15, 51, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 50, 50, 86, 96, 70],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [15, 51, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 20, 30, 40, 80, 55],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 48, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 35, 50, 55, 110, 85],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [68, 48, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 60, 40, 40, 40, 30],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [68, 15, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 70, 60, 70, 40],
+        [6, 3],
+        [0, 0, 0],
+        127,
+        [68, 15, 97],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 90, 80, 70, 80, 130],
+        [3, 2],
+        [0, 0, 0],
+        127,
+        [39, 39, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 38, 38, 56, 56, 67],
+        [10, 12],
+        [0, 0, 0],
+        127,
+        [10, 35, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 58, 58, 76, 76, 67],
+        [10, 12],
+        [0, 0, 0],
+        127,
+        [10, 35, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 40, 15, 35, 35, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 25, 28, 45, 55, 15],
+        [17, 17],
+        [0, 81, 0],
+        191,
+        [56, 98, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 30, 15, 40, 20, 15],
+        [0, 17],
+        [0, 0, 0],
+        191,
+        [56, 172, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 20, 65, 40, 65, 20],
+        [17, 17],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 85, 80, 105, 40],
+        [17, 2],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 45, 70, 45, 70],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [28, 48, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 70, 95, 70, 95],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [28, 48, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 40, 65, 45, 35],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 55, 80, 60, 45],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 75, 85, 115, 90, 55],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 9, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 95, 90, 100, 50],
+        [11, 11],
+        [0, 545, 0],
+        127,
+        [34, 34, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 20, 50, 20, 50, 40],
+        [10, 17],
+        [0, 0, 0],
+        127,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 50, 80, 60, 80, 50],
+        [10, 17],
+        [0, 0, 0],
+        127,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 100, 115, 30, 65, 30],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [5, 69, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 75, 75, 90, 100, 70],
+        [10, 10],
+        [0, 221, 0],
+        127,
+        [11, 6, 2],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 35, 40, 35, 55, 50],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 45, 50, 45, 65, 80],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 55, 70, 55, 95, 110],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 102, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 55, 40, 55, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 53, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 30, 30, 30, 30, 30],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 94, 48],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 55, 105, 85, 30],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 94, 48],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 45, 75, 45, 95],
+        [6, 2],
+        [0, 265, 0],
+        127,
+        [3, 14, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 45, 45, 25, 25, 15],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [6, 11, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 85, 85, 65, 65, 35],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [6, 11, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 65, 60, 130, 95, 110],
+        [13, 13],
+        [0, 0, 0],
+        31,
+        [28, 28, 156],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 65, 110, 60, 130, 65],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [28, 28, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 42, 85, 42, 91],
+        [16, 2],
+        [0, 0, 0],
+        127,
+        [15, 105, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 75, 80, 100, 110, 30],
+        [10, 13],
+        [0, 221, 0],
+        127,
+        [12, 20, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 85, 85, 85],
+        [7, 7],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 72, 48, 72, 48, 48],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [190, 33, 58, 33, 58, 33],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [23, 23, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 65, 90, 65, 85],
+        [0, 13],
+        [0, 0, 0],
+        127,
+        [39, 48, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 90, 35, 35, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [5, 5, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 90, 140, 60, 60, 40],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [5, 5, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 70, 70, 65, 65, 45],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [32, 50, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 105, 35, 65, 85],
+        [4, 2],
+        [0, 0, 0],
+        127,
+        [52, 8, 17],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 85, 200, 55, 65, 30],
+        [8, 4],
+        [0, 233, 0],
+        127,
+        [69, 5, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 50, 40, 40, 30],
+        [17, 17],
+        [0, 0, 0],
+        191,
+        [22, 50, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 75, 60, 60, 45],
+        [17, 17],
+        [0, 0, 0],
+        191,
+        [22, 95, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 85, 55, 55, 85],
+        [10, 3],
+        [0, 245, 0],
+        127,
+        [38, 33, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 130, 100, 55, 80, 65],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 101, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 10, 230, 10, 230, 5],
+        [6, 5],
+        [43, 43, 0],
+        127,
+        [5, 82, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 125, 75, 40, 95, 85],
+        [6, 1],
+        [0, 0, 0],
+        127,
+        [68, 62, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 95, 55, 35, 75, 115],
+        [16, 14],
+        [0, 217, 0],
+        127,
+        [39, 51, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 50, 50, 50, 40],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [53, 95, 118],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 130, 75, 75, 75, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [62, 95, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 40, 70, 40, 20],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [40, 49, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 120, 90, 80, 30],
+        [9, 5],
+        [0, 0, 0],
+        127,
+        [40, 49, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 40, 30, 30, 50],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 80, 60, 60, 50],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 55, 95, 65, 95, 35],
+        [10, 5],
+        [0, 648, 0],
+        191,
+        [55, 30, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 65, 35, 65, 35, 65],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [55, 97, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 105, 75, 105, 75, 45],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [21, 97, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 55, 45, 65, 45, 75],
+        [14, 2],
+        [0, 0, 0],
+        127,
+        [72, 55, 15],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 40, 70, 80, 140, 70],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [33, 11, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 140, 40, 70, 70],
+        [8, 2],
+        [0, 233, 0],
+        127,
+        [51, 5, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 30, 80, 50, 65],
+        [16, 9],
+        [0, 0, 0],
+        127,
+        [48, 18, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 90, 50, 110, 80, 95],
+        [16, 9],
+        [0, 0, 0],
+        127,
+        [48, 18, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 95, 95, 95, 85],
+        [10, 15],
+        [0, 235, 0],
+        127,
+        [33, 97, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 60, 60, 40, 40, 40],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [53, 53, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 120, 60, 60, 50],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [5, 5, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 80, 90, 105, 95, 60],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [36, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 95, 62, 85, 65, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 119, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 20, 35, 20, 45, 75],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [20, 101, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 35, 35, 35, 35, 35],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [62, 80, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 95, 35, 110, 70],
+        [1, 1],
+        [0, 0, 0],
+        0,
+        [22, 101, 80],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 15, 85, 65, 65],
+        [14, 13],
+        [0, 0, 0],
+        254,
+        [12, 108, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 63, 37, 65, 55, 95],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [9, 9, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 75, 37, 70, 55, 83],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 80, 105, 40, 70, 100],
+        [0, 0],
+        [33, 33, 0],
+        254,
+        [47, 113, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [255, 10, 10, 75, 135, 55],
+        [0, 0],
+        [0, 231, 0],
+        254,
+        [30, 32, 131],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 85, 75, 115, 100, 115],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 115, 85, 90, 75, 100],
+        [9, 9],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 75, 115, 90, 115, 85],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [46, 46, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 64, 50, 45, 50, 41],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [62, 62, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 84, 70, 65, 70, 51],
+        [5, 4],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 134, 110, 95, 100, 61],
+        [5, 16],
+        [0, 0, 0],
+        127,
+        [45, 45, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 90, 130, 90, 154, 110],
+        [13, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 136],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [106, 130, 90, 110, 154, 90],
+        [9, 2],
+        [0, 0, 0],
+        255,
+        [46, 46, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 11],
+        [157, 157, 0],
+        255,
+        [30, 30, 30],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 35, 65, 55, 70],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 45, 85, 65, 95],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 65, 105, 85, 120],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 40, 70, 50, 45],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 60, 85, 60, 55],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 70, 110, 70, 80],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 50, 50, 50, 40],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 70, 60, 70, 50],
+        [10, 4],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 110, 90, 85, 90, 60],
+        [10, 4],
+        [0, 0, 0],
+        31,
+        [67, 67, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 35, 30, 30, 35],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [50, 95, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 70, 60, 60, 70],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [22, 95, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 30, 41, 30, 41, 60],
+        [0, 0],
+        [17, 28, 0],
+        127,
+        [53, 82, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 70, 61, 50, 61, 100],
+        [0, 0],
+        [17, 29, 0],
+        127,
+        [53, 82, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 45, 35, 20, 30, 20],
+        [6, 6],
+        [151, 213, 0],
+        127,
+        [19, 19, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 35, 55, 25, 25, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 50, 100, 50, 65],
+        [6, 2],
+        [0, 295, 0],
+        127,
+        [68, 68, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 35, 55, 25, 25, 15],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 61],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 70, 50, 90, 65],
+        [6, 3],
+        [0, 295, 0],
+        127,
+        [19, 19, 14],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 30, 40, 50, 30],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 50, 60, 70, 50],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 70, 90, 100, 70],
+        [10, 11],
+        [0, 219, 0],
+        127,
+        [33, 44, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 50, 30, 30, 30],
+        [11, 11],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 40, 60, 40, 60],
+        [11, 16],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 60, 90, 60, 80],
+        [11, 16],
+        [0, 271, 0],
+        127,
+        [34, 48, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 30, 30, 30, 85],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [62, 62, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 60, 75, 50, 125],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [62, 62, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 30, 55, 30, 85],
+        [10, 2],
+        [571, 0, 0],
+        127,
+        [51, 93, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 100, 95, 70, 65],
+        [10, 2],
+        [571, 0, 0],
+        127,
+        [51, 2, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [28, 25, 25, 45, 35, 40],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 35, 35, 65, 55, 50],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 65, 65, 125, 115, 80],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [28, 36, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 32, 50, 52, 65],
+        [6, 10],
+        [94, 0, 0],
+        127,
+        [33, 33, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 62, 100, 82, 80],
+        [6, 2],
+        [0, 222, 0],
+        127,
+        [22, 22, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 60, 40, 60, 35],
+        [11, 11],
+        [86, 87, 0],
+        127,
+        [27, 90, 95],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 130, 80, 60, 60, 70],
+        [11, 1],
+        [86, 87, 0],
+        127,
+        [27, 90, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 35, 35, 30],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [54, 54, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 55, 55, 90],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [72, 72, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 160, 100, 95, 65, 100],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [54, 54, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [31, 45, 90, 30, 30, 40],
+        [6, 4],
+        [0, 237, 0],
+        127,
+        [14, 14, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 90, 45, 50, 50, 160],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [3, 3, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [1, 90, 45, 30, 30, 40],
+        [6, 7],
+        [0, 0, 0],
+        255,
+        [25, 25, 25],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 51, 23, 51, 23, 28],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [84, 71, 43, 71, 43, 48],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [104, 91, 63, 91, 73, 68],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [43, 43, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 60, 30, 20, 30, 25],
+        [1, 1],
+        [0, 241, 0],
+        63,
+        [47, 62, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [144, 120, 60, 40, 60, 50],
+        [1, 1],
+        [0, 221, 0],
+        63,
+        [47, 62, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 20, 40, 20, 40, 20],
+        [0, 17],
+        [0, 0, 0],
+        191,
+        [47, 37, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 135, 45, 90, 30],
+        [5, 5],
+        [0, 242, 0],
+        127,
+        [5, 42, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 45, 45, 35, 35, 50],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 96, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 65, 65, 55, 55, 90],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 96, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 75, 65, 65, 50],
+        [16, 7],
+        [0, 265, 0],
+        127,
+        [51, 100, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 85, 55, 55, 50],
+        [8, 17],
+        [0, 278, 0],
+        127,
+        [52, 22, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 100, 40, 40, 30],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 140, 50, 50, 40],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 180, 60, 60, 50],
+        [8, 5],
+        [0, 238, 0],
+        127,
+        [5, 69, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 40, 55, 40, 55, 60],
+        [1, 13],
+        [0, 0, 0],
+        127,
+        [74, 74, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 75, 60, 75, 80],
+        [1, 13],
+        [0, 0, 0],
+        127,
+        [74, 74, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 65, 40, 65],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 31, 58],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 75, 60, 105, 60, 105],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [9, 31, 58],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 40, 85, 75, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [57, 57, 31],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 50, 75, 85, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [58, 58, 10],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 73, 75, 47, 85, 85],
+        [6, 6],
+        [0, 213, 0],
+        0,
+        [35, 68, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 47, 75, 73, 85, 85],
+        [6, 6],
+        [0, 213, 0],
+        254,
+        [12, 110, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 45, 100, 80, 65],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 43, 53, 43, 53, 40],
+        [3, 3],
+        [155, 158, 0],
+        127,
+        [64, 60, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 73, 83, 73, 83, 55],
+        [3, 3],
+        [155, 158, 0],
+        127,
+        [64, 60, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 90, 20, 65, 20, 65],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [24, 24, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 120, 40, 95, 40, 95],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [24, 24, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [130, 70, 35, 70, 35, 60],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [41, 12, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [170, 90, 45, 90, 45, 60],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [41, 12, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 40, 65, 45, 35],
+        [9, 4],
+        [0, 0, 0],
+        127,
+        [12, 86, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 100, 70, 105, 75, 40],
+        [9, 4],
+        [0, 0, 0],
+        127,
+        [40, 116, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 140, 85, 70, 20],
+        [9, 9],
+        [0, 249, 0],
+        127,
+        [73, 70, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 25, 35, 70, 80, 60],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [47, 20, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 45, 65, 90, 110, 80],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [47, 20, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 60, 60, 60],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [20, 77, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 100, 45, 45, 45, 10],
+        [4, 4],
+        [0, 237, 0],
+        127,
+        [52, 71, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 50, 50, 50, 70],
+        [4, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 80, 80, 80, 100],
+        [4, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 85, 40, 85, 40, 35],
+        [11, 11],
+        [0, 288, 0],
+        127,
+        [8, 8, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 115, 60, 115, 60, 55],
+        [11, 16],
+        [0, 288, 0],
+        127,
+        [8, 8, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 40, 60, 40, 75, 50],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [30, 30, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 90, 70, 105, 80],
+        [15, 2],
+        [0, 0, 0],
+        127,
+        [30, 30, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 115, 60, 60, 60, 90],
+        [0, 0],
+        [0, 217, 0],
+        127,
+        [17, 17, 137],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 100, 60, 100, 60, 65],
+        [3, 3],
+        [0, 295, 0],
+        127,
+        [61, 61, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 55, 65, 95, 85, 70],
+        [5, 13],
+        [90, 81, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 95, 85, 55, 65, 70],
+        [5, 13],
+        [90, 80, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 48, 43, 46, 41, 60],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [12, 107, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 78, 73, 76, 71, 60],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [12, 107, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 80, 65, 50, 35, 35],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [52, 75, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 120, 85, 90, 55, 55],
+        [10, 16],
+        [0, 0, 0],
+        127,
+        [52, 75, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 40, 55, 40, 70, 55],
+        [4, 13],
+        [0, 269, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 105, 70, 120, 75],
+        [4, 13],
+        [0, 269, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 41, 77, 61, 87, 23],
+        [5, 11],
+        [0, 296, 0],
+        31,
+        [21, 21, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 81, 97, 81, 107, 43],
+        [5, 11],
+        [0, 296, 0],
+        31,
+        [21, 21, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 95, 50, 40, 50, 75],
+        [5, 6],
+        [0, 0, 0],
+        31,
+        [4, 4, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 100, 70, 80, 45],
+        [5, 6],
+        [0, 0, 0],
+        31,
+        [4, 4, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 15, 20, 10, 55, 80],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 12, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 60, 79, 100, 125, 81],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [63, 172, 56],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [0, 0],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        833,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 90, 70, 60, 120, 40],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [16, 16, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 75, 35, 63, 33, 45],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [15, 119, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 115, 65, 83, 63, 65],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [15, 119, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 40, 90, 30, 90, 25],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [26, 26, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 70, 130, 60, 130, 25],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [46, 46, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [99, 68, 83, 72, 87, 51],
+        [11, 2],
+        [0, 0, 0],
+        127,
+        [34, 94, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 50, 80, 95, 90, 65],
+        [13, 13],
+        [0, 224, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 130, 60, 75, 60, 75],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [46, 105, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 23, 48, 23, 48, 23],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [23, 23, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 50, 50, 50, 50],
+        [14, 14],
+        [0, 649, 0],
+        127,
+        [39, 115, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 80, 80, 80],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [39, 115, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 40, 50, 55, 50, 25],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 60, 70, 75, 70, 45],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 80, 90, 95, 90, 65],
+        [14, 10],
+        [0, 0, 0],
+        127,
+        [47, 115, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 64, 85, 74, 55, 32],
+        [10, 10],
+        [88, 89, 0],
+        127,
+        [75, 75, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 104, 105, 94, 75, 52],
+        [10, 10],
+        [0, 226, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 84, 105, 114, 75, 52],
+        [10, 10],
+        [0, 227, 0],
+        127,
+        [33, 33, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 90, 130, 45, 65, 55],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [33, 69, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 30, 55, 40, 65, 97],
+        [10, 10],
+        [93, 0, 0],
+        191,
+        [33, 33, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 75, 60, 40, 30, 50],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [69, 69, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 95, 100, 60, 50, 50],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [69, 69, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 135, 80, 110, 80, 100],
+        [15, 2],
+        [0, 250, 0],
+        127,
+        [22, 22, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 80, 35, 60, 30],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 75, 100, 55, 80, 50],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 135, 130, 95, 90, 70],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 200, 50, 100, 50],
+        [5, 5],
+        [0, 0, 0],
+        255,
+        [29, 29, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 50, 100, 100, 200, 50],
+        [14, 14],
+        [0, 0, 0],
+        255,
+        [29, 29, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 75, 150, 75, 150, 50],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [29, 29, 135],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 90, 110, 130, 110],
+        [15, 13],
+        [0, 0, 0],
+        254,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 90, 80, 130, 110, 110],
+        [15, 13],
+        [0, 0, 0],
+        0,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 90, 150, 140, 90],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [2, 2, 2],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 150, 140, 100, 90, 90],
+        [4, 4],
+        [0, 0, 0],
+        255,
+        [70, 70, 70],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 150, 90, 150, 90, 95],
+        [15, 2],
+        [0, 0, 0],
+        255,
+        [76, 76, 76],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [8, 13],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 150, 50, 150, 50, 150],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        836,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 68, 64, 45, 55, 31],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 89, 85, 55, 65, 36],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 109, 105, 75, 85, 56],
+        [11, 4],
+        [0, 0, 0],
+        31,
+        [65, 65, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 58, 44, 58, 44, 61],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 78, 52, 78, 52, 81],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 104, 71, 104, 71, 108],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [53, 51, 53, 61, 56, 40],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 66, 68, 81, 76, 50],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [84, 86, 88, 111, 101, 60],
+        [10, 8],
+        [0, 0, 0],
+        31,
+        [67, 67, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 30, 30, 30, 60],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 51, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 50, 40, 40, 80],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 120, 70, 50, 60, 100],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [22, 22, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 45, 40, 35, 40, 31],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [86, 109, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 85, 60, 55, 60, 71],
+        [0, 10],
+        [0, 0, 0],
+        127,
+        [86, 109, 141],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [37, 25, 41, 25, 41, 25],
+        [6, 6],
+        [0, 277, 0],
+        127,
+        [61, 61, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 85, 51, 55, 51, 65],
+        [6, 6],
+        [0, 277, 0],
+        127,
+        [68, 68, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 65, 34, 40, 34, 45],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 49, 60, 49, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 79, 95, 79, 70],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [79, 22, 62],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 35, 50, 70, 55],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 70, 65, 125, 105, 90],
+        [11, 3],
+        [0, 245, 0],
+        127,
+        [30, 38, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 125, 40, 30, 30, 58],
+        [5, 5],
+        [0, 0, 0],
+        31,
+        [104, 104, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 165, 60, 65, 50, 58],
+        [5, 5],
+        [0, 0, 0],
+        31,
+        [104, 104, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 42, 118, 42, 88, 30],
+        [5, 8],
+        [0, 0, 0],
+        31,
+        [5, 5, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 52, 168, 47, 138, 30],
+        [5, 8],
+        [0, 0, 0],
+        31,
+        [5, 5, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 29, 45, 29, 45, 36],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 59, 85, 79, 105, 36],
+        [6, 11],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        839,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 94, 50, 94, 50, 66],
+        [6, 2],
+        [0, 222, 0],
+        0,
+        [68, 68, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 30, 42, 30, 42, 70],
+        [6, 2],
+        [0, 94, 0],
+        31,
+        [118, 118, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 102, 80, 102, 40],
+        [6, 2],
+        [0, 245, 0],
+        254,
+        [46, 46, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 70, 45, 90, 95],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [50, 53, 10],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 65, 35, 60, 30, 85],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 55, 85, 50, 115],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 33, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 35, 45, 62, 53, 35],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [34, 34, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 70, 87, 78, 85],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [122, 122, 122],
+        2,
+        841,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 48, 48, 57, 62, 34],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        842,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [111, 83, 68, 92, 82, 39],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        843,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 66, 60, 66, 115],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [101, 53, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 50, 34, 60, 44, 70],
+        [7, 2],
+        [0, 0, 0],
+        127,
+        [106, 84, 138],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 80, 44, 90, 54, 80],
+        [7, 2],
+        [0, 0, 0],
+        127,
+        [106, 84, 138],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 66, 44, 44, 56, 85],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 103, 7],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 76, 84, 54, 96, 105],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [56, 103, 7],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 60, 105, 105, 105],
+        [7, 7],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 125, 52, 105, 52, 71],
+        [16, 2],
+        [0, 0, 0],
+        127,
+        [15, 105, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 55, 42, 42, 37, 85],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [7, 20, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 82, 64, 64, 59, 112],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [47, 20, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 50, 65, 50, 45],
+        [13, 13],
+        [0, 224, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [63, 63, 47, 41, 41, 74],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [1, 106, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [103, 93, 67, 71, 61, 84],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [1, 106, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [57, 24, 86, 24, 86, 23],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [26, 85, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 89, 116, 79, 116, 33],
+        [8, 13],
+        [0, 233, 0],
+        255,
+        [26, 85, 134],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 80, 95, 10, 45, 10],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [5, 69, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [20, 25, 45, 70, 90, 60],
+        [13, 17],
+        [0, 0, 0],
+        127,
+        [43, 111, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 5, 5, 15, 65, 30],
+        [0, 0],
+        [110, 0, 0],
+        254,
+        [30, 32, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 65, 45, 92, 42, 91],
+        [0, 2],
+        [0, 277, 0],
+        127,
+        [51, 77, 145],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 92, 108, 92, 108, 35],
+        [7, 16],
+        [0, 0, 0],
+        127,
+        [46, 46, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 70, 45, 40, 45, 42],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 90, 65, 50, 55, 82],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 130, 95, 80, 85, 102],
+        [15, 4],
+        [0, 0, 0],
+        127,
+        [8, 8, 24],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [135, 85, 40, 40, 85, 5],
+        [0, 0],
+        [234, 234, 0],
+        31,
+        [53, 47, 82],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 70, 40, 35, 40, 60],
+        [1, 1],
+        [0, 0, 0],
+        31,
+        [80, 39, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 110, 70, 115, 70, 90],
+        [1, 8],
+        [0, 0, 0],
+        31,
+        [80, 39, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 72, 78, 38, 42, 32],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [45, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 112, 118, 68, 72, 47],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [45, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 50, 90, 30, 55, 65],
+        [3, 6],
+        [0, 245, 0],
+        127,
+        [4, 97, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 110, 60, 75, 95],
+        [3, 16],
+        [0, 245, 0],
+        127,
+        [4, 97, 51],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 61, 40, 61, 40, 50],
+        [3, 1],
+        [0, 281, 0],
+        127,
+        [107, 87, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [83, 106, 65, 86, 65, 85],
+        [3, 1],
+        [0, 281, 0],
+        127,
+        [107, 87, 143],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 100, 72, 90, 72, 46],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 49, 56, 49, 61, 66],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 114, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [69, 69, 76, 69, 86, 91],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 114, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 20, 50, 60, 120, 50],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [33, 11, 41],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 62, 50, 62, 60, 40],
+        [11, 14],
+        [0, 246, 0],
+        127,
+        [117, 117, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 92, 75, 92, 85, 60],
+        [11, 14],
+        [0, 246, 0],
+        127,
+        [117, 117, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 120, 65, 45, 85, 125],
+        [16, 14],
+        [0, 217, 0],
+        127,
+        [46, 46, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 115, 130, 90, 60],
+        [12, 8],
+        [0, 233, 0],
+        255,
+        [42, 5, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 85, 95, 80, 95, 50],
+        [0, 0],
+        [0, 279, 0],
+        127,
+        [20, 12, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [115, 140, 130, 55, 55, 40],
+        [4, 5],
+        [0, 0, 0],
+        127,
+        [31, 116, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 125, 110, 50, 50],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [34, 102, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 123, 67, 95, 85, 95],
+        [12, 12],
+        [0, 322, 0],
+        63,
+        [78, 78, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 67, 125, 95, 83],
+        [9, 9],
+        [0, 323, 0],
+        63,
+        [49, 49, 72],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 50, 95, 120, 115, 80],
+        [17, 2],
+        [0, 0, 0],
+        31,
+        [55, 32, 105],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 76, 86, 116, 56, 95],
+        [6, 2],
+        [0, 265, 0],
+        127,
+        [3, 110, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 110, 130, 60, 65, 95],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [102, 102, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 60, 110, 130, 95, 65],
+        [14, 14],
+        [0, 0, 0],
+        31,
+        [81, 81, 115],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 125, 45, 75, 95],
+        [4, 2],
+        [0, 0, 0],
+        127,
+        [52, 8, 90],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 130, 80, 70, 60, 80],
+        [14, 4],
+        [0, 0, 0],
+        127,
+        [12, 81, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 80, 70, 135, 75, 90],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [91, 88, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 125, 65, 65, 115, 80],
+        [13, 1],
+        [0, 0, 0],
+        0,
+        [80, 80, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 145, 75, 150, 40],
+        [5, 8],
+        [0, 242, 0],
+        127,
+        [5, 42, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 100, 135, 65, 135, 45],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [46, 46, 119],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 80, 70, 80, 70, 110],
+        [14, 7],
+        [0, 0, 0],
+        254,
+        [81, 81, 130],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 77, 95, 77, 91],
+        [12, 7],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 130, 75, 130, 95],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 105, 105, 105, 105, 80],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 70, 125, 70, 115],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 120, 120, 150, 100, 90],
+        [8, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 120, 100, 150, 120, 100],
+        [10, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 106, 130, 106, 77],
+        [9, 8],
+        [0, 0, 0],
+        127,
+        [18, 18, 49],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 160, 110, 80, 110, 100],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [112, 112, 112],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 100, 120, 100, 120, 90],
+        [7, 15],
+        [0, 0, 0],
+        255,
+        [46, 46, 140],
+        2,
+        849,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 70, 120, 75, 130, 85],
+        [13, 13],
+        [0, 0, 0],
+        254,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 80, 80, 80, 80],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [93, 93, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [93, 93, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 90, 135, 90, 125],
+        [16, 16],
+        [0, 0, 0],
+        255,
+        [123, 123, 123],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [11, 11],
+        [157, 157, 0],
+        255,
+        [30, 30, 30],
+        2,
+        850,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 100, 100, 100, 100, 100],
+        [13, 9],
+        [0, 0, 0],
+        255,
+        [162, 162, 162],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 45, 55, 45, 55, 63],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 75, 60, 75, 83],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 95, 75, 95, 113],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 63, 45, 45, 45, 45],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 93, 55, 70, 55, 55],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 47],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 123, 65, 100, 65, 65],
+        [9, 1],
+        [0, 0, 0],
+        31,
+        [66, 66, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 45, 63, 45, 45],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 60, 83, 60, 60],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 100, 85, 108, 70, 70],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 55, 39, 35, 39, 42],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [50, 51, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 69, 60, 69, 77],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [35, 51, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 45, 25, 45, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [72, 53, 50],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 80, 65, 35, 65, 60],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 146, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 110, 90, 45, 90, 80],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [22, 146, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 50, 37, 50, 37, 66],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [7, 84, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [64, 88, 50, 88, 50, 106],
+        [16, 16],
+        [0, 0, 0],
+        127,
+        [7, 84, 158],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [11, 11],
+        [155, 184, 0],
+        31,
+        [82, 82, 65],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [11, 11],
+        [155, 184, 0],
+        31,
+        [82, 82, 65],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [9, 9],
+        [155, 185, 0],
+        31,
+        [82, 82, 66],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [9, 9],
+        [155, 185, 0],
+        31,
+        [82, 82, 66],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 48, 53, 48, 64],
+        [10, 10],
+        [155, 187, 0],
+        31,
+        [82, 82, 67],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 98, 63, 98, 63, 101],
+        [10, 10],
+        [155, 187, 0],
+        31,
+        [82, 82, 67],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 25, 45, 67, 55, 24],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [108, 28, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [116, 55, 85, 107, 95, 29],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [108, 28, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 55, 50, 36, 30, 43],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 77, 62, 50, 42, 65],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 115, 80, 65, 55, 93],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 105, 79],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 60, 32, 50, 32, 76],
+        [12, 12],
+        [149, 0, 0],
+        127,
+        [31, 78, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 63, 80, 63, 116],
+        [12, 12],
+        [149, 0, 0],
+        127,
+        [31, 78, 157],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 85, 25, 25, 15],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 133, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 105, 105, 50, 40, 20],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 133, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 135, 130, 60, 80, 25],
+        [5, 5],
+        [229, 238, 0],
+        127,
+        [5, 45, 159],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 45, 43, 55, 43, 72],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [109, 103, 86],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 57, 55, 77, 55, 114],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [109, 103, 86],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 40, 30, 45, 68],
+        [4, 4],
+        [0, 0, 0],
+        127,
+        [146, 159, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 135, 60, 50, 65, 88],
+        [4, 8],
+        [0, 0, 0],
+        127,
+        [146, 159, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [103, 60, 86, 60, 86, 50],
+        [0, 0],
+        [155, 158, 0],
+        127,
+        [131, 144, 103],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 55, 25, 35, 35],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 105, 85, 40, 50, 40],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 140, 95, 55, 65, 45],
+        [1, 1],
+        [0, 0, 0],
+        63,
+        [62, 125, 89],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 40, 50, 40, 64],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [33, 93, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 65, 55, 65, 55, 69],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [33, 93, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 95, 75, 85, 75, 74],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [33, 143, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 100, 85, 30, 85, 45],
+        [1, 1],
+        [0, 241, 0],
+        0,
+        [62, 39, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 75, 30, 75, 85],
+        [1, 1],
+        [0, 241, 0],
+        0,
+        [5, 39, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 53, 70, 40, 60, 42],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [68, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 63, 90, 50, 80, 42],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [102, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 103, 80, 70, 80, 92],
+        [6, 11],
+        [0, 219, 0],
+        127,
+        [68, 34, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 45, 59, 30, 39, 57],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 99, 40, 79, 47],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 89, 55, 69, 112],
+        [6, 3],
+        [0, 245, 0],
+        127,
+        [38, 68, 3],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 27, 60, 37, 50, 66],
+        [11, 17],
+        [0, 545, 0],
+        127,
+        [158, 151, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 67, 85, 77, 75, 116],
+        [11, 17],
+        [0, 545, 0],
+        127,
+        [158, 151, 34],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 35, 50, 70, 50, 30],
+        [11, 11],
+        [0, 545, 0],
+        254,
+        [34, 20, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 75, 110, 75, 90],
+        [11, 11],
+        [0, 545, 0],
+        254,
+        [34, 20, 102],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 92, 65, 80, 55, 98],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [120, 91, 104],
+        2,
+        868,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 72, 35, 35, 35, 65],
+        [4, 16],
+        [0, 240, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 82, 45, 45, 45, 74],
+        [4, 16],
+        [0, 240, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 117, 80, 65, 70, 92],
+        [4, 16],
+        [240, 0, 0],
+        127,
+        [22, 153, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 90, 45, 15, 45, 50],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [55, 55, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 140, 55, 30, 55, 95],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [125, 125, 161],
+        2,
+        869,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 86, 67, 106, 67, 60],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [11, 34, 114],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 85, 35, 35, 55],
+        [6, 5],
+        [0, 238, 0],
+        127,
+        [5, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 105, 125, 65, 75, 45],
+        [6, 5],
+        [0, 238, 0],
+        127,
+        [5, 75, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 70, 35, 70, 48],
+        [16, 1],
+        [0, 295, 0],
+        127,
+        [61, 153, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 115, 45, 115, 58],
+        [16, 1],
+        [0, 295, 0],
+        127,
+        [61, 153, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 58, 80, 103, 80, 97],
+        [13, 2],
+        [0, 0, 0],
+        127,
+        [147, 98, 110],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 30, 85, 55, 65, 30],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [152, 152, 152],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 50, 145, 95, 105, 30],
+        [7, 7],
+        [0, 247, 0],
+        127,
+        [152, 152, 152],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 78, 103, 53, 45, 22],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [116, 5, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 108, 133, 83, 65, 32],
+        [10, 5],
+        [0, 0, 0],
+        31,
+        [116, 5, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 112, 45, 74, 45, 70],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [129, 129, 129],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 140, 65, 112, 65, 110],
+        [5, 2],
+        [0, 0, 0],
+        31,
+        [129, 129, 129],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 62, 40, 62, 65],
+        [3, 3],
+        [0, 251, 0],
+        127,
+        [1, 60, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 95, 82, 60, 82, 75],
+        [3, 3],
+        [251, 281, 0],
+        127,
+        [1, 133, 106],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 65, 40, 80, 40, 65],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [149, 149, 149],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 105, 60, 120, 60, 105],
+        [16, 16],
+        [0, 0, 0],
+        31,
+        [149, 149, 149],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 50, 40, 40, 40, 75],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 101, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 60, 65, 60, 115],
+        [0, 0],
+        [0, 0, 0],
+        191,
+        [56, 101, 92],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 50, 55, 65, 45],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 70, 75, 85, 55],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 95, 95, 110, 65],
+        [13, 13],
+        [0, 0, 0],
+        191,
+        [119, 172, 23],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 30, 40, 105, 50, 20],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 40, 50, 125, 60, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 65, 75, 125, 85, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [142, 98, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 44, 50, 44, 50, 55],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [51, 145, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 87, 63, 87, 63, 98],
+        [10, 2],
+        [0, 0, 0],
+        127,
+        [51, 145, 93],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [36, 50, 50, 65, 60, 44],
+        [14, 14],
+        [0, 246, 0],
+        127,
+        [115, 81, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [51, 65, 65, 80, 75, 59],
+        [14, 14],
+        [0, 246, 0],
+        127,
+        [115, 81, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 95, 85, 110, 95, 79],
+        [14, 14],
+        [246, 0, 0],
+        127,
+        [115, 117, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 50, 40, 50, 75],
+        [0, 11],
+        [0, 0, 0],
+        127,
+        [34, 157, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 100, 70, 60, 70, 95],
+        [0, 11],
+        [0, 0, 0],
+        127,
+        [34, 157, 32],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 60, 75, 60, 103],
+        [12, 2],
+        [0, 0, 0],
+        127,
+        [9, 9, 78],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 45, 40, 45, 60],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [68, 61, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 135, 105, 60, 105, 20],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 75, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [69, 55, 45, 55, 55, 15],
+        [11, 3],
+        [86, 87, 0],
+        127,
+        [27, 27, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [114, 85, 70, 85, 80, 30],
+        [11, 3],
+        [86, 87, 0],
+        127,
+        [27, 27, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 40, 50, 65, 85, 40],
+        [10, 7],
+        [0, 0, 0],
+        127,
+        [11, 130, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 60, 70, 85, 105, 60],
+        [10, 7],
+        [0, 0, 0],
+        127,
+        [11, 130, 6],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [165, 75, 80, 40, 45, 65],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [131, 93, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 47, 50, 57, 50, 65],
+        [6, 12],
+        [0, 0, 0],
+        127,
+        [14, 127, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 77, 60, 97, 60, 108],
+        [6, 12],
+        [0, 0, 0],
+        127,
+        [14, 127, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 50, 91, 24, 86, 10],
+        [11, 8],
+        [0, 288, 0],
+        127,
+        [160, 160, 160],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 94, 131, 54, 116, 20],
+        [11, 8],
+        [0, 288, 0],
+        127,
+        [160, 160, 107],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 70, 45, 60, 30],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 95, 70, 85, 50],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 115, 70, 85, 90],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [57, 58, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 45, 40, 60],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 85, 70, 75, 70, 40],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 115, 80, 105, 80, 50],
+        [12, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 55, 85, 55, 30],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [140, 28, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 75, 75, 125, 95, 40],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [140, 28, 148],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 30, 55, 65, 55, 20],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 40, 60, 95, 60, 55],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 90, 145, 90, 80],
+        [7, 9],
+        [0, 0, 0],
+        127,
+        [18, 49, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [46, 87, 60, 30, 40, 57],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 117, 70, 40, 50, 67],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 147, 90, 60, 70, 97],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [79, 104, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 70, 40, 60, 40, 40],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [81, 202, 155],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 130, 80, 70, 80, 50],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [81, 202, 33],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 50, 50, 95, 135, 105],
+        [14, 14],
+        [0, 246, 0],
+        255,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 40, 85, 40, 65, 25],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [93, 75, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 70, 40, 100, 60, 145],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [93, 60, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [109, 66, 84, 81, 99, 32],
+        [4, 12],
+        [0, 237, 0],
+        127,
+        [9, 7, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 85, 50, 55, 50, 65],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [39, 144, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 60, 95, 60, 105],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [39, 144, 120],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 120, 90, 60, 90, 48],
+        [15, 15],
+        [0, 250, 0],
+        127,
+        [24, 125, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 74, 50, 35, 50, 35],
+        [4, 7],
+        [0, 269, 0],
+        255,
+        [89, 103, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 124, 80, 55, 80, 55],
+        [4, 7],
+        [0, 269, 0],
+        255,
+        [89, 103, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 85, 70, 40, 40, 60],
+        [16, 8],
+        [0, 0, 0],
+        127,
+        [128, 39, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 125, 100, 60, 70, 70],
+        [16, 8],
+        [0, 0, 0],
+        127,
+        [128, 39, 46],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 110, 95, 40, 95, 55],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [120, 157, 43],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 83, 50, 37, 50, 60],
+        [0, 2],
+        [0, 0, 0],
+        0,
+        [51, 125, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 123, 75, 57, 75, 80],
+        [0, 2],
+        [0, 0, 0],
+        0,
+        [51, 125, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 55, 75, 45, 65, 60],
+        [16, 2],
+        [0, 0, 0],
+        254,
+        [145, 142, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [110, 65, 105, 55, 95, 80],
+        [16, 2],
+        [0, 0, 0],
+        254,
+        [145, 142, 133],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 97, 66, 105, 66, 65],
+        [9, 9],
+        [0, 0, 0],
+        127,
+        [82, 18, 73],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 109, 112, 48, 48, 109],
+        [6, 8],
+        [0, 0, 0],
+        127,
+        [68, 55, 54],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [52, 65, 50, 45, 50, 38],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [55, 55, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 85, 70, 65, 70, 58],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [55, 55, 55],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [92, 105, 90, 125, 90, 98],
+        [16, 15],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 85, 55, 50, 55, 60],
+        [6, 9],
+        [0, 0, 0],
+        127,
+        [49, 49, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 60, 65, 135, 105, 100],
+        [6, 9],
+        [222, 222, 0],
+        127,
+        [49, 49, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 129, 90, 72, 108],
+        [8, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 129, 90, 72, 90, 108],
+        [5, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 90, 72, 90, 129, 108],
+        [11, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 115, 70, 125, 80, 111],
+        [2, 2],
+        [0, 0, 0],
+        0,
+        [158, 158, 128],
+        2,
+        870,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 115, 70, 125, 80, 111],
+        [12, 2],
+        [0, 0, 0],
+        0,
+        [158, 158, 128],
+        2,
+        871,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 120, 100, 150, 120, 90],
+        [15, 9],
+        [0, 0, 0],
+        255,
+        [163, 163, 163],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 150, 120, 120, 100, 90],
+        [15, 12],
+        [0, 0, 0],
+        255,
+        [164, 164, 164],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 125, 90, 115, 80, 101],
+        [4, 2],
+        [0, 0, 0],
+        0,
+        [159, 159, 125],
+        2,
+        872,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 130, 90, 130, 90, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        3,
+        873,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 72, 90, 129, 90, 108],
+        [10, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        2,
+        875,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 77, 77, 128, 128, 90],
+        [0, 13],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        2,
+        876,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        877,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [56, 61, 65, 48, 45, 38],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 78, 95, 56, 58, 57],
+        [11, 11],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [88, 107, 122, 74, 75, 64],
+        [11, 1],
+        [0, 0, 0],
+        31,
+        [65, 65, 171],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 62, 60, 60],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 59, 58, 90, 70, 73],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 69, 72, 114, 100, 104],
+        [9, 13],
+        [0, 0, 0],
+        31,
+        [66, 66, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [41, 56, 40, 62, 44, 71],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 63, 52, 83, 56, 97],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 95, 67, 103, 71, 122],
+        [10, 16],
+        [0, 0, 0],
+        31,
+        [67, 67, 168],
+        3,
+        881,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 36, 38, 32, 36, 57],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [53, 167, 37],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 56, 77, 50, 77, 78],
+        [0, 4],
+        [0, 0, 0],
+        127,
+        [53, 167, 37],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 43, 40, 38, 62],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [145, 145, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 73, 55, 56, 52, 84],
+        [9, 2],
+        [0, 0, 0],
+        127,
+        [49, 49, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 81, 71, 74, 69, 126],
+        [9, 2],
+        [0, 0, 0],
+        127,
+        [49, 49, 177],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 35, 40, 27, 25, 35],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [19, 14, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 22, 60, 27, 30, 29],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [61, 61, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 52, 50, 90, 50, 89],
+        [6, 2],
+        [0, 0, 0],
+        127,
+        [19, 14, 132],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 50, 58, 73, 54, 72],
+        [9, 0],
+        [0, 0, 0],
+        225,
+        [79, 127, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 68, 72, 109, 66, 106],
+        [9, 0],
+        [0, 0, 0],
+        225,
+        [79, 127, 153],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 38, 39, 61, 79, 42],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 45, 47, 75, 98, 52],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 65, 68, 112, 154, 75],
+        [17, 17],
+        [0, 0, 0],
+        254,
+        [166, 166, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [66, 65, 48, 62, 57, 52],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [157, 157, 179],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [123, 100, 62, 97, 81, 68],
+        [11, 11],
+        [0, 0, 0],
+        127,
+        [157, 157, 179],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 82, 62, 46, 48, 43],
+        [1, 1],
+        [0, 219, 0],
+        127,
+        [89, 104, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 124, 78, 69, 71, 58],
+        [1, 16],
+        [0, 219, 0],
+        127,
+        [89, 104, 113],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 80, 60, 65, 90, 102],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [169, 169, 169],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 48, 54, 63, 60, 68],
+        [13, 13],
+        [0, 0, 0],
+        127,
+        [51, 151, 20],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 48, 76, 83, 81, 104],
+        [13, 13],
+        [0, 0, 0],
+        0,
+        [51, 151, 158],
+        2,
+        883,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 80, 100, 35, 37, 28],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [99, 99, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 110, 150, 45, 49, 35],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [99, 99, 99],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 50, 150, 50, 150, 60],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [176, 176, 176],
+        2,
+        884,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 52, 60, 63, 65, 23],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [131, 131, 165],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [101, 72, 72, 99, 89, 29],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [131, 131, 165],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 48, 66, 59, 57, 49],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [175, 175, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [82, 80, 86, 85, 75, 72],
+        [17, 17],
+        [0, 0, 0],
+        127,
+        [175, 175, 84],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [53, 54, 53, 37, 46, 45],
+        [16, 13],
+        [0, 0, 0],
+        127,
+        [126, 21, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [86, 92, 88, 68, 75, 73],
+        [16, 13],
+        [0, 0, 0],
+        127,
+        [126, 21, 151],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [42, 52, 67, 39, 56, 50],
+        [5, 10],
+        [0, 0, 0],
+        127,
+        [181, 97, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 105, 115, 54, 86, 68],
+        [5, 10],
+        [0, 0, 0],
+        127,
+        [181, 97, 124],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 60, 60, 60, 60, 30],
+        [3, 10],
+        [0, 0, 0],
+        127,
+        [38, 143, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 75, 90, 97, 123, 44],
+        [3, 15],
+        [0, 0, 0],
+        127,
+        [38, 143, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 62, 58, 63, 44],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [178, 178, 178],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 73, 88, 120, 89, 59],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [178, 178, 178],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 38, 33, 61, 43, 70],
+        [12, 0],
+        [0, 0, 0],
+        127,
+        [87, 8, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [62, 55, 52, 109, 94, 109],
+        [12, 0],
+        [0, 0, 0],
+        127,
+        [87, 8, 94],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [58, 89, 77, 45, 45, 48],
+        [5, 15],
+        [0, 0, 0],
+        31,
+        [173, 173, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [82, 121, 119, 69, 59, 71],
+        [5, 15],
+        [0, 0, 0],
+        31,
+        [173, 173, 69],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 59, 50, 67, 63, 46],
+        [5, 14],
+        [0, 0, 0],
+        31,
+        [174, 174, 117],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [123, 77, 72, 99, 92, 58],
+        [5, 14],
+        [0, 0, 0],
+        31,
+        [174, 174, 117],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 65, 65, 110, 130, 60],
+        [17, 17],
+        [0, 0, 0],
+        31,
+        [56, 56, 182],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 92, 75, 74, 63, 118],
+        [1, 2],
+        [0, 221, 0],
+        127,
+        [7, 84, 104],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 58, 57, 81, 67, 101],
+        [12, 17],
+        [0, 0, 0],
+        127,
+        [167, 53, 57],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 50, 150, 50, 150, 50],
+        [5, 17],
+        [0, 0, 0],
+        255,
+        [29, 29, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 50, 35, 55, 75, 40],
+        [15, 15],
+        [0, 295, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 75, 53, 83, 113, 60],
+        [15, 15],
+        [0, 295, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 100, 70, 110, 150, 80],
+        [15, 15],
+        [0, 0, 0],
+        127,
+        [157, 93, 183],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [57, 80, 91, 80, 87, 75],
+        [8, 17],
+        [0, 0, 0],
+        127,
+        [158, 158, 170],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 70, 48, 50, 60, 38],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [30, 119, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 110, 76, 65, 82, 56],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [30, 119, 139],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [49, 66, 70, 44, 55, 51],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        885,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 90, 122, 58, 75, 84],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        888,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 69, 85, 32, 35, 28],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [20, 115, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 117, 184, 44, 46, 28],
+        [14, 14],
+        [0, 0, 0],
+        127,
+        [20, 115, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 30, 35, 45, 40, 55],
+        [2, 15],
+        [0, 0, 0],
+        127,
+        [119, 151, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 70, 80, 97, 80, 123],
+        [2, 15],
+        [0, 0, 0],
+        127,
+        [119, 151, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [187, 187, 187],
+        2,
+        891,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [16, 2],
+        [0, 0, 0],
+        255,
+        [186, 186, 186],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 100, 121, 81, 95, 95],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [188, 188, 188],
+        5,
+        892,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 100, 150, 100, 150, 50],
+        [5, 17],
+        [0, 0, 0],
+        255,
+        [29, 29, 29],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 110, 60, 150, 130, 70],
+        [13, 7],
+        [0, 0, 0],
+        255,
+        [170, 170, 170],
+        2,
+        896,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 110, 120, 130, 90, 70],
+        [9, 10],
+        [0, 0, 0],
+        255,
+        [11, 11, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 55, 55, 50, 50, 42],
+        [11, 2],
+        [0, 0, 0],
+        31,
+        [65, 65, 203],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 75, 75, 70, 70, 52],
+        [11, 2],
+        [0, 0, 0],
+        31,
+        [65, 65, 203],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 107, 75, 100, 100, 70],
+        [11, 7],
+        [0, 0, 0],
+        31,
+        [65, 65, 203],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 65, 40, 60, 40, 70],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 85, 50, 80, 50, 90],
+        [9, 9],
+        [0, 0, 0],
+        31,
+        [66, 66, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 115, 90, 80, 90, 60],
+        [9, 16],
+        [0, 0, 0],
+        31,
+        [66, 66, 22],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 54, 54, 66, 56, 40],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 204],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 69, 69, 91, 81, 50],
+        [10, 10],
+        [0, 0, 0],
+        31,
+        [67, 67, 204],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 74, 74, 126, 116, 60],
+        [10, 17],
+        [0, 0, 0],
+        31,
+        [67, 67, 204],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 75, 30, 30, 30, 65],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 92, 53],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 85, 50, 40, 50, 75],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 92, 53],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 75, 75, 75, 60],
+        [0, 2],
+        [0, 0, 0],
+        127,
+        [51, 92, 125],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 70, 30, 30, 30, 45],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [198, 173, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [88, 110, 60, 55, 60, 45],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [198, 173, 91],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [47, 62, 45, 55, 45, 46],
+        [6, 6],
+        [0, 0, 0],
+        127,
+        [68, 68, 68],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [57, 82, 95, 55, 75, 36],
+        [6, 12],
+        [0, 546, 0],
+        127,
+        [217, 217, 217],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [77, 70, 90, 145, 75, 43],
+        [6, 12],
+        [0, 0, 0],
+        127,
+        [26, 26, 26],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [47, 82, 57, 42, 47, 63],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [52, 89, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 132, 77, 62, 67, 43],
+        [1, 14],
+        [0, 0, 0],
+        127,
+        [52, 89, 83],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 70, 98, 70, 93],
+        [9, 2],
+        [0, 0, 0],
+        191,
+        [216, 216, 216],
+        4,
+        897,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 45, 40, 55, 40, 84],
+        [6, 17],
+        [0, 94, 0],
+        127,
+        [118, 19, 175],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 55, 60, 95, 70, 124],
+        [6, 17],
+        [0, 94, 0],
+        127,
+        [118, 19, 175],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 65, 40, 30, 40, 60],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [51, 72, 80],
+        2,
+        900,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 115, 65, 55, 65, 112],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [51, 146, 80],
+        3,
+        901,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 20, 20, 25, 25, 40],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [208, 208, 208],
+        2,
+        903,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 53, 62, 43, 52, 45],
+        [3, 10],
+        [0, 245, 0],
+        127,
+        [196, 7, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 63, 152, 53, 142, 35],
+        [3, 10],
+        [0, 245, 0],
+        127,
+        [196, 7, 144],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 100, 70, 45, 55, 45],
+        [4, 4],
+        [0, 269, 0],
+        127,
+        [20, 192, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 125, 100, 55, 85, 35],
+        [4, 4],
+        [0, 269, 0],
+        127,
+        [20, 192, 39],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 40, 52, 40, 72, 27],
+        [10, 6],
+        [0, 243, 0],
+        127,
+        [199, 199, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 70, 92, 50, 132, 42],
+        [10, 6],
+        [0, 243, 0],
+        127,
+        [199, 199, 11],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 55, 35, 50, 35, 35],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [102, 102, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 105, 90, 80, 90, 45],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [102, 102, 126],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 55, 65, 75, 15],
+        [11, 17],
+        [86, 87, 0],
+        127,
+        [35, 27, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 45, 80, 90, 100, 30],
+        [11, 17],
+        [86, 87, 0],
+        127,
+        [35, 27, 44],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [48, 44, 40, 71, 40, 77],
+        [3, 9],
+        [0, 228, 0],
+        31,
+        [212, 212, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 64, 60, 111, 60, 117],
+        [3, 9],
+        [0, 228, 0],
+        254,
+        [212, 212, 12],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 75, 50, 45, 50, 50],
+        [0, 1],
+        [0, 0, 0],
+        127,
+        [218, 103, 56],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 125, 80, 55, 60, 60],
+        [0, 1],
+        [0, 0, 0],
+        127,
+        [218, 103, 127],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [42, 30, 38, 30, 38, 32],
+        [11, 11],
+        [0, 884, 0],
+        254,
+        [102, 12, 175],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [52, 40, 48, 40, 48, 62],
+        [11, 11],
+        [0, 884, 0],
+        254,
+        [102, 12, 175],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 120, 98, 50, 98, 72],
+        [11, 11],
+        [884, 0, 0],
+        254,
+        [102, 214, 175],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [51, 52, 90, 82, 110, 100],
+        [17, 17],
+        [0, 883, 0],
+        191,
+        [166, 205, 30],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 60, 80, 90, 110, 60],
+        [0, 13],
+        [0, 0, 0],
+        127,
+        [39, 140, 180],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 120, 90, 40, 60, 80],
+        [1, 1],
+        [0, 0, 0],
+        127,
+        [222, 222, 128],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [25, 35, 40, 20, 30, 80],
+        [6, 10],
+        [0, 0, 0],
+        127,
+        [193, 193, 193],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 125, 140, 60, 90, 40],
+        [6, 10],
+        [0, 0, 0],
+        127,
+        [194, 194, 194],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 55, 80, 70, 45, 15],
+        [7, 4],
+        [0, 247, 0],
+        127,
+        [195, 195, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 75, 110, 100, 75, 35],
+        [7, 4],
+        [0, 247, 0],
+        127,
+        [195, 195, 8],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 60, 130, 30, 130, 5],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [215, 215, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 59],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [4, 4, 4],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [0, 0],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 115, 65, 75, 95, 65],
+        [0, 0],
+        [0, 0, 0],
+        127,
+        [213, 213, 213],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 78, 135, 91, 85, 36],
+        [9, 15],
+        [0, 249, 0],
+        127,
+        [75, 75, 75],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 98, 63, 40, 73, 96],
+        [12, 8],
+        [0, 881, 0],
+        127,
+        [160, 31, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 90, 80, 50, 105, 96],
+        [7, 17],
+        [0, 150, 0],
+        127,
+        [209, 209, 209],
+        2,
+        934,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [68, 105, 70, 70, 70, 92],
+        [10, 13],
+        [0, 0, 0],
+        127,
+        [219, 173, 147],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [78, 60, 85, 135, 91, 36],
+        [0, 15],
+        [0, 156, 0],
+        127,
+        [201, 157, 13],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 131, 100, 86, 90, 40],
+        [7, 11],
+        [0, 0, 0],
+        255,
+        [200, 200, 200],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 55, 65, 45, 45, 45],
+        [15, 15],
+        [0, 326, 0],
+        127,
+        [171, 43, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 75, 90, 65, 70, 65],
+        [15, 1],
+        [0, 326, 0],
+        127,
+        [171, 43, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 110, 125, 100, 105, 85],
+        [15, 1],
+        [326, 0, 0],
+        127,
+        [171, 43, 142],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 115, 85, 95, 75, 130],
+        [12, 17],
+        [0, 0, 0],
+        255,
+        [226, 226, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 85, 75, 130, 115, 95],
+        [13, 17],
+        [0, 0, 0],
+        255,
+        [227, 227, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 130, 115, 85, 95, 75],
+        [11, 17],
+        [0, 0, 0],
+        255,
+        [229, 229, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 75, 115, 95, 130, 85],
+        [10, 17],
+        [0, 0, 0],
+        255,
+        [228, 228, 140],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 29, 31, 29, 31, 37],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [109, 109, 109],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [43, 29, 131, 29, 131, 37],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [5, 5, 5],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [137, 137, 107, 113, 89, 97],
+        [13, 8],
+        [0, 0, 0],
+        255,
+        [230, 230, 230],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [137, 113, 89, 137, 107, 97],
+        [13, 7],
+        [0, 0, 0],
+        255,
+        [231, 231, 231],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [109, 53, 47, 127, 131, 103],
+        [5, 3],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [107, 139, 139, 53, 53, 79],
+        [6, 1],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 137, 37, 137, 37, 151],
+        [6, 1],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [83, 89, 71, 173, 71, 83],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 101, 103, 107, 101, 61],
+        [8, 2],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 181, 131, 59, 31, 109],
+        [11, 8],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [223, 101, 53, 97, 53, 43],
+        [16, 15],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 107, 101, 127, 89, 79],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [232, 232, 232],
+        3,
+        935,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 95, 115, 130, 115, 65],
+        [8, 17],
+        [0, 0, 0],
+        255,
+        [220, 220, 220],
+        2,
+        937,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [90, 125, 80, 90, 90, 125],
+        [1, 7],
+        [0, 0, 0],
+        255,
+        [101, 101, 101],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [67, 73, 67, 73, 67, 73],
+        [3, 3],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 73, 73, 127, 73, 121],
+        [3, 15],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [61, 131, 211, 53, 101, 13],
+        [5, 8],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [53, 127, 53, 151, 79, 107],
+        [9, 7],
+        [0, 0, 0],
+        255,
+        [224, 224, 224],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [88, 112, 75, 102, 80, 143],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [10, 10, 10],
+        1,
+        0,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [30, 56, 35, 25, 35, 72],
+        [16, 0],
+        [0, 0, 0],
+        127,
+        [82, 55, 47],
+        2,
+        808,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 71, 70, 40, 80, 77],
+        [16, 0],
+        [0, 0, 0],
+        127,
+        [82, 55, 47],
+        2,
+        809,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 55, 40, 50, 50, 90],
+        [12, 12],
+        [0, 236, 0],
+        0,
+        [9, 9, 31],
+        8,
+        810,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 85, 50, 95, 85, 110],
+        [12, 13],
+        [0, 0, 0],
+        127,
+        [207, 207, 207],
+        2,
+        817,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 75, 90, 10, 35, 40],
+        [14, 8],
+        [0, 286, 0],
+        127,
+        [81, 81, 202],
+        2,
+        818,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 100, 120, 25, 65, 65],
+        [14, 8],
+        [0, 286, 0],
+        127,
+        [81, 81, 202],
+        2,
+        819,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [38, 41, 40, 50, 65, 65],
+        [14, 14],
+        [0, 649, 0],
+        191,
+        [81, 81, 117],
+        2,
+        820,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [73, 67, 75, 81, 100, 109],
+        [14, 17],
+        [0, 649, 0],
+        191,
+        [81, 81, 117],
+        2,
+        821,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [10, 55, 30, 35, 45, 90],
+        [4, 8],
+        [0, 237, 0],
+        127,
+        [8, 221, 159],
+        2,
+        822,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [35, 100, 60, 50, 70, 110],
+        [4, 8],
+        [0, 237, 0],
+        127,
+        [8, 221, 159],
+        2,
+        823,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 35, 35, 50, 40, 90],
+        [16, 16],
+        [0, 217, 0],
+        127,
+        [53, 101, 155],
+        2,
+        824,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [65, 60, 60, 75, 65, 115],
+        [16, 16],
+        [0, 217, 0],
+        127,
+        [169, 101, 155],
+        2,
+        825,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [40, 80, 100, 30, 30, 20],
+        [5, 12],
+        [0, 0, 0],
+        127,
+        [42, 5, 206],
+        2,
+        826,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 95, 115, 45, 45, 35],
+        [5, 12],
+        [0, 0, 0],
+        127,
+        [42, 5, 206],
+        2,
+        827,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 120, 130, 55, 65, 45],
+        [5, 12],
+        [0, 0, 0],
+        127,
+        [42, 5, 206],
+        2,
+        828,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 80, 50, 40, 50, 25],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [143, 82, 223],
+        2,
+        829,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 105, 75, 65, 100, 50],
+        [3, 16],
+        [0, 0, 0],
+        127,
+        [143, 82, 223],
+        2,
+        830,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 105, 85, 125, 75, 45],
+        [11, 15],
+        [0, 0, 0],
+        127,
+        [119, 119, 139],
+        2,
+        831,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 80, 110, 50, 80, 45],
+        [9, 7],
+        [0, 258, 0],
+        127,
+        [130, 31, 69],
+        2,
+        832,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [9, 9],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        833,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [10, 10],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        833,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 70, 70, 70, 70, 70],
+        [14, 14],
+        [243, 243, 0],
+        127,
+        [59, 59, 59],
+        4,
+        833,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 180, 20, 180, 20, 150],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        836,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 70, 160, 70, 160, 90],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        836,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 95, 90, 95, 90, 180],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [46, 46, 46],
+        4,
+        836,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 79, 105, 59, 85, 36],
+        [6, 4],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        839,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 69, 95, 69, 95, 36],
+        [6, 8],
+        [0, 222, 0],
+        254,
+        [107, 107, 142],
+        3,
+        839,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 60, 70, 87, 78, 85],
+        [11, 11],
+        [0, 239, 0],
+        127,
+        [122, 122, 122],
+        2,
+        841,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [76, 48, 48, 57, 62, 34],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        842,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [111, 83, 68, 92, 82, 39],
+        [10, 4],
+        [0, 0, 0],
+        127,
+        [60, 114, 159],
+        2,
+        843,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 9],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 10],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 14],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 2],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [50, 65, 107, 105, 107, 86],
+        [12, 11],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        6,
+        844,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [150, 120, 100, 120, 100, 90],
+        [7, 15],
+        [0, 0, 0],
+        255,
+        [26, 26, 26],
+        2,
+        849,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 103, 75, 120, 75, 127],
+        [11, 2],
+        [157, 157, 0],
+        255,
+        [32, 32, 32],
+        2,
+        850,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [1, 1],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [2, 2],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [3, 3],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [4, 4],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [5, 5],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [6, 6],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [7, 7],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [9, 9],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [11, 11],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [14, 14],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [15, 15],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [16, 16],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [120, 120, 120, 120, 120, 120],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [121, 121, 121],
+        18,
+        851,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [70, 92, 65, 80, 55, 98],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [69, 91, 104],
+        2,
+        868,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [105, 30, 105, 140, 105, 55],
+        [9, 13],
+        [0, 0, 0],
+        127,
+        [125, 125, 161],
+        2,
+        869,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 100, 80, 110, 90, 121],
+        [2, 2],
+        [0, 0, 0],
+        0,
+        [144, 144, 144],
+        2,
+        870,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [79, 105, 70, 145, 80, 101],
+        [12, 2],
+        [0, 0, 0],
+        0,
+        [10, 10, 10],
+        2,
+        871,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [89, 145, 90, 105, 80, 91],
+        [4, 2],
+        [0, 0, 0],
+        0,
+        [22, 22, 22],
+        2,
+        872,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 120, 90, 170, 100, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [163, 163, 163],
+        3,
+        873,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [125, 170, 100, 120, 90, 95],
+        [15, 14],
+        [0, 0, 0],
+        255,
+        [164, 164, 164],
+        3,
+        873,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [91, 72, 90, 129, 90, 108],
+        [10, 1],
+        [0, 0, 0],
+        255,
+        [154, 154, 154],
+        2,
+        875,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [100, 128, 90, 77, 77, 128],
+        [0, 1],
+        [91, 91, 0],
+        255,
+        [32, 32, 32],
+        2,
+        876,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        877,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        877,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        877,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [71, 120, 95, 120, 95, 99],
+        [6, 8],
+        [0, 0, 0],
+        255,
+        [88, 88, 88],
+        5,
+        877,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 95, 67, 103, 71, 122],
+        [10, 16],
+        [0, 0, 0],
+        0,
+        [210, 210, 210],
+        3,
+        881,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [72, 145, 67, 153, 71, 132],
+        [10, 16],
+        [0, 0, 0],
+        0,
+        [210, 210, 210],
+        3,
+        881,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [74, 48, 76, 83, 81, 104],
+        [13, 13],
+        [0, 0, 0],
+        254,
+        [51, 151, 172],
+        2,
+        883,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 150, 50, 150, 50, 60],
+        [8, 7],
+        [0, 0, 0],
+        127,
+        [176, 176, 176],
+        2,
+        884,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [44, 66, 70, 44, 55, 56],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        885,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 66, 70, 44, 55, 46],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        885,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [59, 66, 70, 44, 55, 41],
+        [7, 11],
+        [239, 239, 0],
+        127,
+        [53, 119, 15],
+        4,
+        885,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 85, 122, 58, 75, 99],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        888,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 95, 122, 58, 75, 69],
+        [7, 11],
+        [0, 0, 0],
+        127,
+        [53, 119, 15],
+        4,
+        888,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 100, 122, 58, 75, 54],
+        [7, 11],
+        [239, 239, 0],
+        127,
+        [53, 119, 15],
+        4,
+        888,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [126, 131, 95, 131, 98, 99],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [187, 187, 187],
+        2,
+        891,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 100, 71, 61, 85, 115],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [188, 188, 188],
+        5,
+        892,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [54, 100, 71, 61, 85, 115],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [211, 211, 211],
+        5,
+        892,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [108, 100, 121, 81, 95, 95],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [211, 211, 211],
+        5,
+        892,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [216, 100, 121, 91, 95, 85],
+        [15, 4],
+        [0, 0, 0],
+        255,
+        [211, 211, 211],
+        5,
+        892,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 160, 60, 170, 130, 80],
+        [13, 16],
+        [0, 0, 0],
+        255,
+        [170, 170, 170],
+        2,
+        896,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 70, 98, 70, 93],
+        [12, 2],
+        [0, 0, 0],
+        191,
+        [216, 216, 216],
+        4,
+        897,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 70, 98, 70, 93],
+        [13, 2],
+        [0, 0, 0],
+        191,
+        [216, 216, 216],
+        4,
+        897,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 70, 70, 98, 70, 93],
+        [7, 2],
+        [0, 0, 0],
+        191,
+        [216, 216, 216],
+        4,
+        897,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 65, 40, 30, 40, 60],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [20, 20, 20],
+        2,
+        900,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [85, 115, 75, 55, 75, 82],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [51, 72, 99],
+        3,
+        901,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [75, 117, 65, 55, 65, 110],
+        [5, 5],
+        [0, 0, 0],
+        127,
+        [181, 181, 181],
+        3,
+        901,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [45, 140, 130, 140, 135, 30],
+        [10, 10],
+        [0, 0, 0],
+        127,
+        [208, 208, 208],
+        2,
+        903,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [1, 1],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [2, 2],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [3, 3],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [4, 4],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [5, 5],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [6, 6],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [7, 7],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [8, 8],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [9, 9],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [10, 10],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [11, 11],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [12, 12],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [13, 13],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [14, 14],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [15, 15],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [16, 16],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [95, 95, 95, 95, 95, 95],
+        [17, 17],
+        [0, 0, 0],
+        255,
+        [225, 225, 225],
+        18,
+        904,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 60, 100, 60, 100, 60],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [60, 100, 60, 100, 60, 120],
+        [5, 2],
+        [0, 0, 0],
+        255,
+        [197, 197, 197],
+        14,
+        921,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [55, 90, 80, 50, 105, 96],
+        [7, 17],
+        [0, 0, 0],
+        127,
+        [209, 209, 209],
+        2,
+        934,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 157, 127, 113, 109, 77],
+        [13, 8],
+        [0, 0, 0],
+        255,
+        [232, 232, 232],
+        3,
+        935,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [97, 113, 109, 157, 127, 77],
+        [13, 7],
+        [0, 0, 0],
+        255,
+        [232, 232, 232],
+        3,
+        935,
+        0,
+        1,
+    ),
+    PersonalInfo::new(
+        [80, 95, 115, 130, 115, 65],
+        [8, 17],
+        [0, 0, 0],
+        255,
+        [220, 220, 220],
+        2,
+        937,
+        0,
+        1,
+    ),
+];
+
 pub(crate) const PERSONAL_BDSP: [PersonalInfo; 560] = [
     PersonalInfo::new(
         [0, 0, 0, 0, 0, 0],
@@ -60949,7 +79861,7 @@ pub struct PersonalInfo {
 impl PersonalInfo {
     /// Construct a new [`PersonalInfo`] struct
     #[allow(clippy::too_many_arguments)]
-    const fn new(
+    pub(crate) const fn new(
         stats: [u8; 6],
         types: [u8; 2],
         items: [u16; 3],
@@ -1,10 +1,67 @@
 use crate::enums::Game;
+use crate::parents::custom_loader;
 use crate::parents::{
-    PersonalInfo, PERSONAL_B2W2, PERSONAL_BDSP, PERSONAL_BW, PERSONAL_D, PERSONAL_HGSS, PERSONAL_P,
-    PERSONAL_PT, PERSONAL_RSEFRLG, PERSONAL_SWSH,
+    PersonalInfo, PERSONAL_B2W2, PERSONAL_BDSP, PERSONAL_BW, PERSONAL_D, PERSONAL_HGSS,
+    PERSONAL_ORAS, PERSONAL_P, PERSONAL_PT, PERSONAL_RSEFRLG, PERSONAL_SWSH, PERSONAL_USUM,
 };
+use std::ops::Deref;
+use std::sync::Arc;
 
-/// Gets the [`PersonalInfo`] slice for the specified `version`
+/// [`PersonalInfo`] table of a game, either built-in or loaded with [`custom_loader`]
+#[derive(Clone)]
+pub enum PersonalTable {
+    /// Table shipped with the library
+    BuiltIn(&'static [PersonalInfo]),
+    /// Table loaded at runtime
+    Custom(Arc<[PersonalInfo]>),
+}
+
+impl PersonalTable {
+    /// Gets the [`PersonalInfo`] for the specified `species` and `form`
+    pub fn get_personal_info(&self, species: u16, form: u8) -> &PersonalInfo {
+        let base = &self[species as usize];
+        let form_index = base.get_form_stats_index();
+        if form == 0 || form_index == 0 {
+            base
+        } else {
+            &self[(form_index as usize) + (form as usize) - 1]
+        }
+    }
+}
+
+impl Deref for PersonalTable {
+    type Target = [PersonalInfo];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            PersonalTable::BuiltIn(table) => table,
+            PersonalTable::Custom(table) => table,
+        }
+    }
+}
+
+/// Resolves the [`PersonalTable`] for the specified `version`
+///
+/// Custom tables loaded with [`custom_loader::load_custom_data`] take priority over the built-in
+/// ones. Generators resolve the table once when they are constructed, so a later load only applies
+/// to generators constructed after it.
+pub fn resolve_personal_table(version: Game) -> PersonalTable {
+    custom_loader::get_personal_table(version).map_or_else(
+        || PersonalTable::BuiltIn(get_personal_table(version)),
+        PersonalTable::Custom,
+    )
+}
+
+/// Resolves the [`PersonalInfo`] for the specified `species` and `form` based on the `version`
+///
+/// Unlike [`get_personal_info`] this takes custom tables into account.
+pub fn resolve_personal_info(version: Game, species: u16, form: u8) -> PersonalInfo {
+    *resolve_personal_table(version).get_personal_info(species, form)
+}
+
+/// Gets the built-in [`PersonalInfo`] slice for the specified `version`
+///
+/// Custom tables are ignored, use [`resolve_personal_table`] to take them into account.
 pub const fn get_personal_table(version: Game) -> &'static [PersonalInfo] {
     if (version.bits() & Game::GEN3.bits()) != Game::NONE.bits() {
         &PERSONAL_RSEFRLG
@@ -20,6 +77,10 @@ pub const fn get_personal_table(version: Game) -> &'static [PersonalInfo] {
         &PERSONAL_BW
     } else if (version.bits() & Game::BW2.bits()) != Game::NONE.bits() {
         &PERSONAL_B2W2
+    } else if (version.bits() & Game::GEN6.bits()) != Game::NONE.bits() {
+        &PERSONAL_ORAS
+    } else if (version.bits() & Game::GEN7.bits()) != Game::NONE.bits() {
+        &PERSONAL_USUM
     } else if (version.bits() & Game::SWSH.bits()) != Game::NONE.bits() {
         &PERSONAL_SWSH
     } else {
@@ -27,7 +88,9 @@ pub const fn get_personal_table(version: Game) -> &'static [PersonalInfo] {
     }
 }
 
-/// Gets the [`PersonalInfo`] for the specified `species` and `form` based on the `version`
+/// Gets the built-in [`PersonalInfo`] for the specified `species` and `form` based on the `version`
+///
+/// Custom tables are ignored, use [`resolve_personal_info`] to take them into account.
 pub const fn get_personal_info(version: Game, species: u16, form: u8) -> &'static PersonalInfo {
     let info = get_personal_table(version);
     let base = &info[species as usize];
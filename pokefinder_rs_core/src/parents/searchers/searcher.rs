@@ -27,3 +27,28 @@ impl<P: Profile + Send + Sync + Clone, F: Filter + Send + Sync + Clone> Searcher
         }
     }
 }
+
+/// Common interface of the searchers that run in the background and report progress and results
+pub trait SearcherT {
+    /// State type found by the searcher
+    type State;
+
+    /// Cancels the running search
+    fn cancel(&self);
+
+    /// Returns the progress of the running search
+    fn progress(&self) -> u32;
+
+    /// Returns the states found since the last call
+    fn results(&self) -> Vec<Self::State>;
+}
+
+/// Returns how many IV combinations lie between `min` and `max`.
+///
+/// The IV searchers advance their progress once per combination.
+pub fn iv_combinations(min: [u8; 6], max: [u8; 6]) -> u32 {
+    min.iter()
+        .zip(max)
+        .map(|(&min, max)| (max as u32 + 1).saturating_sub(min as u32))
+        .product()
+}
@@ -3,7 +3,7 @@ use crate::parents::PersonalInfo;
 /// Contains information that impacts the generation of wild encounters
 #[derive(Copy, Clone)]
 pub struct Slot {
-    pub(crate) info: &'static PersonalInfo,
+    pub(crate) info: PersonalInfo,
     pub(crate) species: u16,
     pub(crate) form: u8,
     pub(crate) min_level: u8,
@@ -12,9 +12,9 @@ pub struct Slot {
 
 impl Slot {
     /// Construct a new [`Slot`] struct
-    pub fn new(species: u16, min_level: u8, max_level: u8, info: &'static PersonalInfo) -> Self {
+    pub fn new(species: u16, min_level: u8, max_level: u8, info: &PersonalInfo) -> Self {
         Self {
-            info,
+            info: *info,
             species,
             form: 0,
             min_level,
@@ -28,10 +28,10 @@ impl Slot {
         form: u8,
         min_level: u8,
         max_level: u8,
-        info: &'static PersonalInfo,
+        info: &PersonalInfo,
     ) -> Self {
         Self {
-            info,
+            info: *info,
             species,
             form,
             min_level,
@@ -45,8 +45,8 @@ impl Slot {
     }
 
     /// Determines the information of the pokemon
-    pub fn get_info(&self) -> &'static PersonalInfo {
-        self.info
+    pub fn get_info(&self) -> &PersonalInfo {
+        &self.info
     }
 
     /// Determines the max level of the pokemon
@@ -67,8 +67,8 @@ impl Slot {
     /// Allows the species to be changed.
     ///
     /// Typically due to some modifier (radar, swarms, etc.)
-    pub fn set_species(&mut self, species: u16, info: &'static PersonalInfo) {
+    pub fn set_species(&mut self, species: u16, info: &PersonalInfo) {
         self.species = species;
-        self.info = info;
+        self.info = *info;
     }
 }
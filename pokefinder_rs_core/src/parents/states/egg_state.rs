@@ -1,8 +1,9 @@
 use crate::parents::states::{State, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// State struct that provides additional egg information
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Serialize, Deserialize)]
 pub struct EggState {
     /// Base pokemon information
     pub base: State,
@@ -58,7 +59,7 @@ impl StateT for EggState {
 }
 
 /// State struct that provides additional information from an egg generator
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct EggGeneratorState {
     /// Base egg pokemon information
     pub base: EggState,
@@ -137,7 +138,7 @@ impl StateT for EggGeneratorState {
 }
 
 /// State struct that provides additional information from an egg searcher
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct EggSearcherState {
     /// Base egg pokemon information
     pub base: EggState,
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Trait containing common functions for ID states
 pub trait IDStateT {
     /// Returns the advances of the state
@@ -11,7 +13,7 @@ pub trait IDStateT {
 }
 
 /// State struct that contains all the common information for TID/SID across each game
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct IDState {
     /// State advances
     pub advances: u32,
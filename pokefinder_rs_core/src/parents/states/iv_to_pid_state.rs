@@ -1,7 +1,8 @@
 use crate::enums::Method;
+use serde::{Deserialize, Serialize};
 
 /// State struct for PID to IV information
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct IVtoPIDState {
     pid: u32,
     seed: u32,
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Contains the information to display custom calculations for PRNG states
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ResearcherState {
     custom: [u64; 10],
     prng: u64,
@@ -65,4 +67,9 @@ impl ResearcherState {
     pub fn get_prng(&self) -> u64 {
         self.prng
     }
+
+    /// Returns if the PRNG value came from a 64bit RNG
+    pub fn is_64bit(&self) -> bool {
+        self.rng_64bit
+    }
 }
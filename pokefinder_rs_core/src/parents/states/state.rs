@@ -1,11 +1,12 @@
 use crate::parents::PersonalInfo;
 use crate::util;
+use serde::{Deserialize, Serialize};
 
 const ORDER: [usize; 6] = [0, 1, 2, 5, 3, 4];
 const CHAR_ORDER: [usize; 11] = [0, 1, 2, 3, 4, 5, 0, 1, 2, 3, 4];
 
 /// Contains all the common information for a Pokemon across each game
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug, Serialize, Deserialize)]
 pub struct State {
     /// Pokemon encryption constant
     pub ec: u32,
@@ -136,7 +137,7 @@ pub trait StateT {
 }
 
 /// Struct that provides additional information from a generator
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct GeneratorState {
     /// Base generator pokemon information
     pub base: State,
@@ -192,7 +193,7 @@ impl StateT for GeneratorState {
 }
 
 /// Struct that provides additional information from a searcher
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SearcherState {
     /// Base searcher pokemon information
     pub base: State,
@@ -1,8 +1,9 @@
 use crate::parents::states::{State, StateT};
 use crate::parents::PersonalInfo;
+use serde::{Deserialize, Serialize};
 
 /// State struct that provides additional wild information
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct WildState {
     /// Base pokemon information
     pub base: State,
@@ -76,7 +77,7 @@ impl StateT for WildState {
 }
 
 /// State struct that provides additional information from a wild generator
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WildGeneratorState {
     /// Base wild pokemon information
     pub base: WildState,
@@ -167,7 +168,7 @@ impl StateT for WildGeneratorState {
 }
 
 /// State struct that provides additional information from a wild searcher
-#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct WildSearcherState {
     /// Base wild pokemon information
     pub base: WildState,
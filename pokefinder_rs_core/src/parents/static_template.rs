@@ -93,7 +93,9 @@ pub trait Template {
     /// Determines what gender the template should have
     fn get_gender(&self) -> u8;
 
-    /// Returns the pokemon information
+    /// Returns the built-in pokemon information
+    ///
+    /// Custom tables are ignored, use [`Template::resolve_info`] to take them into account.
     fn get_info(&self) -> &'static PersonalInfo {
         personal_loader::get_personal_info(self.get_version(), self.get_species(), self.get_form())
     }
@@ -112,4 +114,15 @@ pub trait Template {
 
     /// Determines what [`Game`] the template should appear in
     fn get_version(&self) -> Game;
+
+    /// Resolves the pokemon information
+    ///
+    /// Unlike [`Template::get_info`] this takes custom tables into account.
+    fn resolve_info(&self) -> PersonalInfo {
+        personal_loader::resolve_personal_info(
+            self.get_version(),
+            self.get_species(),
+            self.get_form(),
+        )
+    }
 }
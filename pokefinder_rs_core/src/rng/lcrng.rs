@@ -85,11 +85,8 @@ impl<const ADD: u32, const MULT: u32> LCRNG<ADD, MULT> {
         self.seed
     }
 
-    /// Jumps the RNG by `advances` amount
-    ///
-    /// This function uses a jump ahead table to advance any amount in just O(32)
-    pub fn jump(&mut self, mut advances: u32) -> u32 {
-        let table = match ADD {
+    fn table() -> &'static JumpTable {
+        match ADD {
             0x01 => &ARNG_TABLE,
             0x69C77F93 => &ARNGR_TABLE,
             0x6073 => &POKE_RNG_TABLE,
@@ -99,7 +96,33 @@ impl<const ADD: u32, const MULT: u32> LCRNG<ADD, MULT> {
                 assert_eq!(ADD, 0xA170F641, "Unsupported LCRNG");
                 &XD_RNGR_TABLE
             }
-        };
+        }
+    }
+
+    /// Calculates the number of advances needed to go from the `start` seed to the `end` seed
+    ///
+    /// Jumping by `2^i` only changes bit `i` and higher, so the distance is found one bit at a
+    /// time in just O(32)
+    pub fn distance(start: u32, end: u32) -> u32 {
+        let table = Self::table();
+
+        let mut seed = start;
+        let mut distance = 0;
+        for i in 0..32 {
+            let mask = 1 << i;
+            if ((seed ^ end) & mask) != 0 {
+                seed = seed.wrapping_mul(table.mult[i]).wrapping_add(table.add[i]);
+                distance |= mask;
+            }
+        }
+        distance
+    }
+
+    /// Jumps the RNG by `advances` amount
+    ///
+    /// This function uses a jump ahead table to advance any amount in just O(32)
+    pub fn jump(&mut self, mut advances: u32) -> u32 {
+        let table = Self::table();
         let mut i = 0;
         while advances > 0 {
             if (advances & 1) == 1 {
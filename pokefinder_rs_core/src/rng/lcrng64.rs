@@ -1,14 +1,14 @@
 #[derive(Copy, Clone)]
 struct JumpTable64 {
-    pub add: [u64; 32],
-    pub mult: [u64; 32],
+    pub add: [u64; 64],
+    pub mult: [u64; 64],
 }
 
 impl JumpTable64 {
     const fn new() -> Self {
         Self {
-            add: [0; 32],
-            mult: [0; 32],
+            add: [0; 64],
+            mult: [0; 64],
         }
     }
 }
@@ -19,7 +19,7 @@ const fn compute_jump_table(add: u64, mult: u64) -> JumpTable64 {
     table.mult[0] = mult;
 
     let mut i = 1;
-    while i < 32 {
+    while i < 64 {
         table.add[i] = table.add[i - 1].wrapping_mul(table.mult[i - 1].wrapping_add(1));
         table.mult[i] = table.mult[i - 1].wrapping_mul(table.mult[i - 1]);
         i += 1;
@@ -72,17 +72,40 @@ impl<const ADD: u64, const MULT: u64> LCRNG64<ADD, MULT> {
         self.seed
     }
 
-    /// Jumps the RNG by `advances` amount
-    ///
-    /// This function uses a jump ahead table to advance any amount in just O(32)
-    pub fn jump(&mut self, mut advances: u32) -> u64 {
-        let table = match ADD {
+    fn table() -> &'static JumpTable64 {
+        match ADD {
             0x269ec3 => &BW_RNG_TABLE,
             _ => {
                 assert_eq!(0x9b1ae6e9a384e6f9, ADD, "Unsupported LCRNG64");
                 &BW_RNGR_TABLE
             }
-        };
+        }
+    }
+
+    /// Calculates the number of advances needed to go from the `start` seed to the `end` seed
+    ///
+    /// Jumping by `2^i` only changes bit `i` and higher, so the distance is found one bit at a
+    /// time in just O(64)
+    pub fn distance(start: u64, end: u64) -> u64 {
+        let table = Self::table();
+
+        let mut seed = start;
+        let mut distance = 0;
+        for i in 0..64 {
+            let mask = 1 << i;
+            if ((seed ^ end) & mask) != 0 {
+                seed = seed.wrapping_mul(table.mult[i]).wrapping_add(table.add[i]);
+                distance |= mask;
+            }
+        }
+        distance
+    }
+
+    /// Jumps the RNG by `advances` amount
+    ///
+    /// This function uses a jump ahead table to advance any amount in just O(32)
+    pub fn jump(&mut self, mut advances: u32) -> u64 {
+        let table = Self::table();
 
         let mut i = 0;
         while advances > 0 {
@@ -10,6 +10,9 @@ pub mod lcrng_reverse;
 mod mt;
 mod mt_fast;
 mod rng_list;
+mod sfmt;
+mod sfmt_jump;
+mod sha1;
 mod tiny_mt;
 mod xoroshiro;
 mod xorshift;
@@ -19,6 +22,8 @@ pub use lcrng64::*;
 pub use mt::*;
 pub use mt_fast::*;
 pub use rng_list::*;
+pub use sfmt::*;
+pub use sha1::*;
 pub use tiny_mt::*;
 pub use xoroshiro::*;
 pub use xorshift::*;
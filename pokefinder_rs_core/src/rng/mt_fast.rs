@@ -53,7 +53,7 @@ impl<const SIZE: usize, const ALIGNED_SIZE: usize, const FAST: bool>
 
             for j in (0..(SIZE - (SIZE % 4))).step_by(4) {
                 let m0: [u32; 4] = ptr[j..(j + 4)].try_into().unwrap();
-                let m1: [u32; 4] = ptr[(i + 1)..(i + 5)].try_into().unwrap();
+                let m1: [u32; 4] = ptr[(j + 1)..(j + 5)].try_into().unwrap();
 
                 let x0 = 0x6c078965u32
                     .wrapping_mul(seed ^ (seed >> 30))
@@ -84,7 +84,7 @@ impl<const SIZE: usize, const ALIGNED_SIZE: usize, const FAST: bool>
                 } else {
                     y = xor(y, shr::<18>(y));
                 }
-                ptr[i..(i + 4)].copy_from_slice(&y);
+                ptr[j..(j + 4)].copy_from_slice(&y);
             }
         }
 
@@ -0,0 +1,181 @@
+use crate::rng::sfmt_jump::JUMP_TABLE;
+use crate::rng::Rng;
+
+const N: usize = 156;
+const POS1: usize = 122;
+const SL1: u32 = 18;
+const SR1: u32 = 11;
+const MSK: u128 = 0xbffffff6bffaffffddfecb7fdfffffef;
+const PARITY: [u32; 4] = [0x1, 0x0, 0x0, 0x13c9e684];
+/// Degree of the characteristic polynomial of the SFMT recursion
+const DEGREE: usize = 19968;
+/// Blocks below `1 << JUMP_START` are cheaper to shuffle than to jump
+const JUMP_START: usize = 11;
+
+/// Provides random numbers via the SIMD-oriented Fast Mersenne Twister algorithm
+#[derive(Clone)]
+pub struct SFMT {
+    state: [u32; 624],
+    index: usize,
+}
+
+impl SFMT {
+    /// Construct a new SFMT struct
+    pub fn new(mut seed: u32) -> Self {
+        let mut state = [0; 624];
+        state[0] = seed;
+
+        for (i, s) in state.iter_mut().enumerate().skip(1) {
+            seed = 0x6c078965u32
+                .wrapping_mul(seed ^ (seed >> 30))
+                .wrapping_add(i as u32);
+            *s = seed;
+        }
+
+        let mut new = Self { state, index: 624 };
+        new.period_certification();
+        new
+    }
+
+    /// Construct a new SFMT struct with initial advances
+    ///
+    /// Internally this uses [`SFMT::advance()`] for the initial advances
+    pub fn new_with_initial_advances(seed: u32, advances: u32) -> Self {
+        let mut new = Self::new(seed);
+        new.advance(advances);
+        new
+    }
+
+    /// Gets the next 32bit PRNG state
+    pub fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn period_certification(&mut self) {
+        let mut inner = self.state[..4]
+            .iter()
+            .zip(PARITY)
+            .fold(0, |inner, (s, p)| inner ^ (s & p));
+        for shift in [16, 8, 4, 2, 1] {
+            inner ^= inner >> shift;
+        }
+
+        if (inner & 1) == 0 {
+            self.state[0] ^= 1;
+        }
+    }
+
+    fn get(&self, i: usize) -> u128 {
+        self.state[i * 4..i * 4 + 4]
+            .iter()
+            .rev()
+            .fold(0, |word, &s| (word << 32) | s as u128)
+    }
+
+    fn set(&mut self, i: usize, word: u128) {
+        for (j, s) in self.state[i * 4..i * 4 + 4].iter_mut().enumerate() {
+            *s = (word >> (j * 32)) as u32;
+        }
+    }
+
+    fn shuffle(&mut self) {
+        let mut r1 = self.get(N - 2);
+        let mut r2 = self.get(N - 1);
+        for i in 0..N {
+            let word = recursion(self.get(i), self.get((i + POS1) % N), r1, r2);
+            self.set(i, word);
+            r1 = r2;
+            r2 = word;
+        }
+    }
+
+    /// Moves the state forward by the 128bit words of `polynomial` from [`JUMP_TABLE`]
+    ///
+    /// The jumped state is the sum of every state reached within the degree of the
+    /// characteristic polynomial whose coefficient in `polynomial` is set.
+    fn jump_polynomial(&mut self, polynomial: &[u64; 312]) {
+        let mut words = [0; N];
+        words
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, word)| *word = self.get(i));
+
+        let mut jumped = [0; N];
+        let mut start = 0;
+        for i in 0..DEGREE {
+            if (polynomial[i / 64] >> (i % 64)) & 1 != 0 {
+                let (front, back) = words.split_at(start);
+                jumped
+                    .iter_mut()
+                    .zip(back.iter().chain(front))
+                    .for_each(|(jumped, word)| *jumped ^= word);
+            }
+
+            words[start] = recursion(
+                words[start],
+                words[(start + POS1) % N],
+                words[(start + N - 2) % N],
+                words[(start + N - 1) % N],
+            );
+            start = (start + 1) % N;
+        }
+
+        for (i, word) in jumped.into_iter().enumerate() {
+            self.set(i, word);
+        }
+    }
+}
+
+fn recursion(a: u128, b: u128, c: u128, d: u128) -> u128 {
+    a ^ (a << 8) ^ (lanes(b, |x| x >> SR1) & MSK) ^ (c >> 8) ^ lanes(d, |x| x << SL1)
+}
+
+fn lanes(word: u128, f: impl Fn(u32) -> u32) -> u128 {
+    (0..4).fold(0, |out, i| {
+        out | (f((word >> (i * 32)) as u32) as u128) << (i * 32)
+    })
+}
+
+impl Rng for SFMT {
+    type Output = u64;
+
+    fn next(&mut self) -> Self::Output {
+        if self.index == 624 {
+            self.shuffle();
+            self.index = 0;
+        }
+
+        let low = self.state[self.index] as u64;
+        let high = self.state[self.index + 1] as u64;
+        self.index += 2;
+
+        (high << 32) | low
+    }
+
+    fn advance(&mut self, advances: u32) {
+        let mut advance = (advances as usize) * 2 + self.index;
+        while advance >= 624 {
+            self.shuffle();
+            advance -= 624;
+        }
+        self.index = advance;
+    }
+
+    fn jump(&mut self, advances: u32) {
+        let advance = (advances as usize) * 2 + self.index;
+        let mut blocks = advance / 624;
+        self.index = advance % 624;
+
+        for _ in 0..blocks % (1 << JUMP_START) {
+            self.shuffle();
+        }
+
+        blocks >>= JUMP_START;
+        for polynomial in &JUMP_TABLE {
+            if blocks & 1 != 0 {
+                self.jump_polynomial(polynomial);
+            }
+            blocks >>= 1;
+        }
+    }
+}
@@ -0,0 +1,4085 @@
+/// Jump polynomials of SFMT for 156 * 2^i 128bit words starting at 2^11, bit i is the coefficient of x^i
+pub(crate) static JUMP_TABLE: [[u64; 312]; 13] = [
+    [
+        0x459f23a8b3a3bc38,
+        0x18905554f5db7c69,
+        0xac975a41f34bcfc0,
+        0x21c5d991c40f662a,
+        0xb3e3041f4472670d,
+        0x00069012dc750d74,
+        0x8a4158f708893bb0,
+        0xf985ceb873762ee4,
+        0x27b0104263c9281b,
+        0xc1a8006f0511dcfe,
+        0x1158b6a3d5c2756c,
+        0x5fd89ff97f3feec1,
+        0x7668ab54331ba9bb,
+        0x2e69a3055a78732a,
+        0xc0fc4d264a2f9b3b,
+        0x02219f1426fd6ea3,
+        0x6db9eb601401046e,
+        0xa9f92becadaad42f,
+        0xc6d0c3e965f616fe,
+        0x2a88843f86d2f90e,
+        0x428c8fd9dc231c8d,
+        0x3ab48dd066418094,
+        0x8b8690748824edc8,
+        0xb3d8e4d09288d179,
+        0xb525e03012a99c46,
+        0xa6c6dadd9121865d,
+        0xc9028800d7208166,
+        0x2bd39dbc99e82a9d,
+        0x53cd0a32c50ab922,
+        0xe2e038760b1312ab,
+        0xe0c41575e8aa1b58,
+        0xc35706499e1fc0fa,
+        0x13668a2013869eb5,
+        0xcb3e8e32ee455cff,
+        0x46c2c0c2c821a005,
+        0x40fbfa4053449335,
+        0xdb88d19afaf8cb79,
+        0x8b1735facbd37956,
+        0x1b877c9ae0e6dcce,
+        0xc1ebab6f8ec2c888,
+        0x35fb253e802d1423,
+        0x89379f27c2396aba,
+        0x267d69df2143e847,
+        0x1a42ba7979ab7c33,
+        0x2683922a2ced05b7,
+        0xeb6623c47df56d34,
+        0xa6e2711539e47fc6,
+        0xcaf0879eb8b30abe,
+        0x0d9b515f9546b966,
+        0xe89cab3f676872e7,
+        0x15ff482566dbd23e,
+        0xc99e5fb9cf2b70d5,
+        0x9a1bd9a783f7f7b5,
+        0xcb38ef5b776dfa73,
+        0xa8abf697a7ed6a92,
+        0xb320b2679c03909b,
+        0xb13cc49d3044b860,
+        0x404245cfb05e4274,
+        0xe8edb8789ec0b982,
+        0xc00515a050c24370,
+        0x09bf86f355789082,
+        0xc24683747440bdfd,
+        0x365ab87f626c0e4b,
+        0xfd46bd8a21590c40,
+        0xfe370216c9f67c5c,
+        0x0329568937101e98,
+        0xcc41a8e8cd6873ef,
+        0x3b9a086bed508438,
+        0x5554dd4b880e8df4,
+        0x4b5c188c4aad7437,
+        0x1c917f9afc9076e5,
+        0x89c77132e897c2ac,
+        0x4673579f1249ae44,
+        0x19dff008307c6706,
+        0xd4469ee6ef71a06d,
+        0xf0c9cd3f24342fe3,
+        0xc27425ea5fc1b7e9,
+        0xae69e4254037a587,
+        0x600ac0d032b23807,
+        0xc7fbed77bcf23c3d,
+        0x75c1c9b656c40861,
+        0x3a85f84e24a1bc0d,
+        0xf491e4607ba82ccc,
+        0x9302b47d16d025e8,
+        0x01314add09405e7a,
+        0x8824766339dfafc6,
+        0x1eaef57b22226f9d,
+        0x17fa979a8225e45b,
+        0xde4aad0ca280b49a,
+        0x0bde9310b8779a86,
+        0xcb2704a77d9c3976,
+        0xd976373bddadabc2,
+        0x5cde1024f17b47c7,
+        0x59aba17f8d65ffe6,
+        0x022791857842777e,
+        0x82f83a53b19a984c,
+        0xd2c1ec347761ee38,
+        0xe7562ffa01c24825,
+        0x47e01d777f3ac8d2,
+        0xa39925b72d83c9d2,
+        0x8ee7278ae00156e7,
+        0xf63e167ef914042b,
+        0x1eab963699e75f47,
+        0xbec8425bf33390a0,
+        0xd7765b3bb9b9b5d4,
+        0xe678d4f12d690963,
+        0xf02ac75647d0c88f,
+        0x74bac68fc4d7822c,
+        0x728db0b452d2eba4,
+        0x97aa9a5b654d989e,
+        0x8747483ee88a8603,
+        0x09d940784f3b650b,
+        0xc56be7ebd7d985ce,
+        0x751db8c98e76ebd7,
+        0xe3433c0a21d10492,
+        0xd49d36edd57ab422,
+        0x26bcb0c0ea7fd211,
+        0xb1a2e5fbad790e2a,
+        0x93d1329620e239af,
+        0x4ec423f258566ffd,
+        0x426de701451d078d,
+        0x2b645ec160349860,
+        0x1180b03f5b60b434,
+        0x271ffdafafff8751,
+        0x9ce3f11ca22225e9,
+        0xfa953a6eb1985da5,
+        0xd73a3cdc74b831ff,
+        0xb0316290469ad632,
+        0x7cc6d20d397f4789,
+        0xc615e6655bdb855a,
+        0x6d3bbe0dc1c89c8c,
+        0x3a4f637aff3599dc,
+        0x2dfb349683193535,
+        0x9146e8f32a0ccc8e,
+        0x209a75ba7f2f2e34,
+        0xa4658451e2062c21,
+        0x55f0a3c05f85055d,
+        0xca23cdc02f54243a,
+        0x99eed424b81b324f,
+        0x0aaa15748da5dddc,
+        0x13d34f6835c7e42a,
+        0x29c4600030019616,
+        0xa0dfe975cced1ff0,
+        0x37c8aec06204a204,
+        0x732774c92b24e791,
+        0x7447dcde6b1ca2d6,
+        0xbb2b0fdbf5bdbeb9,
+        0xfeb8c6390a07009b,
+        0x191c6cb082b697ce,
+        0xa1a36db3dd3195fd,
+        0xb8c5ee364b4ba2ae,
+        0x255102825bd25df5,
+        0x1249d16fa4d15fe4,
+        0x5ad3c888434db72a,
+        0x0516cbc513793992,
+        0xca63512025118949,
+        0xafb6f3ff5ca41dcc,
+        0x49cbe4a83d94223e,
+        0x709db6ddb87cf5bf,
+        0x61986a4610d9020f,
+        0x1f0d94c63fac89f8,
+        0x754c6be190721000,
+        0x1ccb7bc96c425fcc,
+        0xb5e23414f25227a6,
+        0x3e87d433b44ed788,
+        0x8106fb27f9c85bf6,
+        0x0f477696e394c59e,
+        0x89a33c5509a88e34,
+        0xa0519a949e01295c,
+        0xdc5fea16a00e3961,
+        0x86c091a09e0b13e5,
+        0xac00cd61ed811e31,
+        0x2feab2c79d573223,
+        0x2f5d768df938ecb4,
+        0x3a9a456f6d2d3736,
+        0x2ff7768a47c770db,
+        0x72a3bc30c3654f0a,
+        0xaaca138e7865fecd,
+        0xddf443f085d2f0e1,
+        0xa445939f6ea67bc8,
+        0xc827ddb17b391423,
+        0x7c8b2248fe4db86d,
+        0x1508e0baa6fe328b,
+        0x6a1aed111159848f,
+        0x1c2cc02adf59533e,
+        0x5246b73eefc9e7b9,
+        0x007a6af469d2bbe8,
+        0xbcd2af214e3666c5,
+        0x2f1c4db79fe2aded,
+        0x23155e011b507b86,
+        0xf2897bf76477582f,
+        0x87f1136d267c0171,
+        0x5b3976fd606213e1,
+        0xc8790e121e003b50,
+        0x7e553991a9ddc513,
+        0x6955e1b0db901cbf,
+        0x894772153dcc4349,
+        0x40829daa5cae11d7,
+        0xb15e65c14a9babaf,
+        0x529207c8cb726a76,
+        0x31485eb3ae8326f0,
+        0xe0217317a5b679b4,
+        0x904c413f96032fe5,
+        0x7af4d89c6c8d4448,
+        0x72cbb296446bcc64,
+        0xa267653a9814b1e6,
+        0x0777b7caec8726db,
+        0xc06e913df052137e,
+        0xa70577a87c64f3b8,
+        0x435ee5e83b368a4d,
+        0x04263b466b34ef88,
+        0x4c2b21b0b161aacb,
+        0x25cf94f4e3709ff4,
+        0x6028abc09ee62f06,
+        0x502327ffbfccef18,
+        0xc063afb04f5160ef,
+        0xf806ceb75bdbe5c0,
+        0x568807befe31b02e,
+        0x869fc45d6ca1211e,
+        0xe4449d3c2046e58e,
+        0x8204b71b4d53a25c,
+        0xbf6fa87a8c761c63,
+        0x8eb01f6bdc0b485a,
+        0x83bd89139c9daa8d,
+        0x32e46b28414784e5,
+        0x5cff2ae95a2e646a,
+        0xa656bad78e1ad2c4,
+        0xbaf1f1013d3ae6f2,
+        0x76bcb21b972c3589,
+        0x90a5d99324b50d6d,
+        0x7c60e6c69816f73f,
+        0x864b3e82ee5938c6,
+        0xac25e726d9a60771,
+        0x298e95306c904b78,
+        0xe578c51709be4b7a,
+        0x43a173fd2b8a2ed1,
+        0x7c0ca77768a023f3,
+        0x62663e6aab791d37,
+        0xcc47e05e80c9cdd9,
+        0xcad16b9f9d878767,
+        0x9c3e048f69cc34a3,
+        0xc110c620a122bf87,
+        0xd6b40e0220e564bc,
+        0x61ef01fb83dad0ac,
+        0xb21b69fbe5b7f223,
+        0xbb5a365ef738f42c,
+        0x9399e35704b1452d,
+        0xa9c50a5b97bcf130,
+        0xbfcc6d6b6e77b1cc,
+        0x0c3c0788af553c46,
+        0x9ee6ef4d055ced66,
+        0xb258ec2f9f3d6ec7,
+        0x5aa4e56f5dfb8154,
+        0xf471e2a788627e52,
+        0xfc725a1e3e5bd517,
+        0x8781b0b1b7da3467,
+        0xc52f54b02c91341a,
+        0xefa74a7281feb266,
+        0xe356b35a5de856f7,
+        0xebc4c0b12ceff4d1,
+        0xdf39cf813ae2dff8,
+        0x77c389ef19d0ad7e,
+        0x21818df8ff7ae6bb,
+        0x9e8a01c45f2ca6be,
+        0x44278ff5caa08da1,
+        0xa70d03f27ad7ff68,
+        0x19d3920fd5cc40a5,
+        0xcb01cda296d03179,
+        0xd87d2e7e6c27b66b,
+        0x457f453c3cfb6c71,
+        0x39b58eff45c6289c,
+        0x0a9c98960d1f5ea2,
+        0x008cb767ae7ce8bb,
+        0x99288fc2bd681215,
+        0xbee95c8bdbb9cfb6,
+        0x3df1443aed40ffa2,
+        0x5a719b83d74e7dcc,
+        0xb4b4895176495c00,
+        0x796334f19473002e,
+        0xc1b65efc92f64b2a,
+        0x3fe0002d1932c8a0,
+        0xa324eca1e416e9a6,
+        0xe9e36129f4b3c798,
+        0x9de628357ecf2932,
+        0x1d93fc2350cc5cad,
+        0x455b5eecd0acd6be,
+        0x8e898ce4829acbcd,
+        0x80eab5db90c5a177,
+        0x69890f863962ebb3,
+        0xf349420e7147a673,
+        0x215fd372208e1e45,
+        0x12a81faccba4ca9c,
+        0xce3dd91c31b1c24f,
+        0xfdf46a720f1e99ef,
+        0xfe6f3ae45fabc476,
+        0xf0c9d85ea30256f7,
+        0xf60d3e80adb2e395,
+        0x8ce9ffa3cd883ebb,
+        0xc74a2c83dc02782c,
+        0x4b5bf5cd2f2d0d16,
+        0x5b60ee3f8fc4f483,
+        0x8503ac5682de2c76,
+        0x4583a59d3f60c1ff,
+        0xe81c49b372759a25,
+        0x5183b69da9ff6d21,
+        0x88a8518891893141,
+        0x0561a8fdf7af1f76,
+        0xeded1014193b9c65,
+        0x72e34141d551c0d7,
+        0x9432ed4e6b6bc0f5,
+        0xdcb571b7fd5a5018,
+        0x1d3c170b96c7a082,
+    ],
+    [
+        0x1f8c2fcb6ace2f7c,
+        0xd828ba11bdc56bed,
+        0x023b736b2a9ab43b,
+        0x939d7a7df6c51983,
+        0x077b9e1e308b9f7e,
+        0xa60ee9f1bd6d42f7,
+        0x00ff3cf59a5de5c4,
+        0xf5cd0594f74927c8,
+        0xd4be8a72038d809c,
+        0x917601ffcdd31db6,
+        0x3b075978cbcc7594,
+        0xade3c07620a55eb8,
+        0x0e7f6749119f9f84,
+        0x53e4522384a4c89d,
+        0x2c137e8b311ab179,
+        0x921e2810dd96f7f7,
+        0x8025bf61996972a8,
+        0x7ec29bbbdfaaa3d7,
+        0x0917f3461cdea954,
+        0xfd49de7aa62bb740,
+        0xe70aa0d8b2ed3a34,
+        0x0c52ed88542ed724,
+        0x63fef7cb9ee708b3,
+        0xa73b195dad37067f,
+        0x5641b6fccee58043,
+        0xe27466e4142db174,
+        0xadabe8ea74f373cb,
+        0x9beeef7297230512,
+        0x4afd59ea8df33ce2,
+        0x170e9727fab290af,
+        0x1424eeade52babfc,
+        0x45e4c7079364d1de,
+        0x971f200e2f8dc7c6,
+        0x2724e4eea5e83a9e,
+        0x0555cd3ddcd67c2a,
+        0x4d8aab992e1b4a77,
+        0x3df2700ffbd56fc3,
+        0x77c2c846bda3a9d0,
+        0xe2397bf78d05bad3,
+        0xe8ec8dcab04c5b3b,
+        0xad69cfa967cb0768,
+        0xb2a49e764d0b5c8e,
+        0xc3dec791d4876b9a,
+        0xc9e36b7b0fde7c80,
+        0xd6455f4f793e7743,
+        0x3cd6d4a4c48438db,
+        0xf0fc10dbaa11d389,
+        0x332ae964042d6ca9,
+        0x3a2f7bb939651f1f,
+        0x7eabc7ed62d60fcb,
+        0x3253211512a04f67,
+        0xef3e46d3732916cd,
+        0xd95ae3c3309a6b26,
+        0xe109d7293ab3ef11,
+        0x1ecc68e7c7625a8b,
+        0xcb1694c2861f7c63,
+        0x7eafe0ae5edb0466,
+        0xd1020992d3a87d75,
+        0x2c2cbb20fa0d4d76,
+        0x7d5eead694a78b55,
+        0xa1712974b4123754,
+        0x722b471cfc6f7e57,
+        0x7f0f5860583b6354,
+        0x7b57e07550cf63ff,
+        0x412a36f5bc899a2a,
+        0xea3e46be9c53fcd5,
+        0x5e4f8cfbeeed33f9,
+        0x469fa08df13ef866,
+        0x28496bf8c31907bb,
+        0xc6b694c7f9eb33ee,
+        0x6a719e072cd690d5,
+        0x6fe2cc7aee59413b,
+        0x516a494431e301f4,
+        0x5d51a14849559b95,
+        0x1162b478b2817a6d,
+        0xbdba212be708fd62,
+        0xa747ab15252b8f37,
+        0x59e46181a63965b3,
+        0xb1a8e7c574438b18,
+        0xdcfa650f2910f1b0,
+        0xba606991edbcc70f,
+        0xff4157bfe4726e9c,
+        0x54e134c836d06127,
+        0x8151301506c90c41,
+        0x77b4a074aef78a60,
+        0x666107048276078f,
+        0x5d03529bfb4d4d9f,
+        0xa690ffb322d07fa5,
+        0x492cc33501d6a878,
+        0x0c8d8c6e67263d60,
+        0xf65453dc37aa1f8d,
+        0x1e0974aa48a21b8a,
+        0x4b5b3ed1b312c85f,
+        0xf2f10a0601234d4b,
+        0x7f242b721dd1299b,
+        0x451a42de8907ae2a,
+        0x84ff03ae8c56f5ec,
+        0x8c912afad4159124,
+        0x30773b8a33747229,
+        0x1b79d8169298f3c6,
+        0xbfc5a6b79debe712,
+        0x9235bb5e9e68ca0a,
+        0x0fe59dd16b726c68,
+        0xe6c878c7678c11b4,
+        0x6df6b214a3d3f3c3,
+        0x6b2426bd1f7b0d02,
+        0x23a9baaf4def3d53,
+        0x10e90216a70a1215,
+        0xcddbcab57cfd5b88,
+        0x66b054d93039750a,
+        0xde1d6a7934c35174,
+        0x077252f7740fbd85,
+        0xa8905d01b8769b31,
+        0x701456d51172b99a,
+        0x02002f4259b0c4ba,
+        0xd3ce2c4162830894,
+        0xb109e39d0c9d3ac2,
+        0x636c1a392985e3f4,
+        0xaaee5f9600e41e68,
+        0x65303630498ac041,
+        0xc66af8df6bcd308a,
+        0xd5c768335fbcfcef,
+        0x6de83f53b190cfa8,
+        0x4cccd4739b71e839,
+        0x3fb91fc687e6b6f5,
+        0xdcd42b78d633c80b,
+        0x8102a72a4647b5a2,
+        0x82ed7c9439e24995,
+        0x5eae70ec494df82d,
+        0x8a5df68fc2bd1fe0,
+        0x4d8e90319c00e017,
+        0x53fbdbc9314fea22,
+        0x42b082da4acf4387,
+        0x143e4b61ece6b350,
+        0x3289c317161ca320,
+        0x72f328004bf06c25,
+        0xaf89168107103b78,
+        0xb6e39286df1e5369,
+        0x138715b687df6844,
+        0x13de5c9b0b799d93,
+        0x96f80f787436a9ee,
+        0x68ff1d58908616d6,
+        0x4ae8cfc9b76f55d7,
+        0xebdb9af598f45c38,
+        0x9afc8f15d88ecd13,
+        0x6fe5270369a9304e,
+        0x45a8b213023e5e5c,
+        0xb270ede717a7aa10,
+        0x579f66aadaef56a6,
+        0x5e56d4bf06b65da3,
+        0x82c0328d371206b0,
+        0x731e1b1ff86ef0ea,
+        0x69054c042099d85f,
+        0x442f079a3d2eff40,
+        0x1e79295712202141,
+        0x2c6eeeeddf98ee0c,
+        0x9eeeb6ad1ddd113b,
+        0x23078ed3c5821e84,
+        0x5f37d0a4e04ffbc9,
+        0xc2d66beef8fa70a9,
+        0x94ce1ff403124d41,
+        0x43c48e417aaeb684,
+        0x0bcc55595c6574b5,
+        0xdc58639e12d1d7bb,
+        0xed70948841637e67,
+        0x9269ae5ba809bd6b,
+        0xa01fe7825b4272ea,
+        0x8da2638498f2650a,
+        0x500fe215f239dde3,
+        0xb1d5e0aa8b8377d4,
+        0x3e53502e50721d7d,
+        0xc3f20a826fe86723,
+        0x417797d471b78ec6,
+        0x1accb26cf7d44b7b,
+        0x342687517faef5df,
+        0xdbfcd386491b5ece,
+        0x683235bc9b1c1a30,
+        0xd333c9c8f0f5d0b0,
+        0x3a4a097d05bc0ee3,
+        0x4417d9441132e11e,
+        0x7b3c92bd635eead0,
+        0xe2fb77521251287b,
+        0x24f2c87610365296,
+        0x178536bd2011dc53,
+        0x33af65fa003d3650,
+        0xa7ea4f946a7616b9,
+        0xc377946e32822263,
+        0x2121476ddfc8b286,
+        0xd8b3c6d5176f0aaa,
+        0x9c68e3b5b11f807d,
+        0x2c6b087f54a63417,
+        0x6705c85bba4d7086,
+        0x9268de1c9cbdd81b,
+        0x65af21c709ed8004,
+        0xf0c3864f7dbef075,
+        0x494af60ee9f40896,
+        0x68d559e47bde28a7,
+        0xf8afc0ac82ee82a3,
+        0x73d910a6dfbbb5ff,
+        0xb8f984f145667b99,
+        0x4f0bb575282d7151,
+        0x8aacf09e27b14131,
+        0xc843a8f994eb5bbc,
+        0xc4ea095120f2d6e1,
+        0x61e7d253abbeee31,
+        0xa6be82fb19a3f4aa,
+        0x65fef979ad14b0b6,
+        0x57f13dbb8b465bed,
+        0x9931be1d42e93161,
+        0x4fbf401663e3ab90,
+        0xc72e651642783281,
+        0x088fe52b264ab9b3,
+        0xbf4479450046adaa,
+        0xe2d7a4bb93127faf,
+        0xd1f331482506fe31,
+        0x3665ca291242b091,
+        0x054a8456e8f9c494,
+        0xdfc06580963ae56f,
+        0x79c9a4519911d86e,
+        0x6df615cf9c675aba,
+        0x4fd4e2bfa47eeb8f,
+        0xa10be72a4907ea24,
+        0x87c6cb6db731671a,
+        0x1bc309255eef1bff,
+        0xfabb1399b9d64d62,
+        0xe89d4c74473955a1,
+        0xb45c3f5e789712bc,
+        0x56a3dc7cae0535c4,
+        0xb2049c5d76dca954,
+        0x5a5b017775340e3f,
+        0xbc6cc71a624cffa3,
+        0x70c5925f598ce17c,
+        0x394f15a9bec93d95,
+        0x17cf8ffc9742a8ea,
+        0xff3edec8900a4dd1,
+        0x1c0caf9fefc2145d,
+        0x47c1bb6a3966c0ec,
+        0x4077e8f2b62a743f,
+        0x8d6d4d4f8606e752,
+        0x496bec85b52de88d,
+        0x06f5b46475883eb3,
+        0x7cbf1109974e4f14,
+        0x40490d7e3fd64ad9,
+        0x19952ecd9a54a267,
+        0x57b73665f2dbea63,
+        0xc66bc47c921047eb,
+        0xb10bbb6e8b426440,
+        0x085df736e4322e5e,
+        0xa9933b1a7eb4f46f,
+        0xebf12bfa6ccd6b98,
+        0xa76d8aaac5ec2bfe,
+        0x36cca0dd77b27eb4,
+        0x8d8b06852ebda699,
+        0xbac190a596ee3302,
+        0x2a64097d52ad23ed,
+        0x2c7ee32e3913af0f,
+        0x61609235e0514953,
+        0x15286b0b6c139e1a,
+        0x461b4bbd534d7907,
+        0x9b344774b5971843,
+        0x48d18d9a69ca8e98,
+        0x286f0e570df3a2c9,
+        0xda10cc40b08e40f0,
+        0xfb0ccf7e4bf6f324,
+        0xcbbf959d5d569bc4,
+        0x7a48047190b2520b,
+        0xa0b3fbbf056d39a5,
+        0xe523512d1ee988bf,
+        0x8c289076d433cc5d,
+        0xa585dd593149ebe7,
+        0x1b4ec2890dcf972c,
+        0x6a7d6931b0a0a0eb,
+        0x094edbbace25f96e,
+        0x8190fdc6754a0eaf,
+        0xc77d7d058a7e587d,
+        0x7c81bf660fac432f,
+        0xa1d02eeadbcea10e,
+        0xfd2c2207ea49f8ed,
+        0x4b88c7a2e1296ba3,
+        0x24f502d8db965102,
+        0x10e5f5d866d95465,
+        0x1b1b20608bb7a33f,
+        0x4d8c9eebf65f6fdb,
+        0xb5931c548f9da664,
+        0x2cb8bf7ad6d3fe30,
+        0xff257a322c276c1d,
+        0xdf37d4771f50949b,
+        0x5eee798be6d44d36,
+        0x14c7aa3633427296,
+        0xfd654de302889491,
+        0x02f7cfa7c37c9bcf,
+        0xee6738c8dea00c06,
+        0x761afe5ea42ca3ff,
+        0x53154b4507f84273,
+        0x6edb27374c413436,
+        0xd9dec5b4dd409f07,
+        0x9bc64af592487b6e,
+        0xc4c37546d5a2c07e,
+        0x3517742dda603141,
+        0x65a1dd4cdbc58f7e,
+        0x297231534e1477af,
+        0xb94aa892cc77cd30,
+        0x2e72a6ee5d44f222,
+        0xf1ebefdd9e78c93e,
+        0xbc8b27cf7332c3ff,
+        0xa82f1002ced2da7d,
+        0x3c1378461fbece07,
+        0x99a9bfe2cf702b95,
+        0x9efcf9e8ff68d71e,
+        0x2956483372e548af,
+        0x1548e448f5266239,
+        0xbb8d81ebd6e7ab11,
+    ],
+    [
+        0x61850a4d7b72fe69,
+        0x5e04b1b87c3d5ffe,
+        0xf0b1938be874f155,
+        0x1ee67ae41207e346,
+        0x338b1475b6194720,
+        0x1cb80b5928790f9a,
+        0xd2c0e56a1e24c87a,
+        0xea4c922c19458b80,
+        0x29f8d931f31dadde,
+        0xfa4c9fc8f38984ae,
+        0xafa9cc196285b87c,
+        0x3bca979cdc62e3fb,
+        0x9c19179afd5b044e,
+        0x9b6097c149bdaa76,
+        0xb0fd8c4cb5f4854a,
+        0xb20a7d3dcbf09b2d,
+        0xb00fa7cbbbc383b2,
+        0xb1df1638f54551ce,
+        0x85d7212832c2abe1,
+        0x1d1b0248a95ea45a,
+        0xc9bb8441b690bf56,
+        0x472b1697ad737666,
+        0xdddc7f1b59bc8a55,
+        0x2840f7ccbd75ac48,
+        0x14cd9051ec03c650,
+        0xf2a38c74e5efa73e,
+        0x5e3c0575ccce4550,
+        0xdbd5996eae81d014,
+        0x4abee67c6aeac3a9,
+        0xd8d533cecfb09d58,
+        0x103457882905975c,
+        0x0371a0a662268995,
+        0xbb061576f43a4f17,
+        0x59f069ce15498bf1,
+        0x1076f5975f56a81a,
+        0x6ab1abe9a849ab64,
+        0x25c5fffd8fd0d731,
+        0x4f8716a3f244485a,
+        0x66ddbd9924747705,
+        0xb2cd41bd07909869,
+        0x3f14bd18550d1c1b,
+        0x00f930ce88f8dfdf,
+        0x4125775b63beb42a,
+        0xd746a7bdd87199b2,
+        0x69ca6a031a8d0195,
+        0xa8ceaeb9c6810e5a,
+        0x1756401f43c0b999,
+        0x35f074ce669875cf,
+        0xce3e6bcfaace3393,
+        0xb2ad8a1b184ff1d0,
+        0x1d3a7b75fb2679c3,
+        0x6faff034664780c1,
+        0x12780cbe952ebafb,
+        0x3ee5d5df34ca333f,
+        0xfd42bc53592e8f51,
+        0x74724bcdb7cd7dcc,
+        0xf2b7211d70b7b3de,
+        0x69b6249237ffb18a,
+        0x4b2a162fa76585e7,
+        0x2d21bd47f45e3305,
+        0xcfffed0e2e3a384d,
+        0x5c8f4397c3fd0c07,
+        0xd304802b4c703b6c,
+        0x1fc8554c020ac16a,
+        0xd400ec94d81fb19d,
+        0x08adb1ec418b1804,
+        0x754ff63348d6a3b6,
+        0xf31c4e5ea185eb7b,
+        0x3bbdec6528f87d62,
+        0x3346389b563e43d8,
+        0x61ee38aec8c70010,
+        0x1fe6b0a5c8c51479,
+        0x8d170305a6cfd300,
+        0x463bd55823ac9036,
+        0x072644817ead615f,
+        0xb85074a8c6dd6b09,
+        0x1c0c91ebcec5ff56,
+        0x3424bed8dd336b32,
+        0x87faf7b9e98344dc,
+        0xc3a9b60751054409,
+        0xba86e7bb47936e3b,
+        0x23314e4e5ab5f8f2,
+        0x55e30dbbf3b4392a,
+        0x917c71847ce341d0,
+        0x75e3cdab1f7ee3d3,
+        0xf6e37e21f5d6dfad,
+        0x84f02eabbb377c26,
+        0xb03a459c085edfa9,
+        0x70a851cfb8cd9963,
+        0x8686f743668facd6,
+        0x79df5e2eb23fd5aa,
+        0x6133e7c72efd0102,
+        0x298bf69cd561648b,
+        0x803d8d0fd79c4974,
+        0x5dad203a3189f86b,
+        0x5c949c27ac6c772b,
+        0xaed0deff70dd1d98,
+        0xf1d56c1b57393361,
+        0xd992e70f762570be,
+        0x314d8968ffa14171,
+        0x88f7b28464ce1ad1,
+        0xb58e0c2dd859e910,
+        0x9c5f2092b6f32701,
+        0xfa764439896693f4,
+        0xc299a52f9b09401d,
+        0x7a48e99f35e2cd57,
+        0xb48a38fa489b9c96,
+        0x3e1319b09566238c,
+        0xa25a7da30944054c,
+        0xe8de7528479c6db1,
+        0x67167ed54ad7adfb,
+        0x5294f8bacba9dfee,
+        0x4da610854d9faea5,
+        0x5d40afc4308c5bf1,
+        0x36f08555f3bd113b,
+        0xa9664b3fef7dbf5f,
+        0xcd6b36bbc6a40bea,
+        0xd1dd8222904796aa,
+        0xbcbfd8b50a619843,
+        0xce9b31239d5589d7,
+        0x8b96e76775f0b981,
+        0x384223a0a9e2fa5e,
+        0xd2839e63e21dbd38,
+        0xf23c2a4bb1582626,
+        0x813ff843bc59d171,
+        0x0b7d5f95a8189e0c,
+        0x5f6e488389abd1ff,
+        0xe9b2997b7dc37748,
+        0x0317349bc211a3b7,
+        0x00d323c5be3e8ce2,
+        0x0898acdfef58e237,
+        0x63b7c92fc2f6ce80,
+        0x7927554271583f56,
+        0x79153b120afa2e3c,
+        0xe7aab5bc06720c03,
+        0x84f60c8167074d42,
+        0xa14856b7107a010f,
+        0xb78af962630d2816,
+        0x46e577818012bfda,
+        0x6f92909ca6866aa2,
+        0x0fbb4b482b251944,
+        0x97c203460556fae9,
+        0xff6a04c7fe3154e6,
+        0x5809ea49dd228abf,
+        0x8d949fc0a04768af,
+        0x8a09045f529c3ff5,
+        0x9aec6aa2a1bfa000,
+        0x315b17a9ad992056,
+        0x7b38db6a34508758,
+        0xd1bd9253a6dc9965,
+        0xb185a2b2b3844a08,
+        0x3ee4f7143d7ed974,
+        0xefb392b737c5b99f,
+        0x47fff75482cee4e1,
+        0xf351a845dd896af3,
+        0x8d65e87156864401,
+        0xda3333a2dd02a303,
+        0xf627aca242efb0db,
+        0xee41c06b5e62e8ef,
+        0x5b070fe7a3bc9bd6,
+        0xdc3a71d705f45dd9,
+        0xb890e2286570aa05,
+        0x9622834511aade91,
+        0x18d4f30e7ed31280,
+        0xe21e90fc797ef876,
+        0x00cda3f056499b9c,
+        0xfcc7a25ee1d108eb,
+        0x06340ddcbb147b11,
+        0x23ad58f2da3cd8a2,
+        0x3074fd7f3210fe70,
+        0xe6372cf7fc56c27a,
+        0x91edc73dd0d36c2a,
+        0x7314846e3d4928e8,
+        0xbab1893321cec0dd,
+        0x38d2da6a263a46e1,
+        0xe6858f8e5c41448e,
+        0x25e87eaef1ddc5df,
+        0x5a74db4257777ad8,
+        0x157d66405172983a,
+        0x5a74ee32a3a9df83,
+        0xe8ff87b173015864,
+        0xdef9788acf60bbfc,
+        0x4ee66f530413d138,
+        0x5cb0b749ac2459b0,
+        0x4c98f185de380438,
+        0x6b652e81c4d750c6,
+        0x1531ef92890835b7,
+        0x496cd3dc894dbe33,
+        0x737a7f505dd112a9,
+        0x193293b2e200eab8,
+        0x8890b1d10bd1018f,
+        0x90d5d22c5df8390d,
+        0xd0cf53ee2b7e39d2,
+        0x7481eb0b84bd16f5,
+        0x4442ab236c840380,
+        0xc35134ad0e4872ee,
+        0xd1cfa6fa2df06646,
+        0x949a6a6e17e5034b,
+        0x38c5c6b16985a9d6,
+        0xc25737f6a5e53cb6,
+        0x4197125addfc5160,
+        0xe76211c5acea64c2,
+        0x5717093eedceb70a,
+        0x55864223a02c3d48,
+        0x67eecd2002cb60e6,
+        0x04f27c9f237bef9f,
+        0x01a98f18734deeda,
+        0xa5a9f32a1a9879de,
+        0x61a51af071818fad,
+        0xfbc14436b6591d49,
+        0x42d69eddf20c109b,
+        0xedbde01f0aea9722,
+        0xe987da75ef44ed03,
+        0xefccfa3bbbdeb1cc,
+        0xaf672d811fbf5857,
+        0x4beee9e7a4cad3ed,
+        0x92585a7319f76c75,
+        0x60fe24a276b72157,
+        0x3dcfa4eae48d77a2,
+        0xdd9fd75139b356d6,
+        0xdf930576bba48e8c,
+        0xa2724949e4ec1a50,
+        0xcfcbdafb758b5625,
+        0x41815334af505244,
+        0x17dc61c5bb83c2d3,
+        0x09c3385de89e4b5e,
+        0x5e288544ad3cafd1,
+        0x55fb4777ac4c6f2d,
+        0x01526847bbf06d01,
+        0x878a29e1d33f04d6,
+        0x8de55d41a16830a2,
+        0x3322eaa52d3d5642,
+        0x1514b78e118d0906,
+        0x2cfa26fba1ae8eb9,
+        0x7adc6588054be689,
+        0x4711a3ba732ab038,
+        0x46f660f9ede2d18f,
+        0xba0b130e86af7baf,
+        0x8b1d85fa4f3cdd77,
+        0x11a99d3e6e6b5881,
+        0x8b22517ae5ded938,
+        0x6905d1e3bd97a81a,
+        0xb25c23628a482053,
+        0xe1305f52377976b4,
+        0x0732ee871990298f,
+        0x349b9ccabf111ab1,
+        0xdc99ecec92092cf1,
+        0xdd18540bd8ba4ba5,
+        0xa598e77ff9584337,
+        0xecbeea42911f9d2c,
+        0xffeda60dae0da679,
+        0x5ad2bfdeaa27697f,
+        0x39224c1987faa093,
+        0x9b405a00e9dc056f,
+        0x03e54b8d0c2d8973,
+        0xd60cda2a64e7b31f,
+        0x4bcf0b3612a58b64,
+        0x9227608e8d2332d1,
+        0x1737b5ff3c0eff39,
+        0xa21230dc2f1e3e2c,
+        0xed824aa496582cd1,
+        0xa39efd7920391cd6,
+        0x245475f41f07a1a1,
+        0x5b7e07ed48da73a0,
+        0xc177ded9bd87fb16,
+        0x4824875963092349,
+        0x404796534f2f6d59,
+        0x5368365735431952,
+        0x4334b49dc913ed55,
+        0x4f044a2987f10642,
+        0x3520aabbbc985735,
+        0xf0cc9242ecc44585,
+        0x5f8566e980c6c85d,
+        0xca5695af3698817d,
+        0x8bf07d28b2c21667,
+        0x4c92237982e94f6c,
+        0x37ce0709443e8403,
+        0x265fcdeb9331c810,
+        0xa13e58e0fd5bf56f,
+        0x54602495cd14880e,
+        0x140a6868bfaa1472,
+        0x859229081476482c,
+        0x022252d3a70ad6bd,
+        0xcf5781d5fd0ccf34,
+        0xeb43d3378c001a20,
+        0x91f55d3ec1e449c0,
+        0xb4260d84198fc193,
+        0xffdb6c5d6379504f,
+        0xaa08daf3608beddb,
+        0x8c52fc2f59aa440c,
+        0xc7ef419286416430,
+        0xdc4628d9dfdd1400,
+        0xa1b88eac0da9c383,
+        0x48e6fdba9fa08754,
+        0x53dd3724a488d6fd,
+        0x334ea4101dfb3c94,
+        0x620529493bd4bac3,
+        0x63505cefd641b114,
+        0xed2f382348ba9629,
+        0xe90353a4fb606638,
+        0xa64fc1c2eb12d412,
+        0x9896f40859838531,
+        0x52807d087993b7e3,
+        0x53d49881d5929df8,
+        0x32d7208724b43bd8,
+        0x8756fa4b590a8b33,
+        0x11601a79b8cb9079,
+        0x7b45c42d722e3de4,
+        0xdd5289c56518d63e,
+        0x51bcee41e1c7c544,
+        0x9c93b96fa5c3ba71,
+        0xc06666884dbf2dcc,
+    ],
+    [
+        0xcf3652ecd590164a,
+        0xc4b68df7f4f8ee0f,
+        0xee2f1edad1cb121e,
+        0x66697d813b0f69e6,
+        0x4e442e67dfc13811,
+        0xd9204798ee720a75,
+        0x470eb78d6f011aec,
+        0x3fd1337316a42882,
+        0x10705470d9d87d11,
+        0x0cb7ca978a944db6,
+        0xdef640a9750d5969,
+        0xbcaa1badccdee72d,
+        0xd7a4891cfee0faed,
+        0x8f063e9d64424f47,
+        0xb42efbb05376a58d,
+        0xf6ebe27c1fa15f31,
+        0xb9656ee0897768f2,
+        0xa56b3a61b34804a5,
+        0x523abfa8f655cad6,
+        0xdcb64b3b1fbca996,
+        0x8f32994a0102c2b1,
+        0xf8e685b07c593a5d,
+        0xab3455096b4784dd,
+        0xddb1717e6abba2a4,
+        0xeea430f64dc9b4c5,
+        0x455528a949d4b95c,
+        0xc030be8a6f104dbb,
+        0x9f84c3ac5a26176d,
+        0xf170d302b29acd9c,
+        0x17e2090050b501d7,
+        0x8c92b78e80d09933,
+        0xdf3cdbbf056fba04,
+        0x184cad378da67d29,
+        0xac0dcde77970447d,
+        0x37e427e6df4e9a3b,
+        0x6ed471f8459e2860,
+        0x3b3217c555f2cedb,
+        0xf2e3de8c5604ccd0,
+        0x6822195de20e1645,
+        0x9e12cc4e9e07ff9f,
+        0x2650874b6167cc36,
+        0x5520f8a4877617ac,
+        0x2e4754bccea21e5c,
+        0x6fbf28f2866b6c44,
+        0x4e8476bf564dd405,
+        0xc52311aa9cc43452,
+        0x33b108e68f872894,
+        0xa0967e3a2e8c3f5b,
+        0x297df9423242f94f,
+        0xf04ab3703ddcca8f,
+        0x8c2014eb6b0ba0dd,
+        0x6ba263c59265743e,
+        0x8cf0f7ef39353328,
+        0xa6d8345ebf446199,
+        0x128b79d0e34984d0,
+        0x9e1b0e81e3837b65,
+        0x10e79356b499f095,
+        0xc72c4c62bf43d0f1,
+        0xfd1adccd8d28c7ce,
+        0x7cbd286363634d0e,
+        0xd6b15302885f958c,
+        0xbb66e5ed19a58a73,
+        0xbdc432031ded187e,
+        0x3497976879780c7f,
+        0x04662b5560f29a64,
+        0x5061c6814e181cc6,
+        0x24a586566d85af1d,
+        0x1e896bfbc9f24daf,
+        0xe547b97951729741,
+        0x5efdcdea0a918e5f,
+        0x9e28fbd86b1571d9,
+        0x53b81059ad314274,
+        0x99242eb282c69f7f,
+        0xb179c988b09ed3c1,
+        0x5e5a73a2ba65fda7,
+        0xfd1995e130555dac,
+        0x525a8655b23643c3,
+        0xe43a9085cfc5de2f,
+        0xf61effd00eca2d17,
+        0xf311579474ba35fc,
+        0x0ac7aa4bdc3ae0ff,
+        0xd0c37e63d2e42b68,
+        0x1783b8f1366355b8,
+        0x60cde0d8907db3a3,
+        0x38df34309df2032d,
+        0xfad2d1b63b441b3a,
+        0x6ed093024595a94e,
+        0x698bc7d79bc89839,
+        0xd550a2d3976fff39,
+        0xd4fc2d701d257c77,
+        0x458e1aa79c1e6c3e,
+        0xd6a6b556854c9f21,
+        0x559c59b3c1dd556e,
+        0xb0c9d0143b461ce9,
+        0x052845bea4759043,
+        0x51c2defb837450ad,
+        0xd9d81529272cef03,
+        0x1fd19457a8ab9f6f,
+        0x5662e3632a57e111,
+        0xa237373c5a567572,
+        0xaa2c009ae97dc214,
+        0xeef18524ed0ef825,
+        0x693f68e75cbf0bb7,
+        0x51904f0cdd0dfb3d,
+        0x9aeaebcc70ade63b,
+        0x92b281be3ed1f997,
+        0x6bc7d0ceed775abb,
+        0xdc2574544cedd993,
+        0xf2931c2ca3b46dab,
+        0x390e604c5d27e8da,
+        0xbaa8df22c3f28fd6,
+        0x6a2895797b7ac2f0,
+        0x40cbf03f34b89f13,
+        0x23c3d19c03a50df2,
+        0xcf9cac15f25491be,
+        0x57ba783cc6dbc0cf,
+        0x6326502ad8c9850e,
+        0x69837dc56e2391bf,
+        0xa5ea942d201959f4,
+        0x1d911c8d6c3dcdd6,
+        0x62d5a0fca5f3d119,
+        0x451238eacaa2c0ce,
+        0xeb3230cf06a42b6c,
+        0x1bcb6c4db7d85321,
+        0x5d12c8fdf9ae5f9f,
+        0xfd437d1324e04e60,
+        0xa793a5258d20b942,
+        0xdcc1833a1f7670a3,
+        0x60e564d1b859f699,
+        0x66fef4243d08bfd7,
+        0x70b1eb7bb3910721,
+        0xe38eb14db3615e50,
+        0xbd198fff006e2c60,
+        0x31994d88421a70b3,
+        0x24966e14526c5b9d,
+        0xce47da827ca89a0c,
+        0xf03c8b65eb9c5ba1,
+        0x9fbed1168eef4210,
+        0x79b3aaac6a8a189b,
+        0xa47dbf0aada12c2c,
+        0x361408bea3fb50a9,
+        0x5b9d4aaf48e8fb29,
+        0xf4f7ec963f6f0cfe,
+        0xc9fc70c6d0e2713f,
+        0x61b679c64b8fcd1c,
+        0xa669f519cd543a30,
+        0xad3b0ea91212e201,
+        0x45d3fe879cd3bbd5,
+        0x1bcd6a9caa960baa,
+        0x0abfef287e475718,
+        0xa3464f1b9817283d,
+        0x1440753d2885a34e,
+        0x721979cdbd3fc863,
+        0x3cebc4bba9f62fcd,
+        0xb1890d9c6d77d6cc,
+        0xc7523c46cc0df7e7,
+        0x822a0b4b47094695,
+        0xa3d9b0227e480637,
+        0xa912a27cca517f7d,
+        0x408a5482232da5ac,
+        0x390025880108576c,
+        0xac7764eaea5758e7,
+        0x1b678761b2bc6ee9,
+        0xc260a64939b93751,
+        0xdfe092e5442bbd26,
+        0xea5056f12086232d,
+        0x2d094f7e3bff1646,
+        0x372cbb5a554cbddf,
+        0x99ba167711306d96,
+        0x00041874a70615b5,
+        0x197f021e989b91ec,
+        0xbacd46d372258199,
+        0xf3627499e7338ac5,
+        0xa10b41fb0322992c,
+        0x687052215b258b0d,
+        0xc961c52e7fe6996c,
+        0x889259fd019c21fe,
+        0xc0372cc28c31abc4,
+        0xc14f8c08fbf31125,
+        0x73e25024ef832eb0,
+        0x6f2d005d56573ca9,
+        0xee4d3f80ee3e390a,
+        0x62e7db78b69baddf,
+        0x10c7d341e4fa5535,
+        0x892ab72f3dc0115e,
+        0x6784ca06a090559b,
+        0xd1677428249f6299,
+        0x28956401b5dea0fe,
+        0x2e4d4fe44a70759e,
+        0x93601e0f2693844b,
+        0x1f05bae1b331953e,
+        0xc91b756408d95d00,
+        0xbe7e4a415c53ac79,
+        0x898631bb37856006,
+        0xa65a8c33717b5922,
+        0xc82c61953248fa19,
+        0x5c956bd6d603aca4,
+        0xbd30ad55d3d66310,
+        0x494fc638f0cc2289,
+        0x54afaa2cb54d9c21,
+        0x279c68c16209b496,
+        0x629b4e75ac30cfe2,
+        0x452a9f22b795d80e,
+        0xf3545a91d1c4a97d,
+        0xe2260473c6b47aa2,
+        0x0ddb3513cc9f4236,
+        0xccd12f7eaebe9a6a,
+        0xff49b5ff4f1f8da0,
+        0x041fb86eaeed0b76,
+        0xd825ef59e33492f2,
+        0xa6382154185bc6a8,
+        0x1365dc5081c74bbc,
+        0xc6aa1abd0e483eed,
+        0x7714eed30056514b,
+        0x4ffa8c5973913b3e,
+        0x259c03ada7df253a,
+        0x4a8eaf639b0cc773,
+        0x62b2ab7e91a0e098,
+        0xb8f75c3629bf7c7c,
+        0xa0d398ada7bec44f,
+        0xf276dd9c54471a5e,
+        0xd69740fdfaf01e05,
+        0xe8ab7b75ec0215a9,
+        0x24d5620435120e61,
+        0xeceb8e65ed281e45,
+        0x03cde5fb0895cc77,
+        0x0123bdbdebdeae4d,
+        0xb65c6ec13bf2fd92,
+        0xf401f693c5940033,
+        0xfc7bef266f2e0ae9,
+        0x95a33d3e0d0cf4ab,
+        0xe5cd6ee8ae09d4b2,
+        0x1f682f5f8a4de0a8,
+        0x928a33d819596f21,
+        0x6899091c5d639818,
+        0x9a517cb68c8f23f4,
+        0x233c82f8cdee696a,
+        0x42d744926b8baee1,
+        0x82c4c2121fea3aff,
+        0x2236884008916510,
+        0xd97414636b926568,
+        0x1a4a7d5e32ad0fbd,
+        0x283957cfac7795af,
+        0x3f27028d65a23a28,
+        0x20ba3842a6a088dd,
+        0x2e832a1803be92c7,
+        0xb612943d98e4d352,
+        0x169ac5a0e7238db7,
+        0xe4acc71a38f64f36,
+        0xf7a50713b749805f,
+        0x8e5c940b8f18aace,
+        0x0c0747146622ea14,
+        0x3753c9ef3e91b41d,
+        0x6b03a80acbf14484,
+        0x10da96515c82cc41,
+        0xa12aaf2cb2179d1a,
+        0x15b71ae4348e6c81,
+        0x81b06ba70b2a75b7,
+        0xbc30152296ce0099,
+        0x71097246ac64afc7,
+        0x7b9596027ad6c7f1,
+        0x95540b887c36007a,
+        0xa788ecf837438d82,
+        0x2836a316712e8c35,
+        0x74b1f0df1d1fa487,
+        0xcb51462281d22163,
+        0x4ef491748f21836c,
+        0x85c18c7567959f99,
+        0x8b606425b4abc6fe,
+        0x210f7b65bd5320ad,
+        0x15d90ddc0347d61d,
+        0x174bd1ae14353414,
+        0x27f3115bd0563e34,
+        0x5c96c192303e908f,
+        0xe40067f736be0717,
+        0x1d9d5d44148b058c,
+        0x88dd61b5010a987a,
+        0xce210013d9d7f981,
+        0x34ceb88656a4f1e2,
+        0x3c2a35a13dc5b992,
+        0x3c8ab11a4df18523,
+        0x73f7f4c2e8fe49a1,
+        0x7c6e4424078de13a,
+        0x27469ae5f56f95ad,
+        0x42e0a8ad715fdcff,
+        0x4a2bba396a10df45,
+        0xb04f60cef71611d4,
+        0x5c0ff608f553dd67,
+        0x0ae1599992c72fea,
+        0x43bbdebc30ff2a0b,
+        0x9a212b82224f1069,
+        0xcdcd6fa9e1ed926c,
+        0x8b29cebab913d40c,
+        0x141adfcc08b931a2,
+        0xaf3fd9903f40364a,
+        0x7d49ec9f9926b5b6,
+        0xaaf5eeaf651178cc,
+        0xfed4c3525b08f26d,
+        0x51a2eb76e2b7cccd,
+        0x80e738e641cf3b61,
+        0x8426e19bfc7b8f9a,
+        0x947d2d211ac40f8f,
+        0x8c35edddaccf57f9,
+        0x61afb7f4f4bb1de7,
+        0x5e9a4dfad3f0c5bc,
+        0xe0f72231a950dbff,
+        0xf4eb13b6f7dd5d9c,
+        0x7506dc705aa3177b,
+        0x40f4202503a168b0,
+        0xaa5225fa764ca4eb,
+        0x6fcf4bab8c7aa04d,
+        0x740dd3efdf8344ce,
+    ],
+    [
+        0xc031217c9a793e31,
+        0x1e5f144c86dc4129,
+        0x342fc8d39d96116d,
+        0x238c7fd6be89b967,
+        0xb65de5cc70327a68,
+        0xeaa782a3a0221438,
+        0xbab258d03b715d33,
+        0x255709796e2d7421,
+        0x1c2c193d8af28a4d,
+        0xb3b6739d21239d76,
+        0x7fe752b57a140b88,
+        0xb297b11b342f6767,
+        0xcb3b6f885fadd5ba,
+        0x0c8fcc225c464c96,
+        0x914cccc4dfa29c92,
+        0xa3e9801e8e44140e,
+        0xfe740d44bc534b8f,
+        0xbe626faa259433f3,
+        0x438121556e766be5,
+        0x0d453720b315ff2a,
+        0xf9f88acfa7a815bd,
+        0x6cf2e70541446e93,
+        0x479cb99937b9a1af,
+        0xb6468c908df4eba9,
+        0xf4f7117dc090601e,
+        0xb571f70141acf250,
+        0xd950ae41ca08fc98,
+        0xa22bd38d843bc8d7,
+        0xd58016e255925533,
+        0x9f9943d746f6240d,
+        0x6e7a50730aa6f436,
+        0xc5346c630ce1aef3,
+        0xe243f598a6e1c526,
+        0x85c24ec50b497867,
+        0x6d2ca752a5c06010,
+        0x420c61ad1478e782,
+        0x0d0a6ee40504972e,
+        0xf2e141eb494423d5,
+        0xfd0bc9a2754767c6,
+        0x3e92c79c6b46542c,
+        0x0565c93f867fbf07,
+        0xe884cf8dd066ea6a,
+        0x32ad0846ffc8621e,
+        0x9b03964d4e3243dc,
+        0x240bd88946488c74,
+        0x9c62f00cab6d92a9,
+        0x2902bdd01b9cc63f,
+        0x26d1e35a417826b7,
+        0x0396ee472296c118,
+        0x0b6a0e77dcbc4a47,
+        0x348661830f03c77e,
+        0x2b6c13dff5cee0de,
+        0x701964cff8a3fa4c,
+        0x153d95255506e5ef,
+        0x2ec2a104e98beb6c,
+        0xaa8699747ef89749,
+        0x94d860225ca027df,
+        0x61943de4ab19f1d4,
+        0x47ea9a38023ea545,
+        0x96ad83d11c6f437c,
+        0x7bc6e2520b72c55a,
+        0xeb7323f00c501dd1,
+        0x6e0465b44efc8f76,
+        0x92ad00f68e1888c9,
+        0x4735326b4eabc61e,
+        0x2094f7177534fbba,
+        0x67fae1b20c6c1edd,
+        0x53c2614559d33537,
+        0x78830d4ca407b00e,
+        0xf560c98f0e698d98,
+        0x48905373b1bf8391,
+        0xa066314f0f5aef8f,
+        0x26ba50908002a131,
+        0x2d00c39e20c48aff,
+        0x668dbd200814f436,
+        0x020735b136ebb948,
+        0xdee05062f733f0ca,
+        0xcb9e699e44e97b81,
+        0x90eef23bfe3b129b,
+        0xd4ac6eb4b07cf70e,
+        0x4192419def379519,
+        0x8079983f12a4c151,
+        0x914a39f716f65d75,
+        0x287748200b499c5d,
+        0xa77284015e991e5e,
+        0xdc35cd6920145da9,
+        0x645fbe72bad4ae43,
+        0x7e2ff74f7d94a792,
+        0xa4071b6f382db31e,
+        0x722bcbc293226e6b,
+        0x46b8a86ea4bc6e8a,
+        0xf550ce0cb3b91a7e,
+        0x1dd0772921d81d92,
+        0x3cf04c4a93c47143,
+        0xd0b3ba1902177193,
+        0x56c5f1ee80342799,
+        0x8197def1fa35ccb7,
+        0x0a2d3d7c605804f4,
+        0x95d41becb32450fe,
+        0xb724e97c8111b88c,
+        0x6fa4a9c33040e48f,
+        0xa3c462f03e25b9c8,
+        0x1172ca328c3729d4,
+        0x406561df84a4e736,
+        0x3bc80503f4e7e0b5,
+        0x5d9f9c42f76bd628,
+        0xae7bad4ec08e0687,
+        0x580377aa034f2dd5,
+        0x364b12da41cf5bb1,
+        0x4327d787e96082ec,
+        0x55803bb03c82cfa9,
+        0x591941ed9d788d8a,
+        0x381c6706b60e78b2,
+        0xd9f3369689f93be7,
+        0x25964e6e79fa0f05,
+        0xd781788af1b2e8d2,
+        0x5ee27c6f3fc18300,
+        0x4d48dc7e92c9ee26,
+        0x808099022cb191a6,
+        0xd26af3e4fc055f6c,
+        0x7f5f6a909092019c,
+        0x7a8b97b7fa5cbfbf,
+        0x4448959ac270b3a3,
+        0xe149b3471dfaa090,
+        0x4a240202dd04489f,
+        0x767d6cb76f6524a1,
+        0x32ace1817d5a3967,
+        0x1222dc48a032e539,
+        0x6b9e5a59bbcb815e,
+        0x48ced5787855436a,
+        0x2c6f2a99b51c9558,
+        0xde9745b55ed44406,
+        0xce4c613a725b6a67,
+        0xd5f5ae55a5b2e12c,
+        0x58b6a4532d076c60,
+        0xc7ae27d04d85793c,
+        0xbb324a5b55cbe94e,
+        0x43d81d6c9338306d,
+        0x2083832ff0ac4a05,
+        0x39e2846422c315c4,
+        0x94c596d0a31de5d8,
+        0xd0e77aa919ff90d0,
+        0xe770d8a760372bb4,
+        0x9d87c1529bef2680,
+        0x97c5df27cdfc015a,
+        0x32c13203326494cb,
+        0x9871c08a9b55c94a,
+        0x717e6ba9891ec56e,
+        0xa33303626037537e,
+        0x7fef947f062872e7,
+        0x2fd2d02e3439832a,
+        0x21e31d2c816ef3f7,
+        0xe9c95fa73cf2e5a5,
+        0xfd339187cbb57499,
+        0x9c363b75f1c6e48a,
+        0x4d0cd3de7d4d2893,
+        0x1f9cc21dc14a32fa,
+        0x12fc2bc5bdc0cfba,
+        0xb876bd88cfca7ecf,
+        0x6cb0b91a16139762,
+        0xa89b6d5df928ca8e,
+        0x746a36c46b4c808f,
+        0xf723457a9abf059b,
+        0xb74e32a2dff94efe,
+        0xc7a6dc493e517947,
+        0xff24a3bc46c7533e,
+        0x1f2897f8ceb30e3e,
+        0x83e6a932705561c0,
+        0xc011a2ffc7d72432,
+        0x83fc3978b12904dd,
+        0x69bf4e47aaabd017,
+        0x2f6da0bc7c193770,
+        0xb849f16c00f83473,
+        0xbfb5cccee3e27773,
+        0x16b9a0a08686a7d3,
+        0xabe40e16a9aebe4d,
+        0xb4c2ca4c19ff8cf2,
+        0xfb8f65709349505d,
+        0xa741593b08443145,
+        0xf10e97f1564d2a5d,
+        0xbd5c6870e1cee360,
+        0xa3039ba6a9290b4b,
+        0x6a5be25b32a2b8c2,
+        0x84386abebdf84cc0,
+        0xde4656c6acd2f641,
+        0x9ca140fc5a1aa06f,
+        0xb60995f132accdc2,
+        0x2c0eead2912ab106,
+        0xbfaedc39d4918316,
+        0xfdf393b98e301320,
+        0x07d800710557e8ea,
+        0xa627ede662f23780,
+        0x6322d06eff8532c5,
+        0x7b04301d48cbcc7d,
+        0x475c82bb651ab94d,
+        0x7a4b2e5673910887,
+        0x452db2454d63f09e,
+        0x652a28d25608e527,
+        0xb98f6df879bddd5e,
+        0xed3d24e8efb7033a,
+        0xa7789b81e125fb9f,
+        0xcf35c0122f8433b8,
+        0x9e9d4f7537c9f7dd,
+        0x35abda5b20470996,
+        0x20a3cc018cabeaf7,
+        0x7c799f8fb4dc1e41,
+        0x2611b951694a76c6,
+        0x306cc60f41dbfea4,
+        0x3a8feadfbbfda972,
+        0x525f96584da1f4c5,
+        0xda1d66ea2b5cca65,
+        0x8d04987d4f930192,
+        0xced86f9a231c4400,
+        0xd6ac910acbf0bc58,
+        0xd8c29d0e035e493e,
+        0xb536cd61a0e47a51,
+        0xd279a5ec4bf217de,
+        0xed54425954e1ddac,
+        0xbe04f18162cc6688,
+        0x50f48cee5b4e4ea7,
+        0xb8528d0747317f88,
+        0xd3512888883e58b5,
+        0x84fbbfd71f5f480f,
+        0x243dbd3b10352b78,
+        0x1f1bcf1db2ef3f76,
+        0x5e6b98eb69cdd66c,
+        0x7a6c711c9849ea22,
+        0xad367050fb54f0b2,
+        0xaffb01015edeba4c,
+        0xc3e36777fa0ee578,
+        0xb24961ba13834632,
+        0xcc25afea1355ce27,
+        0x4362169c3ac3d3c9,
+        0x6d60ca1fc8a27fd0,
+        0x4279635450fbffb0,
+        0xd3f2b237e1b8dd50,
+        0x2c26dd5a8f63d4d8,
+        0xe138b7c2d8bcbee9,
+        0x99b2b712b542ab37,
+        0x914b0fd90ab1db8b,
+        0x17ad45a65abcaacd,
+        0x058e0c9edf9fadff,
+        0xd085a755081f19ba,
+        0xbdfe99f8a7caccac,
+        0xf482a9756f6f44e5,
+        0xec9d8011728cb8a6,
+        0x6cfff34b4f3e502c,
+        0x52b6ec03d8ed12cd,
+        0x9d6fe184c03eeaaf,
+        0xff7eb772a061339f,
+        0x55307ddfce47d879,
+        0x0e97269af9738502,
+        0x59ffe279e540241a,
+        0xeaaca5d449193bd8,
+        0xb95fce5015aab65f,
+        0x8187824b3b4c6e83,
+        0xe19044f674e92b7c,
+        0xd7c5cabef6c4a7a7,
+        0xf9c9c581f830ad23,
+        0x70acb51eac74583b,
+        0xc17253cd8e959abe,
+        0x5d9578108a6b447b,
+        0x4119458192d7575b,
+        0x946c43bbe626c1e1,
+        0xee6ba73c6239b0b6,
+        0x0d37e3f7bf047d1a,
+        0x60301de36ccfd474,
+        0x90b9056e0f1f9db3,
+        0x6ffbd21bd321f373,
+        0xff9791702587111b,
+        0xafa88dd1f52439c2,
+        0x96171a3b11ca4478,
+        0xb5bf19a524b671bc,
+        0xb7da1214f2220ce1,
+        0x0719a0654b64e037,
+        0x2ae3035e24927e8d,
+        0x71cabfc908aa04ff,
+        0xe089fba8f591c3ee,
+        0xe1b2122d55db4eb5,
+        0x960640eacab389e3,
+        0x68212d2ccc7c7441,
+        0xafe4f79f6e78bb07,
+        0xc73e1febfc4ddcc7,
+        0x7ac5d77c997f1b26,
+        0x501e8cec6160ca97,
+        0x41c64b01c5eb10a0,
+        0x575b6a049d7cd0e5,
+        0xcea6ae24f6d8fff6,
+        0x69f3eca0862c4eaa,
+        0xf112ba9831e5e9b2,
+        0xa2f3b10c568779ac,
+        0xa384d91bb531758e,
+        0x05d3781ab57ae3ce,
+        0x9978ff3027492080,
+        0x760680c2f42a3790,
+        0x777aeda15e59b25d,
+        0x95fe355a020644c8,
+        0x1f175bb60521adf5,
+        0xe4b138c2f7d3dbd6,
+        0xb1f0bc5786d75204,
+        0xe0fe5d720f482449,
+        0x8c5350c8e926624e,
+        0xfcf88a0b5be4659c,
+        0x918f4a113497cb8e,
+        0x78ec4c5cf7bc5371,
+        0x21ed831529a1abd6,
+        0x21c17b53550670b1,
+        0x3d047be1f7e29770,
+        0x42df4d6005dc6735,
+        0x457816fcc207aabc,
+        0xe15e9b38e63912da,
+        0x3e7bd30970e2c9b6,
+    ],
+    [
+        0x79efe5213b75b4c4,
+        0x54753b22aa9bd1e6,
+        0x854dc380ed89a285,
+        0x494c819aaa70ae99,
+        0x58a26afe7c6483a3,
+        0x0da3b35e69fc1fa0,
+        0xbcb94d87402bbfc8,
+        0xf8e05ec569659a9c,
+        0x797aca98c08f4f16,
+        0x7f11847732282f54,
+        0x6b5a4649572b2f19,
+        0xe5c057f4429027dd,
+        0xe0099fdfd7a4298d,
+        0x94776ee4e24974f2,
+        0xca82b6a90a6ecf98,
+        0x326ffa4ad9f63820,
+        0xe50582b222a58b4e,
+        0xae01ffb07f41544e,
+        0x2063c3bdc6183195,
+        0x3609df7c4ca725ee,
+        0x48383dc8838b0644,
+        0xa20017ecc97ceced,
+        0xd8eafb420eb51dac,
+        0xd69e007ec6f11a13,
+        0x26a148306824803b,
+        0xb5658a0ce7fb133e,
+        0x19dc7f670be08061,
+        0x40f6de38cc646fa4,
+        0x153f772d74b4be49,
+        0x16ab2d7ee8e5d74a,
+        0x0ba18b1ba79e246c,
+        0x4343894b5115beb8,
+        0xa528986b841905e9,
+        0x8abf77702832e4c0,
+        0x86cc4e8ff1e89ba7,
+        0xf77c5c0332189040,
+        0xc9ed86ab77ea1fda,
+        0x919ed867f6c8e6e9,
+        0xe55583a88f0875bb,
+        0x589fe9569541513f,
+        0x019ca71f65c4975d,
+        0xe0a4b1e440c3597a,
+        0x0b7b8ea6e361d794,
+        0xccca5104c9ca1b46,
+        0x0e10015cfca01d32,
+        0xbf40af81b7a2c482,
+        0xb89dfc90051a4721,
+        0x0a0b6b2670899331,
+        0x07154d51676c5991,
+        0x1785575229cd93a1,
+        0x936fcc49b3ca70ab,
+        0x4fddee97fdb1a9d8,
+        0xa743eb9daba110c4,
+        0x879ae54c3116252e,
+        0xe54dd5f799b6616c,
+        0x03040f11bf80774e,
+        0x9604f5848042ee08,
+        0xa99de5083165357f,
+        0xc7052c1c712f8966,
+        0x38c32c93b58c2c91,
+        0xc4727d013727afd0,
+        0xf5fc95fb5b768739,
+        0x10083f34010e301e,
+        0x12e35da39cf7b4c6,
+        0x324c7dcc2fa951d7,
+        0xe9265d01476d166f,
+        0x12f1ea2b69afdc7d,
+        0x3265922cbaf3e83d,
+        0x11982c328da9e99d,
+        0x44818430dc1fe8a6,
+        0xf25202e974682720,
+        0x5dc9165627ceea76,
+        0x55b325f9864a8434,
+        0x34b76d790549c5f2,
+        0xff9c0824e8b518df,
+        0x47743f84495351c0,
+        0x0f237ecf810e3ff1,
+        0x6d2b52e6613d03f9,
+        0x41a14bfb4d24b8ce,
+        0xf42fd63d2bdff356,
+        0xb35cf5e0b7ba0ab7,
+        0x5c37737d3e226444,
+        0x9ed3bda74088d617,
+        0xedbb67a43b828a73,
+        0xc25c50cb6cb17b14,
+        0x299fd224c54eaa86,
+        0x60f7b2892b61f99f,
+        0x761c29b6b46f94c7,
+        0xcda1bd153da20162,
+        0xea524364b5269976,
+        0xf0dc5aafe5e7a015,
+        0xd1cdeb8d2e582dca,
+        0x634aa70af09f9157,
+        0x25136def7ba6c607,
+        0xfe8ec03981acf586,
+        0xcfa6aeb7de48b9f8,
+        0xd35cbea6000bdc7d,
+        0xddf4b01f4c543556,
+        0x77007fbe591bb9f5,
+        0xc49cbc5502a8c79d,
+        0x95d828d711fcbe98,
+        0x9c6d2d482d49df94,
+        0x21e5528cbf6249f2,
+        0x3d5e28b042f11f56,
+        0x3a49fb149858d737,
+        0xcf7394c79eeb2a50,
+        0x7b4ec642b5ea8da4,
+        0x99031f8edc532f21,
+        0x46435a4fcef73a62,
+        0x380779becfc27f37,
+        0x3d2851d40cf07bd6,
+        0x41db1b39f78811ac,
+        0xe47fd418258a2d20,
+        0xc334207efd65ec79,
+        0x44a180ec41f4f2df,
+        0xdc0deebdd19395ea,
+        0xeb07a4e1ad615bf1,
+        0x2c8096648e8d9364,
+        0x7f6646fb99621415,
+        0xfc30430e4649f249,
+        0xdcdd01f8e54c827f,
+        0x2fab2fc5191251bf,
+        0x6ffa473183d75f1f,
+        0x8436b58aa5144c0b,
+        0xba6c291614ced13d,
+        0x7836cec7b79f0f05,
+        0x67dbf386e4dc562f,
+        0xced25b4258beadf9,
+        0x81cbb2b58e89dd0d,
+        0xfa824a44d799968f,
+        0x368e8dffb0a936a6,
+        0xd016373edd4e3b25,
+        0xea228418ebcbc290,
+        0x6c6cf6711ac7a0fc,
+        0x996abdb2d34b3256,
+        0x5dfe485e582cf6c4,
+        0x2933f130e37f2269,
+        0xca6e3f724c893117,
+        0xa6b11bc2f299214b,
+        0x367a31abc5dbb193,
+        0x8cf2eaf7af4d8b29,
+        0x1d87238ec8da429f,
+        0x50c2d9b60ece80ff,
+        0x320d7123df6949eb,
+        0xba707bffa726cee4,
+        0x117259858b137f7a,
+        0xad3ad5ddda4d37f9,
+        0x26c5b6190c591b33,
+        0x1b697c599b1e1ae2,
+        0x7b639d227397adfa,
+        0x54dd41b28aed8c5e,
+        0x58ca9cb31c152490,
+        0x9f94aa1fb9f9a016,
+        0x08c02ef50dd67ba5,
+        0x3e03e5c7a3da09b3,
+        0x96646f27b1c188b3,
+        0xb95049595ccdd11f,
+        0xb5102ae099f59708,
+        0xb6ca49a0e70a841b,
+        0xfe980e20431039c3,
+        0xa16efdaa75ce53d5,
+        0x5a966036be7ab12f,
+        0xce645c4cae8291f8,
+        0x849cc40e069e2029,
+        0x08463181bf4a390b,
+        0x54b50e1eec5178d6,
+        0x30768a0846807bf9,
+        0xf1f91ec2a0381847,
+        0x4d38bfdef0ae922c,
+        0x67c968725254e048,
+        0x3889265f3338f6bc,
+        0x33108ad6fb6beaaf,
+        0x651a38f181b9918f,
+        0xaf25b59ef8a50336,
+        0x9a48dc70ac851cb3,
+        0x07713b6e0ecde950,
+        0x2ed8c1bf5573faf0,
+        0xf4b0bc998c3f3244,
+        0x72fccd35038010da,
+        0x1747b702e286c0d5,
+        0xc46dd3e3c3ebeb71,
+        0x88e863f326e870ba,
+        0xe91b479a7f9a2fea,
+        0x3f138728d67599e6,
+        0xe0e5e8a58ccc32e9,
+        0xfbdc53de2c37f022,
+        0xc6ead35a618fcb84,
+        0x2baca92b822b2ce4,
+        0xc4f639eb7623e19c,
+        0x2374064219a61322,
+        0x0fd16b5eba1919ab,
+        0x55a06c861f9453a5,
+        0xec1ac4326385b2c1,
+        0xc08958452772b9bd,
+        0x91f3bcdd23169791,
+        0x31cc2fed2e1a5898,
+        0x4a175220e4792986,
+        0x634668a612a2fee3,
+        0xcf452796a0a1ea8e,
+        0x63f073c21b3aa873,
+        0x836c3bc40777cb75,
+        0xbe2cc6b3a242af86,
+        0x2a55590169da5722,
+        0x4f89bcf4bcd7e494,
+        0x442f3369b3a56179,
+        0x32ca02b3fc75eec1,
+        0x5d16fc8c0d470c94,
+        0xcf9fb9ee32279b40,
+        0xd34661f1be1b4128,
+        0xfdbc7f5e57f43c30,
+        0x85c2fba223bbb79d,
+        0xfb63c0cc22f4d609,
+        0xdf541ffe40f01c8c,
+        0x7baa99e3bbaa631a,
+        0x7fd54bba76f4ee9e,
+        0xc3c9d5b09b382bd7,
+        0xc0f40d4687467362,
+        0x1ed3990316faa158,
+        0x6cce9b7a9f452931,
+        0xb2de0ac18d32012b,
+        0x57255702d1fedd18,
+        0x2b9871a677386b64,
+        0x7cea1209f287bfb3,
+        0xfc826b48246bcaaf,
+        0xc3b2a40c9a7b2118,
+        0x374ffc391eac6e8b,
+        0x8450af70eb7c398c,
+        0xf8f02494a34cec70,
+        0x0ac70cd07b4379fb,
+        0x61345a31335e3583,
+        0x6cf1f0decdc99cbc,
+        0x2fb7d7541f983d92,
+        0x8dd7ef033f1cc5ed,
+        0x736d5ce8b868886f,
+        0xd55884f1f44ac550,
+        0xe1f47ba5c2550d8a,
+        0x362fcddb09973435,
+        0x8cee3841b3d939d9,
+        0xa5c1464f500b0a2d,
+        0x9b1b5b88a4f749fd,
+        0x0f8dac1489c7256a,
+        0x208941bd83d00b88,
+        0x72efe060124ee9b2,
+        0x0690a04161344421,
+        0xaaa3c2adec77a666,
+        0x3f75a96587d4126e,
+        0x284ccebebc6ddb6c,
+        0x39367e795cf21c72,
+        0xf66afe164a6cdb3b,
+        0x81ef27b6f0068901,
+        0xb615b7489a7a9e80,
+        0x244edaa246a627f1,
+        0x77810c77b55c720a,
+        0x1b6e3a36258843dd,
+        0x61ffe0257b46ebc9,
+        0xc9b1fcb3e8e3300f,
+        0x991f62ec2691bfb3,
+        0x743a350fc08b7f55,
+        0x649fe937537f0d49,
+        0xd17567cd8d7ee18d,
+        0x21703534726f700f,
+        0x81811eb95252e4f0,
+        0xdf008fcc86e42ddc,
+        0x79fe32eddf44f703,
+        0xb63e9032435145c2,
+        0x8eb462b2a7ee4481,
+        0xdef9b5abe8f501c2,
+        0x8c962f4103a70a6b,
+        0x96f573d545c97e6f,
+        0x4a4f3e5444cafa48,
+        0x57ad083d6c60d7ab,
+        0xf45ca2d5da75c0fe,
+        0x5964ec907266adb6,
+        0x5cad854e9aa00c12,
+        0x3fb883ea6319c130,
+        0x26bc2558d4ce68b2,
+        0x6f52c6fab13cdcc5,
+        0x10e3c3e689e06d69,
+        0x135601a55a8805a8,
+        0x8a7f8c0d9ba5b94b,
+        0xe181a597de23ac2b,
+        0x16daddb7ad853960,
+        0x091735a71c1eb0a0,
+        0x5de1390aed9a419f,
+        0x9a7c8deaee212654,
+        0xafcbd20d74f36848,
+        0x020540bb98ca99a8,
+        0xc83ddd29bf68c768,
+        0xf3c724348343d14a,
+        0x5b439423df9abca1,
+        0x50f854ea091fe4b9,
+        0x363a86225fc21c7c,
+        0xdf01b4be49d8e943,
+        0x67efc6a8cd1aff20,
+        0x259a52ea050701ef,
+        0xf617f5ebd3fcd534,
+        0x1a573993ed37fd2f,
+        0x4a96fec4f2dc85e4,
+        0x28ad2707103da43e,
+        0x6c3b81138c428e8a,
+        0x91d34c1e3e1d4c48,
+        0x60c77d61b4c18b9e,
+        0x77a40b9b5adf1f26,
+        0xcf2770e153d5efdd,
+        0x06b6ed17c4438fc4,
+        0x55683877e47a08e6,
+        0x985b007e006146c2,
+        0x286ed2ac67014f17,
+        0x5bc0b3bb6e6388c7,
+        0x496e1069980a4f49,
+        0x0e23d4c18ceda067,
+        0xd447777b19c1bfcd,
+    ],
+    [
+        0x8f9b78436980f455,
+        0xb2b6c790e06dc37c,
+        0x3b994daef41d2100,
+        0xf7f98dbfb8d4e77c,
+        0x169f80dbc3f14411,
+        0x4a429a1306944a21,
+        0xce24a63804c01851,
+        0x8f3ec1f52687b9d7,
+        0x8f9280f650cdfc8a,
+        0x8e1574e7c0df5ba6,
+        0x4636ce506cab2b7e,
+        0x865b50080c7305cd,
+        0xd2eb16b1f73293ed,
+        0x0f0554e139ae92ef,
+        0x55c1d30632c1c4b7,
+        0xb4dedd331988884d,
+        0xc8625974d69fd671,
+        0x21e94020204c2878,
+        0xbdd792ae9190bf6a,
+        0x0a29f0dfe1d8afe0,
+        0xb60f28ceab9e35e3,
+        0x547fb128b2231904,
+        0x3c391c96fbdb9080,
+        0x5a2a5192fdab7f40,
+        0x247c0bb8e5cea42c,
+        0xc99e78f3f1e4cced,
+        0x7c2ed182d1a4c1bf,
+        0xa7c4ba7860d446ae,
+        0xbd726482342cd97e,
+        0x8234aecb3a060818,
+        0x15b017cd2ef5758d,
+        0xcc9ebc013ced688d,
+        0xb789cf068e02ff38,
+        0x58dd1595593b01de,
+        0x2d09a2d3c4df83cc,
+        0x056fe8f767017291,
+        0x64bd6a99d8561320,
+        0xe4fe3c9377339474,
+        0x110b1795aef00fd2,
+        0x8e2a4d6f74d33ee0,
+        0xd63833b4db47320d,
+        0x70c84d48f6d07ee5,
+        0x7aa8480125dd9cbd,
+        0xd7fa1ea1bb34ad41,
+        0xf5603a33639b0f82,
+        0x24ecbb04d0eb0525,
+        0x78fa0ff7a1d237ed,
+        0x1656667b831a0f99,
+        0x22c852748ddcb1e5,
+        0x57e333865066b0c9,
+        0x8c071f1b133a56a0,
+        0xceaf4e0643879cd0,
+        0x43a2a1b1cf1df6b3,
+        0x0ede137965adfcde,
+        0x9a355494992b7533,
+        0x80fc4e7fa8b44fc0,
+        0x0f0109fd3df40475,
+        0xc2d9edb3db307f96,
+        0xa5d3373c6a9ba194,
+        0x7a94737b9da61db5,
+        0x82d7aad33ae7c0eb,
+        0xa8cc7e2f8e498a95,
+        0x6504705abe702f69,
+        0xedbdc55f81e79c24,
+        0xf82616548be1a44a,
+        0x1cac81f8ab4e442d,
+        0x125323504cfd1a31,
+        0x454a64fde0222fce,
+        0x184f60c053708ebb,
+        0x1ca84bab1e0aa4ca,
+        0x049d56a1eaf6697b,
+        0x0d46665e89db8405,
+        0x9e15d6feb402c3ab,
+        0x6f0063672effd90e,
+        0xe076228956881c64,
+        0xf730a52afcd58386,
+        0x548838060a538acb,
+        0xc928dda15e0befa9,
+        0x566130169da8f4ed,
+        0x89c4ca90ac06a680,
+        0x493cfc3ad6c0b6a1,
+        0x77934c9187e0d39f,
+        0x23640dc0d337fe57,
+        0x470becea05bcb1e6,
+        0xf635ed1714214336,
+        0x7ecb9ed51f9f1a7a,
+        0xb3d9218e4ee7bd71,
+        0xa49d54c7f363a56a,
+        0xd3a1f53fce364eba,
+        0x49e790b763337280,
+        0xf15a8a94df3c7236,
+        0x778f9a58210ae2cb,
+        0x5d06da5c17730be9,
+        0x15c33e8cc786f50b,
+        0x5b2cfbb2b5d98e84,
+        0xeb04743efa75bbdb,
+        0x06c2e78bbd16aef3,
+        0x5878cb25440a3057,
+        0xd65b5352a1b50c2e,
+        0xa17b5e00d57d4c2f,
+        0x68b47fb42eed9b6d,
+        0x02fcc31c968be0a3,
+        0xb1c14f598f2da8ca,
+        0xff0999c1d9fbac8b,
+        0x60983a0bf595f368,
+        0x2255100f383dec11,
+        0x27d297999d32bdfc,
+        0x413e901911374406,
+        0xbc7aa0617dd91834,
+        0x92e0e9d38a16df2e,
+        0x288dc5da261b473d,
+        0xc7718feba2f25e8b,
+        0x290b3a04177d1f62,
+        0xc5e7699cb1d4d672,
+        0xd1815078d289c50b,
+        0x0daa8b5d6a1cd855,
+        0x4cf623b18939765c,
+        0x9f73d7f6f20a8a15,
+        0x4aea95463b71dff3,
+        0x235a786bc0066464,
+        0xfcccb2e1f4d76af0,
+        0x13fb240610db3d89,
+        0x2a725a79065bf656,
+        0x6e1f9b4de76b73fd,
+        0x7275be2ac82aa42e,
+        0x55d3068843f5ff12,
+        0xb0e44bfde544a059,
+        0x88aec9b781f57575,
+        0xd51e2365fec2eb8c,
+        0x8470b843c2efa853,
+        0xc9acdb8a4bab94f6,
+        0x48c79c80c36d738f,
+        0xc161a43e68f5b205,
+        0xfb18d3c71813b87f,
+        0x7096be378f328931,
+        0x777205ae013db188,
+        0xd1a837df9e275575,
+        0x597b507aa1f0fc07,
+        0x501b5f5da787f4cf,
+        0x5ef1ed896cb459df,
+        0xf2d9f73cd53e7d4b,
+        0xa80d8a6be559bccc,
+        0xd93593bff08943ad,
+        0x0f53c6d598e262eb,
+        0x67bbbc9edbfb090d,
+        0x150d7c7956fe4053,
+        0xece9d674cae2bc66,
+        0xccba6a6ddbd095f5,
+        0xcb41ecb2fb6a03b7,
+        0xd04d7ff5c3041d22,
+        0x772660f6bc9fcf4f,
+        0x058588c6659c465a,
+        0xf636491a5fb29246,
+        0xd56e45ad80f1fe16,
+        0x8f70832c8093a76d,
+        0x69b9f19bfc0034c5,
+        0x90f2ff64ed42bf8f,
+        0xde8ff477e566b948,
+        0x6d34f2a75833ab25,
+        0x0a477fc7e03fba49,
+        0xb091cfd9dd472834,
+        0xb24c027fb51f5444,
+        0xa8097ed0ba7155a4,
+        0xb18f3b4402a696f4,
+        0x8d263bff7e133793,
+        0x9da88809ccd3fba6,
+        0x7ace71992d2c873e,
+        0x6ec85a6f37911e29,
+        0x36e078ebc01415f4,
+        0x034c0ebf5d6b58f7,
+        0x0252339470de1ba8,
+        0x45c7685e1419e052,
+        0x11b4253f81c2f2a9,
+        0x5c4074ee6d80a6a9,
+        0x9260e34cdfc340e8,
+        0x4e633969d79f8a2f,
+        0x54cb6935e9391b2c,
+        0x5f1d43bb2ffe034d,
+        0x6808268d385a54c1,
+        0x79ed3a0dcc7f790c,
+        0xd558abfa59ef533e,
+        0xfd6b625c5f4a9056,
+        0x8fb9bdb5bb703fc5,
+        0xe8701b78e98f283b,
+        0x10cb59fe6cb64c2c,
+        0x5a2844eab2fbff39,
+        0xfc45c687642ea49f,
+        0x76a30e5e8a33c6cd,
+        0xbf7708716b939e84,
+        0x6cd1cefe73153604,
+        0xa015e587c4adf706,
+        0x4c2a1ff8cd0a7050,
+        0x9cb0b8f159aeb9a7,
+        0x769e2a4b957fb310,
+        0x2f3ad6ca2dda5043,
+        0x9d61baf10cd63684,
+        0xb5d116eeb1b789af,
+        0x57cd0d5af518ebea,
+        0xb59f9b42edcb2564,
+        0x3962ee953ce86727,
+        0x0847b421bf39d38d,
+        0xc8e1e3e2834a21f1,
+        0xc59f6ec0611c0a08,
+        0x42c09b8a3cdf0b0b,
+        0xf1256c659c586e32,
+        0x83f0d09be27e7c3e,
+        0x7a46a4a1ab31c7a8,
+        0x282099aa65274fcc,
+        0x1aba6315e1c35b8f,
+        0x780b29c5425126e5,
+        0xc3fe03a52aaf1b06,
+        0xdc29bf1914fdcd30,
+        0x52a6ae08ab67bc50,
+        0x84bf159659e4bed2,
+        0x9c6641610c2d03fa,
+        0xb39d7bd246e1564c,
+        0x52eb894d68eb82dd,
+        0x807d4c89918b9a10,
+        0x007a24b43fabca0d,
+        0xe0180979cafbee80,
+        0x80ebd19ede175a1c,
+        0x0218dd7a73b27622,
+        0x512f2af9e2e1ea81,
+        0xa1b568a003fb85d6,
+        0xf4fb1057aa3807d2,
+        0x1863f4ce595f0747,
+        0x54c3a70b8839f307,
+        0x05a1527a6504e1e0,
+        0x25a36669c74a5d4d,
+        0xe18fa69b6c0c402f,
+        0xea80d147ef995b80,
+        0x08bddbb88e8b6588,
+        0xc04d6021f3011322,
+        0x134d16f4bef70938,
+        0x2fed88c58ecc97dc,
+        0x9cde2fcb88c046e0,
+        0xfd8ed341760d39cf,
+        0xe45022fc86773741,
+        0x450d6f1bbc9dcfca,
+        0xf1d1bbf5392cec51,
+        0xcae57f2032c2a43f,
+        0xa3b5bb6c29ffdeb3,
+        0xc2f14a7c1e51a53a,
+        0x919ca763cbe564b6,
+        0xf19b46adf60909d5,
+        0x366f9ef0818aeca5,
+        0xa11616c3758793ab,
+        0xc36aecf1e56557fd,
+        0x0161f2679134ae7a,
+        0x23c1b510fe460280,
+        0x1a74449495016f93,
+        0xd64c403dab5fbbe9,
+        0x112690446baa943e,
+        0xcad417ad1fb7514e,
+        0xd0b6022a7faee699,
+        0x5981b16b33313e45,
+        0xb1d5e6d1bfc39eed,
+        0xe4337d164e87dc83,
+        0x896d09f312ba701a,
+        0xb8d4789111333d33,
+        0xa48213a285d4ecb9,
+        0x0a329cf0b09cd197,
+        0xb7f03567730e083a,
+        0x8656edd6a8542db9,
+        0xb40cad8a5de8d85f,
+        0xf3df714a5ec93d0d,
+        0x89b9e607270aefde,
+        0x008853cbe05ab296,
+        0xedc67398ac3504fb,
+        0xf8ecccf7358c0fa1,
+        0x6ddbbd94b495fbec,
+        0x4c5f7bf663c8f287,
+        0x4b883c7761895771,
+        0x1b594454042ad13c,
+        0x0365c661c57ff7c7,
+        0x22aa4bd8295b5a4e,
+        0xdb1733ddc412da1e,
+        0x0d29176798380922,
+        0xf1a5493bbea4f27c,
+        0x87e66c5d1b96e766,
+        0xce96cab80848ccc6,
+        0xa822492a565e67f6,
+        0x2a28452828693d05,
+        0xd04c53ff30ea52e9,
+        0x335d59046b54f1a9,
+        0x8ab758c049041a49,
+        0x9aabcb7148d3a3c8,
+        0x56a3d37c1912ec3b,
+        0x783d5b4a36d4f80b,
+        0xf0a4bb894a02899c,
+        0x246edc17ab5c9792,
+        0x410cd7a0f30a0cb8,
+        0xcd77d849b3fe246b,
+        0x31b4f3da3186676e,
+        0x6ae0bb23e10b021d,
+        0xf9501518a46ba483,
+        0x27a601b6794f3ea2,
+        0x831c2f6255edd60f,
+        0xbdc2c07727a17a42,
+        0xac819269206d98ac,
+        0x35dff1e2eaa5aeae,
+        0x3596cdea7727cfbd,
+        0x405229c202b51273,
+        0x13ef78b82371f5ee,
+        0x58835d5d7cb93357,
+        0x8e0daa550fce0839,
+        0x2d6602ae6549edef,
+        0xba7c40d3c0b99b1f,
+        0x1e0535f5cf1c8bab,
+        0xa6c8bff923140292,
+        0x3cf14c701cc20e64,
+        0xd235902f4253ba08,
+    ],
+    [
+        0x3479d0dd39f166ba,
+        0x62506e1d9d165c42,
+        0xd5a208b6a31e1829,
+        0x54889959f8c2a54a,
+        0x6450ad3c2d85a6f6,
+        0xd2b13c24e3076819,
+        0xdef72f8d5ac344da,
+        0xd45536a52e2e4e33,
+        0x96a69ae4534d3e2e,
+        0xa2f67647bdecb0e3,
+        0xb03b9478656618f7,
+        0xa11d3a28d20e4d85,
+        0x9f8b09ad8ba5d6ed,
+        0xba977d8558ddb6c5,
+        0x06ad8376bb2788b5,
+        0x853eac6dc7d2c0a4,
+        0x8d3257d66dd0aa0a,
+        0x9db0e8e22aff4f89,
+        0x7f95fc237d29992b,
+        0x9a8e0e63da6f0331,
+        0x06dd0057671dbceb,
+        0x96920d0fc7b2b2fb,
+        0x8087092f95f6ef63,
+        0x74765cbee44fe9be,
+        0xab0be622aad91618,
+        0xc3f5f06889c4142e,
+        0x653567bfc8403af8,
+        0x7c53ef75a9fc2f2c,
+        0xfb2f819c09f7a440,
+        0x7b41dfd95526bda3,
+        0x24416fd7a83a2bc9,
+        0x2485ee7ef7e35471,
+        0x7cef2c471baf78b0,
+        0x5e323b8fcf3ae6b5,
+        0xab9993f6a0a97c30,
+        0x5b7da42cacb69647,
+        0x43dba15041d38d2b,
+        0x8f3644a82bac0150,
+        0xf303fa46e3b12d8c,
+        0xe226b32315f5ba46,
+        0x096104f78b9e5b0c,
+        0xecda44042efc2c5e,
+        0xfb4085a822cf9f80,
+        0x7281df51939350f5,
+        0x6a5247119949c8f6,
+        0x8343a9efcda2895b,
+        0x4cc79ba120dcd991,
+        0x3d633c348a315503,
+        0xea689beae8f2a46e,
+        0x47be21cdb7e56fe2,
+        0x11484b7284b342a9,
+        0x9dd47c969c12330e,
+        0x48a0e44425406851,
+        0x3856393afd678c3c,
+        0x581d369d5fb40c28,
+        0x3c43fc27b0356461,
+        0x93d5f5258e7d85ba,
+        0xe49b42a6c4370aa2,
+        0x1217496d991429ed,
+        0x11a77f95f1309e6e,
+        0xc02e1c8516d41f54,
+        0x29511955db7732d9,
+        0xbbbf5aebf340ba09,
+        0x4d0df8aa8f79ec05,
+        0x434bba7c5747c91a,
+        0xddaa2ce42800749b,
+        0x91e4cd3b326a6646,
+        0x27248e0884a136ba,
+        0xbea0585cb9bed65f,
+        0xe7f59cbcb91cf81c,
+        0xc800d8a0893b8492,
+        0x361208065aaa06a6,
+        0x7df99f35524f8ad7,
+        0x5405616ea8e0c13e,
+        0x85852040436291e0,
+        0x7f7a6cfc89f71091,
+        0x367bc2d0b92e15fa,
+        0x2adad935fe8b6b23,
+        0xfa57f90b4ddc8c97,
+        0xa1f2e8ad0f2dd251,
+        0xc3aa5899bc86043f,
+        0x76a18a80027a9a13,
+        0xd004ed1162a437ee,
+        0x35f49d173ebeed0c,
+        0x1c5920d8b44e98a7,
+        0x3b46ccb6c9a3c2f0,
+        0x7340f44b6fcc0031,
+        0xd76c6bdc59dd0138,
+        0x586ce6495336619c,
+        0xbb90792e15b0aebd,
+        0xc904f389595f01b1,
+        0x6cd89f9f3683dbc3,
+        0x924566fd4e952e00,
+        0xcbb08528eef062b7,
+        0xdf3a2c8b2d43b267,
+        0x30d9158779c1fc6a,
+        0x037d309c7feb5073,
+        0x5db131940ba71a1e,
+        0x04a8e3f4e6becdb4,
+        0xda31bb8172f52b55,
+        0x7d294b29a0712cf9,
+        0xa15908c116a6fcdd,
+        0xf7f8436ec78e1b1e,
+        0x126ae0d9121b2316,
+        0xa3c4ffc66fc74dce,
+        0xd0700acee372e61d,
+        0x8939b6edc0faba86,
+        0x572ab1baf939757f,
+        0xd4198ec412d1fac5,
+        0x7dc756c0a9e46d1e,
+        0xddec8856a6d2df3f,
+        0x13c989ca2402779a,
+        0xacb845454e941818,
+        0x20eabe152a41ad4f,
+        0x2bcf91b687e637b9,
+        0x1c21e1ccfb61e09c,
+        0xe6e64f06205c92ee,
+        0xcb258163e5b969f4,
+        0x8e435c1f600512ca,
+        0xb8607b8c35402abd,
+        0x7090620fc5b0f366,
+        0x8e2501c24e2bd77b,
+        0x013ecd4d5d41aeca,
+        0x49c0c9b784b87392,
+        0xf28b7f15ad44675d,
+        0x8249716acfa7c945,
+        0x727a5e4ce4a76b2e,
+        0x31968104b94416b8,
+        0x91192d95e5377249,
+        0xb7c8cac389a9a351,
+        0xcd9dc16b39203f70,
+        0x74df90f5cfa539d8,
+        0x078c6a7368370468,
+        0xb7eae0f890043837,
+        0x588687716a62884d,
+        0xaa88469d1c1199e3,
+        0x546901e955c6fe9f,
+        0x90308ebe4b77281a,
+        0x789d85a004c2cdfd,
+        0xcdb0abc46444ed16,
+        0x5082508b17bd73c9,
+        0x1c34d6efbcbad5e1,
+        0x7be4de9ac693c362,
+        0xd56cefcdcfe8fa1e,
+        0xa311da181676bfea,
+        0x7c0de696c47e8fcd,
+        0x05879992cc2be29e,
+        0x14d8695993114aff,
+        0x76e97abebfda4027,
+        0xef0c596c29a685cb,
+        0x6256e27677010281,
+        0x1e4e189f7ff76290,
+        0x7ca25b4b717e7d1c,
+        0x534b5b5118613168,
+        0x7337ea4a62dd5fed,
+        0xfbb038527870bab3,
+        0x3486c528a7ed7bd5,
+        0x443618031723129d,
+        0x58f46c68d84db982,
+        0xdce7be1ee801be54,
+        0xc91392c3c3280bfa,
+        0xdaf909027260a8d1,
+        0x336affdc2824792b,
+        0x1765ff8b22e771ab,
+        0xd52186c8b6ea48ec,
+        0xba54ad87ccde6ee2,
+        0x2ac3b6b1e94f6e34,
+        0x0bc7b6c6e36eec64,
+        0x19bfb2bf075e8d95,
+        0x6b2c3bcb8eebf48b,
+        0xaa3c84656a25a013,
+        0x01452e2e3c1582c9,
+        0x79d1fd5f51428c28,
+        0x35c17aa010ddfe63,
+        0x9a73a843680414f8,
+        0xff7ea391b96b2d01,
+        0x7080cc9b52f2d8e6,
+        0x4226d8a6d29a3cdf,
+        0x36c5a3ee79d00a30,
+        0xe0d73d7375585cde,
+        0x2f3cebb154f91584,
+        0x107c672e26367d77,
+        0x96607a816c187c2f,
+        0xdd81c2b997510b92,
+        0xc5f7fc732d38e135,
+        0x54fb77f0fba56382,
+        0x550aa4269ad0d149,
+        0xae1b531e005efd41,
+        0xd9d88adfb4435e58,
+        0x09099dd5ae63eeda,
+        0xf19bef94d19b15cb,
+        0x98bf9d4a065cb3f2,
+        0xa8765d8f2a3aa5a3,
+        0xdf030790922e5fe3,
+        0x9612efe9368fdadf,
+        0x893b8de91d3fd58c,
+        0x51336d9f9425265f,
+        0x4b62aadc94778460,
+        0xcf00bdb6c13e50e0,
+        0xf2674a6c03328c58,
+        0x43a88f13bbc3d841,
+        0x2f8a60e59651f00c,
+        0xc85c52348971cea1,
+        0x0655c310b5918001,
+        0x205dea333cabec66,
+        0x8b16562de93808ea,
+        0x095f7ad899e7accd,
+        0x3be11210e82c4078,
+        0x616de10eef770194,
+        0xea600b42d913d4e7,
+        0xf101d5b74ebdd530,
+        0x802320e9a4b7e552,
+        0xde8554a0377780e3,
+        0x97278ee411fb275e,
+        0x87200a493d942fda,
+        0x0017cdd4b167bb27,
+        0x8632dcf21ef6ad8a,
+        0x20351223805f105c,
+        0x18b4abb6d5edcc59,
+        0x118df7ce01cfe153,
+        0x56536b43c057cd4d,
+        0xfbceab9cb0542094,
+        0x924bbe4552810d72,
+        0x09fa747ef9a04bfe,
+        0xc8e614996ba4435e,
+        0x60e3cb54029ab26f,
+        0x57bc66b668129cfc,
+        0x9d99aff8a9e153a0,
+        0x8c764be424f7ba18,
+        0x1c868df48745e37d,
+        0x8f03ad5622c4bb23,
+        0x62a77b410073844f,
+        0x1716c645fb79de6e,
+        0x2368022df2899f8d,
+        0x68391eeedf82d664,
+        0xe817cfadcab12d16,
+        0x75484844c62822de,
+        0x9ef842c212d04039,
+        0x993ce2ed926b9411,
+        0x78fc23000275b914,
+        0xd2c12ea04eddaf05,
+        0xb7d57188e819cc87,
+        0x14845ace2174b232,
+        0x95d15a2a15a2c800,
+        0x8278ab3efe1985c0,
+        0xb07f863c91763324,
+        0x44d9e42a3d7d1a8b,
+        0x28ed207af037c0eb,
+        0x5591c3bd0a58bff6,
+        0x23b2125bbc92e200,
+        0x3f23b925897f7a9d,
+        0xec752c8dcab90f20,
+        0xa617fb542d110a43,
+        0xf3d897c2b0b8658d,
+        0x582d5afbaad8678f,
+        0x5cc5764f90b63559,
+        0xce93299204655a73,
+        0x9755495b040171cc,
+        0x4473e60b5a0b904a,
+        0x8a804b37708d787e,
+        0x65176f3a1e42839e,
+        0xced83c1a698af1d8,
+        0xf26b5cb8a908633c,
+        0x8971a6c3d97f36da,
+        0x0fa664583b423629,
+        0x03113d9797cc14ad,
+        0x0b874df173e44f99,
+        0xafbaf8904d31e373,
+        0x33b315172da2d420,
+        0x88182ac677763426,
+        0x169759d903abc517,
+        0x7983e147b3bede59,
+        0x00d6e28ac4dd11ee,
+        0xf528b188643e659e,
+        0x76b9af94378fdffc,
+        0xcb8100cfce90108a,
+        0xa1fae46fe1d3b00a,
+        0xe6c5b25143ffd9ef,
+        0x2ffc2bccf7978397,
+        0x9b2fba470aefd67c,
+        0x7090cf92e2edf6fb,
+        0x6226eaeb408f5fe9,
+        0x69daf83dd34040e7,
+        0xfa1b3b1daf6f22b5,
+        0xf402d039d3574efc,
+        0x5cf426ebee571d81,
+        0x0a94bc1a659ca009,
+        0x320df63e7879ac30,
+        0x61b3a9d118dfd320,
+        0xf29e223ba2795c72,
+        0x11ab8c63521805c5,
+        0xd7e7475bfc1e28bd,
+        0xb427ee710ca0870e,
+        0x3d3a3082d911afa7,
+        0xc3c4ab742ab4acdb,
+        0x2cbf7305f07761fa,
+        0x748e85de52e8a9a7,
+        0x59f99473e2993a8d,
+        0x735427a557039390,
+        0xb79548033bae6e3f,
+        0x472666b7c274d60f,
+        0xf71a8dea0fbab974,
+        0xe568a6487a709269,
+        0x1a3a4a823874570c,
+        0x9d1cb7f2ae5652ff,
+        0x952568305a670276,
+        0x5ddde8fb461718ec,
+        0x8bfcf87ee6ea4d0a,
+        0xc54c7b59c0b58afa,
+        0x236806cb853794d0,
+        0xe3f6b68fa0479cdd,
+        0xa1557a0992ff0674,
+    ],
+    [
+        0x43a7d8c0c7817cab,
+        0xdea546d7cc9258f1,
+        0x804dc6a69de6b80a,
+        0xc863a45096800756,
+        0x33dd1944254e7014,
+        0xc932c0128aada0b1,
+        0x716af4797a3f5a7c,
+        0x9768172993361cf8,
+        0x9c1ff2ae0432023c,
+        0xd9dc654e5dd08065,
+        0x92812abdaa598d4c,
+        0xf019ee131c9bc506,
+        0x5919db64a9788ebb,
+        0x515d6fc6e9314f85,
+        0xb12d9660a8110413,
+        0x5394bc432e74e9c8,
+        0xf6195828f2adc902,
+        0xe397456c72baca12,
+        0x0c3e9fb70fdff8b4,
+        0x1f47db36d2d1b696,
+        0xddc42f79c9ef68a8,
+        0xd120e30a10b499bd,
+        0xcf474c92e22f8c96,
+        0xbd0c44bddc5f8363,
+        0xdbda821dc0e5ee2b,
+        0xd7e818ce1cbc78f1,
+        0xfcde6a782e81002c,
+        0x9e9b039dc028739c,
+        0x89e871882eff1d82,
+        0x749dd5266deeec64,
+        0xe03210e84dbc6caa,
+        0x0020ca9b62ebf9b1,
+        0x29444da14793e9c4,
+        0x1a152664bdb1310f,
+        0x57964e561351aacb,
+        0x9a7093c086c9d914,
+        0x0ec7e7056234b32a,
+        0xe48e66e759f609a8,
+        0x2a0780278865d579,
+        0xdc7057622a8fbc03,
+        0xbb2bdf5cb185077f,
+        0x56d64833ba5763d9,
+        0x83be1a1d006ccd1e,
+        0x4d8dd229b47ff1c1,
+        0x76e47f23c516ec8d,
+        0x4fbb93b1eb518acf,
+        0xcc3750b5c3cf7c1b,
+        0x324ab40444f786ad,
+        0x08ec7d7ee6ee1300,
+        0x2818c507049e6be6,
+        0x15f0aa420e756f7e,
+        0x8a44426c317b9284,
+        0x20497ed37512bac7,
+        0x0885d217cbf578f1,
+        0x5763afa77fbb84d3,
+        0xa5264dc8f9aee03f,
+        0xe7d6b49e22321d8c,
+        0xbf544be1226a5126,
+        0xb496fb4864f5369b,
+        0x7a10599ff45d2074,
+        0x49294fe508cd5be7,
+        0x38eb7f3774c68629,
+        0x23b858f0ae52ae8b,
+        0xbfde9334306afb8e,
+        0x0960d1ffc92a074c,
+        0x7af5c3d0e09317d8,
+        0x136c6f3ca755785d,
+        0xf097454f49117af7,
+        0xeb6dc09b4275a399,
+        0x03dab4618c080f0c,
+        0x49e145e9966c29a3,
+        0x3835087f158f9c08,
+        0x156c670b9e01193c,
+        0xf86f55a19dda679e,
+        0x97cd45c139f0f51e,
+        0x60427b6e1b91f881,
+        0xb3618b3f6922e1d1,
+        0x02e9ded0e8377fd9,
+        0x6b68856cefca7d45,
+        0x3b9f2c698d863268,
+        0x087461b7c4ba2170,
+        0x5bd28fb6c1b70492,
+        0xf38b36e834ba8d00,
+        0xe714693561d9c65a,
+        0x21293b3c2889f652,
+        0x0dd6a657ae04259a,
+        0x36a27bbb8dbc87b2,
+        0x943b8d4ca5213096,
+        0x039366c5097748cd,
+        0xfc7a3da264207f06,
+        0x84c6350d9f074484,
+        0xa1151b7f3d98f723,
+        0x31c590521a8d3a30,
+        0x596974f299b6b24c,
+        0x40b61c7cbe498b57,
+        0x13f11087538f85a4,
+        0xf3cf668ebef8a6a3,
+        0xd2c75e86ed9e276c,
+        0xfdc7eca0c1f5404a,
+        0x9a7669d9ae3cad17,
+        0xb613bf40de2f8089,
+        0x7552e4d212c9fb6d,
+        0x36681a665991e341,
+        0x225635763ff516b0,
+        0xabddc60c85c53e27,
+        0x533a0f74e6916526,
+        0x54b4f77ae1edd00d,
+        0xdb660a3c112fb2a8,
+        0xdd0cc3805ed65b91,
+        0x149f34222812d66b,
+        0x62d5b2839c5f356a,
+        0x2b75a69f63d5d524,
+        0x4328075efbb79368,
+        0x54dc1f79fd961edc,
+        0x64efa526214fdcd2,
+        0xba66cad5e1a65dee,
+        0x045d8fb36efe0b95,
+        0xa59f7adf0a49ac3a,
+        0x82cf92d302bf2b53,
+        0x7a9c62dd75a193ab,
+        0xf15713474562aff5,
+        0xa25618df0cbab955,
+        0x3bd0b073539f79ad,
+        0x7c06678e2a94c32d,
+        0xa912b29343cbd7e1,
+        0x540f549ec27751b3,
+        0x8e8445b25736ec2b,
+        0x9219bdfa3d7c0942,
+        0xf3bd6a6f76fc2f56,
+        0xb06069d11e065a03,
+        0x038b74a9307c9b71,
+        0x4a7e6b76f5464f0b,
+        0xe13cab0922b0ddfe,
+        0x990097b327909f01,
+        0xa03bf6f28f6d3f86,
+        0xe438cf436e87ab06,
+        0xfe46cfedbad03433,
+        0x51837a2c71ce2ef7,
+        0x6e6f2f2860952e8c,
+        0x4919390daf2e2dda,
+        0xff2f2e36944cd24a,
+        0xda9462a02fe0ddc9,
+        0x270471a116c3b075,
+        0x9d81eff0d35ff5e6,
+        0x15bb64289ad44b8d,
+        0x5f3508e8d9881807,
+        0x3ce9ae900aac636b,
+        0x62dddffc7489707d,
+        0x5489dd21b07b8bab,
+        0xcb36b7ce5f5e7268,
+        0xb6a5e064324ce7f3,
+        0x04d1854906e2f6ca,
+        0x5d3531fd89c552e4,
+        0xcf8a0b5bd339f002,
+        0x7fd26c6b9f1f45ed,
+        0x02aa9a2296414bde,
+        0x707f8959ebcf4e3f,
+        0x1f7356747ac9586c,
+        0x0e582ea9b78ff5d7,
+        0xedab7cf1a234e09c,
+        0xea11a6e5f5eebd44,
+        0x37c18e252c3868d7,
+        0xe2ea2efc21abd035,
+        0xcf61876ddb1cb18d,
+        0x86fc92853665ddf4,
+        0xa65e0696ffd25618,
+        0xbd4145d6f68751f5,
+        0x60c574f1014cba27,
+        0xf4d382d918a0ce56,
+        0xb3542003d380cef2,
+        0xa948743f8fe658bd,
+        0x51060270c417e675,
+        0xbbad3c30dd179be4,
+        0x47e1eb7138f3d0ca,
+        0xea70447ece5a511e,
+        0x63b4714123ab1a78,
+        0xacd99784457b2ba0,
+        0xa09c26842ebfef1b,
+        0xcdfc9b1b23045dc8,
+        0xc32e895113d739b0,
+        0x7df19015b555327e,
+        0x1adb554c9e055cb3,
+        0x8bc8eaa7597ba9e8,
+        0x5ffe02256d23f28c,
+        0x3101a4f66ba90d32,
+        0xb3bfd0fdd398fc6a,
+        0x245a0dae87bfe741,
+        0x5045f255f445ec3c,
+        0x823e38e70567c830,
+        0x3def22a539f22675,
+        0x97f33ccf7c110728,
+        0x85e4dfb8de6ce794,
+        0x3f03ba47ee106e9c,
+        0xc24c5475e791fc33,
+        0x3ce66733226abafb,
+        0xe5b1cd01bbf3e1bf,
+        0x18d504a8237beffe,
+        0x670f753361cfc2d2,
+        0xe037d59df8c9d803,
+        0x349a122f580d80e6,
+        0x3e25f003ef491825,
+        0x500d3844ca746427,
+        0xdd47859ebd71d0c8,
+        0x28e713fcbb6282e2,
+        0xb4e47a43441c9f54,
+        0x5a86b7a5777bb9bb,
+        0x6ba715908ade0bd6,
+        0x5d2add050dce5a10,
+        0x351004dc626579d2,
+        0x95407d199fadf43a,
+        0x2462a8021c26c878,
+        0x77dc4d363fc67e6f,
+        0x884621aee8314957,
+        0x64688942af91b1f8,
+        0xb72e454be83d22ac,
+        0x21cb9937fe9bf7bf,
+        0xa2e25f4440cd919b,
+        0xfe9f81f0063cd5e4,
+        0xc8ac9c096e71fc71,
+        0x50b0b97d4a3e8b5d,
+        0x401f277bb626c9fb,
+        0x92784c81e5f698cb,
+        0xe40fed79771abed7,
+        0xfaa9535ceadcc0f5,
+        0x2ef2711eef819096,
+        0x46a9df0e913a6f29,
+        0xcbfa03b72ff36013,
+        0x6e915a7552c36f66,
+        0x205f766978887ea9,
+        0x6988e11378b4a197,
+        0x4dfb919ec51feaa9,
+        0xfe8e08b2dfa5208e,
+        0x61a6172f3c62ebf1,
+        0x5f44b0034e5633a9,
+        0x32cb3de2d0cfa5d6,
+        0x28cb73dd6bc9d62e,
+        0xe4304b0a0248f05b,
+        0x2168290db035b78c,
+        0x719904a79aac6e27,
+        0x69d0bcebe17ad9ad,
+        0x1ea131fcf82fa7ba,
+        0x1ed75ef8418955be,
+        0x62d081c500d9766d,
+        0xee2dae453298f4e0,
+        0x69ed4d748a2d0864,
+        0x97dd32ad532ca51e,
+        0x5b572636c78f80d1,
+        0x5e337009906cb0cf,
+        0x00d495b21f0eae55,
+        0xd1b1557c31775b97,
+        0xa7274708dd7ec10b,
+        0xb35a7835c3148a85,
+        0x6efee27cac3e9075,
+        0x057ea4a8709a7271,
+        0x233c9197c6c60d9f,
+        0x94b8869c41c3befe,
+        0x386177f3df91505c,
+        0x4139309ddec23d50,
+        0xb7862be4c379acd0,
+        0xdc0e6bb2a58bdb80,
+        0x87ab5b07cf78abe1,
+        0xc731eb31df311f63,
+        0xca131105bde8f7a0,
+        0xfabf48d2ebd58595,
+        0xefebdcf48b35c6d3,
+        0x983fea5e571e210c,
+        0x126056611aae13fd,
+        0x66020103aa8512b1,
+        0x6760a25adedbc81b,
+        0x9750bd9a40d45d34,
+        0xf0107d78ed55d4c7,
+        0x5ce0c7cf11fc9867,
+        0x2300b491a4d42a19,
+        0xdecc44e96b596873,
+        0x3c3dd09de9086e2c,
+        0x70d2d01ed3322d78,
+        0xcbe8a8f27b407429,
+        0x85dca5a9a0aad483,
+        0x87a74c6a31db86c3,
+        0x55a651beea5a2ffb,
+        0x428beabd9b202285,
+        0xbe084dff465ee4e7,
+        0x732e25d445b09181,
+        0xb59fe4d1943a1e42,
+        0x6b2b5a7df6d19080,
+        0x60787ccd81b02b9d,
+        0xa22b07f440c93710,
+        0xeb85a48f68b99a63,
+        0xb7bfe1a79e81ce61,
+        0xb3a71ad3eb42ae32,
+        0x25442181f1a668da,
+        0x0a9b15e595394ac8,
+        0x8293824712c66216,
+        0xb09dbd46029283e8,
+        0x053700aaba005194,
+        0x9985f2d84c225ac9,
+        0x7d88a149206e975a,
+        0xe0c6c23d417e4664,
+        0x3018adbc626ea7aa,
+        0xcd3381cc3d1f2411,
+        0x1d33abe6169f5272,
+        0x59129379a4846240,
+        0xa0238b72c752398a,
+        0x65fdf840af482996,
+        0x7ebacc3b38a0d5db,
+        0xf681e9f2ed520de1,
+        0xca884a2eeed4289e,
+        0x06e83fcd62af21a1,
+        0xf01a9064e6593e2f,
+        0xea2b20aa31c13432,
+        0xa2a08091877df83d,
+        0x264edb44454f9058,
+    ],
+    [
+        0x76d1e491e5b4a2d9,
+        0x302b885fe7dec0a9,
+        0x11c661706b9ec8ee,
+        0xb15c62da67416324,
+        0x2f62d82e1e8919ed,
+        0xd8e0898714fcf93b,
+        0x1303a0a3b18ff1a2,
+        0x746e5aa008a4cb97,
+        0x96e944622ad86856,
+        0xfeeec228f5280442,
+        0x0c90e8e8b465b36f,
+        0xe0969bae6229b861,
+        0x58b8b57863958109,
+        0xd07ee662a9c35e0d,
+        0x7d1f6a87f5ac9bd4,
+        0x8df96c88d8dfdf72,
+        0x392a6d88adbcba59,
+        0xea3db2fd0115279f,
+        0x7e3aeec97c4aa257,
+        0xdeddba29993d7215,
+        0x0ebcb086bc3d14ff,
+        0x77a7b7d97f41c49a,
+        0x4817ce6e6526060b,
+        0x569df8340efb431e,
+        0xcb662bd62da1e3d0,
+        0xa034236b3ac97091,
+        0x4a78d8b2e535f192,
+        0x35da557ca41d4e13,
+        0x3d5568c2d4a737bd,
+        0x832a87e4f022f493,
+        0x40473b35e44c1a73,
+        0x8a5c3135453108f0,
+        0x3fad1d5a63b5d645,
+        0xffdbb3b755c749ba,
+        0xa9c5d41f7a72ff3e,
+        0x2198ebcaf16b9599,
+        0x64b134d3d51f3d71,
+        0x01312482463b31b5,
+        0x67fdddb6a0e09418,
+        0x9482d7aefab298f5,
+        0x5cc0c5455384121c,
+        0xee3a0c5da5bbbf6f,
+        0x86fac0b090005ce3,
+        0x57881e75f57b7b7e,
+        0x146075e31bf9d974,
+        0x9e234496244426ee,
+        0x9cbbb6427b65e699,
+        0x1e521214aab26f26,
+        0x5646bdfb1a017884,
+        0x8966a89899e9e93b,
+        0x13542e4edeaa8051,
+        0xb14012b919418227,
+        0xdaef8c0a630d2b7a,
+        0x9734af8fde9b7e89,
+        0xf19a46588b6ae2d7,
+        0x10e16ca0296b7d65,
+        0x98f5bb4d58694bbd,
+        0x2fbe22d7afc3b9f8,
+        0x4558967fa737fffc,
+        0xf6a17ff9e9680658,
+        0xf6e928de77855a9e,
+        0x6fc581f645ad09f6,
+        0xad5d73a20f7edf1a,
+        0xaf66210e428612c0,
+        0xe70c5855f29b599f,
+        0x7a4156e12f5eb79e,
+        0x621f35fed0447843,
+        0xd5337f0bfb348e1d,
+        0x798458fe3374a22d,
+        0x0bdfaf91987aa549,
+        0xdbfa81257b14a0c1,
+        0x6ec81695839bcace,
+        0x33bc80af26ff5c59,
+        0xcdc7389ab32c6fa7,
+        0x573c6d26b5c8f17e,
+        0xffd1cbd7c2c025c6,
+        0x6fd8989d99c21cf4,
+        0xe20d632d877873fd,
+        0x8547569fb1bdc790,
+        0x4611c156e33165a6,
+        0xfbd71583724ff212,
+        0xfbd43af425507af7,
+        0x56d8670534a95da1,
+        0x3230eccb3189097f,
+        0x2cad2238385769f7,
+        0xd7e0ac0bb6dcf2cc,
+        0xe1bdf3e81788a083,
+        0x127261c50ca4b607,
+        0xd6620ff9e3cad829,
+        0xe3a36155b6bc47ea,
+        0x107b0d00b88ea330,
+        0xb1361156b13f38e9,
+        0x41822e21e0050884,
+        0x2ef5f52c8e9ac840,
+        0xf2f5ab3c4e5151b9,
+        0x0d32448ef21a2888,
+        0x7eedc8d764dba776,
+        0xa4969f389373778a,
+        0x08b5d92a2af4464c,
+        0x6fd2beb76b666523,
+        0x4dcfd91bc3dca57a,
+        0xf710d377bb7fa63f,
+        0xb4bcbf8f569803e0,
+        0x16c0a94a8f97b36f,
+        0x01f82f9e50558bf7,
+        0xdf4fda5b0f07e4df,
+        0x6167154c2ad57c42,
+        0xe53f8fd6248b8f93,
+        0x45baadc3df65f868,
+        0xd3c2079f754ce715,
+        0x5c9291668dad9336,
+        0x78041275721eaf6a,
+        0x297cd0db0ae697b0,
+        0xf8953de100073e66,
+        0xa1146b1e0cb5ebf6,
+        0x2c22ad9d9b7141e4,
+        0xb7b18c9696aed993,
+        0x137df4d9b136d465,
+        0xc40f569277bb882b,
+        0x1fe33240e67a8381,
+        0x7a50ee1f17edff8a,
+        0xd7eee1c13f22c0cf,
+        0x4f209ca339eebcb1,
+        0xbb2193fd8c87fc89,
+        0x4735afb5af44db5a,
+        0x36e11787da400178,
+        0x3936f12eb374b8f6,
+        0xc8d0a7fa7a608ef7,
+        0xd06abc2c8f569163,
+        0x31d0c261bd8e04a9,
+        0x75b02436448243d3,
+        0x41d234736216c99f,
+        0x6d78fc4d67808d86,
+        0xa5a431a2943d7da5,
+        0xa7d5fda774e1a068,
+        0x68761b963e88272d,
+        0x9cc783cf8961b954,
+        0x8ee3ba7403e9104b,
+        0x7efa0b0cbc4d4367,
+        0x4ad9e54e53e48545,
+        0x6fc57f7ad542c8c6,
+        0x05be3fa09400a2ca,
+        0xe43e10a634eda8a5,
+        0xb0da348a7d4c6720,
+        0x4369fd9a5e424871,
+        0xecd115d73cf69e07,
+        0x0b272187dae84388,
+        0x6ab59443b89acb53,
+        0xd1e2e03bc5d359cf,
+        0x0a1ee2f90079fb24,
+        0xf70e5c51b5001dec,
+        0x9270063f91674346,
+        0x1ae17775b56377ea,
+        0xfd165e8b606f2b53,
+        0x22cd46efdd4d4d81,
+        0x27862ec58be8ddae,
+        0x03dca4f6f651c74f,
+        0x1b439b5c4e661eb5,
+        0x7c33c7c2714ca12f,
+        0x7d57fec87be7b4ed,
+        0x571f86834f6eba19,
+        0x0ceb1a269f242f30,
+        0x1116c1c24f854a8e,
+        0x657210b4db617861,
+        0xfa10a91e97ca6732,
+        0xf84897cc570a06fa,
+        0x1b51aa6ac8dce795,
+        0x27f74f91cac0d002,
+        0x387e26f74b4e8f5a,
+        0xc2d045e71f97cbd1,
+        0xda2daab6b26dfd33,
+        0xbf6f15f4190a0e05,
+        0x9f55e2c007c82814,
+        0xf753ce6b4e638a81,
+        0x7151222316e97264,
+        0x9546a36b53019eb7,
+        0xd2aa33a738736a48,
+        0xb1b23314d6399592,
+        0x38a86d5e0de6d8c1,
+        0xb489356f2547901c,
+        0xfd57beeaf1be8cf1,
+        0x27509968a4f51e34,
+        0xaaf65ce5663618a2,
+        0xebb96b44db9a262d,
+        0x1f73acce53c27015,
+        0x185513c9fb8abe36,
+        0x3774cac114dc42c4,
+        0x5f976069a77b1a65,
+        0xf24214150ebc0fb9,
+        0x4dd2b918cedbc6f1,
+        0x3c6f0f2b7e47c991,
+        0x7ba456bfde5dfebe,
+        0x73cc77ef1b40d90a,
+        0x4efa777e16d6a95e,
+        0x361c22d8a9845402,
+        0xe7f32b86279ed695,
+        0x73282117d397e256,
+        0x6b09766592975161,
+        0x727740464e15a2ea,
+        0xdb662a83ea515a1b,
+        0x04fca418cb6a5171,
+        0x66a08993786adc01,
+        0x0a63706448b48779,
+        0x9bb34e50d594534b,
+        0x1271aca6221cfc18,
+        0x9ac2ece684c31a77,
+        0xd89e5dde0e3a9db4,
+        0x6bef87a01d5f74ff,
+        0xf2d2e5dd158a8ae5,
+        0x577972ad18410747,
+        0xd039b7f344e98330,
+        0xb15ef0468967a103,
+        0x34387286088d5e8b,
+        0xbceece928da1391d,
+        0xfadb700e04d0b086,
+        0xef85eb170550d65f,
+        0x85b14a752fe3a2c5,
+        0x4115a89af575e28d,
+        0x62182e9fb7f00cb4,
+        0xd808a41adeac216d,
+        0x50eff45b98e513c4,
+        0xde095f5b370269a9,
+        0x632b0937f616ce48,
+        0xab8f38f8991d7331,
+        0x2954d5c0ed63071a,
+        0x8f497488e9fcfe90,
+        0x70aafa4fe7dad3c3,
+        0xc7f6ac039b324753,
+        0xf8bb14c410693683,
+        0xa0c04cc63da7624c,
+        0xccd4fd1829c659b1,
+        0x0ec6c3a1fb565e91,
+        0x0645d4af9d4d45aa,
+        0x55b53b6bde1536a2,
+        0x74b3b2d6df951225,
+        0x1552d93cd3236655,
+        0x794f8357bfd8c897,
+        0x28e3d21e1a73234b,
+        0x95d9c2cd8c96b798,
+        0xdb3581066eef63b7,
+        0x9b7a5ff8895e1c15,
+        0x033a83102ea0a588,
+        0xd8c6189b2f922437,
+        0xa7da883766e87801,
+        0x3f215799d213637a,
+        0xb9eddd10818773d7,
+        0x4424fd99fe6f4d40,
+        0x593505cd30ba1f56,
+        0x1fa20ca9308cf3b0,
+        0xb573885b5b8bdc01,
+        0x9c7830bf40ec3016,
+        0x30e2fffb8993dbf9,
+        0x9f90fb32932a79e6,
+        0x56980eea8e499f33,
+        0x6aab9dd36952a698,
+        0x678f90c30530c62e,
+        0xdb5c6bb208ce60d1,
+        0x776aad1e57b456af,
+        0x3a45d55097427e74,
+        0x59bbea28286a2d17,
+        0xffb2098fbe075f19,
+        0xd0bd160d4b32c7de,
+        0x0fce8dcaedf6da67,
+        0xf81e239883f659f4,
+        0x6fca2b307d72fc3f,
+        0x07b805afcc3735c5,
+        0x24f15145815e4b50,
+        0xb70e58f593850c05,
+        0x7077579f9c32ed41,
+        0x4c3f9112a066f2f6,
+        0x4d0e62733341e52d,
+        0x9f8efae33929eec6,
+        0x1469dd50766ead66,
+        0x934d25d519fed95a,
+        0x05cc5b601cc519c9,
+        0xf2611e0997046cb6,
+        0x407df15f9aa68c8f,
+        0xa2c6887f04c0241f,
+        0x64dba4254ef1cb78,
+        0x9451a6c3f86e667f,
+        0x431b341bc4ef3bf8,
+        0x51fac075761498e1,
+        0x2cf0723846fe2a3e,
+        0xbaaf1843c89aaa0f,
+        0xe0b32495ad7577ca,
+        0xcf3cece2ff485d24,
+        0x4b0ffbc2d9ec26fc,
+        0x119d758ac7fb36f4,
+        0xeeef293809bf2677,
+        0x4b069f6cdd8bed12,
+        0x545d6b28f3816167,
+        0xd14e3c1984a6f47f,
+        0x9f523024204d7411,
+        0xfa3e445355a27425,
+        0xcbcfb4917f839582,
+        0x67e4767bf930beee,
+        0x9ee2af83917d2263,
+        0x282c680003bd4d64,
+        0x8f7ae178be645e10,
+        0x75d26a3b8acfbae6,
+        0x6cc0791ac47af9ea,
+        0x0e2e5b8e00821f0d,
+        0x889f8c4e0da40f4b,
+        0xb05640c47ff55f16,
+        0x4f6620b55bfb0678,
+        0x9da4508b3a3a7420,
+        0x8c53196b0a5cb921,
+        0xb08e3b10ddffdeb9,
+        0x9d9aafb196523a2f,
+        0x4667e3946963c4d9,
+        0x4ef8599a6454b34f,
+        0x6d3304abdc7915f9,
+    ],
+    [
+        0xd2e2ab6431c95940,
+        0x9ca55818320da87a,
+        0x982d02588eece8e3,
+        0x847203361eba6a56,
+        0x16fa4d093c3ac5a6,
+        0xb323d27de7aed46f,
+        0x0cad143082ab1940,
+        0x45baea5506a5bcad,
+        0x0d5356239d324045,
+        0x0fab82df6e4db133,
+        0xb0d0f29ddcc7dc22,
+        0xdb51d11e3cf574af,
+        0xdf2c376154f02edc,
+        0x93ea87b516f1e547,
+        0x2a4f3be30fe8fdf4,
+        0x491eb0db80df1d28,
+        0x9541cfbe9982abb4,
+        0x94c2a3a8b68a9d84,
+        0xeb6c9ae956c8b001,
+        0x4740ff9f7c10e31f,
+        0x2566233822ce0855,
+        0xbe9a343be0ab43c4,
+        0x39e4c40ef2887ebb,
+        0x251f88ea8fa3cb04,
+        0xb36641495a3072e1,
+        0x25bd277af37212f0,
+        0x6c03c429ad8176af,
+        0x1f09c07e07d8a098,
+        0xdc1373f649baa905,
+        0x261a8fe09430a2d2,
+        0xe9f591a2e2accecf,
+        0x7c4568db0ba410a2,
+        0x4a2a2aa9d6a577e4,
+        0xea60d4586003b93c,
+        0xddc44669b4a976a7,
+        0x89fac08e581a3644,
+        0x51d709d7644e2c48,
+        0x403121626ca1b5fd,
+        0x2edc5e9c489b0511,
+        0xacd14548a320e6d0,
+        0x2aae4b5f4ee14d2d,
+        0xd68a40797f0689ff,
+        0x62e8aaf0a16c6293,
+        0x8ee944e7935905c1,
+        0xdbdd1ddc558aaa94,
+        0x80d91975cde68655,
+        0xba21ad6c2a28b2b5,
+        0x7c8a78ee0c7e10c4,
+        0xcbb524c9fe951895,
+        0xfacf017b3ca0fa7f,
+        0x3113a68e53b5337c,
+        0x63d072f84d1a2d24,
+        0x4c24f037ebca97e0,
+        0x55c5c64c70bf670a,
+        0xeccf6015d3f9dd1a,
+        0x1c5f2ce6ab12f6be,
+        0x708b6d7c18299a9b,
+        0x97316fb0316102ac,
+        0x427d8d6630c8269c,
+        0xd7847729af923474,
+        0x0f7acfc61b93ae52,
+        0x895897b7f95b6328,
+        0xbcd63b9d3e7f4531,
+        0x550a42929811ac29,
+        0xb2b6e6365961dd4a,
+        0xf8bf2b5751bccd22,
+        0xea48480d9cb8f769,
+        0xf71575529833750c,
+        0xb24f58313f4c38be,
+        0xebaf4429c75fea95,
+        0xe990406bd27b01b6,
+        0xa72c6ddee16f44da,
+        0x008f00f900fd6b2a,
+        0xb5331a07fbe4fe1a,
+        0xc1e771fb07e57db6,
+        0x1c7bba4f5110a90d,
+        0x201e1b1f9d1f3eca,
+        0xf07654b758f77e85,
+        0x5f6378cc80d49af7,
+        0x9222f440f52ea0ea,
+        0x3cc3c7d7672b3ec2,
+        0x45cdc0b8ca3d8b99,
+        0x4fc7d31d04bc29a7,
+        0x923855c5f92a5244,
+        0x2e8a61b60cdf2ba9,
+        0x432afb5aa75d7953,
+        0x9d390fc076f4e17a,
+        0x060d273282094b99,
+        0x3cad13236f606500,
+        0xa2828e110c612da7,
+        0xfcbd06f2c214607c,
+        0x3a48be2629d5f8a7,
+        0xb2b661c8d149d488,
+        0xf976f426ac908a4e,
+        0x4246018178ec0a2a,
+        0xd337761e5608b010,
+        0x7b15c53041493a86,
+        0x0c1b110c00b40407,
+        0x78651c21d717e3d2,
+        0xb91dfd29c2db3eec,
+        0xea7e3568bd997c04,
+        0x87698e0e8221ebee,
+        0x9c980b98613a8e93,
+        0x1cb0c245be1b0296,
+        0xe54bdcbcc0118d93,
+        0x4e8a36154ff2d1c7,
+        0x3d724d33633f6bc8,
+        0xf10b0fa47883c59b,
+        0xe0c344e4c8ece2f8,
+        0x39f3737e3b3d7bce,
+        0x6e07e0993201faf2,
+        0xe37e98fe158d1c49,
+        0xe57f67da78befbc3,
+        0xe4ffead899814908,
+        0xbb2d2f16622aeea6,
+        0x1c438e0ba551c723,
+        0x8a80e5f8379f9491,
+        0x45267cecc6f07506,
+        0xf7286b2aed9220be,
+        0xa755625b7d78ca96,
+        0x0e13b06eb0f80a44,
+        0xe8c43357f9bccd1a,
+        0x0f94dee6f915e524,
+        0x7868f76990f1f40f,
+        0x5967e61d29eef6c9,
+        0xbe5ec85eeb0d1cdb,
+        0x8c2e798f76cae4d5,
+        0x2923f73910427973,
+        0x321bd249ff03f92a,
+        0xab96dbd9d7adaa29,
+        0xb0bd696f2aa8bb10,
+        0xbeaad23187fc33b0,
+        0x14977f4c80cd58f8,
+        0x2b3044ae3f80de5f,
+        0x37083af30f992317,
+        0x92179c9773204468,
+        0xefea2f0aeedb906e,
+        0x128b484a85356dae,
+        0x09077941e2a2f0ba,
+        0x55dae07a7a4e30ed,
+        0xfdd35b6d16ddaba7,
+        0x6d1423fd4742475e,
+        0x38766f2975adf74b,
+        0xa30f6d5c84759439,
+        0x926cb273ee32a6c0,
+        0x2bf2f2825c56b58e,
+        0xf396dca2243e732e,
+        0xbd61e015ed93d694,
+        0x4ae1e6f790e6fcc0,
+        0x56ef995119ff77b3,
+        0x0bce7008148c7725,
+        0xb6f31228390816a1,
+        0x9a80028fee2f6524,
+        0x2d4d8ff49c6a193b,
+        0x68a4e242479ae8ea,
+        0xc021feea76923281,
+        0x491c2d6b03c4beb9,
+        0xae3b0b6ddbe2a2d1,
+        0x4ce36fe413e0bf9f,
+        0xf6f4c7af61274865,
+        0x18b9764eb488f46a,
+        0x024d9fafb5b4fc66,
+        0x32cc2c7446554735,
+        0xeeb34c153ab54c09,
+        0x058eb05b1a2e1ee3,
+        0x7b60a97919f80077,
+        0x08be1ef8b3bfd4f2,
+        0x08953125bea31f6a,
+        0x309ddd5009c9eef7,
+        0x13c5001080cb775d,
+        0xc40fb5496040ac1f,
+        0x8d218a221ad8e882,
+        0x51af923a7c3811e7,
+        0x9999993ae9397b3b,
+        0x9c43855a7d037fea,
+        0xc8d3c3e9286e8afd,
+        0xade3e5b396786bbe,
+        0x7e642589a4a4329d,
+        0x907b46014f0d6e83,
+        0x45e6dffcb65e5aad,
+        0x9e59475c4d9987b2,
+        0xe39a7427b1ae706f,
+        0x9ab0e57fa1248491,
+        0x5fddd293c60ff9ae,
+        0xfb1b81e993e517b2,
+        0x6d0fa37e22195cad,
+        0x5b5f8abd19f85e77,
+        0xa8000198747ca98e,
+        0x55da3487870a1d22,
+        0xf7dce32ed104b726,
+        0x7be3e6d92fa9193c,
+        0xdc964a816d1b9091,
+        0x27c028c4965fff44,
+        0x6eb9ce8a4d7b98e2,
+        0x68645253a26c96fd,
+        0xb7d84d91e914e2df,
+        0x5586aad4b5062120,
+        0xbecf6e3ca0c362d9,
+        0xea8b3da419b04ff9,
+        0xf29edd655b26c980,
+        0x6e2ec1b3c98c4c54,
+        0xb5103ad87c99687b,
+        0x74070004e7f7c245,
+        0x5ff06f9293e43ae6,
+        0xc64362812a6baf3d,
+        0x763d9479c8c41787,
+        0xfb575def14530fb7,
+        0x55712a123d3bd1a2,
+        0x1966e9715ff028e7,
+        0x6b308a9eaa6c85d8,
+        0xdfd0e29dc40e1130,
+        0xd1781c7f481c5a7b,
+        0x6d3347669f222cad,
+        0xc282720b71e4a5b5,
+        0xea5f84f6ca527441,
+        0xeab7f6bf893cbd5c,
+        0x39f0eccb42721e72,
+        0x554a62677e443823,
+        0x6cd26d6b44065f4b,
+        0xc3f85b81bfadcbf0,
+        0x7ae8c9913536846b,
+        0xaf21f498582054ef,
+        0x8f56847af68063d9,
+        0x589759b2eff87c93,
+        0xd7efa6194d0550d7,
+        0x186424b71ce5b430,
+        0xd381c69462096e1a,
+        0x4d09991adbbfd14c,
+        0x76c0ac41d9102784,
+        0x4d4fd9b7cfaad157,
+        0xeb5d954b7ee0be7c,
+        0x3c3a91501ef9ff98,
+        0x3bcd60325cabe43d,
+        0xa1bb01f29b7b90ff,
+        0xe2683d35d057f60b,
+        0xe120d36b0204358c,
+        0xf504dbcc0be22a44,
+        0xe2611ef3127a00ea,
+        0xb2001730d37e53ba,
+        0x6cbc700274aed30f,
+        0xb62fbfc922ab26ec,
+        0x773c2cbfa2f4a8c4,
+        0x2c78ed0a556db278,
+        0x8f89038f2aaec99f,
+        0x863c977b9d8e1a74,
+        0xc491ba74e2eceee5,
+        0xb404cb2aab97514f,
+        0xa6ee6abfc9515474,
+        0x38a26ff9c39ee976,
+        0xc4ed53f07a92fc46,
+        0xc151a21d63c6ebad,
+        0x0a74fcbc89544dae,
+        0x14485636e19e4d42,
+        0xa235eb2d8f5ca403,
+        0x851f2eb06e918afc,
+        0xce80af64d0fc78f0,
+        0xd037657edb101cea,
+        0xd73d403b460fed16,
+        0x65c554dbe5784875,
+        0x078cd35addb791d7,
+        0x0e8dc318af0c4cb1,
+        0x02d4acf377ee8f48,
+        0xce0f3ed0ff1a0d6f,
+        0x43c3f6f7fa78ee50,
+        0xfb12e5a422dc22da,
+        0x9db26051d5367243,
+        0x76521d72487d7714,
+        0x76d2ed132f387fee,
+        0xdb30547a1a4af02e,
+        0xfff8ab9caaa88595,
+        0x5a94d7950352162b,
+        0xabcde11f46be6268,
+        0xed6ffaec800f42a0,
+        0xbfae672a4e27cacb,
+        0x3123c8fdb7474b5e,
+        0x7220ec79ab28cbdc,
+        0x0fe040ee40968cf6,
+        0xc7db685c33a37f1b,
+        0x0188a3a28b046ff4,
+        0x4129993587b56207,
+        0x0f43a1901d535bfe,
+        0x1c588769ebef37f1,
+        0xa833a82036c854b3,
+        0xdf5fc5125db7d56a,
+        0xf92f19dbbb2b768a,
+        0x71d4b5cb1adf4072,
+        0x4a269e170884166d,
+        0x70c3affff94b4b3a,
+        0x4965db85a1e88560,
+        0x4eb73fc67c24e7bd,
+        0x945988165f85a66b,
+        0x2ef87588fa8c922e,
+        0x1d453e1ca698d2b8,
+        0x12a5286f2c73e50a,
+        0x38d695cebe336066,
+        0xc5bf4ce34467fbc9,
+        0x3368b94a0b94d7a8,
+        0x892a7cba595a3104,
+        0x3f7f208d62095b90,
+        0x65e0cb289f95e104,
+        0x4a9b5703229383f7,
+        0x69cbda2904bc96ce,
+        0x95a1295e92071ecf,
+        0x2a6f58c8cc46b448,
+        0xc99dc106180b48c6,
+        0x23f4a34710e3a802,
+        0xd3fcc9e9fbc06e08,
+        0xae5bff092c51b951,
+        0x18d8bcb03da4a25d,
+        0xe6f931b4778f648d,
+        0x2fae22facfb3a37b,
+        0xcd04c35cf801c803,
+    ],
+    [
+        0x3f1d3437a0c15f7c,
+        0xfc4c21ad43a7f1c0,
+        0x71dc51a1ce5109f0,
+        0xa3bae973c16537a1,
+        0xb53ca9bd56552595,
+        0xcaf5c8f3e80a6029,
+        0x963968ddb24f49e8,
+        0xcd14f73f1a37d163,
+        0x41cffd4edcbe7d93,
+        0xa57215c4b592d783,
+        0x43009966e6c14ea6,
+        0x4b1a81729b5ef2cf,
+        0x88472b0a8e97e31e,
+        0x5501ccaebbe9aa43,
+        0x84bf05837e58a4ac,
+        0x6457cc51b520709f,
+        0x47b2fdbebcaeaf15,
+        0x63b994cf63f96efb,
+        0x7a7b6c8e7707bb08,
+        0xe3f2bb86200ef3b5,
+        0x248ea1bb909b15c5,
+        0x6a6f78bb033b81b1,
+        0xe945c01b358ffe10,
+        0xfcb597285ccc9633,
+        0xd148f96298e1d470,
+        0x7aea2184c53076c5,
+        0xa2f73c9539151102,
+        0x9c5d23ee5c88c89f,
+        0x10b3129be4552076,
+        0x239920df5ef96bde,
+        0xaf4f404b87a04744,
+        0x5feafec80240a1df,
+        0x148b58239db01a86,
+        0x870a2ee336803681,
+        0xe68035af48eb1768,
+        0xb2df37ad7f971081,
+        0xa19fe7080a447cba,
+        0x2a8dba874ecf2d97,
+        0x8c12e2d46de3a835,
+        0x3a6489a7a94b516a,
+        0x557cf1cbb30d07c9,
+        0x23d629a928a3d507,
+        0x8c3213768b5df7fd,
+        0xe2f6aef7a0699ded,
+        0x6bbc3ce7c3b987b2,
+        0x6688e13b426ebfd3,
+        0x75e2ab966ca07b05,
+        0x4c2f48c06ad8af3d,
+        0x4a03754e3fe8f465,
+        0xea7f43aa40f3bc59,
+        0xfd840d31ce79c668,
+        0x225b640e7708dd3a,
+        0x79532ef68126bb6c,
+        0x9e875572fa834dfd,
+        0x498e713091b084ce,
+        0xa4b3465d6e0d0ed7,
+        0x615b61964547b088,
+        0xe54f027a3fc172bc,
+        0xb1c7f840ffe7fa90,
+        0xa1550fa3d849b663,
+        0xea117e14530de317,
+        0x9627314e5305b2df,
+        0xdbf64da703c29f61,
+        0x737e4940ceb15ae3,
+        0x18aad253c85adeb8,
+        0xee11e3e8a3ac2bfe,
+        0xb159a2ed9eac119b,
+        0x03538ac53ed8779d,
+        0xf16f1079e1ad10a6,
+        0x238437a22cb60d5c,
+        0xfe7280f5c23c5cb3,
+        0x77bc090953b4d5f4,
+        0xede885c968b6ced1,
+        0xbea6c2bfdb5e824b,
+        0xfb6749147eb4140f,
+        0x60646431bf68bc35,
+        0x0b30ddc8e31df4c8,
+        0xab85506f6593200f,
+        0xe43ab3e4a265225d,
+        0x9bba90a982e79c72,
+        0x55d2c443644fd8a3,
+        0x1303bd913b403d45,
+        0x04b36e05b5f6f0bd,
+        0x290ec118eecb4b4a,
+        0x64130723890a2d2a,
+        0x8434fc92eebaf6af,
+        0x71ae43917e569542,
+        0x87b1531da17da1b1,
+        0x75158f0c9001e3f2,
+        0xd230ddc647439d55,
+        0x38b1d81342d53eb3,
+        0x64575e160822ca5d,
+        0x6ca39fc6307b19b6,
+        0x846eb14b8b02f732,
+        0xd851f5542393c277,
+        0xf7761748b64e4bf8,
+        0x89f3c5491e3e2960,
+        0x2a9c71b4a30b32b3,
+        0x7882aae317d08993,
+        0x158fdb68b9652427,
+        0x742e1d72454654f8,
+        0x7fe256af9891cd3a,
+        0x197741c530d8a540,
+        0x00da29ca689a6c3a,
+        0x53639d63a6ffc0ae,
+        0x6c2f0b55b463f8dd,
+        0xd5d8e4194e5aa909,
+        0x39f85fce63eb3729,
+        0x862b24263fedb6a3,
+        0x2d56196d1df23378,
+        0x485c2fc94779a160,
+        0xbc93c40c354a6f4d,
+        0x6060caf7157e95ee,
+        0xe9527ac5d06bd715,
+        0xb84583e3f3e8a8e6,
+        0xd3b48c69af72a847,
+        0xe38b5b88d835fb15,
+        0x6ebd07367b8646c4,
+        0xb91745ff5a497b31,
+        0xade5ce3bbf2c8f73,
+        0x7ced132585bc2e6b,
+        0x4c810dd496bddad4,
+        0x1c1c0b6698dd85f2,
+        0x02a4586a17a50178,
+        0x184abcf2c86488f8,
+        0xaf625e45961ac798,
+        0x236c3a279cf7fc37,
+        0x08efab1481ddff5c,
+        0xeda2e9d6df8029a4,
+        0x3edf58b174cfa97c,
+        0x8cead387a4af29ae,
+        0xfcd6d0d563c99125,
+        0xdba84d6991f89f3b,
+        0xd6456ae234c9d600,
+        0x9795ebba802718ed,
+        0x210055e35bcb2462,
+        0xbe515a0119223d61,
+        0x22d63492a952ff17,
+        0xa42259d7251f3a54,
+        0xd8422874a6118762,
+        0x25cee97badbb1d75,
+        0x6a550477eb2ab5e1,
+        0x69c14fb5a919954d,
+        0x8aef2b422d9793fb,
+        0x8ebc8a2e358150c7,
+        0x2566556df9e7c86c,
+        0x97ddda997eb8884a,
+        0xf5012172f1cc79da,
+        0xc9b2a06bb88afc75,
+        0x95f729fd27e9f594,
+        0x411a6ecc70e22701,
+        0x3db433e4b0e3fdb4,
+        0x2d3080189f5f5e7b,
+        0xbbef569757202cb3,
+        0x0ec2739573f17a84,
+        0x32bb10e68e080d9f,
+        0x37f026db693e02e6,
+        0xc1766122b3f19095,
+        0xd898c084ab6cc478,
+        0xaaa5664be62582a4,
+        0x3e6f32657250b72f,
+        0xd390e013c38f48fa,
+        0xcf414f9b2f725326,
+        0x2bc4a139583f415c,
+        0x922153b7b7196f41,
+        0x036954049a97a4d5,
+        0x3370205983d98f4b,
+        0xc7246d58c93dbe7b,
+        0x002c6926bfecbe4b,
+        0x46c7459f734c1073,
+        0x3184b834851efdef,
+        0x0991470720b065e3,
+        0x1d0d9242b5c17500,
+        0xf4519b03ce270a3a,
+        0x8a59c23c13e8e0a8,
+        0x714a6c675c90fdba,
+        0xd3cd36795d54d9fc,
+        0x765787c266025660,
+        0x3bd3b6ea39b016cd,
+        0x2440f29764b9c3eb,
+        0xf2356cb4a47d4978,
+        0x4449e736302a92ea,
+        0xcc9982e279318ff6,
+        0x76eae1de2bd516f9,
+        0x4a656a653fc2b282,
+        0xab98014fd5f8b623,
+        0x2536a96691083ecf,
+        0xb28895c0df63ebea,
+        0xb6e4ff392f8f0f1d,
+        0x8f309f9704d21845,
+        0xf04ab9b635c4589d,
+        0x688491526e2beb0f,
+        0xe0e103a6900ef6e4,
+        0xd846a06ba88c4ee6,
+        0xaf67fa828e9f6f67,
+        0xe2974d887777d64c,
+        0x099d0d38dd828631,
+        0x69ce2d8ac9425ffd,
+        0xb952c62ed4cfc7aa,
+        0xa5b714b11f63fc76,
+        0x2d6da6bda53cf47b,
+        0xf854697dc9341672,
+        0x40fc876a7192a3de,
+        0x6fbe5bdd50c088cf,
+        0xc0afe4499f0d9d7a,
+        0xa505cde80ab01ff0,
+        0xd8cbe8d27776a00c,
+        0x9515cd3064314fcc,
+        0x4e1e09ad09e9b20a,
+        0x16e6b02a511b18a0,
+        0x060e76faf39f9d64,
+        0x67b9daa6e8e169f6,
+        0xba1ca7fdc30a6244,
+        0xd34a45cadcadd012,
+        0x91f9935493ec7bde,
+        0xcf35aa83c9b3609b,
+        0x6ec7e14991484b29,
+        0xc58142188c7b7312,
+        0x9b134d70a5e64312,
+        0xe14007c0fdf4c87b,
+        0x8b72e04356c19b25,
+        0x9b47889dfca2d774,
+        0x3b446bfcc620c491,
+        0x9654f4b9ed55621b,
+        0x3ced0247c73666b7,
+        0x7852a62de2c8157e,
+        0xfcb642e7900ebda4,
+        0x5e674f286d1e8701,
+        0x3724a0fb488a8ac0,
+        0xc272a0c45adb247e,
+        0x2b16ed730d55f79b,
+        0x68b686f48d30682f,
+        0x9b7ba899ed0f288d,
+        0xaea9a9c4fbed78dc,
+        0xeee3df46c6659c63,
+        0x695ba5cf308d7ab5,
+        0x2f5f1fce40636628,
+        0xec72e5e5e52ab514,
+        0x923bfb430e9c4a20,
+        0x21cad3876eae4207,
+        0xf37a8539cf823d2b,
+        0x593278b621bc237c,
+        0x911bba9ade7a7613,
+        0x5a5a8b877e555550,
+        0x9bcbc2c697a55b64,
+        0x8bd97a72d44cd018,
+        0xe2a931add33992cb,
+        0x3ab5d83595a03a7c,
+        0x3dbe7bda4d956a39,
+        0x0cbfcf4cb7bf09de,
+        0xe10fcf3cb8fc0e46,
+        0xa60530d402bbacbe,
+        0x881c2c08953df035,
+        0x9bacaa49117ddf4b,
+        0x3622de3943584882,
+        0x280424c1c2272f5f,
+        0x903b2d06d25b63e9,
+        0xb9b94b85e5d6b98b,
+        0x592f3e7dd15bec6e,
+        0x68a99f82ddd3c12d,
+        0x95685b4c9a9a986b,
+        0x3f9ee6702cc69780,
+        0x49609888a07ae205,
+        0x559207939fd3837a,
+        0x9a9410cc51839f7d,
+        0x6f394d85e5824fbf,
+        0x3220bf7929e79113,
+        0x5a13d17ad43913dc,
+        0x4bb2eca1522e2285,
+        0xd48d5f053125614d,
+        0xdb545fcdb07016ad,
+        0x466609b904724d97,
+        0x0ba65de97ab5c364,
+        0x6eb228a45e92a798,
+        0x313d112ae84f2932,
+        0x2ce0beb3b0209174,
+        0x441303bd12136f1e,
+        0x8dbf36c9943cde42,
+        0x57f425803fb70bfb,
+        0x7c0635bf533391bb,
+        0x84bac0fa3b444fc8,
+        0xf7b5b9ceb87c26c5,
+        0x9f6d1a6e7ff01c76,
+        0xe88fe8f26ac2aaa9,
+        0x58352031123df797,
+        0xf6f350bce5dbd7f0,
+        0xce8bebc22c278ffe,
+        0x1050527dafee6b4c,
+        0x17102045dd4bfae1,
+        0x294381998be4c22b,
+        0x6c05a684fc39cf37,
+        0x1418ccc1b5658148,
+        0x06d2f43360571ec6,
+        0x118e6ed5e0fb2cfc,
+        0x8e8c1300a1555819,
+        0xbad34fa48d2813e5,
+        0x6337e71dbc6ce892,
+        0xd03fefdca0622631,
+        0x5d7c0437019d4306,
+        0xc7c6b06564b0c0d2,
+        0x1e0250e153cfb757,
+        0x1442fc5e0918851d,
+        0x26ed1fdf77269ee0,
+        0x3d46a680ed1f4e21,
+        0x4f06bc352e1c1f35,
+        0xec3d1c37902bb47b,
+        0xa234a9c4578050d8,
+        0x9f797f916f761d33,
+        0x9437505b43aee810,
+        0x95dbbc96457994cb,
+        0x14170282b6b70a13,
+        0x64e15b85be8374e0,
+    ],
+    [
+        0x19c79c8d4fd4d5f2,
+        0x13e8d01c814b2828,
+        0x1d0ae827ab496cdd,
+        0x27edc46737bb3e49,
+        0x4c1210f4a0a72dcb,
+        0x5b7c11bab3f296d0,
+        0x18661a6fa2b82388,
+        0x2a4fd22bb8b82234,
+        0x885eacc49a50b591,
+        0x7638742c0ec29f3c,
+        0xb8f8701d7b15485d,
+        0x2c7ef20bd783d41f,
+        0xe7ba09a3868f7b2b,
+        0xa594632fb1021fb2,
+        0x3de8621346f77680,
+        0xa9644a277cfd19ac,
+        0xf22febfbb34a8c3a,
+        0xaf9df8f4fa1c4a05,
+        0x6397cb92db968fa9,
+        0x52ee656b576ca6ca,
+        0xdb9ef295ba341a4e,
+        0xb784f450a49982f8,
+        0x74ce6cd919aa9eab,
+        0x5250652e6c7668d6,
+        0x872f69434c2072ae,
+        0xb2fb7fd5a2990e15,
+        0x844b79a68dc5b177,
+        0x49aa84b04fe529db,
+        0x2ab8384a902e53fc,
+        0xeb4f337f466097bf,
+        0x07666fb93437dedc,
+        0x8bcee2ff39f4434c,
+        0x6290026b9748b3cd,
+        0xe872cdba46a3b67e,
+        0xd29aefa27f11e8b2,
+        0x71958a7b116642f8,
+        0x1978aa3c75b452e6,
+        0x5fbbca114c55f4f9,
+        0x9405b3e34e280722,
+        0xc103bd106fa912e2,
+        0x4183e830b5b16e8a,
+        0x3f0284956d2a896c,
+        0xb79619a881acce22,
+        0xac39d9f3d37d7137,
+        0x163d7416b0d90a98,
+        0xd7d536d0f741b60d,
+        0xaf42247de3c8aee7,
+        0x7bdbd2c8d04a23ca,
+        0x2df2f2010712aa4e,
+        0x184579abcd5908fb,
+        0x7d4bf5850147a4ea,
+        0xf206d6a18aead57a,
+        0xf0c0ffd4b842515a,
+        0x913309e7cf155d7b,
+        0x1be599210527798c,
+        0xd749003dd4573edd,
+        0x8da1ec924943accf,
+        0x297c80232a93bf13,
+        0x75c193c950bdf137,
+        0x81d8bc3d0ac9cfbe,
+        0xb395f7f82b08cbbe,
+        0x3e07248c85ded178,
+        0x357312d7ef0b724d,
+        0x2a77b20810ceec5f,
+        0xb8f63302d8105610,
+        0xeffed4f56803e513,
+        0x158cb67b3e247d39,
+        0xb9d6d5f9fd98ef02,
+        0xf9ebbbbc80d0471d,
+        0x0a7644f315c9add2,
+        0x651c7aa4a187522b,
+        0x6f538e964d10dfea,
+        0x8de24b7fbdfce3b2,
+        0xc776a47ad99ba148,
+        0xbd419bf27a705190,
+        0xc4fb2c4f953f8134,
+        0xcd324570c9d6a486,
+        0xc4ca83f8767a6396,
+        0x2208ae7dd0c617d3,
+        0xb627ba4ba230a8ee,
+        0x677d1a0f7520c0ae,
+        0x26fef64baf6f962e,
+        0xa11d12bbd4dff73e,
+        0x3d0418049f6a134c,
+        0x2106063614f33ce9,
+        0xb7ac7012a03f08d9,
+        0x0ebc0a22a5113e14,
+        0x8c33cb0d0e55e0b5,
+        0x961e2894215c0d61,
+        0x441a6e7d0caf88f7,
+        0x76aa9f1c8ab8bcd9,
+        0x71a7893c9e2affdc,
+        0xdf0b7c89c87b0139,
+        0x7b08a915523f5960,
+        0xb5814221233f49ff,
+        0xce6a12f99af49144,
+        0x9948a18b04d26797,
+        0x9f347cbfac99b03a,
+        0xc31a1206fe8ae968,
+        0x9133965d910f87ce,
+        0xc2b51d1bab1cfd20,
+        0xd6f9d1e57edc2931,
+        0x259bd94583883595,
+        0xfd66328ae3d72d3a,
+        0x3ef4a27e8c0e9ea6,
+        0x4e43904a25063898,
+        0x2d4e28c192cd784a,
+        0x674f192e83b74128,
+        0x83c1cf77ed97dc4d,
+        0xa16b5379cacbb8db,
+        0x199178b1de6a7979,
+        0x03ef9911b8eddcb0,
+        0x3928cd0f6ad3f0b2,
+        0xde4e3170bba87eea,
+        0x5508edbd84a01b6f,
+        0xa263536a39f528e4,
+        0x78422a9cbb19c88a,
+        0x7523790e123f0f80,
+        0x1a6b7948b3e9edac,
+        0xb1f6a8533c868d49,
+        0x21654bf84fb44b25,
+        0x316ba10179845a7f,
+        0x1bd9ac3261b72f42,
+        0xd2cb580d0ade8db5,
+        0x4ec1f024d778b735,
+        0x41b95a2f1a0050e1,
+        0xf271759e42bfa270,
+        0xa65d8f404023fd05,
+        0xb0d39350fd71a5e2,
+        0x27b7d8975af700c3,
+        0x27dec8f1a99f5f04,
+        0x127ced6e1ad2745d,
+        0x86858fb60736fede,
+        0x5b370961a812b216,
+        0x6231b92b1580fe30,
+        0xd597aa24faae1a67,
+        0x01652bc30bdbab6e,
+        0x66b381f369067a6a,
+        0x75b0044a3817741d,
+        0x790b91c313bb44ad,
+        0x87336e7b32c06a12,
+        0x8a64df3f60cf359e,
+        0xc851fec90d8db43e,
+        0x33faab1b559ed627,
+        0xc0e5daeffa4f1119,
+        0xc8b371fb5738416f,
+        0xa1f1e77dffc0fb1a,
+        0x279225d5eaf43fa6,
+        0x2201ab05c7a7c6b7,
+        0x779e77c41aa2888c,
+        0xe444dc14177049cb,
+        0x2b0395c9ac149ece,
+        0x4736643121f8a127,
+        0x97de2269dea05def,
+        0x756ac3625b47a9d5,
+        0x8e7fc96a508e78ae,
+        0x5ddb228c504a4ba4,
+        0x94127dd12b6db973,
+        0xd5ca35fb470af264,
+        0x1bf185623ec4693a,
+        0x2c37a08b8cc0ef54,
+        0x66f24649490f0b99,
+        0x2d68899f509b0f16,
+        0x212a09d25c38e15f,
+        0xd0be336394472adf,
+        0xbda6aa238d8eb5f8,
+        0x977b1368588730bb,
+        0xbb744a90be5704d7,
+        0x14c7570c81cbc29a,
+        0xf20efab31768f657,
+        0x84ad69c0baf4f73b,
+        0x690c8c1f27e92de3,
+        0xaf6726ad4e1ee393,
+        0x06092d392be717f0,
+        0x859df077ebbe955e,
+        0xdee897f0ba917083,
+        0x46e05f31bdc275c4,
+        0x34e2ef88b95c2e9a,
+        0x15955af6b6b2f228,
+        0x1e51addb969cdf3d,
+        0x0d6a04adc0dc8637,
+        0xe3870b714c9e979a,
+        0x3b06fab1aabd1de4,
+        0xb650e7552f38d40e,
+        0xa2aeea78f7b74670,
+        0x5f4a95108a6698e3,
+        0x4fcd1a2d63665559,
+        0xf9fa08aa3dbd9f0e,
+        0xb789a141c12ae79b,
+        0x8e79f821f9aa8c83,
+        0xee97b97d43e7e146,
+        0x97fd20cac7c7b717,
+        0x9b1e8020042cfccd,
+        0x4545fe324590eed5,
+        0xa2bd8c20517f0547,
+        0xc714d325179b807e,
+        0x2c16d973ccb0cd7e,
+        0xe57e589975401755,
+        0x000456a2f0af307a,
+        0x3baa262a93081e6c,
+        0xf04fde568547ec44,
+        0x3a29a11583561b41,
+        0xc90686bb4b7e5950,
+        0xf74153280f98d672,
+        0x5022f70a0f78322d,
+        0xea94a2fb595d088a,
+        0x06588cbed455df90,
+        0xf03e078afb37ace1,
+        0xdbd5142c371bfa03,
+        0xcf1b35e0ddbab1ff,
+        0xba212f611d90a232,
+        0x7cd50b10dbedd556,
+        0x018801537f836179,
+        0xef92a6d0488a2927,
+        0x7eb3e98687fd10d2,
+        0xf843cc2e425b3e6b,
+        0x9a0ff82a56580c44,
+        0x74e057ee4d60752b,
+        0xff1cbd8ace8b058a,
+        0xd468b0deb65d0fca,
+        0x92230f26ebe018d9,
+        0x571d1ebb9ae5e2f6,
+        0xdc1bf252d44dcc85,
+        0xd6abc48a8d8ba84d,
+        0x020b0795481c6457,
+        0x8594f05181c6c432,
+        0x3e715c17188f222c,
+        0xbebd9ce8378c826c,
+        0x1813ec558167d90f,
+        0x067b733c01f33a1d,
+        0x9a07c8445be6edd1,
+        0x069b1facf49e50fd,
+        0x469abb77d6dbac94,
+        0x2929f842ae72ee3c,
+        0xe83eb82339ee2ac8,
+        0x6fee36b843726376,
+        0xc58cf06b5a711208,
+        0xd4cac85bf75bc370,
+        0xa7548548caf2c6a6,
+        0xa00def45af551bdc,
+        0x58e80c74ad6237f1,
+        0xb85f62e74d6a6ce5,
+        0x88722e851df970dd,
+        0xfc63710a2d927ff0,
+        0x351e7f4be2601130,
+        0x3c02d14486116bcf,
+        0xd6ba2d6f31302ee6,
+        0xa2b43bc50c296b07,
+        0x65abfa409b31dd1a,
+        0xdef12d2da54c96ef,
+        0x57ebb55221f30a13,
+        0xb2bd5c6464dc06c5,
+        0x09da68eadee14bd1,
+        0x25221d5c3cdc6e6a,
+        0x52831bd2fb312108,
+        0x816d4ef0585311cb,
+        0x804f4a01e7f83d26,
+        0x538735b71391bfd2,
+        0x32723efd6d7ce907,
+        0x8d357ebc596ddf67,
+        0x2eec7eff3831b64e,
+        0xd70aaa20251277df,
+        0x647885284fbd3495,
+        0x0a6dc56081c30489,
+        0xb8b993fa8eb9199b,
+        0xf58c460cbce2aba0,
+        0xc260e04422b0ff53,
+        0x2aae6e3a1eee3337,
+        0x381e84db1218e40d,
+        0x306b99bc937f4f04,
+        0x29ae653f019b6c6d,
+        0x9429d07815f39978,
+        0x23542d2ba6956bbb,
+        0x389a1b9a8983e34c,
+        0xd697c631170afa80,
+        0xc32f62ee301e66f5,
+        0x5bfc01a800eae400,
+        0x0f60fc0980fbdcc5,
+        0xcde40e702cfa0fc7,
+        0x172ac39b2b50ec82,
+        0xf667292a2bab9814,
+        0x09347cc9fc42a22e,
+        0x83db7569bb3675ca,
+        0xd6e824cadc4691cb,
+        0x87dc94695994f82a,
+        0x46391e0dd3192530,
+        0x28edbcbe3ca785f7,
+        0x32b9621caf1f2402,
+        0x332c9af3e646c5e3,
+        0xf0a1a5e6c9e819c0,
+        0x07197c3a4eeb506f,
+        0x99b988891bf470a2,
+        0x8905ca7d7f617083,
+        0xdb3fe789f5ddc3ce,
+        0x6e4d00bb178bde76,
+        0x5a3e2da50a6a51b8,
+        0x67cefd6f2afa9cc0,
+        0xc58fb1e52840b469,
+        0xdc9554f34de4d722,
+        0x69371ccadadbfc02,
+        0x0922d5835d0474b6,
+        0x52691a9167eb67b6,
+        0xbb05441d4837a1eb,
+        0x09453c2a3507dcd3,
+        0x018abe3b7f96837c,
+        0x316a5dd346cfc906,
+        0x37f0c37aec797455,
+        0xc0b0243343d7769e,
+        0xcfe8af581ffb19d8,
+        0x8b9c8d2e63087b95,
+        0xc8b5601d9d9d5b8c,
+        0x8d11f5074cb674ed,
+    ],
+];
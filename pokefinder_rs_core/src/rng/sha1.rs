@@ -0,0 +1,183 @@
+use crate::enums::{DSType, Game, Language};
+use crate::gen5::{nazos, Profile5};
+use crate::parents::Profile;
+use crate::rng::BWRNG;
+use crate::util::{Date, Time};
+
+const H: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+#[inline]
+const fn change_endian(val: u32) -> u32 {
+    val.swap_bytes()
+}
+
+#[inline]
+const fn bcd(num: u8) -> u32 {
+    (((num / 10) << 4) | (num % 10)) as u32
+}
+
+#[inline]
+fn round(alpha: &mut [u32; 5], f: u32, k: u32, w: u32) {
+    let [a, b, c, d, e] = *alpha;
+    let temp = a
+        .rotate_left(5)
+        .wrapping_add(f)
+        .wrapping_add(e)
+        .wrapping_add(k)
+        .wrapping_add(w);
+    *alpha = [temp, a, b.rotate_left(30), c, d];
+}
+
+#[inline]
+fn section1(alpha: &mut [u32; 5], w: u32) {
+    let [_, b, c, d, _] = *alpha;
+    round(alpha, (b & c) | (!b & d), 0x5a827999, w);
+}
+
+#[inline]
+fn section2(alpha: &mut [u32; 5], w: u32) {
+    let [_, b, c, d, _] = *alpha;
+    round(alpha, b ^ c ^ d, 0x6ed9eba1, w);
+}
+
+#[inline]
+fn section3(alpha: &mut [u32; 5], w: u32) {
+    let [_, b, c, d, _] = *alpha;
+    round(alpha, (b & c) | ((b | c) & d), 0x8f1bbcdc, w);
+}
+
+#[inline]
+fn section4(alpha: &mut [u32; 5], w: u32) {
+    let [_, b, c, d, _] = *alpha;
+    round(alpha, b ^ c ^ d, 0xca62c1d6, w);
+}
+
+/// Provides the SHA1 hash used by Gen 5 games to compute the initial seed
+///
+/// The message is built from the nazo values of the game, the hardware parameters of the
+/// console, the date/time and the held key presses. Everything before the time is constant
+/// for a given date so it can be computed once with [`SHA1::precompute()`] and reused for
+/// every call to [`SHA1::hash_seed()`].
+#[derive(Copy, Clone)]
+pub struct SHA1 {
+    alpha: [u32; 5],
+    data: [u32; 80],
+}
+
+impl SHA1 {
+    /// Construct a new [`SHA1`] struct from a [`Profile5`]
+    pub fn new(profile: &Profile5) -> Self {
+        Self::new_with_parameters(
+            profile.get_version(),
+            profile.get_language(),
+            profile.get_ds_type(),
+            profile.get_mac(),
+            profile.get_soft_reset(),
+            profile.get_vframe(),
+            profile.get_gx_stat(),
+        )
+    }
+
+    /// Construct a new [`SHA1`] struct from the individual hardware parameters
+    pub fn new_with_parameters(
+        version: Game,
+        language: Language,
+        ds_type: DSType,
+        mac: u64,
+        soft_reset: bool,
+        vframe: u8,
+        gx_stat: u8,
+    ) -> Self {
+        let mut data = [0; 80];
+
+        let nazos = nazos::get_nazos(version, language, ds_type);
+        data[..5].copy_from_slice(&nazos);
+
+        data[6] = (mac & 0xffff) as u32;
+        if soft_reset {
+            data[6] ^= 0x1000000;
+        }
+        data[7] = ((mac >> 16) as u32) ^ ((vframe as u32) << 24) ^ (gx_stat as u32);
+
+        // Padding for a 64 byte message
+        data[13] = 0x80000000;
+        data[15] = 0x1a0;
+
+        Self { alpha: H, data }
+    }
+
+    /// Computes the initial seed from the current message
+    ///
+    /// [`SHA1::precompute()`] must be called after any changes to the button, date or Timer0
+    /// values and before calling this function.
+    pub fn hash_seed(&mut self) -> u64 {
+        for i in 16..80 {
+            self.data[i] =
+                (self.data[i - 3] ^ self.data[i - 8] ^ self.data[i - 14] ^ self.data[i - 16])
+                    .rotate_left(1);
+        }
+
+        let mut alpha = self.alpha;
+
+        section1(&mut alpha, self.data[9]);
+        for &w in &self.data[10..20] {
+            section1(&mut alpha, w);
+        }
+        for &w in &self.data[20..40] {
+            section2(&mut alpha, w);
+        }
+        for &w in &self.data[40..60] {
+            section3(&mut alpha, w);
+        }
+        for &w in &self.data[60..80] {
+            section4(&mut alpha, w);
+        }
+
+        let part1 = change_endian(alpha[0].wrapping_add(H[0])) as u64;
+        let part2 = change_endian(alpha[1].wrapping_add(H[1])) as u64;
+
+        BWRNG::new((part2 << 32) | part1).next()
+    }
+
+    /// Computes the rounds of the hash that do not depend on the time
+    pub fn precompute(&mut self) {
+        let mut alpha = H;
+        for &w in &self.data[..9] {
+            section1(&mut alpha, w);
+        }
+        self.alpha = alpha;
+    }
+
+    /// Sets the button value of the message
+    ///
+    /// Button values are retrieved via [`keypresses::get_values()`](crate::gen5::keypresses::get_values)
+    pub fn set_button(&mut self, button: u32) {
+        self.data[12] = button;
+    }
+
+    /// Sets the date of the message
+    pub fn set_date(&mut self, date: Date) {
+        let parts = date.get_parts();
+        self.data[8] = (bcd((parts.year - 2000) as u8) << 24)
+            | (bcd(parts.month) << 16)
+            | (bcd(parts.day) << 8)
+            | date.day_of_week() as u32;
+    }
+
+    /// Sets the time of the message
+    ///
+    /// Every console other than the 3DS flags PM hours
+    pub fn set_time(&mut self, time: Time, ds_type: DSType) {
+        let hour = time.hour();
+        let mut val = (bcd(hour) << 24) | (bcd(time.minute()) << 16) | (bcd(time.second()) << 8);
+        if hour >= 12 && ds_type != DSType::DS3 {
+            val |= 0x40000000;
+        }
+        self.data[9] = val;
+    }
+
+    /// Sets the Timer0 and VCount values of the message
+    pub fn set_timer0(&mut self, timer0: u32, vcount: u8) {
+        self.data[5] = change_endian(((vcount as u32) << 16) | timer0);
+    }
+}
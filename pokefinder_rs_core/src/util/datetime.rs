@@ -1,8 +1,9 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign};
 
 /// Struct that represents a Gregorian date
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct DateParts {
     /// Gregorian date year
     pub year: u16,
@@ -38,7 +39,7 @@ const fn compute_numbers() -> [[char; 2]; 100] {
 ///
 /// Bound between January 1, 2000 and Dec 31, 2099 as those are the only valid dates on the DS
 /// family of consoles.
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Date {
     jd: u32,
 }
@@ -186,7 +187,7 @@ impl Add<u32> for Date {
 }
 
 /// A time struct to track hour/minute/second
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug, Serialize, Deserialize)]
 pub struct Time {
     md: u32,
 }
@@ -260,7 +261,7 @@ impl Display for Time {
 }
 
 /// DateTime struct based on the Julian calendar
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Default, Debug, Serialize, Deserialize)]
 pub struct DateTime {
     date: Date,
     time: Time,
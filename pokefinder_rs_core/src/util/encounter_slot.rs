@@ -48,6 +48,7 @@ const GRASS: [u8; 100] =
 const ROCK_SMASH: [u8; 100] = compute_table::<2, false>([80, 100]);
 const BUG: [u8; 100] = compute_table::<10, true>([80, 60, 50, 40, 30, 20, 15, 10, 5, 0]);
 const HEADBUTT: [u8; 100] = compute_table::<6, false>([50, 65, 80, 90, 95, 100]);
+const SM: [u8; 100] = compute_table::<10, false>([20, 40, 50, 60, 70, 80, 90, 95, 99, 100]);
 
 const WATER_0: [u8; 100] = compute_table::<2, false>([70, 100]);
 const WATER_1: [u8; 100] = compute_table::<3, false>([60, 80, 100]);
@@ -89,6 +90,22 @@ pub fn k_slot(rand: u8, encounter: Encounter) -> u8 {
     }
 }
 
+/// Calculates the encounter slot for Gen 5
+pub fn bw_slot(rand: u8, encounter: Encounter) -> u8 {
+    match encounter {
+        Encounter::SuperRod | Encounter::SpecialSuperRod => WATER_2[rand as usize],
+        Encounter::Surfing | Encounter::SpecialSurf => WATER_4[rand as usize],
+        _ => GRASS[rand as usize],
+    }
+}
+
+/// Calculates the encounter slot for Gen 7
+///
+/// Every encounter type of SM and USUM uses the same 10 slot table
+pub fn sm_slot(rand: u8) -> u8 {
+    SM[rand as usize]
+}
+
 /// Calculates the encounter slot for BDSP
 pub fn bdsp_slot(rand: u8, encounter: Encounter) -> u8 {
     match encounter {
@@ -0,0 +1,698 @@
+use crate::enums::{Game, Method};
+use crate::gen3::states::{EggState3, PokeSpotState, WildSearcherState3};
+use crate::gen4::states::{
+    EggGeneratorState4, EggSearcherState4, GeneratorState4, IDState4, SearcherState4,
+    WildGeneratorState4, WildSearcherState4,
+};
+use crate::gen5::states::{
+    DreamRadarState, EggState5, HiddenGrottoState, MTSeedState5, ProfileSearcherState5,
+    SearcherState5, State5, WildState5,
+};
+use crate::gen8::states::{IDState8, UndergroundState};
+use crate::parents::states::{
+    EggGeneratorState, EggSearcherState, EggState, GeneratorState, IDState, IVtoPIDState,
+    ResearcherState, SearcherState, State, WildGeneratorState, WildSearcherState, WildState,
+};
+use crate::util::translator;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+/// Output formats supported by [`Export`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ExportFormat {
+    /// Comma separated values with a header line
+    Csv,
+    /// Array of objects keyed by the column headers
+    Json,
+}
+
+/// Trait for result states that can be turned into human readable columns
+pub trait ExportState {
+    /// Headers of the columns produced by [`ExportState::columns`]
+    fn headers() -> Vec<&'static str>;
+
+    /// Appends the columns of the state to `row`
+    fn columns(&self, row: &mut Vec<String>);
+}
+
+/// Table of resolved results ready to be written as CSV or JSON
+///
+/// # Example
+/// ```
+/// # use pokefinder_rs_core::parents::states::State;
+/// # use pokefinder_rs_core::util::export::{Export, ExportFormat};
+/// let states = [State::default()];
+/// let export = Export::new(&states);
+/// let mut csv = vec![];
+/// export.write(&mut csv, ExportFormat::Csv).unwrap();
+/// assert!(String::from_utf8(csv).unwrap().starts_with("Level,PID,Shiny,Nature"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Export {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Export {
+    /// Construct a new [`Export`] struct from a list of states
+    pub fn new<S: ExportState>(states: &[S]) -> Self {
+        Self {
+            headers: S::headers().into_iter().map(|h| h.to_string()).collect(),
+            rows: states
+                .iter()
+                .map(|state| {
+                    let mut row = vec![];
+                    state.columns(&mut row);
+                    row
+                })
+                .collect(),
+        }
+    }
+
+    /// Prepends a column holding the same value for every row
+    pub fn with_column(mut self, header: &str, value: &str) -> Self {
+        self.headers.insert(0, header.to_string());
+        for row in &mut self.rows {
+            row.insert(0, value.to_string());
+        }
+        self
+    }
+
+    /// Prepends the species of the results, used for static and egg results that do not store it
+    pub fn with_species(self, species: u16, form: u8) -> Self {
+        let name = translator::get_species_with_form(species, form);
+        self.with_column("Species", &name)
+    }
+
+    /// Prepends the location the results were generated for
+    pub fn with_location(self, location: u16, game: Game) -> Self {
+        let name = translator::get_locations(&[location], game).remove(0);
+        self.with_column("Location", &name)
+    }
+
+    /// Returns the column headers
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Returns the resolved rows
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Writes the table to `writer` in the specified format
+    pub fn write<W: Write>(&self, mut writer: W, format: ExportFormat) -> std::io::Result<()> {
+        match format {
+            ExportFormat::Csv => {
+                writeln!(writer, "{}", csv_line(&self.headers))?;
+                for row in &self.rows {
+                    writeln!(writer, "{}", csv_line(row))?;
+                }
+                Ok(())
+            }
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, self)?;
+                writeln!(writer)
+            }
+        }
+    }
+}
+
+impl Serialize for Export {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Row<'a>(&'a [String], &'a [String]);
+
+        impl Serialize for Row<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for (header, value) in self.0.iter().zip(self.1) {
+                    map.serialize_entry(header, value)?;
+                }
+                map.end()
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        for row in &self.rows {
+            seq.serialize_element(&Row(&self.headers, row))?;
+        }
+        seq.end()
+    }
+}
+
+fn csv_line(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn shiny(shiny: u8) -> &'static str {
+    match shiny {
+        1 => "Star",
+        2 => "Square",
+        _ => "No",
+    }
+}
+
+fn ability(state: &State) -> String {
+    let slot = match state.ability {
+        0 => "1",
+        1 => "2",
+        _ => "H",
+    };
+    // Results generated without personal info have no ability to look up
+    if state.ability_index == 0 {
+        slot.to_string()
+    } else {
+        format!("{}: {}", slot, translator::get_ability(state.ability_index))
+    }
+}
+
+fn call(call: u8) -> String {
+    ["E", "K", "P"][call as usize % 3].to_string()
+}
+
+fn item(item: u16) -> String {
+    if item == 0 {
+        "-".to_string()
+    } else {
+        translator::get_item(item).to_string()
+    }
+}
+
+fn method(method: Method) -> &'static str {
+    match method {
+        Method::Method1 => "Method 1",
+        Method::Method1Reverse => "Reverse Method 1",
+        Method::Method2 => "Method 2",
+        Method::Method4 => "Method 4",
+        Method::CuteCharmDPPt => "Cute Charm (DPPt)",
+        Method::CuteCharmHGSS => "Cute Charm (HGSS)",
+        Method::XDColo => "XD/Colo",
+        Method::Channel => "Channel",
+        _ => "-",
+    }
+}
+
+fn hex32(value: u32) -> String {
+    format!("{:0>8X}", value)
+}
+
+impl ExportState for State {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "Level",
+            "PID",
+            "Shiny",
+            "Nature",
+            "Ability",
+            "HP",
+            "Atk",
+            "Def",
+            "SpA",
+            "SpD",
+            "Spe",
+            "Hidden Power",
+            "Power",
+            "Gender",
+        ]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.level.to_string());
+        row.push(hex32(self.pid));
+        row.push(shiny(self.shiny).to_string());
+        row.push(translator::get_nature(self.nature).to_string());
+        row.push(ability(self));
+        row.extend(self.ivs.iter().map(|iv| iv.to_string()));
+        row.push(translator::get_hidden_power(self.hidden_power).to_string());
+        row.push(self.hidden_power_strength.to_string());
+        row.push(translator::get_gender(self.gender).to_string());
+    }
+}
+
+impl ExportState for GeneratorState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for SearcherState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for WildState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Slot", "Species", "Item"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.encounter_slot.to_string());
+        row.push(translator::get_species_with_form(self.species, self.form));
+        row.push(item(self.item));
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for WildGeneratorState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances"], WildState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for WildSearcherState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed"], WildState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for EggState {
+    fn headers() -> Vec<&'static str> {
+        State::headers()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        // IVs start after the level, PID, shiny, nature and ability columns
+        let ivs = row.len() + 5;
+        self.base.columns(row);
+        for (i, inheritance) in self.inheritance.iter().enumerate() {
+            match inheritance {
+                1 => row[ivs + i] = "A".to_string(),
+                2 => row[ivs + i] = "B".to_string(),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl ExportState for EggGeneratorState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances"], EggState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for EggSearcherState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed"], EggState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for IDState {
+    fn headers() -> Vec<&'static str> {
+        vec!["Advances", "TID", "SID", "TSV"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        row.push(self.tid.to_string());
+        row.push(self.sid.to_string());
+        row.push(self.tsv.to_string());
+    }
+}
+
+impl ExportState for EggState3 {
+    fn headers() -> Vec<&'static str> {
+        [
+            vec!["Held Advances", "Pickup Advances", "Redraws"],
+            EggState::headers(),
+        ]
+        .concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.pickup_advances.to_string());
+        row.push(self.redraws.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for PokeSpotState {
+    fn headers() -> Vec<&'static str> {
+        [
+            vec!["Advances", "Encounter Advances", "Slot", "Species"],
+            State::headers(),
+        ]
+        .concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.encounter_advances.to_string());
+        row.push(self.encounter_slot.to_string());
+        row.push(translator::get_species(self.species).to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for WildSearcherState3 {
+    fn headers() -> Vec<&'static str> {
+        WildSearcherState::headers()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for GeneratorState4 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances", "Call", "Chatot"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(call(self.call));
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for SearcherState4 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed", "Advances"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.base.seed));
+        row.push(self.advances.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for WildGeneratorState4 {
+    fn headers() -> Vec<&'static str> {
+        [
+            vec!["Advances", "Battle Advances", "Call", "Chatot"],
+            WildState::headers(),
+        ]
+        .concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.battle_advances.to_string());
+        row.push(call(self.call));
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for WildSearcherState4 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed", "Advances"], WildState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.base.seed));
+        row.push(self.advances.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for EggGeneratorState4 {
+    fn headers() -> Vec<&'static str> {
+        [
+            vec!["Advances", "Pickup Advances", "Call", "Chatot"],
+            EggState::headers(),
+        ]
+        .concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.pickup_advances.to_string());
+        row.push(call(self.call));
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for EggSearcherState4 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed"], EggGeneratorState4::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        self.state.columns(row);
+    }
+}
+
+impl ExportState for IDState4 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed", "Delay", "Seconds"], IDState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        row.push(self.delay.to_string());
+        row.push(self.seconds.to_string());
+        self.base.columns(row);
+    }
+}
+
+impl ExportState for State5 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances", "Chatot"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for WildState5 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances", "Chatot"], WildState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for EggState5 {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances", "Chatot"], EggState::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.chatot.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for DreamRadarState {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Advances", "Needle"], State::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(self.needle.to_string());
+        self.base.base.columns(row);
+    }
+}
+
+impl<T: ExportState> ExportState for SearcherState5<T> {
+    fn headers() -> Vec<&'static str> {
+        [vec!["Seed", "Date/Time", "Timer0", "Buttons"], T::headers()].concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(format!("{:0>16X}", self.initial_seed));
+        row.push(self.dt.to_string());
+        row.push(format!("{:X}", self.timer0));
+        row.push(translator::get_keypresses(self.buttons));
+        self.state.columns(row);
+    }
+}
+
+impl ExportState for HiddenGrottoState {
+    fn headers() -> Vec<&'static str> {
+        vec!["Advances", "Group", "Slot", "Result", "Gender", "Chatot"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        row.push(self.group.to_string());
+        row.push(self.slot.to_string());
+        if self.item {
+            row.push(item(self.data));
+            row.push("-".to_string());
+        } else {
+            row.push(translator::get_species(self.data).to_string());
+            row.push(translator::get_gender(self.gender).to_string());
+        }
+        row.push(self.chatot.to_string());
+    }
+}
+
+impl ExportState for MTSeedState5 {
+    fn headers() -> Vec<&'static str> {
+        vec!["Seed", "Advances", "HP", "Atk", "Def", "SpA", "SpD", "Spe"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(hex32(self.seed));
+        row.push(self.advances.to_string());
+        row.extend(self.ivs.iter().map(|iv| iv.to_string()));
+    }
+}
+
+impl ExportState for ProfileSearcherState5 {
+    fn headers() -> Vec<&'static str> {
+        vec!["Seed", "Seconds", "Timer0", "VCount", "VFrame", "GxStat"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(format!("{:0>16X}", self.seed));
+        row.push(self.second.to_string());
+        row.push(format!("{:X}", self.timer0));
+        row.push(format!("{:X}", self.vcount));
+        row.push(self.vframe.to_string());
+        row.push(self.gxstat.to_string());
+    }
+}
+
+impl ExportState for UndergroundState {
+    fn headers() -> Vec<&'static str> {
+        [
+            vec!["Advances", "Species", "Egg Move", "Item"],
+            State::headers(),
+        ]
+        .concat()
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.base.advances.to_string());
+        row.push(translator::get_species(self.species).to_string());
+        row.push(if self.egg_move == 0 {
+            "-".to_string()
+        } else {
+            translator::get_move(self.egg_move).to_string()
+        });
+        row.push(item(self.item));
+        self.base.base.columns(row);
+    }
+}
+
+impl ExportState for IDState8 {
+    fn headers() -> Vec<&'static str> {
+        vec!["Advances", "G8TID", "TID", "SID", "TSV"]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.advances.to_string());
+        row.push(self.display_tid.to_string());
+        row.push(self.tid.to_string());
+        row.push(self.sid.to_string());
+        row.push(self.tsv.to_string());
+    }
+}
+
+impl ExportState for IVtoPIDState {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "Seed", "PID", "Method", "Ability", "12.5%", "25%", "50%", "75%", "SID",
+        ]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        let pid = self.get_pid();
+        row.push(hex32(self.get_seed()));
+        row.push(hex32(pid));
+        row.push(method(self.get_method()).to_string());
+        row.push((pid & 1).to_string());
+        // Female if the low byte of the PID is below the gender threshold
+        for threshold in [31, 64, 127, 191] {
+            let gender = u8::from((pid & 0xFF) < threshold);
+            row.push(translator::get_gender(gender).to_string());
+        }
+        row.push(self.get_sid().to_string());
+    }
+}
+
+impl ExportState for ResearcherState {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "Advances",
+            "PRNG",
+            "High 32",
+            "Low 32",
+            "High 16",
+            "Low 16",
+            "Custom 1",
+            "Custom 2",
+            "Custom 3",
+            "Custom 4",
+            "Custom 5",
+            "Custom 6",
+            "Custom 7",
+            "Custom 8",
+            "Custom 9",
+            "Custom 10",
+        ]
+    }
+
+    fn columns(&self, row: &mut Vec<String>) {
+        row.push(self.get_advances().to_string());
+        row.push(if self.is_64bit() {
+            format!("{:0>16X}", self.get_prng())
+        } else {
+            hex32(self.get_prng() as u32)
+        });
+        row.push(hex32(self.get_high32()));
+        row.push(hex32(self.get_low32()));
+        row.push(format!("{:0>4X}", self.get_high16()));
+        row.push(format!("{:0>4X}", self.get_low16()));
+        row.extend((0..10).map(|i| self.get_custom(i).to_string()));
+    }
+}
@@ -1,6 +1,8 @@
 mod datetime;
 /// Collection of functions used for calculating encounter slots
 pub mod encounter_slot;
+/// Conversion of result states into human readable CSV or JSON.
+pub mod export;
 pub mod iv_checker;
 /// Collection of functions used for reversing IVs to possible PIDs.
 pub mod iv_to_pid_calculator;
@@ -1,22 +1,12 @@
-use crate::enums::{Buttons, Game};
+use crate::enums::{Buttons, Game, Language};
 use crate::resources::i18n;
 use crate::util;
-use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
 
-#[allow(dead_code)]
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
-enum Language {
-    German,
-    English,
-    Spanish,
-    French,
-    Italian,
-    Japanese,
-    Korean,
-    Chinese,
-    Count,
-}
+/// Number of languages in the i18n resources
+const LANGUAGE_COUNT: usize = 8;
 
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 #[allow(clippy::upper_case_acronyms)]
@@ -43,76 +33,90 @@ enum Translation {
     Count,
 }
 
-static LANGUAGE: Lazy<Language> = Lazy::new(|| Language::English);
-static ABILITIES: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut abilities = vec![];
-    read_file_to_vec(Translation::Ability, &mut abilities);
-    abilities
-});
-static CHARACTERISTICS: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut characteristics = vec![];
-    read_file_to_vec(Translation::Characteristic, &mut characteristics);
-    characteristics
-});
-static FORMS: Lazy<HashMap<u16, String>> = Lazy::new(|| read_file(Translation::Form));
-static GAMES: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut games = vec![];
-    read_file_to_vec(Translation::Game, &mut games);
-    games
-});
-static HIDDEN_POWERS: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut hidden_powers = vec![];
-    read_file_to_vec(Translation::Power, &mut hidden_powers);
-    hidden_powers
-});
-static ITEMS: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut items = vec![];
-    read_file_to_vec(Translation::Item, &mut items);
-    items
-});
-static MOVES: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut moves = vec![];
-    read_file_to_vec(Translation::Move, &mut moves);
-    moves
-});
-static NATURES: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut natures = vec![];
-    read_file_to_vec(Translation::Nature, &mut natures);
-    natures
-});
-static SPECIES: Lazy<Vec<String>> = Lazy::new(|| {
-    let mut species = vec![];
-    read_file_to_vec(Translation::Species, &mut species);
-    species
-});
+struct Tables {
+    abilities: Vec<String>,
+    characteristics: Vec<String>,
+    forms: HashMap<u16, String>,
+    games: Vec<String>,
+    hidden_powers: Vec<String>,
+    items: Vec<String>,
+    moves: Vec<String>,
+    natures: Vec<String>,
+    species: Vec<String>,
+}
+
+impl Tables {
+    fn new(language: usize) -> Self {
+        Self {
+            abilities: read_file_to_vec(language, Translation::Ability),
+            characteristics: read_file_to_vec(language, Translation::Characteristic),
+            forms: read_file(language, Translation::Form),
+            games: read_file_to_vec(language, Translation::Game),
+            hidden_powers: read_file_to_vec(language, Translation::Power),
+            items: read_file_to_vec(language, Translation::Item),
+            moves: read_file_to_vec(language, Translation::Move),
+            natures: read_file_to_vec(language, Translation::Nature),
+            species: read_file_to_vec(language, Translation::Species),
+        }
+    }
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+static TABLES: [OnceCell<Tables>; LANGUAGE_COUNT] = [const { OnceCell::new() }; LANGUAGE_COUNT];
 static GENDERS: [&str; 3] = ["♂", "♀", "-"];
 static BUTTONS: [&str; 12] = [
     "R", "L", "X", "Y", "A", "B", "Select", "Start", "Right", "Left", "Up", "Down",
 ];
 
-fn read_file_to_vec(translation: Translation, strings: &mut Vec<String>) {
-    let index = ((*LANGUAGE as usize) * Translation::Count as usize) + translation as usize;
+/// Index of the `language` in the i18n resources
+fn resource_index(language: Language) -> usize {
+    match language {
+        Language::German => 0,
+        Language::English => 1,
+        Language::Spanish => 2,
+        Language::French => 3,
+        Language::Italian => 4,
+        Language::Japanese => 5,
+        Language::Korean => 6,
+        Language::Chinese => 7,
+    }
+}
+
+fn current_index() -> usize {
+    resource_index(get_language())
+}
+
+/// Tables of the current language, each language is only loaded once
+fn tables() -> &'static Tables {
+    let language = current_index();
+    TABLES[language].get_or_init(|| Tables::new(language))
+}
+
+fn read_data(language: usize, translation: Translation) -> Vec<u8> {
+    let index = (language * Translation::Count as usize) + translation as usize;
     let start = i18n::INDEXES[index] + 2;
     let end = i18n::INDEXES[index + 1];
 
     let compressed_data = &i18n::I18N[start..end];
-    let data = util::decompress(compressed_data);
+    util::decompress(compressed_data)
+}
 
+fn read_file_to_vec(language: usize, translation: Translation) -> Vec<String> {
+    let data = read_data(language, translation);
+
+    let mut strings = vec![];
     let mut i = 0;
     while i < data.len() {
         let it = data[i..].iter().position(|&i| i == 0).unwrap_or_default();
         strings.push(String::from_utf8_lossy(&data[i..(i + it)]).to_string());
         i += it + 1;
     }
-}
 
-fn read_file(translation: Translation) -> HashMap<u16, String> {
-    let index = ((*LANGUAGE as usize) * Translation::Count as usize) + translation as usize;
-    let start = i18n::INDEXES[index] + 2;
-    let end = i18n::INDEXES[index + 1];
+    strings
+}
 
-    let compressed_data = &i18n::I18N[start..end];
-    let data = util::decompress(compressed_data);
+fn read_file(language: usize, translation: Translation) -> HashMap<u16, String> {
+    let data = read_data(language, translation);
 
     let mut strings = HashMap::new();
     let mut i = 0;
@@ -129,6 +133,38 @@ fn read_file(translation: Translation) -> HashMap<u16, String> {
     strings
 }
 
+/// Gets the [`Language`] strings are currently translated to.
+///
+/// # Example
+/// ```
+/// # use pokefinder_rs_core::enums::Language;
+/// # use pokefinder_rs_core::util::translator::get_language;
+/// assert!(get_language() == Language::English);
+/// ```
+pub fn get_language() -> Language {
+    Language::from(LANGUAGE.load(Ordering::Relaxed))
+}
+
+/// Sets the [`Language`] that strings are translated to.
+///
+/// Strings that were already returned keep their language, every call afterwards uses the
+/// tables of the new language.
+///
+/// # Example
+/// ```
+/// # use pokefinder_rs_core::enums::Language;
+/// # use pokefinder_rs_core::util::translator::{get_nature, set_language};
+/// set_language(Language::French);
+/// assert_eq!(get_nature(0), "Hardi");
+/// set_language(Language::Chinese);
+/// assert_eq!(get_nature(0), "勤奋");
+/// set_language(Language::English);
+/// assert_eq!(get_nature(0), "Hardy");
+/// ```
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language.into(), Ordering::Relaxed);
+}
+
 /// Gets the string for a specified ability.
 ///
 /// # Panics
@@ -147,7 +183,7 @@ fn read_file(translation: Translation) -> HashMap<u16, String> {
 /// assert_eq!(get_ability(ability), "Stench");
 /// ```
 pub fn get_ability(ability: u16) -> &'static str {
-    &ABILITIES[ability as usize - 1]
+    &tables().abilities[ability as usize - 1]
 }
 
 /// Gets the string for a specified characteristic.
@@ -168,7 +204,7 @@ pub fn get_ability(ability: u16) -> &'static str {
 /// assert_eq!(get_characteristic(characteristic), "Loves to eat");
 /// ```
 pub fn get_characteristic(characteristic: u8) -> &'static str {
-    &CHARACTERISTICS[characteristic as usize]
+    &tables().characteristics[characteristic as usize]
 }
 
 /// Gets all the characteristic strings.
@@ -179,7 +215,7 @@ pub fn get_characteristic(characteristic: u8) -> &'static str {
 /// let characteristics = get_characteristics();
 /// ```
 pub fn get_characteristics() -> &'static [String] {
-    &CHARACTERISTICS
+    &tables().characteristics
 }
 
 /// Gets the form string for the specified species and form.
@@ -202,7 +238,7 @@ pub fn get_characteristics() -> &'static [String] {
 /// assert_eq!(get_form(species, form), "Heat");
 /// ```
 pub fn get_form(species: u16, form: u8) -> &'static str {
-    &FORMS[&(((form as u16) << 11) | species)]
+    &tables().forms[&(((form as u16) << 11) | species)]
 }
 
 /// Gets the string for the specified [`Game`].
@@ -228,11 +264,12 @@ pub fn get_form(species: u16, form: u8) -> &'static str {
 /// assert_eq!(get_game(game), "Fire Red");
 /// ```
 pub fn get_game(version: Game) -> &'static str {
+    let games = &tables().games;
     let index = version.bits().trailing_zeros();
     if index == 32 {
-        &GAMES[GAMES.len() - 1]
+        &games[games.len() - 1]
     } else {
-        &GAMES[index as usize]
+        &games[index as usize]
     }
 }
 
@@ -284,7 +321,7 @@ pub fn get_genders() -> &'static [&'static str; 3] {
 /// assert_eq!(get_hidden_power(power), "Fighting");
 /// ```
 pub fn get_hidden_power(power: u8) -> &'static str {
-    &HIDDEN_POWERS[power as usize]
+    &tables().hidden_powers[power as usize]
 }
 
 /// Gets all the hidden power strings.
@@ -295,7 +332,7 @@ pub fn get_hidden_power(power: u8) -> &'static str {
 /// let hidden_powers = get_hidden_powers();
 /// ```
 pub fn get_hidden_powers() -> &'static [String] {
-    &HIDDEN_POWERS
+    &tables().hidden_powers
 }
 
 /// Gets the string for a specified item number.
@@ -316,7 +353,7 @@ pub fn get_hidden_powers() -> &'static [String] {
 /// assert_eq!(get_item(item), "Master Ball");
 /// ```
 pub fn get_item(item: u16) -> &'static str {
-    &ITEMS[item as usize]
+    &tables().items[item as usize]
 }
 
 /// Gets the strings for a specified list of item numbers.
@@ -339,7 +376,7 @@ pub fn get_item(item: u16) -> &'static str {
 pub fn get_items(items: &[u16]) -> Vec<&'static str> {
     items
         .iter()
-        .map(|&num| ITEMS[num as usize].as_str())
+        .map(|&num| tables().items[num as usize].as_str())
         .collect()
 }
 
@@ -441,7 +478,7 @@ pub fn get_locations(nums: &[u16], game: Game) -> Vec<String> {
         Translation::BDSP
     };
 
-    let map = read_file(translation);
+    let map = read_file(current_index(), translation);
     nums.iter().map(|num| map[num].to_string()).collect()
 }
 
@@ -463,7 +500,7 @@ pub fn get_locations(nums: &[u16], game: Game) -> Vec<String> {
 /// assert_eq!(get_move(mov), "Whirlwind");
 /// ```
 pub fn get_move(mov: u16) -> &'static str {
-    &MOVES[mov as usize]
+    &tables().moves[mov as usize]
 }
 
 /// Returns a string for a specified nature.
@@ -484,7 +521,7 @@ pub fn get_move(mov: u16) -> &'static str {
 /// assert_eq!(get_nature(nature), "Hardy");
 /// ```
 pub fn get_nature(nature: u8) -> &'static str {
-    &NATURES[nature as usize]
+    &tables().natures[nature as usize]
 }
 
 /// Gets all the nature strings.
@@ -495,7 +532,7 @@ pub fn get_nature(nature: u8) -> &'static str {
 /// let natures = get_natures();
 /// ```
 pub fn get_natures() -> &'static [String] {
-    &NATURES
+    &tables().natures
 }
 
 /// Returns the string for a specified species.
@@ -517,7 +554,7 @@ pub fn get_natures() -> &'static [String] {
 /// assert_eq!(species_name, "Diglett");
 /// ```
 pub fn get_species(species: u16) -> &'static str {
-    &SPECIES[(species as usize) - 1]
+    &tables().species[(species as usize) - 1]
 }
 
 /// Returns the string for a specified species and form.
@@ -545,10 +582,10 @@ pub fn get_species(species: u16) -> &'static str {
 /// assert_eq!(&species_name, "Rotom (Heat)");
 /// ```
 pub fn get_species_with_form(species: u16, form: u8) -> String {
-    if let Some(it) = FORMS.get(&(((form as u16) << 11) | species)) {
-        format!("{} ({})", SPECIES[(species as usize) - 1], it)
+    if let Some(it) = tables().forms.get(&(((form as u16) << 11) | species)) {
+        format!("{} ({})", tables().species[(species as usize) - 1], it)
     } else {
-        SPECIES[(species as usize) - 1].to_string()
+        tables().species[(species as usize) - 1].to_string()
     }
 }
 
@@ -215,11 +215,43 @@ pub mod utilities4 {
 
 /// Utility functions used by Gen 5 tools
 pub mod utilities5 {
-    use crate::enums::Game;
-    use crate::gen5::Profile5;
+    use crate::enums::{Buttons, Game};
+    use crate::gen5::{keypresses, Profile5};
     use crate::parents::Profile;
-    use crate::rng::BWRNG;
+    use crate::rng::{BWRNG, SHA1};
     use crate::util::utilities::{advance_probability_table, get_pitch};
+    use crate::util::DateTime;
+
+    /// Calculates initial seed for Gen 5 games based on a [`DateTime`], the held [`Buttons`],
+    /// and a `timer0` value.
+    ///
+    /// The remaining hardware parameters are taken from the specified [`Profile5`].
+    ///
+    /// # Example
+    /// ```
+    /// # use pokefinder_rs_core::enums::{Buttons, DSType, Game, Language};
+    /// # use pokefinder_rs_core::gen5::Profile5;
+    /// # use pokefinder_rs_core::util::DateTime;
+    /// # use pokefinder_rs_core::util::utilities5::calc_seed;
+    /// let profile = Profile5::new("-".to_string(), Game::BLACK, 12345, 54321, 0x9bf123456, [true, false, false, false], 0x2e, 6, 5, false, 0x608, 0x608, false, false, false, DSType::DS, Language::English);
+    /// let date_time = DateTime::new_ymd_hms(2000, 1, 1, 0, 0, 0);
+    /// let seed = calc_seed(&profile, &date_time, Buttons::NONE, 0x608);
+    /// assert_eq!(seed, 0x5e89803c95fe8240);
+    /// ```
+    pub fn calc_seed(
+        profile: &Profile5,
+        date_time: &DateTime,
+        buttons: Buttons,
+        timer0: u16,
+    ) -> u64 {
+        let mut sha = SHA1::new(profile);
+        sha.set_timer0(timer0 as u32, profile.get_vcount());
+        sha.set_button(keypresses::get_values(&[buttons])[0]);
+        sha.set_date(date_time.date());
+        sha.precompute();
+        sha.set_time(date_time.time(), profile.get_ds_type());
+        sha.hash_seed()
+    }
 
     /// Returns a modified PID to force a specified gender
     ///
@@ -268,6 +300,19 @@ pub mod utilities5 {
         get_pitch(prng)
     }
 
+    /// Returns the needle direction of a PRNG state
+    ///
+    /// # Example
+    /// ```
+    /// # use pokefinder_rs_core::util::utilities5::get_needle;
+    /// let prng = 0x1234567887654321;
+    /// let needle = get_needle(prng);
+    /// assert_eq!(needle, 0);
+    /// ```
+    pub fn get_needle(prng: u64) -> u8 {
+        (((prng >> 32) * 8) >> 32) as u8
+    }
+
     /// Calculates the initial set of advances based on the specified [`Profile5`].
     ///
     /// Based on the version of the specified [`Profile5`], this calls [`initial_advances_bw`] or
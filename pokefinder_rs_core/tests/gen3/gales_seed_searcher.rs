@@ -0,0 +1,123 @@
+use pokefinder_rs_core::gen3::searchers::{BattleNowCriteria, GalesSeedSearcher};
+use pokefinder_rs_core::rng::XDRNG;
+
+const SEEDS: [u32; 4] = [0x12345678, 0x9ABCDEF0, 0x0FEDCBA9, 0x87654321];
+
+/// Lead used for every team, Mewtwo at level 50
+const SPECIES: u16 = 150;
+const LEVEL: u8 = 50;
+
+/// HP stat of the lead for the HP `iv`, Mewtwo has a base HP of 106 and no EVs
+fn hp(iv: u8) -> u16 {
+    (2 * 106 + iv as u16) * LEVEL as u16 / 100 + LEVEL as u16 + 10
+}
+
+fn criteria(player_team: u8, enemy_team: u8, player_iv: u8, enemy_iv: u8) -> BattleNowCriteria {
+    BattleNowCriteria::new(
+        player_team,
+        enemy_team,
+        SPECIES,
+        LEVEL,
+        hp(player_iv),
+        SPECIES,
+        LEVEL,
+        hp(enemy_iv),
+    )
+}
+
+/// Rerolls the Battle Now teams of XD `rerolls` times where each Pokemon takes `pokemon_draws`
+/// calls before its PID, returns the observed criteria and the seed after the last reroll
+fn reroll(seed: u32, rerolls: usize, pokemon_draws: u32) -> (Vec<BattleNowCriteria>, u32) {
+    let mut rng = XDRNG::new(seed);
+    let mut observed = vec![];
+    for _ in 0..rerolls {
+        let enemy_team = (rng.next_u16() % 5) as u8;
+        let mut player_team = (rng.next_u16() % 5) as u8;
+        while player_team == enemy_team {
+            player_team = (rng.next_u16() % 5) as u8;
+        }
+
+        let tsv = rng.next_u16() ^ rng.next_u16();
+        let mut ivs = [0; 12];
+        for iv in ivs.iter_mut() {
+            let mut draws = vec![];
+            for _ in 0..pokemon_draws {
+                draws.push(rng.next_u16());
+            }
+            // The HP IV is in the first IV call, after the unused PID draw
+            *iv = (draws[pokemon_draws as usize - 3] & 0x1F) as u8;
+            loop {
+                let pid = ((rng.next_u16() as u32) << 16) | rng.next_u16() as u32;
+                if ((pid >> 16) ^ (pid & 0xFFFF)) as u16 ^ tsv >= 8 {
+                    break;
+                }
+            }
+        }
+
+        observed.push(criteria(player_team, enemy_team, ivs[6], ivs[0]));
+    }
+    (observed, rng.seed)
+}
+
+#[test]
+fn search_seeds() {
+    for seed in SEEDS {
+        let (criteria, result) = reroll(seed, 3, 5);
+
+        let searcher = GalesSeedSearcher::new(criteria);
+        searcher.start_search_seeds(&SEEDS);
+        assert_eq!(
+            searcher.get_results(),
+            vec![result],
+            "Unequal result: {seed:08X}"
+        );
+    }
+}
+
+#[test]
+fn pokemon_draws() {
+    // Five calls per Pokemon in XD, the three calls of Colosseum must not match
+    for seed in SEEDS {
+        let (criteria, _) = reroll(seed, 3, 3);
+        assert_ne!(criteria, reroll(seed, 3, 5).0);
+
+        let searcher = GalesSeedSearcher::new(criteria);
+        searcher.start_search_seeds(&[seed]);
+        assert!(
+            searcher.get_results().is_empty(),
+            "Unequal result: {seed:08X}"
+        );
+    }
+}
+
+#[test]
+fn any_hp() {
+    let (mut criteria, result) = reroll(SEEDS[0], 2, 5);
+    for criteria in criteria.iter_mut() {
+        criteria.player_hp = 0;
+        criteria.enemy_hp = 0;
+    }
+
+    let searcher = GalesSeedSearcher::new(criteria.clone());
+    searcher.start_search_seeds(&[SEEDS[0]]);
+    assert_eq!(searcher.get_results(), vec![result]);
+
+    // A wrong lead HP rejects the seed
+    criteria[1].enemy_hp = reroll(SEEDS[0], 2, 5).0[1].enemy_hp + 8;
+    let searcher = GalesSeedSearcher::new(criteria);
+    searcher.start_search_seeds(&[SEEDS[0]]);
+    assert!(searcher.get_results().is_empty());
+}
+
+#[test]
+fn hp_stat() {
+    assert_eq!(hp(0), 166);
+    assert_eq!(hp(31), 181);
+
+    // The stat comes from the base HP of the lead species, Mew has a base HP of 100
+    let (mut criteria, _) = reroll(SEEDS[0], 1, 5);
+    criteria[0].player_species = 151;
+    let searcher = GalesSeedSearcher::new(criteria);
+    searcher.start_search_seeds(&[SEEDS[0]]);
+    assert!(searcher.get_results().is_empty());
+}
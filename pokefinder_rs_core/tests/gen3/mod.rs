@@ -1,10 +1,13 @@
+mod colo_seed_searcher;
 mod egg_generator3;
+mod gales_seed_searcher;
 mod game_cube_generator;
 mod game_cube_searcher;
 mod id_generator3;
 mod pid_to_iv_calculator;
 mod poke_spot_generator;
 mod seed_to_time3;
+mod state_filter3;
 mod static_generator3;
 mod static_searcher3;
 mod wild_generator3;
@@ -0,0 +1,93 @@
+use pokefinder_rs_core::enums::{Game, Method, Shiny};
+use pokefinder_rs_core::gen4::filters::StateFilter4;
+use pokefinder_rs_core::gen4::generators::EventGenerator4;
+use pokefinder_rs_core::gen4::searchers::EventSearcher4;
+use pokefinder_rs_core::gen4::{Profile4, PCD, PGT};
+
+const SEED: u32 = 0x0A0B02A0;
+
+fn filter() -> StateFilter4 {
+    StateFilter4::new(
+        255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16],
+    )
+}
+
+fn profile() -> Profile4 {
+    Profile4::new("-".to_string(), Game::DIAMOND, 12345, 54321, false)
+}
+
+/// Searching the IVs of the first generated state has to find the seed it was generated from
+fn round_trip(pgt: &PGT) {
+    let profile = profile();
+    let filter = filter();
+
+    let generator = EventGenerator4::new(0, 0, 0, pgt, &profile, &filter);
+    let states = generator.generate(SEED);
+    assert_eq!(states.len(), 1);
+    let state = states[0].base.base;
+
+    let searcher = EventSearcher4::new(0, 0, 0x2A0, 0x2A0, pgt, &profile, &filter);
+    searcher.start_search(state.ivs, state.ivs);
+    let results = searcher.get_results();
+    assert!(
+        results
+            .iter()
+            .any(|result| result.base.seed == SEED && result.base.base.pid == state.pid),
+        "Seed not found"
+    );
+}
+
+#[test]
+fn parse() {
+    let mut data = [0u8; 0x358];
+    data[0x0] = 1;
+    data[0x8..0xc].copy_from_slice(&1u32.to_le_bytes());
+    data[0x10..0x12].copy_from_slice(&493u16.to_le_bytes());
+    data[0x14..0x16].copy_from_slice(&1u16.to_le_bytes());
+    data[0x16..0x18].copy_from_slice(&2u16.to_le_bytes());
+    for (i, c) in [0x012Bu16, 0x0145, 0xFFFF].iter().enumerate() {
+        data[0x70 + i * 2..0x72 + i * 2].copy_from_slice(&c.to_le_bytes());
+    }
+    data[0x94] = 100;
+    data[0x150..0x152].copy_from_slice(&42u16.to_le_bytes());
+
+    let pcd = PCD::try_from(&data[..]).unwrap();
+    assert_eq!(pcd.card_id, 42);
+
+    let pgt = pcd.gift;
+    assert_eq!(pgt.species, 493);
+    assert_eq!(pgt.tid, 1);
+    assert_eq!(pgt.sid, 2);
+    assert_eq!(pgt.level, 100);
+    assert_eq!(pgt.ot, "Aa");
+    assert!(pgt.random_ivs);
+    assert!(!pgt.egg);
+    assert!(pgt.get_method() == Method::Method1);
+    assert!(pgt.get_shiny() == Shiny::Random);
+}
+
+#[test]
+fn parse_short() {
+    let data = [0u8; 0x104];
+    assert!(PGT::try_from(&data[..0x103]).is_err());
+    assert!(PGT::try_from(&data[..]).is_ok());
+
+    let error = PCD::try_from(&data[..]).err().unwrap();
+    assert_eq!(error.expected, 0x358);
+    assert_eq!(error.actual, 0x104);
+}
+
+#[test]
+fn manaphy_egg() {
+    let pgt = PGT::new(1, 0, 0, 490, 1, None, true, true);
+    assert!(pgt.get_method() == Method::Method1);
+    assert!(pgt.get_shiny() == Shiny::Never);
+    round_trip(&pgt);
+}
+
+#[test]
+fn wondercard_ivs() {
+    let pgt = PGT::new(0x12345678, 1, 2, 385, 5, None, false, false);
+    assert!(pgt.get_method() == Method::WondercardIVs);
+    round_trip(&pgt);
+}
@@ -1,8 +1,10 @@
 mod chained_sid_calc;
 mod egg_generator4;
+mod event_generator4;
 mod id_generator4;
 mod id_searcher4;
 mod seed_to_time_calculator4;
+mod state_filter4;
 mod static_generator4;
 mod static_searcher4;
 mod wild_generator4;
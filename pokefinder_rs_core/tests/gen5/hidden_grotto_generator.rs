@@ -0,0 +1,97 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{DSType, Game, Language};
+use pokefinder_rs_core::gen5::filters::HiddenGrottoFilter;
+use pokefinder_rs_core::gen5::generators::HiddenGrottoGenerator;
+use pokefinder_rs_core::gen5::states::HiddenGrottoState;
+use pokefinder_rs_core::gen5::{encounters5, Profile5};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct HiddenGrottoData<'a> {
+    #[serde(borrow)]
+    generate: Vec<HiddenGrottoGenerateData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct HiddenGrottoGenerateData<'a> {
+    name: &'a str,
+    seed: u64,
+    location: u8,
+    results: Vec<HiddenGrottoGenerateResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct HiddenGrottoGenerateResult {
+    advances: u32,
+    chatot: u8,
+    data: u16,
+    gender: u8,
+    group: u8,
+    item: bool,
+    slot: u8,
+}
+
+impl From<HiddenGrottoState> for HiddenGrottoGenerateResult {
+    fn from(value: HiddenGrottoState) -> Self {
+        Self {
+            advances: value.advances,
+            chatot: value.chatot,
+            data: value.data,
+            gender: value.gender,
+            group: value.group,
+            item: value.item,
+            slot: value.slot,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("hiddengrotto.json");
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, HiddenGrottoData>(JSON_DATA);
+
+    for (
+        num,
+        HiddenGrottoGenerateData {
+            name,
+            seed,
+            location,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let profile = Profile5::new(
+            "-".to_string(),
+            Game::BW2,
+            12345,
+            54321,
+            0,
+            [false, false, false, false],
+            0,
+            0,
+            0,
+            false,
+            0,
+            0,
+            false,
+            false,
+            false,
+            DSType::DS,
+            Language::English,
+        );
+
+        let encounters = encounters5::get_hidden_grotto_encounters();
+        let area = encounters
+            .iter()
+            .find(|area| area.location == location)
+            .unwrap();
+
+        let filter = HiddenGrottoFilter::new([true; 11], [true; 2], [true; 4]);
+        let generator = HiddenGrottoGenerator::new(0, 99, 0, 55, &profile, &filter);
+
+        let states = generator.generate(seed, area);
+
+        assert_states(results, states, name, num);
+    }
+}
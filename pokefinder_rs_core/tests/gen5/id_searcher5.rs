@@ -0,0 +1,27 @@
+use super::searcher5::{get_seed_data, search};
+use pokefinder_rs_core::gen5::generators::IDGenerator5;
+use pokefinder_rs_core::gen5::searchers::{IDSearcher5, IDSearcherGenerator5};
+use pokefinder_rs_core::parents::filters::IDFilter;
+
+#[test]
+fn search_ids() {
+    for data in get_seed_data() {
+        let profile = data.profile();
+
+        let open = IDFilter::new(vec![], vec![], vec![], vec![]);
+        let expected =
+            IDGenerator5::new(0, 5, &profile, &open).generate(data.seed, 0, false, false)[3];
+
+        let filter = IDFilter::new(vec![expected.tid], vec![expected.sid], vec![], vec![]);
+        let generator =
+            IDSearcherGenerator5::new(IDGenerator5::new(0, 5, &profile, &filter), 0, false, false);
+        let searcher = IDSearcher5::new(&generator, &profile);
+
+        let results = search(&searcher, &data);
+        assert!(
+            results.iter().any(|result| result.state == expected),
+            "TID/SID not found: {}",
+            data.name
+        );
+    }
+}
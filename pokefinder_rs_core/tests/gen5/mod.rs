@@ -1,2 +1,12 @@
 mod dream_radar_generator;
+mod hidden_grotto_generator;
 mod id_generator5;
+mod id_searcher5;
+mod mt_seed_searcher5;
+mod profile_searcher5;
+mod searcher5;
+mod static_generator5;
+mod wild_generator5;
+
+const STATIC_DATA: &str = include_str!("static5.json");
+const WILD_DATA: &str = include_str!("wild5.json");
@@ -0,0 +1,50 @@
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen5::searchers::{MTSeedSearcher5, MaxFrameError};
+use pokefinder_rs_core::rng::{Rng, MT};
+use std::thread;
+
+const SEED: u32 = 0x1234;
+
+fn ivs(seed: u32, advances: u32) -> [u8; 6] {
+    let mut mt = MT::new_with_initial_advances(seed, advances);
+    std::array::from_fn(|_| (mt.next() >> 27) as u8)
+}
+
+#[test]
+fn search() {
+    for (version, offset) in [(Game::BLACK, 0), (Game::BLACK2, 2)] {
+        let ivs = ivs(SEED, 5 + offset);
+        let searcher = MTSeedSearcher5::new(ivs, ivs, 0, 10, version).unwrap();
+
+        // The seed has its upper 16 bits clear so the first block of a single thread finds it
+        thread::scope(|scope| {
+            let search = scope.spawn(|| searcher.start_search(1));
+            while searcher.get_progress() == 0 {
+                thread::yield_now();
+            }
+            searcher.cancel_search();
+            search.join().unwrap();
+        });
+
+        let results = searcher.get_results();
+        let state = results
+            .iter()
+            .find(|state| state.seed == SEED)
+            .expect("Seed not found");
+        assert_eq!(state.advances, 5);
+        assert_eq!(state.ivs, ivs);
+    }
+}
+
+#[test]
+fn max_frame() {
+    assert!(MTSeedSearcher5::new([0; 6], [31; 6], 0, 26, Game::BLACK).is_ok());
+    assert_eq!(
+        MTSeedSearcher5::new([0; 6], [31; 6], 0, 27, Game::BLACK).err(),
+        Some(MaxFrameError { max_frame: 26 })
+    );
+    assert_eq!(
+        MTSeedSearcher5::new([0; 6], [31; 6], 0, 25, Game::WHITE2).err(),
+        Some(MaxFrameError { max_frame: 24 })
+    );
+}
@@ -0,0 +1,173 @@
+use crate::get_test_data;
+use pokefinder_rs_core::enums::{Buttons, DSType, Game, Language};
+use pokefinder_rs_core::gen5::searchers::{
+    ProfileCriteria5, ProfileIVCriteria5, ProfileNeedleCriteria5, ProfileSearcher5,
+    ProfileSeedCriteria5,
+};
+use pokefinder_rs_core::util::{Date, Time};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ProfileSearcher5Data<'a> {
+    #[serde(borrow)]
+    ivs: Vec<IVData<'a>>,
+    #[serde(borrow)]
+    needle: Vec<NeedleData<'a>>,
+    #[serde(borrow)]
+    seed: Vec<SeedData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct SearcherData<'a> {
+    name: &'a str,
+    date: u32,
+    time: u32,
+    #[serde(rename = "minSeconds")]
+    min_seconds: u8,
+    #[serde(rename = "maxSeconds")]
+    max_seconds: u8,
+    #[serde(rename = "minVCount")]
+    min_vcount: u8,
+    #[serde(rename = "maxVCount")]
+    max_vcount: u8,
+    #[serde(rename = "minTimer0")]
+    min_timer0: u16,
+    #[serde(rename = "maxTimer0")]
+    max_timer0: u16,
+    #[serde(rename = "minGxStat")]
+    min_gx_stat: u8,
+    #[serde(rename = "maxGxStat")]
+    max_gx_stat: u8,
+    #[serde(rename = "minVFrame")]
+    min_vframe: u8,
+    #[serde(rename = "maxVFrame")]
+    max_vframe: u8,
+    #[serde(rename = "softReset")]
+    soft_reset: bool,
+    version: &'a str,
+    mac: u64,
+}
+
+#[derive(Deserialize)]
+struct IVData<'a> {
+    #[serde(borrow, flatten)]
+    base: SearcherData<'a>,
+    #[serde(rename = "minIVs")]
+    min_ivs: [u8; 6],
+    #[serde(rename = "maxIVs")]
+    max_ivs: [u8; 6],
+    result: u64,
+}
+
+#[derive(Deserialize)]
+struct NeedleData<'a> {
+    #[serde(borrow, flatten)]
+    base: SearcherData<'a>,
+    needles: Vec<u8>,
+    #[serde(rename = "unovaLink")]
+    unova_link: bool,
+    #[serde(rename = "memoryLink")]
+    memory_link: bool,
+    result: u64,
+}
+
+#[derive(Deserialize)]
+struct SeedData<'a> {
+    #[serde(borrow, flatten)]
+    base: SearcherData<'a>,
+    seed: u64,
+}
+
+const JSON_DATA: &str = include_str!("profilesearcher5.json");
+
+fn get_version(version: &str) -> Game {
+    match version {
+        "Black" => Game::BLACK,
+        "White" => Game::WHITE,
+        "Black2" => Game::BLACK2,
+        _ => Game::WHITE2,
+    }
+}
+
+fn search<C: ProfileCriteria5>(data: &SearcherData, criteria: C) -> Vec<u64> {
+    let searcher = ProfileSearcher5::new(
+        Date::new(data.date),
+        Time::new(data.time),
+        data.min_seconds,
+        data.max_seconds,
+        data.min_vcount,
+        data.max_vcount,
+        data.min_timer0,
+        data.max_timer0,
+        data.min_gx_stat,
+        data.max_gx_stat,
+        data.min_vframe,
+        data.max_vframe,
+        data.soft_reset,
+        get_version(data.version),
+        Language::English,
+        DSType::DS,
+        data.mac,
+        Buttons::NONE,
+        criteria,
+    );
+
+    searcher.start_search(2);
+
+    searcher
+        .get_results()
+        .into_iter()
+        .map(|state| state.seed)
+        .collect()
+}
+
+#[test]
+fn ivs() {
+    let data = get_test_data::<'static, ProfileSearcher5Data>(JSON_DATA);
+
+    for IVData {
+        base,
+        min_ivs,
+        max_ivs,
+        result,
+    } in data.ivs
+    {
+        let criteria = ProfileIVCriteria5::new(min_ivs, max_ivs, get_version(base.version));
+        let seeds = search(&base, criteria);
+        assert_eq!(seeds, vec![result], "Unequal results: {}", base.name);
+    }
+}
+
+#[test]
+fn needle() {
+    let data = get_test_data::<'static, ProfileSearcher5Data>(JSON_DATA);
+
+    for NeedleData {
+        base,
+        needles,
+        unova_link,
+        memory_link,
+        result,
+    } in data.needle
+    {
+        let criteria = ProfileNeedleCriteria5::new(
+            needles,
+            get_version(base.version),
+            unova_link,
+            memory_link,
+        );
+        let seeds = search(&base, criteria);
+        assert_eq!(seeds, vec![result], "Unequal results: {}", base.name);
+    }
+}
+
+#[test]
+fn seed() {
+    let data = get_test_data::<'static, ProfileSearcher5Data>(JSON_DATA);
+
+    for SeedData { base, seed } in data.seed {
+        let criteria = ProfileSeedCriteria5::new(seed);
+        let seeds = search(&base, criteria);
+        assert_eq!(seeds, vec![seed], "Unequal results: {}", base.name);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::get_test_data;
+use pokefinder_rs_core::enums::{DSType, Game, Language};
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::{DreamRadarGenerator, EggGenerator5, EventGenerator5};
+use pokefinder_rs_core::gen5::searchers::{
+    DreamRadarSearcher5, EggSearcher5, EventSearcher5, Searcher5, SeedGenerator5,
+};
+use pokefinder_rs_core::gen5::states::SearcherState5;
+use pokefinder_rs_core::gen5::{encounters5, Profile5, PGF};
+use pokefinder_rs_core::parents::Daycare;
+use pokefinder_rs_core::util::Date;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ProfileSearcher5Data<'a> {
+    #[serde(borrow)]
+    seed: Vec<SeedData<'a>>,
+}
+
+/// Initial seed the profile searcher finds within the first minute of `date`
+#[derive(Deserialize)]
+pub(super) struct SeedData<'a> {
+    pub name: &'a str,
+    version: &'a str,
+    pub date: u32,
+    #[serde(rename = "minVCount")]
+    vcount: u8,
+    #[serde(rename = "minTimer0")]
+    timer0: u16,
+    #[serde(rename = "minGxStat")]
+    gx_stat: u8,
+    #[serde(rename = "minVFrame")]
+    vframe: u8,
+    mac: u64,
+    pub seed: u64,
+}
+
+impl SeedData<'_> {
+    pub fn profile(&self) -> Profile5 {
+        Profile5::new(
+            "-".to_string(),
+            if self.version == "Black" {
+                Game::BLACK
+            } else {
+                Game::BLACK2
+            },
+            12345,
+            54321,
+            self.mac,
+            [true, false, false, false],
+            self.vcount,
+            self.gx_stat,
+            self.vframe,
+            false,
+            self.timer0,
+            self.timer0,
+            false,
+            false,
+            false,
+            DSType::DS,
+            Language::English,
+        )
+    }
+}
+
+const JSON_DATA: &str = include_str!("profilesearcher5.json");
+
+pub(super) fn get_seed_data() -> Vec<SeedData<'static>> {
+    get_test_data::<'static, ProfileSearcher5Data>(JSON_DATA).seed
+}
+
+/// Searches the day of `data` and returns the results with its initial seed
+pub(super) fn search<G: SeedGenerator5>(
+    searcher: &Searcher5<G>,
+    data: &SeedData,
+) -> Vec<SearcherState5<G::State>> {
+    let date = Date::new(data.date);
+    searcher.start_search(2, date, date);
+
+    let results: Vec<_> = searcher
+        .get_results()
+        .into_iter()
+        .filter(|state| state.initial_seed == data.seed)
+        .collect();
+    assert!(!results.is_empty(), "Seed not found: {}", data.name);
+    for state in &results {
+        assert!(
+            state.dt.date() == date && state.dt.time().hour() == 0 && state.dt.time().minute() == 0,
+            "Unexpected date/time: {}",
+            data.name
+        );
+    }
+    results
+}
+
+fn filter() -> StateFilter5 {
+    StateFilter5::new(
+        255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16],
+    )
+}
+
+#[test]
+fn event() {
+    let filter = filter();
+    let pgf = PGF::new(0, 0, 519, 0, 1, 1, 0, 1, 255, 31, 255, 255, 255, 255, true);
+
+    for data in get_seed_data() {
+        let profile = data.profile();
+        let generator = EventGenerator5::new(0, 2, 0, &pgf, &profile, &filter);
+        let searcher = EventSearcher5::new(&generator, &profile);
+
+        let expected = generator.generate(data.seed);
+        let results = search(&searcher, &data);
+        assert_eq!(
+            results.len(),
+            expected.len(),
+            "Unequal results size: {}",
+            data.name
+        );
+        for (result, state) in results.iter().zip(expected.iter()) {
+            assert_eq!(result.state.base.advances, state.base.advances);
+            assert_eq!(result.state.base.base.pid, state.base.base.pid);
+            assert_eq!(result.state.base.base.ivs, state.base.base.ivs);
+        }
+    }
+}
+
+#[test]
+fn egg() {
+    let filter = filter();
+    let daycare = Daycare::new([[31; 6], [31; 6]], [0, 2], [0, 1], [0, 1], [0, 0], 1, false);
+
+    for data in get_seed_data() {
+        let profile = data.profile();
+        let generator = EggGenerator5::new(0, 2, 0, &daycare, &profile, &filter);
+        let searcher = EggSearcher5::new(&generator, &profile);
+
+        let expected = generator.generate(data.seed);
+        let results = search(&searcher, &data);
+        assert_eq!(
+            results.len(),
+            expected.len(),
+            "Unequal results size: {}",
+            data.name
+        );
+        for (result, state) in results.iter().zip(expected.iter()) {
+            assert_eq!(result.state.base.advances, state.base.advances);
+            assert_eq!(result.state.base.base.base.pid, state.base.base.base.pid);
+            assert_eq!(result.state.base.base.base.ivs, state.base.base.base.ivs);
+        }
+    }
+}
+
+#[test]
+fn dream_radar() {
+    let filter = filter();
+    let templates = &encounters5::get_dream_radar_encounters()[..1];
+
+    // Dream Radar is only available in BW2
+    for data in get_seed_data()
+        .into_iter()
+        .filter(|data| data.version == "Black2")
+    {
+        let profile = data.profile();
+        let generator = DreamRadarGenerator::new(0, 2, 0, templates, &profile, &filter);
+        let searcher = DreamRadarSearcher5::new(&generator, &profile);
+
+        let expected = generator.generate(data.seed);
+        let results = search(&searcher, &data);
+        assert_eq!(
+            results.len(),
+            expected.len(),
+            "Unequal results size: {}",
+            data.name
+        );
+        for (result, state) in results.iter().zip(expected.iter()) {
+            assert_eq!(result.state.base.advances, state.base.advances);
+            assert_eq!(result.state.needle, state.needle);
+            assert_eq!(result.state.base.base.pid, state.base.base.pid);
+            assert_eq!(result.state.base.base.ivs, state.base.base.ivs);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::gen5::STATIC_DATA;
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{DSType, Encounter, Game, Language, Lead, Method};
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::StaticGenerator5;
+use pokefinder_rs_core::gen5::states::State5;
+use pokefinder_rs_core::gen5::{encounters5, Profile5};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Static5Data<'a> {
+    #[serde(borrow)]
+    staticgenerator5: StaticGeneratorData<'a>,
+}
+
+#[derive(Deserialize)]
+struct StaticGeneratorData<'a> {
+    #[serde(rename = "generateMethod5IVs", borrow)]
+    generate_method_5_ivs: Vec<GeneratorIVsData<'a>>,
+    #[serde(rename = "generateMethod5CGear", borrow)]
+    generate_method_5_cgear: Vec<GeneratorIVsData<'a>>,
+    #[serde(rename = "generateMethod5", borrow)]
+    generate_method_5: Vec<GeneratorMethod5Data<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorIVsData<'a> {
+    name: &'a str,
+    seed: u64,
+    version: u32,
+    category: u8,
+    pokemon: u16,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorMethod5Data<'a> {
+    name: &'a str,
+    seed: u64,
+    version: u32,
+    category: u8,
+    pokemon: u16,
+    lead: u8,
+    #[serde(rename = "shinyCharm")]
+    shiny_charm: bool,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    chatot: u8,
+    gender: u8,
+    #[serde(rename = "hiddenPower")]
+    hidden_power: u8,
+    #[serde(rename = "hiddenPowerStrength")]
+    hidden_power_strength: u8,
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    stats: [u16; 6],
+}
+
+impl From<State5> for GeneratorResult {
+    fn from(value: State5) -> Self {
+        Self {
+            ability: value.base.base.ability,
+            ability_index: value.base.base.ability_index,
+            advances: value.base.advances,
+            characteristic: value.base.base.characteristic,
+            chatot: value.chatot,
+            gender: value.base.base.gender,
+            hidden_power: value.base.base.hidden_power,
+            hidden_power_strength: value.base.base.hidden_power_strength,
+            ivs: value.base.base.ivs,
+            level: value.base.base.level,
+            nature: value.base.base.nature,
+            pid: value.base.base.pid,
+            shiny: value.base.base.shiny,
+            stats: value.base.base.stats,
+        }
+    }
+}
+
+fn profile(version: u32, shiny_charm: bool) -> Profile5 {
+    Profile5::new(
+        "-".to_string(),
+        Game::from_bits_retain(version),
+        12345,
+        54321,
+        0,
+        [false, false, false, false],
+        0,
+        0,
+        0,
+        false,
+        0,
+        0,
+        false,
+        false,
+        shiny_charm,
+        DSType::DS,
+        Language::English,
+    )
+}
+
+fn encounter(category: u8) -> Encounter {
+    match category {
+        0..=2 => Encounter::Gift,
+        6 => Encounter::Roamer,
+        _ => Encounter::Static,
+    }
+}
+
+fn filter() -> StateFilter5 {
+    StateFilter5::new(
+        255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16],
+    )
+}
+
+fn generate_ivs(method: Method, data: Vec<GeneratorIVsData>) {
+    for (
+        num,
+        GeneratorIVsData {
+            name,
+            seed,
+            version,
+            category,
+            pokemon,
+            results,
+        },
+    ) in data.into_iter().enumerate()
+    {
+        let profile = profile(version, false);
+        let filter = filter();
+
+        let static_template =
+            encounters5::get_static_encounter(category as usize, pokemon as usize);
+        let generator = StaticGenerator5::new(
+            0,
+            9,
+            0,
+            method,
+            encounter(category),
+            Lead::NONE,
+            &profile,
+            &filter,
+        );
+
+        let states = generator.generate(seed, static_template);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+#[test]
+fn generate_method_5_ivs() {
+    let data = get_test_data::<'static, Static5Data>(STATIC_DATA);
+    generate_ivs(
+        Method::Method5IVs,
+        data.staticgenerator5.generate_method_5_ivs,
+    );
+}
+
+#[test]
+fn generate_method_5_cgear() {
+    let data = get_test_data::<'static, Static5Data>(STATIC_DATA);
+    generate_ivs(
+        Method::Method5CGear,
+        data.staticgenerator5.generate_method_5_cgear,
+    );
+}
+
+#[test]
+fn generate_method_5() {
+    let data = get_test_data::<'static, Static5Data>(STATIC_DATA);
+
+    for (
+        num,
+        GeneratorMethod5Data {
+            name,
+            seed,
+            version,
+            category,
+            pokemon,
+            lead,
+            shiny_charm,
+            results,
+        },
+    ) in data
+        .staticgenerator5
+        .generate_method_5
+        .into_iter()
+        .enumerate()
+    {
+        let profile = profile(version, shiny_charm);
+        let filter = filter();
+
+        let static_template =
+            encounters5::get_static_encounter(category as usize, pokemon as usize);
+        let generator = StaticGenerator5::new(
+            0,
+            9,
+            0,
+            Method::Method5,
+            encounter(category),
+            Lead::from_bits_retain(lead),
+            &profile,
+            &filter,
+        );
+
+        let states = generator.generate(seed, static_template);
+
+        assert_states(results, states, name, num);
+    }
+}
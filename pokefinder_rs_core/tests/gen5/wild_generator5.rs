@@ -0,0 +1,264 @@
+use crate::gen5::WILD_DATA;
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{DSType, Encounter, Game, Language, Lead, Method};
+use pokefinder_rs_core::gen5::filters::WildStateFilter5;
+use pokefinder_rs_core::gen5::generators::WildGenerator5;
+use pokefinder_rs_core::gen5::states::WildState5;
+use pokefinder_rs_core::gen5::{encounters5, EncounterArea5, Profile5};
+use pokefinder_rs_core::parents::EncounterAreaT;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Wild5Data<'a> {
+    #[serde(borrow)]
+    wildgenerator5: GeneratorData<'a>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorData<'a> {
+    #[serde(rename = "generateMethod5IVs", borrow)]
+    generate_method_5_ivs: Vec<GenerateIVsData<'a>>,
+    #[serde(rename = "generateMethod5", borrow)]
+    generate_method_5: Vec<GenerateMethod5Data<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GenerateIVsData<'a> {
+    name: &'a str,
+    seed: u64,
+    version: u32,
+    encounter: u8,
+    location: u8,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize)]
+struct GenerateMethod5Data<'a> {
+    name: &'a str,
+    seed: u64,
+    version: u32,
+    encounter: u8,
+    lead: u8,
+    location: u8,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    chatot: u8,
+    #[serde(rename = "encounterSlot")]
+    encounter_slot: u8,
+    form: u8,
+    gender: u8,
+    #[serde(rename = "hiddenPower")]
+    hidden_power: u8,
+    #[serde(rename = "hiddenPowerStrength")]
+    hidden_power_strength: u8,
+    item: u16,
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    species: u16,
+    stats: [u16; 6],
+}
+
+impl From<WildState5> for GeneratorResult {
+    fn from(value: WildState5) -> Self {
+        Self {
+            ability: value.base.base.base.ability,
+            ability_index: value.base.base.base.ability_index,
+            advances: value.base.advances,
+            characteristic: value.base.base.base.characteristic,
+            chatot: value.chatot,
+            encounter_slot: value.base.base.encounter_slot,
+            form: value.base.base.form,
+            gender: value.base.base.base.gender,
+            hidden_power: value.base.base.base.hidden_power,
+            hidden_power_strength: value.base.base.base.hidden_power_strength,
+            item: value.base.base.item,
+            ivs: value.base.base.base.ivs,
+            level: value.base.base.base.level,
+            nature: value.base.base.base.nature,
+            pid: value.base.base.base.pid,
+            shiny: value.base.base.base.shiny,
+            species: value.base.base.species,
+            stats: value.base.base.base.stats,
+        }
+    }
+}
+
+fn profile(version: u32) -> Profile5 {
+    Profile5::new(
+        "-".to_string(),
+        Game::from_bits_retain(version),
+        12345,
+        54321,
+        0,
+        [false, false, false, false],
+        0,
+        0,
+        0,
+        false,
+        0,
+        0,
+        false,
+        false,
+        false,
+        DSType::DS,
+        Language::English,
+    )
+}
+
+fn encounter_area(encounter: Encounter, location: u8, profile: &Profile5) -> EncounterArea5 {
+    encounters5::get_encounters(encounter, 0, profile)
+        .into_iter()
+        .find(|ea| ea.get_location() == location)
+        .unwrap()
+}
+
+fn filter(encounter_slots: [bool; 12]) -> WildStateFilter5 {
+    WildStateFilter5::new(
+        255,
+        255,
+        255,
+        false,
+        [0; 6],
+        [31; 6],
+        [true; 25],
+        [true; 16],
+        encounter_slots,
+    )
+}
+
+#[test]
+fn generate_method_5_ivs() {
+    let data = get_test_data::<'static, Wild5Data>(WILD_DATA);
+    for (
+        num,
+        GenerateIVsData {
+            name,
+            seed,
+            version,
+            encounter,
+            location,
+            results,
+        },
+    ) in data
+        .wildgenerator5
+        .generate_method_5_ivs
+        .into_iter()
+        .enumerate()
+    {
+        let profile = profile(version);
+        let encounter = Encounter::from(encounter);
+        let encounter_area = encounter_area(encounter, location, &profile);
+
+        let filter = filter([true; 12]);
+        let generator = WildGenerator5::new(
+            0,
+            9,
+            0,
+            Method::Method5IVs,
+            encounter,
+            Lead::NONE,
+            &profile,
+            &filter,
+        );
+
+        let states = generator.generate(seed, &encounter_area);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+#[test]
+fn generate_method_5() {
+    let data = get_test_data::<'static, Wild5Data>(WILD_DATA);
+    for (
+        num,
+        GenerateMethod5Data {
+            name,
+            seed,
+            version,
+            encounter,
+            lead,
+            location,
+            results,
+        },
+    ) in data
+        .wildgenerator5
+        .generate_method_5
+        .into_iter()
+        .enumerate()
+    {
+        let profile = profile(version);
+        let encounter = Encounter::from(encounter);
+        let encounter_area = encounter_area(encounter, location, &profile);
+
+        let filter = filter([true; 12]);
+        let generator = WildGenerator5::new(
+            0,
+            9,
+            0,
+            Method::Method5,
+            encounter,
+            Lead::from_bits_retain(lead),
+            &profile,
+            &filter,
+        );
+
+        let states = generator.generate(seed, &encounter_area);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+#[test]
+fn filter_encounter_slot() {
+    let data = get_test_data::<'static, Wild5Data>(WILD_DATA);
+    for GenerateMethod5Data {
+        name,
+        seed,
+        version,
+        encounter,
+        lead,
+        location,
+        results,
+    } in data.wildgenerator5.generate_method_5
+    {
+        let profile = profile(version);
+        let encounter = Encounter::from(encounter);
+        let encounter_area = encounter_area(encounter, location, &profile);
+
+        let slot = results[0].encounter_slot;
+        let mut encounter_slots = [false; 12];
+        encounter_slots[slot as usize] = true;
+
+        let filter = filter(encounter_slots);
+        let generator = WildGenerator5::new(
+            0,
+            9,
+            0,
+            Method::Method5,
+            encounter,
+            Lead::from_bits_retain(lead),
+            &profile,
+            &filter,
+        );
+
+        let expected: Vec<_> = results
+            .into_iter()
+            .filter(|result| result.encounter_slot == slot)
+            .collect();
+        let states = generator.generate(seed, &encounter_area);
+
+        assert_states(expected, states, name, 0);
+    }
+}
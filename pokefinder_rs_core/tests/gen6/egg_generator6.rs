@@ -0,0 +1,195 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen6::filters::StateFilter6;
+use pokefinder_rs_core::gen6::generators::EggGenerator6;
+use pokefinder_rs_core::gen6::Profile6;
+use pokefinder_rs_core::parents::states::EggGeneratorState;
+use pokefinder_rs_core::parents::Daycare;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Egg6Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<EggGenerator6Data<'a>>,
+}
+
+#[derive(Deserialize)]
+struct EggGenerator6Data<'a> {
+    name: &'a str,
+    seed: [u32; 4],
+    pokemon: u16,
+    #[serde(rename = "parentIVs")]
+    parent_ivs: [[u8; 6]; 2],
+    #[serde(rename = "parentAbility")]
+    parent_ability: [u8; 2],
+    #[serde(rename = "parentGender")]
+    parent_gender: [u8; 2],
+    #[serde(rename = "parentItem")]
+    parent_item: [u8; 2],
+    #[serde(rename = "parentNature")]
+    parent_nature: [u8; 2],
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    ec: u32,
+    gender: u8,
+    inheritance: [u8; 6],
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    stats: [u16; 6],
+}
+
+impl From<EggGeneratorState> for GeneratorResult {
+    fn from(value: EggGeneratorState) -> Self {
+        Self {
+            ability: value.base.base.ability,
+            ability_index: value.base.base.ability_index,
+            advances: value.advances,
+            characteristic: value.base.base.characteristic,
+            ec: value.base.base.ec,
+            gender: value.base.base.gender,
+            inheritance: value.base.inheritance,
+            ivs: value.base.base.ivs,
+            level: value.base.base.level,
+            nature: value.base.base.nature,
+            pid: value.base.base.pid,
+            shiny: value.base.base.shiny,
+            stats: value.base.base.stats,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("egg6.json");
+const SEED: [u32; 4] = [0x12345678, 0x9ABCDEF0, 0x0FEDCBA9, 0x87654321];
+const PARENT_IVS: [[u8; 6]; 2] = [[0, 1, 2, 3, 4, 5], [10, 11, 12, 13, 14, 15]];
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, Egg6Data>(JSON_DATA);
+
+    for (
+        num,
+        EggGenerator6Data {
+            name,
+            seed,
+            pokemon,
+            parent_ivs,
+            parent_ability,
+            parent_gender,
+            parent_item,
+            parent_nature,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let min = [0; 6];
+        let max = [31; 6];
+        let natures = [true; 25];
+        let powers = [true; 16];
+        let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, false);
+
+        let daycare = Daycare::new(
+            parent_ivs,
+            parent_ability,
+            parent_gender,
+            parent_item,
+            parent_nature,
+            pokemon,
+            true,
+        );
+        let filter = StateFilter6::new(255, 255, 255, false, min, max, natures, powers);
+        let generator = EggGenerator6::new(0, 9, 0, 88, &daycare, &profile, &filter);
+
+        let states = generator.generate(seed);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+fn generate_daycare(
+    parent_ability: [u8; 2],
+    parent_item: [u8; 2],
+    parent_nature: [u8; 2],
+) -> Vec<EggGeneratorState> {
+    let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, false);
+    let daycare = Daycare::new(
+        PARENT_IVS,
+        parent_ability,
+        [0, 1],
+        parent_item,
+        parent_nature,
+        1,
+        false,
+    );
+    let filter = StateFilter6::new(255, 255, 255, true, [0; 6], [31; 6], [true; 25], [true; 16]);
+    let generator = EggGenerator6::new(0, 199, 0, 88, &daycare, &profile, &filter);
+
+    generator.generate(SEED)
+}
+
+#[test]
+fn destiny_knot() {
+    for (item, count) in [(0, 3), (8, 5)] {
+        let states = generate_daycare([0, 0], [item, 0], [0, 0]);
+        assert_eq!(states.len(), 200);
+        for state in states {
+            let inheritance = state.base.inheritance;
+            assert_eq!(
+                inheritance.iter().filter(|&&parent| parent != 0).count(),
+                count,
+                "Unequal result: {} - {}",
+                item,
+                state.advances
+            );
+
+            for (i, &parent) in inheritance.iter().enumerate() {
+                if parent != 0 {
+                    assert_eq!(
+                        state.base.base.ivs[i],
+                        PARENT_IVS[parent as usize - 1][i],
+                        "Unequal result: {} - {}",
+                        item,
+                        state.advances
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn everstone() {
+    let states = generate_daycare([0, 0], [1, 0], [3, 10]);
+    assert!(states.iter().all(|state| state.base.base.nature == 3));
+
+    let states = generate_daycare([0, 0], [0, 1], [3, 10]);
+    assert!(states.iter().all(|state| state.base.base.nature == 10));
+
+    let states = generate_daycare([0, 0], [1, 1], [3, 10]);
+    assert!(states
+        .iter()
+        .all(|state| matches!(state.base.base.nature, 3 | 10)));
+    assert!(states.iter().any(|state| state.base.base.nature == 3));
+    assert!(states.iter().any(|state| state.base.base.nature == 10));
+}
+
+#[test]
+fn hidden_ability() {
+    // The ability is passed down from the female parent
+    let states = generate_daycare([2, 0], [0, 0], [0, 0]);
+    assert!(states.iter().all(|state| state.base.base.ability < 2));
+
+    let states = generate_daycare([0, 2], [0, 0], [0, 0]);
+    assert!(states.iter().all(|state| state.base.base.ability <= 2));
+    assert!(states.iter().any(|state| state.base.base.ability == 2));
+}
@@ -0,0 +1,4 @@
+mod egg_generator6;
+mod state_filter6;
+mod static_generator6;
+mod wild_generator6;
@@ -0,0 +1,209 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{Game, Lead, Shiny};
+use pokefinder_rs_core::gen6::filters::StateFilter6;
+use pokefinder_rs_core::gen6::generators::StaticGenerator6;
+use pokefinder_rs_core::gen6::Profile6;
+use pokefinder_rs_core::parents::states::GeneratorState;
+use pokefinder_rs_core::parents::StaticTemplate;
+use pokefinder_rs_core::rng::{Rng, MT};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Static6Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<GenerateData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GenerateData<'a> {
+    name: &'a str,
+    seed: u32,
+    lead: u8,
+    #[serde(rename = "shinyCharm")]
+    shiny_charm: bool,
+    species: u16,
+    shiny: u8,
+    ability: u8,
+    #[serde(rename = "ivCount")]
+    iv_count: u8,
+    level: u8,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    ec: u32,
+    gender: u8,
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    stats: [u16; 6],
+}
+
+impl From<GeneratorState> for GeneratorResult {
+    fn from(value: GeneratorState) -> Self {
+        Self {
+            ability: value.base.ability,
+            ability_index: value.base.ability_index,
+            advances: value.advances,
+            characteristic: value.base.characteristic,
+            ec: value.base.ec,
+            gender: value.base.gender,
+            ivs: value.base.ivs,
+            level: value.base.level,
+            nature: value.base.nature,
+            pid: value.base.pid,
+            shiny: value.base.shiny,
+            stats: value.base.stats,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("static6.json");
+const SEED: u32 = 0x12345678;
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, Static6Data>(JSON_DATA);
+    for (
+        num,
+        GenerateData {
+            name,
+            seed,
+            lead,
+            shiny_charm,
+            species,
+            shiny,
+            ability,
+            iv_count,
+            level,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let min = [0; 6];
+        let max = [31; 6];
+        let natures = [true; 25];
+        let powers = [true; 16];
+
+        let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, shiny_charm);
+
+        let static_template = StaticTemplate::new(
+            Game::XY,
+            species,
+            0,
+            Shiny::from(shiny),
+            ability,
+            255,
+            iv_count,
+            level,
+        );
+        let filter = StateFilter6::new(255, 255, 255, false, min, max, natures, powers);
+        let generator =
+            StaticGenerator6::new(0, 9, 0, Lead::from_bits_retain(lead), &profile, &filter);
+
+        let states = generator.generate(seed, &static_template);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+fn psv(pid: u32) -> u16 {
+    ((pid >> 16) ^ (pid & 0xFFFF)) as u16
+}
+
+fn generate_template(
+    profile: &Profile6,
+    lead: Lead,
+    shiny: Shiny,
+    iv_count: u8,
+    max_advances: u32,
+) -> Vec<GeneratorState> {
+    let filter = StateFilter6::new(255, 255, 255, true, [0; 6], [31; 6], [true; 25], [true; 16]);
+    let generator = StaticGenerator6::new(0, max_advances, 0, lead, profile, &filter);
+    let static_template = StaticTemplate::new(Game::XY, 716, 0, shiny, 255, 255, iv_count, 50);
+
+    generator.generate(SEED, &static_template)
+}
+
+#[test]
+fn shiny_lock() {
+    let mut mt = MT::new(SEED);
+    let _ec = mt.next();
+    let pid = mt.next();
+    // Square shiny for the first PID of advance 0
+    let profile = Profile6::new("-".to_string(), Game::X, psv(pid), 0, false);
+
+    let states = generate_template(&profile, Lead::NONE, Shiny::Random, 0, 0);
+    assert_eq!(states[0].base.pid, pid);
+    assert_eq!(states[0].base.shiny, 2);
+
+    let states = generate_template(&profile, Lead::NONE, Shiny::Never, 0, 0);
+    assert_eq!(states[0].base.pid, pid ^ 0x10000000);
+    assert_eq!(states[0].base.shiny, 0);
+}
+
+#[test]
+fn shiny_charm() {
+    let mut mt = MT::new(SEED);
+    let _ec = mt.next();
+    let rolls = [mt.next(), mt.next(), mt.next()];
+    let ivs: Vec<u8> = (0..6).map(|_| (mt.next() >> 27) as u8).collect();
+    // Only the last of the three charm rolls is shiny
+    let tsv = psv(rolls[2]);
+    assert!(rolls[..2].iter().all(|&pid| (psv(pid) ^ tsv) >= 16));
+    let profile = Profile6::new("-".to_string(), Game::X, tsv, 0, true);
+
+    let states = generate_template(&profile, Lead::NONE, Shiny::Random, 0, 0);
+    assert_eq!(states[0].base.pid, rolls[2]);
+    assert_eq!(states[0].base.shiny, 2);
+    assert_eq!(states[0].base.ivs.to_vec(), ivs);
+
+    let profile = Profile6::new("-".to_string(), Game::X, tsv, 0, false);
+    let states = generate_template(&profile, Lead::NONE, Shiny::Random, 0, 0);
+    assert_eq!(states[0].base.pid, rolls[0]);
+    assert_eq!(states[0].base.shiny, 0);
+}
+
+#[test]
+fn flawless_ivs() {
+    let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, false);
+
+    for iv_count in 0..=5 {
+        let states = generate_template(&profile, Lead::NONE, Shiny::Random, iv_count, 99);
+        assert_eq!(states.len(), 100);
+        for state in states {
+            let flawless = state.base.ivs.iter().filter(|&&iv| iv == 31).count();
+            assert!(
+                flawless >= iv_count as usize,
+                "Unequal result: {} - {}",
+                iv_count,
+                state.advances
+            );
+        }
+    }
+}
+
+#[test]
+fn synchronize() {
+    let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, false);
+    // Synchronize with an Adamant lead
+    let states = generate_template(&profile, Lead::from_bits_retain(3), Shiny::Random, 0, 99);
+
+    let mut synced = 0;
+    for state in states {
+        let mut mt = MT::new_with_initial_advances(SEED, state.advances);
+        if ((mt.next() as u64 * 100) >> 32) >= 50 {
+            assert_eq!(state.base.nature, 3, "Unequal result: {}", state.advances);
+            synced += 1;
+        }
+    }
+    assert!(synced > 0);
+}
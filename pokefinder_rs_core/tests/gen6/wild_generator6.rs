@@ -0,0 +1,132 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{Encounter, Game, Lead};
+use pokefinder_rs_core::gen6::filters::WildStateFilter6;
+use pokefinder_rs_core::gen6::generators::WildGenerator6;
+use pokefinder_rs_core::gen6::Profile6;
+use pokefinder_rs_core::parents::states::WildGeneratorState;
+use pokefinder_rs_core::parents::{personal_loader, EncounterArea, Slot};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Wild6Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<GeneratorData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorData<'a> {
+    name: &'a str,
+    seed: u32,
+    encounter: u8,
+    lead: u8,
+    slots: Vec<[u16; 3]>,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    ec: u32,
+    #[serde(rename = "encounterSlot")]
+    encounter_slot: u8,
+    form: u8,
+    gender: u8,
+    #[serde(rename = "hiddenPower")]
+    hidden_power: u8,
+    item: u16,
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    species: u16,
+    stats: [u16; 6],
+}
+
+impl From<WildGeneratorState> for GeneratorResult {
+    fn from(value: WildGeneratorState) -> Self {
+        Self {
+            ability: value.base.base.ability,
+            ability_index: value.base.base.ability_index,
+            advances: value.advances,
+            characteristic: value.base.base.characteristic,
+            ec: value.base.base.ec,
+            encounter_slot: value.base.encounter_slot,
+            form: value.base.form,
+            gender: value.base.base.gender,
+            hidden_power: value.base.base.hidden_power,
+            item: value.base.item,
+            ivs: value.base.base.ivs,
+            level: value.base.base.level,
+            nature: value.base.base.nature,
+            pid: value.base.base.pid,
+            shiny: value.base.base.shiny,
+            species: value.base.species,
+            stats: value.base.base.stats,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("wild6.json");
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, Wild6Data>(JSON_DATA);
+    for (
+        num,
+        GeneratorData {
+            name,
+            seed,
+            encounter,
+            lead,
+            slots,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let min = [0; 6];
+        let max = [31; 6];
+        let natures = [true; 25];
+        let powers = [true; 16];
+        let encounter_slots = [true; 12];
+
+        let encounter = Encounter::from(encounter);
+        let lead = Lead::from_bits_retain(lead);
+
+        let profile = Profile6::new("-".to_string(), Game::X, 12345, 54321, false);
+
+        let pokemon = slots
+            .into_iter()
+            .map(|[species, min_level, max_level]| {
+                Slot::new(
+                    species,
+                    min_level as u8,
+                    max_level as u8,
+                    personal_loader::get_personal_info(Game::X, species, 0),
+                )
+            })
+            .collect();
+        let encounter_area = EncounterArea::new(0, 0, encounter, pokemon);
+
+        let filter = WildStateFilter6::new(
+            255,
+            255,
+            255,
+            false,
+            min,
+            max,
+            natures,
+            powers,
+            encounter_slots,
+        );
+        let generator = WildGenerator6::new(0, 9, 0, encounter, lead, &profile, &filter);
+
+        let states = generator.generate(seed, &encounter_area);
+
+        assert_states(results, states, name, num);
+    }
+}
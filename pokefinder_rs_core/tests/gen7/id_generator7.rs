@@ -0,0 +1,79 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::gen7::generators::IDGenerator7;
+use pokefinder_rs_core::gen8::states::IDState8;
+use pokefinder_rs_core::parents::filters::IDFilter;
+use pokefinder_rs_core::rng::{Rng, SFMT};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct IDGenerator7Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<GenerateData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GenerateData<'a> {
+    name: &'a str,
+    seed: u32,
+    results: Vec<GenerateResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GenerateResult {
+    advances: u32,
+    #[serde(rename = "displayTID")]
+    display_tid: u32,
+    sid: u16,
+    tid: u16,
+    tsv: u16,
+}
+
+impl From<IDState8> for GenerateResult {
+    fn from(value: IDState8) -> Self {
+        Self {
+            advances: value.advances,
+            display_tid: value.display_tid,
+            sid: value.sid,
+            tid: value.tid,
+            tsv: value.tsv,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("id7.json");
+const SEED: u32 = 0x12345678;
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, IDGenerator7Data>(JSON_DATA);
+    for (
+        num,
+        GenerateData {
+            name,
+            seed,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let filter = IDFilter::new(vec![], vec![], vec![], vec![]);
+        let generator = IDGenerator7::new(0, 8, &filter);
+
+        let states = generator.generate(seed);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+#[test]
+fn max_advances() {
+    let filter = IDFilter::new(vec![], vec![], vec![], vec![]);
+    let generator = IDGenerator7::new(5, 0, &filter);
+
+    let states = generator.generate(SEED);
+    assert_eq!(states.len(), 1);
+    assert_eq!(states[0].advances, 5);
+    assert_eq!(
+        states[0].display_tid,
+        SFMT::new_with_initial_advances(SEED, 5).next() as u32 % 1000000
+    );
+}
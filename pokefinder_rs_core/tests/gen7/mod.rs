@@ -0,0 +1,5 @@
+mod egg_generator7;
+mod id_generator7;
+mod state_filter7;
+mod static_generator7;
+mod wild_generator7;
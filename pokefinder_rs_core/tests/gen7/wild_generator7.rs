@@ -0,0 +1,210 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::enums::{Encounter, Game, Lead};
+use pokefinder_rs_core::gen7::filters::WildStateFilter7;
+use pokefinder_rs_core::gen7::generators::WildGenerator7;
+use pokefinder_rs_core::gen7::Profile7;
+use pokefinder_rs_core::parents::states::WildGeneratorState;
+use pokefinder_rs_core::parents::{personal_loader, EncounterArea, Slot};
+use pokefinder_rs_core::rng::{Rng, SFMT};
+use pokefinder_rs_core::util::encounter_slot;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Wild7Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<GeneratorData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorData<'a> {
+    name: &'a str,
+    seed: u32,
+    encounter: u8,
+    lead: u8,
+    chain: u8,
+    slots: Vec<[u16; 3]>,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    ability: u8,
+    #[serde(rename = "abilityIndex")]
+    ability_index: u16,
+    advances: u32,
+    characteristic: u8,
+    ec: u32,
+    #[serde(rename = "encounterSlot")]
+    encounter_slot: u8,
+    form: u8,
+    gender: u8,
+    #[serde(rename = "hiddenPower")]
+    hidden_power: u8,
+    item: u16,
+    ivs: [u8; 6],
+    level: u8,
+    nature: u8,
+    pid: u32,
+    shiny: u8,
+    species: u16,
+    stats: [u16; 6],
+}
+
+impl From<WildGeneratorState> for GeneratorResult {
+    fn from(value: WildGeneratorState) -> Self {
+        Self {
+            ability: value.base.base.ability,
+            ability_index: value.base.base.ability_index,
+            advances: value.advances,
+            characteristic: value.base.base.characteristic,
+            ec: value.base.base.ec,
+            encounter_slot: value.base.encounter_slot,
+            form: value.base.form,
+            gender: value.base.base.gender,
+            hidden_power: value.base.base.hidden_power,
+            item: value.base.item,
+            ivs: value.base.base.ivs,
+            level: value.base.base.level,
+            nature: value.base.base.nature,
+            pid: value.base.base.pid,
+            shiny: value.base.base.shiny,
+            species: value.base.species,
+            stats: value.base.base.stats,
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("wild7.json");
+const SEED: u32 = 0x12345678;
+/// Route 1 of Sun and Moon
+const SPECIES: [u16; 10] = [734, 731, 10, 165, 163, 19, 736, 722, 725, 728];
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, Wild7Data>(JSON_DATA);
+    for (
+        num,
+        GeneratorData {
+            name,
+            seed,
+            encounter,
+            lead,
+            chain,
+            slots,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let min = [0; 6];
+        let max = [31; 6];
+        let natures = [true; 25];
+        let powers = [true; 16];
+        let encounter_slots = [true; 12];
+
+        let encounter = Encounter::from(encounter);
+        let lead = Lead::from_bits_retain(lead);
+
+        let profile = Profile7::new("-".to_string(), Game::SUN, 12345, 54321, false);
+
+        let pokemon = slots
+            .into_iter()
+            .map(|[species, min_level, max_level]| {
+                Slot::new(
+                    species,
+                    min_level as u8,
+                    max_level as u8,
+                    personal_loader::get_personal_info(Game::SUN, species, 0),
+                )
+            })
+            .collect();
+        let encounter_area = EncounterArea::new(0, 0, encounter, pokemon);
+
+        let filter = WildStateFilter7::new(
+            255,
+            255,
+            255,
+            false,
+            min,
+            max,
+            natures,
+            powers,
+            encounter_slots,
+        );
+        let generator = WildGenerator7::new(0, 9, 0, encounter, lead, chain, &profile, &filter);
+
+        let states = generator.generate(seed, &encounter_area);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+fn generate_route(lead: Lead, chain: u8, max_advances: u32) -> Vec<WildGeneratorState> {
+    let profile = Profile7::new("-".to_string(), Game::SUN, 12345, 54321, false);
+
+    let pokemon = SPECIES
+        .into_iter()
+        .map(|species| {
+            Slot::new(
+                species,
+                3,
+                3,
+                personal_loader::get_personal_info(Game::SUN, species, 0),
+            )
+        })
+        .collect();
+    let encounter_area = EncounterArea::new(0, 0, Encounter::Grass, pokemon);
+
+    let filter = WildStateFilter7::new(
+        255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16], [true; 12],
+    );
+    let generator = WildGenerator7::new(
+        0,
+        max_advances,
+        0,
+        Encounter::Grass,
+        lead,
+        chain,
+        &profile,
+        &filter,
+    );
+
+    generator.generate(SEED, &encounter_area)
+}
+
+#[test]
+fn encounter_slot() {
+    let states = generate_route(Lead::NONE, 0, 99);
+    assert_eq!(states.len(), 100);
+
+    let mut slots = [false; 10];
+    for state in states {
+        let mut sfmt = SFMT::new_with_initial_advances(SEED, state.advances);
+        let slot = encounter_slot::sm_slot((sfmt.next() % 100) as u8);
+        assert_eq!(
+            state.base.encounter_slot, slot,
+            "Unequal result: {}",
+            state.advances
+        );
+        assert_eq!(state.base.species, SPECIES[slot as usize]);
+        assert_eq!(state.base.base.level, 3);
+        slots[slot as usize] = true;
+    }
+    // Every slot with a rate of at least 10% is hit within 100 advances
+    assert!(slots[..7].iter().all(|&slot| slot));
+}
+
+#[test]
+fn sos_chain() {
+    for (chain, iv_count) in [(0, 0), (5, 1), (10, 2), (20, 3), (30, 4)] {
+        let states = generate_route(Lead::NONE, chain, 99);
+        for state in states {
+            let flawless = state.base.base.ivs.iter().filter(|&&iv| iv == 31).count();
+            assert!(
+                flawless >= iv_count,
+                "Unequal result: {} - {}",
+                chain,
+                state.advances
+            );
+        }
+    }
+}
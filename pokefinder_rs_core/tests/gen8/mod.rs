@@ -2,6 +2,9 @@ mod egg_generator8;
 mod event_generator8;
 mod id_generator8;
 mod raid_generator;
+mod raid_seed_searcher;
+mod searcher8;
+mod state_filter8;
 mod static_generator8;
 mod underground_generator;
 mod wild_generator8;
@@ -0,0 +1,79 @@
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::RaidGenerator;
+use pokefinder_rs_core::gen8::searchers::{
+    RaidObservation, RaidSeedSearcher, RaidSeedSearcherError,
+};
+use pokefinder_rs_core::gen8::{den_loader, Profile8, Raid};
+use pokefinder_rs_core::parents::Template;
+
+const RAID_CONSTANT: u64 = 0x82A2B175229D6A5B;
+
+fn observe(seed: u64, raid: &Raid) -> RaidObservation {
+    let profile = Profile8::new(
+        "-".to_string(),
+        Game::SWORD,
+        12345,
+        54321,
+        false,
+        false,
+        false,
+    );
+    let filter = StateFilter8::new(
+        255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16],
+    );
+    let generator = RaidGenerator::new(0, 0, 0, &profile, &filter);
+
+    let state = &generator.generate(seed, 15, raid)[0];
+    RaidObservation::new(*raid, state.base.ivs, state.base.ability, state.base.nature)
+}
+
+#[test]
+#[ignore = "checks 2^30 seeds per raid, run with --release -- --ignored"]
+fn search() {
+    // Mudbray, one flawless IV with a random ability and gender
+    let raid = den_loader::get_den(0, 0).get_raid(1, Game::SWORD);
+
+    for (name, seed) in [
+        ("no rerolls", 0x0123456789abcdef),
+        ("IV index reroll", 0x9e3779b97f4a7c15),
+        ("nature reroll", 0x81af155173f23d65),
+        ("gender reroll", 0x1fe68f0af33cb97a),
+    ] {
+        let first = observe(seed, &raid);
+        let second = observe(seed.wrapping_add(RAID_CONSTANT), &raid);
+
+        let searcher = RaidSeedSearcher::new(first, Some((1, second)), 1).unwrap();
+        searcher.start_search(1).unwrap();
+
+        assert!(
+            searcher.get_results().contains(&seed),
+            "Seed not found: {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn underconstrained() {
+    let den = den_loader::get_den(0, 0);
+    let mudbray = den.get_raid(1, Game::SWORD);
+    let flawless = *den
+        .get_raids(Game::SWORD)
+        .iter()
+        .max_by_key(|raid| raid.base.get_iv_count())
+        .unwrap();
+
+    let first = observe(0, &flawless);
+    let second = observe(RAID_CONSTANT, &flawless);
+    assert!(matches!(
+        RaidSeedSearcher::new(first, Some((1, second)), 0),
+        Err(RaidSeedSearcherError::Underconstrained(_))
+    ));
+
+    // Without a second observation every matching seed would be a result
+    assert!(matches!(
+        RaidSeedSearcher::new(observe(0, &mudbray), None, 0),
+        Err(RaidSeedSearcherError::Underconstrained(30))
+    ));
+}
@@ -0,0 +1,159 @@
+use crate::get_test_data;
+use pokefinder_rs_core::enums::{Game, Lead};
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::StaticGenerator8;
+use pokefinder_rs_core::gen8::searchers::{
+    recover_xorshift, StaticSearcher8, StaticSearcherGenerator8, XorshiftObservation,
+    XorshiftRecoveryError,
+};
+use pokefinder_rs_core::gen8::{encounters8, Profile8};
+use pokefinder_rs_core::rng::{Rng, Xorshift};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Static8Data<'a> {
+    #[serde(borrow)]
+    generate: Vec<GenerateData<'a>>,
+}
+
+#[derive(Deserialize)]
+struct GenerateData<'a> {
+    name: &'a str,
+    seed0: u64,
+    seed1: u64,
+    lead: u8,
+    category: usize,
+    pokemon: u16,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize)]
+struct GeneratorResult {
+    advances: u32,
+    ec: u32,
+    ivs: [u8; 6],
+}
+
+const JSON_DATA: &str = include_str!("static8.json");
+
+#[test]
+fn search() {
+    let data = get_test_data::<'static, Static8Data>(JSON_DATA);
+    for GenerateData {
+        name,
+        seed0,
+        seed1,
+        lead,
+        category,
+        pokemon,
+        results,
+    } in data.generate
+    {
+        let profile = Profile8::new(
+            "-".to_string(),
+            Game::BDSP,
+            12345,
+            54321,
+            false,
+            false,
+            false,
+        );
+
+        let static_template = encounters8::get_static_encounter(category, pokemon as usize);
+        let filter = StateFilter8::new(
+            255, 255, 255, false, [0; 6], [31; 6], [true; 25], [true; 16],
+        );
+        let generator =
+            StaticGenerator8::new(0, 9, 0, Lead::from_bits_retain(lead), &profile, &filter);
+        let generator = StaticSearcherGenerator8::new(generator, static_template, false);
+
+        let searcher = StaticSearcher8::new(&generator);
+        searcher.start_search(3, seed0, seed1);
+
+        let mut states = searcher.get_results();
+        states.sort_by_key(|state| state.advances);
+
+        assert_eq!(states.len(), results.len(), "Unequal results size: {name}");
+        for (state, result) in states.iter().zip(results.iter()) {
+            assert_eq!(state.advances, result.advances, "{name}");
+            assert_eq!(state.base.ec, result.ec, "{name}");
+            assert_eq!(state.base.ivs, result.ivs, "{name}");
+        }
+    }
+}
+
+#[test]
+fn recover_from_pokemon() {
+    let data = get_test_data::<'static, Static8Data>(JSON_DATA);
+    let GenerateData {
+        seed0,
+        seed1,
+        results,
+        ..
+    } = &data.generate[0];
+
+    let observations: Vec<XorshiftObservation> = [0, 4, 9]
+        .iter()
+        .flat_map(|&i| {
+            let result = &results[i];
+            XorshiftObservation::from_pokemon(result.advances, result.ec, result.ivs, false)
+        })
+        .collect();
+
+    assert_eq!(recover_xorshift(&observations), Ok(vec![(*seed0, *seed1)]));
+}
+
+#[test]
+fn recover_from_blinks() {
+    let (seed0, seed1) = (0x123456789abcdef0, 0xfedcba9876543210);
+
+    let mut rng = Xorshift::new(seed0, seed1);
+    let mut blinks = vec![];
+    let mut intervals = vec![];
+    let mut first = None;
+    let mut last = 0;
+    for advance in 0.. {
+        let rand = rng.next();
+        if (rand & 0xE) == 0 {
+            blinks.push((rand & 1) as u8);
+            intervals.push(advance - last);
+            first.get_or_insert(advance);
+            last = advance;
+            if blinks.len() == 40 {
+                break;
+            }
+        }
+    }
+
+    let observations = XorshiftObservation::from_blinks(&blinks, &intervals);
+    let states = recover_xorshift(&observations).unwrap();
+
+    // The first blink is advance 0 of the recovered state
+    let mut expected = Xorshift::new(seed0, seed1);
+    expected.advance(first.unwrap());
+    assert!(states.iter().any(|&(s0, s1)| {
+        let mut expected = expected;
+        let mut rng = Xorshift::new(s0, s1);
+        (0..128).all(|_| rng.next() == expected.next())
+    }));
+}
+
+#[test]
+fn recover_errors() {
+    // Five blinks leave most of the state unknown
+    let observations = XorshiftObservation::from_blinks(&[0, 1, 0, 1, 1], &[0, 12, 7, 30, 4]);
+    assert!(matches!(
+        recover_xorshift(&observations),
+        Err(XorshiftRecoveryError::Underconstrained(_))
+    ));
+
+    // The same output can not have two values
+    let observations = [
+        XorshiftObservation::new(3, 0xFF, 0x12),
+        XorshiftObservation::new(3, 0xFF, 0x13),
+    ];
+    assert_eq!(
+        recover_xorshift(&observations),
+        Err(XorshiftRecoveryError::Contradictory)
+    );
+}
@@ -0,0 +1,43 @@
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen8::filters::WildStateFilter8;
+use pokefinder_rs_core::parents::personal_loader;
+use pokefinder_rs_core::parents::states::WildGeneratorState;
+
+fn filter(gender: u8, shiny: u8, powers: [bool; 16]) -> WildStateFilter8 {
+    WildStateFilter8::new(
+        gender, 255, shiny, false, [0; 6], [31; 6], [true; 25], powers, [true; 12],
+    )
+}
+
+fn generator_state(gender: u8, shiny: u8, iv: u8) -> WildGeneratorState {
+    let info = personal_loader::resolve_personal_info(Game::BD, 25, 0);
+    WildGeneratorState::new(0, 0, [iv; 6], 0, gender, 5, 0, shiny, 0, 0, 25, 0, &info)
+}
+
+#[test]
+fn shiny() {
+    // Star and square shinies are separate bits, 3 accepts both and 255 disables the filter
+    for (shiny, expected) in [
+        (1, [false, true, false]),
+        (2, [false, false, true]),
+        (3, [false, true, true]),
+        (255, [true, true, true]),
+    ] {
+        let filter = filter(255, shiny, [true; 16]);
+        for (state_shiny, expected) in expected.into_iter().enumerate() {
+            let state_shiny = state_shiny as u8;
+            assert_eq!(
+                filter.compare_generator_state(&generator_state(0, state_shiny, 31)),
+                expected,
+                "{shiny} - {state_shiny}"
+            );
+        }
+    }
+}
+
+#[test]
+fn gender() {
+    let filter = filter(1, 255, [true; 16]);
+    assert!(filter.compare_generator_state(&generator_state(1, 0, 31)));
+    assert!(!filter.compare_generator_state(&generator_state(0, 0, 31)));
+}
@@ -4,7 +4,10 @@ use std::fmt::Debug;
 mod gen3;
 mod gen4;
 mod gen5;
+mod gen6;
+mod gen7;
 mod gen8;
+mod parents;
 mod rng;
 mod util;
 
@@ -0,0 +1,260 @@
+use pokefinder_rs_core::enums::{Encounter, Game, Shiny};
+use pokefinder_rs_core::gen4::{encounters4, EncounterArea4, Profile4};
+use pokefinder_rs_core::parents::{
+    custom_loader, personal_loader, EncounterAreaT, StaticTemplate, Template,
+};
+use std::io::ErrorKind;
+use std::sync::Mutex;
+
+// Pearl is not used by any other test, the custom data is global to the test binary
+const VERSION: Game = Game::PEARL;
+const LOCATION: u8 = 250;
+
+/// Held by tests that load data for Pearl as clearing it clears everything loaded for it
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn get_encounters() -> Vec<EncounterArea4> {
+    let profile = Profile4::new("".to_string(), VERSION, 12345, 54321, false);
+    encounters4::get_encounters(
+        Encounter::Grass,
+        0,
+        Game::NONE,
+        false,
+        0,
+        false,
+        [0; 2],
+        [0; 5],
+        &profile,
+    )
+}
+
+fn area_json(slots: usize) -> String {
+    let slots = vec![r#"{ "species": 1, "min_level": 3, "max_level": 5 }"#; slots].join(", ");
+    format!(
+        r#"{{ "encounters": [{{ "location": {}, "encounter": "Grass", "rate": 20, "slots": [{}] }}] }}"#,
+        LOCATION, slots
+    )
+}
+
+/// Builds a Pearl ROM where map `i` uses encounter table `i`, each filled with level 5 `species`
+fn pearl(species: u32) -> Vec<u8> {
+    let mut encounter = vec![0; 424];
+    encounter[..4].copy_from_slice(&10u32.to_le_bytes());
+    for slot in encounter[4..100].chunks_exact_mut(8) {
+        slot[0] = 5;
+        slot[4..].copy_from_slice(&species.to_le_bytes());
+    }
+
+    let mut narc = b"NARC\xFE\xFF\x00\x01\0\0\0\0\x10\x00\x03\x00BTAF".to_vec();
+    narc.extend((12 + 183 * 8u32).to_le_bytes());
+    narc.extend(183u32.to_le_bytes());
+    for i in 0..183u32 {
+        narc.extend((i * 424).to_le_bytes());
+        narc.extend(((i + 1) * 424).to_le_bytes());
+    }
+    narc.extend(b"BTNF\x10\0\0\0\x04\0\0\0\x01\0\0\xF0GMIF");
+    narc.extend((8 + 183 * 424u32).to_le_bytes());
+    narc.extend(encounter.repeat(183));
+
+    let mut map_headers = vec![];
+    for i in 0..559u16 {
+        let mut map_header = [0; 24];
+        map_header[10..12].copy_from_slice(&1000u16.to_le_bytes());
+        let encounter_id = if i < 183 { i } else { u16::MAX };
+        map_header[14..16].copy_from_slice(&encounter_id.to_le_bytes());
+        map_headers.extend(map_header);
+    }
+
+    // fielddata/encountdata/p_enc_data.narc
+    let mut fnt = vec![];
+    for (offset, parent) in [(24u32, 3u16), (37, 0xF000), (52, 0xF001)] {
+        fnt.extend(offset.to_le_bytes());
+        fnt.extend(0u16.to_le_bytes());
+        fnt.extend(parent.to_le_bytes());
+    }
+    fnt.extend(b"\x89fielddata\x01\xF0\0");
+    fnt.extend(b"\x8Bencountdata\x02\xF0\0");
+    fnt.extend(b"\x0Fp_enc_data.narc\0");
+
+    let mut rom = vec![0; 0x200];
+    rom[0xC..0x10].copy_from_slice(b"APAE");
+    let fnt_offset = 0x200 + map_headers.len();
+    let fat_offset = fnt_offset + fnt.len();
+    let narc_offset = fat_offset + 8;
+    for (offset, value) in [
+        (0x20, 0x200),
+        (0x2C, map_headers.len()),
+        (0x40, fnt_offset),
+        (0x44, fnt.len()),
+        (0x48, fat_offset),
+        (0x4C, 8),
+    ] {
+        rom[offset..offset + 4].copy_from_slice(&(value as u32).to_le_bytes());
+    }
+    rom.extend(map_headers);
+    rom.extend(fnt);
+    rom.extend((narc_offset as u32).to_le_bytes());
+    rom.extend(((narc_offset + narc.len()) as u32).to_le_bytes());
+    rom.extend(narc);
+    rom
+}
+
+#[test]
+fn load_custom_data() {
+    let _lock = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    let json = r#"{
+        "personal": [
+            { "species": 1, "stats": [99, 49, 49, 65, 65, 45], "types": [11, 3], "gender": 31, "abilities": [65, 65, 0] }
+        ],
+        "encounters": [
+            {
+                "location": 250,
+                "encounter": "Grass",
+                "rate": 20,
+                "slots": [
+                    { "species": 1, "min_level": 3, "max_level": 5 },
+                    { "species": 1, "min_level": 3, "max_level": 5 },
+                    { "species": 4, "min_level": 3, "max_level": 5 },
+                    { "species": 4, "min_level": 3, "max_level": 5 },
+                    { "species": 7, "min_level": 3, "max_level": 5 },
+                    { "species": 7, "min_level": 3, "max_level": 5 },
+                    { "species": 1, "min_level": 3, "max_level": 5 },
+                    { "species": 1, "min_level": 3, "max_level": 5 },
+                    { "species": 4, "min_level": 3, "max_level": 5 },
+                    { "species": 4, "min_level": 3, "max_level": 5 },
+                    { "species": 7, "min_level": 3, "max_level": 5 },
+                    { "species": 7, "min_level": 3, "max_level": 5 }
+                ]
+            }
+        ]
+    }"#;
+
+    assert!(get_encounters()
+        .iter()
+        .all(|area| area.get_location() != LOCATION));
+    let builtin = personal_loader::resolve_personal_table(VERSION);
+
+    custom_loader::load_custom_data(VERSION, json).unwrap();
+    let custom = personal_loader::resolve_personal_table(VERSION);
+    assert_eq!(custom.get_personal_info(1, 0).get_stat(0), 99);
+    assert_eq!(
+        personal_loader::resolve_personal_info(VERSION, 1, 0).get_stat(0),
+        99
+    );
+    // Other games of the same generation keep the built-in data
+    assert_eq!(
+        personal_loader::resolve_personal_info(Game::DIAMOND, 1, 0).get_stat(0),
+        45
+    );
+
+    let area = get_encounters()
+        .into_iter()
+        .find(|area| area.get_location() == LOCATION)
+        .unwrap();
+    assert_eq!(area.get_rate(), 20);
+    assert_eq!(area.get_pokemon().len(), 12);
+    assert_eq!(area.get_pokemon()[4].get_species(), 7);
+    // Slots pick up the custom personal data
+    assert_eq!(area.get_pokemon()[0].get_info().get_stat(0), 99);
+    // Templates only pick it up when resolved
+    let template = StaticTemplate::new(VERSION, 1, 0, Shiny::Random, 255, 255, 0, 5);
+    assert_eq!(template.get_info().get_stat(0), 45);
+    assert_eq!(template.resolve_info().get_stat(0), 99);
+
+    custom_loader::clear_custom_data(VERSION);
+    assert_eq!(
+        personal_loader::resolve_personal_info(VERSION, 1, 0).get_stat(0),
+        45
+    );
+    assert!(get_encounters()
+        .iter()
+        .all(|area| area.get_location() != LOCATION));
+
+    // Tables resolved before the change keep the data they were resolved with
+    assert_eq!(builtin.get_personal_info(1, 0).get_stat(0), 45);
+    assert_eq!(custom.get_personal_info(1, 0).get_stat(0), 99);
+}
+
+#[test]
+fn invalid_data() {
+    // Gen 4 grass areas have 12 slots
+    for slots in [3, 11, 13] {
+        assert!(custom_loader::load_custom_data(VERSION, &area_json(slots)).is_err());
+    }
+    // The generations disagree on the number of grass slots
+    assert!(custom_loader::load_custom_data(Game::PEARL | Game::SUN, &area_json(12)).is_err());
+    // Gen 6 has no wild encounter data
+    assert!(custom_loader::load_custom_data(Game::X, &area_json(12)).is_err());
+    // Surfing areas have 5 slots
+    assert!(
+        custom_loader::load_custom_data(VERSION, &area_json(12).replace("Grass", "Surfing"))
+            .is_err()
+    );
+
+    let personal = |species: u16, form: u8| {
+        format!(
+            r#"{{ "personal": [{{ "species": {}, "form": {}, "stats": [1, 1, 1, 1, 1, 1], "types": [0, 0], "gender": 0, "abilities": [0, 0, 0] }}] }}"#,
+            species, form
+        )
+    };
+    assert!(custom_loader::load_custom_data(VERSION, &personal(1000, 0)).is_err());
+    // Bulbasaur has no forms
+    assert!(custom_loader::load_custom_data(VERSION, &personal(1, 1)).is_err());
+    // Unown only has 28 forms
+    assert!(custom_loader::load_custom_data(VERSION, &personal(201, 28)).is_err());
+}
+
+#[test]
+fn load_rom() {
+    let _lock = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+    // The built-in tables are only filled when the build had the ROMs, compare against them as is
+    let species = |areas: Vec<EncounterArea4>| {
+        areas
+            .iter()
+            .map(|area| (area.get_location(), area.get_pokemon()[0].get_species()))
+            .collect::<Vec<_>>()
+    };
+    let diamond_profile = Profile4::new("".to_string(), Game::DIAMOND, 12345, 54321, false);
+    let get_diamond_encounters = || {
+        encounters4::get_encounters(
+            Encounter::Grass,
+            0,
+            Game::NONE,
+            false,
+            0,
+            false,
+            [0; 2],
+            [0; 5],
+            &diamond_profile,
+        )
+    };
+    let builtin = species(get_encounters());
+    let diamond = species(get_diamond_encounters());
+
+    assert!(custom_loader::load_rom(pearl(150)).unwrap() == VERSION);
+    let encounters = get_encounters();
+    // 33 of the encounter tables belong to special maps
+    assert_eq!(encounters.len(), 150);
+    for area in encounters {
+        assert_eq!(area.get_rate(), 10);
+        assert_eq!(area.get_pokemon()[0].get_species(), 150);
+        assert_eq!(area.get_pokemon()[0].get_max_level(), 5);
+    }
+    // Other games keep their built-in encounters
+    assert_eq!(species(get_diamond_encounters()), diamond);
+
+    custom_loader::clear_custom_data(VERSION);
+    assert_eq!(species(get_encounters()), builtin);
+
+    let mut rom = pearl(150);
+    rom[0xC..0x10].copy_from_slice(b"AAAE");
+    assert_eq!(
+        custom_loader::load_rom(rom).err().unwrap().kind(),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(
+        custom_loader::load_rom(vec![0; 0x10]).err().unwrap().kind(),
+        ErrorKind::InvalidData
+    );
+}
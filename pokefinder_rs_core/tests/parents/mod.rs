@@ -0,0 +1,4 @@
+mod custom_loader;
+mod personal_loader;
+mod researcher_generator;
+mod searcher;
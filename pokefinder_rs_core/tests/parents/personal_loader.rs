@@ -0,0 +1,73 @@
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::parents::personal_loader;
+
+#[test]
+fn gen6() {
+    for version in [Game::XY, Game::ORAS] {
+        let table = personal_loader::get_personal_table(version);
+        assert!(table[1..=721]
+            .iter()
+            .all(|info| info.get_present() && info.get_stats() != [0; 6]));
+
+        // Chespin
+        let info = personal_loader::get_personal_info(version, 650, 0);
+        assert_eq!(info.get_stats(), [56, 61, 65, 48, 45, 38]);
+        assert_eq!(info.get_gender(), 31);
+        // Clefairy is Fairy type since Gen 6
+        assert_eq!(
+            personal_loader::get_personal_info(version, 35, 0).get_type(0),
+            17
+        );
+        // Arbok before its Gen 7 attack buff
+        assert_eq!(
+            personal_loader::get_personal_info(version, 24, 0).get_stat(1),
+            85
+        );
+        // Aegislash before its Gen 8 nerf
+        assert_eq!(
+            personal_loader::get_personal_info(version, 681, 0).get_stat(2),
+            150
+        );
+        assert_eq!(
+            personal_loader::get_personal_info(version, 681, 1).get_stat(1),
+            150
+        );
+        // Hoopa Unbound
+        let info = personal_loader::get_personal_info(version, 720, 1);
+        assert_eq!(info.get_stats(), [80, 160, 60, 170, 130, 80]);
+        assert_eq!(info.get_type(1), 16);
+    }
+}
+
+#[test]
+fn gen7() {
+    for version in [Game::SM, Game::USUM] {
+        let table = personal_loader::get_personal_table(version);
+        assert!(table[1..=807]
+            .iter()
+            .all(|info| info.get_present() && info.get_stats() != [0; 6]));
+
+        // Rowlet
+        let info = personal_loader::get_personal_info(version, 722, 0);
+        assert_eq!(info.get_stats(), [68, 55, 55, 50, 50, 42]);
+        assert_eq!(info.get_gender(), 31);
+        // Arbok after its Gen 7 attack buff
+        assert_eq!(
+            personal_loader::get_personal_info(version, 24, 0).get_stat(1),
+            95
+        );
+        // Alolan Rattata
+        let info = personal_loader::get_personal_info(version, 19, 1);
+        assert_eq!(info.get_type(0), 16);
+        assert_eq!(info.get_type(1), 0);
+        // Minior Meteor and Core forms
+        assert_eq!(
+            personal_loader::get_personal_info(version, 774, 0).get_stats(),
+            [60, 60, 100, 60, 100, 60]
+        );
+        assert_eq!(
+            personal_loader::get_personal_info(version, 774, 7).get_stats(),
+            [60, 100, 60, 100, 60, 120]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{assert_states, get_test_data};
+use pokefinder_rs_core::parents::generators::{Calculation, ResearcherGenerator, ResearcherRng};
+use pokefinder_rs_core::parents::states::ResearcherState;
+use pokefinder_rs_core::rng::TinyMT;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ResearcherData<'a> {
+    #[serde(borrow)]
+    generate: Vec<GenerateData<'a>>,
+    #[serde(borrow)]
+    known: Vec<KnownData<'a>>,
+    #[serde(borrow)]
+    invalid: Vec<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct GenerateData<'a> {
+    name: &'a str,
+    rng: &'a str,
+    seed: Vec<u64>,
+    #[serde(rename = "initialAdvances")]
+    initial_advances: u32,
+    #[serde(borrow)]
+    calculations: Vec<Option<&'a str>>,
+    results: Vec<GeneratorResult>,
+}
+
+#[derive(Deserialize)]
+struct KnownData<'a> {
+    name: &'a str,
+    rng: &'a str,
+    seed: Vec<u64>,
+    #[serde(rename = "initialAdvances")]
+    initial_advances: u32,
+    advances: u32,
+    prng: u64,
+}
+
+#[derive(Deserialize, Ord, PartialOrd, Eq, PartialEq, Debug)]
+struct GeneratorResult {
+    advances: u32,
+    custom: [u64; 10],
+    prng: u64,
+}
+
+impl From<ResearcherState> for GeneratorResult {
+    fn from(value: ResearcherState) -> Self {
+        Self {
+            advances: value.get_advances(),
+            custom: std::array::from_fn(|i| value.get_custom(i)),
+            prng: value.get_prng(),
+        }
+    }
+}
+
+const JSON_DATA: &str = include_str!("researcher.json");
+
+fn researcher_rng(rng: &str, seed: &[u64]) -> ResearcherRng {
+    match rng {
+        "PokeRNG" => ResearcherRng::PokeRNG(seed[0] as u32),
+        "XDRNG" => ResearcherRng::XDRNG(seed[0] as u32),
+        "ARNG" => ResearcherRng::ARNG(seed[0] as u32),
+        "BWRNG" => ResearcherRng::BWRNG(seed[0]),
+        "MT" => ResearcherRng::MT(seed[0] as u32),
+        "TinyMT" => ResearcherRng::TinyMT(seed[0] as u32),
+        "Xorshift" => ResearcherRng::Xorshift(seed[0], seed[1]),
+        "Xoroshiro" => ResearcherRng::Xoroshiro(seed[0], seed[1]),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn generate() {
+    let data = get_test_data::<'static, ResearcherData>(JSON_DATA);
+    for (
+        num,
+        GenerateData {
+            name,
+            rng,
+            seed,
+            initial_advances,
+            calculations,
+            results,
+        },
+    ) in data.generate.into_iter().enumerate()
+    {
+        let rng = researcher_rng(rng, &seed);
+
+        let calculations = std::array::from_fn(|i| {
+            calculations
+                .get(i)
+                .copied()
+                .flatten()
+                .map(|calculation| Calculation::new(calculation, i).unwrap())
+        });
+        let generator = ResearcherGenerator::new(initial_advances, 9, calculations);
+
+        let states = generator.generate(rng);
+
+        assert_states(results, states, name, num);
+    }
+}
+
+#[test]
+fn known() {
+    let data = get_test_data::<'static, ResearcherData>(JSON_DATA);
+    for (
+        num,
+        KnownData {
+            name,
+            rng,
+            seed,
+            initial_advances,
+            advances,
+            prng,
+        },
+    ) in data.known.into_iter().enumerate()
+    {
+        let generator = ResearcherGenerator::new(
+            initial_advances,
+            advances - initial_advances,
+            Default::default(),
+        );
+
+        let states = generator.generate(researcher_rng(rng, &seed));
+        let state = states.last().unwrap();
+
+        assert_eq!(
+            state.get_advances(),
+            advances,
+            "Unequal result: {} - {}",
+            num,
+            name
+        );
+        assert_eq!(state.get_prng(), prng, "Unequal result: {} - {}", num, name);
+    }
+}
+
+#[test]
+fn invalid() {
+    let data = get_test_data::<'static, ResearcherData>(JSON_DATA);
+    for expression in data.invalid {
+        assert!(Calculation::new(expression, 9).is_none(), "{expression}");
+    }
+}
+
+#[test]
+fn custom_references() {
+    // Only the custom values before the calculation are available
+    assert!(Calculation::new("custom1", 1).is_some());
+    assert!(Calculation::new("custom9 + custom1", 9).is_some());
+    assert!(Calculation::new("custom1", 0).is_none());
+    assert!(Calculation::new("custom3 + custom1", 2).is_none());
+    assert!(Calculation::new("previous + prng", 0).is_some());
+}
+
+#[test]
+fn tiny_mt_state() {
+    // Seeding with the state TinyMT is initialized to gives the same values
+    let state = TinyMT::new(0x12345678).state;
+    let generator = ResearcherGenerator::new(0, 20, Default::default());
+    let prngs = |rng| {
+        generator
+            .generate(rng)
+            .iter()
+            .map(|state| state.get_prng())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        prngs(ResearcherRng::TinyMTState(
+            state[0], state[1], state[2], state[3]
+        )),
+        prngs(ResearcherRng::TinyMT(0x12345678))
+    );
+}
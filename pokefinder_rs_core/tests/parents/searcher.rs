@@ -0,0 +1,16 @@
+use pokefinder_rs_core::parents::searchers::iv_combinations;
+
+#[test]
+fn iv_combinations_bounds() {
+    assert_eq!(iv_combinations([0; 6], [31; 6]), 32u32.pow(6));
+    assert_eq!(iv_combinations([31; 6], [31; 6]), 1);
+    assert_eq!(
+        iv_combinations([0, 0, 0, 0, 0, 30], [31, 31, 31, 31, 31, 31]),
+        32u32.pow(5) * 2
+    );
+    // Inverted bounds have nothing to search instead of underflowing
+    assert_eq!(
+        iv_combinations([31, 0, 0, 0, 0, 0], [0, 31, 31, 31, 31, 31]),
+        0
+    );
+}
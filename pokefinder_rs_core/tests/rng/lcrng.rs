@@ -130,6 +130,50 @@ fn jump() {
     }
 }
 
+#[test]
+fn distance() {
+    let data = get_test_data::<'static, LCRNGTestData>(JSON_STR);
+    for LCRNGJumpData {
+        name,
+        seed,
+        advances,
+        results,
+    } in data.jump
+    {
+        assert_eq!(ARNG::distance(seed, results[0]), advances, "ARNG: {}", name);
+        assert_eq!(
+            ARNGR::distance(seed, results[1]),
+            advances,
+            "ARNGR: {}",
+            name
+        );
+        assert_eq!(
+            PokeRNG::distance(seed, results[2]),
+            advances,
+            "PokeRNG: {}",
+            name
+        );
+        assert_eq!(
+            PokeRNGR::distance(seed, results[3]),
+            advances,
+            "PokeRNGR: {}",
+            name
+        );
+        assert_eq!(
+            XDRNG::distance(seed, results[4]),
+            advances,
+            "XDRNG: {}",
+            name
+        );
+        assert_eq!(
+            XDRNGR::distance(seed, results[5]),
+            advances,
+            "XDRNGR: {}",
+            name
+        );
+    }
+}
+
 #[test]
 fn next() {
     let data = get_test_data::<'static, LCRNGTestData>(JSON_STR);
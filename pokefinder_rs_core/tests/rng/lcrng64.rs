@@ -87,6 +87,31 @@ fn jump() {
     }
 }
 
+#[test]
+fn distance() {
+    let data = get_test_data::<'static, LCRNG64TestData>(JSON_STR);
+    for LCRNG64JumpData {
+        name,
+        seed,
+        advances,
+        results,
+    } in data.jump
+    {
+        assert_eq!(
+            BWRNG::distance(seed, results[0]),
+            advances as u64,
+            "BWRNG: {}",
+            name
+        );
+        assert_eq!(
+            BWRNGR::distance(seed, results[1]),
+            advances as u64,
+            "BWRNGR: {}",
+            name
+        );
+    }
+}
+
 #[test]
 fn next() {
     let data = get_test_data::<'static, LCRNG64TestData>(JSON_STR);
@@ -2,5 +2,7 @@ mod lcrng;
 mod lcrng64;
 mod lcrng_reverse;
 mod mt;
+mod sfmt;
+mod sha1;
 mod xoroshiro;
 mod xorshift;
@@ -0,0 +1,99 @@
+use crate::get_test_data;
+use pokefinder_rs_core::rng::{Rng, SFMT};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct SFMTTestData<'a> {
+    #[serde(borrow)]
+    advance: [SFMTAdvanceData<'a>; 4],
+    #[serde(borrow)]
+    next: [SFMTNextData<'a, u64>; 4],
+    #[serde(borrow, rename = "nextUInt")]
+    next_uint: [SFMTNextData<'a, u32>; 4],
+}
+
+#[derive(Deserialize)]
+struct SFMTAdvanceData<'a> {
+    name: &'a str,
+    seed: u32,
+    advances: u32,
+    result: u64,
+}
+
+#[derive(Deserialize)]
+struct SFMTNextData<'a, T> {
+    name: &'a str,
+    seed: u32,
+    result: T,
+}
+
+const JSON_STR: &str = include_str!("sfmt.json");
+
+#[test]
+fn advance() {
+    let data = get_test_data::<'static, SFMTTestData>(JSON_STR);
+    for SFMTAdvanceData {
+        name,
+        seed,
+        advances,
+        result,
+    } in data.advance
+    {
+        let mut rng = SFMT::new(seed);
+        rng.advance(advances - 1);
+        assert_eq!(rng.next(), result, "SFMT: {}", name);
+    }
+}
+
+#[test]
+fn jump() {
+    let data = get_test_data::<'static, SFMTTestData>(JSON_STR);
+    for SFMTAdvanceData {
+        name,
+        seed,
+        advances,
+        result,
+    } in data.advance
+    {
+        let mut rng = SFMT::new(seed);
+        rng.jump(advances - 1);
+        assert_eq!(rng.next(), result, "SFMT: {}", name);
+    }
+}
+
+#[test]
+fn jump_polynomial() {
+    for (seed, advances) in [
+        (0, 700_000),
+        (0x12345678, 1_300_001),
+        (0xFFFFFFFF, 2_600_000),
+    ] {
+        let mut advanced = SFMT::new(seed);
+        advanced.advance(advances);
+        let mut jumped = SFMT::new(seed);
+        jumped.jump(advances);
+        assert_eq!(
+            jumped.next(),
+            advanced.next(),
+            "SFMT: {seed:08X} - {advances}"
+        );
+    }
+}
+
+#[test]
+fn next() {
+    let data = get_test_data::<'static, SFMTTestData>(JSON_STR);
+    for SFMTNextData { name, seed, result } in data.next {
+        let mut rng = SFMT::new(seed);
+        assert_eq!(rng.next(), result, "SFMT: {}", name);
+    }
+}
+
+#[test]
+fn next_u32() {
+    let data = get_test_data::<'static, SFMTTestData>(JSON_STR);
+    for SFMTNextData { name, seed, result } in data.next_uint {
+        let mut rng = SFMT::new(seed);
+        assert_eq!(rng.next_u32(), result, "SFMT: {}", name);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::get_test_data;
+use pokefinder_rs_core::enums::{DSType, Game, Language};
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::rng::SHA1;
+use pokefinder_rs_core::util::{Date, Time};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct SHA1TestData<'a> {
+    #[serde(borrow)]
+    hash: [SHA1HashData<'a>; 4],
+}
+
+#[derive(Deserialize)]
+struct SHA1HashData<'a> {
+    name: &'a str,
+    keypresses: [bool; 4],
+    #[serde(rename = "skipLR")]
+    skip_lr: bool,
+    version: &'a str,
+    mac: u64,
+    #[serde(rename = "softReset")]
+    soft_reset: bool,
+    #[serde(rename = "vFrame")]
+    vframe: u8,
+    #[serde(rename = "gxStat")]
+    gx_stat: u8,
+    timer0: u16,
+    #[serde(rename = "vCount")]
+    vcount: u8,
+    seed: u64,
+}
+
+const JSON_STR: &str = include_str!("sha1.json");
+
+#[test]
+fn hash() {
+    let data = get_test_data::<'static, SHA1TestData>(JSON_STR);
+    for SHA1HashData {
+        name,
+        keypresses,
+        skip_lr,
+        version,
+        mac,
+        soft_reset,
+        vframe,
+        gx_stat,
+        timer0,
+        vcount,
+        seed,
+    } in data.hash
+    {
+        let version = match version {
+            "Black" => Game::BLACK,
+            "White" => Game::WHITE,
+            "Black2" => Game::BLACK2,
+            _ => Game::WHITE2,
+        };
+
+        let profile = Profile5::new(
+            name.to_string(),
+            version,
+            0,
+            0,
+            mac,
+            keypresses,
+            vcount,
+            gx_stat,
+            vframe,
+            skip_lr,
+            timer0,
+            timer0,
+            soft_reset,
+            false,
+            false,
+            DSType::DS,
+            Language::English,
+        );
+
+        let mut sha = SHA1::new(&profile);
+        sha.set_timer0(timer0 as u32, vcount);
+        sha.set_date(Date::new_ymd(2000, 1, 1));
+        sha.set_button(0xff2f0000);
+        sha.precompute();
+        sha.set_time(Time::new_hms(0, 0, 0), DSType::DS);
+
+        assert_eq!(sha.hash_seed(), seed, "SHA1: {}", name);
+    }
+}
@@ -13,6 +13,8 @@ struct EncounterSlotData<'a> {
     k_slot: Vec<SlotData<'a>>,
     #[serde(borrow)]
     bdsp: Vec<SlotData<'a>>,
+    #[serde(borrow)]
+    bw: Vec<SlotData<'a>>,
 }
 
 #[derive(Deserialize)]
@@ -127,3 +129,55 @@ fn bdsp_slot() {
         }
     }
 }
+
+#[test]
+fn bw_slot() {
+    let data = get_test_data::<'static, EncounterSlotData>(JSON_DATA);
+
+    for (
+        num,
+        SlotData {
+            name,
+            encounter,
+            rand,
+        },
+    ) in data.bw.into_iter().enumerate()
+    {
+        for (i, rand) in rand.into_iter().enumerate() {
+            assert_eq!(
+                encounter_slot::bw_slot(rand as u8, Encounter::from(encounter)),
+                i as u8,
+                "Unequal result: {} - {} - {}",
+                name,
+                num,
+                i
+            );
+        }
+    }
+}
+
+#[test]
+fn sm_slot() {
+    for (rand, slot) in [
+        (0, 0),
+        (19, 0),
+        (20, 1),
+        (39, 1),
+        (40, 2),
+        (49, 2),
+        (50, 3),
+        (89, 6),
+        (90, 7),
+        (94, 7),
+        (95, 8),
+        (98, 8),
+        (99, 9),
+    ] {
+        assert_eq!(
+            encounter_slot::sm_slot(rand),
+            slot,
+            "Unequal result: {}",
+            rand
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use pokefinder_rs_core::gen5::states::HiddenGrottoState;
+use pokefinder_rs_core::gen8::states::UndergroundState;
+use pokefinder_rs_core::parents::states::{GeneratorState, State};
+use pokefinder_rs_core::util::export::{Export, ExportFormat};
+
+fn state() -> GeneratorState {
+    GeneratorState {
+        base: State {
+            pid: 0x1234ABCD,
+            ivs: [31, 30, 29, 28, 27, 26],
+            ability: 2,
+            gender: 1,
+            level: 50,
+            nature: 3,
+            shiny: 2,
+            ..Default::default()
+        },
+        advances: 42,
+    }
+}
+
+#[test]
+fn round_trip() {
+    let state = state();
+    let json = serde_json::to_string(&state).unwrap();
+    let result: GeneratorState = serde_json::from_str(&json).unwrap();
+    assert!(result == state, "Unequal state after round trip");
+}
+
+#[test]
+fn csv() {
+    let mut csv = vec![];
+    Export::new(&[state()])
+        .with_species(25, 0)
+        .write(&mut csv, ExportFormat::Csv)
+        .unwrap();
+
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("Species,Advances,Level,PID,Shiny,Nature,Ability,HP,Atk,Def,SpA,SpD,Spe,Hidden Power,Power,Gender")
+    );
+    assert_eq!(
+        lines.next(),
+        Some("Pikachu,42,50,1234ABCD,Square,Adamant,H,31,30,29,28,27,26,Fighting,0,♀")
+    );
+    assert_eq!(lines.next(), None);
+}
+
+#[test]
+fn json() {
+    let state = UndergroundState {
+        base: state(),
+        egg_move: 0,
+        item: 0,
+        species: 25,
+    };
+    let mut json = vec![];
+    Export::new(&[state])
+        .write(&mut json, ExportFormat::Json)
+        .unwrap();
+
+    let rows: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_slice(&json).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["Species"], "Pikachu");
+    assert_eq!(rows[0]["Egg Move"], "-");
+    assert_eq!(rows[0]["Nature"], "Adamant");
+    assert_eq!(rows[0]["Advances"], "42");
+}
+
+#[test]
+fn hidden_grotto() {
+    let states = [
+        HiddenGrottoState::new_species(0, 3, 1, 2, 25, 1),
+        HiddenGrottoState::new_item(82, 4, 2, 5, 1),
+    ];
+    let export = Export::new(&states);
+
+    assert_eq!(
+        export.headers(),
+        vec!["Advances", "Group", "Slot", "Result", "Gender", "Chatot"]
+    );
+    assert_eq!(
+        export.rows(),
+        vec![
+            vec!["3", "1", "2", "Pikachu", "♀", "0"],
+            vec!["4", "2", "5", "Master Ball", "-", "1"],
+        ]
+    );
+}
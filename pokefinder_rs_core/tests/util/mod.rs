@@ -1,4 +1,5 @@
 mod date_time;
 mod encounter_slot;
+mod export;
 mod iv_checker;
 mod iv_to_pid_calculator;
@@ -1,7 +1,13 @@
+use crate::gen3::generators::Gen3Generators;
 use crate::gen3::profile::ProfileManager3;
 use crate::gen3::tools::Gen3Tools;
+use crate::gen4::generators::Gen4Generators;
 use crate::gen4::profile::ProfileManager4;
-use crate::gen4::tools::Gen4Tools;
+use crate::gen4::tools::{Gen4Tools, SeedToTime4};
+use crate::gen5::generators::Gen5Generators;
+use crate::gen5::profile::ProfileManager5;
+use crate::gen8::generators::Gen8Generators;
+use crate::gen8::profile::ProfileManager8;
 use crate::util::IVToPID;
 use egui::Visuals;
 #[cfg(not(target_arch = "wasm32"))]
@@ -11,10 +17,16 @@ use pokefinder_rs_core::parents::init_profile_loader;
 pub struct PokeFinder {
     gen: Gen,
     ivs_to_pid: Option<Box<IVToPID>>,
+    gen_3_generators: Gen3Generators,
     gen_3_tools: Gen3Tools,
+    gen_4_generators: Gen4Generators,
     gen_4_tools: Gen4Tools,
+    gen_5_generators: Gen5Generators,
+    gen_8_generators: Gen8Generators,
     profile_manager3: ProfileManager3,
     profile_manager4: ProfileManager4,
+    profile_manager5: ProfileManager5,
+    profile_manager8: ProfileManager8,
 }
 
 #[derive(Default, PartialEq)]
@@ -22,6 +34,8 @@ enum Gen {
     #[default]
     Gen3,
     Gen4,
+    Gen5,
+    Gen8,
 }
 
 impl PokeFinder {
@@ -38,10 +52,16 @@ impl eframe::App for PokeFinder {
         let Self {
             gen,
             ivs_to_pid,
+            gen_3_generators,
             gen_3_tools,
+            gen_4_generators,
             gen_4_tools,
+            gen_5_generators,
+            gen_8_generators,
             profile_manager3,
             profile_manager4,
+            profile_manager5,
+            profile_manager8,
         } = self;
 
         egui::Window::new("Main").show(ctx, |ui| {
@@ -73,34 +93,132 @@ impl eframe::App for PokeFinder {
                         gen_4_tools.seed_to_time = Some(Box::default());
                     }
                 });
+                ui.menu_button("Gen 5 Tools", |ui| {
+                    if ui.button("Profile Manager 5").clicked() {
+                        profile_manager5.show = true;
+                    }
+                });
+                ui.menu_button("Gen 8 Tools", |ui| {
+                    if ui.button("Profile Manager 8").clicked() {
+                        profile_manager8.show = true;
+                    }
+                });
             });
             ui.separator();
             ui.horizontal(|ui| {
                 ui.selectable_value(gen, Gen::Gen3, "Gen 3");
                 ui.selectable_value(gen, Gen::Gen4, "Gen 4");
+                ui.selectable_value(gen, Gen::Gen5, "Gen 5");
+                ui.selectable_value(gen, Gen::Gen8, "Gen 8");
             });
             ui.separator();
             ui.vertical_centered_justified(|ui| match gen {
                 Gen::Gen3 => {
-                    if ui.button("Egg").clicked() {}
+                    if ui.button("Egg").clicked() && gen_3_generators.egg.is_none() {
+                        gen_3_generators.egg = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("GameCube").clicked() {}
+                    if ui.button("GameCube").clicked() && gen_3_generators.game_cube.is_none() {
+                        gen_3_generators.game_cube = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("IDs").clicked() {}
+                    if ui.button("IDs").clicked() && gen_3_generators.ids.is_none() {
+                        gen_3_generators.ids = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("Static").clicked() {}
+                    if ui.button("Static").clicked() && gen_3_generators.static_generator.is_none()
+                    {
+                        gen_3_generators.static_generator = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("Wild").clicked() {}
+                    if ui.button("Wild").clicked() && gen_3_generators.wild.is_none() {
+                        gen_3_generators.wild = Some(Box::default());
+                    }
                     ui.add_space(3.0);
                 }
                 Gen::Gen4 => {
-                    if ui.button("Egg").clicked() {}
+                    if ui.button("Egg").clicked() && gen_4_generators.egg.is_none() {
+                        gen_4_generators.egg = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("IDs").clicked() {}
+                    if ui.button("IDs").clicked() && gen_4_generators.ids.is_none() {
+                        gen_4_generators.ids = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("Static").clicked() {}
+                    if ui.button("Static").clicked() && gen_4_generators.static_generator.is_none()
+                    {
+                        gen_4_generators.static_generator = Some(Box::default());
+                    }
                     ui.add_space(3.0);
-                    if ui.button("Wild").clicked() {}
+                    if ui.button("Wild").clicked() && gen_4_generators.wild.is_none() {
+                        gen_4_generators.wild = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                }
+                Gen::Gen5 => {
+                    if ui.button("Dream Radar").clicked() && gen_5_generators.dream_radar.is_none()
+                    {
+                        gen_5_generators.dream_radar = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Egg").clicked() && gen_5_generators.egg.is_none() {
+                        gen_5_generators.egg = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Event").clicked() && gen_5_generators.event.is_none() {
+                        gen_5_generators.event = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Hidden Grotto").clicked()
+                        && gen_5_generators.hidden_grotto.is_none()
+                    {
+                        gen_5_generators.hidden_grotto = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("IDs").clicked() && gen_5_generators.ids.is_none() {
+                        gen_5_generators.ids = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Static").clicked() && gen_5_generators.static_generator.is_none()
+                    {
+                        gen_5_generators.static_generator = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Wild").clicked() && gen_5_generators.wild.is_none() {
+                        gen_5_generators.wild = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                }
+                Gen::Gen8 => {
+                    if ui.button("Egg").clicked() && gen_8_generators.egg.is_none() {
+                        gen_8_generators.egg = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Event").clicked() && gen_8_generators.event.is_none() {
+                        gen_8_generators.event = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("IDs").clicked() && gen_8_generators.ids.is_none() {
+                        gen_8_generators.ids = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Raid").clicked() && gen_8_generators.raid.is_none() {
+                        gen_8_generators.raid = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Static").clicked() && gen_8_generators.static_generator.is_none()
+                    {
+                        gen_8_generators.static_generator = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Underground").clicked() && gen_8_generators.underground.is_none()
+                    {
+                        gen_8_generators.underground = Some(Box::default());
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Wild").clicked() && gen_8_generators.wild.is_none() {
+                        gen_8_generators.wild = Some(Box::default());
+                    }
                     ui.add_space(3.0);
                 }
             });
@@ -113,9 +231,17 @@ impl eframe::App for PokeFinder {
             self.ivs_to_pid = None;
         }
 
+        gen_3_generators.show(ctx);
         gen_3_tools.show(ctx);
+        if let Some((seed, version)) = gen_4_generators.show(ctx) {
+            gen_4_tools.seed_to_time = Some(Box::new(SeedToTime4::with_seed(seed, version)));
+        }
         gen_4_tools.show(ctx);
+        gen_5_generators.show(ctx);
+        gen_8_generators.show(ctx);
         profile_manager3.show(ctx);
         profile_manager4.show(ctx);
+        profile_manager5.show(ctx);
+        profile_manager8.show(ctx);
     }
 }
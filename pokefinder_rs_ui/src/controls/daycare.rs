@@ -0,0 +1,149 @@
+use egui::{Ui, Widget};
+use pokefinder_rs_core::parents::Daycare;
+use pokefinder_rs_core::util::translator;
+
+const STATS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+const PARENTS: [&str; 2] = ["Parent A", "Parent B"];
+const GENDERS: [(u8, &str); 3] = [(0, "♂"), (1, "♀"), (3, "Ditto")];
+
+/// Inputs describing the two daycare parents and the egg species
+pub struct DaycareControls {
+    pub parent_ivs: [[u8; 6]; 2],
+    pub parent_ability: [u8; 2],
+    pub parent_gender: [u8; 2],
+    pub parent_item: [u8; 2],
+    pub parent_nature: [u8; 2],
+    pub species: u16,
+    pub masuda: bool,
+}
+
+impl Default for DaycareControls {
+    fn default() -> Self {
+        Self {
+            parent_ivs: [[31; 6]; 2],
+            parent_ability: [0, 0],
+            parent_gender: [0, 1],
+            parent_item: [0, 0],
+            parent_nature: [0, 0],
+            species: 1,
+            masuda: false,
+        }
+    }
+}
+
+impl DaycareControls {
+    /// Draws the parent inputs.
+    ///
+    /// `items` lists the held item names in the order the core expects
+    /// (0 = no item, 1 = Everstone, 2..=7 = power items).
+    pub fn show(&mut self, ui: &mut Ui, id: &str, items: &[&str], max_species: u16) {
+        ui.push_id(id, |ui| {
+            egui::Grid::new("parents").num_columns(3).show(ui, |ui| {
+                ui.label("");
+                for parent in PARENTS {
+                    ui.label(parent);
+                }
+                ui.end_row();
+                for (i, stat) in STATS.iter().enumerate() {
+                    ui.label(*stat);
+                    for parent in 0..2 {
+                        egui::DragValue::new(&mut self.parent_ivs[parent][i])
+                            .clamp_range(0..=31)
+                            .ui(ui);
+                    }
+                    ui.end_row();
+                }
+                ui.label("Ability");
+                for parent in 0..2 {
+                    egui::ComboBox::from_id_source(("ability", parent))
+                        .selected_text(self.parent_ability[parent].to_string())
+                        .show_ui(ui, |ui| {
+                            for ability in 0..2 {
+                                ui.selectable_value(
+                                    &mut self.parent_ability[parent],
+                                    ability,
+                                    ability.to_string(),
+                                );
+                            }
+                        });
+                }
+                ui.end_row();
+                ui.label("Gender");
+                for parent in 0..2 {
+                    egui::ComboBox::from_id_source(("gender", parent))
+                        .selected_text(
+                            GENDERS
+                                .iter()
+                                .find(|(g, _)| *g == self.parent_gender[parent])
+                                .map(|(_, name)| *name)
+                                .unwrap_or_default(),
+                        )
+                        .show_ui(ui, |ui| {
+                            for (gender, name) in GENDERS {
+                                ui.selectable_value(&mut self.parent_gender[parent], gender, name);
+                            }
+                        });
+                }
+                ui.end_row();
+                ui.label("Nature");
+                for parent in 0..2 {
+                    egui::ComboBox::from_id_source(("nature", parent))
+                        .selected_text(translator::get_nature(self.parent_nature[parent]))
+                        .show_ui(ui, |ui| {
+                            for (i, nature) in translator::get_natures().iter().enumerate() {
+                                ui.selectable_value(
+                                    &mut self.parent_nature[parent],
+                                    i as u8,
+                                    nature,
+                                );
+                            }
+                        });
+                }
+                ui.end_row();
+                if items.len() > 1 {
+                    ui.label("Item");
+                    for parent in 0..2 {
+                        egui::ComboBox::from_id_source(("item", parent))
+                            .selected_text(
+                                items
+                                    .get(self.parent_item[parent] as usize)
+                                    .copied()
+                                    .unwrap_or_default(),
+                            )
+                            .show_ui(ui, |ui| {
+                                for (i, item) in items.iter().enumerate() {
+                                    ui.selectable_value(
+                                        &mut self.parent_item[parent],
+                                        i as u8,
+                                        *item,
+                                    );
+                                }
+                            });
+                    }
+                    ui.end_row();
+                }
+                ui.label("Egg Species");
+                ui.horizontal(|ui| {
+                    egui::DragValue::new(&mut self.species)
+                        .clamp_range(1..=max_species)
+                        .ui(ui);
+                    ui.label(translator::get_species(self.species));
+                });
+                ui.checkbox(&mut self.masuda, "Masuda Method");
+                ui.end_row();
+            });
+        });
+    }
+
+    pub fn get_daycare(&self) -> Daycare {
+        Daycare::new(
+            self.parent_ivs,
+            self.parent_ability,
+            self.parent_gender,
+            self.parent_item,
+            self.parent_nature,
+            self.species,
+            self.masuda,
+        )
+    }
+}
@@ -0,0 +1,179 @@
+use egui::{Ui, Widget};
+use pokefinder_rs_core::util::translator;
+
+const STATS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// Filter criteria shared by every generator and searcher window.
+///
+/// The values are stored in the same representation the core filters expect,
+/// so they can be passed straight into the `StateFilterN::new` constructors.
+pub struct Filter {
+    pub min: [u8; 6],
+    pub max: [u8; 6],
+    pub natures: [bool; 25],
+    pub powers: [bool; 16],
+    pub ability: u8,
+    pub gender: u8,
+    pub shiny: u8,
+    pub encounter_slots: [bool; 12],
+    pub skip: bool,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            min: [0; 6],
+            max: [31; 6],
+            natures: [true; 25],
+            powers: [true; 16],
+            ability: 255,
+            gender: 255,
+            shiny: 255,
+            encounter_slots: [true; 12],
+            skip: false,
+        }
+    }
+}
+
+impl Filter {
+    /// Draws the filter controls.
+    ///
+    /// `slots` is the number of encounter slots to offer, or 0 to hide the slot filter.
+    pub fn show(&mut self, ui: &mut Ui, id: &str, slots: usize) {
+        ui.push_id(id, |ui| {
+            egui::Grid::new("ivs").num_columns(4).show(ui, |ui| {
+                for (i, stat) in STATS.iter().enumerate() {
+                    ui.label(*stat);
+                    egui::DragValue::new(&mut self.min[i])
+                        .clamp_range(0..=self.max[i])
+                        .ui(ui);
+                    egui::DragValue::new(&mut self.max[i])
+                        .clamp_range(self.min[i]..=31)
+                        .ui(ui);
+                    ui.horizontal(|ui| {
+                        if ui.small_button("31").clicked() {
+                            self.min[i] = 31;
+                            self.max[i] = 31;
+                        }
+                        if ui.small_button("0").clicked() {
+                            self.min[i] = 0;
+                            self.max[i] = 0;
+                        }
+                        if ui.small_button("Any").clicked() {
+                            self.min[i] = 0;
+                            self.max[i] = 31;
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+            ui.add_space(5.0);
+            egui::Grid::new("misc").num_columns(2).show(ui, |ui| {
+                ui.label("Ability");
+                egui::ComboBox::from_id_source("ability")
+                    .selected_text(match self.ability {
+                        0 => "1",
+                        1 => "2",
+                        2 => "H",
+                        _ => "Any",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.ability, 255, "Any");
+                        ui.selectable_value(&mut self.ability, 0, "1");
+                        ui.selectable_value(&mut self.ability, 1, "2");
+                        ui.selectable_value(&mut self.ability, 2, "H");
+                    });
+                ui.end_row();
+                ui.label("Gender");
+                egui::ComboBox::from_id_source("gender")
+                    .selected_text(if self.gender == 255 {
+                        "Any"
+                    } else {
+                        translator::get_gender(self.gender)
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.gender, 255, "Any");
+                        ui.selectable_value(&mut self.gender, 0, translator::get_gender(0));
+                        ui.selectable_value(&mut self.gender, 1, translator::get_gender(1));
+                    });
+                ui.end_row();
+                ui.label("Shiny");
+                egui::ComboBox::from_id_source("shiny")
+                    .selected_text(match self.shiny {
+                        1 => "Star",
+                        2 => "Square",
+                        3 => "Star/Square",
+                        _ => "Any",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.shiny, 255, "Any");
+                        ui.selectable_value(&mut self.shiny, 1, "Star");
+                        ui.selectable_value(&mut self.shiny, 2, "Square");
+                        ui.selectable_value(&mut self.shiny, 3, "Star/Square");
+                    });
+                ui.end_row();
+                ui.label("Nature");
+                ui.menu_button(
+                    selection_text(&self.natures, |i| translator::get_nature(i as u8)),
+                    |ui| {
+                        check_list(ui, &mut self.natures, |i| translator::get_nature(i as u8));
+                    },
+                );
+                ui.end_row();
+                ui.label("Hidden Power");
+                ui.menu_button(
+                    selection_text(&self.powers, |i| translator::get_hidden_power(i as u8)),
+                    |ui| {
+                        check_list(ui, &mut self.powers, |i| {
+                            translator::get_hidden_power(i as u8)
+                        });
+                    },
+                );
+                ui.end_row();
+                if slots != 0 {
+                    ui.label("Encounter Slot");
+                    let slot_names = (0..slots).map(|i| i.to_string()).collect::<Vec<_>>();
+                    ui.menu_button(
+                        selection_text(&self.encounter_slots[..slots], |i| slot_names[i].as_str()),
+                        |ui| {
+                            check_list(ui, &mut self.encounter_slots[..slots], |i| {
+                                slot_names[i].as_str()
+                            });
+                        },
+                    );
+                    ui.end_row();
+                }
+            });
+            ui.checkbox(&mut self.skip, "Disable Filters");
+        });
+    }
+}
+
+fn selection_text<'a>(selected: &[bool], name: impl Fn(usize) -> &'a str) -> String {
+    let count = selected.iter().filter(|&&b| b).count();
+    if count == selected.len() {
+        "Any".to_string()
+    } else if count == 1 {
+        name(selected.iter().position(|&b| b).unwrap()).to_string()
+    } else {
+        format!("{count} selected")
+    }
+}
+
+fn check_list<'a>(ui: &mut Ui, selected: &mut [bool], name: impl Fn(usize) -> &'a str) {
+    ui.horizontal(|ui| {
+        if ui.button("All").clicked() {
+            selected.iter_mut().for_each(|b| *b = true);
+        }
+        if ui.button("None").clicked() {
+            selected.iter_mut().for_each(|b| *b = false);
+        }
+    });
+    egui::ScrollArea::vertical()
+        .max_height(200.0)
+        .show(ui, |ui| {
+            for (i, b) in selected.iter_mut().enumerate() {
+                ui.checkbox(b, name(i));
+            }
+        });
+}
@@ -0,0 +1,35 @@
+use egui::{Color32, Ui};
+
+/// Text input for hexadecimal values that don't fit in the precision of a `DragValue`
+pub struct HexField {
+    pub value: u64,
+    text: String,
+    digits: usize,
+}
+
+impl HexField {
+    /// Creates a field holding `value` that accepts up to `digits` hex digits
+    pub fn new(value: u64, digits: usize) -> Self {
+        Self {
+            value,
+            text: format!("{:0>1$X}", value, digits),
+            digits,
+        }
+    }
+
+    /// Draws the input, keeping the last valid value while the text can't be parsed
+    pub fn show(&mut self, ui: &mut Ui) {
+        let valid = self.text.len() <= self.digits && u64::from_str_radix(&self.text, 16).is_ok();
+        let mut edit = egui::TextEdit::singleline(&mut self.text)
+            .char_limit(self.digits)
+            .desired_width(8.0 * self.digits as f32 + 8.0);
+        if !valid {
+            edit = edit.text_color(Color32::RED);
+        }
+        if ui.add(edit).changed() {
+            if let Ok(value) = u64::from_str_radix(&self.text, 16) {
+                self.value = value;
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use egui::Ui;
+use pokefinder_rs_core::parents::filters::IDFilter;
+use std::str::FromStr;
+
+/// Text inputs for comma separated TID/SID/TSV lists
+#[derive(Default)]
+pub struct IDFilterControls {
+    pub tid: String,
+    pub sid: String,
+    pub tsv: String,
+    pub display: String,
+}
+
+fn parse_list<T: FromStr>(text: &str) -> Vec<T> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(|s| s.parse().ok())
+        .collect()
+}
+
+impl IDFilterControls {
+    /// Draws the filter inputs as rows of a grid.
+    ///
+    /// The display TID input is only shown when `display` is `true`.
+    pub fn show(&mut self, ui: &mut Ui, display: bool) {
+        ui.label("TID");
+        ui.text_edit_singleline(&mut self.tid);
+        ui.end_row();
+        ui.label("SID");
+        ui.text_edit_singleline(&mut self.sid);
+        ui.end_row();
+        ui.label("TSV");
+        ui.text_edit_singleline(&mut self.tsv);
+        ui.end_row();
+        if display {
+            ui.label("Display TID");
+            ui.text_edit_singleline(&mut self.display);
+            ui.end_row();
+        }
+    }
+
+    pub fn get_filter(&self) -> IDFilter {
+        IDFilter::new(
+            parse_list(&self.tid),
+            parse_list(&self.sid),
+            parse_list(&self.tsv),
+            parse_list(&self.display),
+        )
+    }
+}
@@ -0,0 +1,79 @@
+use egui::Ui;
+use pokefinder_rs_core::enums::Lead;
+use pokefinder_rs_core::util::translator;
+
+/// Lead picker that also keeps track of the Synchronize nature
+pub struct LeadSelect {
+    lead: Lead,
+    synchronize_nature: u8,
+}
+
+impl Default for LeadSelect {
+    fn default() -> Self {
+        Self {
+            lead: Lead::NONE,
+            synchronize_nature: 0,
+        }
+    }
+}
+
+const LEAD_NAMES: [(Lead, &str); 12] = [
+    (Lead::SYNCHRONIZE, "Synchronize"),
+    (Lead::CUTE_CHARM_F, "Cute Charm (♀)"),
+    (Lead::CUTE_CHARM_M, "Cute Charm (♂)"),
+    (Lead::MAGNET_PULL, "Magnet Pull"),
+    (Lead::STATIC, "Static"),
+    (Lead::HARVEST, "Harvest"),
+    (Lead::FLASH_FIRE, "Flash Fire"),
+    (Lead::STORM_DRAIN, "Storm Drain"),
+    (Lead::PRESSURE, "Pressure/Hustle/Vital Spirit"),
+    (Lead::SUCTION_CUPS, "Suction Cups/Sticky Hold"),
+    (Lead::COMPOUND_EYES, "Compound Eyes/Super Luck"),
+    (Lead::ARENA_TRAP, "Arena Trap/Illuminate/No Guard"),
+];
+
+fn lead_name(lead: Lead) -> &'static str {
+    LEAD_NAMES
+        .iter()
+        .find(|(l, _)| *l == lead)
+        .map(|(_, name)| *name)
+        .unwrap_or("None")
+}
+
+impl LeadSelect {
+    /// Draws the lead picker offering `Lead::NONE` followed by `leads`.
+    ///
+    /// Picking [`Lead::SYNCHRONIZE`] shows an additional nature picker.
+    pub fn show(&mut self, ui: &mut Ui, id: &str, leads: &[Lead]) {
+        ui.push_id(id, |ui| {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("lead")
+                    .selected_text(lead_name(self.lead))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.lead, Lead::NONE, lead_name(Lead::NONE));
+                        for &lead in leads {
+                            ui.selectable_value(&mut self.lead, lead, lead_name(lead));
+                        }
+                    });
+                if self.lead == Lead::SYNCHRONIZE {
+                    egui::ComboBox::from_id_source("synchronize")
+                        .selected_text(translator::get_nature(self.synchronize_nature))
+                        .show_ui(ui, |ui| {
+                            for (i, nature) in translator::get_natures().iter().enumerate() {
+                                ui.selectable_value(&mut self.synchronize_nature, i as u8, nature);
+                            }
+                        });
+                }
+            });
+        });
+    }
+
+    /// Returns the selected [`Lead`], with Synchronize resolved to its nature
+    pub fn get_lead(&self) -> Lead {
+        if self.lead == Lead::SYNCHRONIZE {
+            Lead::from_bits_retain(self.synchronize_nature)
+        } else {
+            self.lead
+        }
+    }
+}
@@ -0,0 +1,23 @@
+mod daycare;
+mod filter;
+mod hex;
+mod id_filter;
+mod lead;
+mod names;
+mod profile;
+mod results;
+mod search;
+mod settings;
+mod wondercard;
+
+pub use daycare::*;
+pub use filter::*;
+pub use hex::*;
+pub use id_filter::*;
+pub use lead::*;
+pub use names::*;
+pub use profile::*;
+pub use results::*;
+pub use search::*;
+pub use settings::*;
+pub use wondercard::*;
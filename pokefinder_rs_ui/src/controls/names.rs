@@ -0,0 +1,55 @@
+use pokefinder_rs_core::enums::{Encounter, Method};
+
+pub fn encounter_name(encounter: Encounter) -> &'static str {
+    match encounter {
+        Encounter::Grass => "Grass",
+        Encounter::DoubleGrass => "Double Grass",
+        Encounter::SpecialGrass => "Special Grass",
+        Encounter::RockSmash => "Rock Smash",
+        Encounter::Surfing => "Surfing",
+        Encounter::SpecialSurf => "Special Surf",
+        Encounter::OldRod => "Old Rod",
+        Encounter::GoodRod => "Good Rod",
+        Encounter::SuperRod => "Super Rod",
+        Encounter::SpecialSuperRod => "Special Super Rod",
+        Encounter::Static => "Static",
+        Encounter::BugCatchingContest => "Bug Catching Contest",
+        Encounter::Headbutt => "Headbutt",
+        Encounter::HeadbuttAlt => "Headbutt (Alt)",
+        Encounter::HeadbuttSpecial => "Headbutt (Special)",
+        Encounter::Roamer => "Roamer",
+        Encounter::Gift => "Gift",
+        Encounter::EntraLink => "Entralink",
+        Encounter::GiftEgg => "Gift Egg",
+        Encounter::HiddenGrotto => "Hidden Grotto",
+    }
+}
+
+pub fn method_name(method: Method) -> &'static str {
+    match method {
+        Method::Method1 => "Method 1",
+        Method::Method1Reverse => "Reverse Method 1",
+        Method::Method2 => "Method 2",
+        Method::Method4 => "Method 4",
+        Method::XDColo => "XD/Colo",
+        Method::Channel => "Channel",
+        Method::EBred => "Bred",
+        Method::EBredSplit => "Bred Split",
+        Method::EBredAlternate => "Bred Alternate",
+        Method::EBredPID => "Bred PID",
+        Method::RSFRLGBred => "Bred",
+        Method::RSFRLGBredSplit => "Bred Split",
+        Method::RSFRLGBredAlternate => "Bred Alternate",
+        Method::RSFRLGBredMixed => "Bred Mixed",
+        Method::CuteCharmDPPt => "Cute Charm (DPPt)",
+        Method::CuteCharmHGSS => "Cute Charm (HGSS)",
+        Method::MethodJ => "Method J",
+        Method::MethodK => "Method K",
+        Method::PokeRadar => "Poke Radar",
+        Method::WondercardIVs => "Wondercard IVs",
+        Method::Method5IVs => "Method 5 IVs",
+        Method::Method5CGear => "Method 5 C-Gear",
+        Method::Method5 => "Method 5",
+        Method::None => "None",
+    }
+}
@@ -0,0 +1,33 @@
+use egui::Ui;
+use pokefinder_rs_core::parents::Profile;
+use pokefinder_rs_core::util::translator;
+
+/// Draws a profile picker along with the TID/SID/version of the selected profile.
+///
+/// Returns `true` when the selection changed.
+pub fn profile_select<P: Profile>(
+    ui: &mut Ui,
+    id: &str,
+    profiles: &[P],
+    selected: &mut usize,
+) -> bool {
+    let previous = *selected;
+    ui.horizontal(|ui| {
+        ui.label("Profile");
+        egui::ComboBox::from_id_source(id)
+            .selected_text(profiles[*selected].get_name())
+            .show_ui(ui, |ui| {
+                for (i, profile) in profiles.iter().enumerate() {
+                    ui.selectable_value(selected, i, profile.get_name());
+                }
+            });
+        let profile = &profiles[*selected];
+        ui.label(format!(
+            "Version: {}  TID: {}  SID: {}",
+            translator::get_game(profile.get_version()),
+            profile.get_tid(),
+            profile.get_sid()
+        ));
+    });
+    previous != *selected
+}
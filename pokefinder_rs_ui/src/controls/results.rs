@@ -0,0 +1,107 @@
+use egui::Ui;
+use egui_extras::Column;
+use pokefinder_rs_core::parents::states::{EggState, State};
+use pokefinder_rs_core::util::translator;
+
+/// Draws a striped, scrollable table of pre-formatted result rows.
+///
+/// When `selected` is provided an extra radio button column lets the user pick a row.
+pub fn results_table(
+    ui: &mut Ui,
+    id: &str,
+    headers: &[&str],
+    rows: &[Vec<String>],
+    mut selected: Option<&mut usize>,
+) {
+    let columns = headers.len() + selected.is_some() as usize;
+    ui.push_id(id, |ui| {
+        egui_extras::TableBuilder::new(ui)
+            .columns(Column::auto().clip(false), columns)
+            .auto_shrink([false; 2])
+            .striped(true)
+            .max_scroll_height(300.0)
+            .resizable(true)
+            .header(20.0, |mut header| {
+                if selected.is_some() {
+                    header.col(|ui| {
+                        ui.heading("");
+                    });
+                }
+                for head in headers {
+                    header.col(|ui| {
+                        ui.heading(*head);
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(15.0, rows.len(), |i, mut row| {
+                    if let Some(selected) = selected.as_mut() {
+                        row.col(|ui| {
+                            ui.radio_value(*selected, i, "");
+                        });
+                    }
+                    for cell in &rows[i] {
+                        row.col(|ui| {
+                            ui.label(cell);
+                        });
+                    }
+                });
+            });
+    });
+}
+
+pub fn format_ivs(ivs: [u8; 6]) -> [String; 6] {
+    ivs.map(|iv| iv.to_string())
+}
+
+pub fn format_shiny(shiny: u8) -> &'static str {
+    match shiny {
+        1 => "Star",
+        2 => "Square",
+        _ => "No",
+    }
+}
+
+pub fn format_ability(ability: u8) -> &'static str {
+    match ability {
+        0 => "1",
+        1 => "2",
+        _ => "H",
+    }
+}
+
+/// Formats the columns that every Pokemon result shares.
+///
+/// The columns line up with [`STATE_HEADERS`].
+pub fn format_state(state: &State) -> Vec<String> {
+    let mut row = vec![
+        format!("{:0>8X}", state.pid),
+        format_shiny(state.shiny).to_string(),
+        translator::get_nature(state.nature).to_string(),
+        format_ability(state.ability).to_string(),
+    ];
+    row.extend(format_ivs(state.ivs));
+    row.push(translator::get_hidden_power(state.hidden_power).to_string());
+    row.push(state.hidden_power_strength.to_string());
+    row.push(translator::get_gender(state.gender).to_string());
+    row
+}
+
+/// Formats an egg like [`format_state`], showing inherited IVs as the parent they came from
+pub fn format_egg_state(egg: &EggState) -> Vec<String> {
+    let mut row = format_state(&egg.base);
+    for (i, inheritance) in egg.inheritance.iter().enumerate() {
+        match inheritance {
+            1 => row[4 + i] = "A".to_string(),
+            2 => row[4 + i] = "B".to_string(),
+            _ => {}
+        }
+    }
+    row
+}
+
+/// Headers for the columns produced by [`format_state`]
+pub const STATE_HEADERS: [&str; 13] = [
+    "PID", "Shiny", "Nature", "Ability", "HP", "Atk", "Def", "SpA", "SpD", "Spe", "Hidden",
+    "Power", "Gender",
+];
@@ -0,0 +1,90 @@
+use egui::Ui;
+use pokefinder_rs_core::parents::searchers::SearcherT;
+
+/// A searcher running on a background thread.
+///
+/// The window keeps a clone of the searcher and polls it every frame while the
+/// thread owns the other clone and performs the search.
+pub struct BackgroundSearch<S: SearcherT + Clone + Send + 'static> {
+    searcher: S,
+    total: u32,
+    #[cfg(not(target_arch = "wasm32"))]
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl<S: SearcherT + Clone + Send + 'static> BackgroundSearch<S> {
+    /// Starts `search` with a clone of `searcher`.
+    ///
+    /// `total` is the progress value the searcher reports once it is done.
+    /// Threads are not available on the web, so there the search runs to completion immediately.
+    pub fn start(searcher: S, total: u32, search: impl FnOnce(S) + Send + 'static) -> Self {
+        let thread_searcher = searcher.clone();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let handle = std::thread::spawn(move || search(thread_searcher));
+            Self {
+                searcher,
+                total,
+                handle: Some(handle),
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            search(thread_searcher);
+            Self { searcher, total }
+        }
+    }
+
+    /// Returns `true` once the search thread has exited
+    pub fn finished(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.handle
+                .as_ref()
+                .map(|handle| handle.is_finished())
+                .unwrap_or(true)
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            true
+        }
+    }
+
+    /// Returns the results found since the last call
+    pub fn results(&self) -> Vec<S::State> {
+        self.searcher.results()
+    }
+
+    pub fn cancel(&self) {
+        self.searcher.cancel();
+    }
+
+    /// Draws a progress bar and a cancel button.
+    ///
+    /// Returns `true` when the cancel button was clicked.
+    pub fn show(&self, ui: &mut Ui) -> bool {
+        let mut cancel = false;
+        ui.horizontal(|ui| {
+            let progress = if self.total == 0 {
+                1.0
+            } else {
+                self.searcher.progress() as f32 / self.total as f32
+            };
+            ui.add(
+                egui::ProgressBar::new(progress)
+                    .show_percentage()
+                    .desired_width(300.0),
+            );
+            if ui.button("Cancel").clicked() {
+                cancel = true;
+            }
+        });
+        cancel
+    }
+}
+
+impl<S: SearcherT + Clone + Send + 'static> Drop for BackgroundSearch<S> {
+    fn drop(&mut self) {
+        self.searcher.cancel();
+    }
+}
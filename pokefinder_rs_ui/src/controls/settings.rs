@@ -0,0 +1,103 @@
+use egui::{Ui, Widget};
+
+/// Seed and advance range inputs shared by the generator windows
+pub struct GeneratorSettings {
+    pub seed: u32,
+    pub initial_advances: u32,
+    pub max_advances: u32,
+    pub delay: u32,
+}
+
+impl Default for GeneratorSettings {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            initial_advances: 0,
+            max_advances: 1000,
+            delay: 0,
+        }
+    }
+}
+
+impl GeneratorSettings {
+    /// Draws the settings as rows of a grid.
+    ///
+    /// `seed_width` is the number of hexadecimal digits of the seed, or 0 to hide the seed input.
+    /// The delay input is only shown when `delay` is `true`.
+    pub fn show(&mut self, ui: &mut Ui, seed_width: usize, delay: bool) {
+        if seed_width != 0 {
+            ui.label("Seed");
+            egui::DragValue::new(&mut self.seed)
+                .clamp_range(0..=u32::MAX)
+                .hexadecimal(seed_width, false, true)
+                .ui(ui);
+            ui.end_row();
+        }
+        ui.label("Initial Advances");
+        egui::DragValue::new(&mut self.initial_advances)
+            .clamp_range(0..=u32::MAX)
+            .ui(ui);
+        ui.end_row();
+        ui.label("Max Advances");
+        egui::DragValue::new(&mut self.max_advances)
+            .clamp_range(0..=u32::MAX)
+            .ui(ui);
+        ui.end_row();
+        if delay {
+            ui.label("Delay");
+            egui::DragValue::new(&mut self.delay)
+                .clamp_range(0..=u32::MAX)
+                .ui(ui);
+            ui.end_row();
+        }
+    }
+}
+
+/// Advance and delay range inputs shared by the Gen 4 searcher windows
+pub struct SearcherSettings {
+    pub min_advance: u32,
+    pub max_advance: u32,
+    pub min_delay: u32,
+    pub max_delay: u32,
+}
+
+impl Default for SearcherSettings {
+    fn default() -> Self {
+        Self {
+            min_advance: 0,
+            max_advance: 100,
+            min_delay: 600,
+            max_delay: 800,
+        }
+    }
+}
+
+impl SearcherSettings {
+    /// Draws the settings as rows of a grid.
+    ///
+    /// The advance range is only shown when `advances` is `true`.
+    pub fn show(&mut self, ui: &mut Ui, advances: bool) {
+        if advances {
+            ui.label("Min Advance");
+            egui::DragValue::new(&mut self.min_advance)
+                .clamp_range(0..=self.max_advance)
+                .ui(ui);
+            ui.end_row();
+            ui.label("Max Advance");
+            egui::DragValue::new(&mut self.max_advance)
+                .clamp_range(self.min_advance..=u32::MAX)
+                .ui(ui);
+            ui.end_row();
+        }
+        ui.label("Min Delay");
+        egui::DragValue::new(&mut self.min_delay)
+            .clamp_range(0..=self.max_delay)
+            .ui(ui);
+        ui.end_row();
+        ui.label("Max Delay");
+        egui::DragValue::new(&mut self.max_delay)
+            .clamp_range(self.min_delay..=u32::MAX)
+            .ui(ui);
+        ui.end_row();
+    }
+}
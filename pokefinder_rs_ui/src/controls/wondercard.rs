@@ -0,0 +1,43 @@
+use egui::{Color32, Ui};
+
+/// Path input for a wondercard file and the error of the last import
+#[derive(Default)]
+pub struct WondercardField {
+    path: String,
+    error: Option<String>,
+}
+
+impl WondercardField {
+    /// Draws the path input and import button as a row of a grid.
+    ///
+    /// Returns the contents of the file when it was imported and holds at least `size` bytes.
+    pub fn show(&mut self, ui: &mut Ui, size: usize) -> Option<Vec<u8>> {
+        let mut data = None;
+        ui.label("Wondercard");
+        ui.horizontal(|ui| {
+            ui.text_edit_singleline(&mut self.path);
+            if ui.button("Import").clicked() {
+                match std::fs::read(self.path.trim()) {
+                    Ok(bytes) if bytes.len() >= size => {
+                        self.error = None;
+                        data = Some(bytes);
+                    }
+                    Ok(bytes) => {
+                        self.error = Some(format!(
+                            "The file is {} bytes long, a wondercard needs at least {size}",
+                            bytes.len()
+                        ));
+                    }
+                    Err(error) => self.error = Some(error.to_string()),
+                }
+            }
+        });
+        ui.end_row();
+        if let Some(error) = &self.error {
+            ui.label("");
+            ui.colored_label(Color32::RED, error);
+            ui.end_row();
+        }
+        data
+    }
+}
@@ -0,0 +1,240 @@
+use crate::controls::{
+    format_egg_state, method_name, profile_select, results_table, DaycareControls, Filter,
+    GeneratorSettings, STATE_HEADERS,
+};
+use egui::{Context, Widget};
+use pokefinder_rs_core::enums::{Game, Method};
+use pokefinder_rs_core::gen3::filters::StateFilter3;
+use pokefinder_rs_core::gen3::generators::EggGenerator3;
+use pokefinder_rs_core::gen3::states::EggState3;
+use pokefinder_rs_core::gen3::Profile3;
+use pokefinder_rs_core::parents::{profile_loader_3, Profile};
+
+const EMERALD_METHODS: [Method; 3] = [Method::EBred, Method::EBredSplit, Method::EBredAlternate];
+const RSFRLG_METHODS: [Method; 4] = [
+    Method::RSFRLGBred,
+    Method::RSFRLGBredSplit,
+    Method::RSFRLGBredAlternate,
+    Method::RSFRLGBredMixed,
+];
+const COMPATIBILITY: [(u8, &str); 3] = [
+    (20, "The two don't seem to like each other"),
+    (50, "The two seem to get along"),
+    (70, "The two seem to get along very well"),
+];
+
+pub struct Egg3 {
+    profiles: Vec<Profile3>,
+    profile: usize,
+    held: GeneratorSettings,
+    pickup: GeneratorSettings,
+    method: Method,
+    compatibility: u8,
+    calibration: u8,
+    min_redraw: u8,
+    max_redraw: u8,
+    daycare: DaycareControls,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Egg3 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_3::get_profiles();
+        profiles.retain(|p| (p.get_version() & Game::GEN3) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile3::default());
+        }
+        let mut egg = Self {
+            profiles,
+            profile: 0,
+            held: GeneratorSettings::default(),
+            pickup: GeneratorSettings::default(),
+            method: Method::None,
+            compatibility: 20,
+            calibration: 18,
+            min_redraw: 0,
+            max_redraw: 0,
+            daycare: DaycareControls::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        egg.update_method();
+        egg
+    }
+}
+
+static HEADERS: [&str; 3] = ["Held Advances", "Pickup Advances", "Redraws"];
+
+impl Egg3 {
+    fn emerald(&self) -> bool {
+        self.profiles[self.profile].get_version() == Game::EMERALD
+    }
+
+    fn methods(&self) -> &'static [Method] {
+        if self.emerald() {
+            &EMERALD_METHODS
+        } else {
+            &RSFRLG_METHODS
+        }
+    }
+
+    fn update_method(&mut self) {
+        if !self.methods().contains(&self.method) {
+            self.method = self.methods()[0];
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Egg Generator 3").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "egg_3_profile", &self.profiles, &mut self.profile) {
+                self.update_method();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    let emerald = self.emerald();
+                    ui.label("Held");
+                    egui::Grid::new("egg_3_held").num_columns(2).show(ui, |ui| {
+                        self.held.show(ui, if emerald { 0 } else { 8 }, true);
+                    });
+                    ui.separator();
+                    ui.label("Pickup");
+                    egui::Grid::new("egg_3_pickup")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            self.pickup.show(ui, if emerald { 0 } else { 8 }, true);
+                        });
+                    ui.separator();
+                    egui::Grid::new("egg_3_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Method");
+                            egui::ComboBox::from_id_source("egg_3_method")
+                                .selected_text(method_name(self.method))
+                                .show_ui(ui, |ui| {
+                                    for &method in self.methods() {
+                                        ui.selectable_value(
+                                            &mut self.method,
+                                            method,
+                                            method_name(method),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Compatibility");
+                            egui::ComboBox::from_id_source("egg_3_compatibility")
+                                .selected_text(
+                                    COMPATIBILITY
+                                        .iter()
+                                        .find(|(c, _)| *c == self.compatibility)
+                                        .map(|(_, name)| *name)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (compatibility, name) in COMPATIBILITY {
+                                        ui.selectable_value(
+                                            &mut self.compatibility,
+                                            compatibility,
+                                            name,
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            if emerald {
+                                ui.label("Calibration");
+                                egui::DragValue::new(&mut self.calibration)
+                                    .clamp_range(0..=255)
+                                    .ui(ui);
+                                ui.end_row();
+                                ui.label("Min Redraws");
+                                egui::DragValue::new(&mut self.min_redraw)
+                                    .clamp_range(0..=self.max_redraw)
+                                    .ui(ui);
+                                ui.end_row();
+                                ui.label("Max Redraws");
+                                egui::DragValue::new(&mut self.max_redraw)
+                                    .clamp_range(self.min_redraw..=255)
+                                    .ui(ui);
+                                ui.end_row();
+                            }
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.daycare
+                        .show(ui, "egg_3_daycare", &["None", "Everstone"], 386);
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "egg_3_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "egg_3_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = StateFilter3::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let daycare = self.daycare.get_daycare();
+        let generator = EggGenerator3::new(
+            self.held.initial_advances,
+            self.held.max_advances,
+            self.held.delay,
+            self.pickup.initial_advances,
+            self.pickup.max_advances,
+            self.pickup.delay,
+            self.calibration,
+            self.min_redraw,
+            self.max_redraw,
+            self.method,
+            self.compatibility,
+            &daycare,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.held.seed, self.pickup.seed)
+            .iter()
+            .map(format_egg)
+            .collect();
+    }
+}
+
+fn format_egg(state: &EggState3) -> Vec<String> {
+    let mut row = vec![
+        state.base.advances.to_string(),
+        state.pickup_advances.to_string(),
+        state.redraws.to_string(),
+    ];
+    row.extend(format_egg_state(&state.base.base));
+    row
+}
@@ -0,0 +1,218 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Game, Method};
+use pokefinder_rs_core::gen3::filters::StateFilter3;
+use pokefinder_rs_core::gen3::generators::GameCubeGenerator;
+use pokefinder_rs_core::gen3::{encounters3, Profile3, ShadowTemplate};
+use pokefinder_rs_core::parents::{profile_loader_3, Profile, StaticTemplate, Template};
+use pokefinder_rs_core::util::translator;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Category {
+    Shadow,
+    NonShadow,
+    Channel,
+}
+
+pub struct GameCube {
+    profiles: Vec<Profile3>,
+    profile: usize,
+    settings: GeneratorSettings,
+    category: Category,
+    shadows: Vec<&'static ShadowTemplate>,
+    templates: Vec<&'static StaticTemplate>,
+    pokemon: usize,
+    unset: bool,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for GameCube {
+    fn default() -> Self {
+        let mut profiles = profile_loader_3::get_profiles();
+        profiles.retain(|p| (p.get_version() & Game::GC) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile3 {
+                version: Game::GALES,
+                ..Default::default()
+            });
+        }
+        let mut game_cube = Self {
+            profiles,
+            profile: 0,
+            settings: GeneratorSettings::default(),
+            category: Category::Shadow,
+            shadows: vec![],
+            templates: vec![],
+            pokemon: 0,
+            unset: false,
+            filter: Filter::default(),
+            results: vec![],
+        };
+        game_cube.update_templates();
+        game_cube
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Level"];
+
+impl GameCube {
+    fn update_templates(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.shadows = encounters3::get_shadow_teams()
+            .iter()
+            .filter(|template| (template.get_version() & version) != Game::NONE)
+            .collect();
+        self.templates = encounters3::get_static_encounters(match self.category {
+            Category::Channel => 8,
+            _ => 7,
+        })
+        .iter()
+        .filter(|template| (template.get_version() & version) != Game::NONE)
+        .collect();
+        self.pokemon = 0;
+    }
+
+    fn pokemon_names(&self) -> Vec<String> {
+        if self.category == Category::Shadow {
+            self.shadows
+                .iter()
+                .map(|template| translator::get_species(template.get_species()).to_string())
+                .collect()
+        } else {
+            self.templates
+                .iter()
+                .map(|template| {
+                    translator::get_species_with_form(template.get_species(), template.get_form())
+                })
+                .collect()
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("GameCube Generator").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "game_cube_profile", &self.profiles, &mut self.profile) {
+                self.update_templates();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("game_cube_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            self.settings.show(ui, 8, true);
+                            ui.label("Category");
+                            let previous = self.category;
+                            ui.horizontal(|ui| {
+                                ui.selectable_value(&mut self.category, Category::Shadow, "Shadow");
+                                ui.selectable_value(
+                                    &mut self.category,
+                                    Category::NonShadow,
+                                    "Non-Shadow",
+                                );
+                                ui.selectable_value(
+                                    &mut self.category,
+                                    Category::Channel,
+                                    "Channel",
+                                );
+                            });
+                            if previous != self.category {
+                                self.update_templates();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            let names = self.pokemon_names();
+                            egui::ComboBox::from_id_source("game_cube_pokemon")
+                                .selected_text(
+                                    names
+                                        .get(self.pokemon)
+                                        .map(String::as_str)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, name) in names.iter().enumerate() {
+                                        ui.selectable_value(&mut self.pokemon, i, name);
+                                    }
+                                });
+                            ui.end_row();
+                            if self.category == Category::Shadow {
+                                ui.label("");
+                                ui.checkbox(&mut self.unset, "First Shadow Unset");
+                                ui.end_row();
+                            }
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "game_cube_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "game_cube_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = StateFilter3::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = GameCubeGenerator::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            if self.category == Category::Channel {
+                Method::Channel
+            } else {
+                Method::XDColo
+            },
+            self.unset,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        let states = if self.category == Category::Shadow {
+            self.shadows
+                .get(self.pokemon)
+                .map(|template| generator.generate_shadow(self.settings.seed, template))
+        } else {
+            self.templates
+                .get(self.pokemon)
+                .map(|template| generator.generate(self.settings.seed, template))
+        };
+        self.results = states
+            .unwrap_or_default()
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string(), state.base.level.to_string()];
+                row.extend(format_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,112 @@
+use crate::controls::{results_table, GeneratorSettings, IDFilterControls};
+use egui::{Context, Widget};
+use pokefinder_rs_core::gen3::generators::IDGenerator3;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    XDColo,
+    FRLGE,
+    RS,
+}
+
+pub struct IDs3 {
+    mode: Mode,
+    settings: GeneratorSettings,
+    tid: u16,
+    filter: IDFilterControls,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for IDs3 {
+    fn default() -> Self {
+        Self {
+            mode: Mode::XDColo,
+            settings: GeneratorSettings::default(),
+            tid: 0,
+            filter: IDFilterControls::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 4] = ["Advances", "TID", "SID", "TSV"];
+
+impl IDs3 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("ID Generator 3").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.mode, Mode::XDColo, "XD/Colo");
+                ui.selectable_value(&mut self.mode, Mode::FRLGE, "FRLG/E");
+                ui.selectable_value(&mut self.mode, Mode::RS, "RS");
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                egui::Grid::new("ids_3_settings")
+                    .num_columns(2)
+                    .show(ui, |ui| match self.mode {
+                        Mode::XDColo => {
+                            self.settings.show(ui, 8, false);
+                        }
+                        Mode::FRLGE => {
+                            ui.label("TID");
+                            egui::DragValue::new(&mut self.tid)
+                                .clamp_range(0..=65535)
+                                .ui(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, false);
+                        }
+                        Mode::RS => {
+                            self.settings.seed &= 0xFFFF;
+                            self.settings.show(ui, 4, false);
+                        }
+                    });
+                ui.separator();
+                egui::Grid::new("ids_3_filter")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        self.filter.show(ui, false);
+                    });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                results_table(ui, "ids_3_results", &HEADERS, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = self.filter.get_filter();
+        let generator = IDGenerator3::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            &filter,
+        );
+        let states = match self.mode {
+            Mode::XDColo => generator.generate_xd_colo(self.settings.seed),
+            Mode::FRLGE => generator.generate_frlge(self.tid),
+            Mode::RS => generator.generate_rs(self.settings.seed as u16),
+        };
+        self.results = states
+            .into_iter()
+            .map(|state| {
+                vec![
+                    state.advances.to_string(),
+                    state.tid.to_string(),
+                    state.sid.to_string(),
+                    state.tsv.to_string(),
+                ]
+            })
+            .collect();
+    }
+}
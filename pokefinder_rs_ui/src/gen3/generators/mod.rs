@@ -0,0 +1,71 @@
+mod egg3;
+mod game_cube;
+mod ids3;
+mod static3;
+mod wild3;
+
+pub use egg3::*;
+pub use game_cube::*;
+pub use ids3::*;
+pub use static3::*;
+pub use wild3::*;
+
+use egui::Context;
+
+#[derive(Default)]
+pub struct Gen3Generators {
+    pub egg: Option<Box<Egg3>>,
+    pub game_cube: Option<Box<GameCube>>,
+    pub ids: Option<Box<IDs3>>,
+    pub static_generator: Option<Box<Static3>>,
+    pub wild: Option<Box<Wild3>>,
+}
+
+impl Gen3Generators {
+    pub fn show(&mut self, ctx: &Context) {
+        let mut close = self
+            .egg
+            .as_mut()
+            .map(|egg| egg.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.egg = None;
+        }
+
+        close = self
+            .game_cube
+            .as_mut()
+            .map(|game_cube| game_cube.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.game_cube = None;
+        }
+
+        close = self
+            .ids
+            .as_mut()
+            .map(|ids| ids.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.ids = None;
+        }
+
+        close = self
+            .static_generator
+            .as_mut()
+            .map(|static_generator| static_generator.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.static_generator = None;
+        }
+
+        close = self
+            .wild
+            .as_mut()
+            .map(|wild| wild.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.wild = None;
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::controls::{
+    format_state, method_name, profile_select, results_table, Filter, GeneratorSettings,
+    STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Game, Method};
+use pokefinder_rs_core::gen3::filters::StateFilter3;
+use pokefinder_rs_core::gen3::generators::StaticGenerator3;
+use pokefinder_rs_core::gen3::{encounters3, Profile3};
+use pokefinder_rs_core::parents::{profile_loader_3, Profile, StaticTemplate, Template};
+use pokefinder_rs_core::util::translator;
+
+const CATEGORIES: [&str; 7] = [
+    "Starters",
+    "Fossils",
+    "Gifts",
+    "Game Corner",
+    "Stationary",
+    "Legends",
+    "Events",
+];
+
+pub struct Static3 {
+    profiles: Vec<Profile3>,
+    profile: usize,
+    settings: GeneratorSettings,
+    method: Method,
+    category: usize,
+    templates: Vec<&'static StaticTemplate>,
+    pokemon: usize,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Static3 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_3::get_profiles();
+        profiles.retain(|p| (p.get_version() & Game::GEN3) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile3::default());
+        }
+        let mut static3 = Self {
+            profiles,
+            profile: 0,
+            settings: GeneratorSettings::default(),
+            method: Method::Method1,
+            category: 0,
+            templates: vec![],
+            pokemon: 0,
+            filter: Filter::default(),
+            results: vec![],
+        };
+        static3.update_templates();
+        static3
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Level"];
+
+impl Static3 {
+    fn update_templates(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.templates = encounters3::get_static_encounters(self.category)
+            .iter()
+            .filter(|template| (template.get_version() & version) != Game::NONE)
+            .collect();
+        self.pokemon = 0;
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Static Generator 3").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "static_3_profile", &self.profiles, &mut self.profile) {
+                self.update_templates();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("static_3_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            self.settings.show(ui, 8, true);
+                            ui.label("Method");
+                            egui::ComboBox::from_id_source("static_3_method")
+                                .selected_text(method_name(self.method))
+                                .show_ui(ui, |ui| {
+                                    for method in
+                                        [Method::Method1, Method::Method2, Method::Method4]
+                                    {
+                                        ui.selectable_value(
+                                            &mut self.method,
+                                            method,
+                                            method_name(method),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Category");
+                            let previous = self.category;
+                            egui::ComboBox::from_id_source("static_3_category")
+                                .selected_text(CATEGORIES[self.category])
+                                .show_ui(ui, |ui| {
+                                    for (i, category) in CATEGORIES.iter().enumerate() {
+                                        ui.selectable_value(&mut self.category, i, *category);
+                                    }
+                                });
+                            if previous != self.category {
+                                self.update_templates();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            egui::ComboBox::from_id_source("static_3_pokemon")
+                                .selected_text(
+                                    self.templates
+                                        .get(self.pokemon)
+                                        .map(|template| {
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            )
+                                        })
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, template) in self.templates.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i,
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            ),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "static_3_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "static_3_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(template) = self.templates.get(self.pokemon) else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter3::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = StaticGenerator3::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.method,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.settings.seed, template)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string(), state.base.level.to_string()];
+                row.extend(format_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
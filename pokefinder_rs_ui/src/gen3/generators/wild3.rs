@@ -0,0 +1,277 @@
+use crate::controls::{
+    encounter_name, format_state, method_name, profile_select, results_table, Filter,
+    GeneratorSettings, LeadSelect, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Encounter, Game, Lead, Method};
+use pokefinder_rs_core::gen3::filters::WildStateFilter3;
+use pokefinder_rs_core::gen3::generators::WildGenerator3;
+use pokefinder_rs_core::gen3::{encounters3, EncounterArea3, Profile3};
+use pokefinder_rs_core::parents::{profile_loader_3, EncounterAreaT, Profile};
+use pokefinder_rs_core::util::translator;
+
+const ENCOUNTERS: [Encounter; 6] = [
+    Encounter::Grass,
+    Encounter::RockSmash,
+    Encounter::Surfing,
+    Encounter::OldRod,
+    Encounter::GoodRod,
+    Encounter::SuperRod,
+];
+
+const LEADS: [Lead; 6] = [
+    Lead::SYNCHRONIZE,
+    Lead::CUTE_CHARM_F,
+    Lead::CUTE_CHARM_M,
+    Lead::MAGNET_PULL,
+    Lead::STATIC,
+    Lead::PRESSURE,
+];
+
+pub struct Wild3 {
+    profiles: Vec<Profile3>,
+    profile: usize,
+    settings: GeneratorSettings,
+    method: Method,
+    encounter: Encounter,
+    areas: Vec<EncounterArea3>,
+    locations: Vec<String>,
+    location: usize,
+    species: Vec<u16>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Wild3 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_3::get_profiles();
+        profiles.retain(|p| (p.get_version() & Game::GEN3) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile3::default());
+        }
+        let mut wild = Self {
+            profiles,
+            profile: 0,
+            settings: GeneratorSettings::default(),
+            method: Method::Method1,
+            encounter: Encounter::Grass,
+            areas: vec![],
+            locations: vec![],
+            location: 0,
+            species: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        wild.update_locations();
+        wild
+    }
+}
+
+static HEADERS: [&str; 4] = ["Advances", "Slot", "Species", "Level"];
+
+impl Wild3 {
+    fn update_locations(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.areas = encounters3::get_encounters(self.encounter, version);
+        let locations = self
+            .areas
+            .iter()
+            .map(|area| area.get_location() as u16)
+            .collect::<Vec<_>>();
+        self.locations = translator::get_locations(&locations, version);
+        self.location = 0;
+        self.update_pokemon();
+    }
+
+    fn update_pokemon(&mut self) {
+        self.species = self
+            .areas
+            .get(self.location)
+            .map(|area| area.get_unique_species())
+            .unwrap_or_default();
+        self.pokemon = 0;
+        self.filter.encounter_slots = [true; 12];
+    }
+
+    fn update_slots(&mut self) {
+        self.filter.encounter_slots = [true; 12];
+        if self.pokemon != 0 {
+            let slots =
+                self.areas[self.location].get_slots_by_specie(self.species[self.pokemon - 1]);
+            for (filter, slot) in self.filter.encounter_slots.iter_mut().zip(slots) {
+                *filter = slot;
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Wild Generator 3").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "wild_3_profile", &self.profiles, &mut self.profile) {
+                self.update_locations();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("wild_3_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            self.settings.show(ui, 8, true);
+                            ui.label("Method");
+                            egui::ComboBox::from_id_source("wild_3_method")
+                                .selected_text(method_name(self.method))
+                                .show_ui(ui, |ui| {
+                                    for method in
+                                        [Method::Method1, Method::Method2, Method::Method4]
+                                    {
+                                        ui.selectable_value(
+                                            &mut self.method,
+                                            method,
+                                            method_name(method),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Encounter");
+                            let previous = self.encounter;
+                            egui::ComboBox::from_id_source("wild_3_encounter")
+                                .selected_text(encounter_name(self.encounter))
+                                .show_ui(ui, |ui| {
+                                    for encounter in ENCOUNTERS {
+                                        ui.selectable_value(
+                                            &mut self.encounter,
+                                            encounter,
+                                            encounter_name(encounter),
+                                        );
+                                    }
+                                });
+                            if previous != self.encounter {
+                                self.update_locations();
+                            }
+                            ui.end_row();
+                            ui.label("Location");
+                            let previous = self.location;
+                            egui::ComboBox::from_id_source("wild_3_location")
+                                .selected_text(
+                                    self.locations
+                                        .get(self.location)
+                                        .map(String::as_str)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, location) in self.locations.iter().enumerate() {
+                                        ui.selectable_value(&mut self.location, i, location);
+                                    }
+                                });
+                            if previous != self.location {
+                                self.update_pokemon();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            let previous = self.pokemon;
+                            egui::ComboBox::from_id_source("wild_3_pokemon")
+                                .selected_text(if self.pokemon == 0 {
+                                    "Any".to_string()
+                                } else {
+                                    let species = self.species[self.pokemon - 1];
+                                    translator::get_species_with_form(
+                                        species & 0x7ff,
+                                        (species >> 11) as u8,
+                                    )
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut self.pokemon, 0, "Any");
+                                    for (i, &species) in self.species.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i + 1,
+                                            translator::get_species_with_form(
+                                                species & 0x7ff,
+                                                (species >> 11) as u8,
+                                            ),
+                                        );
+                                    }
+                                });
+                            if previous != self.pokemon {
+                                self.update_slots();
+                            }
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "wild_3_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "wild_3_filter", 12);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "wild_3_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            self.results.clear();
+            return;
+        };
+        let filter = WildStateFilter3::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+            self.filter.encounter_slots,
+        );
+        let profile = &self.profiles[self.profile];
+        let generator = WildGenerator3::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.method,
+            self.encounter,
+            self.lead.get_lead(),
+            profile,
+            &filter,
+        );
+        self.results = generator
+            .generate(self.settings.seed, area)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.advances.to_string(),
+                    state.base.encounter_slot.to_string(),
+                    translator::get_species_with_form(state.base.species, state.base.form),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,284 @@
+use crate::controls::{
+    format_egg_state, profile_select, results_table, BackgroundSearch, DaycareControls, Filter,
+    GeneratorSettings, SearcherSettings, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen4::filters::StateFilter4;
+use pokefinder_rs_core::gen4::generators::EggGenerator4;
+use pokefinder_rs_core::gen4::searchers::EggSearcher4;
+use pokefinder_rs_core::gen4::states::EggGeneratorState4;
+use pokefinder_rs_core::gen4::Profile4;
+use pokefinder_rs_core::parents::{profile_loader_4, Profile};
+
+#[derive(Copy, Clone, PartialEq)]
+enum Tab {
+    Generator,
+    Searcher,
+}
+
+pub struct Egg4 {
+    profiles: Vec<Profile4>,
+    profile: usize,
+    tab: Tab,
+    held: GeneratorSettings,
+    pickup: GeneratorSettings,
+    searcher_settings: SearcherSettings,
+    daycare: DaycareControls,
+    filter: Filter,
+    generator_results: Vec<Vec<String>>,
+    searcher_results: Vec<Vec<String>>,
+    searcher_seeds: Vec<u32>,
+    selected: usize,
+    search: Option<BackgroundSearch<EggSearcher4>>,
+    seed_to_time: Option<u32>,
+}
+
+impl Default for Egg4 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_4::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile4::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            tab: Tab::Generator,
+            held: GeneratorSettings::default(),
+            pickup: GeneratorSettings::default(),
+            searcher_settings: SearcherSettings::default(),
+            daycare: DaycareControls::default(),
+            filter: Filter::default(),
+            generator_results: vec![],
+            searcher_results: vec![],
+            searcher_seeds: vec![],
+            selected: 0,
+            search: None,
+            seed_to_time: None,
+        }
+    }
+}
+
+static GENERATOR_HEADERS: [&str; 3] = ["Held Advances", "Pickup Advances", "Call"];
+static SEARCHER_HEADERS: [&str; 3] = ["Seed", "Held Advances", "Pickup Advances"];
+
+fn format_egg(state: &EggGeneratorState4) -> Vec<String> {
+    let mut row = vec![
+        state.base.advances.to_string(),
+        state.pickup_advances.to_string(),
+    ];
+    row.extend(format_egg_state(&state.base.base));
+    row
+}
+
+impl Egg4 {
+    fn version(&self) -> Game {
+        self.profiles[self.profile].get_version()
+    }
+
+    fn get_filter(&self) -> StateFilter4 {
+        StateFilter4::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        )
+    }
+
+    /// Returns the seed the user asked to send to the Seed to Time tool
+    pub fn take_seed_to_time(&mut self) -> Option<(u32, Game)> {
+        self.seed_to_time.take().map(|seed| (seed, self.version()))
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        if let Some(search) = &self.search {
+            ctx.request_repaint();
+            let finished = search.finished();
+            for state in search.results() {
+                self.searcher_seeds.push(state.seed);
+                let mut row = vec![format!("{:0>8X}", state.seed)];
+                row.extend(format_egg(&state.state));
+                self.searcher_results.push(row);
+            }
+            if finished {
+                self.search = None;
+            }
+        }
+
+        egui::Window::new("Egg 4").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "egg_4_profile", &self.profiles, &mut self.profile);
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.tab, Tab::Generator, "Generator");
+                ui.selectable_value(&mut self.tab, Tab::Searcher, "Searcher");
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    // The searcher tries every seed, so only the advance ranges are needed there
+                    let seed_width = if self.tab == Tab::Generator { 8 } else { 0 };
+                    ui.label("Held");
+                    egui::Grid::new("egg_4_held").num_columns(2).show(ui, |ui| {
+                        self.held.show(ui, seed_width, true);
+                    });
+                    ui.separator();
+                    ui.label("Pickup");
+                    egui::Grid::new("egg_4_pickup")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            self.pickup.show(ui, seed_width, true);
+                        });
+                    if self.tab == Tab::Searcher {
+                        ui.separator();
+                        egui::Grid::new("egg_4_searcher")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                self.searcher_settings.show(ui, false);
+                            });
+                    }
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.daycare.show(
+                        ui,
+                        "egg_4_daycare",
+                        &[
+                            "None",
+                            "Everstone",
+                            "Power Weight",
+                            "Power Bracer",
+                            "Power Belt",
+                            "Power Lens",
+                            "Power Band",
+                            "Power Anklet",
+                        ],
+                        493,
+                    );
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "egg_4_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if self.tab == Tab::Generator {
+                    if ui.button("Generate").clicked() {
+                        self.generate();
+                    }
+                    let headers = GENERATOR_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "egg_4_generator_results",
+                        &headers,
+                        &self.generator_results,
+                        None,
+                    );
+                } else {
+                    if let Some(search) = &self.search {
+                        if search.show(ui) {
+                            search.cancel();
+                        }
+                    } else {
+                        ui.horizontal(|ui| {
+                            if ui.button("Search").clicked() {
+                                self.start_search();
+                            }
+                            if ui.button("Send to Seed to Time").clicked() {
+                                self.seed_to_time = self.searcher_seeds.get(self.selected).copied();
+                            }
+                        });
+                    }
+                    let headers = SEARCHER_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "egg_4_searcher_results",
+                        &headers,
+                        &self.searcher_results,
+                        Some(&mut self.selected),
+                    );
+                }
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = self.get_filter();
+        let daycare = self.daycare.get_daycare();
+        let generator = EggGenerator4::new(
+            self.held.initial_advances,
+            self.held.max_advances,
+            self.held.delay,
+            self.pickup.initial_advances,
+            self.pickup.max_advances,
+            self.pickup.delay,
+            &daycare,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.generator_results = generator
+            .generate(self.held.seed, self.pickup.seed)
+            .iter()
+            .map(|state| {
+                let mut row = format_egg(state);
+                row.insert(2, ["E", "K", "P"][state.call as usize % 3].to_string());
+                row
+            })
+            .collect();
+    }
+
+    fn start_search(&mut self) {
+        self.searcher_results.clear();
+        self.searcher_seeds.clear();
+        self.selected = 0;
+
+        let min_delay = self.searcher_settings.min_delay;
+        let max_delay = self.searcher_settings.max_delay;
+        let searcher = EggSearcher4::new(min_delay, max_delay);
+
+        // The generator borrows its inputs, so it is built on the search thread from owned copies
+        let filter = self.get_filter();
+        let daycare = self.daycare.get_daycare();
+        let profile = self.profiles[self.profile].clone();
+        let held = (
+            self.held.initial_advances,
+            self.held.max_advances,
+            self.held.delay,
+        );
+        let pickup = (
+            self.pickup.initial_advances,
+            self.pickup.max_advances,
+            self.pickup.delay,
+        );
+        self.search = Some(BackgroundSearch::start(
+            searcher,
+            256 * 24 * (max_delay - min_delay + 1),
+            move |searcher| {
+                let generator = EggGenerator4::new(
+                    held.0, held.1, held.2, pickup.0, pickup.1, pickup.2, &daycare, &profile,
+                    &filter,
+                );
+                searcher.start_search(&generator);
+            },
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::controls::{results_table, BackgroundSearch, IDFilterControls};
+use egui::{Context, Widget};
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen4::generators::IDGenerator4;
+use pokefinder_rs_core::gen4::searchers::IDSearcher4;
+use pokefinder_rs_core::gen4::states::IDState4;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Tab {
+    Generator,
+    Searcher,
+}
+
+pub struct IDs4 {
+    tab: Tab,
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    min_delay: u32,
+    max_delay: u32,
+    infinite: bool,
+    filter: IDFilterControls,
+    generator_results: Vec<Vec<String>>,
+    searcher_results: Vec<Vec<String>>,
+    searcher_seeds: Vec<u32>,
+    selected: usize,
+    search: Option<BackgroundSearch<IDSearcher4>>,
+    seed_to_time: Option<u32>,
+}
+
+impl Default for IDs4 {
+    fn default() -> Self {
+        Self {
+            tab: Tab::Generator,
+            year: 2000,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            min_delay: 5000,
+            max_delay: 6000,
+            infinite: false,
+            filter: IDFilterControls::default(),
+            generator_results: vec![],
+            searcher_results: vec![],
+            searcher_seeds: vec![],
+            selected: 0,
+            search: None,
+            seed_to_time: None,
+        }
+    }
+}
+
+static HEADERS: [&str; 6] = ["Seed", "TID", "SID", "TSV", "Delay", "Seconds"];
+
+fn format_id(state: &IDState4) -> Vec<String> {
+    vec![
+        format!("{:0>8X}", state.seed),
+        state.base.tid.to_string(),
+        state.base.sid.to_string(),
+        state.base.tsv.to_string(),
+        state.delay.to_string(),
+        state.seconds.to_string(),
+    ]
+}
+
+impl IDs4 {
+    /// Returns the seed the user asked to send to the Seed to Time tool.
+    ///
+    /// ID seeds are not tied to a profile, so the tool opens in DPPt mode.
+    pub fn take_seed_to_time(&mut self) -> Option<(u32, Game)> {
+        self.seed_to_time.take().map(|seed| (seed, Game::DPPT))
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        if let Some(search) = &self.search {
+            ctx.request_repaint();
+            let finished = search.finished();
+            for state in search.results() {
+                self.searcher_seeds.push(state.seed);
+                self.searcher_results.push(format_id(&state));
+            }
+            if finished {
+                self.search = None;
+            }
+        }
+
+        egui::Window::new("IDs 4").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.tab, Tab::Generator, "Generator");
+                ui.selectable_value(&mut self.tab, Tab::Searcher, "Searcher");
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                egui::Grid::new("ids_4_settings")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Year");
+                        egui::DragValue::new(&mut self.year)
+                            .clamp_range(2000..=2099)
+                            .ui(ui);
+                        ui.end_row();
+                        if self.tab == Tab::Generator {
+                            ui.label("Month");
+                            egui::DragValue::new(&mut self.month)
+                                .clamp_range(1..=12)
+                                .ui(ui);
+                            ui.end_row();
+                            ui.label("Day");
+                            egui::DragValue::new(&mut self.day)
+                                .clamp_range(1..=31)
+                                .ui(ui);
+                            ui.end_row();
+                            ui.label("Hour");
+                            egui::DragValue::new(&mut self.hour)
+                                .clamp_range(0..=23)
+                                .ui(ui);
+                            ui.end_row();
+                            ui.label("Minute");
+                            egui::DragValue::new(&mut self.minute)
+                                .clamp_range(0..=59)
+                                .ui(ui);
+                            ui.end_row();
+                        } else {
+                            ui.label("");
+                            ui.checkbox(&mut self.infinite, "Infinite Search");
+                            ui.end_row();
+                        }
+                        ui.label("Min Delay");
+                        egui::DragValue::new(&mut self.min_delay)
+                            .clamp_range(0..=self.max_delay)
+                            .ui(ui);
+                        ui.end_row();
+                        ui.label("Max Delay");
+                        egui::DragValue::new(&mut self.max_delay)
+                            .clamp_range(self.min_delay..=u32::MAX)
+                            .ui(ui);
+                        ui.end_row();
+                    });
+                ui.separator();
+                egui::Grid::new("ids_4_filter")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        self.filter.show(ui, false);
+                    });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if self.tab == Tab::Generator {
+                    if ui.button("Generate").clicked() {
+                        self.generate();
+                    }
+                    results_table(
+                        ui,
+                        "ids_4_generator_results",
+                        &HEADERS,
+                        &self.generator_results,
+                        None,
+                    );
+                } else {
+                    if let Some(search) = &self.search {
+                        if search.show(ui) {
+                            search.cancel();
+                        }
+                    } else {
+                        ui.horizontal(|ui| {
+                            if ui.button("Search").clicked() {
+                                self.start_search();
+                            }
+                            if ui.button("Send to Seed to Time").clicked() {
+                                self.seed_to_time = self.searcher_seeds.get(self.selected).copied();
+                            }
+                        });
+                    }
+                    results_table(
+                        ui,
+                        "ids_4_searcher_results",
+                        &HEADERS,
+                        &self.searcher_results,
+                        Some(&mut self.selected),
+                    );
+                }
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = self.filter.get_filter();
+        let generator = IDGenerator4::new(
+            self.min_delay,
+            self.max_delay,
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            &filter,
+        );
+        self.generator_results = generator.generate().iter().map(format_id).collect();
+    }
+
+    fn start_search(&mut self) {
+        self.searcher_results.clear();
+        self.searcher_seeds.clear();
+        self.selected = 0;
+
+        let filter = self.filter.get_filter();
+        let searcher = IDSearcher4::new(&filter);
+        let infinite = self.infinite;
+        let year = self.year;
+        let min_delay = self.min_delay;
+        let max_delay = if infinite { 0xe8ffff } else { self.max_delay };
+        self.search = Some(BackgroundSearch::start(
+            searcher,
+            (max_delay - min_delay + 1).saturating_mul(256 * 24),
+            move |searcher| searcher.start_search(infinite, year, min_delay, max_delay),
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+mod egg4;
+mod ids4;
+mod static4;
+mod wild4;
+
+pub use egg4::*;
+pub use ids4::*;
+pub use static4::*;
+pub use wild4::*;
+
+use egui::Context;
+use pokefinder_rs_core::enums::Game;
+
+#[derive(Default)]
+pub struct Gen4Generators {
+    pub egg: Option<Box<Egg4>>,
+    pub ids: Option<Box<IDs4>>,
+    pub static_generator: Option<Box<Static4>>,
+    pub wild: Option<Box<Wild4>>,
+}
+
+impl Gen4Generators {
+    /// Draws the open windows.
+    ///
+    /// Returns the seed and game of a searcher result that should be opened in the Seed to Time tool.
+    pub fn show(&mut self, ctx: &Context) -> Option<(u32, Game)> {
+        let mut seed_to_time = None;
+
+        let mut close = self
+            .egg
+            .as_mut()
+            .map(|egg| egg.show(ctx))
+            .unwrap_or_default();
+        if let Some(egg) = self.egg.as_mut() {
+            seed_to_time = seed_to_time.or(egg.take_seed_to_time());
+        }
+        if close {
+            self.egg = None;
+        }
+
+        close = self
+            .ids
+            .as_mut()
+            .map(|ids| ids.show(ctx))
+            .unwrap_or_default();
+        if let Some(ids) = self.ids.as_mut() {
+            seed_to_time = seed_to_time.or(ids.take_seed_to_time());
+        }
+        if close {
+            self.ids = None;
+        }
+
+        close = self
+            .static_generator
+            .as_mut()
+            .map(|static_generator| static_generator.show(ctx))
+            .unwrap_or_default();
+        if let Some(static_generator) = self.static_generator.as_mut() {
+            seed_to_time = seed_to_time.or(static_generator.take_seed_to_time());
+        }
+        if close {
+            self.static_generator = None;
+        }
+
+        close = self
+            .wild
+            .as_mut()
+            .map(|wild| wild.show(ctx))
+            .unwrap_or_default();
+        if let Some(wild) = self.wild.as_mut() {
+            seed_to_time = seed_to_time.or(wild.take_seed_to_time());
+        }
+        if close {
+            self.wild = None;
+        }
+
+        seed_to_time
+    }
+}
@@ -0,0 +1,328 @@
+use crate::controls::{
+    format_state, method_name, profile_select, results_table, BackgroundSearch, Filter,
+    GeneratorSettings, LeadSelect, SearcherSettings, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Game, Lead, Method};
+use pokefinder_rs_core::gen4::filters::StateFilter4;
+use pokefinder_rs_core::gen4::generators::StaticGenerator4;
+use pokefinder_rs_core::gen4::searchers::StaticSearcher4;
+use pokefinder_rs_core::gen4::{encounters4, Profile4, StaticTemplate4};
+use pokefinder_rs_core::parents::searchers::iv_combinations;
+use pokefinder_rs_core::parents::{profile_loader_4, Profile, Template};
+use pokefinder_rs_core::util::translator;
+
+const CATEGORIES: [&str; 7] = [
+    "Starters",
+    "Fossils",
+    "Gifts",
+    "Game Corner",
+    "Stationary",
+    "Legends",
+    "Events",
+];
+const LEADS: [Lead; 3] = [Lead::SYNCHRONIZE, Lead::CUTE_CHARM_F, Lead::CUTE_CHARM_M];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Tab {
+    Generator,
+    Searcher,
+}
+
+pub struct Static4 {
+    profiles: Vec<Profile4>,
+    profile: usize,
+    tab: Tab,
+    generator_settings: GeneratorSettings,
+    searcher_settings: SearcherSettings,
+    category: usize,
+    templates: Vec<&'static StaticTemplate4>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    generator_results: Vec<Vec<String>>,
+    searcher_results: Vec<Vec<String>>,
+    searcher_seeds: Vec<u32>,
+    selected: usize,
+    search: Option<BackgroundSearch<StaticSearcher4>>,
+    seed_to_time: Option<u32>,
+}
+
+impl Default for Static4 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_4::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile4::default());
+        }
+        let mut static4 = Self {
+            profiles,
+            profile: 0,
+            tab: Tab::Generator,
+            generator_settings: GeneratorSettings::default(),
+            searcher_settings: SearcherSettings::default(),
+            category: 0,
+            templates: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            generator_results: vec![],
+            searcher_results: vec![],
+            searcher_seeds: vec![],
+            selected: 0,
+            search: None,
+            seed_to_time: None,
+        };
+        static4.update_templates();
+        static4
+    }
+}
+
+static GENERATOR_HEADERS: [&str; 3] = ["Advances", "Call", "Level"];
+static SEARCHER_HEADERS: [&str; 3] = ["Seed", "Advances", "Level"];
+
+impl Static4 {
+    fn version(&self) -> Game {
+        self.profiles[self.profile].get_version()
+    }
+
+    fn update_templates(&mut self) {
+        let version = self.version();
+        self.templates = encounters4::get_static_encounters(self.category)
+            .iter()
+            .filter(|template| (template.get_version() & version) != Game::NONE)
+            .collect();
+        self.pokemon = 0;
+    }
+
+    /// Method of the selected template, which is Method 1 for gifts and Method J/K otherwise
+    fn method(&self) -> Method {
+        self.templates
+            .get(self.pokemon)
+            .map(|template| template.method)
+            .unwrap_or(Method::None)
+    }
+
+    fn get_filter(&self) -> StateFilter4 {
+        StateFilter4::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        )
+    }
+
+    /// Returns the seed the user asked to send to the Seed to Time tool
+    pub fn take_seed_to_time(&mut self) -> Option<(u32, Game)> {
+        self.seed_to_time.take().map(|seed| (seed, self.version()))
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        if let Some(search) = &self.search {
+            ctx.request_repaint();
+            let finished = search.finished();
+            for state in search.results() {
+                self.searcher_seeds.push(state.base.seed);
+                let mut row = vec![
+                    format!("{:0>8X}", state.base.seed),
+                    state.advances.to_string(),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                self.searcher_results.push(row);
+            }
+            if finished {
+                self.search = None;
+            }
+        }
+
+        egui::Window::new("Static 4").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "static_4_profile", &self.profiles, &mut self.profile) {
+                self.update_templates();
+            }
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.tab, Tab::Generator, "Generator");
+                ui.selectable_value(&mut self.tab, Tab::Searcher, "Searcher");
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("static_4_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            if self.tab == Tab::Generator {
+                                self.generator_settings.show(ui, 8, true);
+                            } else {
+                                self.searcher_settings.show(ui, true);
+                            }
+                            ui.label("Category");
+                            let previous = self.category;
+                            egui::ComboBox::from_id_source("static_4_category")
+                                .selected_text(CATEGORIES[self.category])
+                                .show_ui(ui, |ui| {
+                                    for (i, category) in CATEGORIES.iter().enumerate() {
+                                        ui.selectable_value(&mut self.category, i, *category);
+                                    }
+                                });
+                            if previous != self.category {
+                                self.update_templates();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            egui::ComboBox::from_id_source("static_4_pokemon")
+                                .selected_text(
+                                    self.templates
+                                        .get(self.pokemon)
+                                        .map(|template| {
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            )
+                                        })
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, template) in self.templates.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i,
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            ),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Method");
+                            ui.label(method_name(self.method()));
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "static_4_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "static_4_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if self.tab == Tab::Generator {
+                    if ui.button("Generate").clicked() {
+                        self.generate();
+                    }
+                    let headers = GENERATOR_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "static_4_generator_results",
+                        &headers,
+                        &self.generator_results,
+                        None,
+                    );
+                } else {
+                    if let Some(search) = &self.search {
+                        if search.show(ui) {
+                            search.cancel();
+                        }
+                    } else {
+                        ui.horizontal(|ui| {
+                            if ui.button("Search").clicked() {
+                                self.start_search();
+                            }
+                            if ui.button("Send to Seed to Time").clicked() {
+                                self.seed_to_time = self.searcher_seeds.get(self.selected).copied();
+                            }
+                        });
+                    }
+                    let headers = SEARCHER_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "static_4_searcher_results",
+                        &headers,
+                        &self.searcher_results,
+                        Some(&mut self.selected),
+                    );
+                }
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(template) = self.templates.get(self.pokemon) else {
+            self.generator_results.clear();
+            return;
+        };
+        let filter = self.get_filter();
+        let generator = StaticGenerator4::new(
+            self.generator_settings.initial_advances,
+            self.generator_settings.max_advances,
+            self.generator_settings.delay,
+            template.method,
+            self.lead.get_lead(),
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.generator_results = generator
+            .generate(self.generator_settings.seed, template)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.base.advances.to_string(),
+                    ["E", "K", "P"][state.call as usize % 3].to_string(),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+
+    fn start_search(&mut self) {
+        let Some(&template) = self.templates.get(self.pokemon) else {
+            return;
+        };
+        self.searcher_results.clear();
+        self.searcher_seeds.clear();
+        self.selected = 0;
+
+        let filter = self.get_filter();
+        let searcher = StaticSearcher4::new(
+            self.searcher_settings.min_advance,
+            self.searcher_settings.max_advance,
+            self.searcher_settings.min_delay,
+            self.searcher_settings.max_delay,
+            template.method,
+            self.lead.get_lead(),
+            &self.profiles[self.profile],
+            &filter,
+        );
+        let min = self.filter.min;
+        let max = self.filter.max;
+        self.search = Some(BackgroundSearch::start(
+            searcher,
+            iv_combinations(min, max),
+            move |searcher| searcher.start_search(min, max, template),
+        ));
+    }
+}
@@ -0,0 +1,552 @@
+use crate::controls::{
+    encounter_name, format_state, method_name, profile_select, results_table, BackgroundSearch,
+    Filter, GeneratorSettings, LeadSelect, SearcherSettings, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Encounter, Game, Lead, Method};
+use pokefinder_rs_core::gen4::filters::WildStateFilter4;
+use pokefinder_rs_core::gen4::generators::WildGenerator4;
+use pokefinder_rs_core::gen4::searchers::WildSearcher4;
+use pokefinder_rs_core::gen4::{encounters4, EncounterArea4, Profile4};
+use pokefinder_rs_core::parents::searchers::iv_combinations;
+use pokefinder_rs_core::parents::states::WildState;
+use pokefinder_rs_core::parents::{profile_loader_4, EncounterAreaT, Profile};
+use pokefinder_rs_core::util::translator;
+
+const DPPT_ENCOUNTERS: [Encounter; 5] = [
+    Encounter::Grass,
+    Encounter::Surfing,
+    Encounter::OldRod,
+    Encounter::GoodRod,
+    Encounter::SuperRod,
+];
+const HGSS_ENCOUNTERS: [Encounter; 10] = [
+    Encounter::Grass,
+    Encounter::Surfing,
+    Encounter::OldRod,
+    Encounter::GoodRod,
+    Encounter::SuperRod,
+    Encounter::RockSmash,
+    Encounter::Headbutt,
+    Encounter::HeadbuttAlt,
+    Encounter::HeadbuttSpecial,
+    Encounter::BugCatchingContest,
+];
+const LEADS: [Lead; 8] = [
+    Lead::SYNCHRONIZE,
+    Lead::CUTE_CHARM_F,
+    Lead::CUTE_CHARM_M,
+    Lead::MAGNET_PULL,
+    Lead::STATIC,
+    Lead::PRESSURE,
+    Lead::SUCTION_CUPS,
+    Lead::ARENA_TRAP,
+];
+const TIMES: [&str; 3] = ["Morning", "Day", "Night"];
+const DUAL_SLOTS: [Game; 6] = [
+    Game::NONE,
+    Game::RUBY,
+    Game::SAPPHIRE,
+    Game::EMERALD,
+    Game::FIRE_RED,
+    Game::LEAF_GREEN,
+];
+const RADIO: [&str; 3] = ["None", "Hoenn Sound", "Sinnoh Sound"];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Tab {
+    Generator,
+    Searcher,
+}
+
+pub struct Wild4 {
+    profiles: Vec<Profile4>,
+    profile: usize,
+    tab: Tab,
+    generator_settings: GeneratorSettings,
+    searcher_settings: SearcherSettings,
+    method: Method,
+    encounter: Encounter,
+    time: usize,
+    dual: Game,
+    radar: bool,
+    radar_shiny: bool,
+    radio: usize,
+    swarm: bool,
+    areas: Vec<EncounterArea4>,
+    locations: Vec<String>,
+    location: usize,
+    species: Vec<u16>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    generator_results: Vec<Vec<String>>,
+    searcher_results: Vec<Vec<String>>,
+    searcher_seeds: Vec<u32>,
+    selected: usize,
+    search: Option<BackgroundSearch<WildSearcher4>>,
+    seed_to_time: Option<u32>,
+}
+
+impl Default for Wild4 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_4::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile4::default());
+        }
+        let mut wild = Self {
+            profiles,
+            profile: 0,
+            tab: Tab::Generator,
+            generator_settings: GeneratorSettings::default(),
+            searcher_settings: SearcherSettings::default(),
+            method: Method::None,
+            encounter: Encounter::Grass,
+            time: 0,
+            dual: Game::NONE,
+            radar: false,
+            radar_shiny: false,
+            radio: 0,
+            swarm: false,
+            areas: vec![],
+            locations: vec![],
+            location: 0,
+            species: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            generator_results: vec![],
+            searcher_results: vec![],
+            searcher_seeds: vec![],
+            selected: 0,
+            search: None,
+            seed_to_time: None,
+        };
+        wild.update_profile();
+        wild
+    }
+}
+
+static GENERATOR_HEADERS: [&str; 6] = ["Advances", "Battle", "Slot", "Species", "Level", "Call"];
+static SEARCHER_HEADERS: [&str; 5] = ["Seed", "Advances", "Slot", "Species", "Level"];
+
+fn format_wild(state: &WildState) -> Vec<String> {
+    let mut row = vec![
+        state.encounter_slot.to_string(),
+        translator::get_species_with_form(state.species, state.form),
+        state.base.level.to_string(),
+    ];
+    row.extend(format_state(&state.base));
+    row
+}
+
+impl Wild4 {
+    fn version(&self) -> Game {
+        self.profiles[self.profile].get_version()
+    }
+
+    fn dppt(&self) -> bool {
+        (self.version() & Game::DPPT) != Game::NONE
+    }
+
+    fn encounters(&self) -> &'static [Encounter] {
+        if self.dppt() {
+            &DPPT_ENCOUNTERS
+        } else {
+            &HGSS_ENCOUNTERS
+        }
+    }
+
+    fn methods(&self) -> &'static [Method] {
+        if !self.dppt() {
+            &[Method::MethodK]
+        } else if self.encounter == Encounter::Grass {
+            &[Method::MethodJ, Method::PokeRadar]
+        } else {
+            &[Method::MethodJ]
+        }
+    }
+
+    fn update_profile(&mut self) {
+        if !self.encounters().contains(&self.encounter) {
+            self.encounter = Encounter::Grass;
+        }
+        self.update_locations();
+    }
+
+    fn update_locations(&mut self) {
+        if !self.methods().contains(&self.method) {
+            self.method = self.methods()[0];
+        }
+        let profile = &self.profiles[self.profile];
+        self.areas = encounters4::get_encounters(
+            self.encounter,
+            self.time,
+            self.dual,
+            self.radar || self.method == Method::PokeRadar,
+            self.radio,
+            self.swarm,
+            [0, 0],
+            [0; 5],
+            profile,
+        );
+        let locations = self
+            .areas
+            .iter()
+            .map(|area| area.get_location() as u16)
+            .collect::<Vec<_>>();
+        self.locations = translator::get_locations(&locations, profile.get_version());
+        if self.location >= self.areas.len() {
+            self.location = 0;
+        }
+        self.update_pokemon();
+    }
+
+    fn update_pokemon(&mut self) {
+        self.species = self
+            .areas
+            .get(self.location)
+            .map(|area| area.get_unique_species())
+            .unwrap_or_default();
+        self.pokemon = 0;
+        self.filter.encounter_slots = [true; 12];
+    }
+
+    fn update_slots(&mut self) {
+        self.filter.encounter_slots = [true; 12];
+        if self.pokemon != 0 {
+            let slots =
+                self.areas[self.location].get_slots_by_specie(self.species[self.pokemon - 1]);
+            for (filter, slot) in self.filter.encounter_slots.iter_mut().zip(slots) {
+                *filter = slot;
+            }
+        }
+    }
+
+    /// Slot the Poke Radar is chained on, which is the first slot of the selected pokemon
+    fn radar_index(&self) -> u8 {
+        if self.pokemon == 0 {
+            0
+        } else {
+            self.filter
+                .encounter_slots
+                .iter()
+                .position(|&slot| slot)
+                .unwrap_or_default() as u8
+        }
+    }
+
+    fn get_filter(&self) -> WildStateFilter4 {
+        WildStateFilter4::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+            self.filter.encounter_slots,
+        )
+    }
+
+    /// Returns the seed the user asked to send to the Seed to Time tool
+    pub fn take_seed_to_time(&mut self) -> Option<(u32, Game)> {
+        self.seed_to_time.take().map(|seed| (seed, self.version()))
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        if let Some(search) = &self.search {
+            ctx.request_repaint();
+            let finished = search.finished();
+            for state in search.results() {
+                self.searcher_seeds.push(state.base.seed);
+                let mut row = vec![
+                    format!("{:0>8X}", state.base.seed),
+                    state.advances.to_string(),
+                ];
+                row.extend(format_wild(&state.base.base));
+                self.searcher_results.push(row);
+            }
+            if finished {
+                self.search = None;
+            }
+        }
+
+        egui::Window::new("Wild 4").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "wild_4_profile", &self.profiles, &mut self.profile) {
+                self.update_profile();
+            }
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.tab, Tab::Generator, "Generator");
+                ui.selectable_value(&mut self.tab, Tab::Searcher, "Searcher");
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("wild_4_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            if self.tab == Tab::Generator {
+                                self.generator_settings.show(ui, 8, true);
+                            } else {
+                                self.searcher_settings.show(ui, true);
+                            }
+                            self.show_encounter_settings(ui);
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "wild_4_filter", 12);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if self.tab == Tab::Generator {
+                    if ui.button("Generate").clicked() {
+                        self.generate();
+                    }
+                    let headers = GENERATOR_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "wild_4_generator_results",
+                        &headers,
+                        &self.generator_results,
+                        None,
+                    );
+                } else {
+                    if let Some(search) = &self.search {
+                        if search.show(ui) {
+                            search.cancel();
+                        }
+                    } else {
+                        ui.horizontal(|ui| {
+                            if ui.button("Search").clicked() {
+                                self.start_search();
+                            }
+                            if ui.button("Send to Seed to Time").clicked() {
+                                self.seed_to_time = self.searcher_seeds.get(self.selected).copied();
+                            }
+                        });
+                    }
+                    let headers = SEARCHER_HEADERS
+                        .iter()
+                        .chain(STATE_HEADERS.iter())
+                        .copied()
+                        .collect::<Vec<_>>();
+                    results_table(
+                        ui,
+                        "wild_4_searcher_results",
+                        &headers,
+                        &self.searcher_results,
+                        Some(&mut self.selected),
+                    );
+                }
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn show_encounter_settings(&mut self, ui: &mut egui::Ui) {
+        let mut refresh = false;
+
+        ui.label("Encounter");
+        egui::ComboBox::from_id_source("wild_4_encounter")
+            .selected_text(encounter_name(self.encounter))
+            .show_ui(ui, |ui| {
+                for &encounter in self.encounters() {
+                    refresh |= ui
+                        .selectable_value(&mut self.encounter, encounter, encounter_name(encounter))
+                        .changed();
+                }
+            });
+        ui.end_row();
+        ui.label("Method");
+        egui::ComboBox::from_id_source("wild_4_method")
+            .selected_text(method_name(self.method))
+            .show_ui(ui, |ui| {
+                for &method in self.methods() {
+                    refresh |= ui
+                        .selectable_value(&mut self.method, method, method_name(method))
+                        .changed();
+                }
+            });
+        ui.end_row();
+        if self.method == Method::PokeRadar {
+            ui.label("");
+            ui.checkbox(&mut self.radar_shiny, "Shiny Patch");
+            ui.end_row();
+        }
+        ui.label("Time");
+        egui::ComboBox::from_id_source("wild_4_time")
+            .selected_text(TIMES[self.time])
+            .show_ui(ui, |ui| {
+                for (i, time) in TIMES.iter().enumerate() {
+                    refresh |= ui.selectable_value(&mut self.time, i, *time).changed();
+                }
+            });
+        ui.end_row();
+        if self.dppt() {
+            ui.label("Dual Slot");
+            egui::ComboBox::from_id_source("wild_4_dual")
+                .selected_text(if self.dual == Game::NONE {
+                    "None"
+                } else {
+                    translator::get_game(self.dual)
+                })
+                .show_ui(ui, |ui| {
+                    for dual in DUAL_SLOTS {
+                        let name = if dual == Game::NONE {
+                            "None"
+                        } else {
+                            translator::get_game(dual)
+                        };
+                        refresh |= ui.selectable_value(&mut self.dual, dual, name).changed();
+                    }
+                });
+            ui.end_row();
+            ui.label("");
+            refresh |= ui.checkbox(&mut self.radar, "Poke Radar").changed();
+            ui.end_row();
+        } else {
+            ui.label("Radio");
+            egui::ComboBox::from_id_source("wild_4_radio")
+                .selected_text(RADIO[self.radio])
+                .show_ui(ui, |ui| {
+                    for (i, radio) in RADIO.iter().enumerate() {
+                        refresh |= ui.selectable_value(&mut self.radio, i, *radio).changed();
+                    }
+                });
+            ui.end_row();
+        }
+        ui.label("");
+        refresh |= ui.checkbox(&mut self.swarm, "Swarm").changed();
+        ui.end_row();
+
+        if refresh {
+            self.update_locations();
+        }
+
+        ui.label("Location");
+        let previous = self.location;
+        egui::ComboBox::from_id_source("wild_4_location")
+            .selected_text(
+                self.locations
+                    .get(self.location)
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            )
+            .show_ui(ui, |ui| {
+                for (i, location) in self.locations.iter().enumerate() {
+                    ui.selectable_value(&mut self.location, i, location);
+                }
+            });
+        if previous != self.location {
+            self.update_pokemon();
+        }
+        ui.end_row();
+        ui.label("Pokemon");
+        let previous = self.pokemon;
+        egui::ComboBox::from_id_source("wild_4_pokemon")
+            .selected_text(if self.pokemon == 0 {
+                "Any".to_string()
+            } else {
+                let species = self.species[self.pokemon - 1];
+                translator::get_species_with_form(species & 0x7ff, (species >> 11) as u8)
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut self.pokemon, 0, "Any");
+                for (i, &species) in self.species.iter().enumerate() {
+                    ui.selectable_value(
+                        &mut self.pokemon,
+                        i + 1,
+                        translator::get_species_with_form(species & 0x7ff, (species >> 11) as u8),
+                    );
+                }
+            });
+        if previous != self.pokemon {
+            self.update_slots();
+        }
+        ui.end_row();
+        ui.label("Lead");
+        self.lead.show(ui, "wild_4_lead", &LEADS);
+        ui.end_row();
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            self.generator_results.clear();
+            return;
+        };
+        let filter = self.get_filter();
+        let generator = WildGenerator4::new(
+            self.generator_settings.initial_advances,
+            self.generator_settings.max_advances,
+            self.generator_settings.delay,
+            self.method,
+            self.encounter,
+            self.lead.get_lead(),
+            self.radar_shiny,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.generator_results = generator
+            .generate(self.generator_settings.seed, area, self.radar_index())
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.base.advances.to_string(),
+                    state.battle_advances.to_string(),
+                ];
+                let mut wild = format_wild(&state.base.base);
+                // The call column sits after the level so the common state columns stay aligned
+                wild.insert(3, ["E", "K", "P"][state.call as usize % 3].to_string());
+                row.append(&mut wild);
+                row
+            })
+            .collect();
+    }
+
+    fn start_search(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            return;
+        };
+        self.searcher_results.clear();
+        self.searcher_seeds.clear();
+        self.selected = 0;
+
+        let filter = self.get_filter();
+        let searcher = WildSearcher4::new(
+            self.searcher_settings.min_advance,
+            self.searcher_settings.max_advance,
+            self.searcher_settings.min_delay,
+            self.searcher_settings.max_delay,
+            self.method,
+            self.encounter,
+            self.lead.get_lead(),
+            self.radar_shiny,
+            area,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        let min = self.filter.min;
+        let max = self.filter.max;
+        let index = self.radar_index();
+        self.search = Some(BackgroundSearch::start(
+            searcher,
+            iv_combinations(min, max),
+            move |searcher| searcher.start_search(min, max, index),
+        ));
+    }
+}
@@ -4,6 +4,7 @@ use egui::{Context, Direction, Layout, Widget};
 use egui_extras::Column;
 use pokefinder_rs_core::enums::Game;
 use pokefinder_rs_core::gen4::{tools::seed_to_time_calculator4, SeedTime4, SeedTimeCalibrate4};
+use pokefinder_rs_core::rng::PokeRNG;
 use pokefinder_rs_core::util::utilities4;
 
 pub struct SeedToTime4 {
@@ -39,6 +40,8 @@ struct DPPtData {
     second_minus: i32,
     second_plus: i32,
     coin_flips: String,
+    use_observed: bool,
+    observed: u32,
 }
 
 impl Default for DPPtData {
@@ -58,6 +61,8 @@ impl Default for DPPtData {
             second_minus: -1,
             second_plus: 1,
             coin_flips: "Coin Flips: ".to_string(),
+            use_observed: false,
+            observed: 0,
         }
     }
 }
@@ -121,6 +126,7 @@ struct CalibrateResult {
     date_time: String,
     delay: String,
     coin_flips: String,
+    advances: String,
     calls: String,
     roamer_locations: String,
 }
@@ -132,6 +138,7 @@ impl From<SeedTimeCalibrate4> for CalibrateResult {
             date_time: value.base.date_time.to_string(),
             delay: value.base.delay.to_string(),
             coin_flips: value.get_sequence(),
+            advances: String::new(),
             calls: value.get_sequence(),
             roamer_locations: if let Some(roamer) = value.roamer {
                 roamer.get_route_string()
@@ -143,16 +150,29 @@ impl From<SeedTimeCalibrate4> for CalibrateResult {
 }
 
 static SEARCH_HEADERS: [&str; 3] = ["Seed", "Date/Time", "Target"];
-static CALIBRATE_HEADERS: [&str; 6] = [
+static CALIBRATE_HEADERS: [&str; 7] = [
     "Seed",
     "Date/Time",
     "Delay",
     "Coin Flips",
+    "Advances",
     "Calls",
     "Roamer Locations",
 ];
 
 impl SeedToTime4 {
+    /// Opens the tool with `seed` already entered for the game family of `version`
+    pub fn with_seed(seed: u32, version: Game) -> Self {
+        let mut new = Self::default();
+        if (version & Game::HGSS) != Game::NONE {
+            new.mode = Game::HGSS;
+            new.hgss.seed = seed;
+        } else {
+            new.dppt.seed = seed;
+        }
+        new
+    }
+
     pub fn show(&mut self, ctx: &Context) -> bool {
         let mut close = false;
 
@@ -304,24 +324,41 @@ impl SeedToTime4 {
                                     self.dppt.second_plus,
                                     target,
                                 );
+                                let observed = self.dppt.use_observed.then_some(self.dppt.observed);
                                 self.dppt.calibrate_view = self
                                     .dppt
                                     .calibrate
                                     .clone()
                                     .into_iter()
-                                    .map(|res| res.into())
+                                    .map(|res| {
+                                        let seed = res.seed;
+                                        let mut view = CalibrateResult::from(res);
+                                        if let Some(observed) = observed {
+                                            view.advances =
+                                                PokeRNG::distance(seed, observed).to_string();
+                                        }
+                                        view
+                                    })
                                     .collect();
                             }
+                            ui.end_row();
+                            ui.checkbox(&mut self.dppt.use_observed, "Observed");
+                            ui.add_enabled(
+                                self.dppt.use_observed,
+                                egui::DragValue::new(&mut self.dppt.observed)
+                                    .hexadecimal(8, false, true)
+                                    .clamp_range(0..=u32::MAX),
+                            );
                         });
                     ui.vertical_centered_justified(|ui| {
                         ui.push_id("seed_to_time4_dppt_calibrate_table", |ui| {
                             egui_extras::TableBuilder::new(ui)
-                                .columns(Column::auto(), 4)
+                                .columns(Column::auto(), 5)
                                 .resizable(true)
                                 .auto_shrink([false; 2])
                                 .max_scroll_height(150.0)
                                 .header(20.0, |mut header| {
-                                    for &head in CALIBRATE_HEADERS.iter().take(4) {
+                                    for &head in CALIBRATE_HEADERS.iter().take(5) {
                                         header.col(|ui| {
                                             ui.heading(head);
                                         });
@@ -365,6 +402,9 @@ impl SeedToTime4 {
                                             row.col(|ui| {
                                                 ui.label(&r.coin_flips);
                                             });
+                                            row.col(|ui| {
+                                                ui.label(&r.advances);
+                                            });
                                         },
                                     );
                                 });
@@ -0,0 +1,158 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField, STATE_HEADERS,
+};
+use egui::{Context, Widget};
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::DreamRadarGenerator;
+use pokefinder_rs_core::gen5::{encounters5, DreamRadarTemplate, Profile5};
+use pokefinder_rs_core::parents::Template;
+use pokefinder_rs_core::util::translator;
+
+/// Number of slots shown in the Dream Radar
+const SLOTS: usize = 6;
+
+fn template_name(template: &DreamRadarTemplate) -> String {
+    translator::get_species_with_form(template.base.get_species(), template.base.get_form())
+}
+
+pub struct DreamRadar {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    badges: u8,
+    slots: [Option<usize>; SLOTS],
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for DreamRadar {
+    fn default() -> Self {
+        Self {
+            // The Dream Radar only transfers to Black 2 and White 2
+            profiles: super::bw2_profiles(),
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            badges: 0,
+            slots: [Some(0), None, None, None, None, None],
+            filter: Filter::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Needle"];
+
+impl DreamRadar {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+        let encounters = encounters5::get_dream_radar_encounters();
+
+        egui::Window::new("Dream Radar Generator").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "dream_radar_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("dream_radar_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, false);
+                            ui.label("Badges");
+                            egui::DragValue::new(&mut self.badges)
+                                .clamp_range(0..=8)
+                                .ui(ui);
+                            ui.end_row();
+                            for (i, slot) in self.slots.iter_mut().enumerate() {
+                                ui.label(format!("Slot {}", i + 1));
+                                egui::ComboBox::from_id_source(format!("dream_radar_slot_{i}"))
+                                    .selected_text(
+                                        slot.map(|index| template_name(&encounters[index]))
+                                            .unwrap_or_else(|| "None".to_string()),
+                                    )
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(slot, None, "None");
+                                        for (index, template) in encounters.iter().enumerate() {
+                                            ui.selectable_value(
+                                                slot,
+                                                Some(index),
+                                                template_name(template),
+                                            );
+                                        }
+                                    });
+                                ui.end_row();
+                            }
+                        });
+                    ui.label("The last filled slot is the Pokemon that is generated");
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "dream_radar_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "dream_radar_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let encounters = encounters5::get_dream_radar_encounters();
+        let templates = self
+            .slots
+            .iter()
+            .flatten()
+            .map(|&index| encounters[index])
+            .collect::<Vec<_>>();
+        if templates.is_empty() {
+            self.results.clear();
+            return;
+        }
+
+        let filter = StateFilter5::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = DreamRadarGenerator::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.badges,
+            &templates,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.base.advances.to_string(), state.needle.to_string()];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
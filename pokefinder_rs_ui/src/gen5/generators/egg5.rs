@@ -0,0 +1,130 @@
+use crate::controls::{
+    format_egg_state, profile_select, results_table, DaycareControls, Filter, GeneratorSettings,
+    HexField, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::EggGenerator5;
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::parents::profile_loader_5;
+
+const ITEMS: [&str; 8] = [
+    "None",
+    "Everstone",
+    "Power Weight",
+    "Power Bracer",
+    "Power Belt",
+    "Power Lens",
+    "Power Band",
+    "Power Anklet",
+];
+
+pub struct Egg5 {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    daycare: DaycareControls,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Egg5 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_5::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile5::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            daycare: DaycareControls::default(),
+            filter: Filter::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Chatot"];
+
+impl Egg5 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Egg Generator 5").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "egg_5_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("egg_5_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.daycare.show(ui, "egg_5_daycare", &ITEMS, 649);
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "egg_5_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "egg_5_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = StateFilter5::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let daycare = self.daycare.get_daycare();
+        let generator = EggGenerator5::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            &daycare,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.base.advances.to_string(), state.chatot.to_string()];
+                row.extend(format_egg_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
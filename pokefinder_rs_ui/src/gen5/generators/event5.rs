@@ -0,0 +1,146 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField,
+    WondercardField, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::EventGenerator5;
+use pokefinder_rs_core::gen5::{Profile5, PGF};
+use pokefinder_rs_core::parents::profile_loader_5;
+use pokefinder_rs_core::util::translator;
+
+/// Size of a PGF wondercard
+const PGF_SIZE: usize = 0xcc;
+
+pub struct Event5 {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    wondercard: WondercardField,
+    pgf: Option<PGF>,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Event5 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_5::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile5::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            wondercard: WondercardField::default(),
+            pgf: None,
+            filter: Filter::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 3] = ["Advances", "Chatot", "Level"];
+
+impl Event5 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Event Generator 5").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "event_5_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("event_5_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            if let Some(data) = self.wondercard.show(ui, PGF_SIZE) {
+                                self.pgf = Some(PGF::from(data.as_slice()));
+                                self.results.clear();
+                            }
+                            ui.label("Pokemon");
+                            ui.label(match &self.pgf {
+                                // Species outside of the Gen 5 dex means the file is not a PGF
+                                Some(pgf) if (1..=649).contains(&pgf.species) => {
+                                    translator::get_species(pgf.species)
+                                }
+                                Some(_) => "Invalid wondercard",
+                                None => "-",
+                            });
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "event_5_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "event_5_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(pgf) = self
+            .pgf
+            .as_ref()
+            .filter(|pgf| (1..=649).contains(&pgf.species))
+        else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter5::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = EventGenerator5::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            pgf,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.base.advances.to_string(),
+                    state.chatot.to_string(),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
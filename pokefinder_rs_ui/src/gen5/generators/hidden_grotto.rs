@@ -0,0 +1,197 @@
+use crate::controls::{profile_select, results_table, GeneratorSettings, HexField};
+use egui::{Context, Widget};
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen5::filters::HiddenGrottoFilter;
+use pokefinder_rs_core::gen5::generators::HiddenGrottoGenerator;
+use pokefinder_rs_core::gen5::{encounters5, HiddenGrottoArea, Profile5};
+use pokefinder_rs_core::util::translator;
+
+const SLOTS: [&str; 11] = [
+    "Pokemon 1",
+    "Pokemon 2",
+    "Pokemon 3",
+    "Item 1",
+    "Item 2",
+    "Item 3",
+    "Item 4",
+    "Hidden Item 1",
+    "Hidden Item 2",
+    "Hidden Item 3",
+    "Hidden Item 4",
+];
+const GENDERS: [&str; 2] = ["Male", "Female"];
+
+pub struct HiddenGrotto {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    areas: Vec<HiddenGrottoArea>,
+    locations: Vec<String>,
+    location: usize,
+    power_level: u8,
+    slots: [bool; 11],
+    groups: [bool; 4],
+    genders: [bool; 2],
+    results: Vec<Vec<String>>,
+}
+
+impl Default for HiddenGrotto {
+    fn default() -> Self {
+        let areas = encounters5::get_hidden_grotto_encounters();
+        let locations = translator::get_locations(
+            &areas
+                .iter()
+                .map(|area| area.location as u16)
+                .collect::<Vec<_>>(),
+            Game::BW2,
+        );
+        Self {
+            // Hidden Grottos only exist in Black 2 and White 2
+            profiles: super::bw2_profiles(),
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            areas,
+            locations,
+            location: 0,
+            power_level: 5,
+            slots: [true; 11],
+            groups: [true; 4],
+            genders: [true; 2],
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 6] = ["Advances", "Group", "Slot", "Result", "Gender", "Chatot"];
+
+impl HiddenGrotto {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Hidden Grotto Generator").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(
+                ui,
+                "hidden_grotto_profile",
+                &self.profiles,
+                &mut self.profile,
+            );
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("hidden_grotto_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Location");
+                            egui::ComboBox::from_id_source("hidden_grotto_location")
+                                .selected_text(
+                                    self.locations
+                                        .get(self.location)
+                                        .cloned()
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, location) in self.locations.iter().enumerate() {
+                                        ui.selectable_value(&mut self.location, i, location);
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Power Level");
+                            egui::DragValue::new(&mut self.power_level)
+                                .clamp_range(0..=100)
+                                .ui(ui);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    egui::Grid::new("hidden_grotto_filter")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Slots");
+                            ui.vertical(|ui| {
+                                for (slot, name) in self.slots.iter_mut().zip(SLOTS) {
+                                    ui.checkbox(slot, name);
+                                }
+                            });
+                            ui.end_row();
+                            ui.label("Groups");
+                            ui.horizontal(|ui| {
+                                for (i, group) in self.groups.iter_mut().enumerate() {
+                                    ui.checkbox(group, i.to_string());
+                                }
+                            });
+                            ui.end_row();
+                            ui.label("Gender");
+                            ui.horizontal(|ui| {
+                                for (gender, name) in self.genders.iter_mut().zip(GENDERS) {
+                                    ui.checkbox(gender, name);
+                                }
+                            });
+                            ui.end_row();
+                        });
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                results_table(ui, "hidden_grotto_results", &HEADERS, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            self.results.clear();
+            return;
+        };
+        let filter = HiddenGrottoFilter::new(self.slots, self.genders, self.groups);
+        let generator = HiddenGrottoGenerator::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.power_level,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value, area)
+            .into_iter()
+            .map(|state| {
+                let (result, gender) = if state.item {
+                    let item = match state.data {
+                        0 => "-",
+                        item => translator::get_item(item),
+                    };
+                    (item.to_string(), "-".to_string())
+                } else {
+                    (
+                        translator::get_species(state.data).to_string(),
+                        translator::get_gender(state.gender).to_string(),
+                    )
+                };
+                vec![
+                    state.advances.to_string(),
+                    state.group.to_string(),
+                    state.slot.to_string(),
+                    result,
+                    gender,
+                    state.chatot.to_string(),
+                ]
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,117 @@
+use crate::controls::{
+    profile_select, results_table, GeneratorSettings, HexField, IDFilterControls,
+};
+use egui::Context;
+use pokefinder_rs_core::gen5::generators::IDGenerator5;
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::parents::profile_loader_5;
+
+pub struct IDs5 {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    pid: HexField,
+    check_pid: bool,
+    check_xor: bool,
+    filter: IDFilterControls,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for IDs5 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_5::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile5::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            pid: HexField::new(0, 8),
+            check_pid: false,
+            check_xor: false,
+            filter: IDFilterControls::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 4] = ["Advances", "TID", "SID", "TSV"];
+
+impl IDs5 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("ID Generator 5").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "ids_5_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                egui::Grid::new("ids_5_settings")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Seed");
+                        self.seed.show(ui);
+                        ui.end_row();
+                        self.settings.show(ui, 0, false);
+                        ui.label("PID");
+                        self.pid.show(ui);
+                        ui.end_row();
+                        ui.label("");
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut self.check_pid, "Shiny PID");
+                            ui.checkbox(&mut self.check_xor, "Match XOR");
+                        });
+                        ui.end_row();
+                    });
+                ui.separator();
+                egui::Grid::new("ids_5_filter")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        self.filter.show(ui, false);
+                    });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                results_table(ui, "ids_5_results", &HEADERS, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = self.filter.get_filter();
+        let generator = IDGenerator5::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(
+                self.seed.value,
+                self.pid.value as u32,
+                self.check_pid,
+                self.check_xor,
+            )
+            .into_iter()
+            .map(|state| {
+                vec![
+                    state.advances.to_string(),
+                    state.tid.to_string(),
+                    state.sid.to_string(),
+                    state.tsv.to_string(),
+                ]
+            })
+            .collect();
+    }
+}
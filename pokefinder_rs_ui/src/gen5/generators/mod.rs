@@ -0,0 +1,126 @@
+mod dream_radar;
+mod egg5;
+mod event5;
+mod hidden_grotto;
+mod ids5;
+mod static5;
+mod wild5;
+
+pub use dream_radar::*;
+pub use egg5::*;
+pub use event5::*;
+pub use hidden_grotto::*;
+pub use ids5::*;
+pub use static5::*;
+pub use wild5::*;
+
+use egui::Context;
+use pokefinder_rs_core::enums::{DSType, Game, Language};
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::parents::{profile_loader_5, Profile};
+
+/// Loads the Black 2 and White 2 profiles, falling back to a default Black 2 profile
+fn bw2_profiles() -> Vec<Profile5> {
+    let mut profiles = profile_loader_5::get_profiles();
+    profiles.retain(|profile| (profile.get_version() & Game::BW2) != Game::NONE);
+    if profiles.is_empty() {
+        profiles.push(Profile5::new(
+            "-".to_string(),
+            Game::BLACK2,
+            12345,
+            54321,
+            0x9BF123456,
+            [true; 4],
+            0x60,
+            6,
+            5,
+            false,
+            0xC79,
+            0xC7A,
+            false,
+            false,
+            false,
+            DSType::DS,
+            Language::English,
+        ));
+    }
+    profiles
+}
+
+#[derive(Default)]
+pub struct Gen5Generators {
+    pub dream_radar: Option<Box<DreamRadar>>,
+    pub egg: Option<Box<Egg5>>,
+    pub event: Option<Box<Event5>>,
+    pub hidden_grotto: Option<Box<HiddenGrotto>>,
+    pub ids: Option<Box<IDs5>>,
+    pub static_generator: Option<Box<Static5>>,
+    pub wild: Option<Box<Wild5>>,
+}
+
+impl Gen5Generators {
+    pub fn show(&mut self, ctx: &Context) {
+        let mut close = self
+            .dream_radar
+            .as_mut()
+            .map(|dream_radar| dream_radar.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.dream_radar = None;
+        }
+
+        close = self
+            .egg
+            .as_mut()
+            .map(|egg| egg.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.egg = None;
+        }
+
+        close = self
+            .event
+            .as_mut()
+            .map(|event| event.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.event = None;
+        }
+
+        close = self
+            .hidden_grotto
+            .as_mut()
+            .map(|hidden_grotto| hidden_grotto.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.hidden_grotto = None;
+        }
+
+        close = self
+            .ids
+            .as_mut()
+            .map(|ids| ids.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.ids = None;
+        }
+
+        close = self
+            .static_generator
+            .as_mut()
+            .map(|static_generator| static_generator.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.static_generator = None;
+        }
+
+        close = self
+            .wild
+            .as_mut()
+            .map(|wild| wild.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.wild = None;
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::controls::{
+    format_state, method_name, profile_select, results_table, Filter, GeneratorSettings, HexField,
+    LeadSelect, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Encounter, Game, Lead, Method};
+use pokefinder_rs_core::gen5::filters::StateFilter5;
+use pokefinder_rs_core::gen5::generators::StaticGenerator5;
+use pokefinder_rs_core::gen5::{encounters5, Profile5};
+use pokefinder_rs_core::parents::{profile_loader_5, Profile, StaticTemplate, Template};
+use pokefinder_rs_core::util::translator;
+
+const CATEGORIES: [&str; 7] = [
+    "Starters",
+    "Fossils",
+    "Gifts",
+    "Stationary",
+    "Legends",
+    "Events",
+    "Roamers",
+];
+const METHODS: [Method; 3] = [Method::Method5IVs, Method::Method5CGear, Method::Method5];
+const LEADS: [Lead; 3] = [Lead::SYNCHRONIZE, Lead::CUTE_CHARM_F, Lead::CUTE_CHARM_M];
+
+pub struct Static5 {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    method: Method,
+    category: usize,
+    templates: Vec<&'static StaticTemplate>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Static5 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_5::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile5::default());
+        }
+        let mut static5 = Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            method: Method::Method5IVs,
+            category: 0,
+            templates: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        static5.update_templates();
+        static5
+    }
+}
+
+static HEADERS: [&str; 3] = ["Advances", "Chatot", "Level"];
+
+impl Static5 {
+    fn update_templates(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.templates = encounters5::get_static_encounters(self.category)
+            .iter()
+            .filter(|template| (template.get_version() & version) != Game::NONE)
+            .collect();
+        self.pokemon = 0;
+    }
+
+    fn encounter(&self) -> Encounter {
+        match self.category {
+            0..=2 => Encounter::Gift,
+            3..=5 => Encounter::Static,
+            _ => Encounter::Roamer,
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Static Generator 5").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "static_5_profile", &self.profiles, &mut self.profile) {
+                self.update_templates();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("static_5_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Method");
+                            egui::ComboBox::from_id_source("static_5_method")
+                                .selected_text(method_name(self.method))
+                                .show_ui(ui, |ui| {
+                                    for method in METHODS {
+                                        ui.selectable_value(
+                                            &mut self.method,
+                                            method,
+                                            method_name(method),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Category");
+                            let previous = self.category;
+                            egui::ComboBox::from_id_source("static_5_category")
+                                .selected_text(CATEGORIES[self.category])
+                                .show_ui(ui, |ui| {
+                                    for (i, category) in CATEGORIES.iter().enumerate() {
+                                        ui.selectable_value(&mut self.category, i, *category);
+                                    }
+                                });
+                            if previous != self.category {
+                                self.update_templates();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            egui::ComboBox::from_id_source("static_5_pokemon")
+                                .selected_text(
+                                    self.templates
+                                        .get(self.pokemon)
+                                        .map(|template| {
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            )
+                                        })
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, template) in self.templates.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i,
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            ),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "static_5_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "static_5_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "static_5_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(template) = self.templates.get(self.pokemon) else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter5::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = StaticGenerator5::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.method,
+            self.encounter(),
+            self.lead.get_lead(),
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value, template)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.base.advances.to_string(),
+                    state.chatot.to_string(),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
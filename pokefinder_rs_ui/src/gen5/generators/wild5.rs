@@ -0,0 +1,297 @@
+use crate::controls::{
+    encounter_name, format_state, method_name, profile_select, results_table, Filter,
+    GeneratorSettings, HexField, LeadSelect, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Encounter, Lead, Method};
+use pokefinder_rs_core::gen5::filters::WildStateFilter5;
+use pokefinder_rs_core::gen5::generators::WildGenerator5;
+use pokefinder_rs_core::gen5::{encounters5, EncounterArea5, Profile5};
+use pokefinder_rs_core::parents::{profile_loader_5, EncounterAreaT, Profile};
+use pokefinder_rs_core::util::translator;
+
+const ENCOUNTERS: [Encounter; 7] = [
+    Encounter::Grass,
+    Encounter::DoubleGrass,
+    Encounter::SpecialGrass,
+    Encounter::Surfing,
+    Encounter::SpecialSurf,
+    Encounter::SuperRod,
+    Encounter::SpecialSuperRod,
+];
+const METHODS: [Method; 3] = [Method::Method5IVs, Method::Method5CGear, Method::Method5];
+const LEADS: [Lead; 4] = [
+    Lead::SYNCHRONIZE,
+    Lead::CUTE_CHARM_F,
+    Lead::CUTE_CHARM_M,
+    Lead::COMPOUND_EYES,
+];
+const SEASONS: [&str; 4] = ["Spring", "Summer", "Autumn", "Winter"];
+
+pub struct Wild5 {
+    profiles: Vec<Profile5>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    method: Method,
+    encounter: Encounter,
+    season: u8,
+    areas: Vec<EncounterArea5>,
+    locations: Vec<String>,
+    location: usize,
+    species: Vec<u16>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Wild5 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_5::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile5::default());
+        }
+        let mut wild = Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            method: Method::Method5IVs,
+            encounter: Encounter::Grass,
+            season: 0,
+            areas: vec![],
+            locations: vec![],
+            location: 0,
+            species: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        wild.update_locations();
+        wild
+    }
+}
+
+static HEADERS: [&str; 5] = ["Advances", "Chatot", "Slot", "Species", "Level"];
+
+impl Wild5 {
+    fn update_locations(&mut self) {
+        let profile = &self.profiles[self.profile];
+        let version = profile.get_version();
+        self.areas = encounters5::get_encounters(self.encounter, self.season, profile);
+        let locations = self
+            .areas
+            .iter()
+            .map(|area| area.get_location() as u16)
+            .collect::<Vec<_>>();
+        self.locations = translator::get_locations(&locations, version);
+        self.location = 0;
+        self.update_pokemon();
+    }
+
+    fn update_pokemon(&mut self) {
+        self.species = self
+            .areas
+            .get(self.location)
+            .map(|area| area.get_unique_species())
+            .unwrap_or_default();
+        self.pokemon = 0;
+        self.filter.encounter_slots = [true; 12];
+    }
+
+    fn update_slots(&mut self) {
+        self.filter.encounter_slots = [true; 12];
+        if self.pokemon != 0 {
+            let slots =
+                self.areas[self.location].get_slots_by_specie(self.species[self.pokemon - 1]);
+            for (filter, slot) in self.filter.encounter_slots.iter_mut().zip(slots) {
+                *filter = slot;
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Wild Generator 5").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "wild_5_profile", &self.profiles, &mut self.profile) {
+                self.update_locations();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("wild_5_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Method");
+                            egui::ComboBox::from_id_source("wild_5_method")
+                                .selected_text(method_name(self.method))
+                                .show_ui(ui, |ui| {
+                                    for method in METHODS {
+                                        ui.selectable_value(
+                                            &mut self.method,
+                                            method,
+                                            method_name(method),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Encounter");
+                            let previous = self.encounter;
+                            egui::ComboBox::from_id_source("wild_5_encounter")
+                                .selected_text(encounter_name(self.encounter))
+                                .show_ui(ui, |ui| {
+                                    for encounter in ENCOUNTERS {
+                                        ui.selectable_value(
+                                            &mut self.encounter,
+                                            encounter,
+                                            encounter_name(encounter),
+                                        );
+                                    }
+                                });
+                            if previous != self.encounter {
+                                self.update_locations();
+                            }
+                            ui.end_row();
+                            ui.label("Season");
+                            let previous = self.season;
+                            egui::ComboBox::from_id_source("wild_5_season")
+                                .selected_text(SEASONS[self.season as usize])
+                                .show_ui(ui, |ui| {
+                                    for (i, season) in SEASONS.iter().enumerate() {
+                                        ui.selectable_value(&mut self.season, i as u8, *season);
+                                    }
+                                });
+                            if previous != self.season {
+                                self.update_locations();
+                            }
+                            ui.end_row();
+                            ui.label("Location");
+                            let previous = self.location;
+                            egui::ComboBox::from_id_source("wild_5_location")
+                                .selected_text(
+                                    self.locations
+                                        .get(self.location)
+                                        .map(String::as_str)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, location) in self.locations.iter().enumerate() {
+                                        ui.selectable_value(&mut self.location, i, location);
+                                    }
+                                });
+                            if previous != self.location {
+                                self.update_pokemon();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            let previous = self.pokemon;
+                            egui::ComboBox::from_id_source("wild_5_pokemon")
+                                .selected_text(if self.pokemon == 0 {
+                                    "Any".to_string()
+                                } else {
+                                    let species = self.species[self.pokemon - 1];
+                                    translator::get_species_with_form(
+                                        species & 0x7ff,
+                                        (species >> 11) as u8,
+                                    )
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut self.pokemon, 0, "Any");
+                                    for (i, &species) in self.species.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i + 1,
+                                            translator::get_species_with_form(
+                                                species & 0x7ff,
+                                                (species >> 11) as u8,
+                                            ),
+                                        );
+                                    }
+                                });
+                            if previous != self.pokemon {
+                                self.update_slots();
+                            }
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "wild_5_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "wild_5_filter", 12);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "wild_5_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            self.results.clear();
+            return;
+        };
+        let filter = WildStateFilter5::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+            self.filter.encounter_slots,
+        );
+        let profile = &self.profiles[self.profile];
+        let generator = WildGenerator5::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.method,
+            self.encounter,
+            self.lead.get_lead(),
+            profile,
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value, area)
+            .into_iter()
+            .map(|state| {
+                let wild = &state.base.base;
+                let mut row = vec![
+                    state.base.advances.to_string(),
+                    state.chatot.to_string(),
+                    wild.encounter_slot.to_string(),
+                    translator::get_species_with_form(wild.species, wild.form),
+                    wild.base.level.to_string(),
+                ];
+                row.extend(format_state(&wild.base));
+                row
+            })
+            .collect();
+    }
+}
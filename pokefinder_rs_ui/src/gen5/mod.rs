@@ -0,0 +1,2 @@
+pub mod generators;
+pub mod profile;
@@ -0,0 +1,5 @@
+mod profile_editor5;
+mod profile_manager5;
+
+pub use profile_editor5::*;
+pub use profile_manager5::*;
@@ -0,0 +1,169 @@
+use crate::controls::HexField;
+use crate::ProfileEditorResult;
+use egui::{Context, Widget};
+use pokefinder_rs_core::enums::{DSType, Game, Language};
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::util::translator;
+
+const DS_TYPES: [(DSType, &str); 3] = [
+    (DSType::DS, "DS Lite"),
+    (DSType::DSi, "DSi"),
+    (DSType::DS3, "3DS"),
+];
+const LANGUAGES: [(Language, &str); 7] = [
+    (Language::English, "ENG"),
+    (Language::French, "FRE"),
+    (Language::German, "DEU"),
+    (Language::Italian, "ITA"),
+    (Language::Japanese, "JPN"),
+    (Language::Korean, "KOR"),
+    (Language::Spanish, "SPA"),
+];
+const KEY_PRESSES: [&str; 4] = ["None", "1", "2", "3"];
+
+pub struct ProfileEditor5 {
+    pub profile: Profile5,
+    mac: HexField,
+    default_result: ProfileEditorResult,
+}
+
+impl Default for ProfileEditor5 {
+    fn default() -> Self {
+        let profile = Profile5::default();
+        Self {
+            mac: HexField::new(profile.mac, 12),
+            profile,
+            default_result: ProfileEditorResult::New,
+        }
+    }
+}
+
+impl ProfileEditor5 {
+    pub fn edit(profile: &Profile5) -> Self {
+        Self {
+            profile: profile.clone(),
+            mac: HexField::new(profile.mac, 12),
+            default_result: ProfileEditorResult::Edit,
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> ProfileEditorResult {
+        let mut result = ProfileEditorResult::Pending;
+
+        egui::Window::new("Profile 5 Editor").show(ctx, |ui| {
+            egui::Grid::new("profile_5_editor_grid")
+                .num_columns(5)
+                .show(ui, |ui| {
+                    ui.label("Profile Name");
+                    ui.text_edit_singleline(&mut self.profile.name);
+                    ui.label("TID");
+                    egui::DragValue::new(&mut self.profile.tid)
+                        .clamp_range(0..=65535)
+                        .ui(ui);
+                    if ui.add(egui::Button::new("Okay").wrap(false)).clicked() {
+                        result = self.default_result;
+                    }
+                    ui.end_row();
+                    ui.label("Version");
+                    egui::ComboBox::new("profile_editor_5_version", "")
+                        .wrap(false)
+                        .selected_text(translator::get_game(self.profile.version))
+                        .show_ui(ui, |ui| {
+                            for version in [Game::BLACK, Game::WHITE, Game::BLACK2, Game::WHITE2] {
+                                ui.selectable_value(
+                                    &mut self.profile.version,
+                                    version,
+                                    translator::get_game(version),
+                                );
+                            }
+                        });
+                    ui.label("SID");
+                    egui::DragValue::new(&mut self.profile.sid)
+                        .clamp_range(0..=65535)
+                        .ui(ui);
+                    if ui.add(egui::Button::new("Cancel").wrap(false)).clicked() {
+                        result = ProfileEditorResult::Cancel;
+                    }
+                    ui.end_row();
+                    ui.label("DS Type");
+                    egui::ComboBox::new("profile_editor_5_ds_type", "")
+                        .wrap(false)
+                        .selected_text(
+                            DS_TYPES
+                                .iter()
+                                .find(|(ds_type, _)| *ds_type == self.profile.ds_type)
+                                .map(|(_, name)| *name)
+                                .unwrap_or_default(),
+                        )
+                        .show_ui(ui, |ui| {
+                            for (ds_type, name) in DS_TYPES {
+                                ui.selectable_value(&mut self.profile.ds_type, ds_type, name);
+                            }
+                        });
+                    ui.label("MAC Address");
+                    self.mac.show(ui);
+                    ui.label("");
+                    ui.end_row();
+                    ui.label("Language");
+                    egui::ComboBox::new("profile_editor_5_language", "")
+                        .wrap(false)
+                        .selected_text(self.profile.get_language_string())
+                        .show_ui(ui, |ui| {
+                            for (language, name) in LANGUAGES {
+                                ui.selectable_value(&mut self.profile.language, language, name);
+                            }
+                        });
+                    ui.label("VCount");
+                    egui::DragValue::new(&mut self.profile.vcount)
+                        .clamp_range(0..=255)
+                        .hexadecimal(2, false, true)
+                        .ui(ui);
+                    ui.label("");
+                    ui.end_row();
+                    ui.label("Timer0 Min");
+                    egui::DragValue::new(&mut self.profile.timer0_min)
+                        .clamp_range(0..=self.profile.timer0_max)
+                        .hexadecimal(4, false, true)
+                        .ui(ui);
+                    ui.label("GxStat");
+                    egui::DragValue::new(&mut self.profile.gx_stat)
+                        .clamp_range(0..=255)
+                        .ui(ui);
+                    ui.label("");
+                    ui.end_row();
+                    ui.label("Timer0 Max");
+                    egui::DragValue::new(&mut self.profile.timer0_max)
+                        .clamp_range(self.profile.timer0_min..=65535)
+                        .hexadecimal(4, false, true)
+                        .ui(ui);
+                    ui.label("VFrame");
+                    egui::DragValue::new(&mut self.profile.vframe)
+                        .clamp_range(0..=255)
+                        .ui(ui);
+                    ui.label("");
+                    ui.end_row();
+                    ui.label("Key Presses");
+                    ui.horizontal(|ui| {
+                        for (key_press, name) in
+                            self.profile.key_presses.iter_mut().zip(KEY_PRESSES)
+                        {
+                            ui.checkbox(key_press, name);
+                        }
+                    });
+                    ui.checkbox(&mut self.profile.skip_lr, "Skip L/R");
+                    ui.checkbox(&mut self.profile.soft_reset, "Soft Reset");
+                    ui.label("");
+                    ui.end_row();
+                    ui.label("");
+                    ui.label("");
+                    ui.checkbox(&mut self.profile.memory_link, "Memory Link");
+                    ui.checkbox(&mut self.profile.shiny_charm, "Shiny Charm");
+                    ui.label("");
+                    ui.end_row();
+                });
+        });
+        self.profile.mac = self.mac.value;
+
+        result
+    }
+}
@@ -0,0 +1,172 @@
+use crate::gen5::profile::ProfileEditor5;
+use crate::ProfileEditorResult;
+use egui::Context;
+use egui_extras::Column;
+use pokefinder_rs_core::gen5::Profile5;
+use pokefinder_rs_core::parents::{profile_loader_5, Profile};
+use pokefinder_rs_core::util::translator;
+
+pub struct ProfileManager5 {
+    pub show: bool,
+    pub profiles: Vec<(Profile5, bool)>,
+    pub edit: usize,
+    pub editor: Option<Box<ProfileEditor5>>,
+}
+
+static HEADERS: [&str; 12] = [
+    "Profile Name",
+    "Version",
+    "TID",
+    "SID",
+    "MAC Address",
+    "DS Type",
+    "Language",
+    "Key Presses",
+    "VCount",
+    "Timer0",
+    "GxStat",
+    "VFrame",
+];
+
+impl Default for ProfileManager5 {
+    fn default() -> Self {
+        Self {
+            profiles: profile_loader_5::get_profiles()
+                .into_iter()
+                .map(|p| (p, false))
+                .collect(),
+            show: false,
+            edit: 0,
+            editor: None,
+        }
+    }
+}
+
+impl ProfileManager5 {
+    pub fn show(&mut self, ctx: &Context) {
+        if self.show {
+            egui::Window::new("Profile Manager 5").show(ctx, |ui| {
+                ui.style_mut().wrap = Some(false);
+                ui.horizontal(|ui| {
+                    if ui.button("New").clicked() {
+                        self.editor = Some(Box::default());
+                    }
+                    if ui.button("Edit").clicked() && !self.profiles.is_empty() {
+                        self.editor =
+                            Some(Box::new(ProfileEditor5::edit(&self.profiles[self.edit].0)));
+                    }
+                    if ui.button("Delete").clicked() {
+                        self.profiles.retain(|(profile, delete)| {
+                            if *delete {
+                                profile_loader_5::remove_profile(profile);
+                                false
+                            } else {
+                                true
+                            }
+                        });
+                    }
+                });
+                ui.vertical_centered_justified(|ui| {
+                    egui_extras::TableBuilder::new(ui)
+                        .columns(Column::auto(), HEADERS.len() + 2)
+                        .resizable(true)
+                        .max_scroll_height(150.0)
+                        .auto_shrink([false; 2])
+                        .striped(true)
+                        .header(20.0, |mut header| {
+                            for head in HEADERS {
+                                header.col(|ui| {
+                                    ui.heading(head);
+                                });
+                            }
+                            header.col(|ui| {
+                                ui.heading("Edit");
+                            });
+                            header.col(|ui| {
+                                ui.heading("Delete");
+                            });
+                        })
+                        .body(|body| {
+                            body.rows(15.0, self.profiles.len(), |i, mut row| {
+                                let (profile, delete) = &mut self.profiles[i];
+                                row.col(|ui| {
+                                    ui.label(profile.get_name());
+                                });
+                                row.col(|ui| {
+                                    ui.label(translator::get_game(profile.get_version()));
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_tid().to_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_sid().to_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(format!("{:0>12X}", profile.get_mac()));
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_ds_type_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_language_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_key_presses_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(format!("{:X}", profile.get_vcount()));
+                                });
+                                row.col(|ui| {
+                                    ui.label(format!(
+                                        "{:X}-{:X}",
+                                        profile.get_timer0_min(),
+                                        profile.get_timer0_max()
+                                    ));
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_gx_stat().to_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(profile.get_vframe().to_string());
+                                });
+                                row.col(|ui| {
+                                    ui.radio_value(&mut self.edit, i, "");
+                                });
+                                row.col(|ui| {
+                                    ui.checkbox(delete, "");
+                                });
+                            });
+                        });
+                    if ui.button("Done").clicked() {
+                        self.show = false;
+                    }
+                });
+            });
+
+            let editor_result = self
+                .editor
+                .as_mut()
+                .map(|editor| editor.show(ctx))
+                .unwrap_or_default();
+            match editor_result {
+                ProfileEditorResult::Pending => {}
+                ProfileEditorResult::Edit => {
+                    profile_loader_5::remove_profile(&self.profiles[self.edit].0);
+                    let editor = self.editor.take();
+                    let profile = editor.unwrap().profile;
+                    profile_loader_5::add_profile(profile.clone());
+                    self.profiles[self.edit].0 = profile;
+                }
+                ProfileEditorResult::New => {
+                    let editor = self.editor.take();
+                    let profile = editor.unwrap().profile;
+                    profile_loader_5::add_profile(profile.clone());
+                    self.profiles.push((profile, false));
+                }
+                ProfileEditorResult::Cancel => {
+                    self.editor = None;
+                }
+            }
+        }
+    }
+}
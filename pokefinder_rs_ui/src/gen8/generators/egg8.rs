@@ -0,0 +1,160 @@
+use crate::controls::{
+    format_egg_state, profile_select, results_table, DaycareControls, Filter, GeneratorSettings,
+    HexField, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::EggGenerator8;
+use pokefinder_rs_core::gen8::Profile8;
+use pokefinder_rs_core::parents::profile_loader_8;
+
+const ITEMS: [&str; 9] = [
+    "None",
+    "Everstone",
+    "Power Weight",
+    "Power Bracer",
+    "Power Belt",
+    "Power Lens",
+    "Power Band",
+    "Power Anklet",
+    "Destiny Knot",
+];
+const COMPATIBILITY: [&str; 3] = [
+    "The two don't seem to like each other",
+    "The two seem to get along",
+    "The two seem to get along very well",
+];
+
+pub struct Egg8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    compatibility: usize,
+    daycare: DaycareControls,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Egg8 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_8::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile8::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            compatibility: 0,
+            daycare: DaycareControls::default(),
+            filter: Filter::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 1] = ["Advances"];
+
+impl Egg8 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Egg Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "egg_8_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("egg_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed 0");
+                            self.seed0.show(ui);
+                            ui.end_row();
+                            ui.label("Seed 1");
+                            self.seed1.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Compatibility");
+                            egui::ComboBox::from_id_source("egg_8_compatibility")
+                                .selected_text(COMPATIBILITY[self.compatibility])
+                                .show_ui(ui, |ui| {
+                                    for (i, name) in COMPATIBILITY.iter().enumerate() {
+                                        ui.selectable_value(&mut self.compatibility, i, *name);
+                                    }
+                                });
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.daycare.show(ui, "egg_8_daycare", &ITEMS, 493);
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "egg_8_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "egg_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = StateFilter8::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let daycare = self.daycare.get_daycare();
+        let profile = &self.profiles[self.profile];
+        // The oval charm raises the chance of an egg being ready at each compatibility
+        let compatibility = if profile.get_oval_charm() {
+            [40, 80, 88]
+        } else {
+            [20, 50, 70]
+        }[self.compatibility];
+        let generator = EggGenerator8::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            compatibility,
+            &daycare,
+            profile,
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed0.value, self.seed1.value)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string()];
+                row.extend(format_egg_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,150 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField,
+    WondercardField, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::EventGenerator8;
+use pokefinder_rs_core::gen8::{Profile8, WB8};
+use pokefinder_rs_core::parents::{profile_loader_8, Profile};
+use pokefinder_rs_core::util::translator;
+
+/// Size of a WB8 wondercard
+const WB8_SIZE: usize = 0x2dc;
+
+pub struct Event8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    wondercard: WondercardField,
+    wb8: Option<WB8>,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Event8 {
+    fn default() -> Self {
+        // WB8 wondercards are only received in BDSP
+        let mut profiles = profile_loader_8::get_profiles();
+        profiles.retain(|profile| (profile.get_version() & Game::BDSP) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile8::default());
+        }
+        Self {
+            profiles,
+            profile: 0,
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            wondercard: WondercardField::default(),
+            wb8: None,
+            filter: Filter::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Level"];
+
+impl Event8 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Event Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            profile_select(ui, "event_8_profile", &self.profiles, &mut self.profile);
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("event_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed 0");
+                            self.seed0.show(ui);
+                            ui.end_row();
+                            ui.label("Seed 1");
+                            self.seed1.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            if let Some(data) = self.wondercard.show(ui, WB8_SIZE) {
+                                self.wb8 = Some(WB8::from(data.as_slice()));
+                                self.results.clear();
+                            }
+                            ui.label("Pokemon");
+                            ui.label(match &self.wb8 {
+                                // Species outside of the BDSP dex means the file is not a WB8
+                                Some(wb8) if (1..=493).contains(&wb8.species) => {
+                                    translator::get_species(wb8.species)
+                                }
+                                Some(_) => "Invalid wondercard",
+                                None => "-",
+                            });
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "event_8_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "event_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(wb8) = self
+            .wb8
+            .as_ref()
+            .filter(|wb8| (1..=493).contains(&wb8.species))
+        else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter8::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = EventGenerator8::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            wb8,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed0.value, self.seed1.value)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string(), state.base.level.to_string()];
+                row.extend(format_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
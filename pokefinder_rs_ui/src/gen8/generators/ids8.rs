@@ -0,0 +1,88 @@
+use crate::controls::{results_table, GeneratorSettings, HexField, IDFilterControls};
+use egui::Context;
+use pokefinder_rs_core::gen8::generators::IDGenerator8;
+
+pub struct IDs8 {
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    filter: IDFilterControls,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for IDs8 {
+    fn default() -> Self {
+        Self {
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            filter: IDFilterControls::default(),
+            results: vec![],
+        }
+    }
+}
+
+static HEADERS: [&str; 5] = ["Advances", "Display TID", "TID", "SID", "TSV"];
+
+impl IDs8 {
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("ID Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            ui.horizontal_top(|ui| {
+                egui::Grid::new("ids_8_settings")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Seed 0");
+                        self.seed0.show(ui);
+                        ui.end_row();
+                        ui.label("Seed 1");
+                        self.seed1.show(ui);
+                        ui.end_row();
+                        self.settings.show(ui, 0, false);
+                    });
+                ui.separator();
+                egui::Grid::new("ids_8_filter")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        self.filter.show(ui, true);
+                    });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                results_table(ui, "ids_8_results", &HEADERS, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let filter = self.filter.get_filter();
+        let generator = IDGenerator8::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed0.value, self.seed1.value)
+            .into_iter()
+            .map(|state| {
+                vec![
+                    state.advances.to_string(),
+                    format!("{:0>6}", state.display_tid),
+                    state.tid.to_string(),
+                    state.sid.to_string(),
+                    state.tsv.to_string(),
+                ]
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,95 @@
+mod egg8;
+mod event8;
+mod ids8;
+mod raid8;
+mod static8;
+mod underground8;
+mod wild8;
+
+pub use egg8::*;
+pub use event8::*;
+pub use ids8::*;
+pub use raid8::*;
+pub use static8::*;
+pub use underground8::*;
+pub use wild8::*;
+
+use egui::Context;
+
+#[derive(Default)]
+pub struct Gen8Generators {
+    pub egg: Option<Box<Egg8>>,
+    pub event: Option<Box<Event8>>,
+    pub ids: Option<Box<IDs8>>,
+    pub raid: Option<Box<Raid8>>,
+    pub static_generator: Option<Box<Static8>>,
+    pub underground: Option<Box<Underground8>>,
+    pub wild: Option<Box<Wild8>>,
+}
+
+impl Gen8Generators {
+    pub fn show(&mut self, ctx: &Context) {
+        let mut close = self
+            .egg
+            .as_mut()
+            .map(|egg| egg.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.egg = None;
+        }
+
+        close = self
+            .event
+            .as_mut()
+            .map(|event| event.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.event = None;
+        }
+
+        close = self
+            .ids
+            .as_mut()
+            .map(|ids| ids.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.ids = None;
+        }
+
+        close = self
+            .raid
+            .as_mut()
+            .map(|raid| raid.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.raid = None;
+        }
+
+        close = self
+            .static_generator
+            .as_mut()
+            .map(|static_generator| static_generator.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.static_generator = None;
+        }
+
+        close = self
+            .underground
+            .as_mut()
+            .map(|underground| underground.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.underground = None;
+        }
+
+        close = self
+            .wild
+            .as_mut()
+            .map(|wild| wild.show(ctx))
+            .unwrap_or_default();
+        if close {
+            self.wild = None;
+        }
+    }
+}
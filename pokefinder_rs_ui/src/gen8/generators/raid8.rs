@@ -0,0 +1,203 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField, STATE_HEADERS,
+};
+use egui::{Context, Widget};
+use pokefinder_rs_core::enums::Game;
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::RaidGenerator;
+use pokefinder_rs_core::gen8::{den_loader, Profile8, Raid};
+use pokefinder_rs_core::parents::{profile_loader_8, Profile, Template};
+use pokefinder_rs_core::util::translator;
+
+/// Number of dens in Sword and Shield
+const DENS: usize = 276;
+
+pub struct Raid8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed: HexField,
+    settings: GeneratorSettings,
+    den: usize,
+    rare: bool,
+    raids: Vec<Raid>,
+    raid: usize,
+    level: u8,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Raid8 {
+    fn default() -> Self {
+        // Max Raid Battles only exist in Sword and Shield
+        let mut profiles = profile_loader_8::get_profiles();
+        profiles.retain(|profile| (profile.get_version() & Game::SWSH) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile8::new(
+                "-".to_string(),
+                Game::SWORD,
+                12345,
+                54321,
+                false,
+                false,
+                false,
+            ));
+        }
+        let mut raid8 = Self {
+            profiles,
+            profile: 0,
+            seed: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            den: 0,
+            rare: false,
+            raids: vec![],
+            raid: 0,
+            level: 15,
+            filter: Filter::default(),
+            results: vec![],
+        };
+        raid8.update_raids();
+        raid8
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Level"];
+
+impl Raid8 {
+    fn update_raids(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.raids = den_loader::get_den(self.den, self.rare as usize)
+            .get_raids(version)
+            .to_vec();
+        self.raid = 0;
+    }
+
+    fn raid_name(raid: &Raid) -> String {
+        format!(
+            "{} {}",
+            raid.get_star_display(),
+            translator::get_species_with_form(raid.base.get_species(), raid.base.get_form())
+        )
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Raid Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "raid_8_profile", &self.profiles, &mut self.profile) {
+                self.update_raids();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("raid_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed");
+                            self.seed.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Den");
+                            let (den, rare) = (self.den, self.rare);
+                            ui.horizontal(|ui| {
+                                egui::DragValue::new(&mut self.den)
+                                    .clamp_range(0..=DENS - 1)
+                                    .ui(ui);
+                                ui.checkbox(&mut self.rare, "Rare");
+                            });
+                            if den != self.den || rare != self.rare {
+                                self.update_raids();
+                            }
+                            ui.end_row();
+                            ui.label("Location");
+                            ui.label(
+                                translator::get_locations(
+                                    &[den_loader::get_location(self.den) as u16],
+                                    Game::SWSH,
+                                )
+                                .remove(0),
+                            );
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            egui::ComboBox::from_id_source("raid_8_pokemon")
+                                .selected_text(
+                                    self.raids
+                                        .get(self.raid)
+                                        .map(Self::raid_name)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, raid) in self.raids.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.raid,
+                                            i,
+                                            Self::raid_name(raid),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Level");
+                            egui::DragValue::new(&mut self.level)
+                                .clamp_range(1..=100)
+                                .ui(ui);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "raid_8_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "raid_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(raid) = self.raids.get(self.raid) else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter8::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = RaidGenerator::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed.value, self.level, raid)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string(), state.base.level.to_string()];
+                row.extend(format_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
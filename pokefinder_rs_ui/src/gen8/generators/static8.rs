@@ -0,0 +1,204 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField, LeadSelect,
+    STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Game, Lead};
+use pokefinder_rs_core::gen8::filters::StateFilter8;
+use pokefinder_rs_core::gen8::generators::StaticGenerator8;
+use pokefinder_rs_core::gen8::{encounters8, Profile8};
+use pokefinder_rs_core::parents::{profile_loader_8, Profile, StaticTemplate, Template};
+use pokefinder_rs_core::util::translator;
+
+const CATEGORIES: [&str; 9] = [
+    "Starters",
+    "Gifts",
+    "Fossils",
+    "Stationary",
+    "Roamers",
+    "Legends",
+    "Ramanas Park (Pure Space)",
+    "Ramanas Park (Strange Space)",
+    "Mythics",
+];
+const ROAMERS: usize = 4;
+const LEADS: [Lead; 3] = [Lead::SYNCHRONIZE, Lead::CUTE_CHARM_F, Lead::CUTE_CHARM_M];
+
+pub struct Static8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    category: usize,
+    templates: Vec<&'static StaticTemplate>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Static8 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_8::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile8::default());
+        }
+        let mut static8 = Self {
+            profiles,
+            profile: 0,
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            category: 0,
+            templates: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        static8.update_templates();
+        static8
+    }
+}
+
+static HEADERS: [&str; 2] = ["Advances", "Level"];
+
+impl Static8 {
+    fn update_templates(&mut self) {
+        let version = self.profiles[self.profile].get_version();
+        self.templates = encounters8::get_static_encounters(self.category)
+            .iter()
+            .filter(|template| (template.get_version() & version) != Game::NONE)
+            .collect();
+        self.pokemon = 0;
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Static Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "static_8_profile", &self.profiles, &mut self.profile) {
+                self.update_templates();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("static_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed 0");
+                            self.seed0.show(ui);
+                            ui.end_row();
+                            ui.label("Seed 1");
+                            self.seed1.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Category");
+                            let previous = self.category;
+                            egui::ComboBox::from_id_source("static_8_category")
+                                .selected_text(CATEGORIES[self.category])
+                                .show_ui(ui, |ui| {
+                                    for (i, category) in CATEGORIES.iter().enumerate() {
+                                        ui.selectable_value(&mut self.category, i, *category);
+                                    }
+                                });
+                            if previous != self.category {
+                                self.update_templates();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            egui::ComboBox::from_id_source("static_8_pokemon")
+                                .selected_text(
+                                    self.templates
+                                        .get(self.pokemon)
+                                        .map(|template| {
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            )
+                                        })
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, template) in self.templates.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i,
+                                            translator::get_species_with_form(
+                                                template.get_species(),
+                                                template.get_form(),
+                                            ),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "static_8_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "static_8_filter", 0);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "static_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(template) = self.templates.get(self.pokemon) else {
+            self.results.clear();
+            return;
+        };
+        let filter = StateFilter8::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+        );
+        let generator = StaticGenerator8::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.lead.get_lead(),
+            &self.profiles[self.profile],
+            &filter,
+        );
+        let states = if self.category == ROAMERS {
+            generator.generate_roamer(self.seed0.value, self.seed1.value, template)
+        } else {
+            generator.generate(self.seed0.value, self.seed1.value, template)
+        };
+        self.results = states
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![state.advances.to_string(), state.base.level.to_string()];
+                row.extend(format_state(&state.base));
+                row
+            })
+            .collect();
+    }
+}
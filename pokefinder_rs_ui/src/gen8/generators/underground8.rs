@@ -0,0 +1,345 @@
+use crate::controls::{
+    format_state, profile_select, results_table, Filter, GeneratorSettings, HexField, LeadSelect,
+    STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Game, Lead};
+use pokefinder_rs_core::gen8::filters::UndergroundStateFilter;
+use pokefinder_rs_core::gen8::generators::UndergroundGenerator;
+use pokefinder_rs_core::gen8::states::UndergroundState;
+use pokefinder_rs_core::gen8::{encounters8, Profile8, UndergroundArea};
+use pokefinder_rs_core::parents::{profile_loader_8, Profile};
+use pokefinder_rs_core::util::translator;
+
+const STORY_FLAGS: [&str; 6] = [
+    "Underground Unlocked",
+    "Strength Obtained",
+    "Defog Obtained",
+    "7 Badges",
+    "Waterfall Obtained",
+    "National Dex",
+];
+const LEVEL_FLAGS: [&str; 9] = [
+    "0-1 Badges",
+    "2 Badges",
+    "3 Badges",
+    "4 Badges",
+    "5 Badges",
+    "6 Badges",
+    "7 Badges",
+    "8 Badges",
+    "National Dex",
+];
+const LEADS: [Lead; 5] = [
+    Lead::SYNCHRONIZE,
+    Lead::CUTE_CHARM_F,
+    Lead::CUTE_CHARM_M,
+    Lead::PRESSURE,
+    Lead::COMPOUND_EYES,
+];
+/// Room ids start at 2 while their names start at this index of the BDSP location table
+const ROOM_OFFSET: u16 = 181;
+
+pub struct Underground8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    story_flag: u8,
+    level_flag: u8,
+    diglett: bool,
+    areas: Vec<UndergroundArea>,
+    rooms: Vec<String>,
+    room: usize,
+    species: Vec<(u16, bool)>,
+    lead: LeadSelect,
+    filter: Filter,
+    states: Vec<UndergroundState>,
+    egg_moves: Vec<u16>,
+    egg_move: Option<u16>,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Underground8 {
+    fn default() -> Self {
+        // The Grand Underground only exists in BDSP
+        let mut profiles = profile_loader_8::get_profiles();
+        profiles.retain(|profile| (profile.get_version() & Game::BDSP) != Game::NONE);
+        if profiles.is_empty() {
+            profiles.push(Profile8::default());
+        }
+        let mut underground = Self {
+            profiles,
+            profile: 0,
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            story_flag: 1,
+            level_flag: 0,
+            diglett: false,
+            areas: vec![],
+            rooms: vec![],
+            room: 0,
+            species: vec![],
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            states: vec![],
+            egg_moves: vec![],
+            egg_move: None,
+            results: vec![],
+        };
+        underground.update_rooms();
+        underground
+    }
+}
+
+static HEADERS: [&str; 5] = ["Advances", "Species", "Level", "Egg Move", "Item"];
+
+fn format_underground(state: &UndergroundState) -> Vec<String> {
+    let mut row = vec![
+        state.base.advances.to_string(),
+        translator::get_species(state.species).to_string(),
+        state.base.base.level.to_string(),
+        if state.egg_move == 0 {
+            "-".to_string()
+        } else {
+            translator::get_move(state.egg_move).to_string()
+        },
+        if state.item == 0 {
+            "-".to_string()
+        } else {
+            translator::get_item(state.item).to_string()
+        },
+    ];
+    row.extend(format_state(&state.base.base));
+    row
+}
+
+impl Underground8 {
+    fn update_rooms(&mut self) {
+        self.areas = encounters8::get_underground_encounters(
+            self.story_flag,
+            self.diglett,
+            &self.profiles[self.profile],
+        );
+        let locations = self
+            .areas
+            .iter()
+            .map(|area| area.location as u16 + ROOM_OFFSET)
+            .collect::<Vec<_>>();
+        self.rooms = translator::get_locations(&locations, Game::BDSP);
+        self.room = self.room.min(self.rooms.len().saturating_sub(1));
+        self.update_species();
+    }
+
+    fn update_species(&mut self) {
+        let mut species = self
+            .areas
+            .get(self.room)
+            .map(|area| area.get_species())
+            .unwrap_or_default();
+        species.dedup();
+        self.species = species.into_iter().map(|specie| (specie, true)).collect();
+    }
+
+    fn update_results(&mut self) {
+        self.results = self
+            .states
+            .iter()
+            .filter(|state| self.egg_move.map_or(true, |mov| state.egg_move == mov))
+            .map(format_underground)
+            .collect();
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Underground Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(
+                ui,
+                "underground_8_profile",
+                &self.profiles,
+                &mut self.profile,
+            ) {
+                self.update_rooms();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("underground_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed 0");
+                            self.seed0.show(ui);
+                            ui.end_row();
+                            ui.label("Seed 1");
+                            self.seed1.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Story Flag");
+                            let previous = self.story_flag;
+                            egui::ComboBox::from_id_source("underground_8_story_flag")
+                                .selected_text(STORY_FLAGS[self.story_flag as usize - 1])
+                                .show_ui(ui, |ui| {
+                                    for (i, flag) in STORY_FLAGS.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.story_flag,
+                                            i as u8 + 1,
+                                            *flag,
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("Level Range");
+                            egui::ComboBox::from_id_source("underground_8_level_flag")
+                                .selected_text(LEVEL_FLAGS[self.level_flag as usize])
+                                .show_ui(ui, |ui| {
+                                    for (i, flag) in LEVEL_FLAGS.iter().enumerate() {
+                                        ui.selectable_value(&mut self.level_flag, i as u8, *flag);
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("");
+                            let diglett = ui.checkbox(&mut self.diglett, "Diglett Boost").changed();
+                            if diglett || previous != self.story_flag {
+                                self.update_rooms();
+                            }
+                            ui.end_row();
+                            ui.label("Room");
+                            let previous = self.room;
+                            egui::ComboBox::from_id_source("underground_8_room")
+                                .selected_text(
+                                    self.rooms.get(self.room).cloned().unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, room) in self.rooms.iter().enumerate() {
+                                        ui.selectable_value(&mut self.room, i, room);
+                                    }
+                                });
+                            if previous != self.room {
+                                self.update_species();
+                            }
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "underground_8_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "underground_8_filter", 0);
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    ui.label("Species");
+                    ui.horizontal(|ui| {
+                        if ui.small_button("All").clicked() {
+                            self.species.iter_mut().for_each(|(_, show)| *show = true);
+                        }
+                        if ui.small_button("None").clicked() {
+                            self.species.iter_mut().for_each(|(_, show)| *show = false);
+                        }
+                    });
+                    egui::ScrollArea::vertical()
+                        .id_source("underground_8_species")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            for (specie, show) in self.species.iter_mut() {
+                                ui.checkbox(show, translator::get_species(*specie));
+                            }
+                        });
+                    ui.separator();
+                    ui.label("Egg Move");
+                    let previous = self.egg_move;
+                    egui::ComboBox::from_id_source("underground_8_egg_move")
+                        .selected_text(self.egg_move.map(translator::get_move).unwrap_or("Any"))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.egg_move, None, "Any");
+                            for &mov in &self.egg_moves {
+                                ui.selectable_value(
+                                    &mut self.egg_move,
+                                    Some(mov),
+                                    translator::get_move(mov),
+                                );
+                            }
+                        });
+                    if previous != self.egg_move {
+                        self.update_results();
+                    }
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "underground_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.room) else {
+            self.states.clear();
+            self.results.clear();
+            return;
+        };
+        let species = self
+            .species
+            .iter()
+            .filter(|(_, show)| *show)
+            .map(|(specie, _)| *specie)
+            .collect::<Vec<_>>();
+        let filter = UndergroundStateFilter::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+            &species,
+        );
+        let generator = UndergroundGenerator::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.lead.get_lead(),
+            self.diglett,
+            self.level_flag,
+            &self.profiles[self.profile],
+            &filter,
+        );
+        self.states = generator.generate(self.seed0.value, self.seed1.value, area);
+
+        // Egg moves depend on the generated species, so the choices are rebuilt from the results
+        self.egg_moves = self
+            .states
+            .iter()
+            .map(|state| state.egg_move)
+            .filter(|&mov| mov != 0)
+            .collect();
+        self.egg_moves.sort_unstable();
+        self.egg_moves.dedup();
+        if self
+            .egg_move
+            .is_some_and(|mov| !self.egg_moves.contains(&mov))
+        {
+            self.egg_move = None;
+        }
+        self.update_results();
+    }
+}
@@ -0,0 +1,304 @@
+use crate::controls::{
+    encounter_name, format_state, profile_select, results_table, Filter, GeneratorSettings,
+    HexField, LeadSelect, STATE_HEADERS,
+};
+use egui::Context;
+use pokefinder_rs_core::enums::{Encounter, Lead};
+use pokefinder_rs_core::gen8::filters::WildStateFilter8;
+use pokefinder_rs_core::gen8::generators::WildGenerator8;
+use pokefinder_rs_core::gen8::{encounters8, EncounterArea8, Profile8};
+use pokefinder_rs_core::parents::{profile_loader_8, EncounterAreaT, Profile};
+use pokefinder_rs_core::util::translator;
+
+const ENCOUNTERS: [Encounter; 5] = [
+    Encounter::Grass,
+    Encounter::Surfing,
+    Encounter::OldRod,
+    Encounter::GoodRod,
+    Encounter::SuperRod,
+];
+const LEADS: [Lead; 8] = [
+    Lead::SYNCHRONIZE,
+    Lead::CUTE_CHARM_F,
+    Lead::CUTE_CHARM_M,
+    Lead::MAGNET_PULL,
+    Lead::STATIC,
+    Lead::PRESSURE,
+    Lead::SUCTION_CUPS,
+    Lead::COMPOUND_EYES,
+];
+const TIMES: [&str; 3] = ["Morning", "Day", "Night"];
+
+pub struct Wild8 {
+    profiles: Vec<Profile8>,
+    profile: usize,
+    seed0: HexField,
+    seed1: HexField,
+    settings: GeneratorSettings,
+    encounter: Encounter,
+    time: u8,
+    radar: bool,
+    swarm: bool,
+    areas: Vec<EncounterArea8>,
+    locations: Vec<String>,
+    location: usize,
+    species: Vec<u16>,
+    pokemon: usize,
+    lead: LeadSelect,
+    filter: Filter,
+    results: Vec<Vec<String>>,
+}
+
+impl Default for Wild8 {
+    fn default() -> Self {
+        let mut profiles = profile_loader_8::get_profiles();
+        if profiles.is_empty() {
+            profiles.push(Profile8::default());
+        }
+        let mut wild = Self {
+            profiles,
+            profile: 0,
+            seed0: HexField::new(0, 16),
+            seed1: HexField::new(0, 16),
+            settings: GeneratorSettings::default(),
+            encounter: Encounter::Grass,
+            time: 0,
+            radar: false,
+            swarm: false,
+            areas: vec![],
+            locations: vec![],
+            location: 0,
+            species: vec![],
+            pokemon: 0,
+            lead: LeadSelect::default(),
+            filter: Filter::default(),
+            results: vec![],
+        };
+        wild.update_locations();
+        wild
+    }
+}
+
+static HEADERS: [&str; 4] = ["Advances", "Slot", "Species", "Level"];
+
+impl Wild8 {
+    fn update_locations(&mut self) {
+        let profile = &self.profiles[self.profile];
+        let version = profile.get_version();
+        self.areas = encounters8::get_encounters(
+            self.encounter,
+            self.time,
+            self.radar,
+            self.swarm,
+            [0, 0],
+            profile,
+        );
+        let locations = self
+            .areas
+            .iter()
+            .map(|area| area.get_location() as u16)
+            .collect::<Vec<_>>();
+        self.locations = translator::get_locations(&locations, version);
+        self.location = 0;
+        self.update_pokemon();
+    }
+
+    fn update_pokemon(&mut self) {
+        self.species = self
+            .areas
+            .get(self.location)
+            .map(|area| area.get_unique_species())
+            .unwrap_or_default();
+        self.pokemon = 0;
+        self.filter.encounter_slots = [true; 12];
+    }
+
+    fn update_slots(&mut self) {
+        self.filter.encounter_slots = [true; 12];
+        if self.pokemon != 0 {
+            let slots =
+                self.areas[self.location].get_slots_by_specie(self.species[self.pokemon - 1]);
+            for (filter, slot) in self.filter.encounter_slots.iter_mut().zip(slots) {
+                *filter = slot;
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) -> bool {
+        let mut close = false;
+
+        egui::Window::new("Wild Generator 8").show(ctx, |ui| {
+            ui.style_mut().wrap = Some(false);
+            if profile_select(ui, "wild_8_profile", &self.profiles, &mut self.profile) {
+                self.update_locations();
+            }
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    egui::Grid::new("wild_8_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Seed 0");
+                            self.seed0.show(ui);
+                            ui.end_row();
+                            ui.label("Seed 1");
+                            self.seed1.show(ui);
+                            ui.end_row();
+                            self.settings.show(ui, 0, true);
+                            ui.label("Encounter");
+                            let previous = self.encounter;
+                            egui::ComboBox::from_id_source("wild_8_encounter")
+                                .selected_text(encounter_name(self.encounter))
+                                .show_ui(ui, |ui| {
+                                    for encounter in ENCOUNTERS {
+                                        ui.selectable_value(
+                                            &mut self.encounter,
+                                            encounter,
+                                            encounter_name(encounter),
+                                        );
+                                    }
+                                });
+                            if previous != self.encounter {
+                                self.update_locations();
+                            }
+                            ui.end_row();
+                            ui.label("Time");
+                            let mut refresh = false;
+                            egui::ComboBox::from_id_source("wild_8_time")
+                                .selected_text(TIMES[self.time as usize])
+                                .show_ui(ui, |ui| {
+                                    for (i, time) in TIMES.iter().enumerate() {
+                                        refresh |= ui
+                                            .selectable_value(&mut self.time, i as u8, *time)
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label("");
+                            ui.horizontal(|ui| {
+                                refresh |= ui.checkbox(&mut self.radar, "Poke Radar").changed();
+                                refresh |= ui.checkbox(&mut self.swarm, "Swarm").changed();
+                            });
+                            if refresh {
+                                self.update_locations();
+                            }
+                            ui.end_row();
+                            ui.label("Location");
+                            let previous = self.location;
+                            egui::ComboBox::from_id_source("wild_8_location")
+                                .selected_text(
+                                    self.locations
+                                        .get(self.location)
+                                        .map(String::as_str)
+                                        .unwrap_or_default(),
+                                )
+                                .show_ui(ui, |ui| {
+                                    for (i, location) in self.locations.iter().enumerate() {
+                                        ui.selectable_value(&mut self.location, i, location);
+                                    }
+                                });
+                            if previous != self.location {
+                                self.update_pokemon();
+                            }
+                            ui.end_row();
+                            ui.label("Pokemon");
+                            let previous = self.pokemon;
+                            egui::ComboBox::from_id_source("wild_8_pokemon")
+                                .selected_text(if self.pokemon == 0 {
+                                    "Any".to_string()
+                                } else {
+                                    let species = self.species[self.pokemon - 1];
+                                    translator::get_species_with_form(
+                                        species & 0x7ff,
+                                        (species >> 11) as u8,
+                                    )
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut self.pokemon, 0, "Any");
+                                    for (i, &species) in self.species.iter().enumerate() {
+                                        ui.selectable_value(
+                                            &mut self.pokemon,
+                                            i + 1,
+                                            translator::get_species_with_form(
+                                                species & 0x7ff,
+                                                (species >> 11) as u8,
+                                            ),
+                                        );
+                                    }
+                                });
+                            if previous != self.pokemon {
+                                self.update_slots();
+                            }
+                            ui.end_row();
+                            ui.label("Lead");
+                            self.lead.show(ui, "wild_8_lead", &LEADS);
+                            ui.end_row();
+                        });
+                });
+                ui.separator();
+                ui.vertical(|ui| {
+                    self.filter.show(ui, "wild_8_filter", 12);
+                });
+            });
+            ui.separator();
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("Generate").clicked() {
+                    self.generate();
+                }
+                let headers = HEADERS
+                    .iter()
+                    .chain(STATE_HEADERS.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                results_table(ui, "wild_8_results", &headers, &self.results, None);
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        close
+    }
+
+    fn generate(&mut self) {
+        let Some(area) = self.areas.get(self.location) else {
+            self.results.clear();
+            return;
+        };
+        let filter = WildStateFilter8::new(
+            self.filter.gender,
+            self.filter.ability,
+            self.filter.shiny,
+            self.filter.skip,
+            self.filter.min,
+            self.filter.max,
+            self.filter.natures,
+            self.filter.powers,
+            self.filter.encounter_slots,
+        );
+        let profile = &self.profiles[self.profile];
+        let generator = WildGenerator8::new(
+            self.settings.initial_advances,
+            self.settings.max_advances,
+            self.settings.delay,
+            self.encounter,
+            self.lead.get_lead(),
+            profile,
+            &filter,
+        );
+        self.results = generator
+            .generate(self.seed0.value, self.seed1.value, area)
+            .into_iter()
+            .map(|state| {
+                let mut row = vec![
+                    state.advances.to_string(),
+                    state.base.encounter_slot.to_string(),
+                    translator::get_species_with_form(state.base.species, state.base.form),
+                    state.base.base.level.to_string(),
+                ];
+                row.extend(format_state(&state.base.base));
+                row
+            })
+            .collect();
+    }
+}
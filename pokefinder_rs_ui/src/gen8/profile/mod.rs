@@ -0,0 +1,5 @@
+mod profile_editor8;
+mod profile_manager8;
+
+pub use profile_editor8::*;
+pub use profile_manager8::*;
@@ -1,6 +1,9 @@
 pub mod app;
+mod controls;
 mod gen3;
 mod gen4;
+mod gen5;
+mod gen8;
 mod util;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Default, Copy, Clone)]